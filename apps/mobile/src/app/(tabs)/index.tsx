@@ -86,12 +86,20 @@ function Host() {
 
 	const buttonLabel = (() => {
 		if (!sshConnMutation.isPending) return 'Connect';
-		if (lastConnectionProgressEvent === null) return 'TCP Connecting...';
-		if (lastConnectionProgressEvent === 'tcpConnected')
-			return 'SSH Handshake...';
-		if (lastConnectionProgressEvent === 'sshHandshake')
-			return 'Authenticating...';
-		return 'Connected!';
+		switch (lastConnectionProgressEvent?.type) {
+			case undefined:
+			case 'dnsResolved':
+				return 'TCP Connecting...';
+			case 'tcpConnected':
+			case 'hostKeyVerified':
+				return 'SSH Handshake...';
+			case 'sshHandshake':
+			case 'authenticating':
+			case 'authAttempt':
+				return 'Authenticating...';
+			default:
+				return 'Connected!';
+		}
 	})();
 
 	return (
@@ -252,7 +252,8 @@ function ShellDetail() {
 										const id = shell.addListener(
 											(ev: ListenerEvent) => {
 												if ('kind' in ev) {
-													logger.warn('listener.dropped', ev);
+													if (ev.kind === 'dropped')
+														logger.warn('listener.dropped', ev);
 													return;
 												}
 												const chunk = ev;
@@ -134,6 +134,24 @@ extern "C" {
     UniffiForeignFutureStructVoid result
     );
     typedef void
+    (*UniffiCallbackInterfaceAuthBannerCallbackMethod0)(
+    uint64_t uniffi_handle, 
+    RustBuffer banner, 
+    void * uniffi_out_return, RustCallStatus* rust_call_status
+    );
+    typedef void
+    (*UniffiCallbackInterfaceCastPlayerStatusCallbackMethod0)(
+    uint64_t uniffi_handle, 
+    RustBuffer status, 
+    void * uniffi_out_return, RustCallStatus* rust_call_status
+    );
+    typedef void
+    (*UniffiCallbackInterfaceChannelPauseCallbackMethod0)(
+    uint64_t uniffi_handle, 
+    int8_t paused, 
+    void * uniffi_out_return, RustCallStatus* rust_call_status
+    );
+    typedef void
     (*UniffiCallbackInterfaceConnectProgressCallbackMethod0)(
     uint64_t uniffi_handle, 
     RustBuffer status, 
@@ -146,6 +164,18 @@ extern "C" {
     void * uniffi_out_return, RustCallStatus* rust_call_status
     );
     typedef void
+    (*UniffiCallbackInterfaceConnectionEventCallbackMethod0)(
+    uint64_t uniffi_handle, 
+    RustBuffer event, 
+    void * uniffi_out_return, RustCallStatus* rust_call_status
+    );
+    typedef void
+    (*UniffiCallbackInterfaceConnectionMetricsCallbackMethod0)(
+    uint64_t uniffi_handle, 
+    RustBuffer metrics, 
+    void * uniffi_out_return, RustCallStatus* rust_call_status
+    );
+    typedef void
     (*UniffiCallbackInterfaceServerKeyCallbackMethod0)(
     uint64_t uniffi_handle, 
     RustBuffer server_key_info, 
@@ -164,13 +194,28 @@ extern "C" {
     uint64_t uniffi_handle, 
     RustBuffer ev, 
     void * uniffi_out_return, RustCallStatus* rust_call_status
-    );typedef struct UniffiVTableCallbackInterfaceConnectProgressCallback {
+    );typedef struct UniffiVTableCallbackInterfaceAuthBannerCallback {
+        UniffiCallbackInterfaceAuthBannerCallbackMethod0 on_change;
+        UniffiCallbackInterfaceFree uniffi_free;
+    } UniffiVTableCallbackInterfaceAuthBannerCallback;typedef struct UniffiVTableCallbackInterfaceCastPlayerStatusCallback {
+        UniffiCallbackInterfaceCastPlayerStatusCallbackMethod0 on_change;
+        UniffiCallbackInterfaceFree uniffi_free;
+    } UniffiVTableCallbackInterfaceCastPlayerStatusCallback;typedef struct UniffiVTableCallbackInterfaceChannelPauseCallback {
+        UniffiCallbackInterfaceChannelPauseCallbackMethod0 on_change;
+        UniffiCallbackInterfaceFree uniffi_free;
+    } UniffiVTableCallbackInterfaceChannelPauseCallback;typedef struct UniffiVTableCallbackInterfaceConnectProgressCallback {
         UniffiCallbackInterfaceConnectProgressCallbackMethod0 on_change;
         UniffiCallbackInterfaceFree uniffi_free;
     } UniffiVTableCallbackInterfaceConnectProgressCallback;typedef struct UniffiVTableCallbackInterfaceConnectionDisconnectedCallback {
         UniffiCallbackInterfaceConnectionDisconnectedCallbackMethod0 on_change;
         UniffiCallbackInterfaceFree uniffi_free;
-    } UniffiVTableCallbackInterfaceConnectionDisconnectedCallback;typedef struct UniffiVTableCallbackInterfaceServerKeyCallback {
+    } UniffiVTableCallbackInterfaceConnectionDisconnectedCallback;typedef struct UniffiVTableCallbackInterfaceConnectionEventCallback {
+        UniffiCallbackInterfaceConnectionEventCallbackMethod0 on_change;
+        UniffiCallbackInterfaceFree uniffi_free;
+    } UniffiVTableCallbackInterfaceConnectionEventCallback;typedef struct UniffiVTableCallbackInterfaceConnectionMetricsCallback {
+        UniffiCallbackInterfaceConnectionMetricsCallbackMethod0 on_change;
+        UniffiCallbackInterfaceFree uniffi_free;
+    } UniffiVTableCallbackInterfaceConnectionMetricsCallback;typedef struct UniffiVTableCallbackInterfaceServerKeyCallback {
         UniffiCallbackInterfaceServerKeyCallbackMethod0 on_change;
         UniffiCallbackInterfaceFree uniffi_free;
     } UniffiVTableCallbackInterfaceServerKeyCallback;typedef struct UniffiVTableCallbackInterfaceShellClosedCallback {
@@ -180,6 +225,156 @@ extern "C" {
         UniffiCallbackInterfaceShellListenerMethod0 on_event;
         UniffiCallbackInterfaceFree uniffi_free;
     } UniffiVTableCallbackInterfaceShellListener;
+    void * uniffi_uniffi_russh_fn_clone_authbannercallback(
+        void * ptr, 
+        RustCallStatus *uniffi_out_err
+    );
+    void uniffi_uniffi_russh_fn_free_authbannercallback(
+        void * ptr, 
+        RustCallStatus *uniffi_out_err
+    );
+    void uniffi_uniffi_russh_fn_init_callback_vtable_authbannercallback(
+        UniffiVTableCallbackInterfaceAuthBannerCallback * vtable
+    );
+    void uniffi_uniffi_russh_fn_method_authbannercallback_on_change(
+        void * ptr, 
+        RustBuffer banner, 
+        RustCallStatus *uniffi_out_err
+    );
+    void * uniffi_uniffi_russh_fn_clone_castplayer(
+        void * ptr, 
+        RustCallStatus *uniffi_out_err
+    );
+    void uniffi_uniffi_russh_fn_free_castplayer(
+        void * ptr, 
+        RustCallStatus *uniffi_out_err
+    );
+    uint64_t uniffi_uniffi_russh_fn_method_castplayer_add_listener(
+        void * ptr, 
+        void * listener, 
+        RustBuffer opts, 
+        RustCallStatus *uniffi_out_err
+    );
+    void * uniffi_uniffi_russh_fn_method_castplayer_attach_emulator(
+        void * ptr, 
+        RustBuffer scrollback_lines, 
+        RustCallStatus *uniffi_out_err
+    );
+    RustBuffer uniffi_uniffi_russh_fn_method_castplayer_buffer_stats(
+        void * ptr, 
+        RustCallStatus *uniffi_out_err
+    );
+    RustBuffer uniffi_uniffi_russh_fn_method_castplayer_commands(
+        void * ptr, 
+        RustCallStatus *uniffi_out_err
+    );
+    uint64_t uniffi_uniffi_russh_fn_method_castplayer_current_seq(
+        void * ptr, 
+        RustCallStatus *uniffi_out_err
+    );
+    void uniffi_uniffi_russh_fn_method_castplayer_detach_emulator(
+        void * ptr, 
+        RustCallStatus *uniffi_out_err
+    );
+    RustBuffer uniffi_uniffi_russh_fn_method_castplayer_get_info(
+        void * ptr, 
+        RustCallStatus *uniffi_out_err
+    );
+    RustBuffer uniffi_uniffi_russh_fn_method_castplayer_get_status(
+        void * ptr, 
+        RustCallStatus *uniffi_out_err
+    );
+    void uniffi_uniffi_russh_fn_method_castplayer_pause(
+        void * ptr, 
+        RustCallStatus *uniffi_out_err
+    );
+    void uniffi_uniffi_russh_fn_method_castplayer_play(
+        void * ptr, 
+        RustCallStatus *uniffi_out_err
+    );
+    RustBuffer uniffi_uniffi_russh_fn_method_castplayer_read_buffer(
+        void * ptr, 
+        RustBuffer cursor, 
+        RustBuffer max_bytes, 
+        RustCallStatus *uniffi_out_err
+    );
+    RustBuffer uniffi_uniffi_russh_fn_method_castplayer_read_text(
+        void * ptr, 
+        RustBuffer cursor, 
+        RustBuffer max_bytes, 
+        RustCallStatus *uniffi_out_err
+    );
+    void uniffi_uniffi_russh_fn_method_castplayer_remove_listener(
+        void * ptr, 
+        uint64_t id, 
+        RustCallStatus *uniffi_out_err
+    );
+    /*handle*/ uint64_t uniffi_uniffi_russh_fn_method_castplayer_search(
+        void * ptr, 
+        RustBuffer pattern, 
+        RustBuffer opts
+    );
+    void uniffi_uniffi_russh_fn_method_castplayer_seek(
+        void * ptr, 
+        double position_ms, 
+        RustCallStatus *uniffi_out_err
+    );
+    void uniffi_uniffi_russh_fn_method_castplayer_set_speed(
+        void * ptr, 
+        double speed, 
+        RustCallStatus *uniffi_out_err
+    );
+    void * uniffi_uniffi_russh_fn_clone_castplayerstatuscallback(
+        void * ptr, 
+        RustCallStatus *uniffi_out_err
+    );
+    void uniffi_uniffi_russh_fn_free_castplayerstatuscallback(
+        void * ptr, 
+        RustCallStatus *uniffi_out_err
+    );
+    void uniffi_uniffi_russh_fn_init_callback_vtable_castplayerstatuscallback(
+        UniffiVTableCallbackInterfaceCastPlayerStatusCallback * vtable
+    );
+    void uniffi_uniffi_russh_fn_method_castplayerstatuscallback_on_change(
+        void * ptr, 
+        RustBuffer status, 
+        RustCallStatus *uniffi_out_err
+    );
+    void * uniffi_uniffi_russh_fn_clone_channelpausecallback(
+        void * ptr, 
+        RustCallStatus *uniffi_out_err
+    );
+    void uniffi_uniffi_russh_fn_free_channelpausecallback(
+        void * ptr, 
+        RustCallStatus *uniffi_out_err
+    );
+    void uniffi_uniffi_russh_fn_init_callback_vtable_channelpausecallback(
+        UniffiVTableCallbackInterfaceChannelPauseCallback * vtable
+    );
+    void uniffi_uniffi_russh_fn_method_channelpausecallback_on_change(
+        void * ptr, 
+        int8_t paused, 
+        RustCallStatus *uniffi_out_err
+    );
+    void * uniffi_uniffi_russh_fn_clone_connectaborttoken(
+        void * ptr, 
+        RustCallStatus *uniffi_out_err
+    );
+    void uniffi_uniffi_russh_fn_free_connectaborttoken(
+        void * ptr, 
+        RustCallStatus *uniffi_out_err
+    );
+    void * uniffi_uniffi_russh_fn_constructor_connectaborttoken_new(
+        RustCallStatus *uniffi_out_err
+    );
+    void uniffi_uniffi_russh_fn_method_connectaborttoken_abort(
+        void * ptr, 
+        RustCallStatus *uniffi_out_err
+    );
+    int8_t uniffi_uniffi_russh_fn_method_connectaborttoken_is_aborted(
+        void * ptr, 
+        RustCallStatus *uniffi_out_err
+    );
     void * uniffi_uniffi_russh_fn_clone_connectprogresscallback(
         void * ptr, 
         RustCallStatus *uniffi_out_err
@@ -212,6 +407,38 @@ extern "C" {
         RustBuffer connection_id, 
         RustCallStatus *uniffi_out_err
     );
+    void * uniffi_uniffi_russh_fn_clone_connectioneventcallback(
+        void * ptr, 
+        RustCallStatus *uniffi_out_err
+    );
+    void uniffi_uniffi_russh_fn_free_connectioneventcallback(
+        void * ptr, 
+        RustCallStatus *uniffi_out_err
+    );
+    void uniffi_uniffi_russh_fn_init_callback_vtable_connectioneventcallback(
+        UniffiVTableCallbackInterfaceConnectionEventCallback * vtable
+    );
+    void uniffi_uniffi_russh_fn_method_connectioneventcallback_on_change(
+        void * ptr, 
+        RustBuffer event, 
+        RustCallStatus *uniffi_out_err
+    );
+    void * uniffi_uniffi_russh_fn_clone_connectionmetricscallback(
+        void * ptr, 
+        RustCallStatus *uniffi_out_err
+    );
+    void uniffi_uniffi_russh_fn_free_connectionmetricscallback(
+        void * ptr, 
+        RustCallStatus *uniffi_out_err
+    );
+    void uniffi_uniffi_russh_fn_init_callback_vtable_connectionmetricscallback(
+        UniffiVTableCallbackInterfaceConnectionMetricsCallback * vtable
+    );
+    void uniffi_uniffi_russh_fn_method_connectionmetricscallback_on_change(
+        void * ptr, 
+        RustBuffer metrics, 
+        RustCallStatus *uniffi_out_err
+    );
     void * uniffi_uniffi_russh_fn_clone_serverkeycallback(
         void * ptr, 
         RustCallStatus *uniffi_out_err
@@ -273,6 +500,11 @@ extern "C" {
         RustBuffer opts, 
         RustCallStatus *uniffi_out_err
     );
+    void * uniffi_uniffi_russh_fn_method_shellsession_attach_emulator(
+        void * ptr, 
+        RustBuffer scrollback_lines, 
+        RustCallStatus *uniffi_out_err
+    );
     RustBuffer uniffi_uniffi_russh_fn_method_shellsession_buffer_stats(
         void * ptr, 
         RustCallStatus *uniffi_out_err
@@ -280,10 +512,18 @@ extern "C" {
     /*handle*/ uint64_t uniffi_uniffi_russh_fn_method_shellsession_close(
         void * ptr
     );
+    RustBuffer uniffi_uniffi_russh_fn_method_shellsession_commands(
+        void * ptr, 
+        RustCallStatus *uniffi_out_err
+    );
     uint64_t uniffi_uniffi_russh_fn_method_shellsession_current_seq(
         void * ptr, 
         RustCallStatus *uniffi_out_err
     );
+    void uniffi_uniffi_russh_fn_method_shellsession_detach_emulator(
+        void * ptr, 
+        RustCallStatus *uniffi_out_err
+    );
     RustBuffer uniffi_uniffi_russh_fn_method_shellsession_get_info(
         void * ptr, 
         RustCallStatus *uniffi_out_err
@@ -294,15 +534,55 @@ extern "C" {
         RustBuffer max_bytes, 
         RustCallStatus *uniffi_out_err
     );
+    RustBuffer uniffi_uniffi_russh_fn_method_shellsession_read_text(
+        void * ptr, 
+        RustBuffer cursor, 
+        RustBuffer max_bytes, 
+        RustCallStatus *uniffi_out_err
+    );
     void uniffi_uniffi_russh_fn_method_shellsession_remove_listener(
         void * ptr, 
         uint64_t id, 
         RustCallStatus *uniffi_out_err
     );
+    /*handle*/ uint64_t uniffi_uniffi_russh_fn_method_shellsession_resize(
+        void * ptr, 
+        uint32_t cols, 
+        uint32_t rows, 
+        uint32_t pixel_width, 
+        uint32_t pixel_height
+    );
+    /*handle*/ uint64_t uniffi_uniffi_russh_fn_method_shellsession_search(
+        void * ptr, 
+        RustBuffer pattern, 
+        RustBuffer opts
+    );
+    /*handle*/ uint64_t uniffi_uniffi_russh_fn_method_shellsession_send_break(
+        void * ptr, 
+        uint32_t duration_ms
+    );
     /*handle*/ uint64_t uniffi_uniffi_russh_fn_method_shellsession_send_data(
         void * ptr, 
         RustBuffer data
     );
+    /*handle*/ uint64_t uniffi_uniffi_russh_fn_method_shellsession_send_signal(
+        void * ptr, 
+        RustBuffer signal
+    );
+    void uniffi_uniffi_russh_fn_method_shellsession_set_buffer_policy(
+        void * ptr, 
+        RustBuffer policy, 
+        RustCallStatus *uniffi_out_err
+    );
+    void uniffi_uniffi_russh_fn_method_shellsession_start_recording(
+        void * ptr, 
+        RustBuffer path, 
+        RustBuffer opts, 
+        RustCallStatus *uniffi_out_err
+    );
+    /*handle*/ uint64_t uniffi_uniffi_russh_fn_method_shellsession_stop_recording(
+        void * ptr
+    );
     void * uniffi_uniffi_russh_fn_clone_sshconnection(
         void * ptr, 
         RustCallStatus *uniffi_out_err
@@ -318,10 +598,73 @@ extern "C" {
         void * ptr, 
         RustCallStatus *uniffi_out_err
     );
+    RustBuffer uniffi_uniffi_russh_fn_method_sshconnection_get_metrics(
+        void * ptr, 
+        RustCallStatus *uniffi_out_err
+    );
+    /*handle*/ uint64_t uniffi_uniffi_russh_fn_method_sshconnection_install_bundled_terminfo(
+        void * ptr, 
+        RustBuffer term_name
+    );
+    /*handle*/ uint64_t uniffi_uniffi_russh_fn_method_sshconnection_install_terminfo(
+        void * ptr, 
+        RustBuffer term_name, 
+        RustBuffer compiled_entry
+    );
+    /*handle*/ uint64_t uniffi_uniffi_russh_fn_method_sshconnection_rekey(
+        void * ptr
+    );
     /*handle*/ uint64_t uniffi_uniffi_russh_fn_method_sshconnection_start_shell(
         void * ptr, 
         RustBuffer opts
     );
+    void * uniffi_uniffi_russh_fn_clone_terminalemulator(
+        void * ptr, 
+        RustCallStatus *uniffi_out_err
+    );
+    void uniffi_uniffi_russh_fn_free_terminalemulator(
+        void * ptr, 
+        RustCallStatus *uniffi_out_err
+    );
+    void * uniffi_uniffi_russh_fn_constructor_terminalemulator_new(
+        uint32_t cols, 
+        uint32_t rows, 
+        RustBuffer scrollback_lines, 
+        RustCallStatus *uniffi_out_err
+    );
+    void uniffi_uniffi_russh_fn_method_terminalemulator_feed(
+        void * ptr, 
+        RustBuffer bytes, 
+        RustCallStatus *uniffi_out_err
+    );
+    void uniffi_uniffi_russh_fn_method_terminalemulator_resize(
+        void * ptr, 
+        uint32_t cols, 
+        uint32_t rows, 
+        RustCallStatus *uniffi_out_err
+    );
+    uint64_t uniffi_uniffi_russh_fn_method_terminalemulator_scrollback_len(
+        void * ptr, 
+        RustCallStatus *uniffi_out_err
+    );
+    RustBuffer uniffi_uniffi_russh_fn_method_terminalemulator_scrollback_lines(
+        void * ptr, 
+        uint64_t start, 
+        uint32_t count, 
+        RustCallStatus *uniffi_out_err
+    );
+    RustBuffer uniffi_uniffi_russh_fn_method_terminalemulator_snapshot(
+        void * ptr, 
+        RustCallStatus *uniffi_out_err
+    );
+    RustBuffer uniffi_uniffi_russh_fn_method_terminalemulator_take_damage(
+        void * ptr, 
+        RustCallStatus *uniffi_out_err
+    );
+    RustBuffer uniffi_uniffi_russh_fn_method_terminalemulator_take_responses(
+        void * ptr, 
+        RustCallStatus *uniffi_out_err
+    );
     /*handle*/ uint64_t uniffi_uniffi_russh_fn_func_connect(
         RustBuffer options
     );
@@ -333,6 +676,21 @@ extern "C" {
         RustBuffer key_type, 
         RustCallStatus *uniffi_out_err
     );
+    RustBuffer uniffi_uniffi_russh_fn_func_memory_usage(
+        RustCallStatus *uniffi_out_err
+    );
+    /*handle*/ uint64_t uniffi_uniffi_russh_fn_func_open_cast_player(
+        RustBuffer source, 
+        RustBuffer options
+    );
+    RustBuffer uniffi_uniffi_russh_fn_func_public_key_fingerprints(
+        RustBuffer public_key, 
+        RustCallStatus *uniffi_out_err
+    );
+    void uniffi_uniffi_russh_fn_func_set_memory_budget(
+        RustBuffer bytes, 
+        RustCallStatus *uniffi_out_err
+    );
     RustBuffer uniffi_uniffi_russh_fn_func_validate_private_key(
         RustBuffer private_key_content, 
         RustCallStatus *uniffi_out_err
@@ -555,47 +913,149 @@ extern "C" {
     );
     uint16_t uniffi_uniffi_russh_checksum_func_generate_key_pair(
     );
+    uint16_t uniffi_uniffi_russh_checksum_func_memory_usage(
+    );
+    uint16_t uniffi_uniffi_russh_checksum_func_open_cast_player(
+    );
+    uint16_t uniffi_uniffi_russh_checksum_func_public_key_fingerprints(
+    );
+    uint16_t uniffi_uniffi_russh_checksum_func_set_memory_budget(
+    );
     uint16_t uniffi_uniffi_russh_checksum_func_validate_private_key(
     );
-    uint16_t uniffi_uniffi_russh_checksum_method_connectprogresscallback_on_change(
+    uint16_t uniffi_uniffi_russh_checksum_method_authbannercallback_on_change(
     );
-    uint16_t uniffi_uniffi_russh_checksum_method_connectiondisconnectedcallback_on_change(
+    uint16_t uniffi_uniffi_russh_checksum_method_castplayer_add_listener(
     );
-    uint16_t uniffi_uniffi_russh_checksum_method_serverkeycallback_on_change(
+    uint16_t uniffi_uniffi_russh_checksum_method_castplayer_attach_emulator(
     );
-    uint16_t uniffi_uniffi_russh_checksum_method_shellclosedcallback_on_change(
+    uint16_t uniffi_uniffi_russh_checksum_method_castplayer_buffer_stats(
     );
-    uint16_t uniffi_uniffi_russh_checksum_method_shelllistener_on_event(
+    uint16_t uniffi_uniffi_russh_checksum_method_castplayer_commands(
     );
-    uint16_t uniffi_uniffi_russh_checksum_method_shellsession_add_listener(
+    uint16_t uniffi_uniffi_russh_checksum_method_castplayer_current_seq(
     );
-    uint16_t uniffi_uniffi_russh_checksum_method_shellsession_buffer_stats(
+    uint16_t uniffi_uniffi_russh_checksum_method_castplayer_detach_emulator(
     );
-    uint16_t uniffi_uniffi_russh_checksum_method_shellsession_close(
+    uint16_t uniffi_uniffi_russh_checksum_method_castplayer_get_info(
     );
-    uint16_t uniffi_uniffi_russh_checksum_method_shellsession_current_seq(
+    uint16_t uniffi_uniffi_russh_checksum_method_castplayer_get_status(
     );
-    uint16_t uniffi_uniffi_russh_checksum_method_shellsession_get_info(
+    uint16_t uniffi_uniffi_russh_checksum_method_castplayer_pause(
     );
-    uint16_t uniffi_uniffi_russh_checksum_method_shellsession_read_buffer(
+    uint16_t uniffi_uniffi_russh_checksum_method_castplayer_play(
     );
-    uint16_t uniffi_uniffi_russh_checksum_method_shellsession_remove_listener(
+    uint16_t uniffi_uniffi_russh_checksum_method_castplayer_read_buffer(
     );
-    uint16_t uniffi_uniffi_russh_checksum_method_shellsession_send_data(
+    uint16_t uniffi_uniffi_russh_checksum_method_castplayer_read_text(
     );
-    uint16_t uniffi_uniffi_russh_checksum_method_sshconnection_disconnect(
+    uint16_t uniffi_uniffi_russh_checksum_method_castplayer_remove_listener(
     );
-    uint16_t uniffi_uniffi_russh_checksum_method_sshconnection_get_info(
+    uint16_t uniffi_uniffi_russh_checksum_method_castplayer_search(
     );
-    uint16_t uniffi_uniffi_russh_checksum_method_sshconnection_start_shell(
+    uint16_t uniffi_uniffi_russh_checksum_method_castplayer_seek(
     );
-    uint32_t ffi_uniffi_russh_uniffi_contract_version(
+    uint16_t uniffi_uniffi_russh_checksum_method_castplayer_set_speed(
     );
-}
-
-
-namespace uniffi::uniffi_russh {
-template <typename T> struct Bridging;
+    uint16_t uniffi_uniffi_russh_checksum_method_castplayerstatuscallback_on_change(
+    );
+    uint16_t uniffi_uniffi_russh_checksum_method_channelpausecallback_on_change(
+    );
+    uint16_t uniffi_uniffi_russh_checksum_method_connectaborttoken_abort(
+    );
+    uint16_t uniffi_uniffi_russh_checksum_method_connectaborttoken_is_aborted(
+    );
+    uint16_t uniffi_uniffi_russh_checksum_method_connectprogresscallback_on_change(
+    );
+    uint16_t uniffi_uniffi_russh_checksum_method_connectiondisconnectedcallback_on_change(
+    );
+    uint16_t uniffi_uniffi_russh_checksum_method_connectioneventcallback_on_change(
+    );
+    uint16_t uniffi_uniffi_russh_checksum_method_connectionmetricscallback_on_change(
+    );
+    uint16_t uniffi_uniffi_russh_checksum_method_serverkeycallback_on_change(
+    );
+    uint16_t uniffi_uniffi_russh_checksum_method_shellclosedcallback_on_change(
+    );
+    uint16_t uniffi_uniffi_russh_checksum_method_shelllistener_on_event(
+    );
+    uint16_t uniffi_uniffi_russh_checksum_method_shellsession_add_listener(
+    );
+    uint16_t uniffi_uniffi_russh_checksum_method_shellsession_attach_emulator(
+    );
+    uint16_t uniffi_uniffi_russh_checksum_method_shellsession_buffer_stats(
+    );
+    uint16_t uniffi_uniffi_russh_checksum_method_shellsession_close(
+    );
+    uint16_t uniffi_uniffi_russh_checksum_method_shellsession_commands(
+    );
+    uint16_t uniffi_uniffi_russh_checksum_method_shellsession_current_seq(
+    );
+    uint16_t uniffi_uniffi_russh_checksum_method_shellsession_detach_emulator(
+    );
+    uint16_t uniffi_uniffi_russh_checksum_method_shellsession_get_info(
+    );
+    uint16_t uniffi_uniffi_russh_checksum_method_shellsession_read_buffer(
+    );
+    uint16_t uniffi_uniffi_russh_checksum_method_shellsession_read_text(
+    );
+    uint16_t uniffi_uniffi_russh_checksum_method_shellsession_remove_listener(
+    );
+    uint16_t uniffi_uniffi_russh_checksum_method_shellsession_resize(
+    );
+    uint16_t uniffi_uniffi_russh_checksum_method_shellsession_search(
+    );
+    uint16_t uniffi_uniffi_russh_checksum_method_shellsession_send_break(
+    );
+    uint16_t uniffi_uniffi_russh_checksum_method_shellsession_send_data(
+    );
+    uint16_t uniffi_uniffi_russh_checksum_method_shellsession_send_signal(
+    );
+    uint16_t uniffi_uniffi_russh_checksum_method_shellsession_set_buffer_policy(
+    );
+    uint16_t uniffi_uniffi_russh_checksum_method_shellsession_start_recording(
+    );
+    uint16_t uniffi_uniffi_russh_checksum_method_shellsession_stop_recording(
+    );
+    uint16_t uniffi_uniffi_russh_checksum_method_sshconnection_disconnect(
+    );
+    uint16_t uniffi_uniffi_russh_checksum_method_sshconnection_get_info(
+    );
+    uint16_t uniffi_uniffi_russh_checksum_method_sshconnection_get_metrics(
+    );
+    uint16_t uniffi_uniffi_russh_checksum_method_sshconnection_install_bundled_terminfo(
+    );
+    uint16_t uniffi_uniffi_russh_checksum_method_sshconnection_install_terminfo(
+    );
+    uint16_t uniffi_uniffi_russh_checksum_method_sshconnection_rekey(
+    );
+    uint16_t uniffi_uniffi_russh_checksum_method_sshconnection_start_shell(
+    );
+    uint16_t uniffi_uniffi_russh_checksum_method_terminalemulator_feed(
+    );
+    uint16_t uniffi_uniffi_russh_checksum_method_terminalemulator_resize(
+    );
+    uint16_t uniffi_uniffi_russh_checksum_method_terminalemulator_scrollback_len(
+    );
+    uint16_t uniffi_uniffi_russh_checksum_method_terminalemulator_scrollback_lines(
+    );
+    uint16_t uniffi_uniffi_russh_checksum_method_terminalemulator_snapshot(
+    );
+    uint16_t uniffi_uniffi_russh_checksum_method_terminalemulator_take_damage(
+    );
+    uint16_t uniffi_uniffi_russh_checksum_method_terminalemulator_take_responses(
+    );
+    uint16_t uniffi_uniffi_russh_checksum_constructor_connectaborttoken_new(
+    );
+    uint16_t uniffi_uniffi_russh_checksum_constructor_terminalemulator_new(
+    );
+    uint32_t ffi_uniffi_russh_uniffi_contract_version(
+    );
+}
+
+
+namespace uniffi::uniffi_russh {
+template <typename T> struct Bridging;
 
 using namespace facebook;
 using CallInvoker = uniffi_runtime::UniffiCallInvoker;
@@ -1087,7 +1547,7 @@ namespace uniffi::uniffi_russh::st::foreignfuture::foreignfuture::free {
     }
 } // namespace uniffi::uniffi_russh::st::foreignfuture::foreignfuture::free
 
-// Callback function: uniffi::uniffi_russh::st::vtablecallbackinterfaceconnectprogresscallback::vtablecallbackinterfaceconnectprogresscallback::free::UniffiCallbackInterfaceFree
+// Callback function: uniffi::uniffi_russh::st::vtablecallbackinterfaceauthbannercallback::vtablecallbackinterfaceauthbannercallback::free::UniffiCallbackInterfaceFree
 //
 // We have the following constraints:
 // - we need to pass a function pointer to Rust.
@@ -1099,7 +1559,7 @@ namespace uniffi::uniffi_russh::st::foreignfuture::foreignfuture::free {
 //
 // We then give the `callback` function pointer to Rust which will call the lambda sometime in the
 // future.
-namespace uniffi::uniffi_russh::st::vtablecallbackinterfaceconnectprogresscallback::vtablecallbackinterfaceconnectprogresscallback::free {
+namespace uniffi::uniffi_russh::st::vtablecallbackinterfaceauthbannercallback::vtablecallbackinterfaceauthbannercallback::free {
     using namespace facebook;
 
     // We need to store a lambda in a global so we can call it from
@@ -1157,7 +1617,7 @@ namespace uniffi::uniffi_russh::st::vtablecallbackinterfaceconnectprogresscallba
     }
 
     static UniffiCallbackInterfaceFree
-    makeCallbackFunction( // uniffi::uniffi_russh::st::vtablecallbackinterfaceconnectprogresscallback::vtablecallbackinterfaceconnectprogresscallback::free
+    makeCallbackFunction( // uniffi::uniffi_russh::st::vtablecallbackinterfaceauthbannercallback::vtablecallbackinterfaceauthbannercallback::free
                     jsi::Runtime &rt,
                      std::shared_ptr<uniffi_runtime::UniffiCallInvoker> callInvoker,
                      const jsi::Value &value) {
@@ -1200,9 +1660,9 @@ namespace uniffi::uniffi_russh::st::vtablecallbackinterfaceconnectprogresscallba
         // then the pointer will no longer be left dangling.
         rsLambda = nullptr;
     }
-} // namespace uniffi::uniffi_russh::st::vtablecallbackinterfaceconnectprogresscallback::vtablecallbackinterfaceconnectprogresscallback::free
+} // namespace uniffi::uniffi_russh::st::vtablecallbackinterfaceauthbannercallback::vtablecallbackinterfaceauthbannercallback::free
 
-// Callback function: uniffi::uniffi_russh::st::vtablecallbackinterfaceconnectiondisconnectedcallback::vtablecallbackinterfaceconnectiondisconnectedcallback::free::UniffiCallbackInterfaceFree
+// Callback function: uniffi::uniffi_russh::st::vtablecallbackinterfacecastplayerstatuscallback::vtablecallbackinterfacecastplayerstatuscallback::free::UniffiCallbackInterfaceFree
 //
 // We have the following constraints:
 // - we need to pass a function pointer to Rust.
@@ -1214,7 +1674,7 @@ namespace uniffi::uniffi_russh::st::vtablecallbackinterfaceconnectprogresscallba
 //
 // We then give the `callback` function pointer to Rust which will call the lambda sometime in the
 // future.
-namespace uniffi::uniffi_russh::st::vtablecallbackinterfaceconnectiondisconnectedcallback::vtablecallbackinterfaceconnectiondisconnectedcallback::free {
+namespace uniffi::uniffi_russh::st::vtablecallbackinterfacecastplayerstatuscallback::vtablecallbackinterfacecastplayerstatuscallback::free {
     using namespace facebook;
 
     // We need to store a lambda in a global so we can call it from
@@ -1272,7 +1732,7 @@ namespace uniffi::uniffi_russh::st::vtablecallbackinterfaceconnectiondisconnecte
     }
 
     static UniffiCallbackInterfaceFree
-    makeCallbackFunction( // uniffi::uniffi_russh::st::vtablecallbackinterfaceconnectiondisconnectedcallback::vtablecallbackinterfaceconnectiondisconnectedcallback::free
+    makeCallbackFunction( // uniffi::uniffi_russh::st::vtablecallbackinterfacecastplayerstatuscallback::vtablecallbackinterfacecastplayerstatuscallback::free
                     jsi::Runtime &rt,
                      std::shared_ptr<uniffi_runtime::UniffiCallInvoker> callInvoker,
                      const jsi::Value &value) {
@@ -1315,9 +1775,9 @@ namespace uniffi::uniffi_russh::st::vtablecallbackinterfaceconnectiondisconnecte
         // then the pointer will no longer be left dangling.
         rsLambda = nullptr;
     }
-} // namespace uniffi::uniffi_russh::st::vtablecallbackinterfaceconnectiondisconnectedcallback::vtablecallbackinterfaceconnectiondisconnectedcallback::free
+} // namespace uniffi::uniffi_russh::st::vtablecallbackinterfacecastplayerstatuscallback::vtablecallbackinterfacecastplayerstatuscallback::free
 
-// Callback function: uniffi::uniffi_russh::st::vtablecallbackinterfaceserverkeycallback::vtablecallbackinterfaceserverkeycallback::free::UniffiCallbackInterfaceFree
+// Callback function: uniffi::uniffi_russh::st::vtablecallbackinterfacechannelpausecallback::vtablecallbackinterfacechannelpausecallback::free::UniffiCallbackInterfaceFree
 //
 // We have the following constraints:
 // - we need to pass a function pointer to Rust.
@@ -1329,7 +1789,7 @@ namespace uniffi::uniffi_russh::st::vtablecallbackinterfaceconnectiondisconnecte
 //
 // We then give the `callback` function pointer to Rust which will call the lambda sometime in the
 // future.
-namespace uniffi::uniffi_russh::st::vtablecallbackinterfaceserverkeycallback::vtablecallbackinterfaceserverkeycallback::free {
+namespace uniffi::uniffi_russh::st::vtablecallbackinterfacechannelpausecallback::vtablecallbackinterfacechannelpausecallback::free {
     using namespace facebook;
 
     // We need to store a lambda in a global so we can call it from
@@ -1387,7 +1847,7 @@ namespace uniffi::uniffi_russh::st::vtablecallbackinterfaceserverkeycallback::vt
     }
 
     static UniffiCallbackInterfaceFree
-    makeCallbackFunction( // uniffi::uniffi_russh::st::vtablecallbackinterfaceserverkeycallback::vtablecallbackinterfaceserverkeycallback::free
+    makeCallbackFunction( // uniffi::uniffi_russh::st::vtablecallbackinterfacechannelpausecallback::vtablecallbackinterfacechannelpausecallback::free
                     jsi::Runtime &rt,
                      std::shared_ptr<uniffi_runtime::UniffiCallInvoker> callInvoker,
                      const jsi::Value &value) {
@@ -1430,9 +1890,9 @@ namespace uniffi::uniffi_russh::st::vtablecallbackinterfaceserverkeycallback::vt
         // then the pointer will no longer be left dangling.
         rsLambda = nullptr;
     }
-} // namespace uniffi::uniffi_russh::st::vtablecallbackinterfaceserverkeycallback::vtablecallbackinterfaceserverkeycallback::free
+} // namespace uniffi::uniffi_russh::st::vtablecallbackinterfacechannelpausecallback::vtablecallbackinterfacechannelpausecallback::free
 
-// Callback function: uniffi::uniffi_russh::st::vtablecallbackinterfaceshellclosedcallback::vtablecallbackinterfaceshellclosedcallback::free::UniffiCallbackInterfaceFree
+// Callback function: uniffi::uniffi_russh::st::vtablecallbackinterfaceconnectprogresscallback::vtablecallbackinterfaceconnectprogresscallback::free::UniffiCallbackInterfaceFree
 //
 // We have the following constraints:
 // - we need to pass a function pointer to Rust.
@@ -1444,7 +1904,7 @@ namespace uniffi::uniffi_russh::st::vtablecallbackinterfaceserverkeycallback::vt
 //
 // We then give the `callback` function pointer to Rust which will call the lambda sometime in the
 // future.
-namespace uniffi::uniffi_russh::st::vtablecallbackinterfaceshellclosedcallback::vtablecallbackinterfaceshellclosedcallback::free {
+namespace uniffi::uniffi_russh::st::vtablecallbackinterfaceconnectprogresscallback::vtablecallbackinterfaceconnectprogresscallback::free {
     using namespace facebook;
 
     // We need to store a lambda in a global so we can call it from
@@ -1502,7 +1962,7 @@ namespace uniffi::uniffi_russh::st::vtablecallbackinterfaceshellclosedcallback::
     }
 
     static UniffiCallbackInterfaceFree
-    makeCallbackFunction( // uniffi::uniffi_russh::st::vtablecallbackinterfaceshellclosedcallback::vtablecallbackinterfaceshellclosedcallback::free
+    makeCallbackFunction( // uniffi::uniffi_russh::st::vtablecallbackinterfaceconnectprogresscallback::vtablecallbackinterfaceconnectprogresscallback::free
                     jsi::Runtime &rt,
                      std::shared_ptr<uniffi_runtime::UniffiCallInvoker> callInvoker,
                      const jsi::Value &value) {
@@ -1545,9 +2005,9 @@ namespace uniffi::uniffi_russh::st::vtablecallbackinterfaceshellclosedcallback::
         // then the pointer will no longer be left dangling.
         rsLambda = nullptr;
     }
-} // namespace uniffi::uniffi_russh::st::vtablecallbackinterfaceshellclosedcallback::vtablecallbackinterfaceshellclosedcallback::free
+} // namespace uniffi::uniffi_russh::st::vtablecallbackinterfaceconnectprogresscallback::vtablecallbackinterfaceconnectprogresscallback::free
 
-// Callback function: uniffi::uniffi_russh::st::vtablecallbackinterfaceshelllistener::vtablecallbackinterfaceshelllistener::free::UniffiCallbackInterfaceFree
+// Callback function: uniffi::uniffi_russh::st::vtablecallbackinterfaceconnectiondisconnectedcallback::vtablecallbackinterfaceconnectiondisconnectedcallback::free::UniffiCallbackInterfaceFree
 //
 // We have the following constraints:
 // - we need to pass a function pointer to Rust.
@@ -1559,7 +2019,7 @@ namespace uniffi::uniffi_russh::st::vtablecallbackinterfaceshellclosedcallback::
 //
 // We then give the `callback` function pointer to Rust which will call the lambda sometime in the
 // future.
-namespace uniffi::uniffi_russh::st::vtablecallbackinterfaceshelllistener::vtablecallbackinterfaceshelllistener::free {
+namespace uniffi::uniffi_russh::st::vtablecallbackinterfaceconnectiondisconnectedcallback::vtablecallbackinterfaceconnectiondisconnectedcallback::free {
     using namespace facebook;
 
     // We need to store a lambda in a global so we can call it from
@@ -1617,7 +2077,7 @@ namespace uniffi::uniffi_russh::st::vtablecallbackinterfaceshelllistener::vtable
     }
 
     static UniffiCallbackInterfaceFree
-    makeCallbackFunction( // uniffi::uniffi_russh::st::vtablecallbackinterfaceshelllistener::vtablecallbackinterfaceshelllistener::free
+    makeCallbackFunction( // uniffi::uniffi_russh::st::vtablecallbackinterfaceconnectiondisconnectedcallback::vtablecallbackinterfaceconnectiondisconnectedcallback::free
                     jsi::Runtime &rt,
                      std::shared_ptr<uniffi_runtime::UniffiCallInvoker> callInvoker,
                      const jsi::Value &value) {
@@ -1660,433 +2120,638 @@ namespace uniffi::uniffi_russh::st::vtablecallbackinterfaceshelllistener::vtable
         // then the pointer will no longer be left dangling.
         rsLambda = nullptr;
     }
-} // namespace uniffi::uniffi_russh::st::vtablecallbackinterfaceshelllistener::vtablecallbackinterfaceshelllistener::free
-namespace uniffi::uniffi_russh {
-using namespace facebook;
-using CallInvoker = uniffi_runtime::UniffiCallInvoker;
+} // namespace uniffi::uniffi_russh::st::vtablecallbackinterfaceconnectiondisconnectedcallback::vtablecallbackinterfaceconnectiondisconnectedcallback::free
 
-template <> struct Bridging<UniffiForeignFuture> {
-  static UniffiForeignFuture fromJs(jsi::Runtime &rt,
-    std::shared_ptr<CallInvoker> callInvoker,
-    const jsi::Value &jsValue
-  ) {
-    // Check if the input is an object
-    if (!jsValue.isObject()) {
-      throw jsi::JSError(rt, "Expected an object for UniffiForeignFuture");
-    }
+// Callback function: uniffi::uniffi_russh::st::vtablecallbackinterfaceconnectioneventcallback::vtablecallbackinterfaceconnectioneventcallback::free::UniffiCallbackInterfaceFree
+//
+// We have the following constraints:
+// - we need to pass a function pointer to Rust.
+// - we need a jsi::Runtime and jsi::Function to call into JS.
+// - function pointers can't store state, so we can't use a lamda.
+//
+// For this, we store a lambda as a global, as `rsLambda`. The `callback` function calls
+// the lambda, which itself calls the `body` which then calls into JS.
+//
+// We then give the `callback` function pointer to Rust which will call the lambda sometime in the
+// future.
+namespace uniffi::uniffi_russh::st::vtablecallbackinterfaceconnectioneventcallback::vtablecallbackinterfaceconnectioneventcallback::free {
+    using namespace facebook;
 
-    // Get the object from the jsi::Value
-    auto jsObject = jsValue.getObject(rt);
+    // We need to store a lambda in a global so we can call it from
+    // a function pointer. The function pointer is passed to Rust.
+    static std::function<void(uint64_t)> rsLambda = nullptr;
 
-    // Create the vtable struct
-    UniffiForeignFuture rsObject;
+    // This is the main body of the callback. It's called from the lambda,
+    // which itself is called from the callback function which is passed to Rust.
+    static void body(jsi::Runtime &rt,
+                     std::shared_ptr<uniffi_runtime::UniffiCallInvoker> callInvoker,
+                     std::shared_ptr<jsi::Value> callbackValue
+            ,uint64_t rs_handle) {
 
-    // Create the vtable from the js callbacks.
-    rsObject.handle = uniffi_jsi::Bridging<uint64_t>::fromJs(
-        rt, callInvoker,
-        jsObject.getProperty(rt, "handle")
-      );
-    rsObject.free = uniffi::uniffi_russh::st::foreignfuture::foreignfuture::free::makeCallbackFunction(
-          rt, callInvoker, jsObject.getProperty(rt, "free")
-        );
+        // Convert the arguments from Rust, into jsi::Values.
+        // We'll use the Bridging class to do this…
+        auto js_handle = uniffi_jsi::Bridging<uint64_t>::toJs(rt, callInvoker, rs_handle);
 
-    return rsObject;
-  }
-};
+        // Now we are ready to call the callback.
+        // We are already on the JS thread, because this `body` function was
+        // invoked from the CallInvoker.
+        try {
+            // Getting the callback function
+            auto cb = callbackValue->asObject(rt).asFunction(rt);
+            auto uniffiResult = cb.call(rt, js_handle
+            );
 
-} // namespace uniffi::uniffi_russh
-namespace uniffi::uniffi_russh {
-using namespace facebook;
-using CallInvoker = uniffi_runtime::UniffiCallInvoker;
+            
 
-template <> struct Bridging<UniffiForeignFutureStructU8> {
-  static UniffiForeignFutureStructU8 fromJs(jsi::Runtime &rt,
-    std::shared_ptr<CallInvoker> callInvoker,
-    const jsi::Value &jsValue
-  ) {
-    // Check if the input is an object
-    if (!jsValue.isObject()) {
-      throw jsi::JSError(rt, "Expected an object for UniffiForeignFutureStructU8");
+            
+        } catch (const jsi::JSError &error) {
+            std::cout << "Error in callback UniffiCallbackInterfaceFree: "
+                    << error.what() << std::endl;
+            throw error;
+        }
     }
 
-    // Get the object from the jsi::Value
-    auto jsObject = jsValue.getObject(rt);
+    static void callback(uint64_t rs_handle) {
+        // If the runtime has shutdown, then there is no point in trying to
+        // call into Javascript. BUT how do we tell if the runtime has shutdown?
+        //
+        // Answer: the module destructor calls into callback `cleanup` method,
+        // which nulls out the rsLamda.
+        //
+        // If rsLamda is null, then there is no runtime to call into.
+        if (rsLambda == nullptr) {
+            // This only occurs when destructors are calling into Rust free/drop,
+            // which causes the JS callback to be dropped.
+            return;
+        }
 
-    // Create the vtable struct
-    UniffiForeignFutureStructU8 rsObject;
+        // The runtime, the actual callback jsi::funtion, and the callInvoker
+        // are all in the lambda.
+        rsLambda(
+            rs_handle);
+    }
 
-    // Create the vtable from the js callbacks.
-    rsObject.return_value = uniffi_jsi::Bridging<uint8_t>::fromJs(
-        rt, callInvoker,
-        jsObject.getProperty(rt, "returnValue")
-      );
-    rsObject.call_status = uniffi::uniffi_russh::Bridging<RustCallStatus>::fromJs(
-        rt, callInvoker,
-        jsObject.getProperty(rt, "callStatus")
-      );
+    static UniffiCallbackInterfaceFree
+    makeCallbackFunction( // uniffi::uniffi_russh::st::vtablecallbackinterfaceconnectioneventcallback::vtablecallbackinterfaceconnectioneventcallback::free
+                    jsi::Runtime &rt,
+                     std::shared_ptr<uniffi_runtime::UniffiCallInvoker> callInvoker,
+                     const jsi::Value &value) {
+        if (rsLambda != nullptr) {
+            // `makeCallbackFunction` is called in two circumstances:
+            //
+            // 1. at startup, when initializing callback interface vtables.
+            // 2. when polling futures. This happens at least once per future that is
+            //    exposed to Javascript. We know that this is always the same function,
+            //    `uniffiFutureContinuationCallback` in `async-rust-calls.ts`.
+            //
+            // We can therefore return the callback function without making anything
+            // new if we've been initialized already.
+            return callback;
+        }
+        auto callbackFunction = value.asObject(rt).asFunction(rt);
+        auto callbackValue = std::make_shared<jsi::Value>(rt, callbackFunction);
+        rsLambda = [&rt, callInvoker, callbackValue](uint64_t rs_handle) {
+                // We immediately make a lambda which will do the work of transforming the
+                // arguments into JSI values and calling the callback.
+                uniffi_runtime::UniffiCallFunc jsLambda = [
+                    callInvoker,
+                    callbackValue
+                    , rs_handle](jsi::Runtime &rt) mutable {
+                    body(rt, callInvoker, callbackValue
+                        , rs_handle);
+                };
+                // We'll then call that lambda from the callInvoker which will
+                // look after calling it on the correct thread.
+                
+                callInvoker->invokeNonBlocking(rt, jsLambda);
+        };
+        return callback;
+    }
 
-    return rsObject;
-  }
-};
+    // This method is called from the destructor of NativeUniffiRussh, which only happens
+    // when the jsi::Runtime is being destroyed.
+    static void cleanup() {
+        // The lambda holds a reference to the the Runtime, so when this is nulled out,
+        // then the pointer will no longer be left dangling.
+        rsLambda = nullptr;
+    }
+} // namespace uniffi::uniffi_russh::st::vtablecallbackinterfaceconnectioneventcallback::vtablecallbackinterfaceconnectioneventcallback::free
 
-} // namespace uniffi::uniffi_russh
-    // Implementation of callback function calling from JS to Rust ForeignFutureCompleteU8,
-    // passed from Rust to JS as part of async callbacks.
-namespace uniffi::uniffi_russh {
-using CallInvoker = uniffi_runtime::UniffiCallInvoker;
-
-template <> struct Bridging<UniffiForeignFutureCompleteU8> {
-  static jsi::Value toJs(jsi::Runtime &rt, std::shared_ptr<CallInvoker> callInvoker, UniffiForeignFutureCompleteU8 rsCallback) {
-    return jsi::Function::createFromHostFunction(
-        rt,
-        jsi::PropNameID::forAscii(rt, "--ForeignFutureCompleteU8"),
-        2,
-        [rsCallback, callInvoker](
-            jsi::Runtime &rt,
-            const jsi::Value &thisValue,
-            const jsi::Value *arguments,
-            size_t count) -> jsi::Value
-        {
-            return intoRust(rt, callInvoker, thisValue, arguments, count, rsCallback);
-        }
-    );
-  }
-
-  static jsi::Value intoRust(
-      jsi::Runtime &rt,
-      std::shared_ptr<CallInvoker> callInvoker,
-      const jsi::Value &thisValue,
-      const jsi::Value *args,
-      size_t count,
-      UniffiForeignFutureCompleteU8 func) {
-    // Convert the arguments into the Rust, with Bridging<T>::fromJs,
-    // then call the rs_callback with those arguments.
-        func(uniffi_jsi::Bridging<uint64_t>::fromJs(rt, callInvoker, args[0]), uniffi::uniffi_russh::Bridging<UniffiForeignFutureStructU8>::fromJs(rt, callInvoker, args[1])
-        );
-
-        
-        return jsi::Value::undefined();
-  }
-};
-} // namespace uniffi::uniffi_russh
-namespace uniffi::uniffi_russh {
-using namespace facebook;
-using CallInvoker = uniffi_runtime::UniffiCallInvoker;
-
-template <> struct Bridging<UniffiForeignFutureStructI8> {
-  static UniffiForeignFutureStructI8 fromJs(jsi::Runtime &rt,
-    std::shared_ptr<CallInvoker> callInvoker,
-    const jsi::Value &jsValue
-  ) {
-    // Check if the input is an object
-    if (!jsValue.isObject()) {
-      throw jsi::JSError(rt, "Expected an object for UniffiForeignFutureStructI8");
-    }
+// Callback function: uniffi::uniffi_russh::st::vtablecallbackinterfaceconnectionmetricscallback::vtablecallbackinterfaceconnectionmetricscallback::free::UniffiCallbackInterfaceFree
+//
+// We have the following constraints:
+// - we need to pass a function pointer to Rust.
+// - we need a jsi::Runtime and jsi::Function to call into JS.
+// - function pointers can't store state, so we can't use a lamda.
+//
+// For this, we store a lambda as a global, as `rsLambda`. The `callback` function calls
+// the lambda, which itself calls the `body` which then calls into JS.
+//
+// We then give the `callback` function pointer to Rust which will call the lambda sometime in the
+// future.
+namespace uniffi::uniffi_russh::st::vtablecallbackinterfaceconnectionmetricscallback::vtablecallbackinterfaceconnectionmetricscallback::free {
+    using namespace facebook;
 
-    // Get the object from the jsi::Value
-    auto jsObject = jsValue.getObject(rt);
+    // We need to store a lambda in a global so we can call it from
+    // a function pointer. The function pointer is passed to Rust.
+    static std::function<void(uint64_t)> rsLambda = nullptr;
 
-    // Create the vtable struct
-    UniffiForeignFutureStructI8 rsObject;
+    // This is the main body of the callback. It's called from the lambda,
+    // which itself is called from the callback function which is passed to Rust.
+    static void body(jsi::Runtime &rt,
+                     std::shared_ptr<uniffi_runtime::UniffiCallInvoker> callInvoker,
+                     std::shared_ptr<jsi::Value> callbackValue
+            ,uint64_t rs_handle) {
 
-    // Create the vtable from the js callbacks.
-    rsObject.return_value = uniffi_jsi::Bridging<int8_t>::fromJs(
-        rt, callInvoker,
-        jsObject.getProperty(rt, "returnValue")
-      );
-    rsObject.call_status = uniffi::uniffi_russh::Bridging<RustCallStatus>::fromJs(
-        rt, callInvoker,
-        jsObject.getProperty(rt, "callStatus")
-      );
+        // Convert the arguments from Rust, into jsi::Values.
+        // We'll use the Bridging class to do this…
+        auto js_handle = uniffi_jsi::Bridging<uint64_t>::toJs(rt, callInvoker, rs_handle);
 
-    return rsObject;
-  }
-};
+        // Now we are ready to call the callback.
+        // We are already on the JS thread, because this `body` function was
+        // invoked from the CallInvoker.
+        try {
+            // Getting the callback function
+            auto cb = callbackValue->asObject(rt).asFunction(rt);
+            auto uniffiResult = cb.call(rt, js_handle
+            );
 
-} // namespace uniffi::uniffi_russh
-    // Implementation of callback function calling from JS to Rust ForeignFutureCompleteI8,
-    // passed from Rust to JS as part of async callbacks.
-namespace uniffi::uniffi_russh {
-using CallInvoker = uniffi_runtime::UniffiCallInvoker;
+            
 
-template <> struct Bridging<UniffiForeignFutureCompleteI8> {
-  static jsi::Value toJs(jsi::Runtime &rt, std::shared_ptr<CallInvoker> callInvoker, UniffiForeignFutureCompleteI8 rsCallback) {
-    return jsi::Function::createFromHostFunction(
-        rt,
-        jsi::PropNameID::forAscii(rt, "--ForeignFutureCompleteI8"),
-        2,
-        [rsCallback, callInvoker](
-            jsi::Runtime &rt,
-            const jsi::Value &thisValue,
-            const jsi::Value *arguments,
-            size_t count) -> jsi::Value
-        {
-            return intoRust(rt, callInvoker, thisValue, arguments, count, rsCallback);
+            
+        } catch (const jsi::JSError &error) {
+            std::cout << "Error in callback UniffiCallbackInterfaceFree: "
+                    << error.what() << std::endl;
+            throw error;
         }
-    );
-  }
+    }
 
-  static jsi::Value intoRust(
-      jsi::Runtime &rt,
-      std::shared_ptr<CallInvoker> callInvoker,
-      const jsi::Value &thisValue,
-      const jsi::Value *args,
-      size_t count,
-      UniffiForeignFutureCompleteI8 func) {
-    // Convert the arguments into the Rust, with Bridging<T>::fromJs,
-    // then call the rs_callback with those arguments.
-        func(uniffi_jsi::Bridging<uint64_t>::fromJs(rt, callInvoker, args[0]), uniffi::uniffi_russh::Bridging<UniffiForeignFutureStructI8>::fromJs(rt, callInvoker, args[1])
-        );
+    static void callback(uint64_t rs_handle) {
+        // If the runtime has shutdown, then there is no point in trying to
+        // call into Javascript. BUT how do we tell if the runtime has shutdown?
+        //
+        // Answer: the module destructor calls into callback `cleanup` method,
+        // which nulls out the rsLamda.
+        //
+        // If rsLamda is null, then there is no runtime to call into.
+        if (rsLambda == nullptr) {
+            // This only occurs when destructors are calling into Rust free/drop,
+            // which causes the JS callback to be dropped.
+            return;
+        }
 
-        
-        return jsi::Value::undefined();
-  }
-};
-} // namespace uniffi::uniffi_russh
-namespace uniffi::uniffi_russh {
-using namespace facebook;
-using CallInvoker = uniffi_runtime::UniffiCallInvoker;
+        // The runtime, the actual callback jsi::funtion, and the callInvoker
+        // are all in the lambda.
+        rsLambda(
+            rs_handle);
+    }
 
-template <> struct Bridging<UniffiForeignFutureStructU16> {
-  static UniffiForeignFutureStructU16 fromJs(jsi::Runtime &rt,
-    std::shared_ptr<CallInvoker> callInvoker,
-    const jsi::Value &jsValue
-  ) {
-    // Check if the input is an object
-    if (!jsValue.isObject()) {
-      throw jsi::JSError(rt, "Expected an object for UniffiForeignFutureStructU16");
+    static UniffiCallbackInterfaceFree
+    makeCallbackFunction( // uniffi::uniffi_russh::st::vtablecallbackinterfaceconnectionmetricscallback::vtablecallbackinterfaceconnectionmetricscallback::free
+                    jsi::Runtime &rt,
+                     std::shared_ptr<uniffi_runtime::UniffiCallInvoker> callInvoker,
+                     const jsi::Value &value) {
+        if (rsLambda != nullptr) {
+            // `makeCallbackFunction` is called in two circumstances:
+            //
+            // 1. at startup, when initializing callback interface vtables.
+            // 2. when polling futures. This happens at least once per future that is
+            //    exposed to Javascript. We know that this is always the same function,
+            //    `uniffiFutureContinuationCallback` in `async-rust-calls.ts`.
+            //
+            // We can therefore return the callback function without making anything
+            // new if we've been initialized already.
+            return callback;
+        }
+        auto callbackFunction = value.asObject(rt).asFunction(rt);
+        auto callbackValue = std::make_shared<jsi::Value>(rt, callbackFunction);
+        rsLambda = [&rt, callInvoker, callbackValue](uint64_t rs_handle) {
+                // We immediately make a lambda which will do the work of transforming the
+                // arguments into JSI values and calling the callback.
+                uniffi_runtime::UniffiCallFunc jsLambda = [
+                    callInvoker,
+                    callbackValue
+                    , rs_handle](jsi::Runtime &rt) mutable {
+                    body(rt, callInvoker, callbackValue
+                        , rs_handle);
+                };
+                // We'll then call that lambda from the callInvoker which will
+                // look after calling it on the correct thread.
+                
+                callInvoker->invokeNonBlocking(rt, jsLambda);
+        };
+        return callback;
     }
 
-    // Get the object from the jsi::Value
-    auto jsObject = jsValue.getObject(rt);
+    // This method is called from the destructor of NativeUniffiRussh, which only happens
+    // when the jsi::Runtime is being destroyed.
+    static void cleanup() {
+        // The lambda holds a reference to the the Runtime, so when this is nulled out,
+        // then the pointer will no longer be left dangling.
+        rsLambda = nullptr;
+    }
+} // namespace uniffi::uniffi_russh::st::vtablecallbackinterfaceconnectionmetricscallback::vtablecallbackinterfaceconnectionmetricscallback::free
 
-    // Create the vtable struct
-    UniffiForeignFutureStructU16 rsObject;
+// Callback function: uniffi::uniffi_russh::st::vtablecallbackinterfaceserverkeycallback::vtablecallbackinterfaceserverkeycallback::free::UniffiCallbackInterfaceFree
+//
+// We have the following constraints:
+// - we need to pass a function pointer to Rust.
+// - we need a jsi::Runtime and jsi::Function to call into JS.
+// - function pointers can't store state, so we can't use a lamda.
+//
+// For this, we store a lambda as a global, as `rsLambda`. The `callback` function calls
+// the lambda, which itself calls the `body` which then calls into JS.
+//
+// We then give the `callback` function pointer to Rust which will call the lambda sometime in the
+// future.
+namespace uniffi::uniffi_russh::st::vtablecallbackinterfaceserverkeycallback::vtablecallbackinterfaceserverkeycallback::free {
+    using namespace facebook;
 
-    // Create the vtable from the js callbacks.
-    rsObject.return_value = uniffi_jsi::Bridging<uint16_t>::fromJs(
-        rt, callInvoker,
-        jsObject.getProperty(rt, "returnValue")
-      );
-    rsObject.call_status = uniffi::uniffi_russh::Bridging<RustCallStatus>::fromJs(
-        rt, callInvoker,
-        jsObject.getProperty(rt, "callStatus")
-      );
+    // We need to store a lambda in a global so we can call it from
+    // a function pointer. The function pointer is passed to Rust.
+    static std::function<void(uint64_t)> rsLambda = nullptr;
 
-    return rsObject;
-  }
-};
+    // This is the main body of the callback. It's called from the lambda,
+    // which itself is called from the callback function which is passed to Rust.
+    static void body(jsi::Runtime &rt,
+                     std::shared_ptr<uniffi_runtime::UniffiCallInvoker> callInvoker,
+                     std::shared_ptr<jsi::Value> callbackValue
+            ,uint64_t rs_handle) {
 
-} // namespace uniffi::uniffi_russh
-    // Implementation of callback function calling from JS to Rust ForeignFutureCompleteU16,
-    // passed from Rust to JS as part of async callbacks.
-namespace uniffi::uniffi_russh {
-using CallInvoker = uniffi_runtime::UniffiCallInvoker;
+        // Convert the arguments from Rust, into jsi::Values.
+        // We'll use the Bridging class to do this…
+        auto js_handle = uniffi_jsi::Bridging<uint64_t>::toJs(rt, callInvoker, rs_handle);
 
-template <> struct Bridging<UniffiForeignFutureCompleteU16> {
-  static jsi::Value toJs(jsi::Runtime &rt, std::shared_ptr<CallInvoker> callInvoker, UniffiForeignFutureCompleteU16 rsCallback) {
-    return jsi::Function::createFromHostFunction(
-        rt,
-        jsi::PropNameID::forAscii(rt, "--ForeignFutureCompleteU16"),
-        2,
-        [rsCallback, callInvoker](
-            jsi::Runtime &rt,
-            const jsi::Value &thisValue,
-            const jsi::Value *arguments,
-            size_t count) -> jsi::Value
-        {
-            return intoRust(rt, callInvoker, thisValue, arguments, count, rsCallback);
-        }
-    );
-  }
+        // Now we are ready to call the callback.
+        // We are already on the JS thread, because this `body` function was
+        // invoked from the CallInvoker.
+        try {
+            // Getting the callback function
+            auto cb = callbackValue->asObject(rt).asFunction(rt);
+            auto uniffiResult = cb.call(rt, js_handle
+            );
 
-  static jsi::Value intoRust(
-      jsi::Runtime &rt,
-      std::shared_ptr<CallInvoker> callInvoker,
-      const jsi::Value &thisValue,
-      const jsi::Value *args,
-      size_t count,
-      UniffiForeignFutureCompleteU16 func) {
-    // Convert the arguments into the Rust, with Bridging<T>::fromJs,
-    // then call the rs_callback with those arguments.
-        func(uniffi_jsi::Bridging<uint64_t>::fromJs(rt, callInvoker, args[0]), uniffi::uniffi_russh::Bridging<UniffiForeignFutureStructU16>::fromJs(rt, callInvoker, args[1])
-        );
+            
 
-        
-        return jsi::Value::undefined();
-  }
-};
-} // namespace uniffi::uniffi_russh
-namespace uniffi::uniffi_russh {
-using namespace facebook;
-using CallInvoker = uniffi_runtime::UniffiCallInvoker;
+            
+        } catch (const jsi::JSError &error) {
+            std::cout << "Error in callback UniffiCallbackInterfaceFree: "
+                    << error.what() << std::endl;
+            throw error;
+        }
+    }
 
-template <> struct Bridging<UniffiForeignFutureStructI16> {
-  static UniffiForeignFutureStructI16 fromJs(jsi::Runtime &rt,
-    std::shared_ptr<CallInvoker> callInvoker,
-    const jsi::Value &jsValue
-  ) {
-    // Check if the input is an object
-    if (!jsValue.isObject()) {
-      throw jsi::JSError(rt, "Expected an object for UniffiForeignFutureStructI16");
+    static void callback(uint64_t rs_handle) {
+        // If the runtime has shutdown, then there is no point in trying to
+        // call into Javascript. BUT how do we tell if the runtime has shutdown?
+        //
+        // Answer: the module destructor calls into callback `cleanup` method,
+        // which nulls out the rsLamda.
+        //
+        // If rsLamda is null, then there is no runtime to call into.
+        if (rsLambda == nullptr) {
+            // This only occurs when destructors are calling into Rust free/drop,
+            // which causes the JS callback to be dropped.
+            return;
+        }
+
+        // The runtime, the actual callback jsi::funtion, and the callInvoker
+        // are all in the lambda.
+        rsLambda(
+            rs_handle);
     }
 
-    // Get the object from the jsi::Value
-    auto jsObject = jsValue.getObject(rt);
+    static UniffiCallbackInterfaceFree
+    makeCallbackFunction( // uniffi::uniffi_russh::st::vtablecallbackinterfaceserverkeycallback::vtablecallbackinterfaceserverkeycallback::free
+                    jsi::Runtime &rt,
+                     std::shared_ptr<uniffi_runtime::UniffiCallInvoker> callInvoker,
+                     const jsi::Value &value) {
+        if (rsLambda != nullptr) {
+            // `makeCallbackFunction` is called in two circumstances:
+            //
+            // 1. at startup, when initializing callback interface vtables.
+            // 2. when polling futures. This happens at least once per future that is
+            //    exposed to Javascript. We know that this is always the same function,
+            //    `uniffiFutureContinuationCallback` in `async-rust-calls.ts`.
+            //
+            // We can therefore return the callback function without making anything
+            // new if we've been initialized already.
+            return callback;
+        }
+        auto callbackFunction = value.asObject(rt).asFunction(rt);
+        auto callbackValue = std::make_shared<jsi::Value>(rt, callbackFunction);
+        rsLambda = [&rt, callInvoker, callbackValue](uint64_t rs_handle) {
+                // We immediately make a lambda which will do the work of transforming the
+                // arguments into JSI values and calling the callback.
+                uniffi_runtime::UniffiCallFunc jsLambda = [
+                    callInvoker,
+                    callbackValue
+                    , rs_handle](jsi::Runtime &rt) mutable {
+                    body(rt, callInvoker, callbackValue
+                        , rs_handle);
+                };
+                // We'll then call that lambda from the callInvoker which will
+                // look after calling it on the correct thread.
+                
+                callInvoker->invokeNonBlocking(rt, jsLambda);
+        };
+        return callback;
+    }
 
-    // Create the vtable struct
-    UniffiForeignFutureStructI16 rsObject;
+    // This method is called from the destructor of NativeUniffiRussh, which only happens
+    // when the jsi::Runtime is being destroyed.
+    static void cleanup() {
+        // The lambda holds a reference to the the Runtime, so when this is nulled out,
+        // then the pointer will no longer be left dangling.
+        rsLambda = nullptr;
+    }
+} // namespace uniffi::uniffi_russh::st::vtablecallbackinterfaceserverkeycallback::vtablecallbackinterfaceserverkeycallback::free
 
-    // Create the vtable from the js callbacks.
-    rsObject.return_value = uniffi_jsi::Bridging<int16_t>::fromJs(
-        rt, callInvoker,
-        jsObject.getProperty(rt, "returnValue")
-      );
-    rsObject.call_status = uniffi::uniffi_russh::Bridging<RustCallStatus>::fromJs(
-        rt, callInvoker,
-        jsObject.getProperty(rt, "callStatus")
-      );
+// Callback function: uniffi::uniffi_russh::st::vtablecallbackinterfaceshellclosedcallback::vtablecallbackinterfaceshellclosedcallback::free::UniffiCallbackInterfaceFree
+//
+// We have the following constraints:
+// - we need to pass a function pointer to Rust.
+// - we need a jsi::Runtime and jsi::Function to call into JS.
+// - function pointers can't store state, so we can't use a lamda.
+//
+// For this, we store a lambda as a global, as `rsLambda`. The `callback` function calls
+// the lambda, which itself calls the `body` which then calls into JS.
+//
+// We then give the `callback` function pointer to Rust which will call the lambda sometime in the
+// future.
+namespace uniffi::uniffi_russh::st::vtablecallbackinterfaceshellclosedcallback::vtablecallbackinterfaceshellclosedcallback::free {
+    using namespace facebook;
 
-    return rsObject;
-  }
-};
+    // We need to store a lambda in a global so we can call it from
+    // a function pointer. The function pointer is passed to Rust.
+    static std::function<void(uint64_t)> rsLambda = nullptr;
 
-} // namespace uniffi::uniffi_russh
-    // Implementation of callback function calling from JS to Rust ForeignFutureCompleteI16,
-    // passed from Rust to JS as part of async callbacks.
-namespace uniffi::uniffi_russh {
-using CallInvoker = uniffi_runtime::UniffiCallInvoker;
+    // This is the main body of the callback. It's called from the lambda,
+    // which itself is called from the callback function which is passed to Rust.
+    static void body(jsi::Runtime &rt,
+                     std::shared_ptr<uniffi_runtime::UniffiCallInvoker> callInvoker,
+                     std::shared_ptr<jsi::Value> callbackValue
+            ,uint64_t rs_handle) {
 
-template <> struct Bridging<UniffiForeignFutureCompleteI16> {
-  static jsi::Value toJs(jsi::Runtime &rt, std::shared_ptr<CallInvoker> callInvoker, UniffiForeignFutureCompleteI16 rsCallback) {
-    return jsi::Function::createFromHostFunction(
-        rt,
-        jsi::PropNameID::forAscii(rt, "--ForeignFutureCompleteI16"),
-        2,
-        [rsCallback, callInvoker](
-            jsi::Runtime &rt,
-            const jsi::Value &thisValue,
-            const jsi::Value *arguments,
-            size_t count) -> jsi::Value
-        {
-            return intoRust(rt, callInvoker, thisValue, arguments, count, rsCallback);
-        }
-    );
-  }
+        // Convert the arguments from Rust, into jsi::Values.
+        // We'll use the Bridging class to do this…
+        auto js_handle = uniffi_jsi::Bridging<uint64_t>::toJs(rt, callInvoker, rs_handle);
 
-  static jsi::Value intoRust(
-      jsi::Runtime &rt,
-      std::shared_ptr<CallInvoker> callInvoker,
-      const jsi::Value &thisValue,
-      const jsi::Value *args,
-      size_t count,
-      UniffiForeignFutureCompleteI16 func) {
-    // Convert the arguments into the Rust, with Bridging<T>::fromJs,
-    // then call the rs_callback with those arguments.
-        func(uniffi_jsi::Bridging<uint64_t>::fromJs(rt, callInvoker, args[0]), uniffi::uniffi_russh::Bridging<UniffiForeignFutureStructI16>::fromJs(rt, callInvoker, args[1])
-        );
+        // Now we are ready to call the callback.
+        // We are already on the JS thread, because this `body` function was
+        // invoked from the CallInvoker.
+        try {
+            // Getting the callback function
+            auto cb = callbackValue->asObject(rt).asFunction(rt);
+            auto uniffiResult = cb.call(rt, js_handle
+            );
 
-        
-        return jsi::Value::undefined();
-  }
-};
-} // namespace uniffi::uniffi_russh
-namespace uniffi::uniffi_russh {
-using namespace facebook;
-using CallInvoker = uniffi_runtime::UniffiCallInvoker;
+            
 
-template <> struct Bridging<UniffiForeignFutureStructU32> {
-  static UniffiForeignFutureStructU32 fromJs(jsi::Runtime &rt,
-    std::shared_ptr<CallInvoker> callInvoker,
-    const jsi::Value &jsValue
-  ) {
-    // Check if the input is an object
-    if (!jsValue.isObject()) {
-      throw jsi::JSError(rt, "Expected an object for UniffiForeignFutureStructU32");
+            
+        } catch (const jsi::JSError &error) {
+            std::cout << "Error in callback UniffiCallbackInterfaceFree: "
+                    << error.what() << std::endl;
+            throw error;
+        }
     }
 
-    // Get the object from the jsi::Value
-    auto jsObject = jsValue.getObject(rt);
+    static void callback(uint64_t rs_handle) {
+        // If the runtime has shutdown, then there is no point in trying to
+        // call into Javascript. BUT how do we tell if the runtime has shutdown?
+        //
+        // Answer: the module destructor calls into callback `cleanup` method,
+        // which nulls out the rsLamda.
+        //
+        // If rsLamda is null, then there is no runtime to call into.
+        if (rsLambda == nullptr) {
+            // This only occurs when destructors are calling into Rust free/drop,
+            // which causes the JS callback to be dropped.
+            return;
+        }
 
-    // Create the vtable struct
-    UniffiForeignFutureStructU32 rsObject;
+        // The runtime, the actual callback jsi::funtion, and the callInvoker
+        // are all in the lambda.
+        rsLambda(
+            rs_handle);
+    }
 
-    // Create the vtable from the js callbacks.
-    rsObject.return_value = uniffi_jsi::Bridging<uint32_t>::fromJs(
-        rt, callInvoker,
-        jsObject.getProperty(rt, "returnValue")
-      );
-    rsObject.call_status = uniffi::uniffi_russh::Bridging<RustCallStatus>::fromJs(
-        rt, callInvoker,
-        jsObject.getProperty(rt, "callStatus")
-      );
+    static UniffiCallbackInterfaceFree
+    makeCallbackFunction( // uniffi::uniffi_russh::st::vtablecallbackinterfaceshellclosedcallback::vtablecallbackinterfaceshellclosedcallback::free
+                    jsi::Runtime &rt,
+                     std::shared_ptr<uniffi_runtime::UniffiCallInvoker> callInvoker,
+                     const jsi::Value &value) {
+        if (rsLambda != nullptr) {
+            // `makeCallbackFunction` is called in two circumstances:
+            //
+            // 1. at startup, when initializing callback interface vtables.
+            // 2. when polling futures. This happens at least once per future that is
+            //    exposed to Javascript. We know that this is always the same function,
+            //    `uniffiFutureContinuationCallback` in `async-rust-calls.ts`.
+            //
+            // We can therefore return the callback function without making anything
+            // new if we've been initialized already.
+            return callback;
+        }
+        auto callbackFunction = value.asObject(rt).asFunction(rt);
+        auto callbackValue = std::make_shared<jsi::Value>(rt, callbackFunction);
+        rsLambda = [&rt, callInvoker, callbackValue](uint64_t rs_handle) {
+                // We immediately make a lambda which will do the work of transforming the
+                // arguments into JSI values and calling the callback.
+                uniffi_runtime::UniffiCallFunc jsLambda = [
+                    callInvoker,
+                    callbackValue
+                    , rs_handle](jsi::Runtime &rt) mutable {
+                    body(rt, callInvoker, callbackValue
+                        , rs_handle);
+                };
+                // We'll then call that lambda from the callInvoker which will
+                // look after calling it on the correct thread.
+                
+                callInvoker->invokeNonBlocking(rt, jsLambda);
+        };
+        return callback;
+    }
 
-    return rsObject;
-  }
-};
+    // This method is called from the destructor of NativeUniffiRussh, which only happens
+    // when the jsi::Runtime is being destroyed.
+    static void cleanup() {
+        // The lambda holds a reference to the the Runtime, so when this is nulled out,
+        // then the pointer will no longer be left dangling.
+        rsLambda = nullptr;
+    }
+} // namespace uniffi::uniffi_russh::st::vtablecallbackinterfaceshellclosedcallback::vtablecallbackinterfaceshellclosedcallback::free
 
-} // namespace uniffi::uniffi_russh
-    // Implementation of callback function calling from JS to Rust ForeignFutureCompleteU32,
-    // passed from Rust to JS as part of async callbacks.
+// Callback function: uniffi::uniffi_russh::st::vtablecallbackinterfaceshelllistener::vtablecallbackinterfaceshelllistener::free::UniffiCallbackInterfaceFree
+//
+// We have the following constraints:
+// - we need to pass a function pointer to Rust.
+// - we need a jsi::Runtime and jsi::Function to call into JS.
+// - function pointers can't store state, so we can't use a lamda.
+//
+// For this, we store a lambda as a global, as `rsLambda`. The `callback` function calls
+// the lambda, which itself calls the `body` which then calls into JS.
+//
+// We then give the `callback` function pointer to Rust which will call the lambda sometime in the
+// future.
+namespace uniffi::uniffi_russh::st::vtablecallbackinterfaceshelllistener::vtablecallbackinterfaceshelllistener::free {
+    using namespace facebook;
+
+    // We need to store a lambda in a global so we can call it from
+    // a function pointer. The function pointer is passed to Rust.
+    static std::function<void(uint64_t)> rsLambda = nullptr;
+
+    // This is the main body of the callback. It's called from the lambda,
+    // which itself is called from the callback function which is passed to Rust.
+    static void body(jsi::Runtime &rt,
+                     std::shared_ptr<uniffi_runtime::UniffiCallInvoker> callInvoker,
+                     std::shared_ptr<jsi::Value> callbackValue
+            ,uint64_t rs_handle) {
+
+        // Convert the arguments from Rust, into jsi::Values.
+        // We'll use the Bridging class to do this…
+        auto js_handle = uniffi_jsi::Bridging<uint64_t>::toJs(rt, callInvoker, rs_handle);
+
+        // Now we are ready to call the callback.
+        // We are already on the JS thread, because this `body` function was
+        // invoked from the CallInvoker.
+        try {
+            // Getting the callback function
+            auto cb = callbackValue->asObject(rt).asFunction(rt);
+            auto uniffiResult = cb.call(rt, js_handle
+            );
+
+            
+
+            
+        } catch (const jsi::JSError &error) {
+            std::cout << "Error in callback UniffiCallbackInterfaceFree: "
+                    << error.what() << std::endl;
+            throw error;
+        }
+    }
+
+    static void callback(uint64_t rs_handle) {
+        // If the runtime has shutdown, then there is no point in trying to
+        // call into Javascript. BUT how do we tell if the runtime has shutdown?
+        //
+        // Answer: the module destructor calls into callback `cleanup` method,
+        // which nulls out the rsLamda.
+        //
+        // If rsLamda is null, then there is no runtime to call into.
+        if (rsLambda == nullptr) {
+            // This only occurs when destructors are calling into Rust free/drop,
+            // which causes the JS callback to be dropped.
+            return;
+        }
+
+        // The runtime, the actual callback jsi::funtion, and the callInvoker
+        // are all in the lambda.
+        rsLambda(
+            rs_handle);
+    }
+
+    static UniffiCallbackInterfaceFree
+    makeCallbackFunction( // uniffi::uniffi_russh::st::vtablecallbackinterfaceshelllistener::vtablecallbackinterfaceshelllistener::free
+                    jsi::Runtime &rt,
+                     std::shared_ptr<uniffi_runtime::UniffiCallInvoker> callInvoker,
+                     const jsi::Value &value) {
+        if (rsLambda != nullptr) {
+            // `makeCallbackFunction` is called in two circumstances:
+            //
+            // 1. at startup, when initializing callback interface vtables.
+            // 2. when polling futures. This happens at least once per future that is
+            //    exposed to Javascript. We know that this is always the same function,
+            //    `uniffiFutureContinuationCallback` in `async-rust-calls.ts`.
+            //
+            // We can therefore return the callback function without making anything
+            // new if we've been initialized already.
+            return callback;
+        }
+        auto callbackFunction = value.asObject(rt).asFunction(rt);
+        auto callbackValue = std::make_shared<jsi::Value>(rt, callbackFunction);
+        rsLambda = [&rt, callInvoker, callbackValue](uint64_t rs_handle) {
+                // We immediately make a lambda which will do the work of transforming the
+                // arguments into JSI values and calling the callback.
+                uniffi_runtime::UniffiCallFunc jsLambda = [
+                    callInvoker,
+                    callbackValue
+                    , rs_handle](jsi::Runtime &rt) mutable {
+                    body(rt, callInvoker, callbackValue
+                        , rs_handle);
+                };
+                // We'll then call that lambda from the callInvoker which will
+                // look after calling it on the correct thread.
+                
+                callInvoker->invokeNonBlocking(rt, jsLambda);
+        };
+        return callback;
+    }
+
+    // This method is called from the destructor of NativeUniffiRussh, which only happens
+    // when the jsi::Runtime is being destroyed.
+    static void cleanup() {
+        // The lambda holds a reference to the the Runtime, so when this is nulled out,
+        // then the pointer will no longer be left dangling.
+        rsLambda = nullptr;
+    }
+} // namespace uniffi::uniffi_russh::st::vtablecallbackinterfaceshelllistener::vtablecallbackinterfaceshelllistener::free
 namespace uniffi::uniffi_russh {
+using namespace facebook;
 using CallInvoker = uniffi_runtime::UniffiCallInvoker;
 
-template <> struct Bridging<UniffiForeignFutureCompleteU32> {
-  static jsi::Value toJs(jsi::Runtime &rt, std::shared_ptr<CallInvoker> callInvoker, UniffiForeignFutureCompleteU32 rsCallback) {
-    return jsi::Function::createFromHostFunction(
-        rt,
-        jsi::PropNameID::forAscii(rt, "--ForeignFutureCompleteU32"),
-        2,
-        [rsCallback, callInvoker](
-            jsi::Runtime &rt,
-            const jsi::Value &thisValue,
-            const jsi::Value *arguments,
-            size_t count) -> jsi::Value
-        {
-            return intoRust(rt, callInvoker, thisValue, arguments, count, rsCallback);
-        }
-    );
-  }
+template <> struct Bridging<UniffiForeignFuture> {
+  static UniffiForeignFuture fromJs(jsi::Runtime &rt,
+    std::shared_ptr<CallInvoker> callInvoker,
+    const jsi::Value &jsValue
+  ) {
+    // Check if the input is an object
+    if (!jsValue.isObject()) {
+      throw jsi::JSError(rt, "Expected an object for UniffiForeignFuture");
+    }
 
-  static jsi::Value intoRust(
-      jsi::Runtime &rt,
-      std::shared_ptr<CallInvoker> callInvoker,
-      const jsi::Value &thisValue,
-      const jsi::Value *args,
-      size_t count,
-      UniffiForeignFutureCompleteU32 func) {
-    // Convert the arguments into the Rust, with Bridging<T>::fromJs,
-    // then call the rs_callback with those arguments.
-        func(uniffi_jsi::Bridging<uint64_t>::fromJs(rt, callInvoker, args[0]), uniffi::uniffi_russh::Bridging<UniffiForeignFutureStructU32>::fromJs(rt, callInvoker, args[1])
+    // Get the object from the jsi::Value
+    auto jsObject = jsValue.getObject(rt);
+
+    // Create the vtable struct
+    UniffiForeignFuture rsObject;
+
+    // Create the vtable from the js callbacks.
+    rsObject.handle = uniffi_jsi::Bridging<uint64_t>::fromJs(
+        rt, callInvoker,
+        jsObject.getProperty(rt, "handle")
+      );
+    rsObject.free = uniffi::uniffi_russh::st::foreignfuture::foreignfuture::free::makeCallbackFunction(
+          rt, callInvoker, jsObject.getProperty(rt, "free")
         );
 
-        
-        return jsi::Value::undefined();
+    return rsObject;
   }
 };
+
 } // namespace uniffi::uniffi_russh
 namespace uniffi::uniffi_russh {
 using namespace facebook;
 using CallInvoker = uniffi_runtime::UniffiCallInvoker;
 
-template <> struct Bridging<UniffiForeignFutureStructI32> {
-  static UniffiForeignFutureStructI32 fromJs(jsi::Runtime &rt,
+template <> struct Bridging<UniffiForeignFutureStructU8> {
+  static UniffiForeignFutureStructU8 fromJs(jsi::Runtime &rt,
     std::shared_ptr<CallInvoker> callInvoker,
     const jsi::Value &jsValue
   ) {
     // Check if the input is an object
     if (!jsValue.isObject()) {
-      throw jsi::JSError(rt, "Expected an object for UniffiForeignFutureStructI32");
+      throw jsi::JSError(rt, "Expected an object for UniffiForeignFutureStructU8");
     }
 
     // Get the object from the jsi::Value
     auto jsObject = jsValue.getObject(rt);
 
     // Create the vtable struct
-    UniffiForeignFutureStructI32 rsObject;
+    UniffiForeignFutureStructU8 rsObject;
 
     // Create the vtable from the js callbacks.
-    rsObject.return_value = uniffi_jsi::Bridging<int32_t>::fromJs(
+    rsObject.return_value = uniffi_jsi::Bridging<uint8_t>::fromJs(
         rt, callInvoker,
         jsObject.getProperty(rt, "returnValue")
       );
@@ -2100,16 +2765,16 @@ template <> struct Bridging<UniffiForeignFutureStructI32> {
 };
 
 } // namespace uniffi::uniffi_russh
-    // Implementation of callback function calling from JS to Rust ForeignFutureCompleteI32,
+    // Implementation of callback function calling from JS to Rust ForeignFutureCompleteU8,
     // passed from Rust to JS as part of async callbacks.
 namespace uniffi::uniffi_russh {
 using CallInvoker = uniffi_runtime::UniffiCallInvoker;
 
-template <> struct Bridging<UniffiForeignFutureCompleteI32> {
-  static jsi::Value toJs(jsi::Runtime &rt, std::shared_ptr<CallInvoker> callInvoker, UniffiForeignFutureCompleteI32 rsCallback) {
+template <> struct Bridging<UniffiForeignFutureCompleteU8> {
+  static jsi::Value toJs(jsi::Runtime &rt, std::shared_ptr<CallInvoker> callInvoker, UniffiForeignFutureCompleteU8 rsCallback) {
     return jsi::Function::createFromHostFunction(
         rt,
-        jsi::PropNameID::forAscii(rt, "--ForeignFutureCompleteI32"),
+        jsi::PropNameID::forAscii(rt, "--ForeignFutureCompleteU8"),
         2,
         [rsCallback, callInvoker](
             jsi::Runtime &rt,
@@ -2128,10 +2793,10 @@ template <> struct Bridging<UniffiForeignFutureCompleteI32> {
       const jsi::Value &thisValue,
       const jsi::Value *args,
       size_t count,
-      UniffiForeignFutureCompleteI32 func) {
+      UniffiForeignFutureCompleteU8 func) {
     // Convert the arguments into the Rust, with Bridging<T>::fromJs,
     // then call the rs_callback with those arguments.
-        func(uniffi_jsi::Bridging<uint64_t>::fromJs(rt, callInvoker, args[0]), uniffi::uniffi_russh::Bridging<UniffiForeignFutureStructI32>::fromJs(rt, callInvoker, args[1])
+        func(uniffi_jsi::Bridging<uint64_t>::fromJs(rt, callInvoker, args[0]), uniffi::uniffi_russh::Bridging<UniffiForeignFutureStructU8>::fromJs(rt, callInvoker, args[1])
         );
 
         
@@ -2143,24 +2808,24 @@ namespace uniffi::uniffi_russh {
 using namespace facebook;
 using CallInvoker = uniffi_runtime::UniffiCallInvoker;
 
-template <> struct Bridging<UniffiForeignFutureStructU64> {
-  static UniffiForeignFutureStructU64 fromJs(jsi::Runtime &rt,
+template <> struct Bridging<UniffiForeignFutureStructI8> {
+  static UniffiForeignFutureStructI8 fromJs(jsi::Runtime &rt,
     std::shared_ptr<CallInvoker> callInvoker,
     const jsi::Value &jsValue
   ) {
     // Check if the input is an object
     if (!jsValue.isObject()) {
-      throw jsi::JSError(rt, "Expected an object for UniffiForeignFutureStructU64");
+      throw jsi::JSError(rt, "Expected an object for UniffiForeignFutureStructI8");
     }
 
     // Get the object from the jsi::Value
     auto jsObject = jsValue.getObject(rt);
 
     // Create the vtable struct
-    UniffiForeignFutureStructU64 rsObject;
+    UniffiForeignFutureStructI8 rsObject;
 
     // Create the vtable from the js callbacks.
-    rsObject.return_value = uniffi_jsi::Bridging<uint64_t>::fromJs(
+    rsObject.return_value = uniffi_jsi::Bridging<int8_t>::fromJs(
         rt, callInvoker,
         jsObject.getProperty(rt, "returnValue")
       );
@@ -2174,16 +2839,16 @@ template <> struct Bridging<UniffiForeignFutureStructU64> {
 };
 
 } // namespace uniffi::uniffi_russh
-    // Implementation of callback function calling from JS to Rust ForeignFutureCompleteU64,
+    // Implementation of callback function calling from JS to Rust ForeignFutureCompleteI8,
     // passed from Rust to JS as part of async callbacks.
 namespace uniffi::uniffi_russh {
 using CallInvoker = uniffi_runtime::UniffiCallInvoker;
 
-template <> struct Bridging<UniffiForeignFutureCompleteU64> {
-  static jsi::Value toJs(jsi::Runtime &rt, std::shared_ptr<CallInvoker> callInvoker, UniffiForeignFutureCompleteU64 rsCallback) {
+template <> struct Bridging<UniffiForeignFutureCompleteI8> {
+  static jsi::Value toJs(jsi::Runtime &rt, std::shared_ptr<CallInvoker> callInvoker, UniffiForeignFutureCompleteI8 rsCallback) {
     return jsi::Function::createFromHostFunction(
         rt,
-        jsi::PropNameID::forAscii(rt, "--ForeignFutureCompleteU64"),
+        jsi::PropNameID::forAscii(rt, "--ForeignFutureCompleteI8"),
         2,
         [rsCallback, callInvoker](
             jsi::Runtime &rt,
@@ -2202,10 +2867,10 @@ template <> struct Bridging<UniffiForeignFutureCompleteU64> {
       const jsi::Value &thisValue,
       const jsi::Value *args,
       size_t count,
-      UniffiForeignFutureCompleteU64 func) {
+      UniffiForeignFutureCompleteI8 func) {
     // Convert the arguments into the Rust, with Bridging<T>::fromJs,
     // then call the rs_callback with those arguments.
-        func(uniffi_jsi::Bridging<uint64_t>::fromJs(rt, callInvoker, args[0]), uniffi::uniffi_russh::Bridging<UniffiForeignFutureStructU64>::fromJs(rt, callInvoker, args[1])
+        func(uniffi_jsi::Bridging<uint64_t>::fromJs(rt, callInvoker, args[0]), uniffi::uniffi_russh::Bridging<UniffiForeignFutureStructI8>::fromJs(rt, callInvoker, args[1])
         );
 
         
@@ -2217,24 +2882,24 @@ namespace uniffi::uniffi_russh {
 using namespace facebook;
 using CallInvoker = uniffi_runtime::UniffiCallInvoker;
 
-template <> struct Bridging<UniffiForeignFutureStructI64> {
-  static UniffiForeignFutureStructI64 fromJs(jsi::Runtime &rt,
+template <> struct Bridging<UniffiForeignFutureStructU16> {
+  static UniffiForeignFutureStructU16 fromJs(jsi::Runtime &rt,
     std::shared_ptr<CallInvoker> callInvoker,
     const jsi::Value &jsValue
   ) {
     // Check if the input is an object
     if (!jsValue.isObject()) {
-      throw jsi::JSError(rt, "Expected an object for UniffiForeignFutureStructI64");
+      throw jsi::JSError(rt, "Expected an object for UniffiForeignFutureStructU16");
     }
 
     // Get the object from the jsi::Value
     auto jsObject = jsValue.getObject(rt);
 
     // Create the vtable struct
-    UniffiForeignFutureStructI64 rsObject;
+    UniffiForeignFutureStructU16 rsObject;
 
     // Create the vtable from the js callbacks.
-    rsObject.return_value = uniffi_jsi::Bridging<int64_t>::fromJs(
+    rsObject.return_value = uniffi_jsi::Bridging<uint16_t>::fromJs(
         rt, callInvoker,
         jsObject.getProperty(rt, "returnValue")
       );
@@ -2248,16 +2913,16 @@ template <> struct Bridging<UniffiForeignFutureStructI64> {
 };
 
 } // namespace uniffi::uniffi_russh
-    // Implementation of callback function calling from JS to Rust ForeignFutureCompleteI64,
+    // Implementation of callback function calling from JS to Rust ForeignFutureCompleteU16,
     // passed from Rust to JS as part of async callbacks.
 namespace uniffi::uniffi_russh {
 using CallInvoker = uniffi_runtime::UniffiCallInvoker;
 
-template <> struct Bridging<UniffiForeignFutureCompleteI64> {
-  static jsi::Value toJs(jsi::Runtime &rt, std::shared_ptr<CallInvoker> callInvoker, UniffiForeignFutureCompleteI64 rsCallback) {
+template <> struct Bridging<UniffiForeignFutureCompleteU16> {
+  static jsi::Value toJs(jsi::Runtime &rt, std::shared_ptr<CallInvoker> callInvoker, UniffiForeignFutureCompleteU16 rsCallback) {
     return jsi::Function::createFromHostFunction(
         rt,
-        jsi::PropNameID::forAscii(rt, "--ForeignFutureCompleteI64"),
+        jsi::PropNameID::forAscii(rt, "--ForeignFutureCompleteU16"),
         2,
         [rsCallback, callInvoker](
             jsi::Runtime &rt,
@@ -2276,10 +2941,10 @@ template <> struct Bridging<UniffiForeignFutureCompleteI64> {
       const jsi::Value &thisValue,
       const jsi::Value *args,
       size_t count,
-      UniffiForeignFutureCompleteI64 func) {
+      UniffiForeignFutureCompleteU16 func) {
     // Convert the arguments into the Rust, with Bridging<T>::fromJs,
     // then call the rs_callback with those arguments.
-        func(uniffi_jsi::Bridging<uint64_t>::fromJs(rt, callInvoker, args[0]), uniffi::uniffi_russh::Bridging<UniffiForeignFutureStructI64>::fromJs(rt, callInvoker, args[1])
+        func(uniffi_jsi::Bridging<uint64_t>::fromJs(rt, callInvoker, args[0]), uniffi::uniffi_russh::Bridging<UniffiForeignFutureStructU16>::fromJs(rt, callInvoker, args[1])
         );
 
         
@@ -2291,24 +2956,24 @@ namespace uniffi::uniffi_russh {
 using namespace facebook;
 using CallInvoker = uniffi_runtime::UniffiCallInvoker;
 
-template <> struct Bridging<UniffiForeignFutureStructF32> {
-  static UniffiForeignFutureStructF32 fromJs(jsi::Runtime &rt,
+template <> struct Bridging<UniffiForeignFutureStructI16> {
+  static UniffiForeignFutureStructI16 fromJs(jsi::Runtime &rt,
     std::shared_ptr<CallInvoker> callInvoker,
     const jsi::Value &jsValue
   ) {
     // Check if the input is an object
     if (!jsValue.isObject()) {
-      throw jsi::JSError(rt, "Expected an object for UniffiForeignFutureStructF32");
+      throw jsi::JSError(rt, "Expected an object for UniffiForeignFutureStructI16");
     }
 
     // Get the object from the jsi::Value
     auto jsObject = jsValue.getObject(rt);
 
     // Create the vtable struct
-    UniffiForeignFutureStructF32 rsObject;
+    UniffiForeignFutureStructI16 rsObject;
 
     // Create the vtable from the js callbacks.
-    rsObject.return_value = uniffi_jsi::Bridging<float>::fromJs(
+    rsObject.return_value = uniffi_jsi::Bridging<int16_t>::fromJs(
         rt, callInvoker,
         jsObject.getProperty(rt, "returnValue")
       );
@@ -2322,16 +2987,16 @@ template <> struct Bridging<UniffiForeignFutureStructF32> {
 };
 
 } // namespace uniffi::uniffi_russh
-    // Implementation of callback function calling from JS to Rust ForeignFutureCompleteF32,
+    // Implementation of callback function calling from JS to Rust ForeignFutureCompleteI16,
     // passed from Rust to JS as part of async callbacks.
 namespace uniffi::uniffi_russh {
 using CallInvoker = uniffi_runtime::UniffiCallInvoker;
 
-template <> struct Bridging<UniffiForeignFutureCompleteF32> {
-  static jsi::Value toJs(jsi::Runtime &rt, std::shared_ptr<CallInvoker> callInvoker, UniffiForeignFutureCompleteF32 rsCallback) {
+template <> struct Bridging<UniffiForeignFutureCompleteI16> {
+  static jsi::Value toJs(jsi::Runtime &rt, std::shared_ptr<CallInvoker> callInvoker, UniffiForeignFutureCompleteI16 rsCallback) {
     return jsi::Function::createFromHostFunction(
         rt,
-        jsi::PropNameID::forAscii(rt, "--ForeignFutureCompleteF32"),
+        jsi::PropNameID::forAscii(rt, "--ForeignFutureCompleteI16"),
         2,
         [rsCallback, callInvoker](
             jsi::Runtime &rt,
@@ -2350,10 +3015,10 @@ template <> struct Bridging<UniffiForeignFutureCompleteF32> {
       const jsi::Value &thisValue,
       const jsi::Value *args,
       size_t count,
-      UniffiForeignFutureCompleteF32 func) {
+      UniffiForeignFutureCompleteI16 func) {
     // Convert the arguments into the Rust, with Bridging<T>::fromJs,
     // then call the rs_callback with those arguments.
-        func(uniffi_jsi::Bridging<uint64_t>::fromJs(rt, callInvoker, args[0]), uniffi::uniffi_russh::Bridging<UniffiForeignFutureStructF32>::fromJs(rt, callInvoker, args[1])
+        func(uniffi_jsi::Bridging<uint64_t>::fromJs(rt, callInvoker, args[0]), uniffi::uniffi_russh::Bridging<UniffiForeignFutureStructI16>::fromJs(rt, callInvoker, args[1])
         );
 
         
@@ -2365,24 +3030,24 @@ namespace uniffi::uniffi_russh {
 using namespace facebook;
 using CallInvoker = uniffi_runtime::UniffiCallInvoker;
 
-template <> struct Bridging<UniffiForeignFutureStructF64> {
-  static UniffiForeignFutureStructF64 fromJs(jsi::Runtime &rt,
+template <> struct Bridging<UniffiForeignFutureStructU32> {
+  static UniffiForeignFutureStructU32 fromJs(jsi::Runtime &rt,
     std::shared_ptr<CallInvoker> callInvoker,
     const jsi::Value &jsValue
   ) {
     // Check if the input is an object
     if (!jsValue.isObject()) {
-      throw jsi::JSError(rt, "Expected an object for UniffiForeignFutureStructF64");
+      throw jsi::JSError(rt, "Expected an object for UniffiForeignFutureStructU32");
     }
 
     // Get the object from the jsi::Value
     auto jsObject = jsValue.getObject(rt);
 
     // Create the vtable struct
-    UniffiForeignFutureStructF64 rsObject;
+    UniffiForeignFutureStructU32 rsObject;
 
     // Create the vtable from the js callbacks.
-    rsObject.return_value = uniffi_jsi::Bridging<double>::fromJs(
+    rsObject.return_value = uniffi_jsi::Bridging<uint32_t>::fromJs(
         rt, callInvoker,
         jsObject.getProperty(rt, "returnValue")
       );
@@ -2396,16 +3061,16 @@ template <> struct Bridging<UniffiForeignFutureStructF64> {
 };
 
 } // namespace uniffi::uniffi_russh
-    // Implementation of callback function calling from JS to Rust ForeignFutureCompleteF64,
+    // Implementation of callback function calling from JS to Rust ForeignFutureCompleteU32,
     // passed from Rust to JS as part of async callbacks.
 namespace uniffi::uniffi_russh {
 using CallInvoker = uniffi_runtime::UniffiCallInvoker;
 
-template <> struct Bridging<UniffiForeignFutureCompleteF64> {
-  static jsi::Value toJs(jsi::Runtime &rt, std::shared_ptr<CallInvoker> callInvoker, UniffiForeignFutureCompleteF64 rsCallback) {
+template <> struct Bridging<UniffiForeignFutureCompleteU32> {
+  static jsi::Value toJs(jsi::Runtime &rt, std::shared_ptr<CallInvoker> callInvoker, UniffiForeignFutureCompleteU32 rsCallback) {
     return jsi::Function::createFromHostFunction(
         rt,
-        jsi::PropNameID::forAscii(rt, "--ForeignFutureCompleteF64"),
+        jsi::PropNameID::forAscii(rt, "--ForeignFutureCompleteU32"),
         2,
         [rsCallback, callInvoker](
             jsi::Runtime &rt,
@@ -2424,10 +3089,10 @@ template <> struct Bridging<UniffiForeignFutureCompleteF64> {
       const jsi::Value &thisValue,
       const jsi::Value *args,
       size_t count,
-      UniffiForeignFutureCompleteF64 func) {
+      UniffiForeignFutureCompleteU32 func) {
     // Convert the arguments into the Rust, with Bridging<T>::fromJs,
     // then call the rs_callback with those arguments.
-        func(uniffi_jsi::Bridging<uint64_t>::fromJs(rt, callInvoker, args[0]), uniffi::uniffi_russh::Bridging<UniffiForeignFutureStructF64>::fromJs(rt, callInvoker, args[1])
+        func(uniffi_jsi::Bridging<uint64_t>::fromJs(rt, callInvoker, args[0]), uniffi::uniffi_russh::Bridging<UniffiForeignFutureStructU32>::fromJs(rt, callInvoker, args[1])
         );
 
         
@@ -2439,24 +3104,24 @@ namespace uniffi::uniffi_russh {
 using namespace facebook;
 using CallInvoker = uniffi_runtime::UniffiCallInvoker;
 
-template <> struct Bridging<UniffiForeignFutureStructPointer> {
-  static UniffiForeignFutureStructPointer fromJs(jsi::Runtime &rt,
+template <> struct Bridging<UniffiForeignFutureStructI32> {
+  static UniffiForeignFutureStructI32 fromJs(jsi::Runtime &rt,
     std::shared_ptr<CallInvoker> callInvoker,
     const jsi::Value &jsValue
   ) {
     // Check if the input is an object
     if (!jsValue.isObject()) {
-      throw jsi::JSError(rt, "Expected an object for UniffiForeignFutureStructPointer");
+      throw jsi::JSError(rt, "Expected an object for UniffiForeignFutureStructI32");
     }
 
     // Get the object from the jsi::Value
     auto jsObject = jsValue.getObject(rt);
 
     // Create the vtable struct
-    UniffiForeignFutureStructPointer rsObject;
+    UniffiForeignFutureStructI32 rsObject;
 
     // Create the vtable from the js callbacks.
-    rsObject.return_value = uniffi_jsi::Bridging<void *>::fromJs(
+    rsObject.return_value = uniffi_jsi::Bridging<int32_t>::fromJs(
         rt, callInvoker,
         jsObject.getProperty(rt, "returnValue")
       );
@@ -2470,16 +3135,16 @@ template <> struct Bridging<UniffiForeignFutureStructPointer> {
 };
 
 } // namespace uniffi::uniffi_russh
-    // Implementation of callback function calling from JS to Rust ForeignFutureCompletePointer,
+    // Implementation of callback function calling from JS to Rust ForeignFutureCompleteI32,
     // passed from Rust to JS as part of async callbacks.
 namespace uniffi::uniffi_russh {
 using CallInvoker = uniffi_runtime::UniffiCallInvoker;
 
-template <> struct Bridging<UniffiForeignFutureCompletePointer> {
-  static jsi::Value toJs(jsi::Runtime &rt, std::shared_ptr<CallInvoker> callInvoker, UniffiForeignFutureCompletePointer rsCallback) {
+template <> struct Bridging<UniffiForeignFutureCompleteI32> {
+  static jsi::Value toJs(jsi::Runtime &rt, std::shared_ptr<CallInvoker> callInvoker, UniffiForeignFutureCompleteI32 rsCallback) {
     return jsi::Function::createFromHostFunction(
         rt,
-        jsi::PropNameID::forAscii(rt, "--ForeignFutureCompletePointer"),
+        jsi::PropNameID::forAscii(rt, "--ForeignFutureCompleteI32"),
         2,
         [rsCallback, callInvoker](
             jsi::Runtime &rt,
@@ -2498,10 +3163,10 @@ template <> struct Bridging<UniffiForeignFutureCompletePointer> {
       const jsi::Value &thisValue,
       const jsi::Value *args,
       size_t count,
-      UniffiForeignFutureCompletePointer func) {
+      UniffiForeignFutureCompleteI32 func) {
     // Convert the arguments into the Rust, with Bridging<T>::fromJs,
     // then call the rs_callback with those arguments.
-        func(uniffi_jsi::Bridging<uint64_t>::fromJs(rt, callInvoker, args[0]), uniffi::uniffi_russh::Bridging<UniffiForeignFutureStructPointer>::fromJs(rt, callInvoker, args[1])
+        func(uniffi_jsi::Bridging<uint64_t>::fromJs(rt, callInvoker, args[0]), uniffi::uniffi_russh::Bridging<UniffiForeignFutureStructI32>::fromJs(rt, callInvoker, args[1])
         );
 
         
@@ -2513,24 +3178,24 @@ namespace uniffi::uniffi_russh {
 using namespace facebook;
 using CallInvoker = uniffi_runtime::UniffiCallInvoker;
 
-template <> struct Bridging<UniffiForeignFutureStructRustBuffer> {
-  static UniffiForeignFutureStructRustBuffer fromJs(jsi::Runtime &rt,
+template <> struct Bridging<UniffiForeignFutureStructU64> {
+  static UniffiForeignFutureStructU64 fromJs(jsi::Runtime &rt,
     std::shared_ptr<CallInvoker> callInvoker,
     const jsi::Value &jsValue
   ) {
     // Check if the input is an object
     if (!jsValue.isObject()) {
-      throw jsi::JSError(rt, "Expected an object for UniffiForeignFutureStructRustBuffer");
+      throw jsi::JSError(rt, "Expected an object for UniffiForeignFutureStructU64");
     }
 
     // Get the object from the jsi::Value
     auto jsObject = jsValue.getObject(rt);
 
     // Create the vtable struct
-    UniffiForeignFutureStructRustBuffer rsObject;
+    UniffiForeignFutureStructU64 rsObject;
 
     // Create the vtable from the js callbacks.
-    rsObject.return_value = uniffi::uniffi_russh::Bridging<RustBuffer>::fromJs(
+    rsObject.return_value = uniffi_jsi::Bridging<uint64_t>::fromJs(
         rt, callInvoker,
         jsObject.getProperty(rt, "returnValue")
       );
@@ -2544,16 +3209,16 @@ template <> struct Bridging<UniffiForeignFutureStructRustBuffer> {
 };
 
 } // namespace uniffi::uniffi_russh
-    // Implementation of callback function calling from JS to Rust ForeignFutureCompleteRustBuffer,
+    // Implementation of callback function calling from JS to Rust ForeignFutureCompleteU64,
     // passed from Rust to JS as part of async callbacks.
 namespace uniffi::uniffi_russh {
 using CallInvoker = uniffi_runtime::UniffiCallInvoker;
 
-template <> struct Bridging<UniffiForeignFutureCompleteRustBuffer> {
-  static jsi::Value toJs(jsi::Runtime &rt, std::shared_ptr<CallInvoker> callInvoker, UniffiForeignFutureCompleteRustBuffer rsCallback) {
+template <> struct Bridging<UniffiForeignFutureCompleteU64> {
+  static jsi::Value toJs(jsi::Runtime &rt, std::shared_ptr<CallInvoker> callInvoker, UniffiForeignFutureCompleteU64 rsCallback) {
     return jsi::Function::createFromHostFunction(
         rt,
-        jsi::PropNameID::forAscii(rt, "--ForeignFutureCompleteRustBuffer"),
+        jsi::PropNameID::forAscii(rt, "--ForeignFutureCompleteU64"),
         2,
         [rsCallback, callInvoker](
             jsi::Runtime &rt,
@@ -2572,10 +3237,10 @@ template <> struct Bridging<UniffiForeignFutureCompleteRustBuffer> {
       const jsi::Value &thisValue,
       const jsi::Value *args,
       size_t count,
-      UniffiForeignFutureCompleteRustBuffer func) {
+      UniffiForeignFutureCompleteU64 func) {
     // Convert the arguments into the Rust, with Bridging<T>::fromJs,
     // then call the rs_callback with those arguments.
-        func(uniffi_jsi::Bridging<uint64_t>::fromJs(rt, callInvoker, args[0]), uniffi::uniffi_russh::Bridging<UniffiForeignFutureStructRustBuffer>::fromJs(rt, callInvoker, args[1])
+        func(uniffi_jsi::Bridging<uint64_t>::fromJs(rt, callInvoker, args[0]), uniffi::uniffi_russh::Bridging<UniffiForeignFutureStructU64>::fromJs(rt, callInvoker, args[1])
         );
 
         
@@ -2587,23 +3252,27 @@ namespace uniffi::uniffi_russh {
 using namespace facebook;
 using CallInvoker = uniffi_runtime::UniffiCallInvoker;
 
-template <> struct Bridging<UniffiForeignFutureStructVoid> {
-  static UniffiForeignFutureStructVoid fromJs(jsi::Runtime &rt,
+template <> struct Bridging<UniffiForeignFutureStructI64> {
+  static UniffiForeignFutureStructI64 fromJs(jsi::Runtime &rt,
     std::shared_ptr<CallInvoker> callInvoker,
     const jsi::Value &jsValue
   ) {
     // Check if the input is an object
     if (!jsValue.isObject()) {
-      throw jsi::JSError(rt, "Expected an object for UniffiForeignFutureStructVoid");
+      throw jsi::JSError(rt, "Expected an object for UniffiForeignFutureStructI64");
     }
 
     // Get the object from the jsi::Value
     auto jsObject = jsValue.getObject(rt);
 
     // Create the vtable struct
-    UniffiForeignFutureStructVoid rsObject;
+    UniffiForeignFutureStructI64 rsObject;
 
     // Create the vtable from the js callbacks.
+    rsObject.return_value = uniffi_jsi::Bridging<int64_t>::fromJs(
+        rt, callInvoker,
+        jsObject.getProperty(rt, "returnValue")
+      );
     rsObject.call_status = uniffi::uniffi_russh::Bridging<RustCallStatus>::fromJs(
         rt, callInvoker,
         jsObject.getProperty(rt, "callStatus")
@@ -2614,16 +3283,16 @@ template <> struct Bridging<UniffiForeignFutureStructVoid> {
 };
 
 } // namespace uniffi::uniffi_russh
-    // Implementation of callback function calling from JS to Rust ForeignFutureCompleteVoid,
+    // Implementation of callback function calling from JS to Rust ForeignFutureCompleteI64,
     // passed from Rust to JS as part of async callbacks.
 namespace uniffi::uniffi_russh {
 using CallInvoker = uniffi_runtime::UniffiCallInvoker;
 
-template <> struct Bridging<UniffiForeignFutureCompleteVoid> {
-  static jsi::Value toJs(jsi::Runtime &rt, std::shared_ptr<CallInvoker> callInvoker, UniffiForeignFutureCompleteVoid rsCallback) {
+template <> struct Bridging<UniffiForeignFutureCompleteI64> {
+  static jsi::Value toJs(jsi::Runtime &rt, std::shared_ptr<CallInvoker> callInvoker, UniffiForeignFutureCompleteI64 rsCallback) {
     return jsi::Function::createFromHostFunction(
         rt,
-        jsi::PropNameID::forAscii(rt, "--ForeignFutureCompleteVoid"),
+        jsi::PropNameID::forAscii(rt, "--ForeignFutureCompleteI64"),
         2,
         [rsCallback, callInvoker](
             jsi::Runtime &rt,
@@ -2642,10 +3311,10 @@ template <> struct Bridging<UniffiForeignFutureCompleteVoid> {
       const jsi::Value &thisValue,
       const jsi::Value *args,
       size_t count,
-      UniffiForeignFutureCompleteVoid func) {
+      UniffiForeignFutureCompleteI64 func) {
     // Convert the arguments into the Rust, with Bridging<T>::fromJs,
     // then call the rs_callback with those arguments.
-        func(uniffi_jsi::Bridging<uint64_t>::fromJs(rt, callInvoker, args[0]), uniffi::uniffi_russh::Bridging<UniffiForeignFutureStructVoid>::fromJs(rt, callInvoker, args[1])
+        func(uniffi_jsi::Bridging<uint64_t>::fromJs(rt, callInvoker, args[0]), uniffi::uniffi_russh::Bridging<UniffiForeignFutureStructI64>::fromJs(rt, callInvoker, args[1])
         );
 
         
@@ -2653,269 +3322,375 @@ template <> struct Bridging<UniffiForeignFutureCompleteVoid> {
   }
 };
 } // namespace uniffi::uniffi_russh
-    // Implementation of callback function calling from Rust to JS CallbackInterfaceConnectProgressCallbackMethod0
-
-// Callback function: uniffi::uniffi_russh::cb::callbackinterfaceconnectprogresscallbackmethod0::UniffiCallbackInterfaceConnectProgressCallbackMethod0
-//
-// We have the following constraints:
-// - we need to pass a function pointer to Rust.
-// - we need a jsi::Runtime and jsi::Function to call into JS.
-// - function pointers can't store state, so we can't use a lamda.
-//
-// For this, we store a lambda as a global, as `rsLambda`. The `callback` function calls
-// the lambda, which itself calls the `body` which then calls into JS.
-//
-// We then give the `callback` function pointer to Rust which will call the lambda sometime in the
-// future.
-namespace uniffi::uniffi_russh::cb::callbackinterfaceconnectprogresscallbackmethod0 {
-    using namespace facebook;
-
-    // We need to store a lambda in a global so we can call it from
-    // a function pointer. The function pointer is passed to Rust.
-    static std::function<void(uint64_t, RustBuffer, void *, RustCallStatus*)> rsLambda = nullptr;
+namespace uniffi::uniffi_russh {
+using namespace facebook;
+using CallInvoker = uniffi_runtime::UniffiCallInvoker;
 
-    // This is the main body of the callback. It's called from the lambda,
-    // which itself is called from the callback function which is passed to Rust.
-    static void body(jsi::Runtime &rt,
-                     std::shared_ptr<uniffi_runtime::UniffiCallInvoker> callInvoker,
-                     std::shared_ptr<jsi::Value> callbackValue
-            ,uint64_t rs_uniffiHandle
-            ,RustBuffer rs_status
-            ,void * rs_uniffiOutReturn, RustCallStatus* uniffi_call_status) {
+template <> struct Bridging<UniffiForeignFutureStructF32> {
+  static UniffiForeignFutureStructF32 fromJs(jsi::Runtime &rt,
+    std::shared_ptr<CallInvoker> callInvoker,
+    const jsi::Value &jsValue
+  ) {
+    // Check if the input is an object
+    if (!jsValue.isObject()) {
+      throw jsi::JSError(rt, "Expected an object for UniffiForeignFutureStructF32");
+    }
 
-        // Convert the arguments from Rust, into jsi::Values.
-        // We'll use the Bridging class to do this…
-        auto js_uniffiHandle = uniffi_jsi::Bridging<uint64_t>::toJs(rt, callInvoker, rs_uniffiHandle);
-        auto js_status = uniffi::uniffi_russh::Bridging<RustBuffer>::toJs(rt, callInvoker, rs_status);
+    // Get the object from the jsi::Value
+    auto jsObject = jsValue.getObject(rt);
 
-        // Now we are ready to call the callback.
-        // We are already on the JS thread, because this `body` function was
-        // invoked from the CallInvoker.
-        try {
-            // Getting the callback function
-            auto cb = callbackValue->asObject(rt).asFunction(rt);
-            auto uniffiResult = cb.call(rt, js_uniffiHandle, js_status
-            );
+    // Create the vtable struct
+    UniffiForeignFutureStructF32 rsObject;
 
-            // Now copy the result back from JS into the RustCallStatus object.
-            uniffi::uniffi_russh::Bridging<RustCallStatus>::copyFromJs(rt, callInvoker, uniffiResult, uniffi_call_status);
+    // Create the vtable from the js callbacks.
+    rsObject.return_value = uniffi_jsi::Bridging<float>::fromJs(
+        rt, callInvoker,
+        jsObject.getProperty(rt, "returnValue")
+      );
+    rsObject.call_status = uniffi::uniffi_russh::Bridging<RustCallStatus>::fromJs(
+        rt, callInvoker,
+        jsObject.getProperty(rt, "callStatus")
+      );
 
-            if (uniffi_call_status->code != UNIFFI_CALL_STATUS_OK) {
-                // The JS callback finished abnormally, so we cannot retrieve the return value.
-                return;
-            }
+    return rsObject;
+  }
+};
 
-            
-        } catch (const jsi::JSError &error) {
-            std::cout << "Error in callback UniffiCallbackInterfaceConnectProgressCallbackMethod0: "
-                    << error.what() << std::endl;
-            throw error;
-        }
-    }
+} // namespace uniffi::uniffi_russh
+    // Implementation of callback function calling from JS to Rust ForeignFutureCompleteF32,
+    // passed from Rust to JS as part of async callbacks.
+namespace uniffi::uniffi_russh {
+using CallInvoker = uniffi_runtime::UniffiCallInvoker;
 
-    static void callback(uint64_t rs_uniffiHandle, RustBuffer rs_status, void * rs_uniffiOutReturn, RustCallStatus* uniffi_call_status) {
-        // If the runtime has shutdown, then there is no point in trying to
-        // call into Javascript. BUT how do we tell if the runtime has shutdown?
-        //
-        // Answer: the module destructor calls into callback `cleanup` method,
-        // which nulls out the rsLamda.
-        //
-        // If rsLamda is null, then there is no runtime to call into.
-        if (rsLambda == nullptr) {
-            // This only occurs when destructors are calling into Rust free/drop,
-            // which causes the JS callback to be dropped.
-            return;
+template <> struct Bridging<UniffiForeignFutureCompleteF32> {
+  static jsi::Value toJs(jsi::Runtime &rt, std::shared_ptr<CallInvoker> callInvoker, UniffiForeignFutureCompleteF32 rsCallback) {
+    return jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "--ForeignFutureCompleteF32"),
+        2,
+        [rsCallback, callInvoker](
+            jsi::Runtime &rt,
+            const jsi::Value &thisValue,
+            const jsi::Value *arguments,
+            size_t count) -> jsi::Value
+        {
+            return intoRust(rt, callInvoker, thisValue, arguments, count, rsCallback);
         }
+    );
+  }
 
-        // The runtime, the actual callback jsi::funtion, and the callInvoker
-        // are all in the lambda.
-        rsLambda(
-            rs_uniffiHandle, 
-            rs_status, 
-            rs_uniffiOutReturn, uniffi_call_status);
-    }
+  static jsi::Value intoRust(
+      jsi::Runtime &rt,
+      std::shared_ptr<CallInvoker> callInvoker,
+      const jsi::Value &thisValue,
+      const jsi::Value *args,
+      size_t count,
+      UniffiForeignFutureCompleteF32 func) {
+    // Convert the arguments into the Rust, with Bridging<T>::fromJs,
+    // then call the rs_callback with those arguments.
+        func(uniffi_jsi::Bridging<uint64_t>::fromJs(rt, callInvoker, args[0]), uniffi::uniffi_russh::Bridging<UniffiForeignFutureStructF32>::fromJs(rt, callInvoker, args[1])
+        );
 
-    static UniffiCallbackInterfaceConnectProgressCallbackMethod0
-    makeCallbackFunction( // uniffi::uniffi_russh::cb::callbackinterfaceconnectprogresscallbackmethod0
-                    jsi::Runtime &rt,
-                     std::shared_ptr<uniffi_runtime::UniffiCallInvoker> callInvoker,
-                     const jsi::Value &value) {
-        if (rsLambda != nullptr) {
-            // `makeCallbackFunction` is called in two circumstances:
-            //
-            // 1. at startup, when initializing callback interface vtables.
-            // 2. when polling futures. This happens at least once per future that is
-            //    exposed to Javascript. We know that this is always the same function,
-            //    `uniffiFutureContinuationCallback` in `async-rust-calls.ts`.
-            //
-            // We can therefore return the callback function without making anything
-            // new if we've been initialized already.
-            return callback;
-        }
-        auto callbackFunction = value.asObject(rt).asFunction(rt);
-        auto callbackValue = std::make_shared<jsi::Value>(rt, callbackFunction);
-        rsLambda = [&rt, callInvoker, callbackValue](uint64_t rs_uniffiHandle, RustBuffer rs_status, void * rs_uniffiOutReturn, RustCallStatus* uniffi_call_status) {
-                // We immediately make a lambda which will do the work of transforming the
-                // arguments into JSI values and calling the callback.
-                uniffi_runtime::UniffiCallFunc jsLambda = [
-                    callInvoker,
-                    callbackValue
-                    , rs_uniffiHandle
-                    , rs_status
-                    , rs_uniffiOutReturn, uniffi_call_status](jsi::Runtime &rt) mutable {
-                    body(rt, callInvoker, callbackValue
-                        , rs_uniffiHandle
-                        , rs_status
-                        , rs_uniffiOutReturn, uniffi_call_status);
-                };
-                // We'll then call that lambda from the callInvoker which will
-                // look after calling it on the correct thread.
-                callInvoker->invokeBlocking(rt, jsLambda);
-        };
-        return callback;
-    }
+        
+        return jsi::Value::undefined();
+  }
+};
+} // namespace uniffi::uniffi_russh
+namespace uniffi::uniffi_russh {
+using namespace facebook;
+using CallInvoker = uniffi_runtime::UniffiCallInvoker;
 
-    // This method is called from the destructor of NativeUniffiRussh, which only happens
-    // when the jsi::Runtime is being destroyed.
-    static void cleanup() {
-        // The lambda holds a reference to the the Runtime, so when this is nulled out,
-        // then the pointer will no longer be left dangling.
-        rsLambda = nullptr;
+template <> struct Bridging<UniffiForeignFutureStructF64> {
+  static UniffiForeignFutureStructF64 fromJs(jsi::Runtime &rt,
+    std::shared_ptr<CallInvoker> callInvoker,
+    const jsi::Value &jsValue
+  ) {
+    // Check if the input is an object
+    if (!jsValue.isObject()) {
+      throw jsi::JSError(rt, "Expected an object for UniffiForeignFutureStructF64");
     }
-} // namespace uniffi::uniffi_russh::cb::callbackinterfaceconnectprogresscallbackmethod0
-    // Implementation of callback function calling from Rust to JS CallbackInterfaceConnectionDisconnectedCallbackMethod0
-
-// Callback function: uniffi::uniffi_russh::cb::callbackinterfaceconnectiondisconnectedcallbackmethod0::UniffiCallbackInterfaceConnectionDisconnectedCallbackMethod0
-//
-// We have the following constraints:
-// - we need to pass a function pointer to Rust.
-// - we need a jsi::Runtime and jsi::Function to call into JS.
-// - function pointers can't store state, so we can't use a lamda.
-//
-// For this, we store a lambda as a global, as `rsLambda`. The `callback` function calls
-// the lambda, which itself calls the `body` which then calls into JS.
-//
-// We then give the `callback` function pointer to Rust which will call the lambda sometime in the
-// future.
-namespace uniffi::uniffi_russh::cb::callbackinterfaceconnectiondisconnectedcallbackmethod0 {
-    using namespace facebook;
-
-    // We need to store a lambda in a global so we can call it from
-    // a function pointer. The function pointer is passed to Rust.
-    static std::function<void(uint64_t, RustBuffer, void *, RustCallStatus*)> rsLambda = nullptr;
 
-    // This is the main body of the callback. It's called from the lambda,
-    // which itself is called from the callback function which is passed to Rust.
-    static void body(jsi::Runtime &rt,
-                     std::shared_ptr<uniffi_runtime::UniffiCallInvoker> callInvoker,
-                     std::shared_ptr<jsi::Value> callbackValue
-            ,uint64_t rs_uniffiHandle
-            ,RustBuffer rs_connectionId
-            ,void * rs_uniffiOutReturn, RustCallStatus* uniffi_call_status) {
+    // Get the object from the jsi::Value
+    auto jsObject = jsValue.getObject(rt);
 
-        // Convert the arguments from Rust, into jsi::Values.
-        // We'll use the Bridging class to do this…
-        auto js_uniffiHandle = uniffi_jsi::Bridging<uint64_t>::toJs(rt, callInvoker, rs_uniffiHandle);
-        auto js_connectionId = uniffi::uniffi_russh::Bridging<RustBuffer>::toJs(rt, callInvoker, rs_connectionId);
+    // Create the vtable struct
+    UniffiForeignFutureStructF64 rsObject;
 
-        // Now we are ready to call the callback.
-        // We are already on the JS thread, because this `body` function was
-        // invoked from the CallInvoker.
-        try {
-            // Getting the callback function
-            auto cb = callbackValue->asObject(rt).asFunction(rt);
-            auto uniffiResult = cb.call(rt, js_uniffiHandle, js_connectionId
-            );
+    // Create the vtable from the js callbacks.
+    rsObject.return_value = uniffi_jsi::Bridging<double>::fromJs(
+        rt, callInvoker,
+        jsObject.getProperty(rt, "returnValue")
+      );
+    rsObject.call_status = uniffi::uniffi_russh::Bridging<RustCallStatus>::fromJs(
+        rt, callInvoker,
+        jsObject.getProperty(rt, "callStatus")
+      );
 
-            // Now copy the result back from JS into the RustCallStatus object.
-            uniffi::uniffi_russh::Bridging<RustCallStatus>::copyFromJs(rt, callInvoker, uniffiResult, uniffi_call_status);
+    return rsObject;
+  }
+};
 
-            if (uniffi_call_status->code != UNIFFI_CALL_STATUS_OK) {
-                // The JS callback finished abnormally, so we cannot retrieve the return value.
-                return;
-            }
+} // namespace uniffi::uniffi_russh
+    // Implementation of callback function calling from JS to Rust ForeignFutureCompleteF64,
+    // passed from Rust to JS as part of async callbacks.
+namespace uniffi::uniffi_russh {
+using CallInvoker = uniffi_runtime::UniffiCallInvoker;
 
-            
-        } catch (const jsi::JSError &error) {
-            std::cout << "Error in callback UniffiCallbackInterfaceConnectionDisconnectedCallbackMethod0: "
-                    << error.what() << std::endl;
-            throw error;
+template <> struct Bridging<UniffiForeignFutureCompleteF64> {
+  static jsi::Value toJs(jsi::Runtime &rt, std::shared_ptr<CallInvoker> callInvoker, UniffiForeignFutureCompleteF64 rsCallback) {
+    return jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "--ForeignFutureCompleteF64"),
+        2,
+        [rsCallback, callInvoker](
+            jsi::Runtime &rt,
+            const jsi::Value &thisValue,
+            const jsi::Value *arguments,
+            size_t count) -> jsi::Value
+        {
+            return intoRust(rt, callInvoker, thisValue, arguments, count, rsCallback);
         }
-    }
+    );
+  }
 
-    static void callback(uint64_t rs_uniffiHandle, RustBuffer rs_connectionId, void * rs_uniffiOutReturn, RustCallStatus* uniffi_call_status) {
-        // If the runtime has shutdown, then there is no point in trying to
-        // call into Javascript. BUT how do we tell if the runtime has shutdown?
-        //
-        // Answer: the module destructor calls into callback `cleanup` method,
-        // which nulls out the rsLamda.
-        //
-        // If rsLamda is null, then there is no runtime to call into.
-        if (rsLambda == nullptr) {
-            // This only occurs when destructors are calling into Rust free/drop,
-            // which causes the JS callback to be dropped.
-            return;
-        }
+  static jsi::Value intoRust(
+      jsi::Runtime &rt,
+      std::shared_ptr<CallInvoker> callInvoker,
+      const jsi::Value &thisValue,
+      const jsi::Value *args,
+      size_t count,
+      UniffiForeignFutureCompleteF64 func) {
+    // Convert the arguments into the Rust, with Bridging<T>::fromJs,
+    // then call the rs_callback with those arguments.
+        func(uniffi_jsi::Bridging<uint64_t>::fromJs(rt, callInvoker, args[0]), uniffi::uniffi_russh::Bridging<UniffiForeignFutureStructF64>::fromJs(rt, callInvoker, args[1])
+        );
 
-        // The runtime, the actual callback jsi::funtion, and the callInvoker
-        // are all in the lambda.
-        rsLambda(
-            rs_uniffiHandle, 
-            rs_connectionId, 
-            rs_uniffiOutReturn, uniffi_call_status);
-    }
+        
+        return jsi::Value::undefined();
+  }
+};
+} // namespace uniffi::uniffi_russh
+namespace uniffi::uniffi_russh {
+using namespace facebook;
+using CallInvoker = uniffi_runtime::UniffiCallInvoker;
 
-    static UniffiCallbackInterfaceConnectionDisconnectedCallbackMethod0
-    makeCallbackFunction( // uniffi::uniffi_russh::cb::callbackinterfaceconnectiondisconnectedcallbackmethod0
-                    jsi::Runtime &rt,
-                     std::shared_ptr<uniffi_runtime::UniffiCallInvoker> callInvoker,
-                     const jsi::Value &value) {
-        if (rsLambda != nullptr) {
-            // `makeCallbackFunction` is called in two circumstances:
-            //
-            // 1. at startup, when initializing callback interface vtables.
-            // 2. when polling futures. This happens at least once per future that is
-            //    exposed to Javascript. We know that this is always the same function,
-            //    `uniffiFutureContinuationCallback` in `async-rust-calls.ts`.
-            //
-            // We can therefore return the callback function without making anything
-            // new if we've been initialized already.
-            return callback;
-        }
-        auto callbackFunction = value.asObject(rt).asFunction(rt);
-        auto callbackValue = std::make_shared<jsi::Value>(rt, callbackFunction);
-        rsLambda = [&rt, callInvoker, callbackValue](uint64_t rs_uniffiHandle, RustBuffer rs_connectionId, void * rs_uniffiOutReturn, RustCallStatus* uniffi_call_status) {
-                // We immediately make a lambda which will do the work of transforming the
-                // arguments into JSI values and calling the callback.
-                uniffi_runtime::UniffiCallFunc jsLambda = [
-                    callInvoker,
-                    callbackValue
-                    , rs_uniffiHandle
-                    , rs_connectionId
-                    , rs_uniffiOutReturn, uniffi_call_status](jsi::Runtime &rt) mutable {
-                    body(rt, callInvoker, callbackValue
-                        , rs_uniffiHandle
-                        , rs_connectionId
-                        , rs_uniffiOutReturn, uniffi_call_status);
-                };
-                // We'll then call that lambda from the callInvoker which will
-                // look after calling it on the correct thread.
-                callInvoker->invokeBlocking(rt, jsLambda);
-        };
-        return callback;
+template <> struct Bridging<UniffiForeignFutureStructPointer> {
+  static UniffiForeignFutureStructPointer fromJs(jsi::Runtime &rt,
+    std::shared_ptr<CallInvoker> callInvoker,
+    const jsi::Value &jsValue
+  ) {
+    // Check if the input is an object
+    if (!jsValue.isObject()) {
+      throw jsi::JSError(rt, "Expected an object for UniffiForeignFutureStructPointer");
     }
 
-    // This method is called from the destructor of NativeUniffiRussh, which only happens
-    // when the jsi::Runtime is being destroyed.
-    static void cleanup() {
-        // The lambda holds a reference to the the Runtime, so when this is nulled out,
-        // then the pointer will no longer be left dangling.
-        rsLambda = nullptr;
+    // Get the object from the jsi::Value
+    auto jsObject = jsValue.getObject(rt);
+
+    // Create the vtable struct
+    UniffiForeignFutureStructPointer rsObject;
+
+    // Create the vtable from the js callbacks.
+    rsObject.return_value = uniffi_jsi::Bridging<void *>::fromJs(
+        rt, callInvoker,
+        jsObject.getProperty(rt, "returnValue")
+      );
+    rsObject.call_status = uniffi::uniffi_russh::Bridging<RustCallStatus>::fromJs(
+        rt, callInvoker,
+        jsObject.getProperty(rt, "callStatus")
+      );
+
+    return rsObject;
+  }
+};
+
+} // namespace uniffi::uniffi_russh
+    // Implementation of callback function calling from JS to Rust ForeignFutureCompletePointer,
+    // passed from Rust to JS as part of async callbacks.
+namespace uniffi::uniffi_russh {
+using CallInvoker = uniffi_runtime::UniffiCallInvoker;
+
+template <> struct Bridging<UniffiForeignFutureCompletePointer> {
+  static jsi::Value toJs(jsi::Runtime &rt, std::shared_ptr<CallInvoker> callInvoker, UniffiForeignFutureCompletePointer rsCallback) {
+    return jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "--ForeignFutureCompletePointer"),
+        2,
+        [rsCallback, callInvoker](
+            jsi::Runtime &rt,
+            const jsi::Value &thisValue,
+            const jsi::Value *arguments,
+            size_t count) -> jsi::Value
+        {
+            return intoRust(rt, callInvoker, thisValue, arguments, count, rsCallback);
+        }
+    );
+  }
+
+  static jsi::Value intoRust(
+      jsi::Runtime &rt,
+      std::shared_ptr<CallInvoker> callInvoker,
+      const jsi::Value &thisValue,
+      const jsi::Value *args,
+      size_t count,
+      UniffiForeignFutureCompletePointer func) {
+    // Convert the arguments into the Rust, with Bridging<T>::fromJs,
+    // then call the rs_callback with those arguments.
+        func(uniffi_jsi::Bridging<uint64_t>::fromJs(rt, callInvoker, args[0]), uniffi::uniffi_russh::Bridging<UniffiForeignFutureStructPointer>::fromJs(rt, callInvoker, args[1])
+        );
+
+        
+        return jsi::Value::undefined();
+  }
+};
+} // namespace uniffi::uniffi_russh
+namespace uniffi::uniffi_russh {
+using namespace facebook;
+using CallInvoker = uniffi_runtime::UniffiCallInvoker;
+
+template <> struct Bridging<UniffiForeignFutureStructRustBuffer> {
+  static UniffiForeignFutureStructRustBuffer fromJs(jsi::Runtime &rt,
+    std::shared_ptr<CallInvoker> callInvoker,
+    const jsi::Value &jsValue
+  ) {
+    // Check if the input is an object
+    if (!jsValue.isObject()) {
+      throw jsi::JSError(rt, "Expected an object for UniffiForeignFutureStructRustBuffer");
     }
-} // namespace uniffi::uniffi_russh::cb::callbackinterfaceconnectiondisconnectedcallbackmethod0
-    // Implementation of callback function calling from Rust to JS CallbackInterfaceServerKeyCallbackMethod0
 
-// Callback function: uniffi::uniffi_russh::cb::callbackinterfaceserverkeycallbackmethod0::UniffiCallbackInterfaceServerKeyCallbackMethod0
+    // Get the object from the jsi::Value
+    auto jsObject = jsValue.getObject(rt);
+
+    // Create the vtable struct
+    UniffiForeignFutureStructRustBuffer rsObject;
+
+    // Create the vtable from the js callbacks.
+    rsObject.return_value = uniffi::uniffi_russh::Bridging<RustBuffer>::fromJs(
+        rt, callInvoker,
+        jsObject.getProperty(rt, "returnValue")
+      );
+    rsObject.call_status = uniffi::uniffi_russh::Bridging<RustCallStatus>::fromJs(
+        rt, callInvoker,
+        jsObject.getProperty(rt, "callStatus")
+      );
+
+    return rsObject;
+  }
+};
+
+} // namespace uniffi::uniffi_russh
+    // Implementation of callback function calling from JS to Rust ForeignFutureCompleteRustBuffer,
+    // passed from Rust to JS as part of async callbacks.
+namespace uniffi::uniffi_russh {
+using CallInvoker = uniffi_runtime::UniffiCallInvoker;
+
+template <> struct Bridging<UniffiForeignFutureCompleteRustBuffer> {
+  static jsi::Value toJs(jsi::Runtime &rt, std::shared_ptr<CallInvoker> callInvoker, UniffiForeignFutureCompleteRustBuffer rsCallback) {
+    return jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "--ForeignFutureCompleteRustBuffer"),
+        2,
+        [rsCallback, callInvoker](
+            jsi::Runtime &rt,
+            const jsi::Value &thisValue,
+            const jsi::Value *arguments,
+            size_t count) -> jsi::Value
+        {
+            return intoRust(rt, callInvoker, thisValue, arguments, count, rsCallback);
+        }
+    );
+  }
+
+  static jsi::Value intoRust(
+      jsi::Runtime &rt,
+      std::shared_ptr<CallInvoker> callInvoker,
+      const jsi::Value &thisValue,
+      const jsi::Value *args,
+      size_t count,
+      UniffiForeignFutureCompleteRustBuffer func) {
+    // Convert the arguments into the Rust, with Bridging<T>::fromJs,
+    // then call the rs_callback with those arguments.
+        func(uniffi_jsi::Bridging<uint64_t>::fromJs(rt, callInvoker, args[0]), uniffi::uniffi_russh::Bridging<UniffiForeignFutureStructRustBuffer>::fromJs(rt, callInvoker, args[1])
+        );
+
+        
+        return jsi::Value::undefined();
+  }
+};
+} // namespace uniffi::uniffi_russh
+namespace uniffi::uniffi_russh {
+using namespace facebook;
+using CallInvoker = uniffi_runtime::UniffiCallInvoker;
+
+template <> struct Bridging<UniffiForeignFutureStructVoid> {
+  static UniffiForeignFutureStructVoid fromJs(jsi::Runtime &rt,
+    std::shared_ptr<CallInvoker> callInvoker,
+    const jsi::Value &jsValue
+  ) {
+    // Check if the input is an object
+    if (!jsValue.isObject()) {
+      throw jsi::JSError(rt, "Expected an object for UniffiForeignFutureStructVoid");
+    }
+
+    // Get the object from the jsi::Value
+    auto jsObject = jsValue.getObject(rt);
+
+    // Create the vtable struct
+    UniffiForeignFutureStructVoid rsObject;
+
+    // Create the vtable from the js callbacks.
+    rsObject.call_status = uniffi::uniffi_russh::Bridging<RustCallStatus>::fromJs(
+        rt, callInvoker,
+        jsObject.getProperty(rt, "callStatus")
+      );
+
+    return rsObject;
+  }
+};
+
+} // namespace uniffi::uniffi_russh
+    // Implementation of callback function calling from JS to Rust ForeignFutureCompleteVoid,
+    // passed from Rust to JS as part of async callbacks.
+namespace uniffi::uniffi_russh {
+using CallInvoker = uniffi_runtime::UniffiCallInvoker;
+
+template <> struct Bridging<UniffiForeignFutureCompleteVoid> {
+  static jsi::Value toJs(jsi::Runtime &rt, std::shared_ptr<CallInvoker> callInvoker, UniffiForeignFutureCompleteVoid rsCallback) {
+    return jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "--ForeignFutureCompleteVoid"),
+        2,
+        [rsCallback, callInvoker](
+            jsi::Runtime &rt,
+            const jsi::Value &thisValue,
+            const jsi::Value *arguments,
+            size_t count) -> jsi::Value
+        {
+            return intoRust(rt, callInvoker, thisValue, arguments, count, rsCallback);
+        }
+    );
+  }
+
+  static jsi::Value intoRust(
+      jsi::Runtime &rt,
+      std::shared_ptr<CallInvoker> callInvoker,
+      const jsi::Value &thisValue,
+      const jsi::Value *args,
+      size_t count,
+      UniffiForeignFutureCompleteVoid func) {
+    // Convert the arguments into the Rust, with Bridging<T>::fromJs,
+    // then call the rs_callback with those arguments.
+        func(uniffi_jsi::Bridging<uint64_t>::fromJs(rt, callInvoker, args[0]), uniffi::uniffi_russh::Bridging<UniffiForeignFutureStructVoid>::fromJs(rt, callInvoker, args[1])
+        );
+
+        
+        return jsi::Value::undefined();
+  }
+};
+} // namespace uniffi::uniffi_russh
+    // Implementation of callback function calling from Rust to JS CallbackInterfaceAuthBannerCallbackMethod0
+
+// Callback function: uniffi::uniffi_russh::cb::callbackinterfaceauthbannercallbackmethod0::UniffiCallbackInterfaceAuthBannerCallbackMethod0
 //
 // We have the following constraints:
 // - we need to pass a function pointer to Rust.
@@ -2927,12 +3702,12 @@ namespace uniffi::uniffi_russh::cb::callbackinterfaceconnectiondisconnectedcallb
 //
 // We then give the `callback` function pointer to Rust which will call the lambda sometime in the
 // future.
-namespace uniffi::uniffi_russh::cb::callbackinterfaceserverkeycallbackmethod0 {
+namespace uniffi::uniffi_russh::cb::callbackinterfaceauthbannercallbackmethod0 {
     using namespace facebook;
 
     // We need to store a lambda in a global so we can call it from
     // a function pointer. The function pointer is passed to Rust.
-    static std::function<void(uint64_t, RustBuffer, UniffiForeignFutureCompleteI8, uint64_t, UniffiForeignFuture *)> rsLambda = nullptr;
+    static std::function<void(uint64_t, RustBuffer, void *, RustCallStatus*)> rsLambda = nullptr;
 
     // This is the main body of the callback. It's called from the lambda,
     // which itself is called from the callback function which is passed to Rust.
@@ -2940,17 +3715,13 @@ namespace uniffi::uniffi_russh::cb::callbackinterfaceserverkeycallbackmethod0 {
                      std::shared_ptr<uniffi_runtime::UniffiCallInvoker> callInvoker,
                      std::shared_ptr<jsi::Value> callbackValue
             ,uint64_t rs_uniffiHandle
-            ,RustBuffer rs_serverKeyInfo
-            ,UniffiForeignFutureCompleteI8 rs_uniffiFutureCallback
-            ,uint64_t rs_uniffiCallbackData
-            ,UniffiForeignFuture * rs_uniffiOutReturn) {
+            ,RustBuffer rs_banner
+            ,void * rs_uniffiOutReturn, RustCallStatus* uniffi_call_status) {
 
         // Convert the arguments from Rust, into jsi::Values.
         // We'll use the Bridging class to do this…
         auto js_uniffiHandle = uniffi_jsi::Bridging<uint64_t>::toJs(rt, callInvoker, rs_uniffiHandle);
-        auto js_serverKeyInfo = uniffi::uniffi_russh::Bridging<RustBuffer>::toJs(rt, callInvoker, rs_serverKeyInfo);
-        auto js_uniffiFutureCallback = uniffi::uniffi_russh::Bridging<UniffiForeignFutureCompleteI8>::toJs(rt, callInvoker, rs_uniffiFutureCallback);
-        auto js_uniffiCallbackData = uniffi_jsi::Bridging<uint64_t>::toJs(rt, callInvoker, rs_uniffiCallbackData);
+        auto js_banner = uniffi::uniffi_russh::Bridging<RustBuffer>::toJs(rt, callInvoker, rs_banner);
 
         // Now we are ready to call the callback.
         // We are already on the JS thread, because this `body` function was
@@ -2958,28 +3729,26 @@ namespace uniffi::uniffi_russh::cb::callbackinterfaceserverkeycallbackmethod0 {
         try {
             // Getting the callback function
             auto cb = callbackValue->asObject(rt).asFunction(rt);
-            auto uniffiResult = cb.call(rt, js_uniffiHandle, js_serverKeyInfo, js_uniffiFutureCallback, js_uniffiCallbackData
+            auto uniffiResult = cb.call(rt, js_uniffiHandle, js_banner
             );
 
-            
+            // Now copy the result back from JS into the RustCallStatus object.
+            uniffi::uniffi_russh::Bridging<RustCallStatus>::copyFromJs(rt, callInvoker, uniffiResult, uniffi_call_status);
+
+            if (uniffi_call_status->code != UNIFFI_CALL_STATUS_OK) {
+                // The JS callback finished abnormally, so we cannot retrieve the return value.
+                return;
+            }
 
             
-            // return type is MutReference(Struct("ForeignFuture"))
-            // Finally, we need to copy the return value back into the Rust pointer.
-            *rs_uniffiOutReturn =
-                uniffi::uniffi_russh::Bridging<
-                    UniffiForeignFuture
-                >::fromJs(
-                    rt, callInvoker, uniffiResult
-                );
         } catch (const jsi::JSError &error) {
-            std::cout << "Error in callback UniffiCallbackInterfaceServerKeyCallbackMethod0: "
+            std::cout << "Error in callback UniffiCallbackInterfaceAuthBannerCallbackMethod0: "
                     << error.what() << std::endl;
             throw error;
         }
     }
 
-    static void callback(uint64_t rs_uniffiHandle, RustBuffer rs_serverKeyInfo, UniffiForeignFutureCompleteI8 rs_uniffiFutureCallback, uint64_t rs_uniffiCallbackData, UniffiForeignFuture * rs_uniffiOutReturn) {
+    static void callback(uint64_t rs_uniffiHandle, RustBuffer rs_banner, void * rs_uniffiOutReturn, RustCallStatus* uniffi_call_status) {
         // If the runtime has shutdown, then there is no point in trying to
         // call into Javascript. BUT how do we tell if the runtime has shutdown?
         //
@@ -2997,14 +3766,12 @@ namespace uniffi::uniffi_russh::cb::callbackinterfaceserverkeycallbackmethod0 {
         // are all in the lambda.
         rsLambda(
             rs_uniffiHandle, 
-            rs_serverKeyInfo, 
-            rs_uniffiFutureCallback, 
-            rs_uniffiCallbackData, 
-            rs_uniffiOutReturn);
+            rs_banner, 
+            rs_uniffiOutReturn, uniffi_call_status);
     }
 
-    static UniffiCallbackInterfaceServerKeyCallbackMethod0
-    makeCallbackFunction( // uniffi::uniffi_russh::cb::callbackinterfaceserverkeycallbackmethod0
+    static UniffiCallbackInterfaceAuthBannerCallbackMethod0
+    makeCallbackFunction( // uniffi::uniffi_russh::cb::callbackinterfaceauthbannercallbackmethod0
                     jsi::Runtime &rt,
                      std::shared_ptr<uniffi_runtime::UniffiCallInvoker> callInvoker,
                      const jsi::Value &value) {
@@ -3022,23 +3789,19 @@ namespace uniffi::uniffi_russh::cb::callbackinterfaceserverkeycallbackmethod0 {
         }
         auto callbackFunction = value.asObject(rt).asFunction(rt);
         auto callbackValue = std::make_shared<jsi::Value>(rt, callbackFunction);
-        rsLambda = [&rt, callInvoker, callbackValue](uint64_t rs_uniffiHandle, RustBuffer rs_serverKeyInfo, UniffiForeignFutureCompleteI8 rs_uniffiFutureCallback, uint64_t rs_uniffiCallbackData, UniffiForeignFuture * rs_uniffiOutReturn) {
+        rsLambda = [&rt, callInvoker, callbackValue](uint64_t rs_uniffiHandle, RustBuffer rs_banner, void * rs_uniffiOutReturn, RustCallStatus* uniffi_call_status) {
                 // We immediately make a lambda which will do the work of transforming the
                 // arguments into JSI values and calling the callback.
                 uniffi_runtime::UniffiCallFunc jsLambda = [
                     callInvoker,
                     callbackValue
                     , rs_uniffiHandle
-                    , rs_serverKeyInfo
-                    , rs_uniffiFutureCallback
-                    , rs_uniffiCallbackData
-                    , rs_uniffiOutReturn](jsi::Runtime &rt) mutable {
+                    , rs_banner
+                    , rs_uniffiOutReturn, uniffi_call_status](jsi::Runtime &rt) mutable {
                     body(rt, callInvoker, callbackValue
                         , rs_uniffiHandle
-                        , rs_serverKeyInfo
-                        , rs_uniffiFutureCallback
-                        , rs_uniffiCallbackData
-                        , rs_uniffiOutReturn);
+                        , rs_banner
+                        , rs_uniffiOutReturn, uniffi_call_status);
                 };
                 // We'll then call that lambda from the callInvoker which will
                 // look after calling it on the correct thread.
@@ -3054,10 +3817,10 @@ namespace uniffi::uniffi_russh::cb::callbackinterfaceserverkeycallbackmethod0 {
         // then the pointer will no longer be left dangling.
         rsLambda = nullptr;
     }
-} // namespace uniffi::uniffi_russh::cb::callbackinterfaceserverkeycallbackmethod0
-    // Implementation of callback function calling from Rust to JS CallbackInterfaceShellClosedCallbackMethod0
+} // namespace uniffi::uniffi_russh::cb::callbackinterfaceauthbannercallbackmethod0
+    // Implementation of callback function calling from Rust to JS CallbackInterfaceCastPlayerStatusCallbackMethod0
 
-// Callback function: uniffi::uniffi_russh::cb::callbackinterfaceshellclosedcallbackmethod0::UniffiCallbackInterfaceShellClosedCallbackMethod0
+// Callback function: uniffi::uniffi_russh::cb::callbackinterfacecastplayerstatuscallbackmethod0::UniffiCallbackInterfaceCastPlayerStatusCallbackMethod0
 //
 // We have the following constraints:
 // - we need to pass a function pointer to Rust.
@@ -3069,12 +3832,12 @@ namespace uniffi::uniffi_russh::cb::callbackinterfaceserverkeycallbackmethod0 {
 //
 // We then give the `callback` function pointer to Rust which will call the lambda sometime in the
 // future.
-namespace uniffi::uniffi_russh::cb::callbackinterfaceshellclosedcallbackmethod0 {
+namespace uniffi::uniffi_russh::cb::callbackinterfacecastplayerstatuscallbackmethod0 {
     using namespace facebook;
 
     // We need to store a lambda in a global so we can call it from
     // a function pointer. The function pointer is passed to Rust.
-    static std::function<void(uint64_t, uint32_t, void *, RustCallStatus*)> rsLambda = nullptr;
+    static std::function<void(uint64_t, RustBuffer, void *, RustCallStatus*)> rsLambda = nullptr;
 
     // This is the main body of the callback. It's called from the lambda,
     // which itself is called from the callback function which is passed to Rust.
@@ -3082,13 +3845,13 @@ namespace uniffi::uniffi_russh::cb::callbackinterfaceshellclosedcallbackmethod0
                      std::shared_ptr<uniffi_runtime::UniffiCallInvoker> callInvoker,
                      std::shared_ptr<jsi::Value> callbackValue
             ,uint64_t rs_uniffiHandle
-            ,uint32_t rs_channelId
+            ,RustBuffer rs_status
             ,void * rs_uniffiOutReturn, RustCallStatus* uniffi_call_status) {
 
         // Convert the arguments from Rust, into jsi::Values.
         // We'll use the Bridging class to do this…
         auto js_uniffiHandle = uniffi_jsi::Bridging<uint64_t>::toJs(rt, callInvoker, rs_uniffiHandle);
-        auto js_channelId = uniffi_jsi::Bridging<uint32_t>::toJs(rt, callInvoker, rs_channelId);
+        auto js_status = uniffi::uniffi_russh::Bridging<RustBuffer>::toJs(rt, callInvoker, rs_status);
 
         // Now we are ready to call the callback.
         // We are already on the JS thread, because this `body` function was
@@ -3096,7 +3859,7 @@ namespace uniffi::uniffi_russh::cb::callbackinterfaceshellclosedcallbackmethod0
         try {
             // Getting the callback function
             auto cb = callbackValue->asObject(rt).asFunction(rt);
-            auto uniffiResult = cb.call(rt, js_uniffiHandle, js_channelId
+            auto uniffiResult = cb.call(rt, js_uniffiHandle, js_status
             );
 
             // Now copy the result back from JS into the RustCallStatus object.
@@ -3109,13 +3872,13 @@ namespace uniffi::uniffi_russh::cb::callbackinterfaceshellclosedcallbackmethod0
 
             
         } catch (const jsi::JSError &error) {
-            std::cout << "Error in callback UniffiCallbackInterfaceShellClosedCallbackMethod0: "
+            std::cout << "Error in callback UniffiCallbackInterfaceCastPlayerStatusCallbackMethod0: "
                     << error.what() << std::endl;
             throw error;
         }
     }
 
-    static void callback(uint64_t rs_uniffiHandle, uint32_t rs_channelId, void * rs_uniffiOutReturn, RustCallStatus* uniffi_call_status) {
+    static void callback(uint64_t rs_uniffiHandle, RustBuffer rs_status, void * rs_uniffiOutReturn, RustCallStatus* uniffi_call_status) {
         // If the runtime has shutdown, then there is no point in trying to
         // call into Javascript. BUT how do we tell if the runtime has shutdown?
         //
@@ -3133,12 +3896,12 @@ namespace uniffi::uniffi_russh::cb::callbackinterfaceshellclosedcallbackmethod0
         // are all in the lambda.
         rsLambda(
             rs_uniffiHandle, 
-            rs_channelId, 
+            rs_status, 
             rs_uniffiOutReturn, uniffi_call_status);
     }
 
-    static UniffiCallbackInterfaceShellClosedCallbackMethod0
-    makeCallbackFunction( // uniffi::uniffi_russh::cb::callbackinterfaceshellclosedcallbackmethod0
+    static UniffiCallbackInterfaceCastPlayerStatusCallbackMethod0
+    makeCallbackFunction( // uniffi::uniffi_russh::cb::callbackinterfacecastplayerstatuscallbackmethod0
                     jsi::Runtime &rt,
                      std::shared_ptr<uniffi_runtime::UniffiCallInvoker> callInvoker,
                      const jsi::Value &value) {
@@ -3156,18 +3919,18 @@ namespace uniffi::uniffi_russh::cb::callbackinterfaceshellclosedcallbackmethod0
         }
         auto callbackFunction = value.asObject(rt).asFunction(rt);
         auto callbackValue = std::make_shared<jsi::Value>(rt, callbackFunction);
-        rsLambda = [&rt, callInvoker, callbackValue](uint64_t rs_uniffiHandle, uint32_t rs_channelId, void * rs_uniffiOutReturn, RustCallStatus* uniffi_call_status) {
+        rsLambda = [&rt, callInvoker, callbackValue](uint64_t rs_uniffiHandle, RustBuffer rs_status, void * rs_uniffiOutReturn, RustCallStatus* uniffi_call_status) {
                 // We immediately make a lambda which will do the work of transforming the
                 // arguments into JSI values and calling the callback.
                 uniffi_runtime::UniffiCallFunc jsLambda = [
                     callInvoker,
                     callbackValue
                     , rs_uniffiHandle
-                    , rs_channelId
+                    , rs_status
                     , rs_uniffiOutReturn, uniffi_call_status](jsi::Runtime &rt) mutable {
                     body(rt, callInvoker, callbackValue
                         , rs_uniffiHandle
-                        , rs_channelId
+                        , rs_status
                         , rs_uniffiOutReturn, uniffi_call_status);
                 };
                 // We'll then call that lambda from the callInvoker which will
@@ -3184,10 +3947,10 @@ namespace uniffi::uniffi_russh::cb::callbackinterfaceshellclosedcallbackmethod0
         // then the pointer will no longer be left dangling.
         rsLambda = nullptr;
     }
-} // namespace uniffi::uniffi_russh::cb::callbackinterfaceshellclosedcallbackmethod0
-    // Implementation of callback function calling from Rust to JS CallbackInterfaceShellListenerMethod0
+} // namespace uniffi::uniffi_russh::cb::callbackinterfacecastplayerstatuscallbackmethod0
+    // Implementation of callback function calling from Rust to JS CallbackInterfaceChannelPauseCallbackMethod0
 
-// Callback function: uniffi::uniffi_russh::cb::callbackinterfaceshelllistenermethod0::UniffiCallbackInterfaceShellListenerMethod0
+// Callback function: uniffi::uniffi_russh::cb::callbackinterfacechannelpausecallbackmethod0::UniffiCallbackInterfaceChannelPauseCallbackMethod0
 //
 // We have the following constraints:
 // - we need to pass a function pointer to Rust.
@@ -3199,12 +3962,12 @@ namespace uniffi::uniffi_russh::cb::callbackinterfaceshellclosedcallbackmethod0
 //
 // We then give the `callback` function pointer to Rust which will call the lambda sometime in the
 // future.
-namespace uniffi::uniffi_russh::cb::callbackinterfaceshelllistenermethod0 {
+namespace uniffi::uniffi_russh::cb::callbackinterfacechannelpausecallbackmethod0 {
     using namespace facebook;
 
     // We need to store a lambda in a global so we can call it from
     // a function pointer. The function pointer is passed to Rust.
-    static std::function<void(uint64_t, RustBuffer, void *, RustCallStatus*)> rsLambda = nullptr;
+    static std::function<void(uint64_t, int8_t, void *, RustCallStatus*)> rsLambda = nullptr;
 
     // This is the main body of the callback. It's called from the lambda,
     // which itself is called from the callback function which is passed to Rust.
@@ -3212,13 +3975,13 @@ namespace uniffi::uniffi_russh::cb::callbackinterfaceshelllistenermethod0 {
                      std::shared_ptr<uniffi_runtime::UniffiCallInvoker> callInvoker,
                      std::shared_ptr<jsi::Value> callbackValue
             ,uint64_t rs_uniffiHandle
-            ,RustBuffer rs_ev
+            ,int8_t rs_paused
             ,void * rs_uniffiOutReturn, RustCallStatus* uniffi_call_status) {
 
         // Convert the arguments from Rust, into jsi::Values.
         // We'll use the Bridging class to do this…
         auto js_uniffiHandle = uniffi_jsi::Bridging<uint64_t>::toJs(rt, callInvoker, rs_uniffiHandle);
-        auto js_ev = uniffi::uniffi_russh::Bridging<RustBuffer>::toJs(rt, callInvoker, rs_ev);
+        auto js_paused = uniffi_jsi::Bridging<int8_t>::toJs(rt, callInvoker, rs_paused);
 
         // Now we are ready to call the callback.
         // We are already on the JS thread, because this `body` function was
@@ -3226,7 +3989,7 @@ namespace uniffi::uniffi_russh::cb::callbackinterfaceshelllistenermethod0 {
         try {
             // Getting the callback function
             auto cb = callbackValue->asObject(rt).asFunction(rt);
-            auto uniffiResult = cb.call(rt, js_uniffiHandle, js_ev
+            auto uniffiResult = cb.call(rt, js_uniffiHandle, js_paused
             );
 
             // Now copy the result back from JS into the RustCallStatus object.
@@ -3239,13 +4002,13 @@ namespace uniffi::uniffi_russh::cb::callbackinterfaceshelllistenermethod0 {
 
             
         } catch (const jsi::JSError &error) {
-            std::cout << "Error in callback UniffiCallbackInterfaceShellListenerMethod0: "
+            std::cout << "Error in callback UniffiCallbackInterfaceChannelPauseCallbackMethod0: "
                     << error.what() << std::endl;
             throw error;
         }
     }
 
-    static void callback(uint64_t rs_uniffiHandle, RustBuffer rs_ev, void * rs_uniffiOutReturn, RustCallStatus* uniffi_call_status) {
+    static void callback(uint64_t rs_uniffiHandle, int8_t rs_paused, void * rs_uniffiOutReturn, RustCallStatus* uniffi_call_status) {
         // If the runtime has shutdown, then there is no point in trying to
         // call into Javascript. BUT how do we tell if the runtime has shutdown?
         //
@@ -3263,12 +4026,12 @@ namespace uniffi::uniffi_russh::cb::callbackinterfaceshelllistenermethod0 {
         // are all in the lambda.
         rsLambda(
             rs_uniffiHandle, 
-            rs_ev, 
+            rs_paused, 
             rs_uniffiOutReturn, uniffi_call_status);
     }
 
-    static UniffiCallbackInterfaceShellListenerMethod0
-    makeCallbackFunction( // uniffi::uniffi_russh::cb::callbackinterfaceshelllistenermethod0
+    static UniffiCallbackInterfaceChannelPauseCallbackMethod0
+    makeCallbackFunction( // uniffi::uniffi_russh::cb::callbackinterfacechannelpausecallbackmethod0
                     jsi::Runtime &rt,
                      std::shared_ptr<uniffi_runtime::UniffiCallInvoker> callInvoker,
                      const jsi::Value &value) {
@@ -3286,18 +4049,18 @@ namespace uniffi::uniffi_russh::cb::callbackinterfaceshelllistenermethod0 {
         }
         auto callbackFunction = value.asObject(rt).asFunction(rt);
         auto callbackValue = std::make_shared<jsi::Value>(rt, callbackFunction);
-        rsLambda = [&rt, callInvoker, callbackValue](uint64_t rs_uniffiHandle, RustBuffer rs_ev, void * rs_uniffiOutReturn, RustCallStatus* uniffi_call_status) {
+        rsLambda = [&rt, callInvoker, callbackValue](uint64_t rs_uniffiHandle, int8_t rs_paused, void * rs_uniffiOutReturn, RustCallStatus* uniffi_call_status) {
                 // We immediately make a lambda which will do the work of transforming the
                 // arguments into JSI values and calling the callback.
                 uniffi_runtime::UniffiCallFunc jsLambda = [
                     callInvoker,
                     callbackValue
                     , rs_uniffiHandle
-                    , rs_ev
+                    , rs_paused
                     , rs_uniffiOutReturn, uniffi_call_status](jsi::Runtime &rt) mutable {
                     body(rt, callInvoker, callbackValue
                         , rs_uniffiHandle
-                        , rs_ev
+                        , rs_paused
                         , rs_uniffiOutReturn, uniffi_call_status);
                 };
                 // We'll then call that lambda from the callInvoker which will
@@ -3314,1121 +4077,3232 @@ namespace uniffi::uniffi_russh::cb::callbackinterfaceshelllistenermethod0 {
         // then the pointer will no longer be left dangling.
         rsLambda = nullptr;
     }
-} // namespace uniffi::uniffi_russh::cb::callbackinterfaceshelllistenermethod0
-namespace uniffi::uniffi_russh {
-using namespace facebook;
-using CallInvoker = uniffi_runtime::UniffiCallInvoker;
-
-template <> struct Bridging<UniffiVTableCallbackInterfaceConnectProgressCallback> {
-  static UniffiVTableCallbackInterfaceConnectProgressCallback fromJs(jsi::Runtime &rt,
-    std::shared_ptr<CallInvoker> callInvoker,
-    const jsi::Value &jsValue
-  ) {
-    // Check if the input is an object
-    if (!jsValue.isObject()) {
-      throw jsi::JSError(rt, "Expected an object for UniffiVTableCallbackInterfaceConnectProgressCallback");
-    }
-
-    // Get the object from the jsi::Value
-    auto jsObject = jsValue.getObject(rt);
+} // namespace uniffi::uniffi_russh::cb::callbackinterfacechannelpausecallbackmethod0
+    // Implementation of callback function calling from Rust to JS CallbackInterfaceConnectProgressCallbackMethod0
 
-    // Create the vtable struct
-    UniffiVTableCallbackInterfaceConnectProgressCallback rsObject;
+// Callback function: uniffi::uniffi_russh::cb::callbackinterfaceconnectprogresscallbackmethod0::UniffiCallbackInterfaceConnectProgressCallbackMethod0
+//
+// We have the following constraints:
+// - we need to pass a function pointer to Rust.
+// - we need a jsi::Runtime and jsi::Function to call into JS.
+// - function pointers can't store state, so we can't use a lamda.
+//
+// For this, we store a lambda as a global, as `rsLambda`. The `callback` function calls
+// the lambda, which itself calls the `body` which then calls into JS.
+//
+// We then give the `callback` function pointer to Rust which will call the lambda sometime in the
+// future.
+namespace uniffi::uniffi_russh::cb::callbackinterfaceconnectprogresscallbackmethod0 {
+    using namespace facebook;
 
-    // Create the vtable from the js callbacks.
-    rsObject.on_change = uniffi::uniffi_russh::cb::callbackinterfaceconnectprogresscallbackmethod0::makeCallbackFunction(
-          rt, callInvoker, jsObject.getProperty(rt, "onChange")
-        );
-    rsObject.uniffi_free = uniffi::uniffi_russh::st::vtablecallbackinterfaceconnectprogresscallback::vtablecallbackinterfaceconnectprogresscallback::free::makeCallbackFunction(
-          rt, callInvoker, jsObject.getProperty(rt, "uniffiFree")
-        );
+    // We need to store a lambda in a global so we can call it from
+    // a function pointer. The function pointer is passed to Rust.
+    static std::function<void(uint64_t, RustBuffer, void *, RustCallStatus*)> rsLambda = nullptr;
 
-    return rsObject;
-  }
-};
+    // This is the main body of the callback. It's called from the lambda,
+    // which itself is called from the callback function which is passed to Rust.
+    static void body(jsi::Runtime &rt,
+                     std::shared_ptr<uniffi_runtime::UniffiCallInvoker> callInvoker,
+                     std::shared_ptr<jsi::Value> callbackValue
+            ,uint64_t rs_uniffiHandle
+            ,RustBuffer rs_status
+            ,void * rs_uniffiOutReturn, RustCallStatus* uniffi_call_status) {
 
-} // namespace uniffi::uniffi_russh
-namespace uniffi::uniffi_russh {
-using namespace facebook;
-using CallInvoker = uniffi_runtime::UniffiCallInvoker;
+        // Convert the arguments from Rust, into jsi::Values.
+        // We'll use the Bridging class to do this…
+        auto js_uniffiHandle = uniffi_jsi::Bridging<uint64_t>::toJs(rt, callInvoker, rs_uniffiHandle);
+        auto js_status = uniffi::uniffi_russh::Bridging<RustBuffer>::toJs(rt, callInvoker, rs_status);
 
-template <> struct Bridging<UniffiVTableCallbackInterfaceConnectionDisconnectedCallback> {
-  static UniffiVTableCallbackInterfaceConnectionDisconnectedCallback fromJs(jsi::Runtime &rt,
-    std::shared_ptr<CallInvoker> callInvoker,
-    const jsi::Value &jsValue
-  ) {
-    // Check if the input is an object
-    if (!jsValue.isObject()) {
-      throw jsi::JSError(rt, "Expected an object for UniffiVTableCallbackInterfaceConnectionDisconnectedCallback");
-    }
+        // Now we are ready to call the callback.
+        // We are already on the JS thread, because this `body` function was
+        // invoked from the CallInvoker.
+        try {
+            // Getting the callback function
+            auto cb = callbackValue->asObject(rt).asFunction(rt);
+            auto uniffiResult = cb.call(rt, js_uniffiHandle, js_status
+            );
 
-    // Get the object from the jsi::Value
-    auto jsObject = jsValue.getObject(rt);
+            // Now copy the result back from JS into the RustCallStatus object.
+            uniffi::uniffi_russh::Bridging<RustCallStatus>::copyFromJs(rt, callInvoker, uniffiResult, uniffi_call_status);
 
-    // Create the vtable struct
-    UniffiVTableCallbackInterfaceConnectionDisconnectedCallback rsObject;
+            if (uniffi_call_status->code != UNIFFI_CALL_STATUS_OK) {
+                // The JS callback finished abnormally, so we cannot retrieve the return value.
+                return;
+            }
 
-    // Create the vtable from the js callbacks.
-    rsObject.on_change = uniffi::uniffi_russh::cb::callbackinterfaceconnectiondisconnectedcallbackmethod0::makeCallbackFunction(
-          rt, callInvoker, jsObject.getProperty(rt, "onChange")
-        );
-    rsObject.uniffi_free = uniffi::uniffi_russh::st::vtablecallbackinterfaceconnectiondisconnectedcallback::vtablecallbackinterfaceconnectiondisconnectedcallback::free::makeCallbackFunction(
-          rt, callInvoker, jsObject.getProperty(rt, "uniffiFree")
-        );
+            
+        } catch (const jsi::JSError &error) {
+            std::cout << "Error in callback UniffiCallbackInterfaceConnectProgressCallbackMethod0: "
+                    << error.what() << std::endl;
+            throw error;
+        }
+    }
 
-    return rsObject;
-  }
-};
+    static void callback(uint64_t rs_uniffiHandle, RustBuffer rs_status, void * rs_uniffiOutReturn, RustCallStatus* uniffi_call_status) {
+        // If the runtime has shutdown, then there is no point in trying to
+        // call into Javascript. BUT how do we tell if the runtime has shutdown?
+        //
+        // Answer: the module destructor calls into callback `cleanup` method,
+        // which nulls out the rsLamda.
+        //
+        // If rsLamda is null, then there is no runtime to call into.
+        if (rsLambda == nullptr) {
+            // This only occurs when destructors are calling into Rust free/drop,
+            // which causes the JS callback to be dropped.
+            return;
+        }
 
-} // namespace uniffi::uniffi_russh
-namespace uniffi::uniffi_russh {
-using namespace facebook;
-using CallInvoker = uniffi_runtime::UniffiCallInvoker;
+        // The runtime, the actual callback jsi::funtion, and the callInvoker
+        // are all in the lambda.
+        rsLambda(
+            rs_uniffiHandle, 
+            rs_status, 
+            rs_uniffiOutReturn, uniffi_call_status);
+    }
 
-template <> struct Bridging<UniffiVTableCallbackInterfaceServerKeyCallback> {
-  static UniffiVTableCallbackInterfaceServerKeyCallback fromJs(jsi::Runtime &rt,
-    std::shared_ptr<CallInvoker> callInvoker,
-    const jsi::Value &jsValue
-  ) {
-    // Check if the input is an object
-    if (!jsValue.isObject()) {
-      throw jsi::JSError(rt, "Expected an object for UniffiVTableCallbackInterfaceServerKeyCallback");
+    static UniffiCallbackInterfaceConnectProgressCallbackMethod0
+    makeCallbackFunction( // uniffi::uniffi_russh::cb::callbackinterfaceconnectprogresscallbackmethod0
+                    jsi::Runtime &rt,
+                     std::shared_ptr<uniffi_runtime::UniffiCallInvoker> callInvoker,
+                     const jsi::Value &value) {
+        if (rsLambda != nullptr) {
+            // `makeCallbackFunction` is called in two circumstances:
+            //
+            // 1. at startup, when initializing callback interface vtables.
+            // 2. when polling futures. This happens at least once per future that is
+            //    exposed to Javascript. We know that this is always the same function,
+            //    `uniffiFutureContinuationCallback` in `async-rust-calls.ts`.
+            //
+            // We can therefore return the callback function without making anything
+            // new if we've been initialized already.
+            return callback;
+        }
+        auto callbackFunction = value.asObject(rt).asFunction(rt);
+        auto callbackValue = std::make_shared<jsi::Value>(rt, callbackFunction);
+        rsLambda = [&rt, callInvoker, callbackValue](uint64_t rs_uniffiHandle, RustBuffer rs_status, void * rs_uniffiOutReturn, RustCallStatus* uniffi_call_status) {
+                // We immediately make a lambda which will do the work of transforming the
+                // arguments into JSI values and calling the callback.
+                uniffi_runtime::UniffiCallFunc jsLambda = [
+                    callInvoker,
+                    callbackValue
+                    , rs_uniffiHandle
+                    , rs_status
+                    , rs_uniffiOutReturn, uniffi_call_status](jsi::Runtime &rt) mutable {
+                    body(rt, callInvoker, callbackValue
+                        , rs_uniffiHandle
+                        , rs_status
+                        , rs_uniffiOutReturn, uniffi_call_status);
+                };
+                // We'll then call that lambda from the callInvoker which will
+                // look after calling it on the correct thread.
+                callInvoker->invokeBlocking(rt, jsLambda);
+        };
+        return callback;
     }
 
-    // Get the object from the jsi::Value
-    auto jsObject = jsValue.getObject(rt);
+    // This method is called from the destructor of NativeUniffiRussh, which only happens
+    // when the jsi::Runtime is being destroyed.
+    static void cleanup() {
+        // The lambda holds a reference to the the Runtime, so when this is nulled out,
+        // then the pointer will no longer be left dangling.
+        rsLambda = nullptr;
+    }
+} // namespace uniffi::uniffi_russh::cb::callbackinterfaceconnectprogresscallbackmethod0
+    // Implementation of callback function calling from Rust to JS CallbackInterfaceConnectionDisconnectedCallbackMethod0
 
-    // Create the vtable struct
-    UniffiVTableCallbackInterfaceServerKeyCallback rsObject;
+// Callback function: uniffi::uniffi_russh::cb::callbackinterfaceconnectiondisconnectedcallbackmethod0::UniffiCallbackInterfaceConnectionDisconnectedCallbackMethod0
+//
+// We have the following constraints:
+// - we need to pass a function pointer to Rust.
+// - we need a jsi::Runtime and jsi::Function to call into JS.
+// - function pointers can't store state, so we can't use a lamda.
+//
+// For this, we store a lambda as a global, as `rsLambda`. The `callback` function calls
+// the lambda, which itself calls the `body` which then calls into JS.
+//
+// We then give the `callback` function pointer to Rust which will call the lambda sometime in the
+// future.
+namespace uniffi::uniffi_russh::cb::callbackinterfaceconnectiondisconnectedcallbackmethod0 {
+    using namespace facebook;
 
-    // Create the vtable from the js callbacks.
-    rsObject.on_change = uniffi::uniffi_russh::cb::callbackinterfaceserverkeycallbackmethod0::makeCallbackFunction(
-          rt, callInvoker, jsObject.getProperty(rt, "onChange")
-        );
-    rsObject.uniffi_free = uniffi::uniffi_russh::st::vtablecallbackinterfaceserverkeycallback::vtablecallbackinterfaceserverkeycallback::free::makeCallbackFunction(
-          rt, callInvoker, jsObject.getProperty(rt, "uniffiFree")
-        );
+    // We need to store a lambda in a global so we can call it from
+    // a function pointer. The function pointer is passed to Rust.
+    static std::function<void(uint64_t, RustBuffer, void *, RustCallStatus*)> rsLambda = nullptr;
 
-    return rsObject;
-  }
-};
-
-} // namespace uniffi::uniffi_russh
-namespace uniffi::uniffi_russh {
-using namespace facebook;
-using CallInvoker = uniffi_runtime::UniffiCallInvoker;
+    // This is the main body of the callback. It's called from the lambda,
+    // which itself is called from the callback function which is passed to Rust.
+    static void body(jsi::Runtime &rt,
+                     std::shared_ptr<uniffi_runtime::UniffiCallInvoker> callInvoker,
+                     std::shared_ptr<jsi::Value> callbackValue
+            ,uint64_t rs_uniffiHandle
+            ,RustBuffer rs_connectionId
+            ,void * rs_uniffiOutReturn, RustCallStatus* uniffi_call_status) {
 
-template <> struct Bridging<UniffiVTableCallbackInterfaceShellClosedCallback> {
-  static UniffiVTableCallbackInterfaceShellClosedCallback fromJs(jsi::Runtime &rt,
-    std::shared_ptr<CallInvoker> callInvoker,
-    const jsi::Value &jsValue
-  ) {
-    // Check if the input is an object
-    if (!jsValue.isObject()) {
-      throw jsi::JSError(rt, "Expected an object for UniffiVTableCallbackInterfaceShellClosedCallback");
-    }
+        // Convert the arguments from Rust, into jsi::Values.
+        // We'll use the Bridging class to do this…
+        auto js_uniffiHandle = uniffi_jsi::Bridging<uint64_t>::toJs(rt, callInvoker, rs_uniffiHandle);
+        auto js_connectionId = uniffi::uniffi_russh::Bridging<RustBuffer>::toJs(rt, callInvoker, rs_connectionId);
 
-    // Get the object from the jsi::Value
-    auto jsObject = jsValue.getObject(rt);
+        // Now we are ready to call the callback.
+        // We are already on the JS thread, because this `body` function was
+        // invoked from the CallInvoker.
+        try {
+            // Getting the callback function
+            auto cb = callbackValue->asObject(rt).asFunction(rt);
+            auto uniffiResult = cb.call(rt, js_uniffiHandle, js_connectionId
+            );
 
-    // Create the vtable struct
-    UniffiVTableCallbackInterfaceShellClosedCallback rsObject;
+            // Now copy the result back from JS into the RustCallStatus object.
+            uniffi::uniffi_russh::Bridging<RustCallStatus>::copyFromJs(rt, callInvoker, uniffiResult, uniffi_call_status);
 
-    // Create the vtable from the js callbacks.
-    rsObject.on_change = uniffi::uniffi_russh::cb::callbackinterfaceshellclosedcallbackmethod0::makeCallbackFunction(
-          rt, callInvoker, jsObject.getProperty(rt, "onChange")
-        );
-    rsObject.uniffi_free = uniffi::uniffi_russh::st::vtablecallbackinterfaceshellclosedcallback::vtablecallbackinterfaceshellclosedcallback::free::makeCallbackFunction(
-          rt, callInvoker, jsObject.getProperty(rt, "uniffiFree")
-        );
+            if (uniffi_call_status->code != UNIFFI_CALL_STATUS_OK) {
+                // The JS callback finished abnormally, so we cannot retrieve the return value.
+                return;
+            }
 
-    return rsObject;
-  }
-};
+            
+        } catch (const jsi::JSError &error) {
+            std::cout << "Error in callback UniffiCallbackInterfaceConnectionDisconnectedCallbackMethod0: "
+                    << error.what() << std::endl;
+            throw error;
+        }
+    }
 
-} // namespace uniffi::uniffi_russh
-namespace uniffi::uniffi_russh {
-using namespace facebook;
-using CallInvoker = uniffi_runtime::UniffiCallInvoker;
+    static void callback(uint64_t rs_uniffiHandle, RustBuffer rs_connectionId, void * rs_uniffiOutReturn, RustCallStatus* uniffi_call_status) {
+        // If the runtime has shutdown, then there is no point in trying to
+        // call into Javascript. BUT how do we tell if the runtime has shutdown?
+        //
+        // Answer: the module destructor calls into callback `cleanup` method,
+        // which nulls out the rsLamda.
+        //
+        // If rsLamda is null, then there is no runtime to call into.
+        if (rsLambda == nullptr) {
+            // This only occurs when destructors are calling into Rust free/drop,
+            // which causes the JS callback to be dropped.
+            return;
+        }
 
-template <> struct Bridging<UniffiVTableCallbackInterfaceShellListener> {
-  static UniffiVTableCallbackInterfaceShellListener fromJs(jsi::Runtime &rt,
-    std::shared_ptr<CallInvoker> callInvoker,
-    const jsi::Value &jsValue
-  ) {
-    // Check if the input is an object
-    if (!jsValue.isObject()) {
-      throw jsi::JSError(rt, "Expected an object for UniffiVTableCallbackInterfaceShellListener");
+        // The runtime, the actual callback jsi::funtion, and the callInvoker
+        // are all in the lambda.
+        rsLambda(
+            rs_uniffiHandle, 
+            rs_connectionId, 
+            rs_uniffiOutReturn, uniffi_call_status);
     }
 
-    // Get the object from the jsi::Value
-    auto jsObject = jsValue.getObject(rt);
+    static UniffiCallbackInterfaceConnectionDisconnectedCallbackMethod0
+    makeCallbackFunction( // uniffi::uniffi_russh::cb::callbackinterfaceconnectiondisconnectedcallbackmethod0
+                    jsi::Runtime &rt,
+                     std::shared_ptr<uniffi_runtime::UniffiCallInvoker> callInvoker,
+                     const jsi::Value &value) {
+        if (rsLambda != nullptr) {
+            // `makeCallbackFunction` is called in two circumstances:
+            //
+            // 1. at startup, when initializing callback interface vtables.
+            // 2. when polling futures. This happens at least once per future that is
+            //    exposed to Javascript. We know that this is always the same function,
+            //    `uniffiFutureContinuationCallback` in `async-rust-calls.ts`.
+            //
+            // We can therefore return the callback function without making anything
+            // new if we've been initialized already.
+            return callback;
+        }
+        auto callbackFunction = value.asObject(rt).asFunction(rt);
+        auto callbackValue = std::make_shared<jsi::Value>(rt, callbackFunction);
+        rsLambda = [&rt, callInvoker, callbackValue](uint64_t rs_uniffiHandle, RustBuffer rs_connectionId, void * rs_uniffiOutReturn, RustCallStatus* uniffi_call_status) {
+                // We immediately make a lambda which will do the work of transforming the
+                // arguments into JSI values and calling the callback.
+                uniffi_runtime::UniffiCallFunc jsLambda = [
+                    callInvoker,
+                    callbackValue
+                    , rs_uniffiHandle
+                    , rs_connectionId
+                    , rs_uniffiOutReturn, uniffi_call_status](jsi::Runtime &rt) mutable {
+                    body(rt, callInvoker, callbackValue
+                        , rs_uniffiHandle
+                        , rs_connectionId
+                        , rs_uniffiOutReturn, uniffi_call_status);
+                };
+                // We'll then call that lambda from the callInvoker which will
+                // look after calling it on the correct thread.
+                callInvoker->invokeBlocking(rt, jsLambda);
+        };
+        return callback;
+    }
 
-    // Create the vtable struct
-    UniffiVTableCallbackInterfaceShellListener rsObject;
+    // This method is called from the destructor of NativeUniffiRussh, which only happens
+    // when the jsi::Runtime is being destroyed.
+    static void cleanup() {
+        // The lambda holds a reference to the the Runtime, so when this is nulled out,
+        // then the pointer will no longer be left dangling.
+        rsLambda = nullptr;
+    }
+} // namespace uniffi::uniffi_russh::cb::callbackinterfaceconnectiondisconnectedcallbackmethod0
+    // Implementation of callback function calling from Rust to JS CallbackInterfaceConnectionEventCallbackMethod0
 
-    // Create the vtable from the js callbacks.
-    rsObject.on_event = uniffi::uniffi_russh::cb::callbackinterfaceshelllistenermethod0::makeCallbackFunction(
-          rt, callInvoker, jsObject.getProperty(rt, "onEvent")
-        );
-    rsObject.uniffi_free = uniffi::uniffi_russh::st::vtablecallbackinterfaceshelllistener::vtablecallbackinterfaceshelllistener::free::makeCallbackFunction(
-          rt, callInvoker, jsObject.getProperty(rt, "uniffiFree")
-        );
+// Callback function: uniffi::uniffi_russh::cb::callbackinterfaceconnectioneventcallbackmethod0::UniffiCallbackInterfaceConnectionEventCallbackMethod0
+//
+// We have the following constraints:
+// - we need to pass a function pointer to Rust.
+// - we need a jsi::Runtime and jsi::Function to call into JS.
+// - function pointers can't store state, so we can't use a lamda.
+//
+// For this, we store a lambda as a global, as `rsLambda`. The `callback` function calls
+// the lambda, which itself calls the `body` which then calls into JS.
+//
+// We then give the `callback` function pointer to Rust which will call the lambda sometime in the
+// future.
+namespace uniffi::uniffi_russh::cb::callbackinterfaceconnectioneventcallbackmethod0 {
+    using namespace facebook;
 
-    return rsObject;
-  }
-};
+    // We need to store a lambda in a global so we can call it from
+    // a function pointer. The function pointer is passed to Rust.
+    static std::function<void(uint64_t, RustBuffer, void *, RustCallStatus*)> rsLambda = nullptr;
 
-} // namespace uniffi::uniffi_russh
+    // This is the main body of the callback. It's called from the lambda,
+    // which itself is called from the callback function which is passed to Rust.
+    static void body(jsi::Runtime &rt,
+                     std::shared_ptr<uniffi_runtime::UniffiCallInvoker> callInvoker,
+                     std::shared_ptr<jsi::Value> callbackValue
+            ,uint64_t rs_uniffiHandle
+            ,RustBuffer rs_event
+            ,void * rs_uniffiOutReturn, RustCallStatus* uniffi_call_status) {
 
+        // Convert the arguments from Rust, into jsi::Values.
+        // We'll use the Bridging class to do this…
+        auto js_uniffiHandle = uniffi_jsi::Bridging<uint64_t>::toJs(rt, callInvoker, rs_uniffiHandle);
+        auto js_event = uniffi::uniffi_russh::Bridging<RustBuffer>::toJs(rt, callInvoker, rs_event);
 
-namespace uniffi::uniffi_russh {
-using namespace facebook;
-using CallInvoker = uniffi_runtime::UniffiCallInvoker;
+        // Now we are ready to call the callback.
+        // We are already on the JS thread, because this `body` function was
+        // invoked from the CallInvoker.
+        try {
+            // Getting the callback function
+            auto cb = callbackValue->asObject(rt).asFunction(rt);
+            auto uniffiResult = cb.call(rt, js_uniffiHandle, js_event
+            );
 
-template <> struct Bridging<UniffiRustFutureContinuationCallback> {
-  static UniffiRustFutureContinuationCallback fromJs(
-    jsi::Runtime &rt,
-    std::shared_ptr<CallInvoker> callInvoker,
-    const jsi::Value &value
-  ) {
-    try {
-      return uniffi::uniffi_russh::cb::rustfuturecontinuationcallback::makeCallbackFunction(
-        rt,
-        callInvoker,
-        value
-      );
-    } catch (const std::logic_error &e) {
-      throw jsi::JSError(rt, e.what());
-    }
-  }
-};
+            // Now copy the result back from JS into the RustCallStatus object.
+            uniffi::uniffi_russh::Bridging<RustCallStatus>::copyFromJs(rt, callInvoker, uniffiResult, uniffi_call_status);
 
-} // namespace uniffi::uniffi_russh
+            if (uniffi_call_status->code != UNIFFI_CALL_STATUS_OK) {
+                // The JS callback finished abnormally, so we cannot retrieve the return value.
+                return;
+            }
 
-NativeUniffiRussh::NativeUniffiRussh(
-    jsi::Runtime &rt,
-    std::shared_ptr<uniffi_runtime::UniffiCallInvoker> invoker
-) : callInvoker(invoker), props() {
-    // Map from Javascript names to the cpp names
-    props["ubrn_uniffi_internal_fn_func_ffi__string_to_byte_length"] = jsi::Function::createFromHostFunction(
-        rt,
-        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_internal_fn_func_ffi__string_to_byte_length"),
-        1,
-        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
-            return this->cpp_uniffi_internal_fn_func_ffi__string_to_byte_length(rt, thisVal, args, count);
-        }
-    );
-    props["ubrn_uniffi_internal_fn_func_ffi__string_to_arraybuffer"] = jsi::Function::createFromHostFunction(
-        rt,
-        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_internal_fn_func_ffi__string_to_arraybuffer"),
-        1,
-        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
-            return this->cpp_uniffi_internal_fn_func_ffi__string_to_arraybuffer(rt, thisVal, args, count);
+            
+        } catch (const jsi::JSError &error) {
+            std::cout << "Error in callback UniffiCallbackInterfaceConnectionEventCallbackMethod0: "
+                    << error.what() << std::endl;
+            throw error;
         }
-    );
-    props["ubrn_uniffi_internal_fn_func_ffi__arraybuffer_to_string"] = jsi::Function::createFromHostFunction(
-        rt,
-        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_internal_fn_func_ffi__arraybuffer_to_string"),
-        1,
-        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
-            return this->cpp_uniffi_internal_fn_func_ffi__arraybuffer_to_string(rt, thisVal, args, count);
+    }
+
+    static void callback(uint64_t rs_uniffiHandle, RustBuffer rs_event, void * rs_uniffiOutReturn, RustCallStatus* uniffi_call_status) {
+        // If the runtime has shutdown, then there is no point in trying to
+        // call into Javascript. BUT how do we tell if the runtime has shutdown?
+        //
+        // Answer: the module destructor calls into callback `cleanup` method,
+        // which nulls out the rsLamda.
+        //
+        // If rsLamda is null, then there is no runtime to call into.
+        if (rsLambda == nullptr) {
+            // This only occurs when destructors are calling into Rust free/drop,
+            // which causes the JS callback to be dropped.
+            return;
         }
-    );
-    props["ubrn_uniffi_uniffi_russh_fn_clone_connectprogresscallback"] = jsi::Function::createFromHostFunction(
-        rt,
-        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_clone_connectprogresscallback"),
-        1,
-        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
-            return this->cpp_uniffi_uniffi_russh_fn_clone_connectprogresscallback(rt, thisVal, args, count);
-        }
-    );
-    props["ubrn_uniffi_uniffi_russh_fn_free_connectprogresscallback"] = jsi::Function::createFromHostFunction(
-        rt,
-        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_free_connectprogresscallback"),
-        1,
-        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
-            return this->cpp_uniffi_uniffi_russh_fn_free_connectprogresscallback(rt, thisVal, args, count);
-        }
-    );
-    props["ubrn_uniffi_uniffi_russh_fn_init_callback_vtable_connectprogresscallback"] = jsi::Function::createFromHostFunction(
-        rt,
-        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_init_callback_vtable_connectprogresscallback"),
-        1,
-        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
-            return this->cpp_uniffi_uniffi_russh_fn_init_callback_vtable_connectprogresscallback(rt, thisVal, args, count);
-        }
-    );
-    props["ubrn_uniffi_uniffi_russh_fn_method_connectprogresscallback_on_change"] = jsi::Function::createFromHostFunction(
-        rt,
-        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_method_connectprogresscallback_on_change"),
-        2,
-        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
-            return this->cpp_uniffi_uniffi_russh_fn_method_connectprogresscallback_on_change(rt, thisVal, args, count);
-        }
-    );
-    props["ubrn_uniffi_uniffi_russh_fn_clone_connectiondisconnectedcallback"] = jsi::Function::createFromHostFunction(
-        rt,
-        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_clone_connectiondisconnectedcallback"),
-        1,
-        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
-            return this->cpp_uniffi_uniffi_russh_fn_clone_connectiondisconnectedcallback(rt, thisVal, args, count);
-        }
-    );
-    props["ubrn_uniffi_uniffi_russh_fn_free_connectiondisconnectedcallback"] = jsi::Function::createFromHostFunction(
-        rt,
-        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_free_connectiondisconnectedcallback"),
-        1,
-        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
-            return this->cpp_uniffi_uniffi_russh_fn_free_connectiondisconnectedcallback(rt, thisVal, args, count);
-        }
-    );
-    props["ubrn_uniffi_uniffi_russh_fn_init_callback_vtable_connectiondisconnectedcallback"] = jsi::Function::createFromHostFunction(
-        rt,
-        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_init_callback_vtable_connectiondisconnectedcallback"),
-        1,
-        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
-            return this->cpp_uniffi_uniffi_russh_fn_init_callback_vtable_connectiondisconnectedcallback(rt, thisVal, args, count);
-        }
-    );
-    props["ubrn_uniffi_uniffi_russh_fn_method_connectiondisconnectedcallback_on_change"] = jsi::Function::createFromHostFunction(
-        rt,
-        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_method_connectiondisconnectedcallback_on_change"),
-        2,
-        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
-            return this->cpp_uniffi_uniffi_russh_fn_method_connectiondisconnectedcallback_on_change(rt, thisVal, args, count);
-        }
-    );
-    props["ubrn_uniffi_uniffi_russh_fn_clone_serverkeycallback"] = jsi::Function::createFromHostFunction(
-        rt,
-        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_clone_serverkeycallback"),
-        1,
-        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
-            return this->cpp_uniffi_uniffi_russh_fn_clone_serverkeycallback(rt, thisVal, args, count);
-        }
-    );
-    props["ubrn_uniffi_uniffi_russh_fn_free_serverkeycallback"] = jsi::Function::createFromHostFunction(
-        rt,
-        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_free_serverkeycallback"),
-        1,
-        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
-            return this->cpp_uniffi_uniffi_russh_fn_free_serverkeycallback(rt, thisVal, args, count);
-        }
-    );
-    props["ubrn_uniffi_uniffi_russh_fn_init_callback_vtable_serverkeycallback"] = jsi::Function::createFromHostFunction(
-        rt,
-        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_init_callback_vtable_serverkeycallback"),
-        1,
-        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
-            return this->cpp_uniffi_uniffi_russh_fn_init_callback_vtable_serverkeycallback(rt, thisVal, args, count);
-        }
-    );
-    props["ubrn_uniffi_uniffi_russh_fn_method_serverkeycallback_on_change"] = jsi::Function::createFromHostFunction(
-        rt,
-        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_method_serverkeycallback_on_change"),
-        2,
-        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
-            return this->cpp_uniffi_uniffi_russh_fn_method_serverkeycallback_on_change(rt, thisVal, args, count);
+
+        // The runtime, the actual callback jsi::funtion, and the callInvoker
+        // are all in the lambda.
+        rsLambda(
+            rs_uniffiHandle, 
+            rs_event, 
+            rs_uniffiOutReturn, uniffi_call_status);
+    }
+
+    static UniffiCallbackInterfaceConnectionEventCallbackMethod0
+    makeCallbackFunction( // uniffi::uniffi_russh::cb::callbackinterfaceconnectioneventcallbackmethod0
+                    jsi::Runtime &rt,
+                     std::shared_ptr<uniffi_runtime::UniffiCallInvoker> callInvoker,
+                     const jsi::Value &value) {
+        if (rsLambda != nullptr) {
+            // `makeCallbackFunction` is called in two circumstances:
+            //
+            // 1. at startup, when initializing callback interface vtables.
+            // 2. when polling futures. This happens at least once per future that is
+            //    exposed to Javascript. We know that this is always the same function,
+            //    `uniffiFutureContinuationCallback` in `async-rust-calls.ts`.
+            //
+            // We can therefore return the callback function without making anything
+            // new if we've been initialized already.
+            return callback;
         }
-    );
-    props["ubrn_uniffi_uniffi_russh_fn_clone_shellclosedcallback"] = jsi::Function::createFromHostFunction(
-        rt,
-        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_clone_shellclosedcallback"),
-        1,
-        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
-            return this->cpp_uniffi_uniffi_russh_fn_clone_shellclosedcallback(rt, thisVal, args, count);
+        auto callbackFunction = value.asObject(rt).asFunction(rt);
+        auto callbackValue = std::make_shared<jsi::Value>(rt, callbackFunction);
+        rsLambda = [&rt, callInvoker, callbackValue](uint64_t rs_uniffiHandle, RustBuffer rs_event, void * rs_uniffiOutReturn, RustCallStatus* uniffi_call_status) {
+                // We immediately make a lambda which will do the work of transforming the
+                // arguments into JSI values and calling the callback.
+                uniffi_runtime::UniffiCallFunc jsLambda = [
+                    callInvoker,
+                    callbackValue
+                    , rs_uniffiHandle
+                    , rs_event
+                    , rs_uniffiOutReturn, uniffi_call_status](jsi::Runtime &rt) mutable {
+                    body(rt, callInvoker, callbackValue
+                        , rs_uniffiHandle
+                        , rs_event
+                        , rs_uniffiOutReturn, uniffi_call_status);
+                };
+                // We'll then call that lambda from the callInvoker which will
+                // look after calling it on the correct thread.
+                callInvoker->invokeBlocking(rt, jsLambda);
+        };
+        return callback;
+    }
+
+    // This method is called from the destructor of NativeUniffiRussh, which only happens
+    // when the jsi::Runtime is being destroyed.
+    static void cleanup() {
+        // The lambda holds a reference to the the Runtime, so when this is nulled out,
+        // then the pointer will no longer be left dangling.
+        rsLambda = nullptr;
+    }
+} // namespace uniffi::uniffi_russh::cb::callbackinterfaceconnectioneventcallbackmethod0
+    // Implementation of callback function calling from Rust to JS CallbackInterfaceConnectionMetricsCallbackMethod0
+
+// Callback function: uniffi::uniffi_russh::cb::callbackinterfaceconnectionmetricscallbackmethod0::UniffiCallbackInterfaceConnectionMetricsCallbackMethod0
+//
+// We have the following constraints:
+// - we need to pass a function pointer to Rust.
+// - we need a jsi::Runtime and jsi::Function to call into JS.
+// - function pointers can't store state, so we can't use a lamda.
+//
+// For this, we store a lambda as a global, as `rsLambda`. The `callback` function calls
+// the lambda, which itself calls the `body` which then calls into JS.
+//
+// We then give the `callback` function pointer to Rust which will call the lambda sometime in the
+// future.
+namespace uniffi::uniffi_russh::cb::callbackinterfaceconnectionmetricscallbackmethod0 {
+    using namespace facebook;
+
+    // We need to store a lambda in a global so we can call it from
+    // a function pointer. The function pointer is passed to Rust.
+    static std::function<void(uint64_t, RustBuffer, void *, RustCallStatus*)> rsLambda = nullptr;
+
+    // This is the main body of the callback. It's called from the lambda,
+    // which itself is called from the callback function which is passed to Rust.
+    static void body(jsi::Runtime &rt,
+                     std::shared_ptr<uniffi_runtime::UniffiCallInvoker> callInvoker,
+                     std::shared_ptr<jsi::Value> callbackValue
+            ,uint64_t rs_uniffiHandle
+            ,RustBuffer rs_metrics
+            ,void * rs_uniffiOutReturn, RustCallStatus* uniffi_call_status) {
+
+        // Convert the arguments from Rust, into jsi::Values.
+        // We'll use the Bridging class to do this…
+        auto js_uniffiHandle = uniffi_jsi::Bridging<uint64_t>::toJs(rt, callInvoker, rs_uniffiHandle);
+        auto js_metrics = uniffi::uniffi_russh::Bridging<RustBuffer>::toJs(rt, callInvoker, rs_metrics);
+
+        // Now we are ready to call the callback.
+        // We are already on the JS thread, because this `body` function was
+        // invoked from the CallInvoker.
+        try {
+            // Getting the callback function
+            auto cb = callbackValue->asObject(rt).asFunction(rt);
+            auto uniffiResult = cb.call(rt, js_uniffiHandle, js_metrics
+            );
+
+            // Now copy the result back from JS into the RustCallStatus object.
+            uniffi::uniffi_russh::Bridging<RustCallStatus>::copyFromJs(rt, callInvoker, uniffiResult, uniffi_call_status);
+
+            if (uniffi_call_status->code != UNIFFI_CALL_STATUS_OK) {
+                // The JS callback finished abnormally, so we cannot retrieve the return value.
+                return;
+            }
+
+            
+        } catch (const jsi::JSError &error) {
+            std::cout << "Error in callback UniffiCallbackInterfaceConnectionMetricsCallbackMethod0: "
+                    << error.what() << std::endl;
+            throw error;
         }
-    );
-    props["ubrn_uniffi_uniffi_russh_fn_free_shellclosedcallback"] = jsi::Function::createFromHostFunction(
-        rt,
-        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_free_shellclosedcallback"),
-        1,
-        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
-            return this->cpp_uniffi_uniffi_russh_fn_free_shellclosedcallback(rt, thisVal, args, count);
+    }
+
+    static void callback(uint64_t rs_uniffiHandle, RustBuffer rs_metrics, void * rs_uniffiOutReturn, RustCallStatus* uniffi_call_status) {
+        // If the runtime has shutdown, then there is no point in trying to
+        // call into Javascript. BUT how do we tell if the runtime has shutdown?
+        //
+        // Answer: the module destructor calls into callback `cleanup` method,
+        // which nulls out the rsLamda.
+        //
+        // If rsLamda is null, then there is no runtime to call into.
+        if (rsLambda == nullptr) {
+            // This only occurs when destructors are calling into Rust free/drop,
+            // which causes the JS callback to be dropped.
+            return;
         }
-    );
-    props["ubrn_uniffi_uniffi_russh_fn_init_callback_vtable_shellclosedcallback"] = jsi::Function::createFromHostFunction(
-        rt,
-        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_init_callback_vtable_shellclosedcallback"),
-        1,
-        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
-            return this->cpp_uniffi_uniffi_russh_fn_init_callback_vtable_shellclosedcallback(rt, thisVal, args, count);
+
+        // The runtime, the actual callback jsi::funtion, and the callInvoker
+        // are all in the lambda.
+        rsLambda(
+            rs_uniffiHandle, 
+            rs_metrics, 
+            rs_uniffiOutReturn, uniffi_call_status);
+    }
+
+    static UniffiCallbackInterfaceConnectionMetricsCallbackMethod0
+    makeCallbackFunction( // uniffi::uniffi_russh::cb::callbackinterfaceconnectionmetricscallbackmethod0
+                    jsi::Runtime &rt,
+                     std::shared_ptr<uniffi_runtime::UniffiCallInvoker> callInvoker,
+                     const jsi::Value &value) {
+        if (rsLambda != nullptr) {
+            // `makeCallbackFunction` is called in two circumstances:
+            //
+            // 1. at startup, when initializing callback interface vtables.
+            // 2. when polling futures. This happens at least once per future that is
+            //    exposed to Javascript. We know that this is always the same function,
+            //    `uniffiFutureContinuationCallback` in `async-rust-calls.ts`.
+            //
+            // We can therefore return the callback function without making anything
+            // new if we've been initialized already.
+            return callback;
         }
-    );
-    props["ubrn_uniffi_uniffi_russh_fn_method_shellclosedcallback_on_change"] = jsi::Function::createFromHostFunction(
-        rt,
-        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_method_shellclosedcallback_on_change"),
-        2,
-        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
-            return this->cpp_uniffi_uniffi_russh_fn_method_shellclosedcallback_on_change(rt, thisVal, args, count);
-        }
-    );
-    props["ubrn_uniffi_uniffi_russh_fn_clone_shelllistener"] = jsi::Function::createFromHostFunction(
-        rt,
-        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_clone_shelllistener"),
-        1,
-        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
-            return this->cpp_uniffi_uniffi_russh_fn_clone_shelllistener(rt, thisVal, args, count);
+        auto callbackFunction = value.asObject(rt).asFunction(rt);
+        auto callbackValue = std::make_shared<jsi::Value>(rt, callbackFunction);
+        rsLambda = [&rt, callInvoker, callbackValue](uint64_t rs_uniffiHandle, RustBuffer rs_metrics, void * rs_uniffiOutReturn, RustCallStatus* uniffi_call_status) {
+                // We immediately make a lambda which will do the work of transforming the
+                // arguments into JSI values and calling the callback.
+                uniffi_runtime::UniffiCallFunc jsLambda = [
+                    callInvoker,
+                    callbackValue
+                    , rs_uniffiHandle
+                    , rs_metrics
+                    , rs_uniffiOutReturn, uniffi_call_status](jsi::Runtime &rt) mutable {
+                    body(rt, callInvoker, callbackValue
+                        , rs_uniffiHandle
+                        , rs_metrics
+                        , rs_uniffiOutReturn, uniffi_call_status);
+                };
+                // We'll then call that lambda from the callInvoker which will
+                // look after calling it on the correct thread.
+                callInvoker->invokeBlocking(rt, jsLambda);
+        };
+        return callback;
+    }
+
+    // This method is called from the destructor of NativeUniffiRussh, which only happens
+    // when the jsi::Runtime is being destroyed.
+    static void cleanup() {
+        // The lambda holds a reference to the the Runtime, so when this is nulled out,
+        // then the pointer will no longer be left dangling.
+        rsLambda = nullptr;
+    }
+} // namespace uniffi::uniffi_russh::cb::callbackinterfaceconnectionmetricscallbackmethod0
+    // Implementation of callback function calling from Rust to JS CallbackInterfaceServerKeyCallbackMethod0
+
+// Callback function: uniffi::uniffi_russh::cb::callbackinterfaceserverkeycallbackmethod0::UniffiCallbackInterfaceServerKeyCallbackMethod0
+//
+// We have the following constraints:
+// - we need to pass a function pointer to Rust.
+// - we need a jsi::Runtime and jsi::Function to call into JS.
+// - function pointers can't store state, so we can't use a lamda.
+//
+// For this, we store a lambda as a global, as `rsLambda`. The `callback` function calls
+// the lambda, which itself calls the `body` which then calls into JS.
+//
+// We then give the `callback` function pointer to Rust which will call the lambda sometime in the
+// future.
+namespace uniffi::uniffi_russh::cb::callbackinterfaceserverkeycallbackmethod0 {
+    using namespace facebook;
+
+    // We need to store a lambda in a global so we can call it from
+    // a function pointer. The function pointer is passed to Rust.
+    static std::function<void(uint64_t, RustBuffer, UniffiForeignFutureCompleteI8, uint64_t, UniffiForeignFuture *)> rsLambda = nullptr;
+
+    // This is the main body of the callback. It's called from the lambda,
+    // which itself is called from the callback function which is passed to Rust.
+    static void body(jsi::Runtime &rt,
+                     std::shared_ptr<uniffi_runtime::UniffiCallInvoker> callInvoker,
+                     std::shared_ptr<jsi::Value> callbackValue
+            ,uint64_t rs_uniffiHandle
+            ,RustBuffer rs_serverKeyInfo
+            ,UniffiForeignFutureCompleteI8 rs_uniffiFutureCallback
+            ,uint64_t rs_uniffiCallbackData
+            ,UniffiForeignFuture * rs_uniffiOutReturn) {
+
+        // Convert the arguments from Rust, into jsi::Values.
+        // We'll use the Bridging class to do this…
+        auto js_uniffiHandle = uniffi_jsi::Bridging<uint64_t>::toJs(rt, callInvoker, rs_uniffiHandle);
+        auto js_serverKeyInfo = uniffi::uniffi_russh::Bridging<RustBuffer>::toJs(rt, callInvoker, rs_serverKeyInfo);
+        auto js_uniffiFutureCallback = uniffi::uniffi_russh::Bridging<UniffiForeignFutureCompleteI8>::toJs(rt, callInvoker, rs_uniffiFutureCallback);
+        auto js_uniffiCallbackData = uniffi_jsi::Bridging<uint64_t>::toJs(rt, callInvoker, rs_uniffiCallbackData);
+
+        // Now we are ready to call the callback.
+        // We are already on the JS thread, because this `body` function was
+        // invoked from the CallInvoker.
+        try {
+            // Getting the callback function
+            auto cb = callbackValue->asObject(rt).asFunction(rt);
+            auto uniffiResult = cb.call(rt, js_uniffiHandle, js_serverKeyInfo, js_uniffiFutureCallback, js_uniffiCallbackData
+            );
+
+            
+
+            
+            // return type is MutReference(Struct("ForeignFuture"))
+            // Finally, we need to copy the return value back into the Rust pointer.
+            *rs_uniffiOutReturn =
+                uniffi::uniffi_russh::Bridging<
+                    UniffiForeignFuture
+                >::fromJs(
+                    rt, callInvoker, uniffiResult
+                );
+        } catch (const jsi::JSError &error) {
+            std::cout << "Error in callback UniffiCallbackInterfaceServerKeyCallbackMethod0: "
+                    << error.what() << std::endl;
+            throw error;
         }
-    );
-    props["ubrn_uniffi_uniffi_russh_fn_free_shelllistener"] = jsi::Function::createFromHostFunction(
-        rt,
-        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_free_shelllistener"),
-        1,
-        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
-            return this->cpp_uniffi_uniffi_russh_fn_free_shelllistener(rt, thisVal, args, count);
+    }
+
+    static void callback(uint64_t rs_uniffiHandle, RustBuffer rs_serverKeyInfo, UniffiForeignFutureCompleteI8 rs_uniffiFutureCallback, uint64_t rs_uniffiCallbackData, UniffiForeignFuture * rs_uniffiOutReturn) {
+        // If the runtime has shutdown, then there is no point in trying to
+        // call into Javascript. BUT how do we tell if the runtime has shutdown?
+        //
+        // Answer: the module destructor calls into callback `cleanup` method,
+        // which nulls out the rsLamda.
+        //
+        // If rsLamda is null, then there is no runtime to call into.
+        if (rsLambda == nullptr) {
+            // This only occurs when destructors are calling into Rust free/drop,
+            // which causes the JS callback to be dropped.
+            return;
         }
-    );
-    props["ubrn_uniffi_uniffi_russh_fn_init_callback_vtable_shelllistener"] = jsi::Function::createFromHostFunction(
-        rt,
-        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_init_callback_vtable_shelllistener"),
-        1,
-        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
-            return this->cpp_uniffi_uniffi_russh_fn_init_callback_vtable_shelllistener(rt, thisVal, args, count);
+
+        // The runtime, the actual callback jsi::funtion, and the callInvoker
+        // are all in the lambda.
+        rsLambda(
+            rs_uniffiHandle, 
+            rs_serverKeyInfo, 
+            rs_uniffiFutureCallback, 
+            rs_uniffiCallbackData, 
+            rs_uniffiOutReturn);
+    }
+
+    static UniffiCallbackInterfaceServerKeyCallbackMethod0
+    makeCallbackFunction( // uniffi::uniffi_russh::cb::callbackinterfaceserverkeycallbackmethod0
+                    jsi::Runtime &rt,
+                     std::shared_ptr<uniffi_runtime::UniffiCallInvoker> callInvoker,
+                     const jsi::Value &value) {
+        if (rsLambda != nullptr) {
+            // `makeCallbackFunction` is called in two circumstances:
+            //
+            // 1. at startup, when initializing callback interface vtables.
+            // 2. when polling futures. This happens at least once per future that is
+            //    exposed to Javascript. We know that this is always the same function,
+            //    `uniffiFutureContinuationCallback` in `async-rust-calls.ts`.
+            //
+            // We can therefore return the callback function without making anything
+            // new if we've been initialized already.
+            return callback;
         }
-    );
-    props["ubrn_uniffi_uniffi_russh_fn_method_shelllistener_on_event"] = jsi::Function::createFromHostFunction(
-        rt,
-        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_method_shelllistener_on_event"),
-        2,
-        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
-            return this->cpp_uniffi_uniffi_russh_fn_method_shelllistener_on_event(rt, thisVal, args, count);
+        auto callbackFunction = value.asObject(rt).asFunction(rt);
+        auto callbackValue = std::make_shared<jsi::Value>(rt, callbackFunction);
+        rsLambda = [&rt, callInvoker, callbackValue](uint64_t rs_uniffiHandle, RustBuffer rs_serverKeyInfo, UniffiForeignFutureCompleteI8 rs_uniffiFutureCallback, uint64_t rs_uniffiCallbackData, UniffiForeignFuture * rs_uniffiOutReturn) {
+                // We immediately make a lambda which will do the work of transforming the
+                // arguments into JSI values and calling the callback.
+                uniffi_runtime::UniffiCallFunc jsLambda = [
+                    callInvoker,
+                    callbackValue
+                    , rs_uniffiHandle
+                    , rs_serverKeyInfo
+                    , rs_uniffiFutureCallback
+                    , rs_uniffiCallbackData
+                    , rs_uniffiOutReturn](jsi::Runtime &rt) mutable {
+                    body(rt, callInvoker, callbackValue
+                        , rs_uniffiHandle
+                        , rs_serverKeyInfo
+                        , rs_uniffiFutureCallback
+                        , rs_uniffiCallbackData
+                        , rs_uniffiOutReturn);
+                };
+                // We'll then call that lambda from the callInvoker which will
+                // look after calling it on the correct thread.
+                callInvoker->invokeBlocking(rt, jsLambda);
+        };
+        return callback;
+    }
+
+    // This method is called from the destructor of NativeUniffiRussh, which only happens
+    // when the jsi::Runtime is being destroyed.
+    static void cleanup() {
+        // The lambda holds a reference to the the Runtime, so when this is nulled out,
+        // then the pointer will no longer be left dangling.
+        rsLambda = nullptr;
+    }
+} // namespace uniffi::uniffi_russh::cb::callbackinterfaceserverkeycallbackmethod0
+    // Implementation of callback function calling from Rust to JS CallbackInterfaceShellClosedCallbackMethod0
+
+// Callback function: uniffi::uniffi_russh::cb::callbackinterfaceshellclosedcallbackmethod0::UniffiCallbackInterfaceShellClosedCallbackMethod0
+//
+// We have the following constraints:
+// - we need to pass a function pointer to Rust.
+// - we need a jsi::Runtime and jsi::Function to call into JS.
+// - function pointers can't store state, so we can't use a lamda.
+//
+// For this, we store a lambda as a global, as `rsLambda`. The `callback` function calls
+// the lambda, which itself calls the `body` which then calls into JS.
+//
+// We then give the `callback` function pointer to Rust which will call the lambda sometime in the
+// future.
+namespace uniffi::uniffi_russh::cb::callbackinterfaceshellclosedcallbackmethod0 {
+    using namespace facebook;
+
+    // We need to store a lambda in a global so we can call it from
+    // a function pointer. The function pointer is passed to Rust.
+    static std::function<void(uint64_t, uint32_t, void *, RustCallStatus*)> rsLambda = nullptr;
+
+    // This is the main body of the callback. It's called from the lambda,
+    // which itself is called from the callback function which is passed to Rust.
+    static void body(jsi::Runtime &rt,
+                     std::shared_ptr<uniffi_runtime::UniffiCallInvoker> callInvoker,
+                     std::shared_ptr<jsi::Value> callbackValue
+            ,uint64_t rs_uniffiHandle
+            ,uint32_t rs_channelId
+            ,void * rs_uniffiOutReturn, RustCallStatus* uniffi_call_status) {
+
+        // Convert the arguments from Rust, into jsi::Values.
+        // We'll use the Bridging class to do this…
+        auto js_uniffiHandle = uniffi_jsi::Bridging<uint64_t>::toJs(rt, callInvoker, rs_uniffiHandle);
+        auto js_channelId = uniffi_jsi::Bridging<uint32_t>::toJs(rt, callInvoker, rs_channelId);
+
+        // Now we are ready to call the callback.
+        // We are already on the JS thread, because this `body` function was
+        // invoked from the CallInvoker.
+        try {
+            // Getting the callback function
+            auto cb = callbackValue->asObject(rt).asFunction(rt);
+            auto uniffiResult = cb.call(rt, js_uniffiHandle, js_channelId
+            );
+
+            // Now copy the result back from JS into the RustCallStatus object.
+            uniffi::uniffi_russh::Bridging<RustCallStatus>::copyFromJs(rt, callInvoker, uniffiResult, uniffi_call_status);
+
+            if (uniffi_call_status->code != UNIFFI_CALL_STATUS_OK) {
+                // The JS callback finished abnormally, so we cannot retrieve the return value.
+                return;
+            }
+
+            
+        } catch (const jsi::JSError &error) {
+            std::cout << "Error in callback UniffiCallbackInterfaceShellClosedCallbackMethod0: "
+                    << error.what() << std::endl;
+            throw error;
         }
-    );
-    props["ubrn_uniffi_uniffi_russh_fn_clone_shellsession"] = jsi::Function::createFromHostFunction(
-        rt,
-        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_clone_shellsession"),
-        1,
-        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
-            return this->cpp_uniffi_uniffi_russh_fn_clone_shellsession(rt, thisVal, args, count);
-        }
-    );
-    props["ubrn_uniffi_uniffi_russh_fn_free_shellsession"] = jsi::Function::createFromHostFunction(
-        rt,
-        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_free_shellsession"),
-        1,
-        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
-            return this->cpp_uniffi_uniffi_russh_fn_free_shellsession(rt, thisVal, args, count);
-        }
-    );
-    props["ubrn_uniffi_uniffi_russh_fn_method_shellsession_add_listener"] = jsi::Function::createFromHostFunction(
-        rt,
-        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_method_shellsession_add_listener"),
-        3,
-        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
-            return this->cpp_uniffi_uniffi_russh_fn_method_shellsession_add_listener(rt, thisVal, args, count);
-        }
-    );
-    props["ubrn_uniffi_uniffi_russh_fn_method_shellsession_buffer_stats"] = jsi::Function::createFromHostFunction(
-        rt,
-        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_method_shellsession_buffer_stats"),
-        1,
-        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
-            return this->cpp_uniffi_uniffi_russh_fn_method_shellsession_buffer_stats(rt, thisVal, args, count);
-        }
-    );
-    props["ubrn_uniffi_uniffi_russh_fn_method_shellsession_close"] = jsi::Function::createFromHostFunction(
-        rt,
-        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_method_shellsession_close"),
-        1,
-        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
-            return this->cpp_uniffi_uniffi_russh_fn_method_shellsession_close(rt, thisVal, args, count);
-        }
-    );
-    props["ubrn_uniffi_uniffi_russh_fn_method_shellsession_current_seq"] = jsi::Function::createFromHostFunction(
-        rt,
-        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_method_shellsession_current_seq"),
-        1,
-        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
-            return this->cpp_uniffi_uniffi_russh_fn_method_shellsession_current_seq(rt, thisVal, args, count);
-        }
-    );
-    props["ubrn_uniffi_uniffi_russh_fn_method_shellsession_get_info"] = jsi::Function::createFromHostFunction(
-        rt,
-        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_method_shellsession_get_info"),
-        1,
-        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
-            return this->cpp_uniffi_uniffi_russh_fn_method_shellsession_get_info(rt, thisVal, args, count);
-        }
-    );
-    props["ubrn_uniffi_uniffi_russh_fn_method_shellsession_read_buffer"] = jsi::Function::createFromHostFunction(
-        rt,
-        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_method_shellsession_read_buffer"),
-        3,
-        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
-            return this->cpp_uniffi_uniffi_russh_fn_method_shellsession_read_buffer(rt, thisVal, args, count);
+    }
+
+    static void callback(uint64_t rs_uniffiHandle, uint32_t rs_channelId, void * rs_uniffiOutReturn, RustCallStatus* uniffi_call_status) {
+        // If the runtime has shutdown, then there is no point in trying to
+        // call into Javascript. BUT how do we tell if the runtime has shutdown?
+        //
+        // Answer: the module destructor calls into callback `cleanup` method,
+        // which nulls out the rsLamda.
+        //
+        // If rsLamda is null, then there is no runtime to call into.
+        if (rsLambda == nullptr) {
+            // This only occurs when destructors are calling into Rust free/drop,
+            // which causes the JS callback to be dropped.
+            return;
         }
-    );
-    props["ubrn_uniffi_uniffi_russh_fn_method_shellsession_remove_listener"] = jsi::Function::createFromHostFunction(
-        rt,
-        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_method_shellsession_remove_listener"),
-        2,
-        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
-            return this->cpp_uniffi_uniffi_russh_fn_method_shellsession_remove_listener(rt, thisVal, args, count);
+
+        // The runtime, the actual callback jsi::funtion, and the callInvoker
+        // are all in the lambda.
+        rsLambda(
+            rs_uniffiHandle, 
+            rs_channelId, 
+            rs_uniffiOutReturn, uniffi_call_status);
+    }
+
+    static UniffiCallbackInterfaceShellClosedCallbackMethod0
+    makeCallbackFunction( // uniffi::uniffi_russh::cb::callbackinterfaceshellclosedcallbackmethod0
+                    jsi::Runtime &rt,
+                     std::shared_ptr<uniffi_runtime::UniffiCallInvoker> callInvoker,
+                     const jsi::Value &value) {
+        if (rsLambda != nullptr) {
+            // `makeCallbackFunction` is called in two circumstances:
+            //
+            // 1. at startup, when initializing callback interface vtables.
+            // 2. when polling futures. This happens at least once per future that is
+            //    exposed to Javascript. We know that this is always the same function,
+            //    `uniffiFutureContinuationCallback` in `async-rust-calls.ts`.
+            //
+            // We can therefore return the callback function without making anything
+            // new if we've been initialized already.
+            return callback;
         }
-    );
-    props["ubrn_uniffi_uniffi_russh_fn_method_shellsession_send_data"] = jsi::Function::createFromHostFunction(
-        rt,
-        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_method_shellsession_send_data"),
-        2,
-        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
-            return this->cpp_uniffi_uniffi_russh_fn_method_shellsession_send_data(rt, thisVal, args, count);
+        auto callbackFunction = value.asObject(rt).asFunction(rt);
+        auto callbackValue = std::make_shared<jsi::Value>(rt, callbackFunction);
+        rsLambda = [&rt, callInvoker, callbackValue](uint64_t rs_uniffiHandle, uint32_t rs_channelId, void * rs_uniffiOutReturn, RustCallStatus* uniffi_call_status) {
+                // We immediately make a lambda which will do the work of transforming the
+                // arguments into JSI values and calling the callback.
+                uniffi_runtime::UniffiCallFunc jsLambda = [
+                    callInvoker,
+                    callbackValue
+                    , rs_uniffiHandle
+                    , rs_channelId
+                    , rs_uniffiOutReturn, uniffi_call_status](jsi::Runtime &rt) mutable {
+                    body(rt, callInvoker, callbackValue
+                        , rs_uniffiHandle
+                        , rs_channelId
+                        , rs_uniffiOutReturn, uniffi_call_status);
+                };
+                // We'll then call that lambda from the callInvoker which will
+                // look after calling it on the correct thread.
+                callInvoker->invokeBlocking(rt, jsLambda);
+        };
+        return callback;
+    }
+
+    // This method is called from the destructor of NativeUniffiRussh, which only happens
+    // when the jsi::Runtime is being destroyed.
+    static void cleanup() {
+        // The lambda holds a reference to the the Runtime, so when this is nulled out,
+        // then the pointer will no longer be left dangling.
+        rsLambda = nullptr;
+    }
+} // namespace uniffi::uniffi_russh::cb::callbackinterfaceshellclosedcallbackmethod0
+    // Implementation of callback function calling from Rust to JS CallbackInterfaceShellListenerMethod0
+
+// Callback function: uniffi::uniffi_russh::cb::callbackinterfaceshelllistenermethod0::UniffiCallbackInterfaceShellListenerMethod0
+//
+// We have the following constraints:
+// - we need to pass a function pointer to Rust.
+// - we need a jsi::Runtime and jsi::Function to call into JS.
+// - function pointers can't store state, so we can't use a lamda.
+//
+// For this, we store a lambda as a global, as `rsLambda`. The `callback` function calls
+// the lambda, which itself calls the `body` which then calls into JS.
+//
+// We then give the `callback` function pointer to Rust which will call the lambda sometime in the
+// future.
+namespace uniffi::uniffi_russh::cb::callbackinterfaceshelllistenermethod0 {
+    using namespace facebook;
+
+    // We need to store a lambda in a global so we can call it from
+    // a function pointer. The function pointer is passed to Rust.
+    static std::function<void(uint64_t, RustBuffer, void *, RustCallStatus*)> rsLambda = nullptr;
+
+    // This is the main body of the callback. It's called from the lambda,
+    // which itself is called from the callback function which is passed to Rust.
+    static void body(jsi::Runtime &rt,
+                     std::shared_ptr<uniffi_runtime::UniffiCallInvoker> callInvoker,
+                     std::shared_ptr<jsi::Value> callbackValue
+            ,uint64_t rs_uniffiHandle
+            ,RustBuffer rs_ev
+            ,void * rs_uniffiOutReturn, RustCallStatus* uniffi_call_status) {
+
+        // Convert the arguments from Rust, into jsi::Values.
+        // We'll use the Bridging class to do this…
+        auto js_uniffiHandle = uniffi_jsi::Bridging<uint64_t>::toJs(rt, callInvoker, rs_uniffiHandle);
+        auto js_ev = uniffi::uniffi_russh::Bridging<RustBuffer>::toJs(rt, callInvoker, rs_ev);
+
+        // Now we are ready to call the callback.
+        // We are already on the JS thread, because this `body` function was
+        // invoked from the CallInvoker.
+        try {
+            // Getting the callback function
+            auto cb = callbackValue->asObject(rt).asFunction(rt);
+            auto uniffiResult = cb.call(rt, js_uniffiHandle, js_ev
+            );
+
+            // Now copy the result back from JS into the RustCallStatus object.
+            uniffi::uniffi_russh::Bridging<RustCallStatus>::copyFromJs(rt, callInvoker, uniffiResult, uniffi_call_status);
+
+            if (uniffi_call_status->code != UNIFFI_CALL_STATUS_OK) {
+                // The JS callback finished abnormally, so we cannot retrieve the return value.
+                return;
+            }
+
+            
+        } catch (const jsi::JSError &error) {
+            std::cout << "Error in callback UniffiCallbackInterfaceShellListenerMethod0: "
+                    << error.what() << std::endl;
+            throw error;
         }
-    );
-    props["ubrn_uniffi_uniffi_russh_fn_clone_sshconnection"] = jsi::Function::createFromHostFunction(
-        rt,
-        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_clone_sshconnection"),
-        1,
-        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
-            return this->cpp_uniffi_uniffi_russh_fn_clone_sshconnection(rt, thisVal, args, count);
+    }
+
+    static void callback(uint64_t rs_uniffiHandle, RustBuffer rs_ev, void * rs_uniffiOutReturn, RustCallStatus* uniffi_call_status) {
+        // If the runtime has shutdown, then there is no point in trying to
+        // call into Javascript. BUT how do we tell if the runtime has shutdown?
+        //
+        // Answer: the module destructor calls into callback `cleanup` method,
+        // which nulls out the rsLamda.
+        //
+        // If rsLamda is null, then there is no runtime to call into.
+        if (rsLambda == nullptr) {
+            // This only occurs when destructors are calling into Rust free/drop,
+            // which causes the JS callback to be dropped.
+            return;
         }
-    );
-    props["ubrn_uniffi_uniffi_russh_fn_free_sshconnection"] = jsi::Function::createFromHostFunction(
-        rt,
-        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_free_sshconnection"),
-        1,
-        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
-            return this->cpp_uniffi_uniffi_russh_fn_free_sshconnection(rt, thisVal, args, count);
+
+        // The runtime, the actual callback jsi::funtion, and the callInvoker
+        // are all in the lambda.
+        rsLambda(
+            rs_uniffiHandle, 
+            rs_ev, 
+            rs_uniffiOutReturn, uniffi_call_status);
+    }
+
+    static UniffiCallbackInterfaceShellListenerMethod0
+    makeCallbackFunction( // uniffi::uniffi_russh::cb::callbackinterfaceshelllistenermethod0
+                    jsi::Runtime &rt,
+                     std::shared_ptr<uniffi_runtime::UniffiCallInvoker> callInvoker,
+                     const jsi::Value &value) {
+        if (rsLambda != nullptr) {
+            // `makeCallbackFunction` is called in two circumstances:
+            //
+            // 1. at startup, when initializing callback interface vtables.
+            // 2. when polling futures. This happens at least once per future that is
+            //    exposed to Javascript. We know that this is always the same function,
+            //    `uniffiFutureContinuationCallback` in `async-rust-calls.ts`.
+            //
+            // We can therefore return the callback function without making anything
+            // new if we've been initialized already.
+            return callback;
         }
-    );
-    props["ubrn_uniffi_uniffi_russh_fn_method_sshconnection_disconnect"] = jsi::Function::createFromHostFunction(
+        auto callbackFunction = value.asObject(rt).asFunction(rt);
+        auto callbackValue = std::make_shared<jsi::Value>(rt, callbackFunction);
+        rsLambda = [&rt, callInvoker, callbackValue](uint64_t rs_uniffiHandle, RustBuffer rs_ev, void * rs_uniffiOutReturn, RustCallStatus* uniffi_call_status) {
+                // We immediately make a lambda which will do the work of transforming the
+                // arguments into JSI values and calling the callback.
+                uniffi_runtime::UniffiCallFunc jsLambda = [
+                    callInvoker,
+                    callbackValue
+                    , rs_uniffiHandle
+                    , rs_ev
+                    , rs_uniffiOutReturn, uniffi_call_status](jsi::Runtime &rt) mutable {
+                    body(rt, callInvoker, callbackValue
+                        , rs_uniffiHandle
+                        , rs_ev
+                        , rs_uniffiOutReturn, uniffi_call_status);
+                };
+                // We'll then call that lambda from the callInvoker which will
+                // look after calling it on the correct thread.
+                callInvoker->invokeBlocking(rt, jsLambda);
+        };
+        return callback;
+    }
+
+    // This method is called from the destructor of NativeUniffiRussh, which only happens
+    // when the jsi::Runtime is being destroyed.
+    static void cleanup() {
+        // The lambda holds a reference to the the Runtime, so when this is nulled out,
+        // then the pointer will no longer be left dangling.
+        rsLambda = nullptr;
+    }
+} // namespace uniffi::uniffi_russh::cb::callbackinterfaceshelllistenermethod0
+namespace uniffi::uniffi_russh {
+using namespace facebook;
+using CallInvoker = uniffi_runtime::UniffiCallInvoker;
+
+template <> struct Bridging<UniffiVTableCallbackInterfaceAuthBannerCallback> {
+  static UniffiVTableCallbackInterfaceAuthBannerCallback fromJs(jsi::Runtime &rt,
+    std::shared_ptr<CallInvoker> callInvoker,
+    const jsi::Value &jsValue
+  ) {
+    // Check if the input is an object
+    if (!jsValue.isObject()) {
+      throw jsi::JSError(rt, "Expected an object for UniffiVTableCallbackInterfaceAuthBannerCallback");
+    }
+
+    // Get the object from the jsi::Value
+    auto jsObject = jsValue.getObject(rt);
+
+    // Create the vtable struct
+    UniffiVTableCallbackInterfaceAuthBannerCallback rsObject;
+
+    // Create the vtable from the js callbacks.
+    rsObject.on_change = uniffi::uniffi_russh::cb::callbackinterfaceauthbannercallbackmethod0::makeCallbackFunction(
+          rt, callInvoker, jsObject.getProperty(rt, "onChange")
+        );
+    rsObject.uniffi_free = uniffi::uniffi_russh::st::vtablecallbackinterfaceauthbannercallback::vtablecallbackinterfaceauthbannercallback::free::makeCallbackFunction(
+          rt, callInvoker, jsObject.getProperty(rt, "uniffiFree")
+        );
+
+    return rsObject;
+  }
+};
+
+} // namespace uniffi::uniffi_russh
+namespace uniffi::uniffi_russh {
+using namespace facebook;
+using CallInvoker = uniffi_runtime::UniffiCallInvoker;
+
+template <> struct Bridging<UniffiVTableCallbackInterfaceCastPlayerStatusCallback> {
+  static UniffiVTableCallbackInterfaceCastPlayerStatusCallback fromJs(jsi::Runtime &rt,
+    std::shared_ptr<CallInvoker> callInvoker,
+    const jsi::Value &jsValue
+  ) {
+    // Check if the input is an object
+    if (!jsValue.isObject()) {
+      throw jsi::JSError(rt, "Expected an object for UniffiVTableCallbackInterfaceCastPlayerStatusCallback");
+    }
+
+    // Get the object from the jsi::Value
+    auto jsObject = jsValue.getObject(rt);
+
+    // Create the vtable struct
+    UniffiVTableCallbackInterfaceCastPlayerStatusCallback rsObject;
+
+    // Create the vtable from the js callbacks.
+    rsObject.on_change = uniffi::uniffi_russh::cb::callbackinterfacecastplayerstatuscallbackmethod0::makeCallbackFunction(
+          rt, callInvoker, jsObject.getProperty(rt, "onChange")
+        );
+    rsObject.uniffi_free = uniffi::uniffi_russh::st::vtablecallbackinterfacecastplayerstatuscallback::vtablecallbackinterfacecastplayerstatuscallback::free::makeCallbackFunction(
+          rt, callInvoker, jsObject.getProperty(rt, "uniffiFree")
+        );
+
+    return rsObject;
+  }
+};
+
+} // namespace uniffi::uniffi_russh
+namespace uniffi::uniffi_russh {
+using namespace facebook;
+using CallInvoker = uniffi_runtime::UniffiCallInvoker;
+
+template <> struct Bridging<UniffiVTableCallbackInterfaceChannelPauseCallback> {
+  static UniffiVTableCallbackInterfaceChannelPauseCallback fromJs(jsi::Runtime &rt,
+    std::shared_ptr<CallInvoker> callInvoker,
+    const jsi::Value &jsValue
+  ) {
+    // Check if the input is an object
+    if (!jsValue.isObject()) {
+      throw jsi::JSError(rt, "Expected an object for UniffiVTableCallbackInterfaceChannelPauseCallback");
+    }
+
+    // Get the object from the jsi::Value
+    auto jsObject = jsValue.getObject(rt);
+
+    // Create the vtable struct
+    UniffiVTableCallbackInterfaceChannelPauseCallback rsObject;
+
+    // Create the vtable from the js callbacks.
+    rsObject.on_change = uniffi::uniffi_russh::cb::callbackinterfacechannelpausecallbackmethod0::makeCallbackFunction(
+          rt, callInvoker, jsObject.getProperty(rt, "onChange")
+        );
+    rsObject.uniffi_free = uniffi::uniffi_russh::st::vtablecallbackinterfacechannelpausecallback::vtablecallbackinterfacechannelpausecallback::free::makeCallbackFunction(
+          rt, callInvoker, jsObject.getProperty(rt, "uniffiFree")
+        );
+
+    return rsObject;
+  }
+};
+
+} // namespace uniffi::uniffi_russh
+namespace uniffi::uniffi_russh {
+using namespace facebook;
+using CallInvoker = uniffi_runtime::UniffiCallInvoker;
+
+template <> struct Bridging<UniffiVTableCallbackInterfaceConnectProgressCallback> {
+  static UniffiVTableCallbackInterfaceConnectProgressCallback fromJs(jsi::Runtime &rt,
+    std::shared_ptr<CallInvoker> callInvoker,
+    const jsi::Value &jsValue
+  ) {
+    // Check if the input is an object
+    if (!jsValue.isObject()) {
+      throw jsi::JSError(rt, "Expected an object for UniffiVTableCallbackInterfaceConnectProgressCallback");
+    }
+
+    // Get the object from the jsi::Value
+    auto jsObject = jsValue.getObject(rt);
+
+    // Create the vtable struct
+    UniffiVTableCallbackInterfaceConnectProgressCallback rsObject;
+
+    // Create the vtable from the js callbacks.
+    rsObject.on_change = uniffi::uniffi_russh::cb::callbackinterfaceconnectprogresscallbackmethod0::makeCallbackFunction(
+          rt, callInvoker, jsObject.getProperty(rt, "onChange")
+        );
+    rsObject.uniffi_free = uniffi::uniffi_russh::st::vtablecallbackinterfaceconnectprogresscallback::vtablecallbackinterfaceconnectprogresscallback::free::makeCallbackFunction(
+          rt, callInvoker, jsObject.getProperty(rt, "uniffiFree")
+        );
+
+    return rsObject;
+  }
+};
+
+} // namespace uniffi::uniffi_russh
+namespace uniffi::uniffi_russh {
+using namespace facebook;
+using CallInvoker = uniffi_runtime::UniffiCallInvoker;
+
+template <> struct Bridging<UniffiVTableCallbackInterfaceConnectionDisconnectedCallback> {
+  static UniffiVTableCallbackInterfaceConnectionDisconnectedCallback fromJs(jsi::Runtime &rt,
+    std::shared_ptr<CallInvoker> callInvoker,
+    const jsi::Value &jsValue
+  ) {
+    // Check if the input is an object
+    if (!jsValue.isObject()) {
+      throw jsi::JSError(rt, "Expected an object for UniffiVTableCallbackInterfaceConnectionDisconnectedCallback");
+    }
+
+    // Get the object from the jsi::Value
+    auto jsObject = jsValue.getObject(rt);
+
+    // Create the vtable struct
+    UniffiVTableCallbackInterfaceConnectionDisconnectedCallback rsObject;
+
+    // Create the vtable from the js callbacks.
+    rsObject.on_change = uniffi::uniffi_russh::cb::callbackinterfaceconnectiondisconnectedcallbackmethod0::makeCallbackFunction(
+          rt, callInvoker, jsObject.getProperty(rt, "onChange")
+        );
+    rsObject.uniffi_free = uniffi::uniffi_russh::st::vtablecallbackinterfaceconnectiondisconnectedcallback::vtablecallbackinterfaceconnectiondisconnectedcallback::free::makeCallbackFunction(
+          rt, callInvoker, jsObject.getProperty(rt, "uniffiFree")
+        );
+
+    return rsObject;
+  }
+};
+
+} // namespace uniffi::uniffi_russh
+namespace uniffi::uniffi_russh {
+using namespace facebook;
+using CallInvoker = uniffi_runtime::UniffiCallInvoker;
+
+template <> struct Bridging<UniffiVTableCallbackInterfaceConnectionEventCallback> {
+  static UniffiVTableCallbackInterfaceConnectionEventCallback fromJs(jsi::Runtime &rt,
+    std::shared_ptr<CallInvoker> callInvoker,
+    const jsi::Value &jsValue
+  ) {
+    // Check if the input is an object
+    if (!jsValue.isObject()) {
+      throw jsi::JSError(rt, "Expected an object for UniffiVTableCallbackInterfaceConnectionEventCallback");
+    }
+
+    // Get the object from the jsi::Value
+    auto jsObject = jsValue.getObject(rt);
+
+    // Create the vtable struct
+    UniffiVTableCallbackInterfaceConnectionEventCallback rsObject;
+
+    // Create the vtable from the js callbacks.
+    rsObject.on_change = uniffi::uniffi_russh::cb::callbackinterfaceconnectioneventcallbackmethod0::makeCallbackFunction(
+          rt, callInvoker, jsObject.getProperty(rt, "onChange")
+        );
+    rsObject.uniffi_free = uniffi::uniffi_russh::st::vtablecallbackinterfaceconnectioneventcallback::vtablecallbackinterfaceconnectioneventcallback::free::makeCallbackFunction(
+          rt, callInvoker, jsObject.getProperty(rt, "uniffiFree")
+        );
+
+    return rsObject;
+  }
+};
+
+} // namespace uniffi::uniffi_russh
+namespace uniffi::uniffi_russh {
+using namespace facebook;
+using CallInvoker = uniffi_runtime::UniffiCallInvoker;
+
+template <> struct Bridging<UniffiVTableCallbackInterfaceConnectionMetricsCallback> {
+  static UniffiVTableCallbackInterfaceConnectionMetricsCallback fromJs(jsi::Runtime &rt,
+    std::shared_ptr<CallInvoker> callInvoker,
+    const jsi::Value &jsValue
+  ) {
+    // Check if the input is an object
+    if (!jsValue.isObject()) {
+      throw jsi::JSError(rt, "Expected an object for UniffiVTableCallbackInterfaceConnectionMetricsCallback");
+    }
+
+    // Get the object from the jsi::Value
+    auto jsObject = jsValue.getObject(rt);
+
+    // Create the vtable struct
+    UniffiVTableCallbackInterfaceConnectionMetricsCallback rsObject;
+
+    // Create the vtable from the js callbacks.
+    rsObject.on_change = uniffi::uniffi_russh::cb::callbackinterfaceconnectionmetricscallbackmethod0::makeCallbackFunction(
+          rt, callInvoker, jsObject.getProperty(rt, "onChange")
+        );
+    rsObject.uniffi_free = uniffi::uniffi_russh::st::vtablecallbackinterfaceconnectionmetricscallback::vtablecallbackinterfaceconnectionmetricscallback::free::makeCallbackFunction(
+          rt, callInvoker, jsObject.getProperty(rt, "uniffiFree")
+        );
+
+    return rsObject;
+  }
+};
+
+} // namespace uniffi::uniffi_russh
+namespace uniffi::uniffi_russh {
+using namespace facebook;
+using CallInvoker = uniffi_runtime::UniffiCallInvoker;
+
+template <> struct Bridging<UniffiVTableCallbackInterfaceServerKeyCallback> {
+  static UniffiVTableCallbackInterfaceServerKeyCallback fromJs(jsi::Runtime &rt,
+    std::shared_ptr<CallInvoker> callInvoker,
+    const jsi::Value &jsValue
+  ) {
+    // Check if the input is an object
+    if (!jsValue.isObject()) {
+      throw jsi::JSError(rt, "Expected an object for UniffiVTableCallbackInterfaceServerKeyCallback");
+    }
+
+    // Get the object from the jsi::Value
+    auto jsObject = jsValue.getObject(rt);
+
+    // Create the vtable struct
+    UniffiVTableCallbackInterfaceServerKeyCallback rsObject;
+
+    // Create the vtable from the js callbacks.
+    rsObject.on_change = uniffi::uniffi_russh::cb::callbackinterfaceserverkeycallbackmethod0::makeCallbackFunction(
+          rt, callInvoker, jsObject.getProperty(rt, "onChange")
+        );
+    rsObject.uniffi_free = uniffi::uniffi_russh::st::vtablecallbackinterfaceserverkeycallback::vtablecallbackinterfaceserverkeycallback::free::makeCallbackFunction(
+          rt, callInvoker, jsObject.getProperty(rt, "uniffiFree")
+        );
+
+    return rsObject;
+  }
+};
+
+} // namespace uniffi::uniffi_russh
+namespace uniffi::uniffi_russh {
+using namespace facebook;
+using CallInvoker = uniffi_runtime::UniffiCallInvoker;
+
+template <> struct Bridging<UniffiVTableCallbackInterfaceShellClosedCallback> {
+  static UniffiVTableCallbackInterfaceShellClosedCallback fromJs(jsi::Runtime &rt,
+    std::shared_ptr<CallInvoker> callInvoker,
+    const jsi::Value &jsValue
+  ) {
+    // Check if the input is an object
+    if (!jsValue.isObject()) {
+      throw jsi::JSError(rt, "Expected an object for UniffiVTableCallbackInterfaceShellClosedCallback");
+    }
+
+    // Get the object from the jsi::Value
+    auto jsObject = jsValue.getObject(rt);
+
+    // Create the vtable struct
+    UniffiVTableCallbackInterfaceShellClosedCallback rsObject;
+
+    // Create the vtable from the js callbacks.
+    rsObject.on_change = uniffi::uniffi_russh::cb::callbackinterfaceshellclosedcallbackmethod0::makeCallbackFunction(
+          rt, callInvoker, jsObject.getProperty(rt, "onChange")
+        );
+    rsObject.uniffi_free = uniffi::uniffi_russh::st::vtablecallbackinterfaceshellclosedcallback::vtablecallbackinterfaceshellclosedcallback::free::makeCallbackFunction(
+          rt, callInvoker, jsObject.getProperty(rt, "uniffiFree")
+        );
+
+    return rsObject;
+  }
+};
+
+} // namespace uniffi::uniffi_russh
+namespace uniffi::uniffi_russh {
+using namespace facebook;
+using CallInvoker = uniffi_runtime::UniffiCallInvoker;
+
+template <> struct Bridging<UniffiVTableCallbackInterfaceShellListener> {
+  static UniffiVTableCallbackInterfaceShellListener fromJs(jsi::Runtime &rt,
+    std::shared_ptr<CallInvoker> callInvoker,
+    const jsi::Value &jsValue
+  ) {
+    // Check if the input is an object
+    if (!jsValue.isObject()) {
+      throw jsi::JSError(rt, "Expected an object for UniffiVTableCallbackInterfaceShellListener");
+    }
+
+    // Get the object from the jsi::Value
+    auto jsObject = jsValue.getObject(rt);
+
+    // Create the vtable struct
+    UniffiVTableCallbackInterfaceShellListener rsObject;
+
+    // Create the vtable from the js callbacks.
+    rsObject.on_event = uniffi::uniffi_russh::cb::callbackinterfaceshelllistenermethod0::makeCallbackFunction(
+          rt, callInvoker, jsObject.getProperty(rt, "onEvent")
+        );
+    rsObject.uniffi_free = uniffi::uniffi_russh::st::vtablecallbackinterfaceshelllistener::vtablecallbackinterfaceshelllistener::free::makeCallbackFunction(
+          rt, callInvoker, jsObject.getProperty(rt, "uniffiFree")
+        );
+
+    return rsObject;
+  }
+};
+
+} // namespace uniffi::uniffi_russh
+
+
+namespace uniffi::uniffi_russh {
+using namespace facebook;
+using CallInvoker = uniffi_runtime::UniffiCallInvoker;
+
+template <> struct Bridging<UniffiRustFutureContinuationCallback> {
+  static UniffiRustFutureContinuationCallback fromJs(
+    jsi::Runtime &rt,
+    std::shared_ptr<CallInvoker> callInvoker,
+    const jsi::Value &value
+  ) {
+    try {
+      return uniffi::uniffi_russh::cb::rustfuturecontinuationcallback::makeCallbackFunction(
+        rt,
+        callInvoker,
+        value
+      );
+    } catch (const std::logic_error &e) {
+      throw jsi::JSError(rt, e.what());
+    }
+  }
+};
+
+} // namespace uniffi::uniffi_russh
+
+NativeUniffiRussh::NativeUniffiRussh(
+    jsi::Runtime &rt,
+    std::shared_ptr<uniffi_runtime::UniffiCallInvoker> invoker
+) : callInvoker(invoker), props() {
+    // Map from Javascript names to the cpp names
+    props["ubrn_uniffi_internal_fn_func_ffi__string_to_byte_length"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_internal_fn_func_ffi__string_to_byte_length"),
+        1,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_internal_fn_func_ffi__string_to_byte_length(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_uniffi_internal_fn_func_ffi__string_to_arraybuffer"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_internal_fn_func_ffi__string_to_arraybuffer"),
+        1,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_internal_fn_func_ffi__string_to_arraybuffer(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_uniffi_internal_fn_func_ffi__arraybuffer_to_string"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_internal_fn_func_ffi__arraybuffer_to_string"),
+        1,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_internal_fn_func_ffi__arraybuffer_to_string(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_uniffi_uniffi_russh_fn_clone_authbannercallback"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_clone_authbannercallback"),
+        1,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_uniffi_russh_fn_clone_authbannercallback(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_uniffi_uniffi_russh_fn_free_authbannercallback"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_free_authbannercallback"),
+        1,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_uniffi_russh_fn_free_authbannercallback(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_uniffi_uniffi_russh_fn_init_callback_vtable_authbannercallback"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_init_callback_vtable_authbannercallback"),
+        1,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_uniffi_russh_fn_init_callback_vtable_authbannercallback(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_uniffi_uniffi_russh_fn_method_authbannercallback_on_change"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_method_authbannercallback_on_change"),
+        2,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_uniffi_russh_fn_method_authbannercallback_on_change(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_uniffi_uniffi_russh_fn_clone_castplayer"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_clone_castplayer"),
+        1,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_uniffi_russh_fn_clone_castplayer(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_uniffi_uniffi_russh_fn_free_castplayer"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_free_castplayer"),
+        1,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_uniffi_russh_fn_free_castplayer(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_uniffi_uniffi_russh_fn_method_castplayer_add_listener"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_method_castplayer_add_listener"),
+        3,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_uniffi_russh_fn_method_castplayer_add_listener(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_uniffi_uniffi_russh_fn_method_castplayer_attach_emulator"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_method_castplayer_attach_emulator"),
+        2,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_uniffi_russh_fn_method_castplayer_attach_emulator(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_uniffi_uniffi_russh_fn_method_castplayer_buffer_stats"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_method_castplayer_buffer_stats"),
+        1,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_uniffi_russh_fn_method_castplayer_buffer_stats(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_uniffi_uniffi_russh_fn_method_castplayer_commands"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_method_castplayer_commands"),
+        1,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_uniffi_russh_fn_method_castplayer_commands(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_uniffi_uniffi_russh_fn_method_castplayer_current_seq"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_method_castplayer_current_seq"),
+        1,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_uniffi_russh_fn_method_castplayer_current_seq(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_uniffi_uniffi_russh_fn_method_castplayer_detach_emulator"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_method_castplayer_detach_emulator"),
+        1,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_uniffi_russh_fn_method_castplayer_detach_emulator(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_uniffi_uniffi_russh_fn_method_castplayer_get_info"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_method_castplayer_get_info"),
+        1,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_uniffi_russh_fn_method_castplayer_get_info(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_uniffi_uniffi_russh_fn_method_castplayer_get_status"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_method_castplayer_get_status"),
+        1,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_uniffi_russh_fn_method_castplayer_get_status(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_uniffi_uniffi_russh_fn_method_castplayer_pause"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_method_castplayer_pause"),
+        1,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_uniffi_russh_fn_method_castplayer_pause(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_uniffi_uniffi_russh_fn_method_castplayer_play"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_method_castplayer_play"),
+        1,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_uniffi_russh_fn_method_castplayer_play(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_uniffi_uniffi_russh_fn_method_castplayer_read_buffer"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_method_castplayer_read_buffer"),
+        3,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_uniffi_russh_fn_method_castplayer_read_buffer(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_uniffi_uniffi_russh_fn_method_castplayer_read_text"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_method_castplayer_read_text"),
+        3,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_uniffi_russh_fn_method_castplayer_read_text(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_uniffi_uniffi_russh_fn_method_castplayer_remove_listener"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_method_castplayer_remove_listener"),
+        2,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_uniffi_russh_fn_method_castplayer_remove_listener(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_uniffi_uniffi_russh_fn_method_castplayer_search"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_method_castplayer_search"),
+        3,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_uniffi_russh_fn_method_castplayer_search(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_uniffi_uniffi_russh_fn_method_castplayer_seek"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_method_castplayer_seek"),
+        2,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_uniffi_russh_fn_method_castplayer_seek(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_uniffi_uniffi_russh_fn_method_castplayer_set_speed"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_method_castplayer_set_speed"),
+        2,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_uniffi_russh_fn_method_castplayer_set_speed(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_uniffi_uniffi_russh_fn_clone_castplayerstatuscallback"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_clone_castplayerstatuscallback"),
+        1,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_uniffi_russh_fn_clone_castplayerstatuscallback(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_uniffi_uniffi_russh_fn_free_castplayerstatuscallback"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_free_castplayerstatuscallback"),
+        1,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_uniffi_russh_fn_free_castplayerstatuscallback(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_uniffi_uniffi_russh_fn_init_callback_vtable_castplayerstatuscallback"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_init_callback_vtable_castplayerstatuscallback"),
+        1,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_uniffi_russh_fn_init_callback_vtable_castplayerstatuscallback(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_uniffi_uniffi_russh_fn_method_castplayerstatuscallback_on_change"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_method_castplayerstatuscallback_on_change"),
+        2,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_uniffi_russh_fn_method_castplayerstatuscallback_on_change(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_uniffi_uniffi_russh_fn_clone_channelpausecallback"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_clone_channelpausecallback"),
+        1,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_uniffi_russh_fn_clone_channelpausecallback(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_uniffi_uniffi_russh_fn_free_channelpausecallback"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_free_channelpausecallback"),
+        1,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_uniffi_russh_fn_free_channelpausecallback(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_uniffi_uniffi_russh_fn_init_callback_vtable_channelpausecallback"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_init_callback_vtable_channelpausecallback"),
+        1,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_uniffi_russh_fn_init_callback_vtable_channelpausecallback(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_uniffi_uniffi_russh_fn_method_channelpausecallback_on_change"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_method_channelpausecallback_on_change"),
+        2,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_uniffi_russh_fn_method_channelpausecallback_on_change(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_uniffi_uniffi_russh_fn_clone_connectaborttoken"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_clone_connectaborttoken"),
+        1,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_uniffi_russh_fn_clone_connectaborttoken(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_uniffi_uniffi_russh_fn_free_connectaborttoken"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_free_connectaborttoken"),
+        1,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_uniffi_russh_fn_free_connectaborttoken(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_uniffi_uniffi_russh_fn_constructor_connectaborttoken_new"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_constructor_connectaborttoken_new"),
+        0,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_uniffi_russh_fn_constructor_connectaborttoken_new(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_uniffi_uniffi_russh_fn_method_connectaborttoken_abort"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_method_connectaborttoken_abort"),
+        1,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_uniffi_russh_fn_method_connectaborttoken_abort(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_uniffi_uniffi_russh_fn_method_connectaborttoken_is_aborted"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_method_connectaborttoken_is_aborted"),
+        1,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_uniffi_russh_fn_method_connectaborttoken_is_aborted(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_uniffi_uniffi_russh_fn_clone_connectprogresscallback"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_clone_connectprogresscallback"),
+        1,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_uniffi_russh_fn_clone_connectprogresscallback(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_uniffi_uniffi_russh_fn_free_connectprogresscallback"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_free_connectprogresscallback"),
+        1,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_uniffi_russh_fn_free_connectprogresscallback(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_uniffi_uniffi_russh_fn_init_callback_vtable_connectprogresscallback"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_init_callback_vtable_connectprogresscallback"),
+        1,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_uniffi_russh_fn_init_callback_vtable_connectprogresscallback(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_uniffi_uniffi_russh_fn_method_connectprogresscallback_on_change"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_method_connectprogresscallback_on_change"),
+        2,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_uniffi_russh_fn_method_connectprogresscallback_on_change(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_uniffi_uniffi_russh_fn_clone_connectiondisconnectedcallback"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_clone_connectiondisconnectedcallback"),
+        1,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_uniffi_russh_fn_clone_connectiondisconnectedcallback(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_uniffi_uniffi_russh_fn_free_connectiondisconnectedcallback"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_free_connectiondisconnectedcallback"),
+        1,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_uniffi_russh_fn_free_connectiondisconnectedcallback(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_uniffi_uniffi_russh_fn_init_callback_vtable_connectiondisconnectedcallback"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_init_callback_vtable_connectiondisconnectedcallback"),
+        1,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_uniffi_russh_fn_init_callback_vtable_connectiondisconnectedcallback(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_uniffi_uniffi_russh_fn_method_connectiondisconnectedcallback_on_change"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_method_connectiondisconnectedcallback_on_change"),
+        2,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_uniffi_russh_fn_method_connectiondisconnectedcallback_on_change(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_uniffi_uniffi_russh_fn_clone_connectioneventcallback"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_clone_connectioneventcallback"),
+        1,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_uniffi_russh_fn_clone_connectioneventcallback(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_uniffi_uniffi_russh_fn_free_connectioneventcallback"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_free_connectioneventcallback"),
+        1,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_uniffi_russh_fn_free_connectioneventcallback(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_uniffi_uniffi_russh_fn_init_callback_vtable_connectioneventcallback"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_init_callback_vtable_connectioneventcallback"),
+        1,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_uniffi_russh_fn_init_callback_vtable_connectioneventcallback(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_uniffi_uniffi_russh_fn_method_connectioneventcallback_on_change"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_method_connectioneventcallback_on_change"),
+        2,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_uniffi_russh_fn_method_connectioneventcallback_on_change(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_uniffi_uniffi_russh_fn_clone_connectionmetricscallback"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_clone_connectionmetricscallback"),
+        1,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_uniffi_russh_fn_clone_connectionmetricscallback(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_uniffi_uniffi_russh_fn_free_connectionmetricscallback"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_free_connectionmetricscallback"),
+        1,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_uniffi_russh_fn_free_connectionmetricscallback(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_uniffi_uniffi_russh_fn_init_callback_vtable_connectionmetricscallback"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_init_callback_vtable_connectionmetricscallback"),
+        1,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_uniffi_russh_fn_init_callback_vtable_connectionmetricscallback(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_uniffi_uniffi_russh_fn_method_connectionmetricscallback_on_change"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_method_connectionmetricscallback_on_change"),
+        2,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_uniffi_russh_fn_method_connectionmetricscallback_on_change(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_uniffi_uniffi_russh_fn_clone_serverkeycallback"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_clone_serverkeycallback"),
+        1,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_uniffi_russh_fn_clone_serverkeycallback(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_uniffi_uniffi_russh_fn_free_serverkeycallback"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_free_serverkeycallback"),
+        1,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_uniffi_russh_fn_free_serverkeycallback(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_uniffi_uniffi_russh_fn_init_callback_vtable_serverkeycallback"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_init_callback_vtable_serverkeycallback"),
+        1,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_uniffi_russh_fn_init_callback_vtable_serverkeycallback(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_uniffi_uniffi_russh_fn_method_serverkeycallback_on_change"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_method_serverkeycallback_on_change"),
+        2,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_uniffi_russh_fn_method_serverkeycallback_on_change(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_uniffi_uniffi_russh_fn_clone_shellclosedcallback"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_clone_shellclosedcallback"),
+        1,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_uniffi_russh_fn_clone_shellclosedcallback(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_uniffi_uniffi_russh_fn_free_shellclosedcallback"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_free_shellclosedcallback"),
+        1,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_uniffi_russh_fn_free_shellclosedcallback(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_uniffi_uniffi_russh_fn_init_callback_vtable_shellclosedcallback"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_init_callback_vtable_shellclosedcallback"),
+        1,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_uniffi_russh_fn_init_callback_vtable_shellclosedcallback(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_uniffi_uniffi_russh_fn_method_shellclosedcallback_on_change"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_method_shellclosedcallback_on_change"),
+        2,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_uniffi_russh_fn_method_shellclosedcallback_on_change(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_uniffi_uniffi_russh_fn_clone_shelllistener"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_clone_shelllistener"),
+        1,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_uniffi_russh_fn_clone_shelllistener(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_uniffi_uniffi_russh_fn_free_shelllistener"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_free_shelllistener"),
+        1,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_uniffi_russh_fn_free_shelllistener(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_uniffi_uniffi_russh_fn_init_callback_vtable_shelllistener"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_init_callback_vtable_shelllistener"),
+        1,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_uniffi_russh_fn_init_callback_vtable_shelllistener(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_uniffi_uniffi_russh_fn_method_shelllistener_on_event"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_method_shelllistener_on_event"),
+        2,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_uniffi_russh_fn_method_shelllistener_on_event(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_uniffi_uniffi_russh_fn_clone_shellsession"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_clone_shellsession"),
+        1,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_uniffi_russh_fn_clone_shellsession(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_uniffi_uniffi_russh_fn_free_shellsession"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_free_shellsession"),
+        1,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_uniffi_russh_fn_free_shellsession(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_uniffi_uniffi_russh_fn_method_shellsession_add_listener"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_method_shellsession_add_listener"),
+        3,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_uniffi_russh_fn_method_shellsession_add_listener(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_uniffi_uniffi_russh_fn_method_shellsession_attach_emulator"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_method_shellsession_attach_emulator"),
+        2,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_uniffi_russh_fn_method_shellsession_attach_emulator(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_uniffi_uniffi_russh_fn_method_shellsession_buffer_stats"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_method_shellsession_buffer_stats"),
+        1,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_uniffi_russh_fn_method_shellsession_buffer_stats(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_uniffi_uniffi_russh_fn_method_shellsession_close"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_method_shellsession_close"),
+        1,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_uniffi_russh_fn_method_shellsession_close(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_uniffi_uniffi_russh_fn_method_shellsession_commands"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_method_shellsession_commands"),
+        1,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_uniffi_russh_fn_method_shellsession_commands(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_uniffi_uniffi_russh_fn_method_shellsession_current_seq"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_method_shellsession_current_seq"),
+        1,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_uniffi_russh_fn_method_shellsession_current_seq(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_uniffi_uniffi_russh_fn_method_shellsession_detach_emulator"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_method_shellsession_detach_emulator"),
+        1,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_uniffi_russh_fn_method_shellsession_detach_emulator(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_uniffi_uniffi_russh_fn_method_shellsession_get_info"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_method_shellsession_get_info"),
+        1,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_uniffi_russh_fn_method_shellsession_get_info(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_uniffi_uniffi_russh_fn_method_shellsession_read_buffer"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_method_shellsession_read_buffer"),
+        3,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_uniffi_russh_fn_method_shellsession_read_buffer(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_uniffi_uniffi_russh_fn_method_shellsession_read_text"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_method_shellsession_read_text"),
+        3,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_uniffi_russh_fn_method_shellsession_read_text(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_uniffi_uniffi_russh_fn_method_shellsession_remove_listener"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_method_shellsession_remove_listener"),
+        2,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_uniffi_russh_fn_method_shellsession_remove_listener(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_uniffi_uniffi_russh_fn_method_shellsession_resize"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_method_shellsession_resize"),
+        5,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_uniffi_russh_fn_method_shellsession_resize(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_uniffi_uniffi_russh_fn_method_shellsession_search"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_method_shellsession_search"),
+        3,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_uniffi_russh_fn_method_shellsession_search(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_uniffi_uniffi_russh_fn_method_shellsession_send_break"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_method_shellsession_send_break"),
+        2,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_uniffi_russh_fn_method_shellsession_send_break(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_uniffi_uniffi_russh_fn_method_shellsession_send_data"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_method_shellsession_send_data"),
+        2,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_uniffi_russh_fn_method_shellsession_send_data(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_uniffi_uniffi_russh_fn_method_shellsession_send_signal"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_method_shellsession_send_signal"),
+        2,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_uniffi_russh_fn_method_shellsession_send_signal(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_uniffi_uniffi_russh_fn_method_shellsession_set_buffer_policy"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_method_shellsession_set_buffer_policy"),
+        2,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_uniffi_russh_fn_method_shellsession_set_buffer_policy(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_uniffi_uniffi_russh_fn_method_shellsession_start_recording"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_method_shellsession_start_recording"),
+        3,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_uniffi_russh_fn_method_shellsession_start_recording(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_uniffi_uniffi_russh_fn_method_shellsession_stop_recording"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_method_shellsession_stop_recording"),
+        1,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_uniffi_russh_fn_method_shellsession_stop_recording(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_uniffi_uniffi_russh_fn_clone_sshconnection"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_clone_sshconnection"),
+        1,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_uniffi_russh_fn_clone_sshconnection(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_uniffi_uniffi_russh_fn_free_sshconnection"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_free_sshconnection"),
+        1,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_uniffi_russh_fn_free_sshconnection(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_uniffi_uniffi_russh_fn_method_sshconnection_disconnect"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_method_sshconnection_disconnect"),
+        1,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_uniffi_russh_fn_method_sshconnection_disconnect(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_uniffi_uniffi_russh_fn_method_sshconnection_get_info"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_method_sshconnection_get_info"),
+        1,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_uniffi_russh_fn_method_sshconnection_get_info(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_uniffi_uniffi_russh_fn_method_sshconnection_get_metrics"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_method_sshconnection_get_metrics"),
+        1,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_uniffi_russh_fn_method_sshconnection_get_metrics(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_uniffi_uniffi_russh_fn_method_sshconnection_install_bundled_terminfo"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_method_sshconnection_install_bundled_terminfo"),
+        2,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_uniffi_russh_fn_method_sshconnection_install_bundled_terminfo(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_uniffi_uniffi_russh_fn_method_sshconnection_install_terminfo"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_method_sshconnection_install_terminfo"),
+        3,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_uniffi_russh_fn_method_sshconnection_install_terminfo(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_uniffi_uniffi_russh_fn_method_sshconnection_rekey"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_method_sshconnection_rekey"),
+        1,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_uniffi_russh_fn_method_sshconnection_rekey(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_uniffi_uniffi_russh_fn_method_sshconnection_start_shell"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_method_sshconnection_start_shell"),
+        2,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_uniffi_russh_fn_method_sshconnection_start_shell(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_uniffi_uniffi_russh_fn_clone_terminalemulator"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_clone_terminalemulator"),
+        1,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_uniffi_russh_fn_clone_terminalemulator(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_uniffi_uniffi_russh_fn_free_terminalemulator"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_free_terminalemulator"),
+        1,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_uniffi_russh_fn_free_terminalemulator(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_uniffi_uniffi_russh_fn_constructor_terminalemulator_new"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_constructor_terminalemulator_new"),
+        3,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_uniffi_russh_fn_constructor_terminalemulator_new(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_uniffi_uniffi_russh_fn_method_terminalemulator_feed"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_method_terminalemulator_feed"),
+        2,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_uniffi_russh_fn_method_terminalemulator_feed(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_uniffi_uniffi_russh_fn_method_terminalemulator_resize"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_method_terminalemulator_resize"),
+        3,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_uniffi_russh_fn_method_terminalemulator_resize(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_uniffi_uniffi_russh_fn_method_terminalemulator_scrollback_len"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_method_terminalemulator_scrollback_len"),
+        1,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_uniffi_russh_fn_method_terminalemulator_scrollback_len(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_uniffi_uniffi_russh_fn_method_terminalemulator_scrollback_lines"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_method_terminalemulator_scrollback_lines"),
+        3,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_uniffi_russh_fn_method_terminalemulator_scrollback_lines(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_uniffi_uniffi_russh_fn_method_terminalemulator_snapshot"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_method_terminalemulator_snapshot"),
+        1,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_uniffi_russh_fn_method_terminalemulator_snapshot(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_uniffi_uniffi_russh_fn_method_terminalemulator_take_damage"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_method_terminalemulator_take_damage"),
+        1,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_uniffi_russh_fn_method_terminalemulator_take_damage(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_uniffi_uniffi_russh_fn_method_terminalemulator_take_responses"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_method_terminalemulator_take_responses"),
+        1,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_uniffi_russh_fn_method_terminalemulator_take_responses(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_uniffi_uniffi_russh_fn_func_connect"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_func_connect"),
+        1,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_uniffi_russh_fn_func_connect(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_uniffi_uniffi_russh_fn_func_extract_public_key"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_func_extract_public_key"),
+        1,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_uniffi_russh_fn_func_extract_public_key(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_uniffi_uniffi_russh_fn_func_generate_key_pair"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_func_generate_key_pair"),
+        1,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_uniffi_russh_fn_func_generate_key_pair(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_uniffi_uniffi_russh_fn_func_memory_usage"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_func_memory_usage"),
+        0,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_uniffi_russh_fn_func_memory_usage(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_uniffi_uniffi_russh_fn_func_open_cast_player"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_func_open_cast_player"),
+        2,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_uniffi_russh_fn_func_open_cast_player(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_uniffi_uniffi_russh_fn_func_public_key_fingerprints"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_func_public_key_fingerprints"),
+        1,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_uniffi_russh_fn_func_public_key_fingerprints(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_uniffi_uniffi_russh_fn_func_set_memory_budget"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_func_set_memory_budget"),
+        1,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_uniffi_russh_fn_func_set_memory_budget(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_uniffi_uniffi_russh_fn_func_validate_private_key"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_func_validate_private_key"),
+        1,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_uniffi_russh_fn_func_validate_private_key(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_ffi_uniffi_russh_rust_future_poll_u8"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_ffi_uniffi_russh_rust_future_poll_u8"),
+        3,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_ffi_uniffi_russh_rust_future_poll_u8(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_ffi_uniffi_russh_rust_future_cancel_u8"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_ffi_uniffi_russh_rust_future_cancel_u8"),
+        1,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_ffi_uniffi_russh_rust_future_cancel_u8(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_ffi_uniffi_russh_rust_future_free_u8"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_ffi_uniffi_russh_rust_future_free_u8"),
+        1,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_ffi_uniffi_russh_rust_future_free_u8(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_ffi_uniffi_russh_rust_future_complete_u8"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_ffi_uniffi_russh_rust_future_complete_u8"),
+        1,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_ffi_uniffi_russh_rust_future_complete_u8(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_ffi_uniffi_russh_rust_future_poll_i8"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_ffi_uniffi_russh_rust_future_poll_i8"),
+        3,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_ffi_uniffi_russh_rust_future_poll_i8(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_ffi_uniffi_russh_rust_future_cancel_i8"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_ffi_uniffi_russh_rust_future_cancel_i8"),
+        1,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_ffi_uniffi_russh_rust_future_cancel_i8(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_ffi_uniffi_russh_rust_future_free_i8"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_ffi_uniffi_russh_rust_future_free_i8"),
+        1,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_ffi_uniffi_russh_rust_future_free_i8(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_ffi_uniffi_russh_rust_future_complete_i8"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_ffi_uniffi_russh_rust_future_complete_i8"),
+        1,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_ffi_uniffi_russh_rust_future_complete_i8(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_ffi_uniffi_russh_rust_future_poll_u16"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_ffi_uniffi_russh_rust_future_poll_u16"),
+        3,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_ffi_uniffi_russh_rust_future_poll_u16(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_ffi_uniffi_russh_rust_future_cancel_u16"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_ffi_uniffi_russh_rust_future_cancel_u16"),
+        1,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_ffi_uniffi_russh_rust_future_cancel_u16(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_ffi_uniffi_russh_rust_future_free_u16"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_ffi_uniffi_russh_rust_future_free_u16"),
+        1,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_ffi_uniffi_russh_rust_future_free_u16(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_ffi_uniffi_russh_rust_future_complete_u16"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_ffi_uniffi_russh_rust_future_complete_u16"),
+        1,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_ffi_uniffi_russh_rust_future_complete_u16(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_ffi_uniffi_russh_rust_future_poll_i16"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_ffi_uniffi_russh_rust_future_poll_i16"),
+        3,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_ffi_uniffi_russh_rust_future_poll_i16(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_ffi_uniffi_russh_rust_future_cancel_i16"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_ffi_uniffi_russh_rust_future_cancel_i16"),
+        1,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_ffi_uniffi_russh_rust_future_cancel_i16(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_ffi_uniffi_russh_rust_future_free_i16"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_ffi_uniffi_russh_rust_future_free_i16"),
+        1,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_ffi_uniffi_russh_rust_future_free_i16(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_ffi_uniffi_russh_rust_future_complete_i16"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_ffi_uniffi_russh_rust_future_complete_i16"),
+        1,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_ffi_uniffi_russh_rust_future_complete_i16(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_ffi_uniffi_russh_rust_future_poll_u32"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_ffi_uniffi_russh_rust_future_poll_u32"),
+        3,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_ffi_uniffi_russh_rust_future_poll_u32(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_ffi_uniffi_russh_rust_future_cancel_u32"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_ffi_uniffi_russh_rust_future_cancel_u32"),
+        1,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_ffi_uniffi_russh_rust_future_cancel_u32(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_ffi_uniffi_russh_rust_future_free_u32"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_ffi_uniffi_russh_rust_future_free_u32"),
+        1,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_ffi_uniffi_russh_rust_future_free_u32(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_ffi_uniffi_russh_rust_future_complete_u32"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_ffi_uniffi_russh_rust_future_complete_u32"),
+        1,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_ffi_uniffi_russh_rust_future_complete_u32(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_ffi_uniffi_russh_rust_future_poll_i32"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_ffi_uniffi_russh_rust_future_poll_i32"),
+        3,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_ffi_uniffi_russh_rust_future_poll_i32(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_ffi_uniffi_russh_rust_future_cancel_i32"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_ffi_uniffi_russh_rust_future_cancel_i32"),
+        1,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_ffi_uniffi_russh_rust_future_cancel_i32(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_ffi_uniffi_russh_rust_future_free_i32"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_ffi_uniffi_russh_rust_future_free_i32"),
+        1,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_ffi_uniffi_russh_rust_future_free_i32(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_ffi_uniffi_russh_rust_future_complete_i32"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_ffi_uniffi_russh_rust_future_complete_i32"),
+        1,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_ffi_uniffi_russh_rust_future_complete_i32(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_ffi_uniffi_russh_rust_future_poll_u64"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_ffi_uniffi_russh_rust_future_poll_u64"),
+        3,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_ffi_uniffi_russh_rust_future_poll_u64(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_ffi_uniffi_russh_rust_future_cancel_u64"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_ffi_uniffi_russh_rust_future_cancel_u64"),
+        1,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_ffi_uniffi_russh_rust_future_cancel_u64(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_ffi_uniffi_russh_rust_future_free_u64"] = jsi::Function::createFromHostFunction(
         rt,
-        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_method_sshconnection_disconnect"),
+        jsi::PropNameID::forAscii(rt, "ubrn_ffi_uniffi_russh_rust_future_free_u64"),
         1,
         [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
-            return this->cpp_uniffi_uniffi_russh_fn_method_sshconnection_disconnect(rt, thisVal, args, count);
+            return this->cpp_ffi_uniffi_russh_rust_future_free_u64(rt, thisVal, args, count);
         }
     );
-    props["ubrn_uniffi_uniffi_russh_fn_method_sshconnection_get_info"] = jsi::Function::createFromHostFunction(
+    props["ubrn_ffi_uniffi_russh_rust_future_complete_u64"] = jsi::Function::createFromHostFunction(
         rt,
-        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_method_sshconnection_get_info"),
+        jsi::PropNameID::forAscii(rt, "ubrn_ffi_uniffi_russh_rust_future_complete_u64"),
         1,
         [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
-            return this->cpp_uniffi_uniffi_russh_fn_method_sshconnection_get_info(rt, thisVal, args, count);
+            return this->cpp_ffi_uniffi_russh_rust_future_complete_u64(rt, thisVal, args, count);
         }
     );
-    props["ubrn_uniffi_uniffi_russh_fn_method_sshconnection_start_shell"] = jsi::Function::createFromHostFunction(
+    props["ubrn_ffi_uniffi_russh_rust_future_poll_i64"] = jsi::Function::createFromHostFunction(
         rt,
-        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_method_sshconnection_start_shell"),
-        2,
+        jsi::PropNameID::forAscii(rt, "ubrn_ffi_uniffi_russh_rust_future_poll_i64"),
+        3,
         [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
-            return this->cpp_uniffi_uniffi_russh_fn_method_sshconnection_start_shell(rt, thisVal, args, count);
+            return this->cpp_ffi_uniffi_russh_rust_future_poll_i64(rt, thisVal, args, count);
         }
     );
-    props["ubrn_uniffi_uniffi_russh_fn_func_connect"] = jsi::Function::createFromHostFunction(
+    props["ubrn_ffi_uniffi_russh_rust_future_cancel_i64"] = jsi::Function::createFromHostFunction(
         rt,
-        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_func_connect"),
+        jsi::PropNameID::forAscii(rt, "ubrn_ffi_uniffi_russh_rust_future_cancel_i64"),
         1,
         [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
-            return this->cpp_uniffi_uniffi_russh_fn_func_connect(rt, thisVal, args, count);
+            return this->cpp_ffi_uniffi_russh_rust_future_cancel_i64(rt, thisVal, args, count);
         }
     );
-    props["ubrn_uniffi_uniffi_russh_fn_func_extract_public_key"] = jsi::Function::createFromHostFunction(
+    props["ubrn_ffi_uniffi_russh_rust_future_free_i64"] = jsi::Function::createFromHostFunction(
         rt,
-        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_func_extract_public_key"),
+        jsi::PropNameID::forAscii(rt, "ubrn_ffi_uniffi_russh_rust_future_free_i64"),
         1,
         [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
-            return this->cpp_uniffi_uniffi_russh_fn_func_extract_public_key(rt, thisVal, args, count);
+            return this->cpp_ffi_uniffi_russh_rust_future_free_i64(rt, thisVal, args, count);
         }
     );
-    props["ubrn_uniffi_uniffi_russh_fn_func_generate_key_pair"] = jsi::Function::createFromHostFunction(
+    props["ubrn_ffi_uniffi_russh_rust_future_complete_i64"] = jsi::Function::createFromHostFunction(
         rt,
-        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_func_generate_key_pair"),
+        jsi::PropNameID::forAscii(rt, "ubrn_ffi_uniffi_russh_rust_future_complete_i64"),
         1,
         [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
-            return this->cpp_uniffi_uniffi_russh_fn_func_generate_key_pair(rt, thisVal, args, count);
+            return this->cpp_ffi_uniffi_russh_rust_future_complete_i64(rt, thisVal, args, count);
         }
     );
-    props["ubrn_uniffi_uniffi_russh_fn_func_validate_private_key"] = jsi::Function::createFromHostFunction(
+    props["ubrn_ffi_uniffi_russh_rust_future_poll_f32"] = jsi::Function::createFromHostFunction(
         rt,
-        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_fn_func_validate_private_key"),
+        jsi::PropNameID::forAscii(rt, "ubrn_ffi_uniffi_russh_rust_future_poll_f32"),
+        3,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_ffi_uniffi_russh_rust_future_poll_f32(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_ffi_uniffi_russh_rust_future_cancel_f32"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_ffi_uniffi_russh_rust_future_cancel_f32"),
         1,
         [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
-            return this->cpp_uniffi_uniffi_russh_fn_func_validate_private_key(rt, thisVal, args, count);
+            return this->cpp_ffi_uniffi_russh_rust_future_cancel_f32(rt, thisVal, args, count);
         }
     );
-    props["ubrn_ffi_uniffi_russh_rust_future_poll_u8"] = jsi::Function::createFromHostFunction(
+    props["ubrn_ffi_uniffi_russh_rust_future_free_f32"] = jsi::Function::createFromHostFunction(
         rt,
-        jsi::PropNameID::forAscii(rt, "ubrn_ffi_uniffi_russh_rust_future_poll_u8"),
+        jsi::PropNameID::forAscii(rt, "ubrn_ffi_uniffi_russh_rust_future_free_f32"),
+        1,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_ffi_uniffi_russh_rust_future_free_f32(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_ffi_uniffi_russh_rust_future_complete_f32"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_ffi_uniffi_russh_rust_future_complete_f32"),
+        1,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_ffi_uniffi_russh_rust_future_complete_f32(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_ffi_uniffi_russh_rust_future_poll_f64"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_ffi_uniffi_russh_rust_future_poll_f64"),
         3,
         [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
-            return this->cpp_ffi_uniffi_russh_rust_future_poll_u8(rt, thisVal, args, count);
+            return this->cpp_ffi_uniffi_russh_rust_future_poll_f64(rt, thisVal, args, count);
         }
     );
-    props["ubrn_ffi_uniffi_russh_rust_future_cancel_u8"] = jsi::Function::createFromHostFunction(
+    props["ubrn_ffi_uniffi_russh_rust_future_cancel_f64"] = jsi::Function::createFromHostFunction(
         rt,
-        jsi::PropNameID::forAscii(rt, "ubrn_ffi_uniffi_russh_rust_future_cancel_u8"),
+        jsi::PropNameID::forAscii(rt, "ubrn_ffi_uniffi_russh_rust_future_cancel_f64"),
         1,
         [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
-            return this->cpp_ffi_uniffi_russh_rust_future_cancel_u8(rt, thisVal, args, count);
+            return this->cpp_ffi_uniffi_russh_rust_future_cancel_f64(rt, thisVal, args, count);
         }
     );
-    props["ubrn_ffi_uniffi_russh_rust_future_free_u8"] = jsi::Function::createFromHostFunction(
+    props["ubrn_ffi_uniffi_russh_rust_future_free_f64"] = jsi::Function::createFromHostFunction(
         rt,
-        jsi::PropNameID::forAscii(rt, "ubrn_ffi_uniffi_russh_rust_future_free_u8"),
+        jsi::PropNameID::forAscii(rt, "ubrn_ffi_uniffi_russh_rust_future_free_f64"),
         1,
         [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
-            return this->cpp_ffi_uniffi_russh_rust_future_free_u8(rt, thisVal, args, count);
+            return this->cpp_ffi_uniffi_russh_rust_future_free_f64(rt, thisVal, args, count);
         }
     );
-    props["ubrn_ffi_uniffi_russh_rust_future_complete_u8"] = jsi::Function::createFromHostFunction(
+    props["ubrn_ffi_uniffi_russh_rust_future_complete_f64"] = jsi::Function::createFromHostFunction(
         rt,
-        jsi::PropNameID::forAscii(rt, "ubrn_ffi_uniffi_russh_rust_future_complete_u8"),
+        jsi::PropNameID::forAscii(rt, "ubrn_ffi_uniffi_russh_rust_future_complete_f64"),
         1,
         [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
-            return this->cpp_ffi_uniffi_russh_rust_future_complete_u8(rt, thisVal, args, count);
+            return this->cpp_ffi_uniffi_russh_rust_future_complete_f64(rt, thisVal, args, count);
         }
     );
-    props["ubrn_ffi_uniffi_russh_rust_future_poll_i8"] = jsi::Function::createFromHostFunction(
+    props["ubrn_ffi_uniffi_russh_rust_future_poll_pointer"] = jsi::Function::createFromHostFunction(
         rt,
-        jsi::PropNameID::forAscii(rt, "ubrn_ffi_uniffi_russh_rust_future_poll_i8"),
+        jsi::PropNameID::forAscii(rt, "ubrn_ffi_uniffi_russh_rust_future_poll_pointer"),
         3,
         [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
-            return this->cpp_ffi_uniffi_russh_rust_future_poll_i8(rt, thisVal, args, count);
+            return this->cpp_ffi_uniffi_russh_rust_future_poll_pointer(rt, thisVal, args, count);
         }
     );
-    props["ubrn_ffi_uniffi_russh_rust_future_cancel_i8"] = jsi::Function::createFromHostFunction(
+    props["ubrn_ffi_uniffi_russh_rust_future_cancel_pointer"] = jsi::Function::createFromHostFunction(
         rt,
-        jsi::PropNameID::forAscii(rt, "ubrn_ffi_uniffi_russh_rust_future_cancel_i8"),
+        jsi::PropNameID::forAscii(rt, "ubrn_ffi_uniffi_russh_rust_future_cancel_pointer"),
         1,
         [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
-            return this->cpp_ffi_uniffi_russh_rust_future_cancel_i8(rt, thisVal, args, count);
+            return this->cpp_ffi_uniffi_russh_rust_future_cancel_pointer(rt, thisVal, args, count);
         }
     );
-    props["ubrn_ffi_uniffi_russh_rust_future_free_i8"] = jsi::Function::createFromHostFunction(
+    props["ubrn_ffi_uniffi_russh_rust_future_free_pointer"] = jsi::Function::createFromHostFunction(
         rt,
-        jsi::PropNameID::forAscii(rt, "ubrn_ffi_uniffi_russh_rust_future_free_i8"),
+        jsi::PropNameID::forAscii(rt, "ubrn_ffi_uniffi_russh_rust_future_free_pointer"),
         1,
         [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
-            return this->cpp_ffi_uniffi_russh_rust_future_free_i8(rt, thisVal, args, count);
+            return this->cpp_ffi_uniffi_russh_rust_future_free_pointer(rt, thisVal, args, count);
         }
     );
-    props["ubrn_ffi_uniffi_russh_rust_future_complete_i8"] = jsi::Function::createFromHostFunction(
+    props["ubrn_ffi_uniffi_russh_rust_future_complete_pointer"] = jsi::Function::createFromHostFunction(
         rt,
-        jsi::PropNameID::forAscii(rt, "ubrn_ffi_uniffi_russh_rust_future_complete_i8"),
+        jsi::PropNameID::forAscii(rt, "ubrn_ffi_uniffi_russh_rust_future_complete_pointer"),
         1,
         [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
-            return this->cpp_ffi_uniffi_russh_rust_future_complete_i8(rt, thisVal, args, count);
+            return this->cpp_ffi_uniffi_russh_rust_future_complete_pointer(rt, thisVal, args, count);
         }
     );
-    props["ubrn_ffi_uniffi_russh_rust_future_poll_u16"] = jsi::Function::createFromHostFunction(
+    props["ubrn_ffi_uniffi_russh_rust_future_poll_rust_buffer"] = jsi::Function::createFromHostFunction(
         rt,
-        jsi::PropNameID::forAscii(rt, "ubrn_ffi_uniffi_russh_rust_future_poll_u16"),
+        jsi::PropNameID::forAscii(rt, "ubrn_ffi_uniffi_russh_rust_future_poll_rust_buffer"),
         3,
         [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
-            return this->cpp_ffi_uniffi_russh_rust_future_poll_u16(rt, thisVal, args, count);
+            return this->cpp_ffi_uniffi_russh_rust_future_poll_rust_buffer(rt, thisVal, args, count);
         }
     );
-    props["ubrn_ffi_uniffi_russh_rust_future_cancel_u16"] = jsi::Function::createFromHostFunction(
+    props["ubrn_ffi_uniffi_russh_rust_future_cancel_rust_buffer"] = jsi::Function::createFromHostFunction(
         rt,
-        jsi::PropNameID::forAscii(rt, "ubrn_ffi_uniffi_russh_rust_future_cancel_u16"),
+        jsi::PropNameID::forAscii(rt, "ubrn_ffi_uniffi_russh_rust_future_cancel_rust_buffer"),
+        1,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_ffi_uniffi_russh_rust_future_cancel_rust_buffer(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_ffi_uniffi_russh_rust_future_free_rust_buffer"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_ffi_uniffi_russh_rust_future_free_rust_buffer"),
+        1,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_ffi_uniffi_russh_rust_future_free_rust_buffer(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_ffi_uniffi_russh_rust_future_complete_rust_buffer"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_ffi_uniffi_russh_rust_future_complete_rust_buffer"),
+        1,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_ffi_uniffi_russh_rust_future_complete_rust_buffer(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_ffi_uniffi_russh_rust_future_poll_void"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_ffi_uniffi_russh_rust_future_poll_void"),
+        3,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_ffi_uniffi_russh_rust_future_poll_void(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_ffi_uniffi_russh_rust_future_cancel_void"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_ffi_uniffi_russh_rust_future_cancel_void"),
+        1,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_ffi_uniffi_russh_rust_future_cancel_void(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_ffi_uniffi_russh_rust_future_free_void"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_ffi_uniffi_russh_rust_future_free_void"),
+        1,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_ffi_uniffi_russh_rust_future_free_void(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_ffi_uniffi_russh_rust_future_complete_void"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_ffi_uniffi_russh_rust_future_complete_void"),
         1,
         [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
-            return this->cpp_ffi_uniffi_russh_rust_future_cancel_u16(rt, thisVal, args, count);
+            return this->cpp_ffi_uniffi_russh_rust_future_complete_void(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_uniffi_uniffi_russh_checksum_func_connect"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_checksum_func_connect"),
+        0,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_uniffi_russh_checksum_func_connect(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_uniffi_uniffi_russh_checksum_func_extract_public_key"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_checksum_func_extract_public_key"),
+        0,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_uniffi_russh_checksum_func_extract_public_key(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_uniffi_uniffi_russh_checksum_func_generate_key_pair"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_checksum_func_generate_key_pair"),
+        0,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_uniffi_russh_checksum_func_generate_key_pair(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_uniffi_uniffi_russh_checksum_func_memory_usage"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_checksum_func_memory_usage"),
+        0,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_uniffi_russh_checksum_func_memory_usage(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_uniffi_uniffi_russh_checksum_func_open_cast_player"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_checksum_func_open_cast_player"),
+        0,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_uniffi_russh_checksum_func_open_cast_player(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_uniffi_uniffi_russh_checksum_func_public_key_fingerprints"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_checksum_func_public_key_fingerprints"),
+        0,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_uniffi_russh_checksum_func_public_key_fingerprints(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_uniffi_uniffi_russh_checksum_func_set_memory_budget"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_checksum_func_set_memory_budget"),
+        0,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_uniffi_russh_checksum_func_set_memory_budget(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_uniffi_uniffi_russh_checksum_func_validate_private_key"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_checksum_func_validate_private_key"),
+        0,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_uniffi_russh_checksum_func_validate_private_key(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_uniffi_uniffi_russh_checksum_method_authbannercallback_on_change"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_checksum_method_authbannercallback_on_change"),
+        0,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_uniffi_russh_checksum_method_authbannercallback_on_change(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_uniffi_uniffi_russh_checksum_method_castplayer_add_listener"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_checksum_method_castplayer_add_listener"),
+        0,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_uniffi_russh_checksum_method_castplayer_add_listener(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_uniffi_uniffi_russh_checksum_method_castplayer_attach_emulator"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_checksum_method_castplayer_attach_emulator"),
+        0,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_uniffi_russh_checksum_method_castplayer_attach_emulator(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_uniffi_uniffi_russh_checksum_method_castplayer_buffer_stats"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_checksum_method_castplayer_buffer_stats"),
+        0,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_uniffi_russh_checksum_method_castplayer_buffer_stats(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_uniffi_uniffi_russh_checksum_method_castplayer_commands"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_checksum_method_castplayer_commands"),
+        0,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_uniffi_russh_checksum_method_castplayer_commands(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_uniffi_uniffi_russh_checksum_method_castplayer_current_seq"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_checksum_method_castplayer_current_seq"),
+        0,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_uniffi_russh_checksum_method_castplayer_current_seq(rt, thisVal, args, count);
         }
     );
-    props["ubrn_ffi_uniffi_russh_rust_future_free_u16"] = jsi::Function::createFromHostFunction(
+    props["ubrn_uniffi_uniffi_russh_checksum_method_castplayer_detach_emulator"] = jsi::Function::createFromHostFunction(
         rt,
-        jsi::PropNameID::forAscii(rt, "ubrn_ffi_uniffi_russh_rust_future_free_u16"),
-        1,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_checksum_method_castplayer_detach_emulator"),
+        0,
         [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
-            return this->cpp_ffi_uniffi_russh_rust_future_free_u16(rt, thisVal, args, count);
+            return this->cpp_uniffi_uniffi_russh_checksum_method_castplayer_detach_emulator(rt, thisVal, args, count);
         }
     );
-    props["ubrn_ffi_uniffi_russh_rust_future_complete_u16"] = jsi::Function::createFromHostFunction(
+    props["ubrn_uniffi_uniffi_russh_checksum_method_castplayer_get_info"] = jsi::Function::createFromHostFunction(
         rt,
-        jsi::PropNameID::forAscii(rt, "ubrn_ffi_uniffi_russh_rust_future_complete_u16"),
-        1,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_checksum_method_castplayer_get_info"),
+        0,
         [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
-            return this->cpp_ffi_uniffi_russh_rust_future_complete_u16(rt, thisVal, args, count);
+            return this->cpp_uniffi_uniffi_russh_checksum_method_castplayer_get_info(rt, thisVal, args, count);
         }
     );
-    props["ubrn_ffi_uniffi_russh_rust_future_poll_i16"] = jsi::Function::createFromHostFunction(
+    props["ubrn_uniffi_uniffi_russh_checksum_method_castplayer_get_status"] = jsi::Function::createFromHostFunction(
         rt,
-        jsi::PropNameID::forAscii(rt, "ubrn_ffi_uniffi_russh_rust_future_poll_i16"),
-        3,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_checksum_method_castplayer_get_status"),
+        0,
         [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
-            return this->cpp_ffi_uniffi_russh_rust_future_poll_i16(rt, thisVal, args, count);
+            return this->cpp_uniffi_uniffi_russh_checksum_method_castplayer_get_status(rt, thisVal, args, count);
         }
     );
-    props["ubrn_ffi_uniffi_russh_rust_future_cancel_i16"] = jsi::Function::createFromHostFunction(
+    props["ubrn_uniffi_uniffi_russh_checksum_method_castplayer_pause"] = jsi::Function::createFromHostFunction(
         rt,
-        jsi::PropNameID::forAscii(rt, "ubrn_ffi_uniffi_russh_rust_future_cancel_i16"),
-        1,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_checksum_method_castplayer_pause"),
+        0,
         [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
-            return this->cpp_ffi_uniffi_russh_rust_future_cancel_i16(rt, thisVal, args, count);
+            return this->cpp_uniffi_uniffi_russh_checksum_method_castplayer_pause(rt, thisVal, args, count);
         }
     );
-    props["ubrn_ffi_uniffi_russh_rust_future_free_i16"] = jsi::Function::createFromHostFunction(
+    props["ubrn_uniffi_uniffi_russh_checksum_method_castplayer_play"] = jsi::Function::createFromHostFunction(
         rt,
-        jsi::PropNameID::forAscii(rt, "ubrn_ffi_uniffi_russh_rust_future_free_i16"),
-        1,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_checksum_method_castplayer_play"),
+        0,
         [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
-            return this->cpp_ffi_uniffi_russh_rust_future_free_i16(rt, thisVal, args, count);
+            return this->cpp_uniffi_uniffi_russh_checksum_method_castplayer_play(rt, thisVal, args, count);
         }
     );
-    props["ubrn_ffi_uniffi_russh_rust_future_complete_i16"] = jsi::Function::createFromHostFunction(
+    props["ubrn_uniffi_uniffi_russh_checksum_method_castplayer_read_buffer"] = jsi::Function::createFromHostFunction(
         rt,
-        jsi::PropNameID::forAscii(rt, "ubrn_ffi_uniffi_russh_rust_future_complete_i16"),
-        1,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_checksum_method_castplayer_read_buffer"),
+        0,
         [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
-            return this->cpp_ffi_uniffi_russh_rust_future_complete_i16(rt, thisVal, args, count);
+            return this->cpp_uniffi_uniffi_russh_checksum_method_castplayer_read_buffer(rt, thisVal, args, count);
         }
     );
-    props["ubrn_ffi_uniffi_russh_rust_future_poll_u32"] = jsi::Function::createFromHostFunction(
+    props["ubrn_uniffi_uniffi_russh_checksum_method_castplayer_read_text"] = jsi::Function::createFromHostFunction(
         rt,
-        jsi::PropNameID::forAscii(rt, "ubrn_ffi_uniffi_russh_rust_future_poll_u32"),
-        3,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_checksum_method_castplayer_read_text"),
+        0,
         [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
-            return this->cpp_ffi_uniffi_russh_rust_future_poll_u32(rt, thisVal, args, count);
+            return this->cpp_uniffi_uniffi_russh_checksum_method_castplayer_read_text(rt, thisVal, args, count);
         }
     );
-    props["ubrn_ffi_uniffi_russh_rust_future_cancel_u32"] = jsi::Function::createFromHostFunction(
+    props["ubrn_uniffi_uniffi_russh_checksum_method_castplayer_remove_listener"] = jsi::Function::createFromHostFunction(
         rt,
-        jsi::PropNameID::forAscii(rt, "ubrn_ffi_uniffi_russh_rust_future_cancel_u32"),
-        1,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_checksum_method_castplayer_remove_listener"),
+        0,
         [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
-            return this->cpp_ffi_uniffi_russh_rust_future_cancel_u32(rt, thisVal, args, count);
+            return this->cpp_uniffi_uniffi_russh_checksum_method_castplayer_remove_listener(rt, thisVal, args, count);
         }
     );
-    props["ubrn_ffi_uniffi_russh_rust_future_free_u32"] = jsi::Function::createFromHostFunction(
+    props["ubrn_uniffi_uniffi_russh_checksum_method_castplayer_search"] = jsi::Function::createFromHostFunction(
         rt,
-        jsi::PropNameID::forAscii(rt, "ubrn_ffi_uniffi_russh_rust_future_free_u32"),
-        1,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_checksum_method_castplayer_search"),
+        0,
         [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
-            return this->cpp_ffi_uniffi_russh_rust_future_free_u32(rt, thisVal, args, count);
+            return this->cpp_uniffi_uniffi_russh_checksum_method_castplayer_search(rt, thisVal, args, count);
         }
     );
-    props["ubrn_ffi_uniffi_russh_rust_future_complete_u32"] = jsi::Function::createFromHostFunction(
+    props["ubrn_uniffi_uniffi_russh_checksum_method_castplayer_seek"] = jsi::Function::createFromHostFunction(
         rt,
-        jsi::PropNameID::forAscii(rt, "ubrn_ffi_uniffi_russh_rust_future_complete_u32"),
-        1,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_checksum_method_castplayer_seek"),
+        0,
         [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
-            return this->cpp_ffi_uniffi_russh_rust_future_complete_u32(rt, thisVal, args, count);
+            return this->cpp_uniffi_uniffi_russh_checksum_method_castplayer_seek(rt, thisVal, args, count);
         }
     );
-    props["ubrn_ffi_uniffi_russh_rust_future_poll_i32"] = jsi::Function::createFromHostFunction(
+    props["ubrn_uniffi_uniffi_russh_checksum_method_castplayer_set_speed"] = jsi::Function::createFromHostFunction(
         rt,
-        jsi::PropNameID::forAscii(rt, "ubrn_ffi_uniffi_russh_rust_future_poll_i32"),
-        3,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_checksum_method_castplayer_set_speed"),
+        0,
         [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
-            return this->cpp_ffi_uniffi_russh_rust_future_poll_i32(rt, thisVal, args, count);
+            return this->cpp_uniffi_uniffi_russh_checksum_method_castplayer_set_speed(rt, thisVal, args, count);
         }
     );
-    props["ubrn_ffi_uniffi_russh_rust_future_cancel_i32"] = jsi::Function::createFromHostFunction(
+    props["ubrn_uniffi_uniffi_russh_checksum_method_castplayerstatuscallback_on_change"] = jsi::Function::createFromHostFunction(
         rt,
-        jsi::PropNameID::forAscii(rt, "ubrn_ffi_uniffi_russh_rust_future_cancel_i32"),
-        1,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_checksum_method_castplayerstatuscallback_on_change"),
+        0,
         [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
-            return this->cpp_ffi_uniffi_russh_rust_future_cancel_i32(rt, thisVal, args, count);
+            return this->cpp_uniffi_uniffi_russh_checksum_method_castplayerstatuscallback_on_change(rt, thisVal, args, count);
         }
     );
-    props["ubrn_ffi_uniffi_russh_rust_future_free_i32"] = jsi::Function::createFromHostFunction(
+    props["ubrn_uniffi_uniffi_russh_checksum_method_channelpausecallback_on_change"] = jsi::Function::createFromHostFunction(
         rt,
-        jsi::PropNameID::forAscii(rt, "ubrn_ffi_uniffi_russh_rust_future_free_i32"),
-        1,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_checksum_method_channelpausecallback_on_change"),
+        0,
         [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
-            return this->cpp_ffi_uniffi_russh_rust_future_free_i32(rt, thisVal, args, count);
+            return this->cpp_uniffi_uniffi_russh_checksum_method_channelpausecallback_on_change(rt, thisVal, args, count);
         }
     );
-    props["ubrn_ffi_uniffi_russh_rust_future_complete_i32"] = jsi::Function::createFromHostFunction(
+    props["ubrn_uniffi_uniffi_russh_checksum_method_connectaborttoken_abort"] = jsi::Function::createFromHostFunction(
         rt,
-        jsi::PropNameID::forAscii(rt, "ubrn_ffi_uniffi_russh_rust_future_complete_i32"),
-        1,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_checksum_method_connectaborttoken_abort"),
+        0,
         [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
-            return this->cpp_ffi_uniffi_russh_rust_future_complete_i32(rt, thisVal, args, count);
+            return this->cpp_uniffi_uniffi_russh_checksum_method_connectaborttoken_abort(rt, thisVal, args, count);
         }
     );
-    props["ubrn_ffi_uniffi_russh_rust_future_poll_u64"] = jsi::Function::createFromHostFunction(
+    props["ubrn_uniffi_uniffi_russh_checksum_method_connectaborttoken_is_aborted"] = jsi::Function::createFromHostFunction(
         rt,
-        jsi::PropNameID::forAscii(rt, "ubrn_ffi_uniffi_russh_rust_future_poll_u64"),
-        3,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_checksum_method_connectaborttoken_is_aborted"),
+        0,
         [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
-            return this->cpp_ffi_uniffi_russh_rust_future_poll_u64(rt, thisVal, args, count);
+            return this->cpp_uniffi_uniffi_russh_checksum_method_connectaborttoken_is_aborted(rt, thisVal, args, count);
         }
     );
-    props["ubrn_ffi_uniffi_russh_rust_future_cancel_u64"] = jsi::Function::createFromHostFunction(
+    props["ubrn_uniffi_uniffi_russh_checksum_method_connectprogresscallback_on_change"] = jsi::Function::createFromHostFunction(
         rt,
-        jsi::PropNameID::forAscii(rt, "ubrn_ffi_uniffi_russh_rust_future_cancel_u64"),
-        1,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_checksum_method_connectprogresscallback_on_change"),
+        0,
         [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
-            return this->cpp_ffi_uniffi_russh_rust_future_cancel_u64(rt, thisVal, args, count);
+            return this->cpp_uniffi_uniffi_russh_checksum_method_connectprogresscallback_on_change(rt, thisVal, args, count);
         }
     );
-    props["ubrn_ffi_uniffi_russh_rust_future_free_u64"] = jsi::Function::createFromHostFunction(
+    props["ubrn_uniffi_uniffi_russh_checksum_method_connectiondisconnectedcallback_on_change"] = jsi::Function::createFromHostFunction(
         rt,
-        jsi::PropNameID::forAscii(rt, "ubrn_ffi_uniffi_russh_rust_future_free_u64"),
-        1,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_checksum_method_connectiondisconnectedcallback_on_change"),
+        0,
         [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
-            return this->cpp_ffi_uniffi_russh_rust_future_free_u64(rt, thisVal, args, count);
+            return this->cpp_uniffi_uniffi_russh_checksum_method_connectiondisconnectedcallback_on_change(rt, thisVal, args, count);
         }
     );
-    props["ubrn_ffi_uniffi_russh_rust_future_complete_u64"] = jsi::Function::createFromHostFunction(
+    props["ubrn_uniffi_uniffi_russh_checksum_method_connectioneventcallback_on_change"] = jsi::Function::createFromHostFunction(
         rt,
-        jsi::PropNameID::forAscii(rt, "ubrn_ffi_uniffi_russh_rust_future_complete_u64"),
-        1,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_checksum_method_connectioneventcallback_on_change"),
+        0,
         [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
-            return this->cpp_ffi_uniffi_russh_rust_future_complete_u64(rt, thisVal, args, count);
+            return this->cpp_uniffi_uniffi_russh_checksum_method_connectioneventcallback_on_change(rt, thisVal, args, count);
         }
     );
-    props["ubrn_ffi_uniffi_russh_rust_future_poll_i64"] = jsi::Function::createFromHostFunction(
+    props["ubrn_uniffi_uniffi_russh_checksum_method_connectionmetricscallback_on_change"] = jsi::Function::createFromHostFunction(
         rt,
-        jsi::PropNameID::forAscii(rt, "ubrn_ffi_uniffi_russh_rust_future_poll_i64"),
-        3,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_checksum_method_connectionmetricscallback_on_change"),
+        0,
         [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
-            return this->cpp_ffi_uniffi_russh_rust_future_poll_i64(rt, thisVal, args, count);
+            return this->cpp_uniffi_uniffi_russh_checksum_method_connectionmetricscallback_on_change(rt, thisVal, args, count);
         }
     );
-    props["ubrn_ffi_uniffi_russh_rust_future_cancel_i64"] = jsi::Function::createFromHostFunction(
+    props["ubrn_uniffi_uniffi_russh_checksum_method_serverkeycallback_on_change"] = jsi::Function::createFromHostFunction(
         rt,
-        jsi::PropNameID::forAscii(rt, "ubrn_ffi_uniffi_russh_rust_future_cancel_i64"),
-        1,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_checksum_method_serverkeycallback_on_change"),
+        0,
         [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
-            return this->cpp_ffi_uniffi_russh_rust_future_cancel_i64(rt, thisVal, args, count);
+            return this->cpp_uniffi_uniffi_russh_checksum_method_serverkeycallback_on_change(rt, thisVal, args, count);
         }
     );
-    props["ubrn_ffi_uniffi_russh_rust_future_free_i64"] = jsi::Function::createFromHostFunction(
+    props["ubrn_uniffi_uniffi_russh_checksum_method_shellclosedcallback_on_change"] = jsi::Function::createFromHostFunction(
         rt,
-        jsi::PropNameID::forAscii(rt, "ubrn_ffi_uniffi_russh_rust_future_free_i64"),
-        1,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_checksum_method_shellclosedcallback_on_change"),
+        0,
         [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
-            return this->cpp_ffi_uniffi_russh_rust_future_free_i64(rt, thisVal, args, count);
+            return this->cpp_uniffi_uniffi_russh_checksum_method_shellclosedcallback_on_change(rt, thisVal, args, count);
         }
     );
-    props["ubrn_ffi_uniffi_russh_rust_future_complete_i64"] = jsi::Function::createFromHostFunction(
+    props["ubrn_uniffi_uniffi_russh_checksum_method_shelllistener_on_event"] = jsi::Function::createFromHostFunction(
         rt,
-        jsi::PropNameID::forAscii(rt, "ubrn_ffi_uniffi_russh_rust_future_complete_i64"),
-        1,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_checksum_method_shelllistener_on_event"),
+        0,
         [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
-            return this->cpp_ffi_uniffi_russh_rust_future_complete_i64(rt, thisVal, args, count);
+            return this->cpp_uniffi_uniffi_russh_checksum_method_shelllistener_on_event(rt, thisVal, args, count);
         }
     );
-    props["ubrn_ffi_uniffi_russh_rust_future_poll_f32"] = jsi::Function::createFromHostFunction(
+    props["ubrn_uniffi_uniffi_russh_checksum_method_shellsession_add_listener"] = jsi::Function::createFromHostFunction(
         rt,
-        jsi::PropNameID::forAscii(rt, "ubrn_ffi_uniffi_russh_rust_future_poll_f32"),
-        3,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_checksum_method_shellsession_add_listener"),
+        0,
         [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
-            return this->cpp_ffi_uniffi_russh_rust_future_poll_f32(rt, thisVal, args, count);
+            return this->cpp_uniffi_uniffi_russh_checksum_method_shellsession_add_listener(rt, thisVal, args, count);
         }
     );
-    props["ubrn_ffi_uniffi_russh_rust_future_cancel_f32"] = jsi::Function::createFromHostFunction(
+    props["ubrn_uniffi_uniffi_russh_checksum_method_shellsession_attach_emulator"] = jsi::Function::createFromHostFunction(
         rt,
-        jsi::PropNameID::forAscii(rt, "ubrn_ffi_uniffi_russh_rust_future_cancel_f32"),
-        1,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_checksum_method_shellsession_attach_emulator"),
+        0,
         [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
-            return this->cpp_ffi_uniffi_russh_rust_future_cancel_f32(rt, thisVal, args, count);
+            return this->cpp_uniffi_uniffi_russh_checksum_method_shellsession_attach_emulator(rt, thisVal, args, count);
         }
     );
-    props["ubrn_ffi_uniffi_russh_rust_future_free_f32"] = jsi::Function::createFromHostFunction(
+    props["ubrn_uniffi_uniffi_russh_checksum_method_shellsession_buffer_stats"] = jsi::Function::createFromHostFunction(
         rt,
-        jsi::PropNameID::forAscii(rt, "ubrn_ffi_uniffi_russh_rust_future_free_f32"),
-        1,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_checksum_method_shellsession_buffer_stats"),
+        0,
         [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
-            return this->cpp_ffi_uniffi_russh_rust_future_free_f32(rt, thisVal, args, count);
+            return this->cpp_uniffi_uniffi_russh_checksum_method_shellsession_buffer_stats(rt, thisVal, args, count);
         }
     );
-    props["ubrn_ffi_uniffi_russh_rust_future_complete_f32"] = jsi::Function::createFromHostFunction(
+    props["ubrn_uniffi_uniffi_russh_checksum_method_shellsession_close"] = jsi::Function::createFromHostFunction(
         rt,
-        jsi::PropNameID::forAscii(rt, "ubrn_ffi_uniffi_russh_rust_future_complete_f32"),
-        1,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_checksum_method_shellsession_close"),
+        0,
         [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
-            return this->cpp_ffi_uniffi_russh_rust_future_complete_f32(rt, thisVal, args, count);
+            return this->cpp_uniffi_uniffi_russh_checksum_method_shellsession_close(rt, thisVal, args, count);
         }
     );
-    props["ubrn_ffi_uniffi_russh_rust_future_poll_f64"] = jsi::Function::createFromHostFunction(
+    props["ubrn_uniffi_uniffi_russh_checksum_method_shellsession_commands"] = jsi::Function::createFromHostFunction(
         rt,
-        jsi::PropNameID::forAscii(rt, "ubrn_ffi_uniffi_russh_rust_future_poll_f64"),
-        3,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_checksum_method_shellsession_commands"),
+        0,
         [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
-            return this->cpp_ffi_uniffi_russh_rust_future_poll_f64(rt, thisVal, args, count);
+            return this->cpp_uniffi_uniffi_russh_checksum_method_shellsession_commands(rt, thisVal, args, count);
         }
     );
-    props["ubrn_ffi_uniffi_russh_rust_future_cancel_f64"] = jsi::Function::createFromHostFunction(
+    props["ubrn_uniffi_uniffi_russh_checksum_method_shellsession_current_seq"] = jsi::Function::createFromHostFunction(
         rt,
-        jsi::PropNameID::forAscii(rt, "ubrn_ffi_uniffi_russh_rust_future_cancel_f64"),
-        1,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_checksum_method_shellsession_current_seq"),
+        0,
         [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
-            return this->cpp_ffi_uniffi_russh_rust_future_cancel_f64(rt, thisVal, args, count);
+            return this->cpp_uniffi_uniffi_russh_checksum_method_shellsession_current_seq(rt, thisVal, args, count);
         }
     );
-    props["ubrn_ffi_uniffi_russh_rust_future_free_f64"] = jsi::Function::createFromHostFunction(
+    props["ubrn_uniffi_uniffi_russh_checksum_method_shellsession_detach_emulator"] = jsi::Function::createFromHostFunction(
         rt,
-        jsi::PropNameID::forAscii(rt, "ubrn_ffi_uniffi_russh_rust_future_free_f64"),
-        1,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_checksum_method_shellsession_detach_emulator"),
+        0,
         [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
-            return this->cpp_ffi_uniffi_russh_rust_future_free_f64(rt, thisVal, args, count);
+            return this->cpp_uniffi_uniffi_russh_checksum_method_shellsession_detach_emulator(rt, thisVal, args, count);
         }
     );
-    props["ubrn_ffi_uniffi_russh_rust_future_complete_f64"] = jsi::Function::createFromHostFunction(
+    props["ubrn_uniffi_uniffi_russh_checksum_method_shellsession_get_info"] = jsi::Function::createFromHostFunction(
         rt,
-        jsi::PropNameID::forAscii(rt, "ubrn_ffi_uniffi_russh_rust_future_complete_f64"),
-        1,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_checksum_method_shellsession_get_info"),
+        0,
         [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
-            return this->cpp_ffi_uniffi_russh_rust_future_complete_f64(rt, thisVal, args, count);
+            return this->cpp_uniffi_uniffi_russh_checksum_method_shellsession_get_info(rt, thisVal, args, count);
         }
     );
-    props["ubrn_ffi_uniffi_russh_rust_future_poll_pointer"] = jsi::Function::createFromHostFunction(
+    props["ubrn_uniffi_uniffi_russh_checksum_method_shellsession_read_buffer"] = jsi::Function::createFromHostFunction(
         rt,
-        jsi::PropNameID::forAscii(rt, "ubrn_ffi_uniffi_russh_rust_future_poll_pointer"),
-        3,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_checksum_method_shellsession_read_buffer"),
+        0,
         [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
-            return this->cpp_ffi_uniffi_russh_rust_future_poll_pointer(rt, thisVal, args, count);
+            return this->cpp_uniffi_uniffi_russh_checksum_method_shellsession_read_buffer(rt, thisVal, args, count);
         }
     );
-    props["ubrn_ffi_uniffi_russh_rust_future_cancel_pointer"] = jsi::Function::createFromHostFunction(
+    props["ubrn_uniffi_uniffi_russh_checksum_method_shellsession_read_text"] = jsi::Function::createFromHostFunction(
         rt,
-        jsi::PropNameID::forAscii(rt, "ubrn_ffi_uniffi_russh_rust_future_cancel_pointer"),
-        1,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_checksum_method_shellsession_read_text"),
+        0,
         [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
-            return this->cpp_ffi_uniffi_russh_rust_future_cancel_pointer(rt, thisVal, args, count);
+            return this->cpp_uniffi_uniffi_russh_checksum_method_shellsession_read_text(rt, thisVal, args, count);
         }
     );
-    props["ubrn_ffi_uniffi_russh_rust_future_free_pointer"] = jsi::Function::createFromHostFunction(
+    props["ubrn_uniffi_uniffi_russh_checksum_method_shellsession_remove_listener"] = jsi::Function::createFromHostFunction(
         rt,
-        jsi::PropNameID::forAscii(rt, "ubrn_ffi_uniffi_russh_rust_future_free_pointer"),
-        1,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_checksum_method_shellsession_remove_listener"),
+        0,
         [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
-            return this->cpp_ffi_uniffi_russh_rust_future_free_pointer(rt, thisVal, args, count);
+            return this->cpp_uniffi_uniffi_russh_checksum_method_shellsession_remove_listener(rt, thisVal, args, count);
         }
     );
-    props["ubrn_ffi_uniffi_russh_rust_future_complete_pointer"] = jsi::Function::createFromHostFunction(
+    props["ubrn_uniffi_uniffi_russh_checksum_method_shellsession_resize"] = jsi::Function::createFromHostFunction(
         rt,
-        jsi::PropNameID::forAscii(rt, "ubrn_ffi_uniffi_russh_rust_future_complete_pointer"),
-        1,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_checksum_method_shellsession_resize"),
+        0,
         [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
-            return this->cpp_ffi_uniffi_russh_rust_future_complete_pointer(rt, thisVal, args, count);
+            return this->cpp_uniffi_uniffi_russh_checksum_method_shellsession_resize(rt, thisVal, args, count);
         }
     );
-    props["ubrn_ffi_uniffi_russh_rust_future_poll_rust_buffer"] = jsi::Function::createFromHostFunction(
+    props["ubrn_uniffi_uniffi_russh_checksum_method_shellsession_search"] = jsi::Function::createFromHostFunction(
         rt,
-        jsi::PropNameID::forAscii(rt, "ubrn_ffi_uniffi_russh_rust_future_poll_rust_buffer"),
-        3,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_checksum_method_shellsession_search"),
+        0,
         [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
-            return this->cpp_ffi_uniffi_russh_rust_future_poll_rust_buffer(rt, thisVal, args, count);
+            return this->cpp_uniffi_uniffi_russh_checksum_method_shellsession_search(rt, thisVal, args, count);
         }
     );
-    props["ubrn_ffi_uniffi_russh_rust_future_cancel_rust_buffer"] = jsi::Function::createFromHostFunction(
+    props["ubrn_uniffi_uniffi_russh_checksum_method_shellsession_send_break"] = jsi::Function::createFromHostFunction(
         rt,
-        jsi::PropNameID::forAscii(rt, "ubrn_ffi_uniffi_russh_rust_future_cancel_rust_buffer"),
-        1,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_checksum_method_shellsession_send_break"),
+        0,
         [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
-            return this->cpp_ffi_uniffi_russh_rust_future_cancel_rust_buffer(rt, thisVal, args, count);
+            return this->cpp_uniffi_uniffi_russh_checksum_method_shellsession_send_break(rt, thisVal, args, count);
         }
     );
-    props["ubrn_ffi_uniffi_russh_rust_future_free_rust_buffer"] = jsi::Function::createFromHostFunction(
+    props["ubrn_uniffi_uniffi_russh_checksum_method_shellsession_send_data"] = jsi::Function::createFromHostFunction(
         rt,
-        jsi::PropNameID::forAscii(rt, "ubrn_ffi_uniffi_russh_rust_future_free_rust_buffer"),
-        1,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_checksum_method_shellsession_send_data"),
+        0,
         [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
-            return this->cpp_ffi_uniffi_russh_rust_future_free_rust_buffer(rt, thisVal, args, count);
+            return this->cpp_uniffi_uniffi_russh_checksum_method_shellsession_send_data(rt, thisVal, args, count);
         }
     );
-    props["ubrn_ffi_uniffi_russh_rust_future_complete_rust_buffer"] = jsi::Function::createFromHostFunction(
+    props["ubrn_uniffi_uniffi_russh_checksum_method_shellsession_send_signal"] = jsi::Function::createFromHostFunction(
         rt,
-        jsi::PropNameID::forAscii(rt, "ubrn_ffi_uniffi_russh_rust_future_complete_rust_buffer"),
-        1,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_checksum_method_shellsession_send_signal"),
+        0,
         [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
-            return this->cpp_ffi_uniffi_russh_rust_future_complete_rust_buffer(rt, thisVal, args, count);
+            return this->cpp_uniffi_uniffi_russh_checksum_method_shellsession_send_signal(rt, thisVal, args, count);
         }
     );
-    props["ubrn_ffi_uniffi_russh_rust_future_poll_void"] = jsi::Function::createFromHostFunction(
+    props["ubrn_uniffi_uniffi_russh_checksum_method_shellsession_set_buffer_policy"] = jsi::Function::createFromHostFunction(
         rt,
-        jsi::PropNameID::forAscii(rt, "ubrn_ffi_uniffi_russh_rust_future_poll_void"),
-        3,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_checksum_method_shellsession_set_buffer_policy"),
+        0,
         [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
-            return this->cpp_ffi_uniffi_russh_rust_future_poll_void(rt, thisVal, args, count);
+            return this->cpp_uniffi_uniffi_russh_checksum_method_shellsession_set_buffer_policy(rt, thisVal, args, count);
         }
     );
-    props["ubrn_ffi_uniffi_russh_rust_future_cancel_void"] = jsi::Function::createFromHostFunction(
+    props["ubrn_uniffi_uniffi_russh_checksum_method_shellsession_start_recording"] = jsi::Function::createFromHostFunction(
         rt,
-        jsi::PropNameID::forAscii(rt, "ubrn_ffi_uniffi_russh_rust_future_cancel_void"),
-        1,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_checksum_method_shellsession_start_recording"),
+        0,
         [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
-            return this->cpp_ffi_uniffi_russh_rust_future_cancel_void(rt, thisVal, args, count);
+            return this->cpp_uniffi_uniffi_russh_checksum_method_shellsession_start_recording(rt, thisVal, args, count);
         }
     );
-    props["ubrn_ffi_uniffi_russh_rust_future_free_void"] = jsi::Function::createFromHostFunction(
+    props["ubrn_uniffi_uniffi_russh_checksum_method_shellsession_stop_recording"] = jsi::Function::createFromHostFunction(
         rt,
-        jsi::PropNameID::forAscii(rt, "ubrn_ffi_uniffi_russh_rust_future_free_void"),
-        1,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_checksum_method_shellsession_stop_recording"),
+        0,
         [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
-            return this->cpp_ffi_uniffi_russh_rust_future_free_void(rt, thisVal, args, count);
+            return this->cpp_uniffi_uniffi_russh_checksum_method_shellsession_stop_recording(rt, thisVal, args, count);
         }
     );
-    props["ubrn_ffi_uniffi_russh_rust_future_complete_void"] = jsi::Function::createFromHostFunction(
+    props["ubrn_uniffi_uniffi_russh_checksum_method_sshconnection_disconnect"] = jsi::Function::createFromHostFunction(
         rt,
-        jsi::PropNameID::forAscii(rt, "ubrn_ffi_uniffi_russh_rust_future_complete_void"),
-        1,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_checksum_method_sshconnection_disconnect"),
+        0,
         [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
-            return this->cpp_ffi_uniffi_russh_rust_future_complete_void(rt, thisVal, args, count);
+            return this->cpp_uniffi_uniffi_russh_checksum_method_sshconnection_disconnect(rt, thisVal, args, count);
         }
     );
-    props["ubrn_uniffi_uniffi_russh_checksum_func_connect"] = jsi::Function::createFromHostFunction(
+    props["ubrn_uniffi_uniffi_russh_checksum_method_sshconnection_get_info"] = jsi::Function::createFromHostFunction(
         rt,
-        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_checksum_func_connect"),
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_checksum_method_sshconnection_get_info"),
         0,
         [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
-            return this->cpp_uniffi_uniffi_russh_checksum_func_connect(rt, thisVal, args, count);
+            return this->cpp_uniffi_uniffi_russh_checksum_method_sshconnection_get_info(rt, thisVal, args, count);
         }
     );
-    props["ubrn_uniffi_uniffi_russh_checksum_func_extract_public_key"] = jsi::Function::createFromHostFunction(
+    props["ubrn_uniffi_uniffi_russh_checksum_method_sshconnection_get_metrics"] = jsi::Function::createFromHostFunction(
         rt,
-        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_checksum_func_extract_public_key"),
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_checksum_method_sshconnection_get_metrics"),
         0,
         [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
-            return this->cpp_uniffi_uniffi_russh_checksum_func_extract_public_key(rt, thisVal, args, count);
+            return this->cpp_uniffi_uniffi_russh_checksum_method_sshconnection_get_metrics(rt, thisVal, args, count);
         }
     );
-    props["ubrn_uniffi_uniffi_russh_checksum_func_generate_key_pair"] = jsi::Function::createFromHostFunction(
+    props["ubrn_uniffi_uniffi_russh_checksum_method_sshconnection_install_bundled_terminfo"] = jsi::Function::createFromHostFunction(
         rt,
-        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_checksum_func_generate_key_pair"),
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_checksum_method_sshconnection_install_bundled_terminfo"),
         0,
         [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
-            return this->cpp_uniffi_uniffi_russh_checksum_func_generate_key_pair(rt, thisVal, args, count);
+            return this->cpp_uniffi_uniffi_russh_checksum_method_sshconnection_install_bundled_terminfo(rt, thisVal, args, count);
         }
     );
-    props["ubrn_uniffi_uniffi_russh_checksum_func_validate_private_key"] = jsi::Function::createFromHostFunction(
+    props["ubrn_uniffi_uniffi_russh_checksum_method_sshconnection_install_terminfo"] = jsi::Function::createFromHostFunction(
         rt,
-        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_checksum_func_validate_private_key"),
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_checksum_method_sshconnection_install_terminfo"),
         0,
         [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
-            return this->cpp_uniffi_uniffi_russh_checksum_func_validate_private_key(rt, thisVal, args, count);
+            return this->cpp_uniffi_uniffi_russh_checksum_method_sshconnection_install_terminfo(rt, thisVal, args, count);
         }
     );
-    props["ubrn_uniffi_uniffi_russh_checksum_method_connectprogresscallback_on_change"] = jsi::Function::createFromHostFunction(
+    props["ubrn_uniffi_uniffi_russh_checksum_method_sshconnection_rekey"] = jsi::Function::createFromHostFunction(
         rt,
-        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_checksum_method_connectprogresscallback_on_change"),
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_checksum_method_sshconnection_rekey"),
         0,
         [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
-            return this->cpp_uniffi_uniffi_russh_checksum_method_connectprogresscallback_on_change(rt, thisVal, args, count);
+            return this->cpp_uniffi_uniffi_russh_checksum_method_sshconnection_rekey(rt, thisVal, args, count);
         }
     );
-    props["ubrn_uniffi_uniffi_russh_checksum_method_connectiondisconnectedcallback_on_change"] = jsi::Function::createFromHostFunction(
+    props["ubrn_uniffi_uniffi_russh_checksum_method_sshconnection_start_shell"] = jsi::Function::createFromHostFunction(
         rt,
-        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_checksum_method_connectiondisconnectedcallback_on_change"),
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_checksum_method_sshconnection_start_shell"),
         0,
         [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
-            return this->cpp_uniffi_uniffi_russh_checksum_method_connectiondisconnectedcallback_on_change(rt, thisVal, args, count);
+            return this->cpp_uniffi_uniffi_russh_checksum_method_sshconnection_start_shell(rt, thisVal, args, count);
         }
     );
-    props["ubrn_uniffi_uniffi_russh_checksum_method_serverkeycallback_on_change"] = jsi::Function::createFromHostFunction(
+    props["ubrn_uniffi_uniffi_russh_checksum_method_terminalemulator_feed"] = jsi::Function::createFromHostFunction(
         rt,
-        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_checksum_method_serverkeycallback_on_change"),
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_checksum_method_terminalemulator_feed"),
         0,
         [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
-            return this->cpp_uniffi_uniffi_russh_checksum_method_serverkeycallback_on_change(rt, thisVal, args, count);
+            return this->cpp_uniffi_uniffi_russh_checksum_method_terminalemulator_feed(rt, thisVal, args, count);
         }
     );
-    props["ubrn_uniffi_uniffi_russh_checksum_method_shellclosedcallback_on_change"] = jsi::Function::createFromHostFunction(
+    props["ubrn_uniffi_uniffi_russh_checksum_method_terminalemulator_resize"] = jsi::Function::createFromHostFunction(
         rt,
-        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_checksum_method_shellclosedcallback_on_change"),
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_checksum_method_terminalemulator_resize"),
         0,
         [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
-            return this->cpp_uniffi_uniffi_russh_checksum_method_shellclosedcallback_on_change(rt, thisVal, args, count);
+            return this->cpp_uniffi_uniffi_russh_checksum_method_terminalemulator_resize(rt, thisVal, args, count);
         }
     );
-    props["ubrn_uniffi_uniffi_russh_checksum_method_shelllistener_on_event"] = jsi::Function::createFromHostFunction(
+    props["ubrn_uniffi_uniffi_russh_checksum_method_terminalemulator_scrollback_len"] = jsi::Function::createFromHostFunction(
         rt,
-        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_checksum_method_shelllistener_on_event"),
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_checksum_method_terminalemulator_scrollback_len"),
         0,
         [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
-            return this->cpp_uniffi_uniffi_russh_checksum_method_shelllistener_on_event(rt, thisVal, args, count);
+            return this->cpp_uniffi_uniffi_russh_checksum_method_terminalemulator_scrollback_len(rt, thisVal, args, count);
         }
     );
-    props["ubrn_uniffi_uniffi_russh_checksum_method_shellsession_add_listener"] = jsi::Function::createFromHostFunction(
+    props["ubrn_uniffi_uniffi_russh_checksum_method_terminalemulator_scrollback_lines"] = jsi::Function::createFromHostFunction(
         rt,
-        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_checksum_method_shellsession_add_listener"),
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_checksum_method_terminalemulator_scrollback_lines"),
         0,
         [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
-            return this->cpp_uniffi_uniffi_russh_checksum_method_shellsession_add_listener(rt, thisVal, args, count);
+            return this->cpp_uniffi_uniffi_russh_checksum_method_terminalemulator_scrollback_lines(rt, thisVal, args, count);
         }
     );
-    props["ubrn_uniffi_uniffi_russh_checksum_method_shellsession_buffer_stats"] = jsi::Function::createFromHostFunction(
+    props["ubrn_uniffi_uniffi_russh_checksum_method_terminalemulator_snapshot"] = jsi::Function::createFromHostFunction(
         rt,
-        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_checksum_method_shellsession_buffer_stats"),
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_checksum_method_terminalemulator_snapshot"),
         0,
         [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
-            return this->cpp_uniffi_uniffi_russh_checksum_method_shellsession_buffer_stats(rt, thisVal, args, count);
+            return this->cpp_uniffi_uniffi_russh_checksum_method_terminalemulator_snapshot(rt, thisVal, args, count);
         }
     );
-    props["ubrn_uniffi_uniffi_russh_checksum_method_shellsession_close"] = jsi::Function::createFromHostFunction(
+    props["ubrn_uniffi_uniffi_russh_checksum_method_terminalemulator_take_damage"] = jsi::Function::createFromHostFunction(
         rt,
-        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_checksum_method_shellsession_close"),
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_checksum_method_terminalemulator_take_damage"),
         0,
         [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
-            return this->cpp_uniffi_uniffi_russh_checksum_method_shellsession_close(rt, thisVal, args, count);
+            return this->cpp_uniffi_uniffi_russh_checksum_method_terminalemulator_take_damage(rt, thisVal, args, count);
         }
     );
-    props["ubrn_uniffi_uniffi_russh_checksum_method_shellsession_current_seq"] = jsi::Function::createFromHostFunction(
+    props["ubrn_uniffi_uniffi_russh_checksum_method_terminalemulator_take_responses"] = jsi::Function::createFromHostFunction(
         rt,
-        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_checksum_method_shellsession_current_seq"),
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_checksum_method_terminalemulator_take_responses"),
         0,
         [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
-            return this->cpp_uniffi_uniffi_russh_checksum_method_shellsession_current_seq(rt, thisVal, args, count);
+            return this->cpp_uniffi_uniffi_russh_checksum_method_terminalemulator_take_responses(rt, thisVal, args, count);
         }
     );
-    props["ubrn_uniffi_uniffi_russh_checksum_method_shellsession_get_info"] = jsi::Function::createFromHostFunction(
+    props["ubrn_uniffi_uniffi_russh_checksum_constructor_connectaborttoken_new"] = jsi::Function::createFromHostFunction(
         rt,
-        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_checksum_method_shellsession_get_info"),
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_checksum_constructor_connectaborttoken_new"),
         0,
         [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
-            return this->cpp_uniffi_uniffi_russh_checksum_method_shellsession_get_info(rt, thisVal, args, count);
+            return this->cpp_uniffi_uniffi_russh_checksum_constructor_connectaborttoken_new(rt, thisVal, args, count);
         }
     );
-    props["ubrn_uniffi_uniffi_russh_checksum_method_shellsession_read_buffer"] = jsi::Function::createFromHostFunction(
+    props["ubrn_uniffi_uniffi_russh_checksum_constructor_terminalemulator_new"] = jsi::Function::createFromHostFunction(
         rt,
-        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_checksum_method_shellsession_read_buffer"),
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_checksum_constructor_terminalemulator_new"),
         0,
         [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
-            return this->cpp_uniffi_uniffi_russh_checksum_method_shellsession_read_buffer(rt, thisVal, args, count);
+            return this->cpp_uniffi_uniffi_russh_checksum_constructor_terminalemulator_new(rt, thisVal, args, count);
         }
     );
-    props["ubrn_uniffi_uniffi_russh_checksum_method_shellsession_remove_listener"] = jsi::Function::createFromHostFunction(
+    props["ubrn_ffi_uniffi_russh_uniffi_contract_version"] = jsi::Function::createFromHostFunction(
         rt,
-        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_checksum_method_shellsession_remove_listener"),
+        jsi::PropNameID::forAscii(rt, "ubrn_ffi_uniffi_russh_uniffi_contract_version"),
         0,
         [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
-            return this->cpp_uniffi_uniffi_russh_checksum_method_shellsession_remove_listener(rt, thisVal, args, count);
+            return this->cpp_ffi_uniffi_russh_uniffi_contract_version(rt, thisVal, args, count);
         }
     );
-    props["ubrn_uniffi_uniffi_russh_checksum_method_shellsession_send_data"] = jsi::Function::createFromHostFunction(
+    props["ubrn_uniffi_internal_fn_method_authbannercallback_ffi__bless_pointer"] = jsi::Function::createFromHostFunction(
         rt,
-        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_checksum_method_shellsession_send_data"),
-        0,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_internal_fn_method_authbannercallback_ffi__bless_pointer"),
+        1,
         [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
-            return this->cpp_uniffi_uniffi_russh_checksum_method_shellsession_send_data(rt, thisVal, args, count);
+            return this->cpp_uniffi_internal_fn_method_authbannercallback_ffi__bless_pointer(rt, thisVal, args, count);
         }
     );
-    props["ubrn_uniffi_uniffi_russh_checksum_method_sshconnection_disconnect"] = jsi::Function::createFromHostFunction(
+    props["ubrn_uniffi_internal_fn_method_castplayer_ffi__bless_pointer"] = jsi::Function::createFromHostFunction(
         rt,
-        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_checksum_method_sshconnection_disconnect"),
-        0,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_internal_fn_method_castplayer_ffi__bless_pointer"),
+        1,
         [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
-            return this->cpp_uniffi_uniffi_russh_checksum_method_sshconnection_disconnect(rt, thisVal, args, count);
+            return this->cpp_uniffi_internal_fn_method_castplayer_ffi__bless_pointer(rt, thisVal, args, count);
         }
     );
-    props["ubrn_uniffi_uniffi_russh_checksum_method_sshconnection_get_info"] = jsi::Function::createFromHostFunction(
+    props["ubrn_uniffi_internal_fn_method_castplayerstatuscallback_ffi__bless_pointer"] = jsi::Function::createFromHostFunction(
         rt,
-        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_checksum_method_sshconnection_get_info"),
-        0,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_internal_fn_method_castplayerstatuscallback_ffi__bless_pointer"),
+        1,
         [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
-            return this->cpp_uniffi_uniffi_russh_checksum_method_sshconnection_get_info(rt, thisVal, args, count);
+            return this->cpp_uniffi_internal_fn_method_castplayerstatuscallback_ffi__bless_pointer(rt, thisVal, args, count);
         }
     );
-    props["ubrn_uniffi_uniffi_russh_checksum_method_sshconnection_start_shell"] = jsi::Function::createFromHostFunction(
+    props["ubrn_uniffi_internal_fn_method_channelpausecallback_ffi__bless_pointer"] = jsi::Function::createFromHostFunction(
         rt,
-        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_uniffi_russh_checksum_method_sshconnection_start_shell"),
-        0,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_internal_fn_method_channelpausecallback_ffi__bless_pointer"),
+        1,
         [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
-            return this->cpp_uniffi_uniffi_russh_checksum_method_sshconnection_start_shell(rt, thisVal, args, count);
+            return this->cpp_uniffi_internal_fn_method_channelpausecallback_ffi__bless_pointer(rt, thisVal, args, count);
         }
     );
-    props["ubrn_ffi_uniffi_russh_uniffi_contract_version"] = jsi::Function::createFromHostFunction(
+    props["ubrn_uniffi_internal_fn_method_connectaborttoken_ffi__bless_pointer"] = jsi::Function::createFromHostFunction(
         rt,
-        jsi::PropNameID::forAscii(rt, "ubrn_ffi_uniffi_russh_uniffi_contract_version"),
-        0,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_internal_fn_method_connectaborttoken_ffi__bless_pointer"),
+        1,
         [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
-            return this->cpp_ffi_uniffi_russh_uniffi_contract_version(rt, thisVal, args, count);
+            return this->cpp_uniffi_internal_fn_method_connectaborttoken_ffi__bless_pointer(rt, thisVal, args, count);
         }
     );
     props["ubrn_uniffi_internal_fn_method_connectprogresscallback_ffi__bless_pointer"] = jsi::Function::createFromHostFunction(
@@ -4447,6 +7321,22 @@ NativeUniffiRussh::NativeUniffiRussh(
             return this->cpp_uniffi_internal_fn_method_connectiondisconnectedcallback_ffi__bless_pointer(rt, thisVal, args, count);
         }
     );
+    props["ubrn_uniffi_internal_fn_method_connectioneventcallback_ffi__bless_pointer"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_internal_fn_method_connectioneventcallback_ffi__bless_pointer"),
+        1,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_internal_fn_method_connectioneventcallback_ffi__bless_pointer(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_uniffi_internal_fn_method_connectionmetricscallback_ffi__bless_pointer"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_internal_fn_method_connectionmetricscallback_ffi__bless_pointer"),
+        1,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_internal_fn_method_connectionmetricscallback_ffi__bless_pointer(rt, thisVal, args, count);
+        }
+    );
     props["ubrn_uniffi_internal_fn_method_serverkeycallback_ffi__bless_pointer"] = jsi::Function::createFromHostFunction(
         rt,
         jsi::PropNameID::forAscii(rt, "ubrn_uniffi_internal_fn_method_serverkeycallback_ffi__bless_pointer"),
@@ -4471,160 +7361,720 @@ NativeUniffiRussh::NativeUniffiRussh(
             return this->cpp_uniffi_internal_fn_method_shelllistener_ffi__bless_pointer(rt, thisVal, args, count);
         }
     );
-    props["ubrn_uniffi_internal_fn_method_shellsession_ffi__bless_pointer"] = jsi::Function::createFromHostFunction(
-        rt,
-        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_internal_fn_method_shellsession_ffi__bless_pointer"),
-        1,
-        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
-            return this->cpp_uniffi_internal_fn_method_shellsession_ffi__bless_pointer(rt, thisVal, args, count);
-        }
+    props["ubrn_uniffi_internal_fn_method_shellsession_ffi__bless_pointer"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_internal_fn_method_shellsession_ffi__bless_pointer"),
+        1,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_internal_fn_method_shellsession_ffi__bless_pointer(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_uniffi_internal_fn_method_sshconnection_ffi__bless_pointer"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_internal_fn_method_sshconnection_ffi__bless_pointer"),
+        1,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_internal_fn_method_sshconnection_ffi__bless_pointer(rt, thisVal, args, count);
+        }
+    );
+    props["ubrn_uniffi_internal_fn_method_terminalemulator_ffi__bless_pointer"] = jsi::Function::createFromHostFunction(
+        rt,
+        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_internal_fn_method_terminalemulator_ffi__bless_pointer"),
+        1,
+        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_internal_fn_method_terminalemulator_ffi__bless_pointer(rt, thisVal, args, count);
+        }
+    );
+}
+
+void NativeUniffiRussh::registerModule(jsi::Runtime &rt, std::shared_ptr<react::CallInvoker> callInvoker) {
+    auto invoker = std::make_shared<uniffi_runtime::UniffiCallInvoker>(callInvoker);
+    auto tm = std::make_shared<NativeUniffiRussh>(rt, invoker);
+    auto obj = rt.global().createFromHostObject(rt, tm);
+    rt.global().setProperty(rt, "NativeUniffiRussh", obj);
+}
+
+void NativeUniffiRussh::unregisterModule(jsi::Runtime &rt) {
+    uniffi::uniffi_russh::registry::clearRegistry();
+}
+
+jsi::Value NativeUniffiRussh::get(jsi::Runtime& rt, const jsi::PropNameID& name) {
+    try {
+        return jsi::Value(rt, props.at(name.utf8(rt)));
+    }
+    catch (std::out_of_range &e) {
+        return jsi::Value::undefined();
+    }
+}
+
+std::vector<jsi::PropNameID> NativeUniffiRussh::getPropertyNames(jsi::Runtime& rt) {
+    std::vector<jsi::PropNameID> rval;
+    for (auto& [key, value] : props) {
+        rval.push_back(jsi::PropNameID::forUtf8(rt, key));
+    }
+    return rval;
+}
+
+void NativeUniffiRussh::set(jsi::Runtime& rt, const jsi::PropNameID& name, const jsi::Value& value) {
+    props.insert_or_assign(name.utf8(rt), &value);
+}
+
+NativeUniffiRussh::~NativeUniffiRussh() {
+    // Cleanup for callback function RustFutureContinuationCallback
+uniffi::uniffi_russh::cb::rustfuturecontinuationcallback::cleanup();
+    // Cleanup for "free" callback function CallbackInterfaceFree
+uniffi::uniffi_russh::st::foreignfuture::foreignfuture::free::cleanup();uniffi::uniffi_russh::st::vtablecallbackinterfaceauthbannercallback::vtablecallbackinterfaceauthbannercallback::free::cleanup();uniffi::uniffi_russh::st::vtablecallbackinterfacecastplayerstatuscallback::vtablecallbackinterfacecastplayerstatuscallback::free::cleanup();uniffi::uniffi_russh::st::vtablecallbackinterfacechannelpausecallback::vtablecallbackinterfacechannelpausecallback::free::cleanup();uniffi::uniffi_russh::st::vtablecallbackinterfaceconnectprogresscallback::vtablecallbackinterfaceconnectprogresscallback::free::cleanup();uniffi::uniffi_russh::st::vtablecallbackinterfaceconnectiondisconnectedcallback::vtablecallbackinterfaceconnectiondisconnectedcallback::free::cleanup();uniffi::uniffi_russh::st::vtablecallbackinterfaceconnectioneventcallback::vtablecallbackinterfaceconnectioneventcallback::free::cleanup();uniffi::uniffi_russh::st::vtablecallbackinterfaceconnectionmetricscallback::vtablecallbackinterfaceconnectionmetricscallback::free::cleanup();uniffi::uniffi_russh::st::vtablecallbackinterfaceserverkeycallback::vtablecallbackinterfaceserverkeycallback::free::cleanup();uniffi::uniffi_russh::st::vtablecallbackinterfaceshellclosedcallback::vtablecallbackinterfaceshellclosedcallback::free::cleanup();uniffi::uniffi_russh::st::vtablecallbackinterfaceshelllistener::vtablecallbackinterfaceshelllistener::free::cleanup();
+    // Cleanup for callback function CallbackInterfaceAuthBannerCallbackMethod0
+uniffi::uniffi_russh::cb::callbackinterfaceauthbannercallbackmethod0::cleanup();
+    // Cleanup for callback function CallbackInterfaceCastPlayerStatusCallbackMethod0
+uniffi::uniffi_russh::cb::callbackinterfacecastplayerstatuscallbackmethod0::cleanup();
+    // Cleanup for callback function CallbackInterfaceChannelPauseCallbackMethod0
+uniffi::uniffi_russh::cb::callbackinterfacechannelpausecallbackmethod0::cleanup();
+    // Cleanup for callback function CallbackInterfaceConnectProgressCallbackMethod0
+uniffi::uniffi_russh::cb::callbackinterfaceconnectprogresscallbackmethod0::cleanup();
+    // Cleanup for callback function CallbackInterfaceConnectionDisconnectedCallbackMethod0
+uniffi::uniffi_russh::cb::callbackinterfaceconnectiondisconnectedcallbackmethod0::cleanup();
+    // Cleanup for callback function CallbackInterfaceConnectionEventCallbackMethod0
+uniffi::uniffi_russh::cb::callbackinterfaceconnectioneventcallbackmethod0::cleanup();
+    // Cleanup for callback function CallbackInterfaceConnectionMetricsCallbackMethod0
+uniffi::uniffi_russh::cb::callbackinterfaceconnectionmetricscallbackmethod0::cleanup();
+    // Cleanup for callback function CallbackInterfaceServerKeyCallbackMethod0
+uniffi::uniffi_russh::cb::callbackinterfaceserverkeycallbackmethod0::cleanup();
+    // Cleanup for callback function CallbackInterfaceShellClosedCallbackMethod0
+uniffi::uniffi_russh::cb::callbackinterfaceshellclosedcallbackmethod0::cleanup();
+    // Cleanup for callback function CallbackInterfaceShellListenerMethod0
+uniffi::uniffi_russh::cb::callbackinterfaceshelllistenermethod0::cleanup();
+}
+
+// Utility functions for serialization/deserialization of strings.
+jsi::Value NativeUniffiRussh::cpp_uniffi_internal_fn_func_ffi__string_to_byte_length(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+    return uniffi_jsi::Bridging<std::string>::string_to_bytelength(rt, args[0]);
+}
+
+jsi::Value NativeUniffiRussh::cpp_uniffi_internal_fn_func_ffi__string_to_arraybuffer(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+    return uniffi_jsi::Bridging<std::string>::string_to_arraybuffer(rt, args[0]);
+}
+
+jsi::Value NativeUniffiRussh::cpp_uniffi_internal_fn_func_ffi__arraybuffer_to_string(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+    return uniffi_jsi::Bridging<std::string>::arraybuffer_to_string(rt, args[0]);
+}jsi::Value NativeUniffiRussh::cpp_uniffi_internal_fn_method_authbannercallback_ffi__bless_pointer(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+    auto pointer = uniffi_jsi::Bridging<uint64_t>::fromJs(rt, callInvoker, args[0]);
+    auto static destructor = [](uint64_t p) {
+        auto pointer = reinterpret_cast<void *>(static_cast<uintptr_t>(p));
+        RustCallStatus status = {0};
+        uniffi_uniffi_russh_fn_free_authbannercallback(pointer, &status);
+    };
+    auto ptrObj = std::make_shared<uniffi_jsi::DestructibleObject>(pointer, destructor);
+    auto obj = jsi::Object::createFromHostObject(rt, ptrObj);
+    return jsi::Value(rt, obj);
+}jsi::Value NativeUniffiRussh::cpp_uniffi_internal_fn_method_castplayer_ffi__bless_pointer(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+    auto pointer = uniffi_jsi::Bridging<uint64_t>::fromJs(rt, callInvoker, args[0]);
+    auto static destructor = [](uint64_t p) {
+        auto pointer = reinterpret_cast<void *>(static_cast<uintptr_t>(p));
+        RustCallStatus status = {0};
+        uniffi_uniffi_russh_fn_free_castplayer(pointer, &status);
+    };
+    auto ptrObj = std::make_shared<uniffi_jsi::DestructibleObject>(pointer, destructor);
+    auto obj = jsi::Object::createFromHostObject(rt, ptrObj);
+    return jsi::Value(rt, obj);
+}jsi::Value NativeUniffiRussh::cpp_uniffi_internal_fn_method_castplayerstatuscallback_ffi__bless_pointer(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+    auto pointer = uniffi_jsi::Bridging<uint64_t>::fromJs(rt, callInvoker, args[0]);
+    auto static destructor = [](uint64_t p) {
+        auto pointer = reinterpret_cast<void *>(static_cast<uintptr_t>(p));
+        RustCallStatus status = {0};
+        uniffi_uniffi_russh_fn_free_castplayerstatuscallback(pointer, &status);
+    };
+    auto ptrObj = std::make_shared<uniffi_jsi::DestructibleObject>(pointer, destructor);
+    auto obj = jsi::Object::createFromHostObject(rt, ptrObj);
+    return jsi::Value(rt, obj);
+}jsi::Value NativeUniffiRussh::cpp_uniffi_internal_fn_method_channelpausecallback_ffi__bless_pointer(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+    auto pointer = uniffi_jsi::Bridging<uint64_t>::fromJs(rt, callInvoker, args[0]);
+    auto static destructor = [](uint64_t p) {
+        auto pointer = reinterpret_cast<void *>(static_cast<uintptr_t>(p));
+        RustCallStatus status = {0};
+        uniffi_uniffi_russh_fn_free_channelpausecallback(pointer, &status);
+    };
+    auto ptrObj = std::make_shared<uniffi_jsi::DestructibleObject>(pointer, destructor);
+    auto obj = jsi::Object::createFromHostObject(rt, ptrObj);
+    return jsi::Value(rt, obj);
+}jsi::Value NativeUniffiRussh::cpp_uniffi_internal_fn_method_connectaborttoken_ffi__bless_pointer(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+    auto pointer = uniffi_jsi::Bridging<uint64_t>::fromJs(rt, callInvoker, args[0]);
+    auto static destructor = [](uint64_t p) {
+        auto pointer = reinterpret_cast<void *>(static_cast<uintptr_t>(p));
+        RustCallStatus status = {0};
+        uniffi_uniffi_russh_fn_free_connectaborttoken(pointer, &status);
+    };
+    auto ptrObj = std::make_shared<uniffi_jsi::DestructibleObject>(pointer, destructor);
+    auto obj = jsi::Object::createFromHostObject(rt, ptrObj);
+    return jsi::Value(rt, obj);
+}jsi::Value NativeUniffiRussh::cpp_uniffi_internal_fn_method_connectprogresscallback_ffi__bless_pointer(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+    auto pointer = uniffi_jsi::Bridging<uint64_t>::fromJs(rt, callInvoker, args[0]);
+    auto static destructor = [](uint64_t p) {
+        auto pointer = reinterpret_cast<void *>(static_cast<uintptr_t>(p));
+        RustCallStatus status = {0};
+        uniffi_uniffi_russh_fn_free_connectprogresscallback(pointer, &status);
+    };
+    auto ptrObj = std::make_shared<uniffi_jsi::DestructibleObject>(pointer, destructor);
+    auto obj = jsi::Object::createFromHostObject(rt, ptrObj);
+    return jsi::Value(rt, obj);
+}jsi::Value NativeUniffiRussh::cpp_uniffi_internal_fn_method_connectiondisconnectedcallback_ffi__bless_pointer(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+    auto pointer = uniffi_jsi::Bridging<uint64_t>::fromJs(rt, callInvoker, args[0]);
+    auto static destructor = [](uint64_t p) {
+        auto pointer = reinterpret_cast<void *>(static_cast<uintptr_t>(p));
+        RustCallStatus status = {0};
+        uniffi_uniffi_russh_fn_free_connectiondisconnectedcallback(pointer, &status);
+    };
+    auto ptrObj = std::make_shared<uniffi_jsi::DestructibleObject>(pointer, destructor);
+    auto obj = jsi::Object::createFromHostObject(rt, ptrObj);
+    return jsi::Value(rt, obj);
+}jsi::Value NativeUniffiRussh::cpp_uniffi_internal_fn_method_connectioneventcallback_ffi__bless_pointer(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+    auto pointer = uniffi_jsi::Bridging<uint64_t>::fromJs(rt, callInvoker, args[0]);
+    auto static destructor = [](uint64_t p) {
+        auto pointer = reinterpret_cast<void *>(static_cast<uintptr_t>(p));
+        RustCallStatus status = {0};
+        uniffi_uniffi_russh_fn_free_connectioneventcallback(pointer, &status);
+    };
+    auto ptrObj = std::make_shared<uniffi_jsi::DestructibleObject>(pointer, destructor);
+    auto obj = jsi::Object::createFromHostObject(rt, ptrObj);
+    return jsi::Value(rt, obj);
+}jsi::Value NativeUniffiRussh::cpp_uniffi_internal_fn_method_connectionmetricscallback_ffi__bless_pointer(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+    auto pointer = uniffi_jsi::Bridging<uint64_t>::fromJs(rt, callInvoker, args[0]);
+    auto static destructor = [](uint64_t p) {
+        auto pointer = reinterpret_cast<void *>(static_cast<uintptr_t>(p));
+        RustCallStatus status = {0};
+        uniffi_uniffi_russh_fn_free_connectionmetricscallback(pointer, &status);
+    };
+    auto ptrObj = std::make_shared<uniffi_jsi::DestructibleObject>(pointer, destructor);
+    auto obj = jsi::Object::createFromHostObject(rt, ptrObj);
+    return jsi::Value(rt, obj);
+}jsi::Value NativeUniffiRussh::cpp_uniffi_internal_fn_method_serverkeycallback_ffi__bless_pointer(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+    auto pointer = uniffi_jsi::Bridging<uint64_t>::fromJs(rt, callInvoker, args[0]);
+    auto static destructor = [](uint64_t p) {
+        auto pointer = reinterpret_cast<void *>(static_cast<uintptr_t>(p));
+        RustCallStatus status = {0};
+        uniffi_uniffi_russh_fn_free_serverkeycallback(pointer, &status);
+    };
+    auto ptrObj = std::make_shared<uniffi_jsi::DestructibleObject>(pointer, destructor);
+    auto obj = jsi::Object::createFromHostObject(rt, ptrObj);
+    return jsi::Value(rt, obj);
+}jsi::Value NativeUniffiRussh::cpp_uniffi_internal_fn_method_shellclosedcallback_ffi__bless_pointer(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+    auto pointer = uniffi_jsi::Bridging<uint64_t>::fromJs(rt, callInvoker, args[0]);
+    auto static destructor = [](uint64_t p) {
+        auto pointer = reinterpret_cast<void *>(static_cast<uintptr_t>(p));
+        RustCallStatus status = {0};
+        uniffi_uniffi_russh_fn_free_shellclosedcallback(pointer, &status);
+    };
+    auto ptrObj = std::make_shared<uniffi_jsi::DestructibleObject>(pointer, destructor);
+    auto obj = jsi::Object::createFromHostObject(rt, ptrObj);
+    return jsi::Value(rt, obj);
+}jsi::Value NativeUniffiRussh::cpp_uniffi_internal_fn_method_shelllistener_ffi__bless_pointer(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+    auto pointer = uniffi_jsi::Bridging<uint64_t>::fromJs(rt, callInvoker, args[0]);
+    auto static destructor = [](uint64_t p) {
+        auto pointer = reinterpret_cast<void *>(static_cast<uintptr_t>(p));
+        RustCallStatus status = {0};
+        uniffi_uniffi_russh_fn_free_shelllistener(pointer, &status);
+    };
+    auto ptrObj = std::make_shared<uniffi_jsi::DestructibleObject>(pointer, destructor);
+    auto obj = jsi::Object::createFromHostObject(rt, ptrObj);
+    return jsi::Value(rt, obj);
+}jsi::Value NativeUniffiRussh::cpp_uniffi_internal_fn_method_shellsession_ffi__bless_pointer(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+    auto pointer = uniffi_jsi::Bridging<uint64_t>::fromJs(rt, callInvoker, args[0]);
+    auto static destructor = [](uint64_t p) {
+        auto pointer = reinterpret_cast<void *>(static_cast<uintptr_t>(p));
+        RustCallStatus status = {0};
+        uniffi_uniffi_russh_fn_free_shellsession(pointer, &status);
+    };
+    auto ptrObj = std::make_shared<uniffi_jsi::DestructibleObject>(pointer, destructor);
+    auto obj = jsi::Object::createFromHostObject(rt, ptrObj);
+    return jsi::Value(rt, obj);
+}jsi::Value NativeUniffiRussh::cpp_uniffi_internal_fn_method_sshconnection_ffi__bless_pointer(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+    auto pointer = uniffi_jsi::Bridging<uint64_t>::fromJs(rt, callInvoker, args[0]);
+    auto static destructor = [](uint64_t p) {
+        auto pointer = reinterpret_cast<void *>(static_cast<uintptr_t>(p));
+        RustCallStatus status = {0};
+        uniffi_uniffi_russh_fn_free_sshconnection(pointer, &status);
+    };
+    auto ptrObj = std::make_shared<uniffi_jsi::DestructibleObject>(pointer, destructor);
+    auto obj = jsi::Object::createFromHostObject(rt, ptrObj);
+    return jsi::Value(rt, obj);
+}jsi::Value NativeUniffiRussh::cpp_uniffi_internal_fn_method_terminalemulator_ffi__bless_pointer(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+    auto pointer = uniffi_jsi::Bridging<uint64_t>::fromJs(rt, callInvoker, args[0]);
+    auto static destructor = [](uint64_t p) {
+        auto pointer = reinterpret_cast<void *>(static_cast<uintptr_t>(p));
+        RustCallStatus status = {0};
+        uniffi_uniffi_russh_fn_free_terminalemulator(pointer, &status);
+    };
+    auto ptrObj = std::make_shared<uniffi_jsi::DestructibleObject>(pointer, destructor);
+    auto obj = jsi::Object::createFromHostObject(rt, ptrObj);
+    return jsi::Value(rt, obj);
+}
+
+// Methods calling directly into the uniffi generated C API of the Rust crate.
+jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_fn_clone_authbannercallback(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+        RustCallStatus status = uniffi::uniffi_russh::Bridging<RustCallStatus>::rustSuccess(rt);
+        auto value = uniffi_uniffi_russh_fn_clone_authbannercallback(uniffi_jsi::Bridging<void *>::fromJs(rt, callInvoker, args[0]), 
+            &status
+        );
+        uniffi::uniffi_russh::Bridging<RustCallStatus>::copyIntoJs(rt, callInvoker, status, args[count - 1]);
+
+        
+        return uniffi_jsi::Bridging<void *>::toJs(rt, callInvoker, value);
+}
+jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_fn_free_authbannercallback(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+        RustCallStatus status = uniffi::uniffi_russh::Bridging<RustCallStatus>::rustSuccess(rt);
+        uniffi_uniffi_russh_fn_free_authbannercallback(uniffi_jsi::Bridging<void *>::fromJs(rt, callInvoker, args[0]), 
+            &status
+        );
+        uniffi::uniffi_russh::Bridging<RustCallStatus>::copyIntoJs(rt, callInvoker, status, args[count - 1]);
+
+        
+        return jsi::Value::undefined();
+}
+jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_fn_init_callback_vtable_authbannercallback(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+    auto vtableInstance =
+        uniffi::uniffi_russh::Bridging<UniffiVTableCallbackInterfaceAuthBannerCallback>::fromJs(
+            rt,
+            callInvoker,
+            args[0]
+        );
+
+    std::lock_guard<std::mutex> lock(uniffi::uniffi_russh::registry::vtableMutex);
+    uniffi_uniffi_russh_fn_init_callback_vtable_authbannercallback(
+        uniffi::uniffi_russh::registry::putTable(
+            "UniffiVTableCallbackInterfaceAuthBannerCallback",
+            vtableInstance
+        )
+    );
+    return jsi::Value::undefined();
+}
+jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_fn_method_authbannercallback_on_change(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+        RustCallStatus status = uniffi::uniffi_russh::Bridging<RustCallStatus>::rustSuccess(rt);
+        uniffi_uniffi_russh_fn_method_authbannercallback_on_change(uniffi_jsi::Bridging<void *>::fromJs(rt, callInvoker, args[0]), uniffi::uniffi_russh::Bridging<RustBuffer>::fromJs(rt, callInvoker, args[1]), 
+            &status
+        );
+        uniffi::uniffi_russh::Bridging<RustCallStatus>::copyIntoJs(rt, callInvoker, status, args[count - 1]);
+
+        
+        return jsi::Value::undefined();
+}
+jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_fn_clone_castplayer(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+        RustCallStatus status = uniffi::uniffi_russh::Bridging<RustCallStatus>::rustSuccess(rt);
+        auto value = uniffi_uniffi_russh_fn_clone_castplayer(uniffi_jsi::Bridging<void *>::fromJs(rt, callInvoker, args[0]), 
+            &status
+        );
+        uniffi::uniffi_russh::Bridging<RustCallStatus>::copyIntoJs(rt, callInvoker, status, args[count - 1]);
+
+        
+        return uniffi_jsi::Bridging<void *>::toJs(rt, callInvoker, value);
+}
+jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_fn_free_castplayer(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+        RustCallStatus status = uniffi::uniffi_russh::Bridging<RustCallStatus>::rustSuccess(rt);
+        uniffi_uniffi_russh_fn_free_castplayer(uniffi_jsi::Bridging<void *>::fromJs(rt, callInvoker, args[0]), 
+            &status
+        );
+        uniffi::uniffi_russh::Bridging<RustCallStatus>::copyIntoJs(rt, callInvoker, status, args[count - 1]);
+
+        
+        return jsi::Value::undefined();
+}
+jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_fn_method_castplayer_add_listener(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+        RustCallStatus status = uniffi::uniffi_russh::Bridging<RustCallStatus>::rustSuccess(rt);
+        auto value = uniffi_uniffi_russh_fn_method_castplayer_add_listener(uniffi_jsi::Bridging<void *>::fromJs(rt, callInvoker, args[0]), uniffi_jsi::Bridging<void *>::fromJs(rt, callInvoker, args[1]), uniffi::uniffi_russh::Bridging<RustBuffer>::fromJs(rt, callInvoker, args[2]), 
+            &status
+        );
+        uniffi::uniffi_russh::Bridging<RustCallStatus>::copyIntoJs(rt, callInvoker, status, args[count - 1]);
+
+        
+        return uniffi_jsi::Bridging<uint64_t>::toJs(rt, callInvoker, value);
+}
+jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_fn_method_castplayer_attach_emulator(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+        RustCallStatus status = uniffi::uniffi_russh::Bridging<RustCallStatus>::rustSuccess(rt);
+        auto value = uniffi_uniffi_russh_fn_method_castplayer_attach_emulator(uniffi_jsi::Bridging<void *>::fromJs(rt, callInvoker, args[0]), uniffi::uniffi_russh::Bridging<RustBuffer>::fromJs(rt, callInvoker, args[1]), 
+            &status
+        );
+        uniffi::uniffi_russh::Bridging<RustCallStatus>::copyIntoJs(rt, callInvoker, status, args[count - 1]);
+
+        
+        return uniffi_jsi::Bridging<void *>::toJs(rt, callInvoker, value);
+}
+jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_fn_method_castplayer_buffer_stats(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+        RustCallStatus status = uniffi::uniffi_russh::Bridging<RustCallStatus>::rustSuccess(rt);
+        auto value = uniffi_uniffi_russh_fn_method_castplayer_buffer_stats(uniffi_jsi::Bridging<void *>::fromJs(rt, callInvoker, args[0]), 
+            &status
+        );
+        uniffi::uniffi_russh::Bridging<RustCallStatus>::copyIntoJs(rt, callInvoker, status, args[count - 1]);
+
+        
+        return uniffi::uniffi_russh::Bridging<RustBuffer>::toJs(rt, callInvoker, value);
+}
+jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_fn_method_castplayer_commands(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+        RustCallStatus status = uniffi::uniffi_russh::Bridging<RustCallStatus>::rustSuccess(rt);
+        auto value = uniffi_uniffi_russh_fn_method_castplayer_commands(uniffi_jsi::Bridging<void *>::fromJs(rt, callInvoker, args[0]), 
+            &status
+        );
+        uniffi::uniffi_russh::Bridging<RustCallStatus>::copyIntoJs(rt, callInvoker, status, args[count - 1]);
+
+        
+        return uniffi::uniffi_russh::Bridging<RustBuffer>::toJs(rt, callInvoker, value);
+}
+jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_fn_method_castplayer_current_seq(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+        RustCallStatus status = uniffi::uniffi_russh::Bridging<RustCallStatus>::rustSuccess(rt);
+        auto value = uniffi_uniffi_russh_fn_method_castplayer_current_seq(uniffi_jsi::Bridging<void *>::fromJs(rt, callInvoker, args[0]), 
+            &status
+        );
+        uniffi::uniffi_russh::Bridging<RustCallStatus>::copyIntoJs(rt, callInvoker, status, args[count - 1]);
+
+        
+        return uniffi_jsi::Bridging<uint64_t>::toJs(rt, callInvoker, value);
+}
+jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_fn_method_castplayer_detach_emulator(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+        RustCallStatus status = uniffi::uniffi_russh::Bridging<RustCallStatus>::rustSuccess(rt);
+        uniffi_uniffi_russh_fn_method_castplayer_detach_emulator(uniffi_jsi::Bridging<void *>::fromJs(rt, callInvoker, args[0]), 
+            &status
+        );
+        uniffi::uniffi_russh::Bridging<RustCallStatus>::copyIntoJs(rt, callInvoker, status, args[count - 1]);
+
+        
+        return jsi::Value::undefined();
+}
+jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_fn_method_castplayer_get_info(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+        RustCallStatus status = uniffi::uniffi_russh::Bridging<RustCallStatus>::rustSuccess(rt);
+        auto value = uniffi_uniffi_russh_fn_method_castplayer_get_info(uniffi_jsi::Bridging<void *>::fromJs(rt, callInvoker, args[0]), 
+            &status
+        );
+        uniffi::uniffi_russh::Bridging<RustCallStatus>::copyIntoJs(rt, callInvoker, status, args[count - 1]);
+
+        
+        return uniffi::uniffi_russh::Bridging<RustBuffer>::toJs(rt, callInvoker, value);
+}
+jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_fn_method_castplayer_get_status(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+        RustCallStatus status = uniffi::uniffi_russh::Bridging<RustCallStatus>::rustSuccess(rt);
+        auto value = uniffi_uniffi_russh_fn_method_castplayer_get_status(uniffi_jsi::Bridging<void *>::fromJs(rt, callInvoker, args[0]), 
+            &status
+        );
+        uniffi::uniffi_russh::Bridging<RustCallStatus>::copyIntoJs(rt, callInvoker, status, args[count - 1]);
+
+        
+        return uniffi::uniffi_russh::Bridging<RustBuffer>::toJs(rt, callInvoker, value);
+}
+jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_fn_method_castplayer_pause(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+        RustCallStatus status = uniffi::uniffi_russh::Bridging<RustCallStatus>::rustSuccess(rt);
+        uniffi_uniffi_russh_fn_method_castplayer_pause(uniffi_jsi::Bridging<void *>::fromJs(rt, callInvoker, args[0]), 
+            &status
+        );
+        uniffi::uniffi_russh::Bridging<RustCallStatus>::copyIntoJs(rt, callInvoker, status, args[count - 1]);
+
+        
+        return jsi::Value::undefined();
+}
+jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_fn_method_castplayer_play(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+        RustCallStatus status = uniffi::uniffi_russh::Bridging<RustCallStatus>::rustSuccess(rt);
+        uniffi_uniffi_russh_fn_method_castplayer_play(uniffi_jsi::Bridging<void *>::fromJs(rt, callInvoker, args[0]), 
+            &status
+        );
+        uniffi::uniffi_russh::Bridging<RustCallStatus>::copyIntoJs(rt, callInvoker, status, args[count - 1]);
+
+        
+        return jsi::Value::undefined();
+}
+jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_fn_method_castplayer_read_buffer(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+        RustCallStatus status = uniffi::uniffi_russh::Bridging<RustCallStatus>::rustSuccess(rt);
+        auto value = uniffi_uniffi_russh_fn_method_castplayer_read_buffer(uniffi_jsi::Bridging<void *>::fromJs(rt, callInvoker, args[0]), uniffi::uniffi_russh::Bridging<RustBuffer>::fromJs(rt, callInvoker, args[1]), uniffi::uniffi_russh::Bridging<RustBuffer>::fromJs(rt, callInvoker, args[2]), 
+            &status
+        );
+        uniffi::uniffi_russh::Bridging<RustCallStatus>::copyIntoJs(rt, callInvoker, status, args[count - 1]);
+
+        
+        return uniffi::uniffi_russh::Bridging<RustBuffer>::toJs(rt, callInvoker, value);
+}
+jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_fn_method_castplayer_read_text(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+        RustCallStatus status = uniffi::uniffi_russh::Bridging<RustCallStatus>::rustSuccess(rt);
+        auto value = uniffi_uniffi_russh_fn_method_castplayer_read_text(uniffi_jsi::Bridging<void *>::fromJs(rt, callInvoker, args[0]), uniffi::uniffi_russh::Bridging<RustBuffer>::fromJs(rt, callInvoker, args[1]), uniffi::uniffi_russh::Bridging<RustBuffer>::fromJs(rt, callInvoker, args[2]), 
+            &status
+        );
+        uniffi::uniffi_russh::Bridging<RustCallStatus>::copyIntoJs(rt, callInvoker, status, args[count - 1]);
+
+        
+        return uniffi::uniffi_russh::Bridging<RustBuffer>::toJs(rt, callInvoker, value);
+}
+jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_fn_method_castplayer_remove_listener(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+        RustCallStatus status = uniffi::uniffi_russh::Bridging<RustCallStatus>::rustSuccess(rt);
+        uniffi_uniffi_russh_fn_method_castplayer_remove_listener(uniffi_jsi::Bridging<void *>::fromJs(rt, callInvoker, args[0]), uniffi_jsi::Bridging<uint64_t>::fromJs(rt, callInvoker, args[1]), 
+            &status
+        );
+        uniffi::uniffi_russh::Bridging<RustCallStatus>::copyIntoJs(rt, callInvoker, status, args[count - 1]);
+
+        
+        return jsi::Value::undefined();
+}
+jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_fn_method_castplayer_search(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+        auto value = uniffi_uniffi_russh_fn_method_castplayer_search(uniffi_jsi::Bridging<void *>::fromJs(rt, callInvoker, args[0]), uniffi::uniffi_russh::Bridging<RustBuffer>::fromJs(rt, callInvoker, args[1]), uniffi::uniffi_russh::Bridging<RustBuffer>::fromJs(rt, callInvoker, args[2])
+        );
+
+        
+        return uniffi_jsi::Bridging</*handle*/ uint64_t>::toJs(rt, callInvoker, value);
+}
+jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_fn_method_castplayer_seek(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+        RustCallStatus status = uniffi::uniffi_russh::Bridging<RustCallStatus>::rustSuccess(rt);
+        uniffi_uniffi_russh_fn_method_castplayer_seek(uniffi_jsi::Bridging<void *>::fromJs(rt, callInvoker, args[0]), uniffi_jsi::Bridging<double>::fromJs(rt, callInvoker, args[1]), 
+            &status
+        );
+        uniffi::uniffi_russh::Bridging<RustCallStatus>::copyIntoJs(rt, callInvoker, status, args[count - 1]);
+
+        
+        return jsi::Value::undefined();
+}
+jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_fn_method_castplayer_set_speed(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+        RustCallStatus status = uniffi::uniffi_russh::Bridging<RustCallStatus>::rustSuccess(rt);
+        uniffi_uniffi_russh_fn_method_castplayer_set_speed(uniffi_jsi::Bridging<void *>::fromJs(rt, callInvoker, args[0]), uniffi_jsi::Bridging<double>::fromJs(rt, callInvoker, args[1]), 
+            &status
+        );
+        uniffi::uniffi_russh::Bridging<RustCallStatus>::copyIntoJs(rt, callInvoker, status, args[count - 1]);
+
+        
+        return jsi::Value::undefined();
+}
+jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_fn_clone_castplayerstatuscallback(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+        RustCallStatus status = uniffi::uniffi_russh::Bridging<RustCallStatus>::rustSuccess(rt);
+        auto value = uniffi_uniffi_russh_fn_clone_castplayerstatuscallback(uniffi_jsi::Bridging<void *>::fromJs(rt, callInvoker, args[0]), 
+            &status
+        );
+        uniffi::uniffi_russh::Bridging<RustCallStatus>::copyIntoJs(rt, callInvoker, status, args[count - 1]);
+
+        
+        return uniffi_jsi::Bridging<void *>::toJs(rt, callInvoker, value);
+}
+jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_fn_free_castplayerstatuscallback(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+        RustCallStatus status = uniffi::uniffi_russh::Bridging<RustCallStatus>::rustSuccess(rt);
+        uniffi_uniffi_russh_fn_free_castplayerstatuscallback(uniffi_jsi::Bridging<void *>::fromJs(rt, callInvoker, args[0]), 
+            &status
+        );
+        uniffi::uniffi_russh::Bridging<RustCallStatus>::copyIntoJs(rt, callInvoker, status, args[count - 1]);
+
+        
+        return jsi::Value::undefined();
+}
+jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_fn_init_callback_vtable_castplayerstatuscallback(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+    auto vtableInstance =
+        uniffi::uniffi_russh::Bridging<UniffiVTableCallbackInterfaceCastPlayerStatusCallback>::fromJs(
+            rt,
+            callInvoker,
+            args[0]
+        );
+
+    std::lock_guard<std::mutex> lock(uniffi::uniffi_russh::registry::vtableMutex);
+    uniffi_uniffi_russh_fn_init_callback_vtable_castplayerstatuscallback(
+        uniffi::uniffi_russh::registry::putTable(
+            "UniffiVTableCallbackInterfaceCastPlayerStatusCallback",
+            vtableInstance
+        )
     );
-    props["ubrn_uniffi_internal_fn_method_sshconnection_ffi__bless_pointer"] = jsi::Function::createFromHostFunction(
-        rt,
-        jsi::PropNameID::forAscii(rt, "ubrn_uniffi_internal_fn_method_sshconnection_ffi__bless_pointer"),
-        1,
-        [this](jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args, size_t count) -> jsi::Value {
-            return this->cpp_uniffi_internal_fn_method_sshconnection_ffi__bless_pointer(rt, thisVal, args, count);
-        }
+    return jsi::Value::undefined();
+}
+jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_fn_method_castplayerstatuscallback_on_change(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+        RustCallStatus status = uniffi::uniffi_russh::Bridging<RustCallStatus>::rustSuccess(rt);
+        uniffi_uniffi_russh_fn_method_castplayerstatuscallback_on_change(uniffi_jsi::Bridging<void *>::fromJs(rt, callInvoker, args[0]), uniffi::uniffi_russh::Bridging<RustBuffer>::fromJs(rt, callInvoker, args[1]), 
+            &status
+        );
+        uniffi::uniffi_russh::Bridging<RustCallStatus>::copyIntoJs(rt, callInvoker, status, args[count - 1]);
+
+        
+        return jsi::Value::undefined();
+}
+jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_fn_clone_channelpausecallback(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+        RustCallStatus status = uniffi::uniffi_russh::Bridging<RustCallStatus>::rustSuccess(rt);
+        auto value = uniffi_uniffi_russh_fn_clone_channelpausecallback(uniffi_jsi::Bridging<void *>::fromJs(rt, callInvoker, args[0]), 
+            &status
+        );
+        uniffi::uniffi_russh::Bridging<RustCallStatus>::copyIntoJs(rt, callInvoker, status, args[count - 1]);
+
+        
+        return uniffi_jsi::Bridging<void *>::toJs(rt, callInvoker, value);
+}
+jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_fn_free_channelpausecallback(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+        RustCallStatus status = uniffi::uniffi_russh::Bridging<RustCallStatus>::rustSuccess(rt);
+        uniffi_uniffi_russh_fn_free_channelpausecallback(uniffi_jsi::Bridging<void *>::fromJs(rt, callInvoker, args[0]), 
+            &status
+        );
+        uniffi::uniffi_russh::Bridging<RustCallStatus>::copyIntoJs(rt, callInvoker, status, args[count - 1]);
+
+        
+        return jsi::Value::undefined();
+}
+jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_fn_init_callback_vtable_channelpausecallback(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+    auto vtableInstance =
+        uniffi::uniffi_russh::Bridging<UniffiVTableCallbackInterfaceChannelPauseCallback>::fromJs(
+            rt,
+            callInvoker,
+            args[0]
+        );
+
+    std::lock_guard<std::mutex> lock(uniffi::uniffi_russh::registry::vtableMutex);
+    uniffi_uniffi_russh_fn_init_callback_vtable_channelpausecallback(
+        uniffi::uniffi_russh::registry::putTable(
+            "UniffiVTableCallbackInterfaceChannelPauseCallback",
+            vtableInstance
+        )
     );
+    return jsi::Value::undefined();
 }
+jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_fn_method_channelpausecallback_on_change(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+        RustCallStatus status = uniffi::uniffi_russh::Bridging<RustCallStatus>::rustSuccess(rt);
+        uniffi_uniffi_russh_fn_method_channelpausecallback_on_change(uniffi_jsi::Bridging<void *>::fromJs(rt, callInvoker, args[0]), uniffi_jsi::Bridging<int8_t>::fromJs(rt, callInvoker, args[1]), 
+            &status
+        );
+        uniffi::uniffi_russh::Bridging<RustCallStatus>::copyIntoJs(rt, callInvoker, status, args[count - 1]);
 
-void NativeUniffiRussh::registerModule(jsi::Runtime &rt, std::shared_ptr<react::CallInvoker> callInvoker) {
-    auto invoker = std::make_shared<uniffi_runtime::UniffiCallInvoker>(callInvoker);
-    auto tm = std::make_shared<NativeUniffiRussh>(rt, invoker);
-    auto obj = rt.global().createFromHostObject(rt, tm);
-    rt.global().setProperty(rt, "NativeUniffiRussh", obj);
+        
+        return jsi::Value::undefined();
 }
+jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_fn_clone_connectaborttoken(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+        RustCallStatus status = uniffi::uniffi_russh::Bridging<RustCallStatus>::rustSuccess(rt);
+        auto value = uniffi_uniffi_russh_fn_clone_connectaborttoken(uniffi_jsi::Bridging<void *>::fromJs(rt, callInvoker, args[0]), 
+            &status
+        );
+        uniffi::uniffi_russh::Bridging<RustCallStatus>::copyIntoJs(rt, callInvoker, status, args[count - 1]);
 
-void NativeUniffiRussh::unregisterModule(jsi::Runtime &rt) {
-    uniffi::uniffi_russh::registry::clearRegistry();
+        
+        return uniffi_jsi::Bridging<void *>::toJs(rt, callInvoker, value);
 }
+jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_fn_free_connectaborttoken(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+        RustCallStatus status = uniffi::uniffi_russh::Bridging<RustCallStatus>::rustSuccess(rt);
+        uniffi_uniffi_russh_fn_free_connectaborttoken(uniffi_jsi::Bridging<void *>::fromJs(rt, callInvoker, args[0]), 
+            &status
+        );
+        uniffi::uniffi_russh::Bridging<RustCallStatus>::copyIntoJs(rt, callInvoker, status, args[count - 1]);
 
-jsi::Value NativeUniffiRussh::get(jsi::Runtime& rt, const jsi::PropNameID& name) {
-    try {
-        return jsi::Value(rt, props.at(name.utf8(rt)));
-    }
-    catch (std::out_of_range &e) {
+        
         return jsi::Value::undefined();
-    }
 }
+jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_fn_constructor_connectaborttoken_new(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+        RustCallStatus status = uniffi::uniffi_russh::Bridging<RustCallStatus>::rustSuccess(rt);
+        auto value = uniffi_uniffi_russh_fn_constructor_connectaborttoken_new(
+            &status
+        );
+        uniffi::uniffi_russh::Bridging<RustCallStatus>::copyIntoJs(rt, callInvoker, status, args[count - 1]);
 
-std::vector<jsi::PropNameID> NativeUniffiRussh::getPropertyNames(jsi::Runtime& rt) {
-    std::vector<jsi::PropNameID> rval;
-    for (auto& [key, value] : props) {
-        rval.push_back(jsi::PropNameID::forUtf8(rt, key));
-    }
-    return rval;
+        
+        return uniffi_jsi::Bridging<void *>::toJs(rt, callInvoker, value);
 }
+jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_fn_method_connectaborttoken_abort(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+        RustCallStatus status = uniffi::uniffi_russh::Bridging<RustCallStatus>::rustSuccess(rt);
+        uniffi_uniffi_russh_fn_method_connectaborttoken_abort(uniffi_jsi::Bridging<void *>::fromJs(rt, callInvoker, args[0]), 
+            &status
+        );
+        uniffi::uniffi_russh::Bridging<RustCallStatus>::copyIntoJs(rt, callInvoker, status, args[count - 1]);
 
-void NativeUniffiRussh::set(jsi::Runtime& rt, const jsi::PropNameID& name, const jsi::Value& value) {
-    props.insert_or_assign(name.utf8(rt), &value);
+        
+        return jsi::Value::undefined();
 }
+jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_fn_method_connectaborttoken_is_aborted(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+        RustCallStatus status = uniffi::uniffi_russh::Bridging<RustCallStatus>::rustSuccess(rt);
+        auto value = uniffi_uniffi_russh_fn_method_connectaborttoken_is_aborted(uniffi_jsi::Bridging<void *>::fromJs(rt, callInvoker, args[0]), 
+            &status
+        );
+        uniffi::uniffi_russh::Bridging<RustCallStatus>::copyIntoJs(rt, callInvoker, status, args[count - 1]);
 
-NativeUniffiRussh::~NativeUniffiRussh() {
-    // Cleanup for callback function RustFutureContinuationCallback
-uniffi::uniffi_russh::cb::rustfuturecontinuationcallback::cleanup();
-    // Cleanup for "free" callback function CallbackInterfaceFree
-uniffi::uniffi_russh::st::foreignfuture::foreignfuture::free::cleanup();uniffi::uniffi_russh::st::vtablecallbackinterfaceconnectprogresscallback::vtablecallbackinterfaceconnectprogresscallback::free::cleanup();uniffi::uniffi_russh::st::vtablecallbackinterfaceconnectiondisconnectedcallback::vtablecallbackinterfaceconnectiondisconnectedcallback::free::cleanup();uniffi::uniffi_russh::st::vtablecallbackinterfaceserverkeycallback::vtablecallbackinterfaceserverkeycallback::free::cleanup();uniffi::uniffi_russh::st::vtablecallbackinterfaceshellclosedcallback::vtablecallbackinterfaceshellclosedcallback::free::cleanup();uniffi::uniffi_russh::st::vtablecallbackinterfaceshelllistener::vtablecallbackinterfaceshelllistener::free::cleanup();
-    // Cleanup for callback function CallbackInterfaceConnectProgressCallbackMethod0
-uniffi::uniffi_russh::cb::callbackinterfaceconnectprogresscallbackmethod0::cleanup();
-    // Cleanup for callback function CallbackInterfaceConnectionDisconnectedCallbackMethod0
-uniffi::uniffi_russh::cb::callbackinterfaceconnectiondisconnectedcallbackmethod0::cleanup();
-    // Cleanup for callback function CallbackInterfaceServerKeyCallbackMethod0
-uniffi::uniffi_russh::cb::callbackinterfaceserverkeycallbackmethod0::cleanup();
-    // Cleanup for callback function CallbackInterfaceShellClosedCallbackMethod0
-uniffi::uniffi_russh::cb::callbackinterfaceshellclosedcallbackmethod0::cleanup();
-    // Cleanup for callback function CallbackInterfaceShellListenerMethod0
-uniffi::uniffi_russh::cb::callbackinterfaceshelllistenermethod0::cleanup();
+        
+        return uniffi_jsi::Bridging<int8_t>::toJs(rt, callInvoker, value);
+}
+jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_fn_clone_connectprogresscallback(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+        RustCallStatus status = uniffi::uniffi_russh::Bridging<RustCallStatus>::rustSuccess(rt);
+        auto value = uniffi_uniffi_russh_fn_clone_connectprogresscallback(uniffi_jsi::Bridging<void *>::fromJs(rt, callInvoker, args[0]), 
+            &status
+        );
+        uniffi::uniffi_russh::Bridging<RustCallStatus>::copyIntoJs(rt, callInvoker, status, args[count - 1]);
+
+        
+        return uniffi_jsi::Bridging<void *>::toJs(rt, callInvoker, value);
 }
+jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_fn_free_connectprogresscallback(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+        RustCallStatus status = uniffi::uniffi_russh::Bridging<RustCallStatus>::rustSuccess(rt);
+        uniffi_uniffi_russh_fn_free_connectprogresscallback(uniffi_jsi::Bridging<void *>::fromJs(rt, callInvoker, args[0]), 
+            &status
+        );
+        uniffi::uniffi_russh::Bridging<RustCallStatus>::copyIntoJs(rt, callInvoker, status, args[count - 1]);
 
-// Utility functions for serialization/deserialization of strings.
-jsi::Value NativeUniffiRussh::cpp_uniffi_internal_fn_func_ffi__string_to_byte_length(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
-    return uniffi_jsi::Bridging<std::string>::string_to_bytelength(rt, args[0]);
+        
+        return jsi::Value::undefined();
 }
+jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_fn_init_callback_vtable_connectprogresscallback(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+    auto vtableInstance =
+        uniffi::uniffi_russh::Bridging<UniffiVTableCallbackInterfaceConnectProgressCallback>::fromJs(
+            rt,
+            callInvoker,
+            args[0]
+        );
 
-jsi::Value NativeUniffiRussh::cpp_uniffi_internal_fn_func_ffi__string_to_arraybuffer(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
-    return uniffi_jsi::Bridging<std::string>::string_to_arraybuffer(rt, args[0]);
+    std::lock_guard<std::mutex> lock(uniffi::uniffi_russh::registry::vtableMutex);
+    uniffi_uniffi_russh_fn_init_callback_vtable_connectprogresscallback(
+        uniffi::uniffi_russh::registry::putTable(
+            "UniffiVTableCallbackInterfaceConnectProgressCallback",
+            vtableInstance
+        )
+    );
+    return jsi::Value::undefined();
 }
+jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_fn_method_connectprogresscallback_on_change(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+        RustCallStatus status = uniffi::uniffi_russh::Bridging<RustCallStatus>::rustSuccess(rt);
+        uniffi_uniffi_russh_fn_method_connectprogresscallback_on_change(uniffi_jsi::Bridging<void *>::fromJs(rt, callInvoker, args[0]), uniffi::uniffi_russh::Bridging<RustBuffer>::fromJs(rt, callInvoker, args[1]), 
+            &status
+        );
+        uniffi::uniffi_russh::Bridging<RustCallStatus>::copyIntoJs(rt, callInvoker, status, args[count - 1]);
 
-jsi::Value NativeUniffiRussh::cpp_uniffi_internal_fn_func_ffi__arraybuffer_to_string(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
-    return uniffi_jsi::Bridging<std::string>::arraybuffer_to_string(rt, args[0]);
-}jsi::Value NativeUniffiRussh::cpp_uniffi_internal_fn_method_connectprogresscallback_ffi__bless_pointer(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
-    auto pointer = uniffi_jsi::Bridging<uint64_t>::fromJs(rt, callInvoker, args[0]);
-    auto static destructor = [](uint64_t p) {
-        auto pointer = reinterpret_cast<void *>(static_cast<uintptr_t>(p));
-        RustCallStatus status = {0};
-        uniffi_uniffi_russh_fn_free_connectprogresscallback(pointer, &status);
-    };
-    auto ptrObj = std::make_shared<uniffi_jsi::DestructibleObject>(pointer, destructor);
-    auto obj = jsi::Object::createFromHostObject(rt, ptrObj);
-    return jsi::Value(rt, obj);
-}jsi::Value NativeUniffiRussh::cpp_uniffi_internal_fn_method_connectiondisconnectedcallback_ffi__bless_pointer(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
-    auto pointer = uniffi_jsi::Bridging<uint64_t>::fromJs(rt, callInvoker, args[0]);
-    auto static destructor = [](uint64_t p) {
-        auto pointer = reinterpret_cast<void *>(static_cast<uintptr_t>(p));
-        RustCallStatus status = {0};
-        uniffi_uniffi_russh_fn_free_connectiondisconnectedcallback(pointer, &status);
-    };
-    auto ptrObj = std::make_shared<uniffi_jsi::DestructibleObject>(pointer, destructor);
-    auto obj = jsi::Object::createFromHostObject(rt, ptrObj);
-    return jsi::Value(rt, obj);
-}jsi::Value NativeUniffiRussh::cpp_uniffi_internal_fn_method_serverkeycallback_ffi__bless_pointer(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
-    auto pointer = uniffi_jsi::Bridging<uint64_t>::fromJs(rt, callInvoker, args[0]);
-    auto static destructor = [](uint64_t p) {
-        auto pointer = reinterpret_cast<void *>(static_cast<uintptr_t>(p));
-        RustCallStatus status = {0};
-        uniffi_uniffi_russh_fn_free_serverkeycallback(pointer, &status);
-    };
-    auto ptrObj = std::make_shared<uniffi_jsi::DestructibleObject>(pointer, destructor);
-    auto obj = jsi::Object::createFromHostObject(rt, ptrObj);
-    return jsi::Value(rt, obj);
-}jsi::Value NativeUniffiRussh::cpp_uniffi_internal_fn_method_shellclosedcallback_ffi__bless_pointer(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
-    auto pointer = uniffi_jsi::Bridging<uint64_t>::fromJs(rt, callInvoker, args[0]);
-    auto static destructor = [](uint64_t p) {
-        auto pointer = reinterpret_cast<void *>(static_cast<uintptr_t>(p));
-        RustCallStatus status = {0};
-        uniffi_uniffi_russh_fn_free_shellclosedcallback(pointer, &status);
-    };
-    auto ptrObj = std::make_shared<uniffi_jsi::DestructibleObject>(pointer, destructor);
-    auto obj = jsi::Object::createFromHostObject(rt, ptrObj);
-    return jsi::Value(rt, obj);
-}jsi::Value NativeUniffiRussh::cpp_uniffi_internal_fn_method_shelllistener_ffi__bless_pointer(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
-    auto pointer = uniffi_jsi::Bridging<uint64_t>::fromJs(rt, callInvoker, args[0]);
-    auto static destructor = [](uint64_t p) {
-        auto pointer = reinterpret_cast<void *>(static_cast<uintptr_t>(p));
-        RustCallStatus status = {0};
-        uniffi_uniffi_russh_fn_free_shelllistener(pointer, &status);
-    };
-    auto ptrObj = std::make_shared<uniffi_jsi::DestructibleObject>(pointer, destructor);
-    auto obj = jsi::Object::createFromHostObject(rt, ptrObj);
-    return jsi::Value(rt, obj);
-}jsi::Value NativeUniffiRussh::cpp_uniffi_internal_fn_method_shellsession_ffi__bless_pointer(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
-    auto pointer = uniffi_jsi::Bridging<uint64_t>::fromJs(rt, callInvoker, args[0]);
-    auto static destructor = [](uint64_t p) {
-        auto pointer = reinterpret_cast<void *>(static_cast<uintptr_t>(p));
-        RustCallStatus status = {0};
-        uniffi_uniffi_russh_fn_free_shellsession(pointer, &status);
-    };
-    auto ptrObj = std::make_shared<uniffi_jsi::DestructibleObject>(pointer, destructor);
-    auto obj = jsi::Object::createFromHostObject(rt, ptrObj);
-    return jsi::Value(rt, obj);
-}jsi::Value NativeUniffiRussh::cpp_uniffi_internal_fn_method_sshconnection_ffi__bless_pointer(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
-    auto pointer = uniffi_jsi::Bridging<uint64_t>::fromJs(rt, callInvoker, args[0]);
-    auto static destructor = [](uint64_t p) {
-        auto pointer = reinterpret_cast<void *>(static_cast<uintptr_t>(p));
-        RustCallStatus status = {0};
-        uniffi_uniffi_russh_fn_free_sshconnection(pointer, &status);
-    };
-    auto ptrObj = std::make_shared<uniffi_jsi::DestructibleObject>(pointer, destructor);
-    auto obj = jsi::Object::createFromHostObject(rt, ptrObj);
-    return jsi::Value(rt, obj);
+        
+        return jsi::Value::undefined();
+}
+jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_fn_clone_connectiondisconnectedcallback(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+        RustCallStatus status = uniffi::uniffi_russh::Bridging<RustCallStatus>::rustSuccess(rt);
+        auto value = uniffi_uniffi_russh_fn_clone_connectiondisconnectedcallback(uniffi_jsi::Bridging<void *>::fromJs(rt, callInvoker, args[0]), 
+            &status
+        );
+        uniffi::uniffi_russh::Bridging<RustCallStatus>::copyIntoJs(rt, callInvoker, status, args[count - 1]);
+
+        
+        return uniffi_jsi::Bridging<void *>::toJs(rt, callInvoker, value);
+}
+jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_fn_free_connectiondisconnectedcallback(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+        RustCallStatus status = uniffi::uniffi_russh::Bridging<RustCallStatus>::rustSuccess(rt);
+        uniffi_uniffi_russh_fn_free_connectiondisconnectedcallback(uniffi_jsi::Bridging<void *>::fromJs(rt, callInvoker, args[0]), 
+            &status
+        );
+        uniffi::uniffi_russh::Bridging<RustCallStatus>::copyIntoJs(rt, callInvoker, status, args[count - 1]);
+
+        
+        return jsi::Value::undefined();
 }
+jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_fn_init_callback_vtable_connectiondisconnectedcallback(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+    auto vtableInstance =
+        uniffi::uniffi_russh::Bridging<UniffiVTableCallbackInterfaceConnectionDisconnectedCallback>::fromJs(
+            rt,
+            callInvoker,
+            args[0]
+        );
 
-// Methods calling directly into the uniffi generated C API of the Rust crate.
-jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_fn_clone_connectprogresscallback(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+    std::lock_guard<std::mutex> lock(uniffi::uniffi_russh::registry::vtableMutex);
+    uniffi_uniffi_russh_fn_init_callback_vtable_connectiondisconnectedcallback(
+        uniffi::uniffi_russh::registry::putTable(
+            "UniffiVTableCallbackInterfaceConnectionDisconnectedCallback",
+            vtableInstance
+        )
+    );
+    return jsi::Value::undefined();
+}
+jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_fn_method_connectiondisconnectedcallback_on_change(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
         RustCallStatus status = uniffi::uniffi_russh::Bridging<RustCallStatus>::rustSuccess(rt);
-        auto value = uniffi_uniffi_russh_fn_clone_connectprogresscallback(uniffi_jsi::Bridging<void *>::fromJs(rt, callInvoker, args[0]), 
+        uniffi_uniffi_russh_fn_method_connectiondisconnectedcallback_on_change(uniffi_jsi::Bridging<void *>::fromJs(rt, callInvoker, args[0]), uniffi::uniffi_russh::Bridging<RustBuffer>::fromJs(rt, callInvoker, args[1]), 
+            &status
+        );
+        uniffi::uniffi_russh::Bridging<RustCallStatus>::copyIntoJs(rt, callInvoker, status, args[count - 1]);
+
+        
+        return jsi::Value::undefined();
+}
+jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_fn_clone_connectioneventcallback(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+        RustCallStatus status = uniffi::uniffi_russh::Bridging<RustCallStatus>::rustSuccess(rt);
+        auto value = uniffi_uniffi_russh_fn_clone_connectioneventcallback(uniffi_jsi::Bridging<void *>::fromJs(rt, callInvoker, args[0]), 
             &status
         );
         uniffi::uniffi_russh::Bridging<RustCallStatus>::copyIntoJs(rt, callInvoker, status, args[count - 1]);
@@ -4632,9 +8082,9 @@ jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_fn_clone_connectprogressca
         
         return uniffi_jsi::Bridging<void *>::toJs(rt, callInvoker, value);
 }
-jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_fn_free_connectprogresscallback(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_fn_free_connectioneventcallback(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
         RustCallStatus status = uniffi::uniffi_russh::Bridging<RustCallStatus>::rustSuccess(rt);
-        uniffi_uniffi_russh_fn_free_connectprogresscallback(uniffi_jsi::Bridging<void *>::fromJs(rt, callInvoker, args[0]), 
+        uniffi_uniffi_russh_fn_free_connectioneventcallback(uniffi_jsi::Bridging<void *>::fromJs(rt, callInvoker, args[0]), 
             &status
         );
         uniffi::uniffi_russh::Bridging<RustCallStatus>::copyIntoJs(rt, callInvoker, status, args[count - 1]);
@@ -4642,26 +8092,26 @@ jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_fn_free_connectprogresscal
         
         return jsi::Value::undefined();
 }
-jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_fn_init_callback_vtable_connectprogresscallback(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_fn_init_callback_vtable_connectioneventcallback(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
     auto vtableInstance =
-        uniffi::uniffi_russh::Bridging<UniffiVTableCallbackInterfaceConnectProgressCallback>::fromJs(
+        uniffi::uniffi_russh::Bridging<UniffiVTableCallbackInterfaceConnectionEventCallback>::fromJs(
             rt,
             callInvoker,
             args[0]
         );
 
     std::lock_guard<std::mutex> lock(uniffi::uniffi_russh::registry::vtableMutex);
-    uniffi_uniffi_russh_fn_init_callback_vtable_connectprogresscallback(
+    uniffi_uniffi_russh_fn_init_callback_vtable_connectioneventcallback(
         uniffi::uniffi_russh::registry::putTable(
-            "UniffiVTableCallbackInterfaceConnectProgressCallback",
+            "UniffiVTableCallbackInterfaceConnectionEventCallback",
             vtableInstance
         )
     );
     return jsi::Value::undefined();
 }
-jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_fn_method_connectprogresscallback_on_change(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_fn_method_connectioneventcallback_on_change(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
         RustCallStatus status = uniffi::uniffi_russh::Bridging<RustCallStatus>::rustSuccess(rt);
-        uniffi_uniffi_russh_fn_method_connectprogresscallback_on_change(uniffi_jsi::Bridging<void *>::fromJs(rt, callInvoker, args[0]), uniffi::uniffi_russh::Bridging<RustBuffer>::fromJs(rt, callInvoker, args[1]), 
+        uniffi_uniffi_russh_fn_method_connectioneventcallback_on_change(uniffi_jsi::Bridging<void *>::fromJs(rt, callInvoker, args[0]), uniffi::uniffi_russh::Bridging<RustBuffer>::fromJs(rt, callInvoker, args[1]), 
             &status
         );
         uniffi::uniffi_russh::Bridging<RustCallStatus>::copyIntoJs(rt, callInvoker, status, args[count - 1]);
@@ -4669,9 +8119,9 @@ jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_fn_method_connectprogressc
         
         return jsi::Value::undefined();
 }
-jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_fn_clone_connectiondisconnectedcallback(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_fn_clone_connectionmetricscallback(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
         RustCallStatus status = uniffi::uniffi_russh::Bridging<RustCallStatus>::rustSuccess(rt);
-        auto value = uniffi_uniffi_russh_fn_clone_connectiondisconnectedcallback(uniffi_jsi::Bridging<void *>::fromJs(rt, callInvoker, args[0]), 
+        auto value = uniffi_uniffi_russh_fn_clone_connectionmetricscallback(uniffi_jsi::Bridging<void *>::fromJs(rt, callInvoker, args[0]), 
             &status
         );
         uniffi::uniffi_russh::Bridging<RustCallStatus>::copyIntoJs(rt, callInvoker, status, args[count - 1]);
@@ -4679,9 +8129,9 @@ jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_fn_clone_connectiondisconn
         
         return uniffi_jsi::Bridging<void *>::toJs(rt, callInvoker, value);
 }
-jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_fn_free_connectiondisconnectedcallback(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_fn_free_connectionmetricscallback(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
         RustCallStatus status = uniffi::uniffi_russh::Bridging<RustCallStatus>::rustSuccess(rt);
-        uniffi_uniffi_russh_fn_free_connectiondisconnectedcallback(uniffi_jsi::Bridging<void *>::fromJs(rt, callInvoker, args[0]), 
+        uniffi_uniffi_russh_fn_free_connectionmetricscallback(uniffi_jsi::Bridging<void *>::fromJs(rt, callInvoker, args[0]), 
             &status
         );
         uniffi::uniffi_russh::Bridging<RustCallStatus>::copyIntoJs(rt, callInvoker, status, args[count - 1]);
@@ -4689,26 +8139,26 @@ jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_fn_free_connectiondisconne
         
         return jsi::Value::undefined();
 }
-jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_fn_init_callback_vtable_connectiondisconnectedcallback(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_fn_init_callback_vtable_connectionmetricscallback(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
     auto vtableInstance =
-        uniffi::uniffi_russh::Bridging<UniffiVTableCallbackInterfaceConnectionDisconnectedCallback>::fromJs(
+        uniffi::uniffi_russh::Bridging<UniffiVTableCallbackInterfaceConnectionMetricsCallback>::fromJs(
             rt,
             callInvoker,
             args[0]
         );
 
     std::lock_guard<std::mutex> lock(uniffi::uniffi_russh::registry::vtableMutex);
-    uniffi_uniffi_russh_fn_init_callback_vtable_connectiondisconnectedcallback(
+    uniffi_uniffi_russh_fn_init_callback_vtable_connectionmetricscallback(
         uniffi::uniffi_russh::registry::putTable(
-            "UniffiVTableCallbackInterfaceConnectionDisconnectedCallback",
+            "UniffiVTableCallbackInterfaceConnectionMetricsCallback",
             vtableInstance
         )
     );
     return jsi::Value::undefined();
 }
-jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_fn_method_connectiondisconnectedcallback_on_change(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_fn_method_connectionmetricscallback_on_change(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
         RustCallStatus status = uniffi::uniffi_russh::Bridging<RustCallStatus>::rustSuccess(rt);
-        uniffi_uniffi_russh_fn_method_connectiondisconnectedcallback_on_change(uniffi_jsi::Bridging<void *>::fromJs(rt, callInvoker, args[0]), uniffi::uniffi_russh::Bridging<RustBuffer>::fromJs(rt, callInvoker, args[1]), 
+        uniffi_uniffi_russh_fn_method_connectionmetricscallback_on_change(uniffi_jsi::Bridging<void *>::fromJs(rt, callInvoker, args[0]), uniffi::uniffi_russh::Bridging<RustBuffer>::fromJs(rt, callInvoker, args[1]), 
             &status
         );
         uniffi::uniffi_russh::Bridging<RustCallStatus>::copyIntoJs(rt, callInvoker, status, args[count - 1]);
@@ -4884,6 +8334,16 @@ jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_fn_method_shellsession_add
         
         return uniffi_jsi::Bridging<uint64_t>::toJs(rt, callInvoker, value);
 }
+jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_fn_method_shellsession_attach_emulator(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+        RustCallStatus status = uniffi::uniffi_russh::Bridging<RustCallStatus>::rustSuccess(rt);
+        auto value = uniffi_uniffi_russh_fn_method_shellsession_attach_emulator(uniffi_jsi::Bridging<void *>::fromJs(rt, callInvoker, args[0]), uniffi::uniffi_russh::Bridging<RustBuffer>::fromJs(rt, callInvoker, args[1]), 
+            &status
+        );
+        uniffi::uniffi_russh::Bridging<RustCallStatus>::copyIntoJs(rt, callInvoker, status, args[count - 1]);
+
+        
+        return uniffi_jsi::Bridging<void *>::toJs(rt, callInvoker, value);
+}
 jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_fn_method_shellsession_buffer_stats(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
         RustCallStatus status = uniffi::uniffi_russh::Bridging<RustCallStatus>::rustSuccess(rt);
         auto value = uniffi_uniffi_russh_fn_method_shellsession_buffer_stats(uniffi_jsi::Bridging<void *>::fromJs(rt, callInvoker, args[0]), 
@@ -4901,6 +8361,16 @@ jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_fn_method_shellsession_clo
         
         return uniffi_jsi::Bridging</*handle*/ uint64_t>::toJs(rt, callInvoker, value);
 }
+jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_fn_method_shellsession_commands(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+        RustCallStatus status = uniffi::uniffi_russh::Bridging<RustCallStatus>::rustSuccess(rt);
+        auto value = uniffi_uniffi_russh_fn_method_shellsession_commands(uniffi_jsi::Bridging<void *>::fromJs(rt, callInvoker, args[0]), 
+            &status
+        );
+        uniffi::uniffi_russh::Bridging<RustCallStatus>::copyIntoJs(rt, callInvoker, status, args[count - 1]);
+
+        
+        return uniffi::uniffi_russh::Bridging<RustBuffer>::toJs(rt, callInvoker, value);
+}
 jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_fn_method_shellsession_current_seq(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
         RustCallStatus status = uniffi::uniffi_russh::Bridging<RustCallStatus>::rustSuccess(rt);
         auto value = uniffi_uniffi_russh_fn_method_shellsession_current_seq(uniffi_jsi::Bridging<void *>::fromJs(rt, callInvoker, args[0]), 
@@ -4911,6 +8381,16 @@ jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_fn_method_shellsession_cur
         
         return uniffi_jsi::Bridging<uint64_t>::toJs(rt, callInvoker, value);
 }
+jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_fn_method_shellsession_detach_emulator(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+        RustCallStatus status = uniffi::uniffi_russh::Bridging<RustCallStatus>::rustSuccess(rt);
+        uniffi_uniffi_russh_fn_method_shellsession_detach_emulator(uniffi_jsi::Bridging<void *>::fromJs(rt, callInvoker, args[0]), 
+            &status
+        );
+        uniffi::uniffi_russh::Bridging<RustCallStatus>::copyIntoJs(rt, callInvoker, status, args[count - 1]);
+
+        
+        return jsi::Value::undefined();
+}
 jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_fn_method_shellsession_get_info(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
         RustCallStatus status = uniffi::uniffi_russh::Bridging<RustCallStatus>::rustSuccess(rt);
         auto value = uniffi_uniffi_russh_fn_method_shellsession_get_info(uniffi_jsi::Bridging<void *>::fromJs(rt, callInvoker, args[0]), 
@@ -4929,6 +8409,16 @@ jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_fn_method_shellsession_rea
         uniffi::uniffi_russh::Bridging<RustCallStatus>::copyIntoJs(rt, callInvoker, status, args[count - 1]);
 
         
+        return uniffi::uniffi_russh::Bridging<RustBuffer>::toJs(rt, callInvoker, value);
+}
+jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_fn_method_shellsession_read_text(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+        RustCallStatus status = uniffi::uniffi_russh::Bridging<RustCallStatus>::rustSuccess(rt);
+        auto value = uniffi_uniffi_russh_fn_method_shellsession_read_text(uniffi_jsi::Bridging<void *>::fromJs(rt, callInvoker, args[0]), uniffi::uniffi_russh::Bridging<RustBuffer>::fromJs(rt, callInvoker, args[1]), uniffi::uniffi_russh::Bridging<RustBuffer>::fromJs(rt, callInvoker, args[2]), 
+            &status
+        );
+        uniffi::uniffi_russh::Bridging<RustCallStatus>::copyIntoJs(rt, callInvoker, status, args[count - 1]);
+
+        
         return uniffi::uniffi_russh::Bridging<RustBuffer>::toJs(rt, callInvoker, value);
 }
 jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_fn_method_shellsession_remove_listener(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
@@ -4941,11 +8431,66 @@ jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_fn_method_shellsession_rem
         
         return jsi::Value::undefined();
 }
+jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_fn_method_shellsession_resize(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+        auto value = uniffi_uniffi_russh_fn_method_shellsession_resize(uniffi_jsi::Bridging<void *>::fromJs(rt, callInvoker, args[0]), uniffi_jsi::Bridging<uint32_t>::fromJs(rt, callInvoker, args[1]), uniffi_jsi::Bridging<uint32_t>::fromJs(rt, callInvoker, args[2]), uniffi_jsi::Bridging<uint32_t>::fromJs(rt, callInvoker, args[3]), uniffi_jsi::Bridging<uint32_t>::fromJs(rt, callInvoker, args[4])
+        );
+
+        
+        return uniffi_jsi::Bridging</*handle*/ uint64_t>::toJs(rt, callInvoker, value);
+}
+jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_fn_method_shellsession_search(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+        auto value = uniffi_uniffi_russh_fn_method_shellsession_search(uniffi_jsi::Bridging<void *>::fromJs(rt, callInvoker, args[0]), uniffi::uniffi_russh::Bridging<RustBuffer>::fromJs(rt, callInvoker, args[1]), uniffi::uniffi_russh::Bridging<RustBuffer>::fromJs(rt, callInvoker, args[2])
+        );
+
+        
+        return uniffi_jsi::Bridging</*handle*/ uint64_t>::toJs(rt, callInvoker, value);
+}
+jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_fn_method_shellsession_send_break(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+        auto value = uniffi_uniffi_russh_fn_method_shellsession_send_break(uniffi_jsi::Bridging<void *>::fromJs(rt, callInvoker, args[0]), uniffi_jsi::Bridging<uint32_t>::fromJs(rt, callInvoker, args[1])
+        );
+
+        
+        return uniffi_jsi::Bridging</*handle*/ uint64_t>::toJs(rt, callInvoker, value);
+}
 jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_fn_method_shellsession_send_data(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
         auto value = uniffi_uniffi_russh_fn_method_shellsession_send_data(uniffi_jsi::Bridging<void *>::fromJs(rt, callInvoker, args[0]), uniffi::uniffi_russh::Bridging<RustBuffer>::fromJs(rt, callInvoker, args[1])
         );
 
         
+        return uniffi_jsi::Bridging</*handle*/ uint64_t>::toJs(rt, callInvoker, value);
+}
+jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_fn_method_shellsession_send_signal(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+        auto value = uniffi_uniffi_russh_fn_method_shellsession_send_signal(uniffi_jsi::Bridging<void *>::fromJs(rt, callInvoker, args[0]), uniffi::uniffi_russh::Bridging<RustBuffer>::fromJs(rt, callInvoker, args[1])
+        );
+
+        
+        return uniffi_jsi::Bridging</*handle*/ uint64_t>::toJs(rt, callInvoker, value);
+}
+jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_fn_method_shellsession_set_buffer_policy(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+        RustCallStatus status = uniffi::uniffi_russh::Bridging<RustCallStatus>::rustSuccess(rt);
+        uniffi_uniffi_russh_fn_method_shellsession_set_buffer_policy(uniffi_jsi::Bridging<void *>::fromJs(rt, callInvoker, args[0]), uniffi::uniffi_russh::Bridging<RustBuffer>::fromJs(rt, callInvoker, args[1]), 
+            &status
+        );
+        uniffi::uniffi_russh::Bridging<RustCallStatus>::copyIntoJs(rt, callInvoker, status, args[count - 1]);
+
+        
+        return jsi::Value::undefined();
+}
+jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_fn_method_shellsession_start_recording(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+        RustCallStatus status = uniffi::uniffi_russh::Bridging<RustCallStatus>::rustSuccess(rt);
+        uniffi_uniffi_russh_fn_method_shellsession_start_recording(uniffi_jsi::Bridging<void *>::fromJs(rt, callInvoker, args[0]), uniffi::uniffi_russh::Bridging<RustBuffer>::fromJs(rt, callInvoker, args[1]), uniffi::uniffi_russh::Bridging<RustBuffer>::fromJs(rt, callInvoker, args[2]), 
+            &status
+        );
+        uniffi::uniffi_russh::Bridging<RustCallStatus>::copyIntoJs(rt, callInvoker, status, args[count - 1]);
+
+        
+        return jsi::Value::undefined();
+}
+jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_fn_method_shellsession_stop_recording(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+        auto value = uniffi_uniffi_russh_fn_method_shellsession_stop_recording(uniffi_jsi::Bridging<void *>::fromJs(rt, callInvoker, args[0])
+        );
+
+        
         return uniffi_jsi::Bridging</*handle*/ uint64_t>::toJs(rt, callInvoker, value);
 }
 jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_fn_clone_sshconnection(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
@@ -4985,6 +8530,37 @@ jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_fn_method_sshconnection_ge
         
         return uniffi::uniffi_russh::Bridging<RustBuffer>::toJs(rt, callInvoker, value);
 }
+jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_fn_method_sshconnection_get_metrics(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+        RustCallStatus status = uniffi::uniffi_russh::Bridging<RustCallStatus>::rustSuccess(rt);
+        auto value = uniffi_uniffi_russh_fn_method_sshconnection_get_metrics(uniffi_jsi::Bridging<void *>::fromJs(rt, callInvoker, args[0]), 
+            &status
+        );
+        uniffi::uniffi_russh::Bridging<RustCallStatus>::copyIntoJs(rt, callInvoker, status, args[count - 1]);
+
+        
+        return uniffi::uniffi_russh::Bridging<RustBuffer>::toJs(rt, callInvoker, value);
+}
+jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_fn_method_sshconnection_install_bundled_terminfo(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+        auto value = uniffi_uniffi_russh_fn_method_sshconnection_install_bundled_terminfo(uniffi_jsi::Bridging<void *>::fromJs(rt, callInvoker, args[0]), uniffi::uniffi_russh::Bridging<RustBuffer>::fromJs(rt, callInvoker, args[1])
+        );
+
+        
+        return uniffi_jsi::Bridging</*handle*/ uint64_t>::toJs(rt, callInvoker, value);
+}
+jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_fn_method_sshconnection_install_terminfo(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+        auto value = uniffi_uniffi_russh_fn_method_sshconnection_install_terminfo(uniffi_jsi::Bridging<void *>::fromJs(rt, callInvoker, args[0]), uniffi::uniffi_russh::Bridging<RustBuffer>::fromJs(rt, callInvoker, args[1]), uniffi::uniffi_russh::Bridging<RustBuffer>::fromJs(rt, callInvoker, args[2])
+        );
+
+        
+        return uniffi_jsi::Bridging</*handle*/ uint64_t>::toJs(rt, callInvoker, value);
+}
+jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_fn_method_sshconnection_rekey(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+        auto value = uniffi_uniffi_russh_fn_method_sshconnection_rekey(uniffi_jsi::Bridging<void *>::fromJs(rt, callInvoker, args[0])
+        );
+
+        
+        return uniffi_jsi::Bridging</*handle*/ uint64_t>::toJs(rt, callInvoker, value);
+}
 jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_fn_method_sshconnection_start_shell(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
         auto value = uniffi_uniffi_russh_fn_method_sshconnection_start_shell(uniffi_jsi::Bridging<void *>::fromJs(rt, callInvoker, args[0]), uniffi::uniffi_russh::Bridging<RustBuffer>::fromJs(rt, callInvoker, args[1])
         );
@@ -4992,6 +8568,106 @@ jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_fn_method_sshconnection_st
         
         return uniffi_jsi::Bridging</*handle*/ uint64_t>::toJs(rt, callInvoker, value);
 }
+jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_fn_clone_terminalemulator(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+        RustCallStatus status = uniffi::uniffi_russh::Bridging<RustCallStatus>::rustSuccess(rt);
+        auto value = uniffi_uniffi_russh_fn_clone_terminalemulator(uniffi_jsi::Bridging<void *>::fromJs(rt, callInvoker, args[0]), 
+            &status
+        );
+        uniffi::uniffi_russh::Bridging<RustCallStatus>::copyIntoJs(rt, callInvoker, status, args[count - 1]);
+
+        
+        return uniffi_jsi::Bridging<void *>::toJs(rt, callInvoker, value);
+}
+jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_fn_free_terminalemulator(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+        RustCallStatus status = uniffi::uniffi_russh::Bridging<RustCallStatus>::rustSuccess(rt);
+        uniffi_uniffi_russh_fn_free_terminalemulator(uniffi_jsi::Bridging<void *>::fromJs(rt, callInvoker, args[0]), 
+            &status
+        );
+        uniffi::uniffi_russh::Bridging<RustCallStatus>::copyIntoJs(rt, callInvoker, status, args[count - 1]);
+
+        
+        return jsi::Value::undefined();
+}
+jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_fn_constructor_terminalemulator_new(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+        RustCallStatus status = uniffi::uniffi_russh::Bridging<RustCallStatus>::rustSuccess(rt);
+        auto value = uniffi_uniffi_russh_fn_constructor_terminalemulator_new(uniffi_jsi::Bridging<uint32_t>::fromJs(rt, callInvoker, args[0]), uniffi_jsi::Bridging<uint32_t>::fromJs(rt, callInvoker, args[1]), uniffi::uniffi_russh::Bridging<RustBuffer>::fromJs(rt, callInvoker, args[2]), 
+            &status
+        );
+        uniffi::uniffi_russh::Bridging<RustCallStatus>::copyIntoJs(rt, callInvoker, status, args[count - 1]);
+
+        
+        return uniffi_jsi::Bridging<void *>::toJs(rt, callInvoker, value);
+}
+jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_fn_method_terminalemulator_feed(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+        RustCallStatus status = uniffi::uniffi_russh::Bridging<RustCallStatus>::rustSuccess(rt);
+        uniffi_uniffi_russh_fn_method_terminalemulator_feed(uniffi_jsi::Bridging<void *>::fromJs(rt, callInvoker, args[0]), uniffi::uniffi_russh::Bridging<RustBuffer>::fromJs(rt, callInvoker, args[1]), 
+            &status
+        );
+        uniffi::uniffi_russh::Bridging<RustCallStatus>::copyIntoJs(rt, callInvoker, status, args[count - 1]);
+
+        
+        return jsi::Value::undefined();
+}
+jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_fn_method_terminalemulator_resize(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+        RustCallStatus status = uniffi::uniffi_russh::Bridging<RustCallStatus>::rustSuccess(rt);
+        uniffi_uniffi_russh_fn_method_terminalemulator_resize(uniffi_jsi::Bridging<void *>::fromJs(rt, callInvoker, args[0]), uniffi_jsi::Bridging<uint32_t>::fromJs(rt, callInvoker, args[1]), uniffi_jsi::Bridging<uint32_t>::fromJs(rt, callInvoker, args[2]), 
+            &status
+        );
+        uniffi::uniffi_russh::Bridging<RustCallStatus>::copyIntoJs(rt, callInvoker, status, args[count - 1]);
+
+        
+        return jsi::Value::undefined();
+}
+jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_fn_method_terminalemulator_scrollback_len(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+        RustCallStatus status = uniffi::uniffi_russh::Bridging<RustCallStatus>::rustSuccess(rt);
+        auto value = uniffi_uniffi_russh_fn_method_terminalemulator_scrollback_len(uniffi_jsi::Bridging<void *>::fromJs(rt, callInvoker, args[0]), 
+            &status
+        );
+        uniffi::uniffi_russh::Bridging<RustCallStatus>::copyIntoJs(rt, callInvoker, status, args[count - 1]);
+
+        
+        return uniffi_jsi::Bridging<uint64_t>::toJs(rt, callInvoker, value);
+}
+jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_fn_method_terminalemulator_scrollback_lines(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+        RustCallStatus status = uniffi::uniffi_russh::Bridging<RustCallStatus>::rustSuccess(rt);
+        auto value = uniffi_uniffi_russh_fn_method_terminalemulator_scrollback_lines(uniffi_jsi::Bridging<void *>::fromJs(rt, callInvoker, args[0]), uniffi_jsi::Bridging<uint64_t>::fromJs(rt, callInvoker, args[1]), uniffi_jsi::Bridging<uint32_t>::fromJs(rt, callInvoker, args[2]), 
+            &status
+        );
+        uniffi::uniffi_russh::Bridging<RustCallStatus>::copyIntoJs(rt, callInvoker, status, args[count - 1]);
+
+        
+        return uniffi::uniffi_russh::Bridging<RustBuffer>::toJs(rt, callInvoker, value);
+}
+jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_fn_method_terminalemulator_snapshot(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+        RustCallStatus status = uniffi::uniffi_russh::Bridging<RustCallStatus>::rustSuccess(rt);
+        auto value = uniffi_uniffi_russh_fn_method_terminalemulator_snapshot(uniffi_jsi::Bridging<void *>::fromJs(rt, callInvoker, args[0]), 
+            &status
+        );
+        uniffi::uniffi_russh::Bridging<RustCallStatus>::copyIntoJs(rt, callInvoker, status, args[count - 1]);
+
+        
+        return uniffi::uniffi_russh::Bridging<RustBuffer>::toJs(rt, callInvoker, value);
+}
+jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_fn_method_terminalemulator_take_damage(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+        RustCallStatus status = uniffi::uniffi_russh::Bridging<RustCallStatus>::rustSuccess(rt);
+        auto value = uniffi_uniffi_russh_fn_method_terminalemulator_take_damage(uniffi_jsi::Bridging<void *>::fromJs(rt, callInvoker, args[0]), 
+            &status
+        );
+        uniffi::uniffi_russh::Bridging<RustCallStatus>::copyIntoJs(rt, callInvoker, status, args[count - 1]);
+
+        
+        return uniffi::uniffi_russh::Bridging<RustBuffer>::toJs(rt, callInvoker, value);
+}
+jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_fn_method_terminalemulator_take_responses(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+        RustCallStatus status = uniffi::uniffi_russh::Bridging<RustCallStatus>::rustSuccess(rt);
+        auto value = uniffi_uniffi_russh_fn_method_terminalemulator_take_responses(uniffi_jsi::Bridging<void *>::fromJs(rt, callInvoker, args[0]), 
+            &status
+        );
+        uniffi::uniffi_russh::Bridging<RustCallStatus>::copyIntoJs(rt, callInvoker, status, args[count - 1]);
+
+        
+        return uniffi::uniffi_russh::Bridging<RustBuffer>::toJs(rt, callInvoker, value);
+}
 jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_fn_func_connect(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
         auto value = uniffi_uniffi_russh_fn_func_connect(uniffi::uniffi_russh::Bridging<RustBuffer>::fromJs(rt, callInvoker, args[0])
         );
@@ -5019,6 +8695,43 @@ jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_fn_func_generate_key_pair(
         
         return uniffi::uniffi_russh::Bridging<RustBuffer>::toJs(rt, callInvoker, value);
 }
+jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_fn_func_memory_usage(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+        RustCallStatus status = uniffi::uniffi_russh::Bridging<RustCallStatus>::rustSuccess(rt);
+        auto value = uniffi_uniffi_russh_fn_func_memory_usage(
+            &status
+        );
+        uniffi::uniffi_russh::Bridging<RustCallStatus>::copyIntoJs(rt, callInvoker, status, args[count - 1]);
+
+        
+        return uniffi::uniffi_russh::Bridging<RustBuffer>::toJs(rt, callInvoker, value);
+}
+jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_fn_func_open_cast_player(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+        auto value = uniffi_uniffi_russh_fn_func_open_cast_player(uniffi::uniffi_russh::Bridging<RustBuffer>::fromJs(rt, callInvoker, args[0]), uniffi::uniffi_russh::Bridging<RustBuffer>::fromJs(rt, callInvoker, args[1])
+        );
+
+        
+        return uniffi_jsi::Bridging</*handle*/ uint64_t>::toJs(rt, callInvoker, value);
+}
+jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_fn_func_public_key_fingerprints(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+        RustCallStatus status = uniffi::uniffi_russh::Bridging<RustCallStatus>::rustSuccess(rt);
+        auto value = uniffi_uniffi_russh_fn_func_public_key_fingerprints(uniffi::uniffi_russh::Bridging<RustBuffer>::fromJs(rt, callInvoker, args[0]), 
+            &status
+        );
+        uniffi::uniffi_russh::Bridging<RustCallStatus>::copyIntoJs(rt, callInvoker, status, args[count - 1]);
+
+        
+        return uniffi::uniffi_russh::Bridging<RustBuffer>::toJs(rt, callInvoker, value);
+}
+jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_fn_func_set_memory_budget(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+        RustCallStatus status = uniffi::uniffi_russh::Bridging<RustCallStatus>::rustSuccess(rt);
+        uniffi_uniffi_russh_fn_func_set_memory_budget(uniffi::uniffi_russh::Bridging<RustBuffer>::fromJs(rt, callInvoker, args[0]), 
+            &status
+        );
+        uniffi::uniffi_russh::Bridging<RustCallStatus>::copyIntoJs(rt, callInvoker, status, args[count - 1]);
+
+        
+        return jsi::Value::undefined();
+}
 jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_fn_func_validate_private_key(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
         RustCallStatus status = uniffi::uniffi_russh::Bridging<RustCallStatus>::rustSuccess(rt);
         auto value = uniffi_uniffi_russh_fn_func_validate_private_key(uniffi::uniffi_russh::Bridging<RustBuffer>::fromJs(rt, callInvoker, args[0]), 
@@ -5451,6 +9164,34 @@ jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_checksum_func_generate_key
         );
 
         
+        return uniffi_jsi::Bridging<uint16_t>::toJs(rt, callInvoker, value);
+}
+jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_checksum_func_memory_usage(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+        auto value = uniffi_uniffi_russh_checksum_func_memory_usage(
+        );
+
+        
+        return uniffi_jsi::Bridging<uint16_t>::toJs(rt, callInvoker, value);
+}
+jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_checksum_func_open_cast_player(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+        auto value = uniffi_uniffi_russh_checksum_func_open_cast_player(
+        );
+
+        
+        return uniffi_jsi::Bridging<uint16_t>::toJs(rt, callInvoker, value);
+}
+jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_checksum_func_public_key_fingerprints(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+        auto value = uniffi_uniffi_russh_checksum_func_public_key_fingerprints(
+        );
+
+        
+        return uniffi_jsi::Bridging<uint16_t>::toJs(rt, callInvoker, value);
+}
+jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_checksum_func_set_memory_budget(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+        auto value = uniffi_uniffi_russh_checksum_func_set_memory_budget(
+        );
+
+        
         return uniffi_jsi::Bridging<uint16_t>::toJs(rt, callInvoker, value);
 }
 jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_checksum_func_validate_private_key(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
@@ -5458,6 +9199,153 @@ jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_checksum_func_validate_pri
         );
 
         
+        return uniffi_jsi::Bridging<uint16_t>::toJs(rt, callInvoker, value);
+}
+jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_checksum_method_authbannercallback_on_change(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+        auto value = uniffi_uniffi_russh_checksum_method_authbannercallback_on_change(
+        );
+
+        
+        return uniffi_jsi::Bridging<uint16_t>::toJs(rt, callInvoker, value);
+}
+jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_checksum_method_castplayer_add_listener(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+        auto value = uniffi_uniffi_russh_checksum_method_castplayer_add_listener(
+        );
+
+        
+        return uniffi_jsi::Bridging<uint16_t>::toJs(rt, callInvoker, value);
+}
+jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_checksum_method_castplayer_attach_emulator(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+        auto value = uniffi_uniffi_russh_checksum_method_castplayer_attach_emulator(
+        );
+
+        
+        return uniffi_jsi::Bridging<uint16_t>::toJs(rt, callInvoker, value);
+}
+jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_checksum_method_castplayer_buffer_stats(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+        auto value = uniffi_uniffi_russh_checksum_method_castplayer_buffer_stats(
+        );
+
+        
+        return uniffi_jsi::Bridging<uint16_t>::toJs(rt, callInvoker, value);
+}
+jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_checksum_method_castplayer_commands(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+        auto value = uniffi_uniffi_russh_checksum_method_castplayer_commands(
+        );
+
+        
+        return uniffi_jsi::Bridging<uint16_t>::toJs(rt, callInvoker, value);
+}
+jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_checksum_method_castplayer_current_seq(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+        auto value = uniffi_uniffi_russh_checksum_method_castplayer_current_seq(
+        );
+
+        
+        return uniffi_jsi::Bridging<uint16_t>::toJs(rt, callInvoker, value);
+}
+jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_checksum_method_castplayer_detach_emulator(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+        auto value = uniffi_uniffi_russh_checksum_method_castplayer_detach_emulator(
+        );
+
+        
+        return uniffi_jsi::Bridging<uint16_t>::toJs(rt, callInvoker, value);
+}
+jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_checksum_method_castplayer_get_info(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+        auto value = uniffi_uniffi_russh_checksum_method_castplayer_get_info(
+        );
+
+        
+        return uniffi_jsi::Bridging<uint16_t>::toJs(rt, callInvoker, value);
+}
+jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_checksum_method_castplayer_get_status(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+        auto value = uniffi_uniffi_russh_checksum_method_castplayer_get_status(
+        );
+
+        
+        return uniffi_jsi::Bridging<uint16_t>::toJs(rt, callInvoker, value);
+}
+jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_checksum_method_castplayer_pause(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+        auto value = uniffi_uniffi_russh_checksum_method_castplayer_pause(
+        );
+
+        
+        return uniffi_jsi::Bridging<uint16_t>::toJs(rt, callInvoker, value);
+}
+jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_checksum_method_castplayer_play(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+        auto value = uniffi_uniffi_russh_checksum_method_castplayer_play(
+        );
+
+        
+        return uniffi_jsi::Bridging<uint16_t>::toJs(rt, callInvoker, value);
+}
+jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_checksum_method_castplayer_read_buffer(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+        auto value = uniffi_uniffi_russh_checksum_method_castplayer_read_buffer(
+        );
+
+        
+        return uniffi_jsi::Bridging<uint16_t>::toJs(rt, callInvoker, value);
+}
+jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_checksum_method_castplayer_read_text(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+        auto value = uniffi_uniffi_russh_checksum_method_castplayer_read_text(
+        );
+
+        
+        return uniffi_jsi::Bridging<uint16_t>::toJs(rt, callInvoker, value);
+}
+jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_checksum_method_castplayer_remove_listener(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+        auto value = uniffi_uniffi_russh_checksum_method_castplayer_remove_listener(
+        );
+
+        
+        return uniffi_jsi::Bridging<uint16_t>::toJs(rt, callInvoker, value);
+}
+jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_checksum_method_castplayer_search(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+        auto value = uniffi_uniffi_russh_checksum_method_castplayer_search(
+        );
+
+        
+        return uniffi_jsi::Bridging<uint16_t>::toJs(rt, callInvoker, value);
+}
+jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_checksum_method_castplayer_seek(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+        auto value = uniffi_uniffi_russh_checksum_method_castplayer_seek(
+        );
+
+        
+        return uniffi_jsi::Bridging<uint16_t>::toJs(rt, callInvoker, value);
+}
+jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_checksum_method_castplayer_set_speed(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+        auto value = uniffi_uniffi_russh_checksum_method_castplayer_set_speed(
+        );
+
+        
+        return uniffi_jsi::Bridging<uint16_t>::toJs(rt, callInvoker, value);
+}
+jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_checksum_method_castplayerstatuscallback_on_change(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+        auto value = uniffi_uniffi_russh_checksum_method_castplayerstatuscallback_on_change(
+        );
+
+        
+        return uniffi_jsi::Bridging<uint16_t>::toJs(rt, callInvoker, value);
+}
+jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_checksum_method_channelpausecallback_on_change(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+        auto value = uniffi_uniffi_russh_checksum_method_channelpausecallback_on_change(
+        );
+
+        
+        return uniffi_jsi::Bridging<uint16_t>::toJs(rt, callInvoker, value);
+}
+jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_checksum_method_connectaborttoken_abort(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+        auto value = uniffi_uniffi_russh_checksum_method_connectaborttoken_abort(
+        );
+
+        
+        return uniffi_jsi::Bridging<uint16_t>::toJs(rt, callInvoker, value);
+}
+jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_checksum_method_connectaborttoken_is_aborted(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+        auto value = uniffi_uniffi_russh_checksum_method_connectaborttoken_is_aborted(
+        );
+
+        
         return uniffi_jsi::Bridging<uint16_t>::toJs(rt, callInvoker, value);
 }
 jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_checksum_method_connectprogresscallback_on_change(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
@@ -5472,6 +9360,20 @@ jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_checksum_method_connection
         );
 
         
+        return uniffi_jsi::Bridging<uint16_t>::toJs(rt, callInvoker, value);
+}
+jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_checksum_method_connectioneventcallback_on_change(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+        auto value = uniffi_uniffi_russh_checksum_method_connectioneventcallback_on_change(
+        );
+
+        
+        return uniffi_jsi::Bridging<uint16_t>::toJs(rt, callInvoker, value);
+}
+jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_checksum_method_connectionmetricscallback_on_change(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+        auto value = uniffi_uniffi_russh_checksum_method_connectionmetricscallback_on_change(
+        );
+
+        
         return uniffi_jsi::Bridging<uint16_t>::toJs(rt, callInvoker, value);
 }
 jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_checksum_method_serverkeycallback_on_change(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
@@ -5500,6 +9402,13 @@ jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_checksum_method_shellsessi
         );
 
         
+        return uniffi_jsi::Bridging<uint16_t>::toJs(rt, callInvoker, value);
+}
+jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_checksum_method_shellsession_attach_emulator(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+        auto value = uniffi_uniffi_russh_checksum_method_shellsession_attach_emulator(
+        );
+
+        
         return uniffi_jsi::Bridging<uint16_t>::toJs(rt, callInvoker, value);
 }
 jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_checksum_method_shellsession_buffer_stats(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
@@ -5514,6 +9423,13 @@ jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_checksum_method_shellsessi
         );
 
         
+        return uniffi_jsi::Bridging<uint16_t>::toJs(rt, callInvoker, value);
+}
+jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_checksum_method_shellsession_commands(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+        auto value = uniffi_uniffi_russh_checksum_method_shellsession_commands(
+        );
+
+        
         return uniffi_jsi::Bridging<uint16_t>::toJs(rt, callInvoker, value);
 }
 jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_checksum_method_shellsession_current_seq(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
@@ -5521,6 +9437,13 @@ jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_checksum_method_shellsessi
         );
 
         
+        return uniffi_jsi::Bridging<uint16_t>::toJs(rt, callInvoker, value);
+}
+jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_checksum_method_shellsession_detach_emulator(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+        auto value = uniffi_uniffi_russh_checksum_method_shellsession_detach_emulator(
+        );
+
+        
         return uniffi_jsi::Bridging<uint16_t>::toJs(rt, callInvoker, value);
 }
 jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_checksum_method_shellsession_get_info(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
@@ -5535,6 +9458,13 @@ jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_checksum_method_shellsessi
         );
 
         
+        return uniffi_jsi::Bridging<uint16_t>::toJs(rt, callInvoker, value);
+}
+jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_checksum_method_shellsession_read_text(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+        auto value = uniffi_uniffi_russh_checksum_method_shellsession_read_text(
+        );
+
+        
         return uniffi_jsi::Bridging<uint16_t>::toJs(rt, callInvoker, value);
 }
 jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_checksum_method_shellsession_remove_listener(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
@@ -5542,6 +9472,27 @@ jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_checksum_method_shellsessi
         );
 
         
+        return uniffi_jsi::Bridging<uint16_t>::toJs(rt, callInvoker, value);
+}
+jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_checksum_method_shellsession_resize(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+        auto value = uniffi_uniffi_russh_checksum_method_shellsession_resize(
+        );
+
+        
+        return uniffi_jsi::Bridging<uint16_t>::toJs(rt, callInvoker, value);
+}
+jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_checksum_method_shellsession_search(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+        auto value = uniffi_uniffi_russh_checksum_method_shellsession_search(
+        );
+
+        
+        return uniffi_jsi::Bridging<uint16_t>::toJs(rt, callInvoker, value);
+}
+jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_checksum_method_shellsession_send_break(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+        auto value = uniffi_uniffi_russh_checksum_method_shellsession_send_break(
+        );
+
+        
         return uniffi_jsi::Bridging<uint16_t>::toJs(rt, callInvoker, value);
 }
 jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_checksum_method_shellsession_send_data(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
@@ -5549,6 +9500,34 @@ jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_checksum_method_shellsessi
         );
 
         
+        return uniffi_jsi::Bridging<uint16_t>::toJs(rt, callInvoker, value);
+}
+jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_checksum_method_shellsession_send_signal(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+        auto value = uniffi_uniffi_russh_checksum_method_shellsession_send_signal(
+        );
+
+        
+        return uniffi_jsi::Bridging<uint16_t>::toJs(rt, callInvoker, value);
+}
+jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_checksum_method_shellsession_set_buffer_policy(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+        auto value = uniffi_uniffi_russh_checksum_method_shellsession_set_buffer_policy(
+        );
+
+        
+        return uniffi_jsi::Bridging<uint16_t>::toJs(rt, callInvoker, value);
+}
+jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_checksum_method_shellsession_start_recording(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+        auto value = uniffi_uniffi_russh_checksum_method_shellsession_start_recording(
+        );
+
+        
+        return uniffi_jsi::Bridging<uint16_t>::toJs(rt, callInvoker, value);
+}
+jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_checksum_method_shellsession_stop_recording(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+        auto value = uniffi_uniffi_russh_checksum_method_shellsession_stop_recording(
+        );
+
+        
         return uniffi_jsi::Bridging<uint16_t>::toJs(rt, callInvoker, value);
 }
 jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_checksum_method_sshconnection_disconnect(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
@@ -5563,6 +9542,34 @@ jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_checksum_method_sshconnect
         );
 
         
+        return uniffi_jsi::Bridging<uint16_t>::toJs(rt, callInvoker, value);
+}
+jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_checksum_method_sshconnection_get_metrics(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+        auto value = uniffi_uniffi_russh_checksum_method_sshconnection_get_metrics(
+        );
+
+        
+        return uniffi_jsi::Bridging<uint16_t>::toJs(rt, callInvoker, value);
+}
+jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_checksum_method_sshconnection_install_bundled_terminfo(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+        auto value = uniffi_uniffi_russh_checksum_method_sshconnection_install_bundled_terminfo(
+        );
+
+        
+        return uniffi_jsi::Bridging<uint16_t>::toJs(rt, callInvoker, value);
+}
+jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_checksum_method_sshconnection_install_terminfo(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+        auto value = uniffi_uniffi_russh_checksum_method_sshconnection_install_terminfo(
+        );
+
+        
+        return uniffi_jsi::Bridging<uint16_t>::toJs(rt, callInvoker, value);
+}
+jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_checksum_method_sshconnection_rekey(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+        auto value = uniffi_uniffi_russh_checksum_method_sshconnection_rekey(
+        );
+
+        
         return uniffi_jsi::Bridging<uint16_t>::toJs(rt, callInvoker, value);
 }
 jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_checksum_method_sshconnection_start_shell(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
@@ -5570,6 +9577,69 @@ jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_checksum_method_sshconnect
         );
 
         
+        return uniffi_jsi::Bridging<uint16_t>::toJs(rt, callInvoker, value);
+}
+jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_checksum_method_terminalemulator_feed(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+        auto value = uniffi_uniffi_russh_checksum_method_terminalemulator_feed(
+        );
+
+        
+        return uniffi_jsi::Bridging<uint16_t>::toJs(rt, callInvoker, value);
+}
+jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_checksum_method_terminalemulator_resize(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+        auto value = uniffi_uniffi_russh_checksum_method_terminalemulator_resize(
+        );
+
+        
+        return uniffi_jsi::Bridging<uint16_t>::toJs(rt, callInvoker, value);
+}
+jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_checksum_method_terminalemulator_scrollback_len(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+        auto value = uniffi_uniffi_russh_checksum_method_terminalemulator_scrollback_len(
+        );
+
+        
+        return uniffi_jsi::Bridging<uint16_t>::toJs(rt, callInvoker, value);
+}
+jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_checksum_method_terminalemulator_scrollback_lines(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+        auto value = uniffi_uniffi_russh_checksum_method_terminalemulator_scrollback_lines(
+        );
+
+        
+        return uniffi_jsi::Bridging<uint16_t>::toJs(rt, callInvoker, value);
+}
+jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_checksum_method_terminalemulator_snapshot(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+        auto value = uniffi_uniffi_russh_checksum_method_terminalemulator_snapshot(
+        );
+
+        
+        return uniffi_jsi::Bridging<uint16_t>::toJs(rt, callInvoker, value);
+}
+jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_checksum_method_terminalemulator_take_damage(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+        auto value = uniffi_uniffi_russh_checksum_method_terminalemulator_take_damage(
+        );
+
+        
+        return uniffi_jsi::Bridging<uint16_t>::toJs(rt, callInvoker, value);
+}
+jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_checksum_method_terminalemulator_take_responses(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+        auto value = uniffi_uniffi_russh_checksum_method_terminalemulator_take_responses(
+        );
+
+        
+        return uniffi_jsi::Bridging<uint16_t>::toJs(rt, callInvoker, value);
+}
+jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_checksum_constructor_connectaborttoken_new(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+        auto value = uniffi_uniffi_russh_checksum_constructor_connectaborttoken_new(
+        );
+
+        
+        return uniffi_jsi::Bridging<uint16_t>::toJs(rt, callInvoker, value);
+}
+jsi::Value NativeUniffiRussh::cpp_uniffi_uniffi_russh_checksum_constructor_terminalemulator_new(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
+        auto value = uniffi_uniffi_russh_checksum_constructor_terminalemulator_new(
+        );
+
+        
         return uniffi_jsi::Bridging<uint16_t>::toJs(rt, callInvoker, value);
 }
 jsi::Value NativeUniffiRussh::cpp_ffi_uniffi_russh_uniffi_contract_version(jsi::Runtime& rt, const jsi::Value& thisVal, const jsi::Value* args, size_t count) {
@@ -0,0 +1,268 @@
+use vte::{Params, Parser, Perform};
+
+/// Terminal output with escape sequences removed, mapped back to the input.
+pub(crate) struct StrippedText {
+    pub text: String,
+    // (text offset, input offset) at each point where text and input bytes
+    // stop lining up (escapes, dropped controls, invalid UTF-8).
+    spans: Vec<(usize, usize)>,
+}
+
+impl StrippedText {
+    /// Input offset of the text byte at `index` (< text.len()).
+    pub fn input_offset(&self, index: usize) -> usize {
+        let i = self.spans.partition_point(|(t, _)| *t <= index) - 1;
+        let (text_at, input_at) = self.spans[i];
+        input_at + (index - text_at)
+    }
+}
+
+/// Strips CSI/OSC/DCS/ESC sequences and control characters other than LF and
+/// TAB from a byte stream fed in pieces (sequences may span pieces). A CR not
+/// followed by LF becomes LF, so overwritten text does not run together.
+pub(crate) struct Stripper {
+    parser: Parser,
+    sink: Sink,
+}
+
+struct Sink {
+    text: String,
+    spans: Vec<(usize, usize)>,
+    pos: usize,                // input offset of the byte being parsed
+    start: Option<usize>,      // where the pending char/sequence began
+    fired: bool,               // the parser acted on the current byte
+    pending_cr: Option<usize>, // input offset of a CR that may precede a LF
+}
+
+impl Stripper {
+    pub fn new() -> Self {
+        Stripper {
+            parser: Parser::new(),
+            sink: Sink {
+                text: String::new(),
+                spans: Vec::new(),
+                pos: 0,
+                start: None,
+                fired: false,
+                pending_cr: None,
+            },
+        }
+    }
+
+    pub fn feed(&mut self, bytes: &[u8]) {
+        // Byte at a time, so printed chars can be traced to their input.
+        for byte in bytes {
+            let sink = &mut self.sink;
+            if let Some(cr) = sink.pending_cr {
+                match byte {
+                    b'\r' => {}
+                    b'\n' => sink.pending_cr = None,
+                    _ => {
+                        sink.pending_cr = None;
+                        sink.push_at(cr, 1, '\n');
+                    }
+                }
+            }
+            sink.start.get_or_insert(sink.pos);
+            sink.fired = false;
+            self.parser.advance(sink, std::slice::from_ref(byte));
+            if self.sink.fired {
+                self.sink.start = None;
+            }
+            self.sink.pos += 1;
+        }
+    }
+
+    pub fn finish(mut self) -> StrippedText {
+        if let Some(cr) = self.sink.pending_cr.take() {
+            self.sink.push_at(cr, 1, '\n');
+        }
+        StrippedText {
+            text: self.sink.text,
+            spans: self.sink.spans,
+        }
+    }
+}
+
+impl Sink {
+    fn push(&mut self, c: char) {
+        self.fired = true;
+        let start = self.start.unwrap_or(self.pos);
+        self.push_at(start, self.pos + 1 - start, c);
+    }
+
+    /// Append `c`, read from `input_len` bytes at input offset `start`.
+    fn push_at(&mut self, start: usize, input_len: usize, c: char) {
+        let expected = self
+            .spans
+            .last()
+            .map(|(text_at, input_at)| input_at + (self.text.len() - text_at));
+        if expected != Some(start) || c.len_utf8() != input_len {
+            self.spans.push((self.text.len(), start));
+        }
+        self.text.push(c);
+    }
+}
+
+impl Perform for Sink {
+    fn print(&mut self, c: char) {
+        self.push(c);
+    }
+
+    fn execute(&mut self, byte: u8) {
+        match byte {
+            b'\n' => self.push('\n'),
+            b'\t' => self.push('\t'),
+            b'\r' => {
+                self.fired = true;
+                self.pending_cr = Some(self.pos);
+            }
+            _ => self.fired = true,
+        }
+    }
+
+    fn hook(&mut self, _params: &Params, _intermediates: &[u8], _ignore: bool, _action: char) {
+        self.fired = true;
+    }
+
+    fn put(&mut self, _byte: u8) {
+        self.fired = true;
+    }
+
+    fn unhook(&mut self) {
+        self.fired = true;
+    }
+
+    fn osc_dispatch(&mut self, _params: &[&[u8]], _bell_terminated: bool) {
+        self.fired = true;
+    }
+
+    fn csi_dispatch(
+        &mut self,
+        _params: &Params,
+        _intermediates: &[u8],
+        _ignore: bool,
+        _action: char,
+    ) {
+        self.fired = true;
+    }
+
+    fn esc_dispatch(&mut self, _intermediates: &[u8], _ignore: bool, _byte: u8) {
+        self.fired = true;
+    }
+}
+
+/// Renders output as plain text the way a terminal would show each line:
+/// CR returns to the start of the line and later text overwrites, BS steps
+/// back, and erase-in-line clears. Other escapes are dropped.
+pub(crate) fn render_text(bytes: &[u8]) -> String {
+    let mut parser = Parser::new();
+    let mut lines = Lines {
+        text: String::new(),
+        line: Vec::new(),
+        col: 0,
+    };
+    parser.advance(&mut lines, bytes);
+    lines.text.extend(lines.line);
+    lines.text
+}
+
+struct Lines {
+    text: String,    // finished lines
+    line: Vec<char>, // the current line
+    col: usize,
+}
+
+impl Perform for Lines {
+    fn print(&mut self, c: char) {
+        if self.col < self.line.len() {
+            self.line[self.col] = c;
+        } else {
+            self.line.resize(self.col, ' ');
+            self.line.push(c);
+        }
+        self.col += 1;
+    }
+
+    fn execute(&mut self, byte: u8) {
+        match byte {
+            b'\n' => {
+                self.text.extend(self.line.drain(..));
+                self.text.push('\n');
+                self.col = 0;
+            }
+            b'\r' => self.col = 0,
+            0x08 => self.col = self.col.saturating_sub(1),
+            b'\t' => self.print('\t'),
+            _ => {}
+        }
+    }
+
+    fn csi_dispatch(
+        &mut self,
+        params: &Params,
+        _intermediates: &[u8],
+        _ignore: bool,
+        action: char,
+    ) {
+        // EL: 0 to the end of the line, 1 to the cursor, 2 all of it.
+        if action == 'K' {
+            match params.iter().next().map_or(0, |p| p[0]) {
+                0 => self.line.truncate(self.col),
+                1 => {
+                    let end = (self.col + 1).min(self.line.len());
+                    self.line[..end].fill(' ');
+                }
+                _ => self.line.clear(),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn strips_escapes_split_across_pieces() {
+        let mut stripper = Stripper::new();
+        // "ok\r\n\x1b[31merror\x1b[0m: bé\r\n" split inside the escapes and a char.
+        for piece in [
+            &b"ok\r\n\x1b[3"[..],
+            b"1merr",
+            b"or\x1b",
+            b"[0m: b\xc3",
+            b"\xa9\r\n",
+        ] {
+            stripper.feed(piece);
+        }
+        let stripped = stripper.finish();
+        assert_eq!(stripped.text, "ok\nerror: bé\n");
+        let input = b"ok\r\n\x1b[31merror\x1b[0m: b\xc3\xa9\r\n";
+        for (index, expected) in [(0, b'o'), (3, b'e'), (7, b'r'), (8, b':'), (11, 0xc3)] {
+            assert_eq!(input[stripped.input_offset(index)], expected, "{index}");
+        }
+    }
+
+    #[test]
+    fn lone_carriage_returns_break_lines() {
+        let mut stripper = Stripper::new();
+        stripper.feed(b"a\r");
+        stripper.feed(b"b\r\r\nc\r");
+        let stripped = stripper.finish();
+        assert_eq!(stripped.text, "a\nb\nc\n");
+        let input = b"a\rb\r\r\nc\r";
+        for (index, expected) in [(1, 1), (2, 2), (3, 5), (4, 6), (5, 7)] {
+            assert_eq!(stripped.input_offset(index), expected, "{index}");
+        }
+        assert_eq!(input[stripped.input_offset(1)], b'\r');
+    }
+
+    #[test]
+    fn renders_overwritten_lines() {
+        let out = render_text(
+            b"\x1b[1mbuild\x1b[0m\r\n 10%\r 50%\r100%\r\ndone\x08\x08NE\r\nlong line\r\x1b[Kok\n\tx",
+        );
+        assert_eq!(out, "build\n100%\ndoNE\nok\n\tx");
+    }
+}
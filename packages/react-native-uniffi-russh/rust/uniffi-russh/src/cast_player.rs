@@ -0,0 +1,725 @@
+use std::{
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use serde_json::Value;
+use tokio::{sync::Notify, time::Instant};
+
+use crate::{
+    output_buffer::OutputBuffer,
+    shell_integration::CommandRecord,
+    ssh_shell::{
+        BufferPolicy, BufferReadResult, BufferStats, Cursor, ListenerOptions, SearchOptions,
+        SearchResult, ShellListener, StreamKind, TextReadResult,
+    },
+    terminal_emulator::TerminalEmulator,
+    utils::SshError,
+};
+
+#[derive(Debug, Clone, PartialEq, uniffi::Enum)]
+pub enum CastSource {
+    File { path: String },
+    Text { contents: String },
+}
+
+#[derive(Clone, uniffi::Record)]
+pub struct CastPlayerOptions {
+    #[uniffi(default = None)]
+    pub speed: Option<f64>, // default 1.0
+    // Longest pause kept between events; defaults to the file's
+    // idle_time_limit, 0 turns compression off.
+    #[uniffi(default = None)]
+    pub idle_time_limit_ms: Option<f64>,
+    #[uniffi(default = None)]
+    pub autoplay: Option<bool>,
+    #[uniffi(default = None)]
+    pub on_status_changed: Option<Arc<dyn CastPlayerStatusCallback>>,
+    #[uniffi(default = None)]
+    pub buffer_policy: Option<BufferPolicy>,
+}
+
+#[uniffi::export(with_foreign)]
+pub trait CastPlayerStatusCallback: Send + Sync {
+    fn on_change(&self, status: PlaybackStatus);
+}
+
+#[derive(Debug, Clone, PartialEq, uniffi::Record)]
+pub struct CastInfo {
+    pub version: u32,
+    pub cols: u32, // initial size; resizes show up in PlaybackStatus
+    pub rows: u32,
+    pub title: Option<String>,
+    pub term: Option<String>,
+    pub started_at_ms: Option<f64>,
+    pub duration_ms: f64, // after idle-time compression
+    pub idle_time_limit_ms: Option<f64>,
+}
+
+/// Positions are on the compressed timeline, in recording time (not scaled
+/// by speed).
+#[derive(Debug, Clone, PartialEq, uniffi::Record)]
+pub struct PlaybackStatus {
+    pub playing: bool,
+    pub position_ms: f64,
+    pub duration_ms: f64,
+    pub speed: f64,
+    pub cols: u32,
+    pub rows: u32,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum FrameData {
+    Output(String),
+    Resize { cols: u32, rows: u32 },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Frame {
+    at_ms: f64, // playback position
+    t_ms: f64,  // chunk timestamp: recording start plus the original offset
+    data: FrameData,
+}
+
+struct Cast {
+    info: CastInfo,
+    file_idle_limit_ms: Option<f64>,
+    events: Vec<(f64, FrameData)>, // offset from the start, uncompressed
+}
+
+fn invalid(msg: impl std::fmt::Display) -> SshError {
+    SshError::InvalidRecording(msg.to_string())
+}
+
+fn parse_cast(text: &str) -> Result<Cast, SshError> {
+    let mut lines = text
+        .lines()
+        .enumerate()
+        .filter(|(_, l)| !l.trim().is_empty());
+    let (_, first) = lines.next().ok_or_else(|| invalid("empty file"))?;
+    if let Ok(head) = serde_json::from_str::<Value>(first) {
+        match head["version"].as_u64() {
+            Some(2) => return parse_v2(&head, lines),
+            Some(1) | None => {}
+            Some(v) => return Err(SshError::Unsupported(format!("asciicast version {v}"))),
+        }
+    }
+    // v1 is a single (usually pretty-printed) JSON document.
+    let doc: Value = serde_json::from_str(text).map_err(invalid)?;
+    match doc["version"].as_u64() {
+        Some(1) => parse_v1(&doc),
+        v => Err(SshError::Unsupported(format!("asciicast version {v:?}"))),
+    }
+}
+
+fn header_info(head: &Value, version: u32) -> Result<CastInfo, SshError> {
+    let size = |key: &str| {
+        head[key]
+            .as_u64()
+            .map(|n| n as u32)
+            .ok_or_else(|| invalid(format!("header has no {key}")))
+    };
+    Ok(CastInfo {
+        version,
+        cols: size("width")?,
+        rows: size("height")?,
+        title: head["title"].as_str().map(String::from),
+        term: head["env"]["TERM"].as_str().map(String::from),
+        started_at_ms: head["timestamp"].as_f64().map(|s| s * 1000.0),
+        duration_ms: 0.0,
+        idle_time_limit_ms: None,
+    })
+}
+
+fn parse_v2<'a>(
+    head: &Value,
+    lines: impl Iterator<Item = (usize, &'a str)>,
+) -> Result<Cast, SshError> {
+    let info = header_info(head, 2)?;
+    let file_idle_limit_ms = head["idle_time_limit"].as_f64().map(|s| s * 1000.0);
+    let mut lines = lines.peekable();
+    let mut events = Vec::new();
+    while let Some((n, line)) = lines.next() {
+        let event = serde_json::from_str::<(f64, String, String)>(line);
+        let (t, code, data) = match event {
+            Ok(event) => event,
+            // A recording cut off mid-write ends in a partial line.
+            Err(_) if lines.peek().is_none() => break,
+            Err(e) => return Err(invalid(format!("line {}: {e}", n + 1))),
+        };
+        let data = match code.as_str() {
+            "o" => FrameData::Output(data),
+            "r" => match data.split_once('x').map(|(c, r)| (c.parse(), r.parse())) {
+                Some((Ok(cols), Ok(rows))) => FrameData::Resize { cols, rows },
+                _ => return Err(invalid(format!("line {}: bad size {data:?}", n + 1))),
+            },
+            // Input and markers don't affect the screen.
+            _ => continue,
+        };
+        events.push((t * 1000.0, data));
+    }
+    Ok(Cast {
+        info,
+        file_idle_limit_ms,
+        events,
+    })
+}
+
+fn parse_v1(doc: &Value) -> Result<Cast, SshError> {
+    let info = header_info(doc, 1)?;
+    let stdout: Vec<(f64, String)> =
+        serde_json::from_value(doc["stdout"].clone()).map_err(invalid)?;
+    // v1 stores the delay since the previous frame.
+    let mut t_ms = 0.0;
+    let events = stdout
+        .into_iter()
+        .map(|(delay, data)| {
+            t_ms += delay * 1000.0;
+            (t_ms, FrameData::Output(data))
+        })
+        .collect();
+    Ok(Cast {
+        info,
+        file_idle_limit_ms: None,
+        events,
+    })
+}
+
+/// Place events on the playback timeline, shortening pauses longer than
+/// `idle_limit_ms`.
+fn timeline(
+    events: Vec<(f64, FrameData)>,
+    started_at_ms: f64,
+    idle_limit_ms: Option<f64>,
+) -> Vec<Frame> {
+    let limit = idle_limit_ms.unwrap_or(f64::INFINITY);
+    let (mut prev, mut at_ms) = (0.0f64, 0.0f64);
+    events
+        .into_iter()
+        .map(|(t, data)| {
+            // Clamp out-of-order times, as players do.
+            let t = t.max(prev);
+            at_ms += (t - prev).min(limit);
+            prev = t;
+            Frame {
+                at_ms,
+                t_ms: started_at_ms + t,
+                data,
+            }
+        })
+        .collect()
+}
+
+struct Playback {
+    position_ms: f64,            // as of resumed_at when playing
+    resumed_at: Option<Instant>, // Some while playing
+    speed: f64,
+    next_frame: usize,
+    cols: u32,
+    rows: u32,
+}
+
+impl Playback {
+    fn position(&self, duration_ms: f64) -> f64 {
+        match self.resumed_at {
+            Some(at) => {
+                let elapsed = at.elapsed().as_secs_f64() * 1000.0 * self.speed;
+                (self.position_ms + elapsed).min(duration_ms)
+            }
+            None => self.position_ms,
+        }
+    }
+}
+
+// State shared with the playback task.
+struct Shared {
+    info: CastInfo,
+    frames: Vec<Frame>,
+    state: Mutex<Playback>,
+    wake: Notify,
+    buffer: Arc<OutputBuffer>,
+    on_status_changed: Option<Arc<dyn CastPlayerStatusCallback>>,
+}
+
+impl Shared {
+    fn lock(&self) -> std::sync::MutexGuard<'_, Playback> {
+        self.state.lock().unwrap_or_else(|p| p.into_inner())
+    }
+
+    fn status(&self, state: &Playback) -> PlaybackStatus {
+        PlaybackStatus {
+            playing: state.resumed_at.is_some(),
+            position_ms: state.position(self.info.duration_ms),
+            duration_ms: self.info.duration_ms,
+            speed: state.speed,
+            cols: state.cols,
+            rows: state.rows,
+        }
+    }
+
+    // Call without holding the state lock.
+    fn notify(&self, status: PlaybackStatus) {
+        if let Some(cb) = self.on_status_changed.as_ref() {
+            cb.on_change(status);
+        }
+    }
+
+    /// Append frames up to `position_ms`, batching output between resizes.
+    /// Returns whether the status changed (resize, or playback ended).
+    fn advance(&self, state: &mut Playback, position_ms: f64, mut out: Vec<u8>) -> bool {
+        let mut changed = false;
+        let mut t_ms = 0.0;
+        while let Some(frame) = self.frames.get(state.next_frame) {
+            if frame.at_ms > position_ms {
+                break;
+            }
+            state.next_frame += 1;
+            t_ms = frame.t_ms;
+            match &frame.data {
+                FrameData::Output(data) => out.extend_from_slice(data.as_bytes()),
+                FrameData::Resize { cols, rows } => {
+                    self.buffer
+                        .append(&std::mem::take(&mut out), StreamKind::Stdout, t_ms);
+                    self.resize(state, *cols, *rows);
+                    changed = true;
+                }
+            }
+        }
+        self.buffer.append(&out, StreamKind::Stdout, t_ms);
+        if state.resumed_at.is_some() && state.next_frame == self.frames.len() {
+            state.position_ms = self.info.duration_ms;
+            state.resumed_at = None;
+            changed = true;
+        }
+        changed
+    }
+
+    fn resize(&self, state: &mut Playback, cols: u32, rows: u32) {
+        (state.cols, state.rows) = (cols, rows);
+        if let Some(emulator) = self.buffer.emulator() {
+            emulator.resize(cols, rows);
+        }
+    }
+
+    // Restart the clock from the current position, e.g. before a speed change.
+    fn rebase(&self, state: &mut Playback) {
+        state.position_ms = state.position(self.info.duration_ms);
+        if state.resumed_at.is_some() {
+            state.resumed_at = Some(Instant::now());
+        }
+    }
+
+    fn seek(&self, state: &mut Playback, position_ms: f64) {
+        let position_ms = position_ms.clamp(0.0, self.info.duration_ms);
+        let played = state
+            .next_frame
+            .checked_sub(1)
+            .map(|i| self.frames[i].at_ms);
+        let mut out = Vec::new();
+        if played.is_some_and(|at| at > position_ms) {
+            // Going back: start over from an empty buffer and a reset screen.
+            self.buffer.clear();
+            state.next_frame = 0;
+            self.resize(state, self.info.cols, self.info.rows);
+            out.extend_from_slice(b"\x1bc");
+        }
+        state.position_ms = position_ms;
+        state.resumed_at = state.resumed_at.map(|_| Instant::now());
+        self.advance(state, position_ms, out);
+    }
+}
+
+async fn run_playback(shared: Arc<Shared>) {
+    loop {
+        let (due, status) = {
+            let mut state = shared.lock();
+            let position_ms = state.position(shared.info.duration_ms);
+            let changed = shared.advance(&mut state, position_ms, Vec::new());
+            let due = match shared.frames.get(state.next_frame) {
+                Some(frame) if state.resumed_at.is_some() => {
+                    let wait_ms = (frame.at_ms - position_ms).max(0.0) / state.speed;
+                    Some(Instant::now() + Duration::from_secs_f64(wait_ms / 1000.0))
+                }
+                _ => None,
+            };
+            (due, changed.then(|| shared.status(&state)))
+        };
+        if let Some(status) = status {
+            shared.notify(status);
+        }
+        match due {
+            Some(due) => {
+                tokio::select! {
+                    _ = tokio::time::sleep_until(due) => {}
+                    _ = shared.wake.notified() => {}
+                }
+            }
+            None => shared.wake.notified().await,
+        }
+    }
+}
+
+/// Plays an asciicast recording into the same buffer and listener API as
+/// `ShellSession`, so recordings render like live sessions.
+#[derive(uniffi::Object)]
+pub struct CastPlayer {
+    shared: Arc<Shared>,
+    task: tokio::task::JoinHandle<()>,
+}
+
+impl Drop for CastPlayer {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+/// Load an asciicast v2 (or v1) recording. Paused at the start unless
+/// `autoplay` is set.
+#[uniffi::export(async_runtime = "tokio")]
+pub async fn open_cast_player(
+    source: CastSource,
+    options: CastPlayerOptions,
+) -> Result<Arc<CastPlayer>, SshError> {
+    let text = match source {
+        CastSource::File { path } => tokio::task::spawn_blocking(move || {
+            std::fs::read_to_string(path).map_err(|e| SshError::Io(e.to_string()))
+        })
+        .await
+        .map_err(|e| SshError::Io(e.to_string()))??,
+        CastSource::Text { contents } => contents,
+    };
+    let speed = options.speed.unwrap_or(1.0);
+    if !(speed > 0.0 && speed.is_finite()) {
+        return Err(SshError::InvalidArgument(format!("speed {speed}")));
+    }
+    let buffer_policy = options.buffer_policy.unwrap_or_default();
+    buffer_policy.validate()?;
+    let cast = parse_cast(&text)?;
+    let idle_time_limit_ms = match options.idle_time_limit_ms {
+        Some(limit) if limit > 0.0 => Some(limit),
+        Some(_) => None,
+        None => cast.file_idle_limit_ms,
+    };
+    let frames = timeline(
+        cast.events,
+        cast.info.started_at_ms.unwrap_or(0.0),
+        idle_time_limit_ms,
+    );
+    let info = CastInfo {
+        duration_ms: frames.last().map_or(0.0, |f| f.at_ms),
+        idle_time_limit_ms,
+        ..cast.info
+    };
+    let rt_handle = tokio::runtime::Handle::current();
+    let shared = Arc::new(Shared {
+        state: Mutex::new(Playback {
+            position_ms: 0.0,
+            resumed_at: options.autoplay.unwrap_or(false).then(Instant::now),
+            speed,
+            next_frame: 0,
+            cols: info.cols,
+            rows: info.rows,
+        }),
+        info,
+        frames,
+        wake: Notify::new(),
+        buffer: OutputBuffer::new(rt_handle.clone(), &buffer_policy),
+        on_status_changed: options.on_status_changed,
+    });
+    let task = rt_handle.spawn(run_playback(shared.clone()));
+    Ok(Arc::new(CastPlayer { shared, task }))
+}
+
+#[uniffi::export(async_runtime = "tokio")]
+impl CastPlayer {
+    pub fn get_info(&self) -> CastInfo {
+        self.shared.info.clone()
+    }
+
+    pub fn get_status(&self) -> PlaybackStatus {
+        self.shared.status(&self.shared.lock())
+    }
+
+    /// Resume playback; at the end, start over.
+    pub fn play(&self) {
+        let status = {
+            let mut state = self.shared.lock();
+            if state.resumed_at.is_some() {
+                return;
+            }
+            if state.next_frame == self.shared.frames.len() {
+                self.shared.seek(&mut state, 0.0);
+            }
+            state.resumed_at = Some(Instant::now());
+            self.shared.status(&state)
+        };
+        self.shared.wake.notify_one();
+        self.shared.notify(status);
+    }
+
+    pub fn pause(&self) {
+        let status = {
+            let mut state = self.shared.lock();
+            if state.resumed_at.is_none() {
+                return;
+            }
+            self.shared.rebase(&mut state);
+            state.resumed_at = None;
+            self.shared.status(&state)
+        };
+        self.shared.wake.notify_one();
+        self.shared.notify(status);
+    }
+
+    /// Jump to `position_ms`. Seeking back clears the buffer and replays from
+    /// the start behind a terminal reset (ESC c), so listeners see a `Dropped`
+    /// range if they read from an older seq.
+    pub fn seek(&self, position_ms: f64) {
+        let status = {
+            let mut state = self.shared.lock();
+            self.shared.seek(&mut state, position_ms);
+            self.shared.status(&state)
+        };
+        self.shared.wake.notify_one();
+        self.shared.notify(status);
+    }
+
+    /// Playback rate; 2.0 plays twice as fast.
+    pub fn set_speed(&self, speed: f64) -> Result<(), SshError> {
+        if !(speed > 0.0 && speed.is_finite()) {
+            return Err(SshError::InvalidArgument(format!("speed {speed}")));
+        }
+        let status = {
+            let mut state = self.shared.lock();
+            self.shared.rebase(&mut state);
+            state.speed = speed;
+            self.shared.status(&state)
+        };
+        self.shared.wake.notify_one();
+        self.shared.notify(status);
+        Ok(())
+    }
+
+    /// Buffer statistics snapshot.
+    pub fn buffer_stats(&self) -> BufferStats {
+        self.shared.buffer.buffer_stats()
+    }
+
+    /// Current next sequence number.
+    pub fn current_seq(&self) -> u64 {
+        self.shared.buffer.current_seq()
+    }
+
+    /// Read what has been played so far from a cursor.
+    pub fn read_buffer(&self, cursor: Cursor, max_bytes: Option<u64>) -> BufferReadResult {
+        self.shared.buffer.read_buffer(cursor, max_bytes)
+    }
+
+    /// Same as `ShellSession::read_text`, over what has been played so far.
+    pub fn read_text(&self, cursor: Cursor, max_bytes: Option<u64>) -> TextReadResult {
+        self.shared.buffer.read_text(cursor, max_bytes)
+    }
+
+    /// Same as `ShellSession::search`, over what has been played so far.
+    pub async fn search(
+        &self,
+        pattern: String,
+        opts: SearchOptions,
+    ) -> Result<SearchResult, SshError> {
+        self.shared.buffer.search(pattern, opts).await
+    }
+
+    /// Add a listener with optional replay and live follow.
+    pub fn add_listener(
+        &self,
+        listener: Arc<dyn ShellListener>,
+        opts: ListenerOptions,
+    ) -> Result<u64, SshError> {
+        self.shared.buffer.add_listener(listener, opts)
+    }
+
+    pub fn remove_listener(&self, id: u64) {
+        self.shared.buffer.remove_listener(id);
+    }
+
+    /// Same as `ShellSession::attach_emulator`; follows the recording's
+    /// resizes.
+    pub fn attach_emulator(&self, scrollback_lines: Option<u32>) -> Arc<TerminalEmulator> {
+        let state = self.shared.lock();
+        self.shared
+            .buffer
+            .attach_emulator(state.cols, state.rows, scrollback_lines)
+    }
+
+    pub fn detach_emulator(&self) {
+        self.shared.buffer.detach_emulator();
+    }
+
+    /// Shell integration commands played so far.
+    pub fn commands(&self) -> Vec<CommandRecord> {
+        self.shared.buffer.commands()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::output_buffer::MEMORY_TESTS;
+
+    #[test]
+    fn parses_and_compresses_idle_time() {
+        let v2 = concat!(
+            r#"{"version": 2, "width": 80, "height": 24, "timestamp": 1000, "idle_time_limit": 2}"#,
+            "\n",
+            r#"[0.5, "o", "$ "]"#,
+            "\n",
+            r#"[1.0, "i", "ls\r"]"#,
+            "\n",
+            r#"[10.0, "r", "100x30"]"#,
+            "\n",
+            r#"[10.25, "o", "a\r\n"]"#,
+            "\n",
+            r#"[10.5, "o", "trunc"#,
+        );
+        let cast = parse_cast(v2).unwrap();
+        assert_eq!((cast.info.cols, cast.info.rows), (80, 24));
+        assert_eq!(cast.file_idle_limit_ms, Some(2000.0));
+        let frames = timeline(cast.events, 1_000_000.0, cast.file_idle_limit_ms);
+        let at: Vec<(f64, f64)> = frames.iter().map(|f| (f.at_ms, f.t_ms)).collect();
+        assert_eq!(
+            at,
+            [
+                (500.0, 1_000_500.0),
+                (2500.0, 1_010_000.0),
+                (2750.0, 1_010_250.0)
+            ]
+        );
+        assert_eq!(
+            frames[1].data,
+            FrameData::Resize {
+                cols: 100,
+                rows: 30
+            }
+        );
+
+        let v1 = r#"{
+            "version": 1, "width": 40, "height": 10,
+            "stdout": [[0.25, "a"], [1.5, "b"]]
+        }"#;
+        let cast = parse_cast(v1).unwrap();
+        assert_eq!(cast.info.version, 1);
+        let frames = timeline(cast.events, 0.0, None);
+        assert_eq!(frames[1].at_ms, 1750.0);
+        assert_eq!(frames[1].data, FrameData::Output("b".into()));
+
+        assert!(matches!(
+            parse_cast(r#"{"version": 3, "term": {"cols": 80, "rows": 24}}"#),
+            Err(SshError::Unsupported(_))
+        ));
+    }
+
+    const CAST: &str = concat!(
+        r#"{"version": 2, "width": 10, "height": 2}"#,
+        "\n",
+        r#"[1.0, "o", "a"]"#,
+        "\n",
+        r#"[2.0, "o", "b"]"#,
+        "\n",
+        r#"[3.0, "o", "c"]"#,
+    );
+
+    #[derive(Default)]
+    struct Statuses(Mutex<Vec<PlaybackStatus>>);
+
+    impl CastPlayerStatusCallback for Statuses {
+        fn on_change(&self, status: PlaybackStatus) {
+            self.0.lock().unwrap().push(status);
+        }
+    }
+
+    async fn open(autoplay: bool, statuses: Arc<Statuses>) -> Arc<CastPlayer> {
+        open_cast_player(
+            CastSource::Text {
+                contents: CAST.to_string(),
+            },
+            CastPlayerOptions {
+                speed: None,
+                idle_time_limit_ms: None,
+                autoplay: Some(autoplay),
+                on_status_changed: Some(statuses),
+                buffer_policy: None,
+            },
+        )
+        .await
+        .unwrap()
+    }
+
+    fn played(player: &CastPlayer) -> String {
+        let read = player.read_buffer(Cursor::Head, None);
+        let bytes: Vec<u8> = read.chunks.into_iter().flat_map(|c| c.bytes).collect();
+        String::from_utf8(bytes).unwrap()
+    }
+
+    async fn sleep_ms(ms: u64) {
+        tokio::time::sleep(Duration::from_millis(ms)).await;
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn plays_pauses_and_stops_at_the_end() {
+        let _shared = MEMORY_TESTS.read().await;
+        let statuses = Arc::new(Statuses::default());
+        let player = open(false, statuses.clone()).await;
+        sleep_ms(5000).await;
+        assert_eq!(played(&player), "");
+
+        player.play();
+        sleep_ms(1500).await;
+        assert_eq!(played(&player), "a");
+        player.pause();
+        assert_eq!(player.get_status().position_ms, 1500.0);
+        sleep_ms(5000).await;
+        assert_eq!(played(&player), "a");
+
+        // 500ms at double speed is 1000ms of recording.
+        player.set_speed(2.0).unwrap();
+        assert!(player.set_speed(0.0).is_err());
+        player.play();
+        sleep_ms(500).await;
+        assert_eq!(played(&player), "ab");
+        assert_eq!(player.get_status().position_ms, 2500.0);
+
+        sleep_ms(1000).await;
+        assert_eq!(played(&player), "abc");
+        let status = player.get_status();
+        assert!(!status.playing);
+        assert_eq!(status.position_ms, status.duration_ms);
+        let last = statuses.0.lock().unwrap().last().cloned().unwrap();
+        assert_eq!(last, status);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn seeking_back_replays_behind_a_reset() {
+        let _shared = MEMORY_TESTS.read().await;
+        let player = open(false, Arc::default()).await;
+        player.seek(2500.0);
+        assert_eq!(played(&player), "ab");
+
+        player.seek(500.0);
+        assert_eq!(played(&player), "\x1bc");
+        let stats = player.buffer_stats();
+        assert_eq!(stats.dropped_bytes_total, 2);
+        // A reader from before the seek learns what it missed.
+        let read = player.read_buffer(Cursor::Seq { seq: 1 }, None);
+        assert!(read.dropped.is_some());
+
+        player.seek(1000.0);
+        assert_eq!(played(&player), "\x1bca");
+
+        // Playing from the end starts over.
+        player.seek(3000.0);
+        player.play();
+        sleep_ms(1500).await;
+        assert_eq!(played(&player), "\x1bca");
+        assert!(player.get_status().playing);
+    }
+}
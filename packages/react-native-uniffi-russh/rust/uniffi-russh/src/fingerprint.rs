@@ -0,0 +1,175 @@
+use base64::Engine as _;
+use russh::keys::ssh_key::{public::KeyData, EcdsaCurve, HashAlg, Mpint};
+use russh::keys::PublicKey;
+
+use crate::utils::SshError;
+
+#[derive(Debug, Clone, PartialEq, uniffi::Record)]
+pub struct PublicKeyFingerprints {
+    pub algorithm: String,
+    pub fingerprint_sha256: String, // e.g., "SHA256:..." (no padding)
+    pub fingerprint_md5: String,    // e.g., "MD5:16:27:ac:..."
+    pub randomart: String,          // multi-line, as printed by `ssh-keygen -lv`
+}
+
+/// Fingerprints and randomart for a public key given as an OpenSSH line
+/// ("ssh-ed25519 AAAA... comment", e.g. from `extract_public_key`) or as the
+/// bare base64 key blob (e.g. `ServerPublicKeyInfo.key_base64`).
+#[uniffi::export]
+pub fn public_key_fingerprints(public_key: String) -> Result<PublicKeyFingerprints, SshError> {
+    let trimmed = public_key.trim();
+    let key = match PublicKey::from_openssh(trimmed) {
+        Ok(key) => key,
+        Err(e) => {
+            let blob = base64::engine::general_purpose::STANDARD
+                .decode(trimmed)
+                .map_err(|_| SshError::from(e))?;
+            PublicKey::from_bytes(&blob)?
+        }
+    };
+    Ok(fingerprints(&key))
+}
+
+pub(crate) fn fingerprints(pk: &PublicKey) -> PublicKeyFingerprints {
+    let sha256 = pk.fingerprint(HashAlg::Sha256);
+    PublicKeyFingerprints {
+        algorithm: pk.algorithm().to_string(),
+        fingerprint_sha256: sha256.to_string(),
+        fingerprint_md5: md5_fingerprint(pk),
+        randomart: randomart(&key_title(pk), "SHA256", sha256.as_bytes()),
+    }
+}
+
+fn md5_fingerprint(pk: &PublicKey) -> String {
+    let blob = pk.to_bytes().unwrap_or_default();
+    let digest = md5::compute(blob);
+    let hex: Vec<String> = digest.iter().map(|b| format!("{b:02x}")).collect();
+    format!("MD5:{}", hex.join(":"))
+}
+
+/// "[ED25519 256]", "[RSA 3072]", ... as in OpenSSH's randomart header.
+fn key_title(pk: &PublicKey) -> String {
+    let (name, bits) = match pk.key_data() {
+        KeyData::Ed25519(_) => ("ED25519", Some(256)),
+        KeyData::SkEd25519(_) => ("ED25519-SK", Some(256)),
+        KeyData::Rsa(k) => ("RSA", Some(mpint_bits(&k.n))),
+        KeyData::Dsa(k) => ("DSA", Some(mpint_bits(&k.p))),
+        KeyData::Ecdsa(k) => (
+            "ECDSA",
+            Some(match k.curve() {
+                EcdsaCurve::NistP256 => 256,
+                EcdsaCurve::NistP384 => 384,
+                EcdsaCurve::NistP521 => 521,
+            }),
+        ),
+        KeyData::SkEcdsaSha2NistP256(_) => ("ECDSA-SK", Some(256)),
+        _ => ("UNKNOWN", None),
+    };
+    match bits {
+        Some(bits) if name.len() + 4 + bits.to_string().len() <= FIELD_WIDTH => {
+            format!("[{name} {bits}]")
+        }
+        _ => format!("[{name}]"),
+    }
+}
+
+fn mpint_bits(n: &Mpint) -> u32 {
+    let bytes = n.as_positive_bytes().unwrap_or_default();
+    match bytes.iter().position(|b| *b != 0) {
+        Some(i) => (bytes.len() - i) as u32 * 8 - bytes[i].leading_zeros(),
+        None => 0,
+    }
+}
+
+// "Drunken bishop" visual host key, matching OpenSSH's key_fingerprint_randomart.
+const FIELD_WIDTH: usize = 17;
+const FIELD_HEIGHT: usize = 9;
+const AUGMENTATION: &[u8] = b" .o+=*BOX@%&#/^SE";
+
+pub(crate) fn randomart(title: &str, hash_name: &str, digest: &[u8]) -> String {
+    let symbols = AUGMENTATION.len() - 1; // index of 'E'
+    let mut field = [[0usize; FIELD_HEIGHT]; FIELD_WIDTH];
+    let (mut x, mut y) = (FIELD_WIDTH / 2, FIELD_HEIGHT / 2);
+    for byte in digest {
+        let mut input = *byte;
+        for _ in 0..4 {
+            x = if input & 0x1 != 0 {
+                (x + 1).min(FIELD_WIDTH - 1)
+            } else {
+                x.saturating_sub(1)
+            };
+            y = if input & 0x2 != 0 {
+                (y + 1).min(FIELD_HEIGHT - 1)
+            } else {
+                y.saturating_sub(1)
+            };
+            if field[x][y] < symbols - 2 {
+                field[x][y] += 1;
+            }
+            input >>= 2;
+        }
+    }
+    field[FIELD_WIDTH / 2][FIELD_HEIGHT / 2] = symbols - 1;
+    field[x][y] = symbols;
+
+    let mut out = String::with_capacity((FIELD_WIDTH + 3) * (FIELD_HEIGHT + 2));
+    out.push_str(&border(title));
+    out.push('\n');
+    for row in 0..FIELD_HEIGHT {
+        out.push('|');
+        for column in field.iter() {
+            out.push(AUGMENTATION[column[row].min(symbols)] as char);
+        }
+        out.push_str("|\n");
+    }
+    out.push_str(&border(&format!("[{hash_name}]")));
+    out
+}
+
+fn border(label: &str) -> String {
+    let label = if label.len() > FIELD_WIDTH { "" } else { label };
+    let left = (FIELD_WIDTH - label.len()) / 2;
+    let right = FIELD_WIDTH - label.len() - left;
+    format!("+{}{label}{}+", "-".repeat(left), "-".repeat(right))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ED25519: &str =
+        "ssh-ed25519 AAAAC3NzaC1lZDI1NTE5AAAAIOMqqnkVzrm0SdG6UOoqKLsabgH5C9okWi0dh2l9GKJl test";
+
+    #[test]
+    fn fingerprints_openssh_line_and_bare_blob() {
+        let from_line = public_key_fingerprints(ED25519.into()).unwrap();
+        let blob = ED25519.split_whitespace().nth(1).unwrap();
+        let from_blob = public_key_fingerprints(blob.into()).unwrap();
+        assert_eq!(from_line, from_blob);
+        assert_eq!(from_line.algorithm, "ssh-ed25519");
+        assert_eq!(
+            from_line.fingerprint_md5,
+            "MD5:65:96:2d:fc:e8:d5:a9:11:64:0c:0f:ea:00:6e:5b:bd"
+        );
+        assert_eq!(
+            from_line.fingerprint_sha256,
+            "SHA256:+DiY3wvvV6TuJJhbpZisF/zLDA0zPMSvHdkr4UvCOqU"
+        );
+        let expected_art = [
+            "+--[ED25519 256]--+",
+            "|                 |",
+            "|     .           |",
+            "|      o          |",
+            "|     o o o  .    |",
+            "|     .B S oo     |",
+            "|     =+^ =...    |",
+            "|    oo#o@.o.     |",
+            "|    E+.&.=o      |",
+            "|    ooo.X=.      |",
+            "+----[SHA256]-----+",
+        ];
+        assert_eq!(from_line.randomart, expected_art.join("\n"));
+
+        assert!(public_key_fingerprints("not a key".into()).is_err());
+    }
+}
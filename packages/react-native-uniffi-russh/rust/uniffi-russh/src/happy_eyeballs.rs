@@ -0,0 +1,181 @@
+use std::{
+    io,
+    net::{IpAddr, Ipv6Addr, SocketAddr, SocketAddrV6},
+    time::Duration,
+};
+
+use tokio::{net::TcpStream, task::JoinSet};
+
+// RFC 8305 §5 recommends 250ms between connection attempts.
+pub(crate) static CONNECTION_ATTEMPT_DELAY: Duration = Duration::from_millis(250);
+
+/// Resolve `host` into the addresses to race, in attempt order (RFC 8305
+/// §4).
+///
+/// Accepts hostnames, IPv4 literals and IPv6 literals with or without
+/// brackets, including zone-scoped ones ("fe80::1%wlan0", "[fe80::1%25en0]").
+pub(crate) async fn resolve_candidates(host: &str, port: u16) -> io::Result<Vec<SocketAddr>> {
+    Ok(interleave_families(resolve(host, port).await?))
+}
+
+async fn resolve(host: &str, port: u16) -> io::Result<Vec<SocketAddr>> {
+    let host = normalize_host(host);
+    if let Some(addr) = parse_ip_literal(&host, port) {
+        return Ok(vec![addr]);
+    }
+    // Hostnames, and IPv6 literals scoped by interface name, which only the
+    // system resolver can map to an interface index.
+    let addrs: Vec<SocketAddr> = tokio::net::lookup_host((host.as_str(), port))
+        .await?
+        .collect();
+    if addrs.is_empty() {
+        return Err(io::Error::new(
+            io::ErrorKind::NotFound,
+            format!("no addresses found for {host}"),
+        ));
+    }
+    Ok(addrs)
+}
+
+/// Strip URI-style brackets. Inside brackets the zone separator may be
+/// written as "%25" (RFC 6874), so unescape it there; a bare host is taken
+/// as written.
+fn normalize_host(host: &str) -> String {
+    let host = host.trim();
+    let Some(inner) = host.strip_prefix('[').and_then(|h| h.strip_suffix(']')) else {
+        return host.to_string();
+    };
+    match inner.split_once('%') {
+        Some((addr, zone)) => match zone.strip_prefix("25") {
+            Some(zone) => format!("{addr}%{zone}"),
+            None => inner.to_string(),
+        },
+        None => inner.to_string(),
+    }
+}
+
+/// Parse IP literals we can build without the resolver, including IPv6 with a
+/// numeric zone. A zone given as an interface name returns None.
+fn parse_ip_literal(host: &str, port: u16) -> Option<SocketAddr> {
+    if let Ok(ip) = host.parse::<IpAddr>() {
+        return Some(SocketAddr::new(ip, port));
+    }
+    let (addr, zone) = host.split_once('%')?;
+    let ip = addr.parse::<Ipv6Addr>().ok()?;
+    let scope_id = zone.parse::<u32>().ok()?;
+    Some(SocketAddr::V6(SocketAddrV6::new(ip, port, 0, scope_id)))
+}
+
+/// Order addresses for racing (RFC 8305 §4): alternate families, starting
+/// with the family the resolver ranked first, keeping order within a family.
+fn interleave_families(addrs: Vec<SocketAddr>) -> Vec<SocketAddr> {
+    let Some(first) = addrs.first() else {
+        return addrs;
+    };
+    let first_is_v6 = first.is_ipv6();
+    let (preferred, other): (Vec<SocketAddr>, Vec<SocketAddr>) =
+        addrs.into_iter().partition(|a| a.is_ipv6() == first_is_v6);
+    let mut out = Vec::with_capacity(preferred.len() + other.len());
+    let mut preferred = preferred.into_iter();
+    let mut other = other.into_iter();
+    loop {
+        match (preferred.next(), other.next()) {
+            (None, None) => break,
+            (a, b) => out.extend(a.into_iter().chain(b)),
+        }
+    }
+    out
+}
+
+/// Connect to one of `candidates` with RFC 8305 "happy eyeballs" racing:
+/// start one attempt, then another every CONNECTION_ATTEMPT_DELAY (or as soon
+/// as one fails) until a connection succeeds. The losers are aborted, which
+/// drops their sockets.
+pub(crate) async fn race(candidates: &[SocketAddr]) -> io::Result<(TcpStream, SocketAddr)> {
+    let mut queue = candidates.iter().copied();
+    let mut in_flight = JoinSet::new();
+    let mut last_err: Option<io::Error> = None;
+    let mut start_next = true;
+    loop {
+        if start_next {
+            if let Some(addr) = queue.next() {
+                in_flight.spawn(async move { (addr, TcpStream::connect(addr).await) });
+            }
+            start_next = false;
+        }
+        if in_flight.is_empty() {
+            return Err(last_err.unwrap_or_else(|| {
+                io::Error::new(io::ErrorKind::NotFound, "no addresses to connect to")
+            }));
+        }
+        tokio::select! {
+            joined = in_flight.join_next() => match joined {
+                Some(Ok((addr, Ok(stream)))) => return Ok((stream, addr)),
+                Some(Ok((_, Err(e)))) => {
+                    last_err = Some(e);
+                    start_next = true;
+                }
+                Some(Err(e)) => {
+                    last_err = Some(io::Error::other(e));
+                    start_next = true;
+                }
+                None => {}
+            },
+            _ = tokio::time::sleep(CONNECTION_ATTEMPT_DELAY) => start_next = true,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_bracketed_and_scoped_ipv6_literals() {
+        let addr = parse_ip_literal(&normalize_host("[::1]"), 22).unwrap();
+        assert_eq!(addr, "[::1]:22".parse().unwrap());
+
+        let SocketAddr::V6(scoped) = parse_ip_literal("fe80::1%25", 22).unwrap() else {
+            panic!("expected IPv6");
+        };
+        assert_eq!(scoped.scope_id(), 25);
+
+        let SocketAddr::V6(escaped) =
+            parse_ip_literal(&normalize_host("[fe80::1%253]"), 22).unwrap()
+        else {
+            panic!("expected IPv6");
+        };
+        assert_eq!(escaped.scope_id(), 3);
+
+        assert_eq!(normalize_host("[fe80::1%25wlan0]"), "fe80::1%wlan0");
+        assert_eq!(normalize_host("[fe80::1%3]"), "fe80::1%3");
+        // Only a bracketed host is URI-escaped.
+        assert_eq!(normalize_host("fe80::1%25wlan0"), "fe80::1%25wlan0");
+        assert!(parse_ip_literal("fe80::1%wlan0", 22).is_none());
+        assert!(parse_ip_literal("example.com", 22).is_none());
+    }
+
+    #[test]
+    fn interleaves_address_families() {
+        let addrs: Vec<SocketAddr> = ["[2001:db8::1]:22", "[2001:db8::2]:22", "192.0.2.1:22"]
+            .iter()
+            .map(|a| a.parse().unwrap())
+            .collect();
+        let ordered = interleave_families(addrs.clone());
+        assert_eq!(ordered, vec![addrs[0], addrs[2], addrs[1]]);
+    }
+
+    #[tokio::test]
+    async fn race_skips_unreachable_candidates() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let good = listener.local_addr().unwrap();
+        // Bind then drop to get a port that refuses connections.
+        let refused = tokio::net::TcpListener::bind("127.0.0.1:0")
+            .await
+            .unwrap()
+            .local_addr()
+            .unwrap();
+        let (_, chosen) = race(&[refused, good]).await.unwrap();
+        assert_eq!(chosen, good);
+    }
+}
@@ -5,9 +5,21 @@
 //! - https://jhugman.github.io/uniffi-bindgen-react-native/idioms/callback-interfaces.html
 //! - https://jhugman.github.io/uniffi-bindgen-react-native/idioms/async-callbacks.html
 
+mod ansi;
+pub mod cast_player;
+pub mod fingerprint;
+mod happy_eyeballs;
+pub mod metrics;
+mod output_buffer;
 pub mod private_key;
+pub mod recording;
+pub mod shell_integration;
 pub mod ssh_connection;
 pub mod ssh_shell;
+pub mod terminal_emulator;
+pub mod terminfo;
+mod transport;
+mod utf8;
 pub mod utils;
 
 uniffi::setup_scaffolding!();
@@ -0,0 +1,181 @@
+use std::sync::{
+    atomic::{AtomicU32, AtomicU64, Ordering},
+    Mutex,
+};
+
+use crate::utils::now_ms;
+
+/// Snapshot of the traffic and latency counters of a connection.
+#[derive(Debug, Clone, PartialEq, uniffi::Record)]
+pub struct SshConnectionMetrics {
+    pub bytes_sent: u64,     // bytes written to the socket (encrypted, incl. framing)
+    pub bytes_received: u64, // bytes read from the socket (encrypted, incl. framing)
+    pub payload_bytes_sent: u64, // channel data handed to russh
+    pub payload_bytes_received: u64, // channel data delivered by russh
+    // Payload bytes per socket byte. Framing, MACs and handshakes keep this
+    // below 1 without compression; above 1, compression is paying off.
+    pub compression_ratio_sent: Option<f64>,
+    pub compression_ratio_received: Option<f64>,
+    pub compression_client_to_server: Option<String>, // e.g., "zlib@openssh.com", "none"
+    pub compression_server_to_client: Option<String>,
+    pub channels_open: u32,
+    pub channels_opened_total: u32,
+    // Completed re-exchanges: rekey limits, `rekey()` and server-started.
+    pub rekey_count: u32,
+    pub rtt_last_ms: Option<f64>,
+    pub rtt_min_ms: Option<f64>,
+    pub rtt_smoothed_ms: Option<f64>,
+    pub sampled_at_ms: f64,
+}
+
+#[uniffi::export(with_foreign)]
+pub trait ConnectionMetricsCallback: Send + Sync {
+    fn on_change(&self, metrics: SshConnectionMetrics);
+}
+
+// How often the connection monitor probes the round-trip time and reports
+// metrics, unless overridden in ConnectOptions.
+pub(crate) static DEFAULT_METRICS_INTERVAL_MS: u64 = 10_000;
+
+// A probe without a reply by then is dropped (no sample) so it stops holding
+// the client handle.
+pub(crate) static RTT_PROBE_TIMEOUT_MS: u64 = 5_000;
+
+/// Round-trip-time estimator. The smoothed value follows RFC 6298 (alpha = 1/8).
+#[derive(Debug, Default, Clone, Copy)]
+pub(crate) struct RttEstimate {
+    last_ms: Option<f64>,
+    min_ms: Option<f64>,
+    smoothed_ms: Option<f64>,
+}
+impl RttEstimate {
+    pub(crate) fn record(&mut self, sample_ms: f64) {
+        self.last_ms = Some(sample_ms);
+        self.min_ms = Some(self.min_ms.map_or(sample_ms, |m| m.min(sample_ms)));
+        self.smoothed_ms = Some(match self.smoothed_ms {
+            Some(srtt) => srtt + (sample_ms - srtt) / 8.0,
+            None => sample_ms,
+        });
+    }
+}
+
+/// Live counters shared between the transport, the connection and its shells.
+#[derive(Debug, Default)]
+pub(crate) struct ConnectionMetrics {
+    pub(crate) bytes_sent: AtomicU64,
+    pub(crate) bytes_received: AtomicU64,
+    pub(crate) payload_bytes_sent: AtomicU64,
+    pub(crate) payload_bytes_received: AtomicU64,
+    pub(crate) channels_open: AtomicU32,
+    pub(crate) channels_opened_total: AtomicU32,
+    pub(crate) rekey_count: AtomicU32,
+    rtt: Mutex<RttEstimate>,
+    compression: Mutex<Option<(String, String)>>, // (client->server, server->client)
+}
+
+impl ConnectionMetrics {
+    pub(crate) fn record_rtt(&self, sample_ms: f64) {
+        self.rtt
+            .lock()
+            .unwrap_or_else(|p| p.into_inner())
+            .record(sample_ms);
+    }
+
+    pub(crate) fn set_compression(&self, client_to_server: &str, server_to_client: &str) {
+        *self.compression.lock().unwrap_or_else(|p| p.into_inner()) =
+            Some((client_to_server.to_string(), server_to_client.to_string()));
+    }
+
+    pub(crate) fn payload_sent(&self, len: usize) {
+        self.payload_bytes_sent
+            .fetch_add(len as u64, Ordering::Relaxed);
+    }
+
+    pub(crate) fn payload_received(&self, len: usize) {
+        self.payload_bytes_received
+            .fetch_add(len as u64, Ordering::Relaxed);
+    }
+
+    pub(crate) fn channel_opened(&self) {
+        self.channels_open.fetch_add(1, Ordering::Relaxed);
+        self.channels_opened_total.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn channel_closed(&self) {
+        self.channels_open.fetch_sub(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn snapshot(&self) -> SshConnectionMetrics {
+        let rtt = *self.rtt.lock().unwrap_or_else(|p| p.into_inner());
+        let (compression_client_to_server, compression_server_to_client) = self
+            .compression
+            .lock()
+            .unwrap_or_else(|p| p.into_inner())
+            .clone()
+            .unzip();
+        let bytes_sent = self.bytes_sent.load(Ordering::Relaxed);
+        let bytes_received = self.bytes_received.load(Ordering::Relaxed);
+        let payload_bytes_sent = self.payload_bytes_sent.load(Ordering::Relaxed);
+        let payload_bytes_received = self.payload_bytes_received.load(Ordering::Relaxed);
+        SshConnectionMetrics {
+            bytes_sent,
+            bytes_received,
+            payload_bytes_sent,
+            payload_bytes_received,
+            compression_ratio_sent: ratio(payload_bytes_sent, bytes_sent),
+            compression_ratio_received: ratio(payload_bytes_received, bytes_received),
+            compression_client_to_server,
+            compression_server_to_client,
+            channels_open: self.channels_open.load(Ordering::Relaxed),
+            channels_opened_total: self.channels_opened_total.load(Ordering::Relaxed),
+            rekey_count: self.rekey_count.load(Ordering::Relaxed),
+            rtt_last_ms: rtt.last_ms,
+            rtt_min_ms: rtt.min_ms,
+            rtt_smoothed_ms: rtt.smoothed_ms,
+            sampled_at_ms: now_ms(),
+        }
+    }
+}
+
+// None until there is payload to compare against.
+fn ratio(payload: u64, wire: u64) -> Option<f64> {
+    (payload > 0 && wire > 0).then(|| payload as f64 / wire as f64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rtt_estimate_tracks_last_min_and_smoothed() {
+        let mut rtt = RttEstimate::default();
+        assert_eq!(rtt.last_ms, None);
+
+        rtt.record(80.0);
+        assert_eq!(rtt.last_ms, Some(80.0));
+        assert_eq!(rtt.min_ms, Some(80.0));
+        assert_eq!(rtt.smoothed_ms, Some(80.0)); // first sample taken as is
+
+        rtt.record(40.0);
+        assert_eq!(rtt.last_ms, Some(40.0));
+        assert_eq!(rtt.min_ms, Some(40.0));
+        assert_eq!(rtt.smoothed_ms, Some(75.0)); // 80 + (40 - 80) / 8
+
+        rtt.record(155.0);
+        assert_eq!(rtt.last_ms, Some(155.0));
+        assert_eq!(rtt.min_ms, Some(40.0));
+        assert_eq!(rtt.smoothed_ms, Some(85.0)); // 75 + (155 - 75) / 8
+    }
+
+    #[test]
+    fn snapshot_reports_rtt() {
+        let metrics = ConnectionMetrics::default();
+        assert_eq!(metrics.snapshot().rtt_last_ms, None);
+        metrics.record_rtt(12.0);
+        metrics.record_rtt(20.0);
+        let snap = metrics.snapshot();
+        assert_eq!(snap.rtt_last_ms, Some(20.0));
+        assert_eq!(snap.rtt_min_ms, Some(12.0));
+        assert_eq!(snap.rtt_smoothed_ms, Some(13.0));
+    }
+}
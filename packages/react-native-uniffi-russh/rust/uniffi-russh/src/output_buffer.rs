@@ -0,0 +1,1620 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::{
+        atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering},
+        Arc, Mutex, Weak,
+    },
+    time::{Duration, Instant},
+};
+
+use bytes::Bytes;
+use tokio::sync::{broadcast, Notify};
+
+use crate::{
+    ansi::{render_text, Stripper},
+    recording::{CastEvent, CastHeader, Recorder, RecordingStats},
+    shell_integration::{CommandRecord, CommandTracker, DEFAULT_MAX_COMMAND_RECORDS},
+    ssh_shell::{
+        BufferPolicy, BufferReadResult, BufferStats, ChannelPauseCallback, Cursor, DroppedRange,
+        InvalidUtf8, ListenerOptions, MemoryUsage, SearchMatch, SearchOptions, SearchResult,
+        ShellEvent, ShellListener, StreamKind, TerminalChunk, TerminalText, TextReadResult,
+        DEFAULT_BROADCAST_CHUNK_CAPACITY, DEFAULT_LOSSLESS_MAX_LAG_BYTES, DEFAULT_MAX_CHUNK_SIZE,
+        DEFAULT_READ_BUFFER_MAX_BYTES, DEFAULT_SEARCH_MAX_MATCHES,
+        DEFAULT_SHELL_RING_BUFFER_CAPACITY, DEFAULT_TERM_COALESCE_MS,
+    },
+    terminal_emulator::TerminalEmulator,
+    utf8::Utf8Carry,
+    utils::{now_ms, SshError},
+};
+
+// Bytes held by all rings, and the cap on that total (0 = none).
+static TOTAL_USED_BYTES: AtomicUsize = AtomicUsize::new(0);
+static MEMORY_BUDGET: AtomicUsize = AtomicUsize::new(0);
+static BUFFERS: Mutex<Vec<Weak<OutputBuffer>>> = Mutex::new(Vec::new());
+
+pub(crate) fn set_memory_budget(bytes: Option<u64>) -> Result<(), SshError> {
+    let budget = match bytes {
+        Some(n) => usize::try_from(n).map_err(|_| {
+            SshError::InvalidArgument(format!("memory budget {n} exceeds the address space"))
+        })?,
+        None => 0,
+    };
+    MEMORY_BUDGET.store(budget, Ordering::Relaxed);
+    enforce_memory_budget();
+    Ok(())
+}
+
+// For byte counts from the caller that only bound a walk or a read: past the
+// address space they cannot be reached anyway.
+fn saturating_usize(n: u64) -> usize {
+    usize::try_from(n).unwrap_or(usize::MAX)
+}
+
+pub(crate) fn memory_usage() -> MemoryUsage {
+    let budget = MEMORY_BUDGET.load(Ordering::Relaxed);
+    MemoryUsage {
+        used_bytes: TOTAL_USED_BYTES.load(Ordering::Relaxed) as u64,
+        budget_bytes: (budget != 0).then_some(budget as u64),
+    }
+}
+
+// Evict the first-appended chunks across all buffers until the total fits.
+fn enforce_memory_budget() {
+    let budget = MEMORY_BUDGET.load(Ordering::Relaxed);
+    if budget == 0 || TOTAL_USED_BYTES.load(Ordering::Relaxed) <= budget {
+        return;
+    }
+    let buffers: Vec<Arc<OutputBuffer>> = {
+        let mut all = BUFFERS.lock().unwrap_or_else(|p| p.into_inner());
+        all.retain(|b| b.strong_count() > 0);
+        all.iter().filter_map(Weak::upgrade).collect()
+    };
+    while TOTAL_USED_BYTES.load(Ordering::Relaxed) > budget {
+        let oldest = buffers
+            .iter()
+            .filter_map(|b| Some((b.oldest_stored_at()?, b)))
+            .min_by_key(|(stored_at, _)| *stored_at);
+        match oldest {
+            Some((_, buffer)) => buffer.evict_front(),
+            None => break,
+        }
+    }
+}
+
+// Internal chunk type kept in ring/broadcast
+#[derive(Debug)]
+pub(crate) struct Chunk {
+    // TODO: This is very similar to TerminalChunk. The only difference is the bytes field
+    pub(crate) seq: u64,
+    pub(crate) t_ms: f64,
+    pub(crate) stream: StreamKind,
+    pub(crate) bytes: Bytes,
+    pub(crate) end: u64,          // stream bytes up to and including this chunk
+    pub(crate) line: u64,         // newlines before this chunk: the line it starts in
+    pub(crate) starts_line: bool, // the byte before it was a newline (or none)
+    pub(crate) commands: Vec<CommandRecord>, // records updated by marks in this chunk
+    // When it was appended. The memory budget evicts by this: cast playback
+    // stamps t_ms relative to the recording, not the wall clock.
+    pub(crate) stored_at: Instant,
+}
+
+// Turns chunks into text for a listener, with a decoder per stream.
+struct TextListener {
+    inner: Arc<dyn ShellListener>,
+    decoders: Mutex<[Utf8Carry; 2]>, // stdout, stderr
+}
+
+impl TextListener {
+    fn new(inner: Arc<dyn ShellListener>, invalid: InvalidUtf8) -> Self {
+        TextListener {
+            inner,
+            decoders: Mutex::new([Utf8Carry::new(invalid), Utf8Carry::new(invalid)]),
+        }
+    }
+}
+
+impl ShellListener for TextListener {
+    fn on_event(&self, ev: ShellEvent) {
+        let mut decoders = self.decoders.lock().unwrap_or_else(|p| p.into_inner());
+        match ev {
+            ShellEvent::Chunk(chunk) => {
+                let decoder = &mut decoders[(chunk.stream == StreamKind::Stderr) as usize];
+                let text = decoder.decode(&chunk.bytes);
+                drop(decoders);
+                if !text.is_empty() {
+                    self.inner.on_event(ShellEvent::Text(TerminalText {
+                        seq: chunk.seq,
+                        t_ms: chunk.t_ms,
+                        stream: chunk.stream,
+                        text,
+                    }));
+                }
+            }
+            ShellEvent::Dropped { .. } => {
+                // The rest of a held-back character was lost.
+                for decoder in decoders.iter_mut() {
+                    decoder.flush();
+                }
+                drop(decoders);
+                self.inner.on_event(ev);
+            }
+            ev => {
+                drop(decoders);
+                self.inner.on_event(ev);
+            }
+        }
+    }
+}
+
+fn count_newlines(bytes: &[u8]) -> u64 {
+    bytes.iter().filter(|&&b| b == b'\n').count() as u64
+}
+
+// Where `line` starts in the ring: (chunk index, offset in it). Lines that
+// were evicted start at the head; lines past the end at ring.len().
+fn line_start(ring: &VecDeque<Arc<Chunk>>, line: u64) -> (usize, usize) {
+    // The chunk holding the line-th newline; the line starts right after it.
+    let idx = ring.partition_point(|c| c.line < line);
+    let Some(chunk) = idx.checked_sub(1).map(|i| &ring[i]) else {
+        return (0, 0);
+    };
+    let nth = (line - chunk.line - 1) as usize;
+    let offset = chunk
+        .bytes
+        .iter()
+        .enumerate()
+        .filter(|(_, &b)| b == b'\n')
+        .nth(nth)
+        .map_or(chunk.bytes.len(), |(i, _)| i + 1);
+    if offset == chunk.bytes.len() {
+        (idx, 0)
+    } else {
+        (idx - 1, offset)
+    }
+}
+
+struct LosslessProgress {
+    delivered: u64, // stream bytes handed to the listener
+    max_lag: u64,
+}
+
+/// Output a paused channel received but has not appended yet. The channel's
+/// window is held meanwhile, so this is bounded by what was left of it.
+#[derive(Default)]
+pub(crate) struct ChannelIntake {
+    held: VecDeque<(Vec<u8>, StreamKind, f64)>,
+    paused_since: Option<f64>,
+}
+
+/// Replay ring, live broadcast and listeners for a stream of terminal output.
+/// Shared by live shells and recording playback.
+pub(crate) struct OutputBuffer {
+    // Ring buffer
+    ring: Mutex<VecDeque<Arc<Chunk>>>,
+    ring_bytes_capacity: AtomicUsize,
+    max_chunk_bytes: AtomicUsize,
+    used_bytes: Mutex<usize>,
+    dropped_bytes_total: AtomicU64,
+    head_seq: AtomicU64,
+    tail_seq: AtomicU64,
+    next_seq: AtomicU64,
+    appended_bytes: AtomicU64,
+    appended_lines: AtomicU64,
+    at_line_start: AtomicBool, // the last byte appended was a newline
+
+    // Live broadcast
+    sender: broadcast::Sender<Arc<Chunk>>,
+    broadcast_chunks: usize, // fixed at creation
+
+    // Listener tasks management
+    listener_tasks: Mutex<HashMap<u64, tokio::task::JoinHandle<()>>>,
+    next_listener_id: AtomicU64,
+    coalesce_ms: AtomicU64,
+    pub(crate) rt_handle: tokio::runtime::Handle,
+
+    // Lossless listeners hold back appends while too far behind.
+    lossless: Mutex<HashMap<u64, LosslessProgress>>,
+    progress: Notify,
+    channel_paused: AtomicBool,
+    channel_paused_ms_total: Mutex<f64>,
+
+    // Fed as chunks are appended, so they see every chunk in order.
+    emulator: Mutex<Option<Arc<TerminalEmulator>>>,
+    command_tracker: Mutex<CommandTracker>,
+    recorder: Mutex<Option<Recorder>>,
+}
+
+impl OutputBuffer {
+    /// Create a buffer counted against the memory budget. Call
+    /// `policy.validate()` first.
+    pub(crate) fn new(rt_handle: tokio::runtime::Handle, policy: &BufferPolicy) -> Arc<Self> {
+        let broadcast_chunks = policy
+            .broadcast_chunks
+            .map_or(DEFAULT_BROADCAST_CHUNK_CAPACITY, |n| n as usize);
+        let (sender, _rx) = broadcast::channel::<Arc<Chunk>>(broadcast_chunks);
+        let buffer = Arc::new(OutputBuffer {
+            ring: Mutex::new(VecDeque::new()),
+            ring_bytes_capacity: AtomicUsize::new(
+                policy
+                    .ring_bytes
+                    .map_or(DEFAULT_SHELL_RING_BUFFER_CAPACITY, saturating_usize),
+            ),
+            max_chunk_bytes: AtomicUsize::new(
+                policy
+                    .max_chunk_bytes
+                    .map_or(DEFAULT_MAX_CHUNK_SIZE, |n| n as usize),
+            ),
+            used_bytes: Mutex::new(0),
+            dropped_bytes_total: AtomicU64::new(0),
+            head_seq: AtomicU64::new(1),
+            tail_seq: AtomicU64::new(0),
+            next_seq: AtomicU64::new(1),
+            appended_bytes: AtomicU64::new(0),
+            appended_lines: AtomicU64::new(0),
+            at_line_start: AtomicBool::new(true),
+            sender,
+            broadcast_chunks,
+            listener_tasks: Mutex::new(HashMap::new()),
+            next_listener_id: AtomicU64::new(1),
+            coalesce_ms: AtomicU64::new(
+                policy
+                    .coalesce_ms
+                    .map_or(DEFAULT_TERM_COALESCE_MS, u64::from),
+            ),
+            rt_handle,
+            lossless: Mutex::new(HashMap::new()),
+            progress: Notify::new(),
+            channel_paused: AtomicBool::new(false),
+            channel_paused_ms_total: Mutex::new(0.0),
+            emulator: Mutex::new(None),
+            command_tracker: Mutex::new(CommandTracker::new(DEFAULT_MAX_COMMAND_RECORDS)),
+            recorder: Mutex::new(None),
+        });
+        BUFFERS
+            .lock()
+            .unwrap_or_else(|p| p.into_inner())
+            .push(Arc::downgrade(&buffer));
+        buffer
+    }
+
+    /// Apply the set fields of `policy`; shrinking the ring evicts at once.
+    pub(crate) fn set_policy(&self, policy: &BufferPolicy) -> Result<(), SshError> {
+        policy.validate()?;
+        if policy
+            .broadcast_chunks
+            .is_some_and(|n| n as usize != self.broadcast_chunks)
+        {
+            return Err(SshError::InvalidArgument(
+                "broadcast_chunks can only be set when the session starts".to_string(),
+            ));
+        }
+        if let Some(n) = policy.max_chunk_bytes {
+            self.max_chunk_bytes.store(n as usize, Ordering::Relaxed);
+        }
+        if let Some(n) = policy.coalesce_ms {
+            self.coalesce_ms.store(n as u64, Ordering::Relaxed);
+        }
+        if let Some(n) = policy.ring_bytes {
+            self.ring_bytes_capacity
+                .store(saturating_usize(n), Ordering::Relaxed);
+            self.evict_if_needed();
+        }
+        Ok(())
+    }
+
+    fn evict_if_needed(&self) {
+        let cap = self.ring_bytes_capacity.load(Ordering::Relaxed);
+        let mut used = self.used_bytes.lock().unwrap_or_else(|p| p.into_inner());
+        let mut ring = self.ring.lock().unwrap_or_else(|p| p.into_inner());
+        while *used > cap {
+            if !self.pop_front(&mut ring, &mut used) {
+                break;
+            }
+        }
+    }
+
+    fn evict_front(&self) {
+        let mut used = self.used_bytes.lock().unwrap_or_else(|p| p.into_inner());
+        let mut ring = self.ring.lock().unwrap_or_else(|p| p.into_inner());
+        self.pop_front(&mut ring, &mut used);
+    }
+
+    // Lock used_bytes before ring, as append does.
+    fn pop_front(&self, ring: &mut VecDeque<Arc<Chunk>>, used: &mut usize) -> bool {
+        let Some(front) = ring.pop_front() else {
+            return false;
+        };
+        *used -= front.bytes.len();
+        TOTAL_USED_BYTES.fetch_sub(front.bytes.len(), Ordering::Relaxed);
+        self.dropped_bytes_total
+            .fetch_add(front.bytes.len() as u64, Ordering::Relaxed);
+        self.head_seq
+            .store(front.seq.saturating_add(1), Ordering::Relaxed);
+        true
+    }
+
+    fn oldest_stored_at(&self) -> Option<Instant> {
+        let ring = self.ring.lock().unwrap_or_else(|p| p.into_inner());
+        ring.front().map(|c| c.stored_at)
+    }
+
+    /// Split `data` into chunks stamped `t_ms`, store them and notify
+    /// listeners and attachments.
+    pub(crate) fn append(&self, data: &[u8], stream: StreamKind, t_ms: f64) {
+        let mut offset = 0usize;
+        while offset < data.len() {
+            let max_chunk = self.max_chunk_bytes.load(Ordering::Relaxed);
+            let end = (offset + max_chunk).min(data.len());
+            let slice = &data[offset..end];
+            let seq = self.next_seq.fetch_add(1, Ordering::Relaxed);
+            let end_bytes = self
+                .appended_bytes
+                .fetch_add(slice.len() as u64, Ordering::Relaxed)
+                + slice.len() as u64;
+            let line = self
+                .appended_lines
+                .fetch_add(count_newlines(slice), Ordering::Relaxed);
+            let starts_line = self
+                .at_line_start
+                .swap(slice.last() == Some(&b'\n'), Ordering::Relaxed);
+            let commands = self
+                .command_tracker
+                .lock()
+                .unwrap_or_else(|p| p.into_inner())
+                .feed(seq, t_ms, slice);
+            let chunk = Arc::new(Chunk {
+                seq,
+                t_ms,
+                stream,
+                bytes: Bytes::copy_from_slice(slice),
+                end: end_bytes,
+                line,
+                starts_line,
+                commands,
+                stored_at: Instant::now(),
+            });
+            // push to ring
+            {
+                let mut used = self.used_bytes.lock().unwrap_or_else(|p| p.into_inner());
+                let mut q = match self.ring.lock() {
+                    Ok(g) => g,
+                    Err(p) => p.into_inner(),
+                };
+                q.push_back(chunk.clone());
+                *used += slice.len();
+                TOTAL_USED_BYTES.fetch_add(slice.len(), Ordering::Relaxed);
+                self.tail_seq.store(seq, Ordering::Relaxed);
+            }
+            self.evict_if_needed();
+            enforce_memory_budget();
+            // emulate
+            if let Some(emu) = self
+                .emulator
+                .lock()
+                .unwrap_or_else(|p| p.into_inner())
+                .as_ref()
+            {
+                emu.feed_chunk(seq, slice);
+            }
+            // record
+            if let Some(rec) = self
+                .recorder
+                .lock()
+                .unwrap_or_else(|p| p.into_inner())
+                .as_ref()
+            {
+                rec.send(CastEvent::Output {
+                    t_ms,
+                    bytes: chunk.bytes.clone(),
+                });
+            }
+            // broadcast
+            let _ = self.sender.send(chunk);
+
+            offset = end;
+        }
+    }
+
+    /// Drop everything buffered (readers from before get a Dropped range) and
+    /// forget commands, before output is replayed from the start.
+    pub(crate) fn clear(&self) {
+        *self
+            .command_tracker
+            .lock()
+            .unwrap_or_else(|p| p.into_inner()) = CommandTracker::new(DEFAULT_MAX_COMMAND_RECORDS);
+        let mut used = self.used_bytes.lock().unwrap_or_else(|p| p.into_inner());
+        let mut q = self.ring.lock().unwrap_or_else(|p| p.into_inner());
+        self.dropped_bytes_total
+            .fetch_add(*used as u64, Ordering::Relaxed);
+        TOTAL_USED_BYTES.fetch_sub(*used, Ordering::Relaxed);
+        *used = 0;
+        q.clear();
+        self.head_seq
+            .store(self.next_seq.load(Ordering::Relaxed), Ordering::Relaxed);
+    }
+
+    /// Buffer statistics snapshot.
+    pub(crate) fn buffer_stats(&self) -> BufferStats {
+        let used = *self.used_bytes.lock().unwrap_or_else(|p| p.into_inner()) as u64;
+        let tail_line = self.appended_lines.load(Ordering::Relaxed);
+        let (chunks_count, head_line) = {
+            let q = self.ring.lock().unwrap_or_else(|p| p.into_inner());
+            (q.len() as u64, q.front().map_or(tail_line, |c| c.line))
+        };
+        BufferStats {
+            ring_bytes_count: self.ring_bytes_capacity.load(Ordering::Relaxed) as u64,
+            used_bytes: used,
+            chunks_count,
+            head_seq: self.head_seq.load(Ordering::Relaxed),
+            tail_seq: self.tail_seq.load(Ordering::Relaxed),
+            dropped_bytes_total: self.dropped_bytes_total.load(Ordering::Relaxed),
+            head_line,
+            tail_line,
+            channel_paused: self.channel_paused.load(Ordering::Relaxed),
+            channel_paused_ms_total: *self
+                .channel_paused_ms_total
+                .lock()
+                .unwrap_or_else(|p| p.into_inner()),
+        }
+    }
+
+    /// Current next sequence number.
+    pub(crate) fn current_seq(&self) -> u64 {
+        self.tail_seq.load(Ordering::Relaxed).saturating_add(1)
+    }
+
+    /// Read the ring buffer from a cursor.
+    pub(crate) fn read_buffer(&self, cursor: Cursor, max_bytes: Option<u64>) -> BufferReadResult {
+        let max_total = saturating_usize(max_bytes.unwrap_or(DEFAULT_READ_BUFFER_MAX_BYTES));
+        let mut out_chunks: Vec<TerminalChunk> = Vec::new();
+        let mut dropped: Option<DroppedRange> = None;
+        let head_seq_now = self.head_seq.load(Ordering::Relaxed);
+        let tail_seq_now = self.tail_seq.load(Ordering::Relaxed);
+
+        // Line cursors may start inside the first chunk.
+        let mut first_offset = 0usize;
+
+        // Lock ring to determine start and collect arcs, then drop lock.
+        let (_start_idx_unused, _start_seq, arcs): (usize, u64, Vec<Arc<Chunk>>) = {
+            let ring = match self.ring.lock() {
+                Ok(g) => g,
+                Err(p) => p.into_inner(),
+            };
+            let (start_seq, idx) = match cursor {
+                Cursor::Head => (head_seq_now, 0usize),
+                Cursor::Seq { seq: mut s } => {
+                    if s < head_seq_now {
+                        dropped = Some(DroppedRange {
+                            from_seq: s,
+                            to_seq: head_seq_now - 1,
+                        });
+                        s = head_seq_now;
+                    }
+                    // Binary search: the ring may have moved since head_seq_now.
+                    (s, ring.partition_point(|c| c.seq < s))
+                }
+                Cursor::TimeMs { t_ms: t } => {
+                    // first chunk with t_ms >= t; times don't decrease
+                    let idx = ring.partition_point(|c| c.t_ms < t);
+                    let s = ring
+                        .get(idx)
+                        .map_or(tail_seq_now.saturating_add(1), |c| c.seq);
+                    (s, idx)
+                }
+                Cursor::Line { line } => {
+                    // The line started in evicted output, either before the
+                    // head or partway into the head's own line; which chunk
+                    // held it is gone, so report everything before the head.
+                    let lost = ring
+                        .front()
+                        .is_some_and(|c| line < c.line || (line == c.line && !c.starts_line));
+                    if lost && head_seq_now > 1 {
+                        dropped = Some(DroppedRange {
+                            from_seq: 1,
+                            to_seq: head_seq_now - 1,
+                        });
+                    }
+                    let (idx, offset) = line_start(&ring, line);
+                    first_offset = offset;
+                    let s = ring
+                        .get(idx)
+                        .map_or(tail_seq_now.saturating_add(1), |c| c.seq);
+                    (s, idx)
+                }
+                Cursor::TailLines { lines: n } => {
+                    // A trailing partial line (e.g. a prompt) counts as a line.
+                    let total = ring.back().map_or(0, |c| {
+                        let partial = c.bytes.last() != Some(&b'\n');
+                        c.line + count_newlines(&c.bytes) + partial as u64
+                    });
+                    let (idx, offset) = line_start(&ring, total.saturating_sub(n));
+                    first_offset = offset;
+                    let s = ring
+                        .get(idx)
+                        .map_or(tail_seq_now.saturating_add(1), |c| c.seq);
+                    (s, idx)
+                }
+                Cursor::TailBytes { bytes: n } => {
+                    // Walk from tail backwards until approx n bytes, then forward.
+                    let mut bytes = 0usize;
+                    let mut idx = ring.len();
+                    for i in (0..ring.len()).rev() {
+                        let b = ring[i].bytes.len();
+                        if bytes >= saturating_usize(n) {
+                            idx = i + 1;
+                            break;
+                        }
+                        bytes += b;
+                        idx = i;
+                    }
+                    let s = if idx < ring.len() {
+                        ring[idx].seq
+                    } else {
+                        tail_seq_now.saturating_add(1)
+                    };
+                    (s, idx)
+                }
+                Cursor::Live => (tail_seq_now.saturating_add(1), ring.len()),
+            };
+            let arcs: Vec<Arc<Chunk>> = ring.iter().skip(idx).cloned().collect();
+            (idx, start_seq, arcs)
+        };
+
+        // Build output respecting max_bytes
+        let mut total = 0usize;
+        for ch in arcs {
+            let bytes = &ch.bytes[std::mem::take(&mut first_offset)..];
+            let len = bytes.len();
+            if total + len > max_total {
+                break;
+            }
+            out_chunks.push(TerminalChunk {
+                seq: ch.seq,
+                t_ms: ch.t_ms,
+                stream: ch.stream,
+                bytes: bytes.to_vec(),
+            });
+            total += len;
+        }
+        let next_seq = if let Some(last) = out_chunks.last() {
+            last.seq + 1
+        } else {
+            tail_seq_now.saturating_add(1)
+        };
+        BufferReadResult {
+            chunks: out_chunks,
+            next_seq,
+            dropped,
+        }
+    }
+
+    pub(crate) fn read_text(&self, cursor: Cursor, max_bytes: Option<u64>) -> TextReadResult {
+        let read = self.read_buffer(cursor, max_bytes);
+        let bytes: Vec<u8> = read
+            .chunks
+            .iter()
+            .flat_map(|c| c.bytes.iter().copied())
+            .collect();
+        TextReadResult {
+            text: render_text(&bytes),
+            from_seq: read.chunks.first().map(|c| c.seq),
+            to_seq: read.chunks.last().map(|c| c.seq),
+            next_seq: read.next_seq,
+            dropped: read.dropped,
+        }
+    }
+
+    /// See `ShellSession::search`. Runs on a blocking thread: it scans the
+    /// whole ring.
+    pub(crate) async fn search(
+        self: &Arc<Self>,
+        pattern: String,
+        opts: SearchOptions,
+    ) -> Result<SearchResult, SshError> {
+        let buffer = self.clone();
+        self.rt_handle
+            .spawn_blocking(move || buffer.search_blocking(pattern, opts))
+            .await
+            .map_err(|e| SshError::Io(e.to_string()))?
+    }
+
+    fn search_blocking(
+        &self,
+        pattern: String,
+        opts: SearchOptions,
+    ) -> Result<SearchResult, SshError> {
+        let pattern = if opts.literal.unwrap_or(false) {
+            regex::escape(&pattern)
+        } else {
+            pattern
+        };
+        let re = regex::RegexBuilder::new(&pattern)
+            .case_insensitive(opts.case_insensitive.unwrap_or(false))
+            .multi_line(true)
+            .build()
+            .map_err(|e| SshError::InvalidPattern(e.to_string()))?;
+        let max = opts.max_matches.unwrap_or(DEFAULT_SEARCH_MAX_MATCHES) as usize;
+        let newest_first = opts.newest_first.unwrap_or(false);
+
+        let arcs: Vec<Arc<Chunk>> = {
+            let ring = self.ring.lock().unwrap_or_else(|p| p.into_inner());
+            ring.iter().cloned().collect()
+        };
+        // Chunks as one stream: (offset of first byte, chunk).
+        let mut starts: Vec<(usize, &Chunk)> = Vec::with_capacity(arcs.len());
+        let mut stripper = Stripper::new();
+        let mut total = 0usize;
+        for chunk in &arcs {
+            starts.push((total, chunk));
+            stripper.feed(&chunk.bytes);
+            total += chunk.bytes.len();
+        }
+        let stripped = stripper.finish();
+        let locate = |at: usize| {
+            let i = starts.partition_point(|(start, _)| *start <= at) - 1;
+            let (start, chunk) = starts[i];
+            (chunk.seq, (at - start) as u64)
+        };
+
+        let mut matches = std::collections::VecDeque::new();
+        let mut truncated = false;
+        for m in re.find_iter(&stripped.text).filter(|m| !m.is_empty()) {
+            if matches.len() == max {
+                truncated = true;
+                if !newest_first || max == 0 {
+                    break;
+                }
+                matches.pop_front();
+            }
+            let (seq, offset) = locate(stripped.input_offset(m.start()));
+            let (end_seq, last) = locate(stripped.input_offset(m.end() - 1));
+            matches.push_back(SearchMatch {
+                seq,
+                offset,
+                end_seq,
+                end_offset: last + 1,
+                text: m.as_str().to_string(),
+            });
+        }
+        let mut matches: Vec<SearchMatch> = matches.into();
+        if newest_first {
+            matches.reverse();
+        }
+        Ok(SearchResult { matches, truncated })
+    }
+
+    /// Add a listener with optional replay and live follow.
+    pub(crate) fn add_listener(
+        self: &Arc<Self>,
+        listener: Arc<dyn ShellListener>,
+        opts: ListenerOptions,
+    ) -> Result<u64, SshError> {
+        // Snapshot for replay; emit from task to avoid re-entrant callbacks during FFI.
+        let replay = self.read_buffer(opts.cursor.clone(), None);
+        let replay_commands = self.replay_commands(&replay);
+        let mut rx = self.sender.subscribe();
+        let id = self.next_listener_id.fetch_add(1, Ordering::Relaxed);
+        let default_coalesce_ms = self.coalesce_ms.load(Ordering::Relaxed) as u32;
+        let coalesce_ms = opts.coalesce_ms.unwrap_or(default_coalesce_ms);
+        let listener: Arc<dyn ShellListener> = match opts.text {
+            Some(invalid) => Arc::new(TextListener::new(listener, invalid)),
+            None => listener,
+        };
+        if opts.lossless.unwrap_or(false) {
+            let max_lag = opts.max_lag_bytes.unwrap_or(DEFAULT_LOSSLESS_MAX_LAG_BYTES);
+            let handle = self.spawn_lossless_listener(
+                id,
+                listener,
+                replay,
+                replay_commands,
+                max_lag,
+                coalesce_ms,
+            );
+            if let Ok(mut map) = self.listener_tasks.lock() {
+                map.insert(id, handle);
+            }
+            return Ok(id);
+        }
+
+        let rt = self.rt_handle.clone();
+        let handle = rt.spawn(async move {
+            // Emit replay first
+            let mut last_seq_seen: u64 = replay.next_seq.saturating_sub(1);
+            emit_replay(listener.as_ref(), replay, replay_commands);
+
+            let mut acc: Vec<u8> = Vec::new();
+            let mut acc_stream: Option<StreamKind>;
+            let mut acc_last_seq: u64;
+            let mut acc_last_t: f64;
+            let window = Duration::from_millis(coalesce_ms as u64);
+            let mut pending_drop_from: Option<u64> = None;
+
+            loop {
+                // First receive an item
+                let first = match rx.recv().await {
+                    Ok(c) => c,
+                    Err(broadcast::error::RecvError::Lagged(_n)) => { pending_drop_from = Some(last_seq_seen.saturating_add(1)); continue; }
+                    Err(broadcast::error::RecvError::Closed) => break,
+                };
+                if let Some(from) = pending_drop_from.take() {
+                    if from <= first.seq.saturating_sub(1) {
+                        listener.on_event(ShellEvent::Dropped { from_seq: from, to_seq: first.seq - 1 });
+                    }
+                }
+                // Start accumulating
+                acc.clear(); acc_stream = Some(first.stream); acc_last_seq = first.seq; acc_last_t = first.t_ms; acc.extend_from_slice(&first.bytes);
+                last_seq_seen = first.seq;
+                // Command marks end the batch so events follow their output.
+                let mut acc_commands: Vec<CommandRecord> = first.commands.clone();
+
+                // Drain within window while same stream
+                let mut deadline = tokio::time::Instant::now() + window;
+                while acc_commands.is_empty() {
+                    let timeout = tokio::time::sleep_until(deadline);
+                    tokio::pin!(timeout);
+                    tokio::select! {
+                        _ = &mut timeout => break,
+                        msg = rx.recv() => {
+                            match msg {
+                                Ok(c) => {
+                                    if Some(c.stream) == acc_stream { acc.extend_from_slice(&c.bytes); acc_last_seq = c.seq; acc_last_t = c.t_ms; last_seq_seen = c.seq; acc_commands.extend(c.commands.iter().cloned()); }
+                                    else { // flush and start new
+                                        let chunk = TerminalChunk { seq: acc_last_seq, t_ms: acc_last_t, stream: acc_stream.unwrap_or(StreamKind::Stdout), bytes: std::mem::take(&mut acc) };
+                                        listener.on_event(ShellEvent::Chunk(chunk));
+                                        acc_stream = Some(c.stream); acc_last_seq = c.seq; acc_last_t = c.t_ms; acc.extend_from_slice(&c.bytes); last_seq_seen = c.seq;
+                                        acc_commands.extend(c.commands.iter().cloned());
+                                        deadline = tokio::time::Instant::now() + window;
+                                    }
+                                }
+                                Err(broadcast::error::RecvError::Lagged(_n)) => { pending_drop_from = Some(last_seq_seen.saturating_add(1)); break; }
+                                Err(broadcast::error::RecvError::Closed) => { break; }
+                            }
+                        }
+                    }
+                }
+                if let Some(s) = acc_stream.take() {
+                    let chunk = TerminalChunk { seq: acc_last_seq, t_ms: acc_last_t, stream: s, bytes: std::mem::take(&mut acc) };
+                    listener.on_event(ShellEvent::Chunk(chunk));
+                }
+                for record in acc_commands {
+                    listener.on_event(ShellEvent::Command(record));
+                }
+            }
+        });
+        if let Ok(mut map) = self.listener_tasks.lock() {
+            map.insert(id, handle);
+        }
+        Ok(id)
+    }
+
+    pub(crate) fn remove_listener(&self, id: u64) {
+        if let Ok(mut map) = self.listener_tasks.lock() {
+            if let Some(h) = map.remove(&id) {
+                h.abort();
+            }
+        }
+        self.forget_lossless(id);
+    }
+
+    // Delivers from the ring instead of the broadcast, so it never lags; the
+    // broadcast only wakes it. It holds a Weak ref so it ends with the buffer.
+    fn spawn_lossless_listener(
+        self: &Arc<Self>,
+        id: u64,
+        listener: Arc<dyn ShellListener>,
+        replay: BufferReadResult,
+        replay_commands: Vec<(u64, Vec<CommandRecord>)>,
+        max_lag: u64,
+        coalesce_ms: u32,
+    ) -> tokio::task::JoinHandle<()> {
+        let mut rx = self.sender.subscribe();
+        // Output from before the listener was added doesn't count as lag.
+        self.lossless
+            .lock()
+            .unwrap_or_else(|p| p.into_inner())
+            .insert(
+                id,
+                LosslessProgress {
+                    delivered: self.appended_bytes.load(Ordering::Relaxed),
+                    max_lag,
+                },
+            );
+        let weak = Arc::downgrade(self);
+        self.rt_handle.spawn(async move {
+            let mut next_seq = replay.next_seq;
+            emit_replay(listener.as_ref(), replay, replay_commands);
+            let window = Duration::from_millis(coalesce_ms as u64);
+            loop {
+                let Some(buffer) = weak.upgrade() else {
+                    return;
+                };
+                let (dropped, chunks) =
+                    buffer.chunks_from(next_seq, DEFAULT_READ_BUFFER_MAX_BYTES as usize);
+                let Some(last) = chunks.last().cloned() else {
+                    drop(buffer);
+                    // Lagged only means there is more to read.
+                    if let Err(broadcast::error::RecvError::Closed) = rx.recv().await {
+                        return;
+                    }
+                    tokio::time::sleep(window).await;
+                    continue;
+                };
+                if let Some(dr) = dropped {
+                    listener.on_event(ShellEvent::Dropped {
+                        from_seq: dr.from_seq,
+                        to_seq: dr.to_seq,
+                    });
+                }
+                // Merge runs of the same stream, then report commands.
+                let mut i = 0;
+                while i < chunks.len() {
+                    let run = chunks[i..]
+                        .iter()
+                        .take_while(|c| c.stream == chunks[i].stream)
+                        .count();
+                    let group = &chunks[i..i + run];
+                    let tail = &group[run - 1];
+                    listener.on_event(ShellEvent::Chunk(TerminalChunk {
+                        seq: tail.seq,
+                        t_ms: tail.t_ms,
+                        stream: tail.stream,
+                        bytes: group.iter().flat_map(|c| c.bytes.iter().copied()).collect(),
+                    }));
+                    i += run;
+                }
+                for record in chunks.iter().flat_map(|c| c.commands.iter()) {
+                    listener.on_event(ShellEvent::Command(record.clone()));
+                }
+                next_seq = last.seq + 1;
+                buffer.lossless_delivered(id, last.end);
+            }
+        })
+    }
+
+    // Command updates from the replayed chunks, by chunk seq, so a new
+    // listener sees the marks a live one saw for the same output.
+    fn replay_commands(&self, replay: &BufferReadResult) -> Vec<(u64, Vec<CommandRecord>)> {
+        let (Some(first), Some(last)) = (replay.chunks.first(), replay.chunks.last()) else {
+            return Vec::new();
+        };
+        let (first, last) = (first.seq, last.seq);
+        let ring = self.ring.lock().unwrap_or_else(|p| p.into_inner());
+        ring.iter()
+            .filter(|c| (first..=last).contains(&c.seq) && !c.commands.is_empty())
+            .map(|c| (c.seq, c.commands.clone()))
+            .collect()
+    }
+
+    // Chunks from `seq` on, up to about `max_bytes` (at least one).
+    fn chunks_from(&self, seq: u64, max_bytes: usize) -> (Option<DroppedRange>, Vec<Arc<Chunk>>) {
+        let ring = self.ring.lock().unwrap_or_else(|p| p.into_inner());
+        let head_seq = self.head_seq.load(Ordering::Relaxed);
+        let dropped = (seq < head_seq).then(|| DroppedRange {
+            from_seq: seq,
+            to_seq: head_seq - 1,
+        });
+        let mut total = 0usize;
+        let chunks = ring
+            .iter()
+            .skip(seq.saturating_sub(head_seq) as usize)
+            .take_while(|c| {
+                let take = total == 0 || total + c.bytes.len() <= max_bytes;
+                total += c.bytes.len();
+                take
+            })
+            .cloned()
+            .collect();
+        (dropped, chunks)
+    }
+
+    fn lossless_delivered(&self, id: u64, end: u64) {
+        if let Some(progress) = self
+            .lossless
+            .lock()
+            .unwrap_or_else(|p| p.into_inner())
+            .get_mut(&id)
+        {
+            progress.delivered = progress.delivered.max(end);
+        }
+        self.progress.notify_waiters();
+    }
+
+    fn forget_lossless(&self, id: u64) {
+        let removed = self
+            .lossless
+            .lock()
+            .unwrap_or_else(|p| p.into_inner())
+            .remove(&id);
+        if removed.is_some() {
+            self.progress.notify_waiters();
+        }
+    }
+
+    fn lossless_behind(&self) -> bool {
+        let appended = self.appended_bytes.load(Ordering::Relaxed);
+        let lossless = self.lossless.lock().unwrap_or_else(|p| p.into_inner());
+        lossless
+            .values()
+            .any(|l| appended.saturating_sub(l.delivered) > l.max_lag)
+    }
+
+    /// Append output from the channel, or hold it in `intake` while paused.
+    /// The channel pauses once a lossless listener is too far behind.
+    /// Returns whether it is paused; the shell reader holds the channel's
+    /// window while it is, so the server stops sending.
+    pub(crate) fn take_in(
+        &self,
+        intake: &mut ChannelIntake,
+        data: &[u8],
+        stream: StreamKind,
+        t_ms: f64,
+        on_pause: Option<&dyn ChannelPauseCallback>,
+    ) -> bool {
+        if intake.paused_since.is_some() {
+            intake.held.push_back((data.to_vec(), stream, t_ms));
+            return true;
+        }
+        self.append(data, stream, t_ms);
+        if self.lossless_behind() {
+            intake.paused_since = Some(now_ms());
+            self.channel_paused.store(true, Ordering::Relaxed);
+            if let Some(cb) = on_pause {
+                cb.on_change(true);
+            }
+        }
+        intake.paused_since.is_some()
+    }
+
+    /// Wait until no lossless listener is too far behind.
+    pub(crate) async fn lossless_caught_up(&self) {
+        loop {
+            let progressed = self.progress.notified();
+            tokio::pin!(progressed);
+            progressed.as_mut().enable();
+            if !self.lossless_behind() {
+                return;
+            }
+            progressed.await;
+        }
+    }
+
+    /// Append held output while the lossless listeners keep up; the pause
+    /// ends once all of it is in. Returns whether the channel is still
+    /// paused.
+    pub(crate) fn resume(
+        &self,
+        intake: &mut ChannelIntake,
+        on_pause: Option<&dyn ChannelPauseCallback>,
+    ) -> bool {
+        while !self.lossless_behind() {
+            let Some((data, stream, t_ms)) = intake.held.pop_front() else {
+                self.end_pause(intake, on_pause);
+                return false;
+            };
+            self.append(&data, stream, t_ms);
+        }
+        true
+    }
+
+    /// Append all held output and end the pause, when the channel closes.
+    pub(crate) fn flush_held(
+        &self,
+        intake: &mut ChannelIntake,
+        on_pause: Option<&dyn ChannelPauseCallback>,
+    ) {
+        for (data, stream, t_ms) in std::mem::take(&mut intake.held) {
+            self.append(&data, stream, t_ms);
+        }
+        self.end_pause(intake, on_pause);
+    }
+
+    fn end_pause(&self, intake: &mut ChannelIntake, on_pause: Option<&dyn ChannelPauseCallback>) {
+        let Some(since) = intake.paused_since.take() else {
+            return;
+        };
+        *self
+            .channel_paused_ms_total
+            .lock()
+            .unwrap_or_else(|p| p.into_inner()) += now_ms() - since;
+        self.channel_paused.store(false, Ordering::Relaxed);
+        if let Some(cb) = on_pause {
+            cb.on_change(false);
+        }
+    }
+
+    pub(crate) fn attach_emulator(
+        &self,
+        cols: u32,
+        rows: u32,
+        scrollback_lines: Option<u32>,
+    ) -> Arc<TerminalEmulator> {
+        let mut slot = self.emulator.lock().unwrap_or_else(|p| p.into_inner());
+        if let Some(emulator) = slot.as_ref() {
+            return emulator.clone();
+        }
+        let emulator = TerminalEmulator::new(cols, rows, scrollback_lines);
+        emulator.replay(
+            || *slot = Some(emulator.clone()),
+            || {
+                let ring = self.ring.lock().unwrap_or_else(|p| p.into_inner());
+                ring.iter().map(|c| (c.seq, c.bytes.to_vec())).collect()
+            },
+        );
+        emulator
+    }
+
+    pub(crate) fn emulator(&self) -> Option<Arc<TerminalEmulator>> {
+        self.emulator
+            .lock()
+            .unwrap_or_else(|p| p.into_inner())
+            .clone()
+    }
+
+    pub(crate) fn detach_emulator(&self) {
+        self.emulator
+            .lock()
+            .unwrap_or_else(|p| p.into_inner())
+            .take();
+    }
+
+    pub(crate) fn start_recording(
+        &self,
+        path: String,
+        header: CastHeader,
+        record_input: bool,
+    ) -> Result<(), SshError> {
+        let mut slot = self.recorder.lock().unwrap_or_else(|p| p.into_inner());
+        if slot.is_some() {
+            return Err(SshError::RecordingAlreadyRunning);
+        }
+        let recorder = Recorder::start(&self.rt_handle, path, header, record_input)
+            .map_err(|e| SshError::Io(e.to_string()))?;
+        *slot = Some(recorder);
+        Ok(())
+    }
+
+    pub(crate) async fn stop_recording(&self) -> Result<Option<RecordingStats>, SshError> {
+        let recorder = self
+            .recorder
+            .lock()
+            .unwrap_or_else(|p| p.into_inner())
+            .take();
+        let Some(recorder) = recorder else {
+            return Ok(None);
+        };
+        match recorder.finish().await {
+            Ok(stats) => Ok(Some(stats)),
+            Err(e) => Err(SshError::Io(e.to_string())),
+        }
+    }
+
+    /// Pass a non-output event (input, resize) to the running recording.
+    pub(crate) fn record(&self, event: CastEvent) {
+        if let Some(recorder) = self
+            .recorder
+            .lock()
+            .unwrap_or_else(|p| p.into_inner())
+            .as_ref()
+        {
+            if recorder.record_input || !matches!(event, CastEvent::Input { .. }) {
+                recorder.send(event);
+            }
+        }
+    }
+
+    /// Drop the recorder without waiting; its file is finished in the background.
+    pub(crate) fn abandon_recording(&self) {
+        self.recorder
+            .lock()
+            .unwrap_or_else(|p| p.into_inner())
+            .take();
+    }
+
+    pub(crate) fn commands(&self) -> Vec<CommandRecord> {
+        self.command_tracker
+            .lock()
+            .unwrap_or_else(|p| p.into_inner())
+            .records()
+    }
+}
+
+// Replay for a new listener: the dropped range, then each chunk followed by
+// the command updates its marks made.
+fn emit_replay(
+    listener: &dyn ShellListener,
+    replay: BufferReadResult,
+    commands: Vec<(u64, Vec<CommandRecord>)>,
+) {
+    if let Some(dr) = replay.dropped.as_ref() {
+        listener.on_event(ShellEvent::Dropped {
+            from_seq: dr.from_seq,
+            to_seq: dr.to_seq,
+        });
+    }
+    let mut commands = commands.into_iter().peekable();
+    for ch in replay.chunks.into_iter() {
+        let seq = ch.seq;
+        listener.on_event(ShellEvent::Chunk(ch));
+        while let Some((_, records)) = commands.next_if(|(s, _)| *s <= seq) {
+            for record in records {
+                listener.on_event(ShellEvent::Command(record));
+            }
+        }
+    }
+}
+
+impl Drop for OutputBuffer {
+    fn drop(&mut self) {
+        let used = *self.used_bytes.get_mut().unwrap_or_else(|p| p.into_inner());
+        TOTAL_USED_BYTES.fetch_sub(used, Ordering::Relaxed);
+    }
+}
+
+// Every buffer counts toward TOTAL_USED_BYTES and is subject to
+// MEMORY_BUDGET, so tests that create buffers hold this for reading and tests
+// that set a budget hold it for writing.
+#[cfg(test)]
+pub(crate) static MEMORY_TESTS: tokio::sync::RwLock<()> = tokio::sync::RwLock::const_new(());
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Lifts the budget even if the test fails.
+    struct ResetBudget;
+
+    impl Drop for ResetBudget {
+        fn drop(&mut self) {
+            set_memory_budget(None).unwrap();
+        }
+    }
+
+    #[derive(Default)]
+    struct Events(Mutex<Vec<ShellEvent>>);
+
+    impl ShellListener for Events {
+        fn on_event(&self, ev: ShellEvent) {
+            self.0.lock().unwrap().push(ev);
+        }
+    }
+
+    fn listener_options(cursor: Cursor) -> ListenerOptions {
+        ListenerOptions {
+            cursor,
+            coalesce_ms: Some(0),
+            lossless: None,
+            max_lag_bytes: None,
+            text: None,
+        }
+    }
+
+    #[derive(Default)]
+    struct Pauses(Mutex<Vec<bool>>);
+
+    impl ChannelPauseCallback for Pauses {
+        fn on_change(&self, paused: bool) {
+            self.0.lock().unwrap().push(paused);
+        }
+    }
+
+    fn chunk_bytes(events: &Events) -> Vec<u8> {
+        let events = events.0.lock().unwrap();
+        assert!(
+            !events
+                .iter()
+                .any(|ev| matches!(ev, ShellEvent::Dropped { .. })),
+            "{events:?}"
+        );
+        events
+            .iter()
+            .filter_map(|ev| match ev {
+                ShellEvent::Chunk(c) => Some(c.bytes.clone()),
+                _ => None,
+            })
+            .flatten()
+            .collect()
+    }
+
+    // Runs the shell reader's side of a pause: wait, then append what was held.
+    async fn take_in_and_wait(
+        buffer: &OutputBuffer,
+        intake: &mut ChannelIntake,
+        data: &[u8],
+        t_ms: f64,
+        pauses: &Pauses,
+    ) {
+        let mut paused = buffer.take_in(intake, data, StreamKind::Stdout, t_ms, Some(pauses));
+        while paused {
+            buffer.lossless_caught_up().await;
+            paused = buffer.resume(intake, Some(pauses));
+        }
+    }
+
+    #[tokio::test]
+    async fn lossless_listener_pauses_the_channel_until_it_catches_up() {
+        let _shared = MEMORY_TESTS.read().await;
+        // A one-chunk broadcast, so only the ring keeps this listener whole.
+        let policy = BufferPolicy {
+            max_chunk_bytes: Some(4),
+            broadcast_chunks: Some(1),
+            ..Default::default()
+        };
+        let buffer = OutputBuffer::new(tokio::runtime::Handle::current(), &policy);
+        let events = Arc::new(Events::default());
+        buffer
+            .add_listener(
+                events.clone(),
+                ListenerOptions {
+                    lossless: Some(true),
+                    max_lag_bytes: Some(8),
+                    ..listener_options(Cursor::Live)
+                },
+            )
+            .unwrap();
+        let pauses = Pauses::default();
+        let mut intake = ChannelIntake::default();
+        let mut sent = Vec::new();
+        for i in 0..10 {
+            let line = format!("line {i}\n");
+            sent.extend_from_slice(line.as_bytes());
+            take_in_and_wait(&buffer, &mut intake, line.as_bytes(), i as f64, &pauses).await;
+            assert!(sent.len() - chunk_bytes(&events).len() <= 8);
+        }
+        // Every pause ends once the listener has caught up.
+        let pauses = pauses.0.into_inner().unwrap();
+        assert!(!pauses.is_empty());
+        assert_eq!(pauses.len() % 2, 0, "{pauses:?}");
+        assert!(pauses.chunks(2).all(|p| p == [true, false]), "{pauses:?}");
+        let stats = buffer.buffer_stats();
+        assert!(!stats.channel_paused);
+        assert!(stats.channel_paused_ms_total >= 0.0);
+
+        while chunk_bytes(&events).len() < sent.len() {
+            tokio::task::yield_now().await;
+        }
+        assert_eq!(chunk_bytes(&events), sent);
+    }
+
+    #[tokio::test]
+    async fn paused_channel_holds_output_until_the_listener_catches_up() {
+        let _shared = MEMORY_TESTS.read().await;
+        let buffer = OutputBuffer::new(tokio::runtime::Handle::current(), &BufferPolicy::default());
+        let events = Arc::new(Events::default());
+        buffer
+            .add_listener(
+                events.clone(),
+                ListenerOptions {
+                    lossless: Some(true),
+                    max_lag_bytes: Some(1),
+                    ..listener_options(Cursor::Live)
+                },
+            )
+            .unwrap();
+        let pauses = Pauses::default();
+        let mut intake = ChannelIntake::default();
+        // The listener task has not run yet, so the first line pauses the
+        // channel and the second is held out of the ring.
+        assert!(buffer.take_in(&mut intake, b"a\n", StreamKind::Stdout, 1.0, Some(&pauses)));
+        assert!(buffer.take_in(&mut intake, b"b\n", StreamKind::Stderr, 2.0, Some(&pauses)));
+        assert!(buffer.buffer_stats().channel_paused);
+        assert_eq!(buffer.current_seq(), 2);
+
+        // Appending "b\n" puts the listener behind again, so it takes two.
+        buffer.lossless_caught_up().await;
+        assert!(buffer.resume(&mut intake, Some(&pauses)));
+        buffer.lossless_caught_up().await;
+        assert!(!buffer.resume(&mut intake, Some(&pauses)));
+        while chunk_bytes(&events).len() < 4 {
+            tokio::task::yield_now().await;
+        }
+        assert_eq!(chunk_bytes(&events), b"a\nb\n");
+        assert_eq!(pauses.0.into_inner().unwrap(), [true, false]);
+        assert!(!buffer.buffer_stats().channel_paused);
+    }
+
+    #[tokio::test]
+    async fn removing_a_lagging_lossless_listener_ends_the_pause() {
+        let _shared = MEMORY_TESTS.read().await;
+        let buffer = OutputBuffer::new(tokio::runtime::Handle::current(), &BufferPolicy::default());
+        let id = buffer
+            .add_listener(
+                Arc::new(Events::default()),
+                ListenerOptions {
+                    lossless: Some(true),
+                    max_lag_bytes: Some(1),
+                    ..listener_options(Cursor::Live)
+                },
+            )
+            .unwrap();
+        let pauses = Pauses::default();
+        let mut intake = ChannelIntake::default();
+        assert!(buffer.take_in(
+            &mut intake,
+            b"lagging",
+            StreamKind::Stdout,
+            1.0,
+            Some(&pauses)
+        ));
+        buffer.remove_listener(id);
+        buffer.lossless_caught_up().await;
+        assert!(!buffer.resume(&mut intake, Some(&pauses)));
+        assert_eq!(pauses.0.into_inner().unwrap(), [true, false]);
+    }
+
+    #[tokio::test]
+    async fn replay_reports_commands_after_their_output() {
+        let _shared = MEMORY_TESTS.read().await;
+        let buffer = OutputBuffer::new(tokio::runtime::Handle::current(), &BufferPolicy::default());
+        buffer.append(
+            b"\x1b]133;A\x07$ \x1b]133;B\x07ls\r\n",
+            StreamKind::Stdout,
+            1.0,
+        );
+        buffer.append(
+            b"\x1b]133;C\x07a b\r\n\x1b]133;D;0\x07",
+            StreamKind::Stdout,
+            2.0,
+        );
+
+        for lossless in [false, true] {
+            let events = Arc::new(Events::default());
+            let id = buffer
+                .add_listener(
+                    events.clone(),
+                    ListenerOptions {
+                        lossless: Some(lossless),
+                        ..listener_options(Cursor::Head)
+                    },
+                )
+                .unwrap();
+            tokio::task::yield_now().await;
+            buffer.remove_listener(id);
+            let kinds: Vec<String> = events
+                .0
+                .lock()
+                .unwrap()
+                .iter()
+                .map(|ev| match ev {
+                    ShellEvent::Chunk(c) => format!("chunk {}", c.seq),
+                    ShellEvent::Command(r) => format!("command {:?}", r.command),
+                    _ => "other".to_string(),
+                })
+                .collect();
+            assert_eq!(
+                kinds,
+                [
+                    "chunk 1",
+                    "command None",
+                    "command None",
+                    "chunk 2",
+                    "command Some(\"ls\")",
+                    "command Some(\"ls\")",
+                ],
+                "lossless: {lossless}"
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn search_breaks_lines_at_lone_carriage_returns() {
+        let _shared = MEMORY_TESTS.read().await;
+        let buffer = OutputBuffer::new(tokio::runtime::Handle::current(), &BufferPolicy::default());
+        buffer.append(b"one\rtwo\r\nthree two", StreamKind::Stdout, 1.0);
+        let search = |pattern: &str, max_matches, newest_first| {
+            buffer.search(
+                pattern.to_string(),
+                SearchOptions {
+                    case_insensitive: None,
+                    literal: None,
+                    max_matches,
+                    newest_first: Some(newest_first),
+                },
+            )
+        };
+
+        assert!(search("onetwo", None, false)
+            .await
+            .unwrap()
+            .matches
+            .is_empty());
+        let result = search("^two$", None, false).await.unwrap();
+        assert_eq!(result.matches.len(), 1);
+        assert_eq!(
+            (result.matches[0].offset, result.matches[0].end_offset),
+            (4, 7)
+        );
+
+        let result = search("two", Some(1), true).await.unwrap();
+        assert_eq!(result.matches[0].offset, 15);
+        assert!(result.truncated);
+        let result = search("two", Some(0), true).await.unwrap();
+        assert!(result.matches.is_empty() && result.truncated);
+    }
+
+    #[test]
+    fn evicts_on_shrink_and_over_budget() {
+        let _exclusive = MEMORY_TESTS.blocking_write();
+        let rt = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap();
+        let policy = BufferPolicy {
+            max_chunk_bytes: Some(10),
+            ..Default::default()
+        };
+        let a = OutputBuffer::new(rt.handle().clone(), &policy);
+        let b = OutputBuffer::new(rt.handle().clone(), &policy);
+        a.append(&[b'a'; 40], StreamKind::Stdout, 1.0);
+        b.append(&[b'b'; 20], StreamKind::Stdout, 2.0);
+        assert_eq!(a.buffer_stats().chunks_count, 4);
+
+        a.set_policy(&BufferPolicy {
+            ring_bytes: Some(25),
+            ..Default::default()
+        })
+        .unwrap();
+        let stats = a.buffer_stats();
+        assert_eq!((stats.used_bytes, stats.head_seq), (20, 3));
+        for invalid in [
+            BufferPolicy {
+                broadcast_chunks: Some(1),
+                ..Default::default()
+            },
+            BufferPolicy {
+                max_chunk_bytes: Some(0),
+                ..Default::default()
+            },
+        ] {
+            assert!(matches!(
+                a.set_policy(&invalid),
+                Err(SshError::InvalidArgument(_))
+            ));
+        }
+
+        // Budget of 30 over a (20 bytes, older) and b (20): a's oldest go.
+        let base = memory_usage().used_bytes - 40;
+        let _reset = ResetBudget;
+        set_memory_budget(Some(base + 30)).unwrap();
+        assert_eq!(a.buffer_stats().used_bytes, 10);
+        assert_eq!(b.buffer_stats().used_bytes, 20);
+        b.append(&[b'c'; 10], StreamKind::Stdout, 3.0);
+        assert_eq!(a.buffer_stats().used_bytes, 0);
+        set_memory_budget(None).unwrap();
+        drop((a, b));
+        assert_eq!(memory_usage().used_bytes, base);
+    }
+
+    #[test]
+    fn budget_evicts_by_append_order_not_timestamp() {
+        let _exclusive = MEMORY_TESTS.blocking_write();
+        let rt = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap();
+        // A cast player stamps recording-relative times, far below a live
+        // shell's wall-clock ones, but its output here is the newest.
+        let player = OutputBuffer::new(rt.handle().clone(), &BufferPolicy::default());
+        let live = OutputBuffer::new(rt.handle().clone(), &BufferPolicy::default());
+        live.append(b"live", StreamKind::Stdout, now_ms());
+        std::thread::sleep(Duration::from_millis(1));
+        player.append(b"cast", StreamKind::Stdout, 0.5);
+
+        let base = memory_usage().used_bytes - 8;
+        let _reset = ResetBudget;
+        set_memory_budget(Some(base + 4)).unwrap();
+        assert_eq!(live.buffer_stats().used_bytes, 0);
+        assert_eq!(player.buffer_stats().used_bytes, 4);
+    }
+
+    #[test]
+    fn resolves_line_and_time_cursors() {
+        let _shared = MEMORY_TESTS.blocking_read();
+        let rt = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap();
+        let policy = BufferPolicy {
+            max_chunk_bytes: Some(4),
+            ..Default::default()
+        };
+        let buffer = OutputBuffer::new(rt.handle().clone(), &policy);
+        // Chunks: "a\nbb" "\nccc" "\n" | "dd\ne" "e$ "
+        buffer.append(b"a\nbb\nccc\n", StreamKind::Stdout, 10.0);
+        buffer.append(b"dd\nee$ ", StreamKind::Stdout, 20.0);
+        let text = |cursor| {
+            let read = buffer.read_buffer(cursor, None);
+            let bytes: Vec<u8> = read.chunks.into_iter().flat_map(|c| c.bytes).collect();
+            String::from_utf8(bytes).unwrap()
+        };
+        assert_eq!(text(Cursor::TailLines { lines: 2 }), "dd\nee$ ");
+        assert_eq!(text(Cursor::TailLines { lines: 4 }), "bb\nccc\ndd\nee$ ");
+        assert_eq!(text(Cursor::TailLines { lines: 0 }), "");
+        assert_eq!(text(Cursor::Line { line: 1 }), "bb\nccc\ndd\nee$ ");
+        assert_eq!(text(Cursor::Line { line: 3 }), "dd\nee$ ");
+        assert_eq!(text(Cursor::Line { line: 9 }), "");
+        assert_eq!(text(Cursor::TimeMs { t_ms: 15.0 }), "dd\nee$ ");
+        assert_eq!(text(Cursor::TimeMs { t_ms: 25.0 }), "");
+        assert_eq!(text(Cursor::Seq { seq: 2 }), "\nccc\ndd\nee$ ");
+        let stats = buffer.buffer_stats();
+        assert_eq!((stats.head_line, stats.tail_line), (0, 4));
+    }
+
+    #[test]
+    fn line_cursor_before_the_head_reports_dropped_output() {
+        let _shared = MEMORY_TESTS.blocking_read();
+        let rt = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap();
+        let policy = BufferPolicy {
+            max_chunk_bytes: Some(4),
+            ..Default::default()
+        };
+        let buffer = OutputBuffer::new(rt.handle().clone(), &policy);
+        // Chunks: "a\nbb" "\nccc" "\n"; keeping 5 bytes evicts the first.
+        buffer.append(b"a\nbb\nccc\n", StreamKind::Stdout, 10.0);
+        buffer
+            .set_policy(&BufferPolicy {
+                ring_bytes: Some(5),
+                ..Default::default()
+            })
+            .unwrap();
+        assert_eq!(buffer.buffer_stats().head_line, 1);
+
+        let read = buffer.read_buffer(Cursor::Line { line: 0 }, None);
+        assert_eq!(
+            read.dropped,
+            Some(DroppedRange {
+                from_seq: 1,
+                to_seq: 1
+            })
+        );
+        assert_eq!(read.chunks.first().map(|c| c.seq), Some(2));
+        let read = buffer.read_buffer(Cursor::Line { line: 2 }, None);
+        assert_eq!(read.dropped, None);
+        let bytes: Vec<u8> = read.chunks.into_iter().flat_map(|c| c.bytes).collect();
+        assert_eq!(bytes, b"ccc\n");
+    }
+
+    #[test]
+    fn line_cursor_at_a_head_starting_mid_line_reports_dropped_output() {
+        let _shared = MEMORY_TESTS.blocking_read();
+        let rt = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap();
+        let policy = BufferPolicy {
+            max_chunk_bytes: Some(4),
+            ..Default::default()
+        };
+        let buffer = OutputBuffer::new(rt.handle().clone(), &policy);
+        // Line 1 is "bb", but its start went with "a\nbb"; the head "\nccc"
+        // is counted in line 1 all the same.
+        buffer.append(b"a\nbb\nccc\n", StreamKind::Stdout, 10.0);
+        buffer
+            .set_policy(&BufferPolicy {
+                ring_bytes: Some(5),
+                ..Default::default()
+            })
+            .unwrap();
+        let read = buffer.read_buffer(Cursor::Line { line: 1 }, None);
+        assert_eq!(
+            read.dropped,
+            Some(DroppedRange {
+                from_seq: 1,
+                to_seq: 1
+            })
+        );
+        assert_eq!(read.chunks.first().map(|c| c.seq), Some(2));
+
+        // A head that begins its line loses nothing of it.
+        let buffer = OutputBuffer::new(rt.handle().clone(), &policy);
+        buffer.append(b"abc\n", StreamKind::Stdout, 10.0);
+        buffer.append(b"dd\n", StreamKind::Stdout, 11.0);
+        buffer
+            .set_policy(&BufferPolicy {
+                ring_bytes: Some(3),
+                ..Default::default()
+            })
+            .unwrap();
+        let read = buffer.read_buffer(Cursor::Line { line: 1 }, None);
+        assert_eq!(read.dropped, None);
+        let bytes: Vec<u8> = read.chunks.into_iter().flat_map(|c| c.bytes).collect();
+        assert_eq!(bytes, b"dd\n");
+    }
+}
@@ -0,0 +1,186 @@
+use std::io::{self, BufWriter, Write};
+
+use bytes::Bytes;
+use serde_json::json;
+use tokio::sync::mpsc;
+
+use crate::{ssh_shell::InvalidUtf8, utf8::Utf8Carry};
+
+#[derive(Debug, Clone, PartialEq, uniffi::Record)]
+pub struct RecordingOptions {
+    #[uniffi(default = None)]
+    pub record_input: Option<bool>, // also record `send_data` (may capture passwords)
+    #[uniffi(default = None)]
+    pub title: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, uniffi::Record)]
+pub struct RecordingStats {
+    pub path: String,
+    pub events: u64,
+    pub duration_ms: f64, // last event's time
+}
+
+/// Asciicast v2 header fields.
+pub(crate) struct CastHeader {
+    pub cols: u32,
+    pub rows: u32,
+    pub started_at_ms: f64,
+    pub term: String,
+    pub title: Option<String>,
+}
+
+pub(crate) enum CastEvent {
+    Output { t_ms: f64, bytes: Bytes },
+    Input { t_ms: f64, bytes: Vec<u8> },
+    Resize { t_ms: f64, cols: u32, rows: u32 },
+}
+
+/// A live recording. Events are queued without blocking and written by a
+/// blocking task; dropping the recorder finishes the file.
+pub(crate) struct Recorder {
+    tx: mpsc::UnboundedSender<CastEvent>,
+    pub record_input: bool,
+    task: tokio::task::JoinHandle<io::Result<RecordingStats>>,
+}
+
+impl Recorder {
+    /// Create `path` and start writing to it.
+    pub fn start(
+        rt: &tokio::runtime::Handle,
+        path: String,
+        header: CastHeader,
+        record_input: bool,
+    ) -> io::Result<Self> {
+        let file = std::fs::File::create(&path)?;
+        let (tx, rx) = mpsc::unbounded_channel();
+        let task = rt.spawn_blocking(move || {
+            let (events, duration_ms) = write_cast(BufWriter::new(file), &header, rx)?;
+            Ok(RecordingStats {
+                path,
+                events,
+                duration_ms,
+            })
+        });
+        Ok(Recorder {
+            tx,
+            record_input,
+            task,
+        })
+    }
+
+    pub fn send(&self, event: CastEvent) {
+        // The writer only stops early on an I/O error, which finish reports.
+        let _ = self.tx.send(event);
+    }
+
+    /// Close the queue and wait for the writer to flush.
+    pub async fn finish(self) -> io::Result<RecordingStats> {
+        drop(self.tx);
+        self.task.await.map_err(io::Error::other)?
+    }
+}
+
+/// Write the header, then events until the sender is dropped. Returns the
+/// event count and the last event's time.
+pub(crate) fn write_cast(
+    mut out: impl Write,
+    header: &CastHeader,
+    mut rx: mpsc::UnboundedReceiver<CastEvent>,
+) -> io::Result<(u64, f64)> {
+    let mut head = json!({
+        "version": 2,
+        "width": header.cols,
+        "height": header.rows,
+        "timestamp": (header.started_at_ms / 1000.0) as u64,
+        "env": { "TERM": header.term },
+    });
+    if let Some(title) = &header.title {
+        head["title"] = json!(title);
+    }
+    writeln!(out, "{head}")?;
+
+    let mut output = Utf8Carry::new(InvalidUtf8::Replace);
+    let mut input = Utf8Carry::new(InvalidUtf8::Replace);
+    let (mut events, mut last_ms) = (0u64, 0f64);
+    while let Some(event) = rx.blocking_recv() {
+        let (t_ms, code, data) = match event {
+            CastEvent::Output { t_ms, bytes } => (t_ms, "o", output.decode(&bytes)),
+            CastEvent::Input { t_ms, bytes } => (t_ms, "i", input.decode(&bytes)),
+            CastEvent::Resize { t_ms, cols, rows } => (t_ms, "r", format!("{cols}x{rows}")),
+        };
+        if data.is_empty() {
+            continue;
+        }
+        // Chunks stamped just before the recording started count as 0.
+        let rel_ms = (t_ms - header.started_at_ms).max(last_ms);
+        let seconds = (rel_ms / 1000.0 * 1e6).round() / 1e6;
+        writeln!(out, "{}", json!([seconds, code, data]))?;
+        events += 1;
+        last_ms = rel_ms;
+    }
+    out.flush()?;
+    Ok((events, last_ms))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn writes_header_and_events() {
+        let (tx, rx) = mpsc::unbounded_channel();
+        let output = |t_ms, bytes: &'static [u8]| CastEvent::Output {
+            t_ms,
+            bytes: Bytes::from_static(bytes),
+        };
+        // "é" split across chunks; an invalid byte in the middle of a chunk.
+        tx.send(output(999.0, b"$ \xc3")).unwrap();
+        tx.send(output(1500.0, b"\xa9\xff\r\n")).unwrap();
+        tx.send(CastEvent::Input {
+            t_ms: 2000.25,
+            bytes: b"ls\r".to_vec(),
+        })
+        .unwrap();
+        tx.send(CastEvent::Resize {
+            t_ms: 2500.0,
+            cols: 100,
+            rows: 30,
+        })
+        .unwrap();
+        drop(tx);
+
+        let header = CastHeader {
+            cols: 80,
+            rows: 24,
+            started_at_ms: 1000.0,
+            term: "xterm-256color".into(),
+            title: Some("demo".into()),
+        };
+        let mut out = Vec::new();
+        let (events, last_ms) = write_cast(&mut out, &header, rx).unwrap();
+        assert_eq!((events, last_ms), (4, 1500.0));
+        let lines: Vec<String> = String::from_utf8(out)
+            .unwrap()
+            .lines()
+            .map(String::from)
+            .collect();
+        let head: serde_json::Value = serde_json::from_str(&lines[0]).unwrap();
+        assert_eq!(head["version"], 2);
+        assert_eq!(
+            (head["width"].clone(), head["height"].clone()),
+            (json!(80), json!(24))
+        );
+        assert_eq!(head["env"]["TERM"], "xterm-256color");
+        assert_eq!(head["title"], "demo");
+        assert_eq!(
+            &lines[1..],
+            [
+                r#"[0.0,"o","$ "]"#,
+                r#"[0.5,"o","é�\r\n"]"#,
+                r#"[1.00025,"i","ls\r"]"#,
+                r#"[1.5,"r","100x30"]"#,
+            ]
+        );
+    }
+}
@@ -0,0 +1,277 @@
+use std::collections::VecDeque;
+
+use crate::ansi::Stripper;
+
+// Completed and in-progress commands kept per session; oldest are dropped.
+pub(crate) static DEFAULT_MAX_COMMAND_RECORDS: usize = 1000;
+
+// Longest OSC payload / echoed command line kept while scanning.
+static MAX_OSC_BYTES: usize = 4096;
+
+/// A byte in the output stream: `offset` into the chunk with `seq` (may equal
+/// the chunk's length, i.e. the start of the next chunk).
+#[derive(Debug, Clone, Copy, PartialEq, uniffi::Record)]
+pub struct StreamPosition {
+    pub seq: u64,
+    pub offset: u64,
+}
+
+/// One prompt/command/output cycle, from shell integration marks (OSC 133,
+/// or VS Code's OSC 633). Fields fill in as marks arrive.
+#[derive(Debug, Clone, PartialEq, uniffi::Record)]
+pub struct CommandRecord {
+    pub id: u64,
+    pub prompt_start: Option<StreamPosition>,  // A
+    pub command_start: Option<StreamPosition>, // B: prompt ends, input echo begins
+    pub output_start: Option<StreamPosition>,  // C: command runs
+    pub output_end: Option<StreamPosition>,    // D: command finished
+    // From OSC 633;E when the shell sends it, else the echoed input.
+    pub command: Option<String>,
+    pub exit_code: Option<i32>,
+    // When the chunk holding the C/D mark arrived: marks that arrive in one
+    // read (a command faster than the round trip) give a duration of 0.
+    pub started_at_ms: Option<f64>,
+    pub finished_at_ms: Option<f64>,
+    pub duration_ms: Option<f64>,
+}
+
+impl CommandRecord {
+    fn finished(&self) -> bool {
+        self.output_end.is_some()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Scan {
+    Ground,
+    Esc,
+    Osc,
+    OscEsc, // ESC inside an OSC: ST if '\' follows
+}
+
+/// Scans shell output for OSC 133/633 marks and keeps command records.
+pub(crate) struct CommandTracker {
+    records: VecDeque<CommandRecord>,
+    max_records: usize,
+    next_id: u64,
+    scan: Scan,
+    osc: Vec<u8>,
+    osc_start: StreamPosition,
+    input: Option<Vec<u8>>, // output between B and C
+    input_len_at_esc: usize,
+}
+
+impl CommandTracker {
+    pub fn new(max_records: usize) -> Self {
+        CommandTracker {
+            records: VecDeque::new(),
+            max_records,
+            next_id: 1,
+            scan: Scan::Ground,
+            osc: Vec::new(),
+            osc_start: StreamPosition { seq: 0, offset: 0 },
+            input: None,
+            input_len_at_esc: 0,
+        }
+    }
+
+    pub fn records(&self) -> Vec<CommandRecord> {
+        self.records.iter().cloned().collect()
+    }
+
+    /// Scan one chunk; returns the records it changed, in order.
+    pub fn feed(&mut self, seq: u64, t_ms: f64, bytes: &[u8]) -> Vec<CommandRecord> {
+        let mut updated = Vec::new();
+        for (i, &byte) in bytes.iter().enumerate() {
+            let input_len = self.input.as_ref().map_or(0, Vec::len);
+            if let Some(input) = self.input.as_mut() {
+                if input.len() < MAX_OSC_BYTES {
+                    input.push(byte);
+                }
+            }
+            let here = StreamPosition {
+                seq,
+                offset: i as u64,
+            };
+            let next = StreamPosition {
+                seq,
+                offset: i as u64 + 1,
+            };
+            self.scan = match (self.scan, byte) {
+                (Scan::Ground | Scan::Esc | Scan::OscEsc, 0x1b) => {
+                    self.osc_start = here;
+                    self.input_len_at_esc = input_len;
+                    Scan::Esc
+                }
+                (Scan::Esc | Scan::OscEsc, b']') => {
+                    self.osc.clear();
+                    Scan::Osc
+                }
+                (Scan::Osc, 0x07) | (Scan::OscEsc, b'\\') => {
+                    if let Some(record) = self.dispatch(next, t_ms) {
+                        updated.push(record);
+                    }
+                    Scan::Ground
+                }
+                (Scan::Osc, 0x1b) => Scan::OscEsc,
+                (Scan::Osc, _) => {
+                    if self.osc.len() < MAX_OSC_BYTES {
+                        self.osc.push(byte);
+                    }
+                    Scan::Osc
+                }
+                _ => Scan::Ground,
+            };
+        }
+        updated
+    }
+
+    fn dispatch(&mut self, end: StreamPosition, t_ms: f64) -> Option<CommandRecord> {
+        let payload = String::from_utf8_lossy(&self.osc).into_owned();
+        let mut parts = payload.split(';');
+        let vscode = match parts.next() {
+            Some("133") => false,
+            Some("633") => true,
+            _ => return None,
+        };
+        let start = self.osc_start;
+        match parts.next()? {
+            "A" => {
+                let record = self.open(|_| false);
+                record.prompt_start = Some(end);
+            }
+            "B" => {
+                let record = self.open(|r| r.command_start.is_none() && r.output_start.is_none());
+                record.command_start = Some(end);
+                self.input = Some(Vec::new());
+            }
+            "C" => {
+                let echoed = self.input.take().map(|mut input| {
+                    input.truncate(self.input_len_at_esc);
+                    let mut stripper = Stripper::new();
+                    stripper.feed(&input);
+                    stripper.finish().text.trim().to_string()
+                });
+                let record = self.open(|r| r.output_start.is_none());
+                record.output_start = Some(end);
+                record.started_at_ms = Some(t_ms);
+                if record.command.is_none() {
+                    record.command = echoed.filter(|c| !c.is_empty());
+                }
+            }
+            "D" => {
+                self.input = None;
+                let record = self.records.back_mut().filter(|r| !r.finished())?;
+                record.output_end = Some(start);
+                record.exit_code = parts.next().and_then(|code| code.trim().parse().ok());
+                record.finished_at_ms = Some(t_ms);
+                record.duration_ms = record.started_at_ms.map(|s| t_ms - s);
+            }
+            "E" if vscode => {
+                let record = self.open(|r| r.output_start.is_none());
+                record.command = Some(unescape_vscode(parts.next().unwrap_or_default()));
+            }
+            _ => return None,
+        }
+        self.records.back().cloned()
+    }
+
+    /// The unfinished last record if `reuse` accepts it, else a new one.
+    fn open(&mut self, reuse: impl Fn(&CommandRecord) -> bool) -> &mut CommandRecord {
+        let reusable = self
+            .records
+            .back()
+            .is_some_and(|r| !r.finished() && reuse(r));
+        if !reusable {
+            if self.records.len() == self.max_records {
+                self.records.pop_front();
+            }
+            self.records.push_back(CommandRecord {
+                id: self.next_id,
+                prompt_start: None,
+                command_start: None,
+                output_start: None,
+                output_end: None,
+                command: None,
+                exit_code: None,
+                started_at_ms: None,
+                finished_at_ms: None,
+                duration_ms: None,
+            });
+            self.next_id += 1;
+        }
+        self.records.back_mut().unwrap()
+    }
+}
+
+// OSC 633;E escapes '\' as "\\" and bytes such as ';' as "\xAB".
+fn unescape_vscode(s: &str) -> String {
+    let mut out = Vec::with_capacity(s.len());
+    let bytes = s.as_bytes();
+    let mut i = 0;
+    while i < bytes.len() {
+        match (bytes[i], bytes.get(i + 1)) {
+            (b'\\', Some(b'\\')) => {
+                out.push(b'\\');
+                i += 2;
+            }
+            (b'\\', Some(b'x')) => match s
+                .get(i + 2..i + 4)
+                .and_then(|h| u8::from_str_radix(h, 16).ok())
+            {
+                Some(byte) => {
+                    out.push(byte);
+                    i += 4;
+                }
+                None => {
+                    out.push(b'\\');
+                    i += 1;
+                }
+            },
+            (byte, _) => {
+                out.push(byte);
+                i += 1;
+            }
+        }
+    }
+    String::from_utf8_lossy(&out).into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tracks_marks_split_across_chunks() {
+        let mut tracker = CommandTracker::new(10);
+        tracker.feed(1, 0.0, b"\x1b]133;A\x07$ \x1b]133;B\x07l");
+        tracker.feed(2, 1.0, b"s -l\x1b[K\r\n\x1b]13");
+        let updated = tracker.feed(3, 5.0, b"3;C\x1b\\out\r\n\x1b]133;D;2\x07");
+        assert_eq!(updated.len(), 2);
+        let done = &updated[1];
+        assert_eq!(done.command.as_deref(), Some("ls -l"));
+        assert_eq!(
+            done.prompt_start,
+            Some(StreamPosition { seq: 1, offset: 8 })
+        );
+        assert_eq!(
+            done.output_start,
+            Some(StreamPosition { seq: 3, offset: 5 })
+        );
+        assert_eq!(done.output_end, Some(StreamPosition { seq: 3, offset: 10 }));
+        assert_eq!((done.exit_code, done.duration_ms), (Some(2), Some(0.0)));
+
+        // VS Code marks carry the exact command line.
+        tracker.feed(
+            4,
+            6.0,
+            b"\x1b]633;A\x07$ \x1b]633;B\x07\x1b]633;E;echo a\\x3bb\x07",
+        );
+        tracker.feed(5, 8.0, b"\x1b]633;C\x07a\r\n\x1b]633;D\x07");
+        let records = tracker.records();
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[1].command.as_deref(), Some("echo a;b"));
+        assert_eq!(records[1].exit_code, None);
+        assert!(records[1].finished_at_ms.is_some());
+    }
+}
@@ -1,27 +1,36 @@
 use std::fmt;
 use std::sync::{Arc, Weak};
 
-use tokio::sync::{broadcast, Mutex as AsyncMutex};
+use tokio::sync::{watch, Mutex as AsyncMutex};
 
 use russh::client::{Config, Handle as ClientHandle};
 use russh::keys::PrivateKeyWithHashAlg;
-use russh::{self, client, ChannelMsg, Disconnect};
+use russh::{self, client, compression, ChannelMsg, Disconnect, Preferred};
+use std::borrow::Cow;
 
+use crate::fingerprint::fingerprints;
+use crate::happy_eyeballs;
+use crate::metrics::{
+    ConnectionMetrics, ConnectionMetricsCallback, SshConnectionMetrics,
+    DEFAULT_METRICS_INTERVAL_MS, RTT_PROBE_TIMEOUT_MS,
+};
+use crate::output_buffer::{ChannelIntake, OutputBuffer};
 use crate::private_key::normalize_openssh_ed25519_seed_key;
 use crate::ssh_shell::{
-    append_and_broadcast, Chunk, ShellSession, ShellSessionInfo, StartShellOptions, StreamKind,
-    DEFAULT_BROADCAST_CHUNK_CAPACITY, DEFAULT_MAX_CHUNK_SIZE, DEFAULT_SHELL_RING_BUFFER_CAPACITY,
-    DEFAULT_TERMINAL_MODES, DEFAULT_TERM_COALESCE_MS, DEFAULT_TERM_COL_WIDTH,
-    DEFAULT_TERM_PIXEL_HEIGHT, DEFAULT_TERM_PIXEL_WIDTH, DEFAULT_TERM_ROW_HEIGHT,
+    shell_env, ShellCommand, ShellSession, ShellSessionInfo, StartShellOptions, StreamKind,
+    TerminalGeometry, DEFAULT_TERMINAL_MODES, DEFAULT_TERM_COL_WIDTH, DEFAULT_TERM_PIXEL_HEIGHT,
+    DEFAULT_TERM_PIXEL_WIDTH, DEFAULT_TERM_ROW_HEIGHT,
+};
+use crate::terminfo::{
+    bundled_entry, check_command, install_command, validate_compiled_entry, validate_term_name,
+    TerminfoStatus,
 };
+use crate::transport::{is_aead_cipher, HandshakeTranscript, MeteredStream};
 use crate::utils::{now_ms, SshError};
 use russh::keys::PublicKeyBase64;
-use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
 
-use std::{
-    collections::HashMap,
-    sync::{atomic::AtomicU64, Mutex},
-};
+use std::{collections::HashMap, sync::Mutex};
 
 fn server_public_key_to_info(
     host: &str,
@@ -29,21 +38,21 @@ fn server_public_key_to_info(
     remote_ip: Option<String>,
     pk: &russh::keys::PublicKey,
 ) -> ServerPublicKeyInfo {
-    // Algorithm identifier (e.g., "ssh-ed25519", "rsa-sha2-512")
-    let algorithm = pk.algorithm().to_string();
-
     // Key blob (base64)
     let key_base64 = pk.public_key_base64();
 
-    // Fingerprints via russh-keys/ssh-key helpers
-    let fingerprint_sha256 = format!("{}", pk.fingerprint(russh::keys::ssh_key::HashAlg::Sha256));
+    // Algorithm identifier (e.g., "ssh-ed25519", "rsa-sha2-512"), fingerprints
+    // and randomart
+    let fp = fingerprints(pk);
 
     ServerPublicKeyInfo {
         host: host.to_string(),
         port,
         remote_ip,
-        algorithm,
-        fingerprint_sha256,
+        algorithm: fp.algorithm,
+        fingerprint_sha256: fp.fingerprint_sha256,
+        fingerprint_md5: fp.fingerprint_md5,
+        randomart: fp.randomart,
         key_base64,
     }
 }
@@ -68,22 +77,228 @@ pub struct ConnectOptions {
     pub on_connection_progress_callback: Option<Arc<dyn ConnectProgressCallback>>,
     pub on_disconnected_callback: Option<Arc<dyn ConnectionDisconnectedCallback>>,
     pub on_server_key_callback: Arc<dyn ServerKeyCallback>,
+    #[uniffi(default = None)]
+    pub on_metrics_callback: Option<Arc<dyn ConnectionMetricsCallback>>,
+    #[uniffi(default = None)]
+    pub metrics_interval_ms: Option<u32>, // round-trip probe + report period, with on_metrics_callback
+    #[uniffi(default = None)]
+    pub rekey_limits: Option<RekeyLimits>,
+    #[uniffi(default = None)]
+    pub on_connection_event_callback: Option<Arc<dyn ConnectionEventCallback>>,
+    #[uniffi(default = None)]
+    pub on_auth_banner_callback: Option<Arc<dyn AuthBannerCallback>>,
+    #[uniffi(default = None)]
+    pub abort_token: Option<Arc<ConnectAbortToken>>,
+    #[uniffi(default = None)]
+    pub compression: Option<CompressionPreference>, // None: russh's default order
 }
 
+/// Transport compression (zlib@openssh.com / zlib). Worth its CPU cost on slow
+/// links; on a fast LAN it mostly isn't.
 #[derive(Debug, Clone, Copy, PartialEq, uniffi::Enum)]
-pub enum SshConnectionProgressEvent {
-    // Before any progress events, assume: TcpConnecting
-    TcpConnected,
-    SshHandshake,
-    // If promise has not resolved, assume: Authenticating
-    // After promise resolves, assume: Connected
+pub enum CompressionPreference {
+    Off,
+    Prefer,  // compress if the server supports it
+    Require, // fail the handshake if the server does not support it
+}
+impl CompressionPreference {
+    fn algorithms(self) -> &'static [compression::Name] {
+        match self {
+            CompressionPreference::Off => &[compression::NONE],
+            CompressionPreference::Prefer => &[
+                compression::ZLIB_LEGACY,
+                compression::ZLIB,
+                compression::NONE,
+            ],
+            CompressionPreference::Require => &[compression::ZLIB_LEGACY, compression::ZLIB],
+        }
+    }
 }
 
+/// Cancels an in-flight `connect`, which then rejects with
+/// `SshError::Cancelled`. Aborting after connect has resolved does nothing.
+/// A token stays aborted, so use a fresh one per attempt.
+#[derive(Debug, uniffi::Object)]
+pub struct ConnectAbortToken {
+    aborted: watch::Sender<bool>,
+}
+
+#[uniffi::export]
+impl ConnectAbortToken {
+    #[uniffi::constructor]
+    pub fn new() -> Arc<Self> {
+        Arc::new(Self {
+            aborted: watch::channel(false).0,
+        })
+    }
+
+    pub fn abort(&self) {
+        self.aborted.send_replace(true);
+    }
+
+    pub fn is_aborted(&self) -> bool {
+        *self.aborted.borrow()
+    }
+}
+
+impl ConnectAbortToken {
+    async fn aborted(&self) {
+        let mut rx = self.aborted.subscribe();
+        let _ = rx.wait_for(|aborted| *aborted).await;
+    }
+}
+
+/// Resolves once the connect owning `abort` was cancelled. Never resolves if
+/// the connect finished first (its sender is gone by then).
+pub(crate) async fn connect_aborted(mut abort: watch::Receiver<bool>) {
+    if abort.wait_for(|aborted| *aborted).await.is_err() {
+        std::future::pending::<()>().await;
+    }
+}
+
+/// Key re-exchange thresholds. Byte limits apply to each direction, like
+/// russh's own limits. Unset fields keep the defaults (512 MiB / 1 h).
+///
+/// Byte limits must be between 1 byte and 768 MiB, so they stay below russh's
+/// own 1 GiB backstop; time limits must be at least 1 s, the policy's check
+/// period. Anything else fails the connect with `InvalidArgument`.
+#[derive(Debug, Clone, Copy, PartialEq, uniffi::Record)]
+pub struct RekeyLimits {
+    pub write_bytes: Option<u64>,
+    pub read_bytes: Option<u64>,
+    pub time_ms: Option<u64>,
+}
+
+/// Algorithms chosen by the key exchange, per RFC 4253 §7.1.
 #[derive(Debug, Clone, PartialEq, uniffi::Record)]
-pub struct SshConnectionInfoProgressTimings {
-    // TODO: We should have a field for each SshConnectionProgressEvent. Would be great if this were enforced by the compiler.
-    pub tcp_established_at_ms: f64,
-    pub ssh_handshake_at_ms: f64,
+pub struct NegotiatedAlgorithms {
+    pub kex: String,
+    pub host_key: String,
+    pub cipher_client_to_server: String,
+    pub cipher_server_to_client: String,
+    pub mac_client_to_server: String, // "implicit" for AEAD ciphers
+    pub mac_server_to_client: String,
+    pub compression_client_to_server: String,
+    pub compression_server_to_client: String,
+}
+
+impl NegotiatedAlgorithms {
+    /// russh negotiates one cipher for both directions.
+    fn from_names(names: &russh::Names) -> Self {
+        let cipher = names.cipher.as_ref().to_string();
+        let mac = |name: &russh::mac::Name| {
+            if is_aead_cipher(&cipher) {
+                "implicit".to_string()
+            } else {
+                name.as_ref().to_string()
+            }
+        };
+        Self {
+            kex: names.kex.as_ref().to_string(),
+            host_key: names.key.as_str().to_string(),
+            mac_client_to_server: mac(&names.client_mac),
+            mac_server_to_client: mac(&names.server_mac),
+            cipher_client_to_server: cipher.clone(),
+            cipher_server_to_client: cipher,
+            compression_client_to_server: names.client_compression_name.as_ref().to_string(),
+            compression_server_to_client: names.server_compression_name.as_ref().to_string(),
+        }
+    }
+}
+
+/// Events that happen on an established connection.
+#[derive(Debug, Clone, PartialEq, uniffi::Enum)]
+pub enum SshConnectionEvent {
+    // Every completed re-exchange, whichever side started it, with the
+    // algorithms it negotiated.
+    Rekeyed {
+        rekey_count: u32,
+        at_ms: f64,
+        algorithms: NegotiatedAlgorithms,
+    },
+}
+
+#[uniffi::export(with_foreign)]
+pub trait ConnectionEventCallback: Send + Sync {
+    fn on_change(&self, event: SshConnectionEvent);
+}
+
+/// Declares SshConnectionProgressEvent together with the timestamp field each
+/// event fills in SshConnectionInfoProgressTimings, so an event cannot be
+/// added without its timing.
+macro_rules! connection_progress_timeline {
+    ($(
+        $(#[$meta:meta])*
+        $variant:ident $({ $($field:ident: $ty:ty),* $(,)? })? => $timing:ident
+    ),* $(,)?) => {
+        #[derive(Debug, Clone, PartialEq, uniffi::Enum)]
+        pub enum SshConnectionProgressEvent {
+            $($(#[$meta])* $variant $({ $($field: $ty),* })?),*
+        }
+
+        #[derive(Debug, Clone, Default, PartialEq, uniffi::Record)]
+        pub struct SshConnectionInfoProgressTimings {
+            $(pub $timing: f64),*
+        }
+
+        impl SshConnectionInfoProgressTimings {
+            pub(crate) fn record(&mut self, event: &SshConnectionProgressEvent, at_ms: f64) {
+                match event {
+                    $(SshConnectionProgressEvent::$variant { .. } => self.$timing = at_ms),*
+                }
+            }
+        }
+    };
+}
+
+// Events are emitted in this order. A connect that resolves has been through
+// all of them; one that rejects stopped after the last event it emitted.
+connection_progress_timeline! {
+    DnsResolved {
+        candidates: Vec<String>, // socket addresses, in the order they will be tried
+    } => dns_resolved_at_ms,
+    TcpConnected {
+        chosen: String, // the address whose TCP connect won the race
+    } => tcp_established_at_ms,
+    HostKeyVerified => host_key_verified_at_ms,
+    // Key exchange finished; the transport is encrypted.
+    SshHandshake => ssh_handshake_at_ms,
+    Authenticating => authenticating_at_ms,
+    // One per authentication method tried; the timing keeps the latest.
+    AuthAttempt { method: String } => auth_attempt_at_ms, // "password", "publickey"
+    Authenticated => authenticated_at_ms,
+    Connected => connected_at_ms,
+}
+
+/// Stamps progress events into the timings record and forwards them to the
+/// caller's callback.
+pub(crate) struct ProgressReporter {
+    callback: Option<Arc<dyn ConnectProgressCallback>>,
+    timings: Mutex<SshConnectionInfoProgressTimings>,
+}
+impl ProgressReporter {
+    pub(crate) fn new(callback: Option<Arc<dyn ConnectProgressCallback>>) -> Self {
+        Self {
+            callback,
+            timings: Mutex::new(SshConnectionInfoProgressTimings::default()),
+        }
+    }
+
+    pub(crate) fn emit(&self, event: SshConnectionProgressEvent) {
+        if let Ok(mut timings) = self.timings.lock() {
+            timings.record(&event, now_ms());
+        }
+        if let Some(cb) = self.callback.as_ref() {
+            cb.on_change(event);
+        }
+    }
+
+    pub(crate) fn timings(&self) -> SshConnectionInfoProgressTimings {
+        self.timings
+            .lock()
+            .unwrap_or_else(|p| p.into_inner())
+            .clone()
+    }
 }
 
 #[uniffi::export(with_foreign)]
@@ -96,6 +311,13 @@ pub trait ConnectionDisconnectedCallback: Send + Sync {
     fn on_change(&self, connection_id: String);
 }
 
+/// Receives SSH_MSG_USERAUTH_BANNER text (legal notices, MOTD-style warnings)
+/// while authentication is still in progress.
+#[uniffi::export(with_foreign)]
+pub trait AuthBannerCallback: Send + Sync {
+    fn on_change(&self, banner: String);
+}
+
 #[derive(Debug, Clone, PartialEq, uniffi::Record)]
 pub struct ServerPublicKeyInfo {
     pub host: String,
@@ -103,6 +325,8 @@ pub struct ServerPublicKeyInfo {
     pub remote_ip: Option<String>,
     pub algorithm: String,
     pub fingerprint_sha256: String, // e.g., "SHA256:..." (no padding)
+    pub fingerprint_md5: String,    // e.g., "MD5:16:27:ac:..." (legacy)
+    pub randomart: String,          // OpenSSH "drunken bishop" picture
     pub key_base64: String,         // raw key blob (base64)
 }
 
@@ -119,17 +343,45 @@ pub struct SshConnectionInfo {
     pub created_at_ms: f64,
     pub connected_at_ms: f64,
     pub progress_timings: SshConnectionInfoProgressTimings,
+    pub server_identification: Option<String>, // e.g., "SSH-2.0-OpenSSH_9.6"
+    pub auth_banner: Option<String>,
+    // None only if the handshake could not be followed (non-standard framing).
+    pub negotiated_algorithms: Option<NegotiatedAlgorithms>,
 }
 
-/// Minimal client::Handler with optional server key callback.
+/// Minimal client::Handler with optional server key and banner callbacks.
 pub(crate) struct NoopHandler {
     pub on_server_key_callback: Arc<dyn ServerKeyCallback>,
+    pub on_auth_banner_callback: Option<Arc<dyn AuthBannerCallback>>,
     pub host: String,
     pub port: u16,
     pub remote_ip: Option<String>,
+    // Banner text received so far; servers may send more than one.
+    pub auth_banner: Arc<Mutex<Option<String>>>,
+    pub progress: Arc<ProgressReporter>,
+    pub abort: watch::Receiver<bool>,
+    pub metrics: Arc<ConnectionMetrics>,
+    pub last_rekey: Arc<Mutex<RekeyMark>>,
+    pub on_connection_event_callback: Option<Arc<dyn ConnectionEventCallback>>,
 }
 impl client::Handler for NoopHandler {
     type Error = SshError;
+    fn auth_banner(
+        &mut self,
+        banner: &str,
+        _session: &mut client::Session,
+    ) -> impl std::future::Future<
+        Output = std::result::Result<(), <Self as russh::client::Handler>::Error>,
+    > + std::marker::Send {
+        if let Ok(mut stored) = self.auth_banner.lock() {
+            stored.get_or_insert_with(String::new).push_str(banner);
+        }
+        if let Some(cb) = self.on_auth_banner_callback.as_ref() {
+            cb.on_change(banner.to_string());
+        }
+        async { Ok(()) }
+    }
+
     fn check_server_key(
         &mut self,
         server_public_key: &russh::keys::PublicKey,
@@ -142,12 +394,49 @@ impl client::Handler for NoopHandler {
         let remote_ip = self.remote_ip.clone();
         // Build structured info for UI/decision.
         let info = server_public_key_to_info(&host, port, remote_ip, server_public_key);
+        let progress = self.progress.clone();
+        let abort = self.abort.clone();
         async move {
-            // Delegate decision to user callback (async via UniFFI).
-            let accept = cb.on_change(info).await;
+            // Delegate decision to user callback (async via UniFFI). The
+            // session task waits here, so it must notice an abort itself.
+            let accept = tokio::select! {
+                accept = cb.on_change(info) => accept,
+                _ = connect_aborted(abort) => return Err(SshError::Cancelled),
+            };
+            if accept {
+                progress.emit(SshConnectionProgressEvent::HostKeyVerified);
+            }
             Ok(accept)
         }
     }
+
+    fn kex_done(
+        &mut self,
+        names: &russh::Names,
+        rekey: bool,
+    ) -> impl std::future::Future<
+        Output = std::result::Result<(), <Self as russh::client::Handler>::Error>,
+    > + std::marker::Send {
+        if rekey {
+            if let Ok(mut mark) = self.last_rekey.lock() {
+                *mark = RekeyMark::now(&self.metrics);
+            }
+            let rekey_count = self.metrics.rekey_count.fetch_add(1, Ordering::Relaxed) + 1;
+            let algorithms = NegotiatedAlgorithms::from_names(names);
+            self.metrics.set_compression(
+                &algorithms.compression_client_to_server,
+                &algorithms.compression_server_to_client,
+            );
+            if let Some(cb) = self.on_connection_event_callback.as_ref() {
+                cb.on_change(SshConnectionEvent::Rekeyed {
+                    rekey_count,
+                    at_ms: now_ms(),
+                    algorithms,
+                });
+            }
+        }
+        async { Ok(()) }
+    }
 }
 
 #[derive(uniffi::Object)]
@@ -161,6 +450,13 @@ pub struct SshConnection {
 
     // Weak self for child sessions to refer back without cycles.
     pub(crate) self_weak: AsyncMutex<Weak<SshConnection>>,
+
+    pub(crate) metrics: Arc<ConnectionMetrics>,
+    pub(crate) rekey_policy: RekeyPolicy,
+    // Shared with NoopHandler, which moves it on every completed rekey.
+    pub(crate) last_rekey: Arc<Mutex<RekeyMark>>,
+    // Metrics monitor + rekey policy; aborted on disconnect.
+    pub(crate) background_tasks: Mutex<Vec<tokio::task::JoinHandle<()>>>,
 }
 
 impl fmt::Debug for SshConnection {
@@ -169,6 +465,10 @@ impl fmt::Debug for SshConnection {
             .field("info.connection_details", &self.info.connection_details)
             .field("info.created_at_ms", &self.info.created_at_ms)
             .field("info.connected_at_ms", &self.info.connected_at_ms)
+            .field(
+                "info.negotiated_algorithms",
+                &self.info.negotiated_algorithms,
+            )
             .finish()
     }
 }
@@ -180,6 +480,17 @@ impl SshConnection {
         self.info.clone()
     }
 
+    /// Traffic and latency counters snapshot.
+    pub fn get_metrics(&self) -> SshConnectionMetrics {
+        self.metrics.snapshot()
+    }
+
+    /// Force a key re-exchange now. Resolves once the new keys are in use;
+    /// fails if that takes longer than REKEY_TIMEOUT_MS.
+    pub async fn rekey(&self) -> Result<(), SshError> {
+        self.rekey_internal().await
+    }
+
     pub async fn start_shell(
         &self,
         opts: StartShellOptions,
@@ -187,13 +498,17 @@ impl SshConnection {
         let started_at_ms = now_ms();
 
         let term = opts.term;
+        let term_name = match opts.term_name.as_deref() {
+            Some(name) => {
+                validate_term_name(name)?;
+                name.to_string()
+            }
+            None => term.as_ssh_name().to_string(),
+        };
+        let buffer_policy = opts.buffer_policy.clone().unwrap_or_default();
+        buffer_policy.validate()?;
         let on_closed_callback = opts.on_closed_callback.clone();
 
-        let client_handle = self.client_handle.lock().await;
-
-        let ch = client_handle.channel_open_session().await?;
-        let channel_id: u32 = ch.id().into();
-
         let mut modes: Vec<(russh::Pty, u32)> = DEFAULT_TERMINAL_MODES.to_vec();
         if let Some(terminal_mode_params) = &opts.terminal_mode {
             for m in terminal_mode_params {
@@ -228,84 +543,111 @@ impl SshConnection {
             .and_then(|s| s.pixel_height)
             .unwrap_or(DEFAULT_TERM_PIXEL_HEIGHT);
 
-        ch.request_pty(
-            true,
-            term.as_ssh_name(),
-            col_width,
-            row_height,
+        // Without a PTY the server does no CRLF translation or echo, and
+        // stderr stays separate.
+        let pty = opts.allocate_pty.unwrap_or(true);
+        let terminal_geometry = TerminalGeometry {
+            cols: col_width,
+            rows: row_height,
             pixel_width,
             pixel_height,
-            &modes,
-        )
-        .await?;
-        ch.request_shell(true).await?;
+        };
+        let env = shell_env(opts.locale.as_deref(), opts.env.as_ref());
+
+        let client_handle = self.client_handle.lock().await;
+
+        let mut ch = client_handle.channel_open_session().await?;
+        let channel_id: u32 = ch.id().into();
+        self.metrics.channel_opened();
+
+        let requested = async {
+            let rejected_env = request_env(&mut ch, &env).await?;
+            if pty {
+                ch.request_pty(
+                    true,
+                    term_name.as_str(),
+                    col_width,
+                    row_height,
+                    pixel_width,
+                    pixel_height,
+                    &modes,
+                )
+                .await?;
+            }
+            match &opts.command {
+                None => ch.request_shell(true).await?,
+                Some(ShellCommand::Exec { command }) => ch.exec(true, command.as_str()).await?,
+                Some(ShellCommand::Subsystem { name }) => {
+                    ch.request_subsystem(true, name.as_str()).await?
+                }
+            }
+            Ok::<_, SshError>(rejected_env)
+        }
+        .await;
+        let rejected_env = match requested {
+            Ok(rejected_env) => rejected_env,
+            Err(e) => {
+                let _ = ch.close().await;
+                self.metrics.channel_closed();
+                return Err(e);
+            }
+        };
 
         // Split for read/write; spawn reader.
         let (mut reader, writer) = ch.split();
 
         // Setup ring + broadcast for this session
-        let (tx, _rx) = broadcast::channel::<Arc<Chunk>>(DEFAULT_BROADCAST_CHUNK_CAPACITY);
-        let ring = Arc::new(Mutex::new(std::collections::VecDeque::<Arc<Chunk>>::new()));
-        let used_bytes = Arc::new(Mutex::new(0usize));
-        let next_seq = Arc::new(AtomicU64::new(1));
-        let head_seq = Arc::new(AtomicU64::new(1));
-        let tail_seq = Arc::new(AtomicU64::new(0));
-        let dropped_bytes_total = Arc::new(AtomicU64::new(0));
-        let ring_bytes_capacity = Arc::new(AtomicUsize::new(DEFAULT_SHELL_RING_BUFFER_CAPACITY));
-        let default_coalesce_ms = AtomicU64::new(DEFAULT_TERM_COALESCE_MS);
-
-        let ring_clone = ring.clone();
-        let used_bytes_clone = used_bytes.clone();
-        let tx_clone = tx.clone();
-        let ring_bytes_capacity_c = ring_bytes_capacity.clone();
-        let dropped_bytes_total_c = dropped_bytes_total.clone();
-        let head_seq_c = head_seq.clone();
-        let tail_seq_c = tail_seq.clone();
-        let next_seq_c = next_seq.clone();
+        let buffer = OutputBuffer::new(tokio::runtime::Handle::current(), &buffer_policy);
+        let buffer_c = buffer.clone();
 
         let on_closed_callback_for_reader = on_closed_callback.clone();
+        let on_channel_pause_callback = opts.on_channel_pause_callback.clone();
+        let metrics_c = self.metrics.clone();
+        let parent = self.self_weak.lock().await.clone();
+        let parent_c = parent.clone();
 
+        // Held until the session is in the map, so a close from the server
+        // can't look for it before it is there.
+        let mut shells = self.shells.lock().await;
+        // The reader never stops draining the channel, or russh's session
+        // loop would block on it; a lagging lossless listener pauses the
+        // server through this channel's window instead.
+        let window = writer.clone();
         let reader_task = tokio::spawn(async move {
-            let max_chunk = DEFAULT_MAX_CHUNK_SIZE;
+            let on_pause = on_channel_pause_callback.as_deref();
+            let mut intake = ChannelIntake::default();
+            let mut window_held = false;
             loop {
-                match reader.wait().await {
-                    Some(ChannelMsg::Data { data }) => {
-                        append_and_broadcast(
-                            &data,
-                            StreamKind::Stdout,
-                            &ring_clone,
-                            &used_bytes_clone,
-                            &ring_bytes_capacity_c,
-                            &dropped_bytes_total_c,
-                            &head_seq_c,
-                            &tail_seq_c,
-                            &next_seq_c,
-                            &tx_clone,
-                            max_chunk,
-                        );
-                    }
-                    Some(ChannelMsg::ExtendedData { data, .. }) => {
-                        append_and_broadcast(
-                            &data,
-                            StreamKind::Stderr,
-                            &ring_clone,
-                            &used_bytes_clone,
-                            &ring_bytes_capacity_c,
-                            &dropped_bytes_total_c,
-                            &head_seq_c,
-                            &tail_seq_c,
-                            &next_seq_c,
-                            &tx_clone,
-                            max_chunk,
-                        );
-                    }
-                    Some(ChannelMsg::Close) | None => {
-                        if let Some(sl) = on_closed_callback_for_reader.as_ref() {
-                            sl.on_change(channel_id);
+                let paused = tokio::select! {
+                    msg = reader.wait() => match msg {
+                        Some(ChannelMsg::Data { data }) => {
+                            metrics_c.payload_received(data.len());
+                            buffer_c.take_in(&mut intake, &data, StreamKind::Stdout, now_ms(), on_pause)
                         }
-                        break;
+                        Some(ChannelMsg::ExtendedData { data, .. }) => {
+                            metrics_c.payload_received(data.len());
+                            buffer_c.take_in(&mut intake, &data, StreamKind::Stderr, now_ms(), on_pause)
+                        }
+                        Some(ChannelMsg::Close) | None => {
+                            buffer_c.flush_held(&mut intake, on_pause);
+                            if let Some(conn) = parent_c.upgrade() {
+                                conn.forget_shell(channel_id).await;
+                            }
+                            if let Some(sl) = on_closed_callback_for_reader.as_ref() {
+                                sl.on_change(channel_id);
+                            }
+                            break;
+                        }
+                        _ => continue,
+                    },
+                    _ = buffer_c.lossless_caught_up(), if window_held => {
+                        buffer_c.resume(&mut intake, on_pause)
                     }
-                    _ => {}
+                };
+                if paused != window_held {
+                    window_held = paused;
+                    // Fails only once the session is gone.
+                    let _ = window.hold_window(paused).await;
                 }
             }
         });
@@ -316,36 +658,71 @@ impl SshConnection {
                 created_at_ms: started_at_ms,
                 connected_at_ms: now_ms(),
                 term,
+                term_name,
                 connection_id: self.info.connection_id.clone(),
+                rejected_env,
+                terminal_geometry,
+                command: opts.command.clone(),
+                pty,
             },
+            terminal_geometry: Mutex::new(terminal_geometry),
             on_closed_callback,
-            parent: self.self_weak.lock().await.clone(),
+            parent,
 
             writer: AsyncMutex::new(writer),
             reader_task,
 
-            // Ring buffer
-            ring,
-            ring_bytes_capacity,
-            used_bytes,
-            dropped_bytes_total,
-            head_seq,
-            tail_seq,
-
-            // Listener tasks management
-            sender: tx,
-            listener_tasks: Arc::new(Mutex::new(HashMap::new())),
-            next_listener_id: AtomicU64::new(1),
-            coalesce_ms: default_coalesce_ms,
-            rt_handle: tokio::runtime::Handle::current(),
+            buffer,
         });
 
-        self.shells.lock().await.insert(channel_id, session.clone());
+        shells.insert(channel_id, session.clone());
 
         Ok(session)
     }
 
+    /// Install `compiled_entry` for `term_name` into the server's ~/.terminfo,
+    /// unless the server can already resolve that name. Compile the entry
+    /// with `tic -o`, or use install_bundled_terminfo. Run before starting a
+    /// shell with a TERM the server may not know.
+    pub async fn install_terminfo(
+        &self,
+        term_name: String,
+        compiled_entry: Vec<u8>,
+    ) -> Result<TerminfoStatus, SshError> {
+        validate_term_name(&term_name)?;
+        validate_compiled_entry(&compiled_entry)?;
+        if self.exec_status(&check_command(&term_name), &[]).await? == 0 {
+            return Ok(TerminfoStatus::AlreadyPresent);
+        }
+        match self
+            .exec_status(&install_command(&term_name), &compiled_entry)
+            .await?
+        {
+            0 => Ok(TerminfoStatus::Installed),
+            code => Err(SshError::RemoteCommandFailed(format!(
+                "installing terminfo entry {term_name} failed (exit status {code})"
+            ))),
+        }
+    }
+
+    /// install_terminfo with the entry bundled in this library for
+    /// `term_name` (currently "fressh"). Fails with InvalidArgument for
+    /// other names.
+    pub async fn install_bundled_terminfo(
+        &self,
+        term_name: String,
+    ) -> Result<TerminfoStatus, SshError> {
+        let entry = bundled_entry(&term_name)?;
+        self.install_terminfo(term_name, entry.to_vec()).await
+    }
+
     pub async fn disconnect(&self) -> Result<(), SshError> {
+        if let Ok(mut tasks) = self.background_tasks.lock() {
+            for task in tasks.drain(..) {
+                task.abort();
+            }
+        }
+
         // TODO: Check if we need to close all these if we are about to disconnect?
         let sessions: Vec<Arc<ShellSession>> = {
             let map = self.shells.lock().await;
@@ -368,7 +745,32 @@ impl SshConnection {
 
 #[uniffi::export(async_runtime = "tokio")]
 pub async fn connect(options: ConnectOptions) -> Result<Arc<SshConnection>, SshError> {
+    // Dropping connect_inner aborts the TCP race and drops the handshake or
+    // auth in progress; the signal also reaches the transport and the host
+    // key check, which live on in russh's session task.
+    let (abort_tx, abort_rx) = watch::channel(false);
+    let token = options.abort_token.clone();
+    tokio::select! {
+        biased;
+        _ = async {
+            match token {
+                Some(t) => t.aborted().await,
+                None => std::future::pending().await,
+            }
+        } => {
+            let _ = abort_tx.send(true);
+            Err(SshError::Cancelled)
+        }
+        res = connect_inner(options, abort_rx) => res,
+    }
+}
+
+async fn connect_inner(
+    options: ConnectOptions,
+    abort: watch::Receiver<bool>,
+) -> Result<Arc<SshConnection>, SshError> {
     let started_at_ms = now_ms();
+    let rekey_policy = RekeyPolicy::from_limits(options.rekey_limits.as_ref())?;
     let details = ConnectionDetails {
         host: options.connection_details.host.clone(),
         port: options.connection_details.port,
@@ -376,34 +778,72 @@ pub async fn connect(options: ConnectOptions) -> Result<Arc<SshConnection>, SshE
         security: options.connection_details.security.clone(),
     };
 
+    let progress = Arc::new(ProgressReporter::new(
+        options.on_connection_progress_callback.clone(),
+    ));
+
     // TCP
-    let addr = format!("{}:{}", details.host, details.port);
-    let socket = tokio::net::TcpStream::connect(&addr).await?;
+    let candidates = happy_eyeballs::resolve_candidates(&details.host, details.port).await?;
+    progress.emit(SshConnectionProgressEvent::DnsResolved {
+        candidates: candidates.iter().map(|a| a.to_string()).collect(),
+    });
+    let (socket, chosen) = happy_eyeballs::race(&candidates).await?;
     let local_port = socket.local_addr()?.port();
+    progress.emit(SshConnectionProgressEvent::TcpConnected {
+        chosen: chosen.to_string(),
+    });
 
-    let tcp_established_at_ms = now_ms();
-    if let Some(sl) = options.on_connection_progress_callback.as_ref() {
-        sl.on_change(SshConnectionProgressEvent::TcpConnected);
+    let mut preferred = Preferred::default();
+    if let Some(pref) = options.compression {
+        preferred.compression = Cow::Borrowed(pref.algorithms());
     }
-    let cfg = Arc::new(Config::default());
+    let cfg = Arc::new(Config {
+        limits: rekey_policy.russh_backstop(),
+        preferred,
+        ..Default::default()
+    });
     let remote_ip = socket.peer_addr().ok().map(|a| a.ip().to_string());
+    let metrics = Arc::new(ConnectionMetrics::default());
+    let last_rekey = Arc::new(Mutex::new(RekeyMark::now(&metrics)));
+    let transcript = Arc::new(Mutex::new(HandshakeTranscript::default()));
+    let auth_banner = Arc::new(Mutex::new(None));
     let mut handle: ClientHandle<NoopHandler> = russh::client::connect_stream(
         cfg,
-        socket,
+        MeteredStream::new(socket, metrics.clone(), transcript.clone())
+            .with_abort(connect_aborted(abort.clone())),
         NoopHandler {
             on_server_key_callback: options.on_server_key_callback.clone(),
+            on_auth_banner_callback: options.on_auth_banner_callback.clone(),
             host: options.connection_details.host.clone(),
             port: options.connection_details.port,
             remote_ip,
+            auth_banner: auth_banner.clone(),
+            progress: progress.clone(),
+            abort,
+            metrics: metrics.clone(),
+            last_rekey: last_rekey.clone(),
+            on_connection_event_callback: options.on_connection_event_callback.clone(),
         },
     )
     .await?;
-    let ssh_handshake_at_ms = now_ms();
-    if let Some(sl) = options.on_connection_progress_callback.as_ref() {
-        sl.on_change(SshConnectionProgressEvent::SshHandshake);
+    let (negotiated_algorithms, server_identification) = {
+        let t = transcript.lock().unwrap_or_else(|p| p.into_inner());
+        (t.negotiated(), t.server_id.clone())
+    };
+    if let Some(algs) = negotiated_algorithms.as_ref() {
+        metrics.set_compression(
+            &algs.compression_client_to_server,
+            &algs.compression_server_to_client,
+        );
     }
+    progress.emit(SshConnectionProgressEvent::SshHandshake);
+
+    progress.emit(SshConnectionProgressEvent::Authenticating);
     let auth_result = match &details.security {
         Security::Password { password } => {
+            progress.emit(SshConnectionProgressEvent::AuthAttempt {
+                method: "password".into(),
+            });
             handle
                 .authenticate_password(details.username.clone(), password.clone())
                 .await?
@@ -414,6 +854,9 @@ pub async fn connect(options: ConnectOptions) -> Result<Arc<SshConnection>, SshE
             // Normalize and parse using shared helper so RN-validated keys match runtime parsing.
             let (_canonical, parsed) = normalize_openssh_ed25519_seed_key(private_key_content)?;
             let pk_with_hash = PrivateKeyWithHashAlg::new(Arc::new(parsed), None);
+            progress.emit(SshConnectionProgressEvent::AuthAttempt {
+                method: "publickey".into(),
+            });
             handle
                 .authenticate_publickey(details.username.clone(), pk_with_hash)
                 .await?
@@ -422,6 +865,9 @@ pub async fn connect(options: ConnectOptions) -> Result<Arc<SshConnection>, SshE
     if !matches!(auth_result, russh::client::AuthResult::Success) {
         return Err(auth_result.into());
     }
+    progress.emit(SshConnectionProgressEvent::Authenticated);
+    progress.emit(SshConnectionProgressEvent::Connected);
+    let progress_timings = progress.timings();
 
     let connection_id = format!(
         "{}@{}:{}:{}",
@@ -432,18 +878,310 @@ pub async fn connect(options: ConnectOptions) -> Result<Arc<SshConnection>, SshE
             connection_id,
             connection_details: details,
             created_at_ms: started_at_ms,
-            connected_at_ms: now_ms(),
-            progress_timings: SshConnectionInfoProgressTimings {
-                tcp_established_at_ms,
-                ssh_handshake_at_ms,
-            },
+            connected_at_ms: progress_timings.connected_at_ms,
+            progress_timings,
+            server_identification,
+            auth_banner: auth_banner
+                .lock()
+                .unwrap_or_else(|p| p.into_inner())
+                .clone(),
+            negotiated_algorithms,
         },
         client_handle: AsyncMutex::new(handle),
         shells: AsyncMutex::new(HashMap::new()),
         self_weak: AsyncMutex::new(Weak::new()),
         on_disconnected_callback: options.on_disconnected_callback.clone(),
+        last_rekey,
+        metrics,
+        rekey_policy,
+        background_tasks: Mutex::new(Vec::new()),
     });
     // Initialize weak self reference.
     *conn.self_weak.lock().await = Arc::downgrade(&conn);
+
+    let interval_ms = options
+        .metrics_interval_ms
+        .map(u64::from)
+        .unwrap_or(DEFAULT_METRICS_INTERVAL_MS);
+    // Without a callback nobody sees the round-trip time, so don't probe.
+    let monitor = options
+        .on_metrics_callback
+        .clone()
+        .map(|cb| spawn_connection_monitor(Arc::downgrade(&conn), interval_ms, cb));
+    let rekey = spawn_rekey_policy(Arc::downgrade(&conn));
+    if let Ok(mut tasks) = conn.background_tasks.lock() {
+        tasks.extend(monitor);
+        tasks.push(rekey);
+    }
     Ok(conn)
 }
+
+// Internal helpers (not exported via UniFFI)
+impl SshConnection {
+    /// Time one request/reply exchange with the server. `None` if there was
+    /// no reply within RTT_PROBE_TIMEOUT_MS; the probe holds the client handle,
+    /// so it must not wait on a stalled server for long.
+    pub(crate) async fn probe_round_trip(&self) -> Result<Option<f64>, SshError> {
+        let probe = async {
+            let h = self.client_handle.lock().await;
+            let started = std::time::Instant::now();
+            round_trip(&h).await?;
+            Ok::<_, SshError>(started.elapsed().as_secs_f64() * 1000.0)
+        };
+        let timeout = std::time::Duration::from_millis(RTT_PROBE_TIMEOUT_MS);
+        let Ok(rtt_ms) = tokio::time::timeout(timeout, probe).await else {
+            return Ok(None);
+        };
+        let rtt_ms = rtt_ms?;
+        self.metrics.record_rtt(rtt_ms);
+        Ok(Some(rtt_ms))
+    }
+
+    /// Drop a closed shell from `shells`. Only the first close of a channel
+    /// finds it there, so `channels_open` counts each channel once.
+    pub(crate) async fn forget_shell(&self, channel_id: u32) {
+        if self.shells.lock().await.remove(&channel_id).is_some() {
+            self.metrics.channel_closed();
+        }
+    }
+
+    /// Run `command` without a PTY, feed it `stdin`, and return its exit
+    /// status. Output is discarded.
+    pub(crate) async fn exec_status(&self, command: &str, stdin: &[u8]) -> Result<u32, SshError> {
+        let mut ch = {
+            let h = self.client_handle.lock().await;
+            h.channel_open_session().await?
+        };
+        self.metrics.channel_opened();
+        let result = async {
+            ch.exec(true, command).await?;
+            if !stdin.is_empty() {
+                ch.data(stdin).await?;
+                self.metrics.payload_sent(stdin.len());
+            }
+            ch.eof().await?;
+            let mut exit_status = None;
+            loop {
+                match ch.wait().await {
+                    Some(ChannelMsg::ExitStatus { exit_status: code }) => exit_status = Some(code),
+                    Some(ChannelMsg::Data { data })
+                    | Some(ChannelMsg::ExtendedData { data, .. }) => {
+                        self.metrics.payload_received(data.len());
+                    }
+                    Some(ChannelMsg::Close) | None => break,
+                    Some(_) => {}
+                }
+            }
+            exit_status.ok_or_else(|| SshError::Russh(format!("no exit status from {command:?}")))
+        }
+        .await;
+        self.metrics.channel_closed();
+        result
+    }
+
+    /// Start a re-exchange and wait for it. NoopHandler::kex_done counts and
+    /// reports it, as it does for the ones the server or russh start.
+    pub(crate) async fn rekey_internal(&self) -> Result<(), SshError> {
+        let rekey = async {
+            let h = self.client_handle.lock().await;
+            h.rekey_soon().await?;
+            // russh holds back further handle requests while a key exchange
+            // is in flight, so this reply arrives only once the new keys are
+            // in use.
+            round_trip(&h).await
+        };
+        let timeout = std::time::Duration::from_millis(REKEY_TIMEOUT_MS);
+        tokio::time::timeout(timeout, rekey).await.map_err(|_| {
+            SshError::Russh(format!(
+                "rekey did not complete within {REKEY_TIMEOUT_MS}ms"
+            ))
+        })?
+    }
+
+    fn rekey_due(&self) -> bool {
+        let Ok(mark) = self.last_rekey.lock() else {
+            return false;
+        };
+        let sent = self.metrics.bytes_sent.load(Ordering::Relaxed) - mark.bytes_sent;
+        let received = self.metrics.bytes_received.load(Ordering::Relaxed) - mark.bytes_received;
+        sent >= self.rekey_policy.write_bytes
+            || received >= self.rekey_policy.read_bytes
+            || mark.at.elapsed() >= self.rekey_policy.time
+    }
+}
+
+/// Send `env` requests and wait for the replies, which arrive in request order.
+/// Returns the names the server refused.
+async fn request_env(
+    ch: &mut russh::Channel<client::Msg>,
+    env: &[(String, String)],
+) -> Result<Vec<String>, SshError> {
+    for (name, value) in env {
+        ch.set_env(true, name.as_str(), value.as_str()).await?;
+    }
+    let mut rejected = Vec::new();
+    for (name, _) in env {
+        loop {
+            match ch.wait().await {
+                Some(ChannelMsg::Success) => break,
+                Some(ChannelMsg::Failure) => {
+                    rejected.push(name.clone());
+                    break;
+                }
+                Some(ChannelMsg::Close) | None => return Err(SshError::Disconnected),
+                Some(_) => {}
+            }
+        }
+    }
+    Ok(rejected)
+}
+
+/// Wait for the server to answer one global request.
+///
+/// russh swallows the replies to `keepalive@openssh.com`, so we send a
+/// `cancel-tcpip-forward` for a forward that never existed instead. The server
+/// must answer it (RFC 4254 §4) and the request has no effect; a failure reply
+/// is the expected outcome.
+async fn round_trip(h: &ClientHandle<NoopHandler>) -> Result<(), SshError> {
+    match h.cancel_tcpip_forward("", 0).await {
+        Ok(()) | Err(russh::Error::RequestDenied) => Ok(()),
+        Err(e) => Err(e.into()),
+    }
+}
+
+// The connection enforces the configured limits itself, so they can be
+// checked (and rejected) up front. russh's limits stay in place above ours as
+// a backstop; its byte limits are capped at 1 GiB.
+pub(crate) static DEFAULT_REKEY_BYTES_LIMIT: u64 = 512 * 1024 * 1024; // 512MiB
+pub(crate) static MAX_REKEY_BYTES_LIMIT: u64 = 768 * 1024 * 1024; // 768MiB
+pub(crate) static DEFAULT_REKEY_TIME_MS: u64 = 60 * 60 * 1000; // 1h
+static RUSSH_REKEY_BYTES_BACKSTOP: usize = 1 << 30;
+static RUSSH_REKEY_TIME_GRACE_MS: u64 = 30_000;
+static REKEY_POLICY_CHECK_MS: u64 = 1_000;
+// A re-exchange still running by then is given up on, releasing the client
+// handle; the session itself may still finish it.
+static REKEY_TIMEOUT_MS: u64 = 30_000;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct RekeyPolicy {
+    write_bytes: u64,
+    read_bytes: u64,
+    time: std::time::Duration,
+}
+
+impl RekeyPolicy {
+    fn from_limits(limits: Option<&RekeyLimits>) -> Result<Self, SshError> {
+        let bytes = |name: &str, v: Option<u64>| match v {
+            None => Ok(DEFAULT_REKEY_BYTES_LIMIT),
+            Some(v) if (1..=MAX_REKEY_BYTES_LIMIT).contains(&v) => Ok(v),
+            Some(v) => Err(SshError::InvalidArgument(format!(
+                "rekey_limits.{name} must be 1..={MAX_REKEY_BYTES_LIMIT}, got {v}"
+            ))),
+        };
+        let time_ms = match limits.and_then(|l| l.time_ms) {
+            None => DEFAULT_REKEY_TIME_MS,
+            Some(v) if v >= REKEY_POLICY_CHECK_MS => v,
+            Some(v) => {
+                return Err(SshError::InvalidArgument(format!(
+                    "rekey_limits.time_ms must be at least {REKEY_POLICY_CHECK_MS}, got {v}"
+                )))
+            }
+        };
+        Ok(Self {
+            write_bytes: bytes("write_bytes", limits.and_then(|l| l.write_bytes))?,
+            read_bytes: bytes("read_bytes", limits.and_then(|l| l.read_bytes))?,
+            time: std::time::Duration::from_millis(time_ms),
+        })
+    }
+
+    fn russh_backstop(&self) -> russh::Limits {
+        russh::Limits::new(
+            RUSSH_REKEY_BYTES_BACKSTOP,
+            RUSSH_REKEY_BYTES_BACKSTOP,
+            self.time + std::time::Duration::from_millis(RUSSH_REKEY_TIME_GRACE_MS),
+        )
+    }
+}
+
+/// Transport position at the last completed key exchange.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct RekeyMark {
+    at: std::time::Instant,
+    bytes_sent: u64,
+    bytes_received: u64,
+}
+
+impl RekeyMark {
+    fn now(metrics: &ConnectionMetrics) -> Self {
+        Self {
+            at: std::time::Instant::now(),
+            bytes_sent: metrics.bytes_sent.load(Ordering::Relaxed),
+            bytes_received: metrics.bytes_received.load(Ordering::Relaxed),
+        }
+    }
+}
+
+fn spawn_rekey_policy(conn: Weak<SshConnection>) -> tokio::task::JoinHandle<()> {
+    tokio::spawn(async move {
+        let period = std::time::Duration::from_millis(REKEY_POLICY_CHECK_MS);
+        let mut ticker = tokio::time::interval(period);
+        ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        loop {
+            ticker.tick().await;
+            let Some(conn) = conn.upgrade() else { break };
+            if conn.rekey_due() && conn.rekey_internal().await.is_err() {
+                break;
+            }
+        }
+    })
+}
+
+fn spawn_connection_monitor(
+    conn: Weak<SshConnection>,
+    interval_ms: u64,
+    on_metrics_callback: Arc<dyn ConnectionMetricsCallback>,
+) -> tokio::task::JoinHandle<()> {
+    tokio::spawn(async move {
+        let period = std::time::Duration::from_millis(interval_ms.max(1));
+        let mut ticker = tokio::time::interval(period);
+        ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        loop {
+            ticker.tick().await;
+            let Some(conn) = conn.upgrade() else { break };
+            if conn.probe_round_trip().await.is_err() {
+                // Transport is gone; nothing left to measure.
+                break;
+            }
+            on_metrics_callback.on_change(conn.metrics.snapshot());
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rekey_limits_outside_the_policy_range_are_rejected() {
+        let limits = |write_bytes, time_ms| RekeyLimits {
+            write_bytes,
+            read_bytes: None,
+            time_ms,
+        };
+        let policy = RekeyPolicy::from_limits(Some(&limits(Some(1024), Some(1_000)))).unwrap();
+        assert_eq!(policy.write_bytes, 1024);
+        assert_eq!(policy.read_bytes, DEFAULT_REKEY_BYTES_LIMIT);
+        assert_eq!(policy.time, std::time::Duration::from_secs(1));
+
+        for bad in [
+            limits(Some(0), None),
+            limits(Some(MAX_REKEY_BYTES_LIMIT + 1), None),
+            limits(None, Some(999)),
+        ] {
+            assert!(matches!(
+                RekeyPolicy::from_limits(Some(&bad)),
+                Err(SshError::InvalidArgument(_))
+            ));
+        }
+    }
+}
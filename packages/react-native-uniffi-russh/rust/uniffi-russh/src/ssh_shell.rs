@@ -1,20 +1,18 @@
 use std::{
-    collections::HashMap,
-    sync::{
-        atomic::{AtomicU64, AtomicUsize, Ordering},
-        Arc, Mutex,
-    },
-    time::Duration,
+    collections::{BTreeMap, HashMap},
+    sync::{Arc, Mutex},
 };
 
-use bytes::Bytes;
-
 use crate::{
+    output_buffer::{self, OutputBuffer},
+    recording::{CastEvent, CastHeader, RecordingOptions, RecordingStats},
+    shell_integration::CommandRecord,
     ssh_connection::SshConnection,
+    terminal_emulator::TerminalEmulator,
     utils::{now_ms, SshError},
 };
 use russh::{self, client};
-use tokio::sync::{broadcast, Mutex as AsyncMutex};
+use tokio::sync::Mutex as AsyncMutex;
 
 // Note: russh accepts an untyped string for the terminal type
 #[derive(Debug, Clone, Copy, PartialEq, uniffi::Enum)]
@@ -41,6 +39,43 @@ impl TerminalType {
     }
 }
 
+/// Signals deliverable with the SSH `signal` channel request (RFC 4254 §6.9).
+#[derive(Debug, Clone, Copy, PartialEq, uniffi::Enum)]
+pub enum ShellSignal {
+    Abrt,
+    Alrm,
+    Fpe,
+    Hup,
+    Ill,
+    Int,
+    Kill,
+    Pipe,
+    Quit,
+    Segv,
+    Term,
+    Usr1,
+    Usr2,
+}
+impl ShellSignal {
+    fn as_russh(self) -> russh::Sig {
+        match self {
+            ShellSignal::Abrt => russh::Sig::ABRT,
+            ShellSignal::Alrm => russh::Sig::ALRM,
+            ShellSignal::Fpe => russh::Sig::FPE,
+            ShellSignal::Hup => russh::Sig::HUP,
+            ShellSignal::Ill => russh::Sig::ILL,
+            ShellSignal::Int => russh::Sig::INT,
+            ShellSignal::Kill => russh::Sig::KILL,
+            ShellSignal::Pipe => russh::Sig::PIPE,
+            ShellSignal::Quit => russh::Sig::QUIT,
+            ShellSignal::Segv => russh::Sig::SEGV,
+            ShellSignal::Term => russh::Sig::TERM,
+            ShellSignal::Usr1 => russh::Sig::USR1,
+            ShellSignal::Usr2 => russh::Sig::Custom("USR2".to_string()),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, uniffi::Enum)]
 pub enum StreamKind {
     Stdout,
@@ -61,10 +96,29 @@ pub struct DroppedRange {
     pub to_seq: u64,
 }
 
+/// Decoded output for listeners with `text` set. A character split across
+/// chunks is held back until it is complete.
+#[derive(Debug, Clone, PartialEq, uniffi::Record)]
+pub struct TerminalText {
+    pub seq: u64, // last chunk whose bytes are (at least partly) included
+    pub t_ms: f64,
+    pub stream: StreamKind,
+    pub text: String,
+}
+
+/// What text listeners get for bytes that aren't valid UTF-8.
+#[derive(Debug, Clone, Copy, PartialEq, uniffi::Enum)]
+pub enum InvalidUtf8 {
+    Replace, // U+FFFD per invalid sequence
+    Escape,  // "\xNN" per byte
+}
+
 #[derive(Debug, Clone, PartialEq, uniffi::Enum)]
 pub enum ShellEvent {
     Chunk(TerminalChunk),
+    Text(TerminalText), // in place of Chunk for text listeners
     Dropped { from_seq: u64, to_seq: u64 },
+    Command(CommandRecord), // a shell integration mark updated this record
 }
 
 #[uniffi::export(with_foreign)]
@@ -97,6 +151,131 @@ pub struct StartShellOptions {
     pub terminal_size: Option<TerminalSize>,
     pub terminal_pixel_size: Option<TerminalPixelSize>,
     pub on_closed_callback: Option<Arc<dyn ShellClosedCallback>>,
+    #[uniffi(default = None)]
+    pub env: Option<HashMap<String, String>>, // sent as `env` requests; overrides forwarded locale
+    #[uniffi(default = None)]
+    pub locale: Option<String>, // BCP-47 tag, e.g. "en-US"; sent as LANG/LC_* (see shell_env)
+    #[uniffi(default = None)]
+    pub command: Option<ShellCommand>, // None: the user's login shell
+    #[uniffi(default = None)]
+    pub allocate_pty: Option<bool>, // default true; false for scripting/automation
+    #[uniffi(default = None)]
+    pub term_name: Option<String>, // any TERM (e.g., "xterm-kitty"); overrides `term`
+    #[uniffi(default = None)]
+    pub buffer_policy: Option<BufferPolicy>,
+    #[uniffi(default = None)]
+    pub on_channel_pause_callback: Option<Arc<dyn ChannelPauseCallback>>,
+}
+
+/// Ring buffer and delivery settings. Unset fields keep the default (at
+/// start) or the current value (`set_buffer_policy`).
+#[derive(Debug, Clone, Default, PartialEq, uniffi::Record)]
+pub struct BufferPolicy {
+    #[uniffi(default = None)]
+    pub ring_bytes: Option<u64>, // replay history kept; see DEFAULT_SHELL_RING_BUFFER_CAPACITY
+    #[uniffi(default = None)]
+    pub max_chunk_bytes: Option<u32>,
+    #[uniffi(default = None)]
+    pub broadcast_chunks: Option<u32>, // start only; lag allowed before listeners drop
+    #[uniffi(default = None)]
+    pub coalesce_ms: Option<u32>, // for listeners that don't set their own
+}
+
+impl BufferPolicy {
+    pub(crate) fn validate(&self) -> Result<(), SshError> {
+        if self.max_chunk_bytes == Some(0) || self.broadcast_chunks == Some(0) {
+            return Err(SshError::InvalidArgument(
+                "max_chunk_bytes and broadcast_chunks must be > 0".to_string(),
+            ));
+        }
+        if let Some(n) = self.ring_bytes {
+            if usize::try_from(n).is_err() {
+                return Err(SshError::InvalidArgument(format!(
+                    "ring_bytes {n} exceeds the address space"
+                )));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Ring buffer bytes held by all sessions and players, against the budget
+/// set with `set_memory_budget`.
+#[derive(Debug, Clone, PartialEq, uniffi::Record)]
+pub struct MemoryUsage {
+    pub used_bytes: u64,
+    pub budget_bytes: Option<u64>,
+}
+
+/// Cap the ring buffers of all sessions at `bytes` in total (None: no cap).
+/// Over the cap, the chunks appended first go first, whichever session or
+/// player holds them.
+#[uniffi::export]
+pub fn set_memory_budget(bytes: Option<u64>) -> Result<(), SshError> {
+    output_buffer::set_memory_budget(bytes)
+}
+
+#[uniffi::export]
+pub fn memory_usage() -> MemoryUsage {
+    output_buffer::memory_usage()
+}
+
+/// What to run in the PTY instead of the login shell.
+#[derive(Debug, Clone, PartialEq, uniffi::Enum)]
+pub enum ShellCommand {
+    Exec { command: String },   // e.g., "tmux attach", "htop"
+    Subsystem { name: String }, // e.g., a console server's subsystem
+}
+
+// Sent as LANG when the locale has no POSIX equivalent (no region, or a
+// numeric one like "es-419"), so remote tools still default to UTF-8.
+pub(crate) static DEFAULT_FORWARDED_LANG: &str = "C.UTF-8";
+
+// Set alongside LANG: a LANG/LC_* left over in the server's login files would
+// otherwise still pick the character set.
+static FORWARDED_LC_VARS: &[&str] = &["LC_CTYPE"];
+
+/// Environment to request for a new shell, sorted by name. With `locale`,
+/// LANG and LC_CTYPE are set to its POSIX form; `env` entries win over them.
+pub(crate) fn shell_env(
+    locale: Option<&str>,
+    explicit: Option<&HashMap<String, String>>,
+) -> Vec<(String, String)> {
+    let mut env = BTreeMap::new();
+    if let Some(locale) = locale {
+        let lang = posix_locale(locale).unwrap_or_else(|| DEFAULT_FORWARDED_LANG.to_string());
+        for name in FORWARDED_LC_VARS {
+            env.insert(name.to_string(), lang.clone());
+        }
+        env.insert("LANG".to_string(), lang);
+    }
+    if let Some(explicit) = explicit {
+        env.extend(explicit.iter().map(|(k, v)| (k.clone(), v.clone())));
+    }
+    env.into_iter().collect()
+}
+
+/// Map a BCP-47 tag to a UTF-8 POSIX locale name: "en-US" -> "en_US.UTF-8",
+/// "zh-Hans-CN" -> "zh_CN.UTF-8". Script and extension subtags are dropped.
+/// None without a two-letter region.
+fn posix_locale(tag: &str) -> Option<String> {
+    let mut subtags = tag.split(['-', '_']);
+    let language = subtags.next()?;
+    if !(2..=3).contains(&language.len()) || !language.bytes().all(|b| b.is_ascii_alphabetic()) {
+        return None;
+    }
+    let mut next = subtags.next()?;
+    if next.len() == 4 && next.bytes().all(|b| b.is_ascii_alphabetic()) {
+        next = subtags.next()?; // script
+    }
+    if next.len() != 2 || !next.bytes().all(|b| b.is_ascii_alphabetic()) {
+        return None;
+    }
+    Some(format!(
+        "{}_{}.UTF-8",
+        language.to_ascii_lowercase(),
+        next.to_ascii_uppercase()
+    ))
 }
 
 #[uniffi::export(with_foreign)]
@@ -104,6 +283,14 @@ pub trait ShellClosedCallback: Send + Sync {
     fn on_change(&self, channel_id: u32);
 }
 
+/// Called with true when a lossless listener falls behind and the channel
+/// stops granting the server window, and with false when it resumes. Only
+/// this channel waits; the rest of the connection keeps running.
+#[uniffi::export(with_foreign)]
+pub trait ChannelPauseCallback: Send + Sync {
+    fn on_change(&self, paused: bool);
+}
+
 /// Snapshot of shell session info for property-like access in TS.
 #[derive(Debug, Clone, PartialEq, uniffi::Record)]
 pub struct ShellSessionInfo {
@@ -111,13 +298,33 @@ pub struct ShellSessionInfo {
     pub created_at_ms: f64,
     pub connected_at_ms: f64,
     pub term: TerminalType,
+    pub term_name: String, // TERM sent with the PTY request
     pub connection_id: String,
+    // Names of `env` variables the server refused (sshd only accepts what its
+    // AcceptEnv lists).
+    pub rejected_env: Vec<String>,
+    pub terminal_geometry: TerminalGeometry, // as last sent to the server
+    pub command: Option<ShellCommand>,
+    // false: raw byte stream with no terminal semantics (term, modes and
+    // geometry were not sent).
+    pub pty: bool,
+}
+
+/// PTY size in characters and pixels (0 = unknown, per RFC 4254).
+#[derive(Debug, Clone, Copy, PartialEq, uniffi::Record)]
+pub struct TerminalGeometry {
+    pub cols: u32,
+    pub rows: u32,
+    pub pixel_width: u32,
+    pub pixel_height: u32,
 }
 
 #[derive(uniffi::Object)]
 pub struct ShellSession {
     pub info: ShellSessionInfo,
     pub on_closed_callback: Option<Arc<dyn ShellClosedCallback>>,
+    // Current PTY size; get_info() reports it in place of the initial one.
+    pub(crate) terminal_geometry: Mutex<TerminalGeometry>,
 
     // Weak backref; avoid retain cycle.
     pub(crate) parent: std::sync::Weak<SshConnection>,
@@ -126,22 +333,8 @@ pub struct ShellSession {
     // We keep the reader task to allow cancellation on close.
     pub(crate) reader_task: tokio::task::JoinHandle<()>,
 
-    // Ring buffer
-    pub(crate) ring: Arc<Mutex<std::collections::VecDeque<Arc<Chunk>>>>,
-    pub(crate) ring_bytes_capacity: Arc<AtomicUsize>,
-    pub(crate) used_bytes: Arc<Mutex<usize>>,
-    pub(crate) dropped_bytes_total: Arc<AtomicU64>,
-    pub(crate) head_seq: Arc<AtomicU64>,
-    pub(crate) tail_seq: Arc<AtomicU64>,
-
-    // Live broadcast
-    pub(crate) sender: broadcast::Sender<Arc<Chunk>>,
-
-    // Listener tasks management
-    pub(crate) listener_tasks: Arc<Mutex<HashMap<u64, tokio::task::JoinHandle<()>>>>,
-    pub(crate) next_listener_id: AtomicU64,
-    pub(crate) coalesce_ms: AtomicU64,
-    pub(crate) rt_handle: tokio::runtime::Handle,
+    // Ring buffer, listeners and attachments; shared with the reader task.
+    pub(crate) buffer: Arc<OutputBuffer>,
 }
 
 #[derive(Debug, Clone, PartialEq, uniffi::Enum)]
@@ -151,12 +344,25 @@ pub enum Cursor {
     Seq { seq: u64 },         // start from the given sequence number
     TimeMs { t_ms: f64 },     // start from the given time in milliseconds
     Live,                     // start from the live stream
+    // Line cursors may start inside a chunk; its TerminalChunk then holds the rest.
+    TailLines { lines: u64 }, // start of the last N lines; a trailing partial line counts
+    // Start of line N, counted from 0 (see BufferStats head_line). A line
+    // before head_line starts at the head, with `dropped` set.
+    Line { line: u64 },
 }
 
 #[derive(Debug, Clone, PartialEq, uniffi::Record)]
 pub struct ListenerOptions {
     pub cursor: Cursor,
     pub coalesce_ms: Option<u32>, // coalesce chunks into this many milliseconds
+    // Never skip output: while this listener is more than max_lag_bytes
+    // behind, the channel is paused (see ChannelPauseCallback).
+    #[uniffi(default = None)]
+    pub lossless: Option<bool>,
+    #[uniffi(default = None)]
+    pub max_lag_bytes: Option<u64>, // see DEFAULT_LOSSLESS_MAX_LAG_BYTES
+    #[uniffi(default = None)]
+    pub text: Option<InvalidUtf8>, // deliver ShellEvent::Text, decoded per stream
 }
 
 #[derive(Debug, Clone, PartialEq, uniffi::Record)]
@@ -166,6 +372,16 @@ pub struct BufferReadResult {
     pub dropped: Option<DroppedRange>,
 }
 
+/// Buffered output as plain text (see `ShellSession::read_text`).
+#[derive(Debug, Clone, PartialEq, uniffi::Record)]
+pub struct TextReadResult {
+    pub text: String,
+    pub from_seq: Option<u64>, // source chunks; None if there were none
+    pub to_seq: Option<u64>,
+    pub next_seq: u64,
+    pub dropped: Option<DroppedRange>,
+}
+
 #[derive(Debug, Clone, PartialEq, uniffi::Record)]
 pub struct BufferStats {
     pub ring_bytes_count: u64,
@@ -175,16 +391,41 @@ pub struct BufferStats {
     pub dropped_bytes_total: u64,
 
     pub chunks_count: u64,
+    // Lines by newline count: head_line is where the ring starts, tail_line
+    // the (possibly empty) line being written.
+    pub head_line: u64,
+    pub tail_line: u64,
+    pub channel_paused: bool, // server output paused for a lossless listener
+    pub channel_paused_ms_total: f64,
+}
+
+#[derive(Debug, Clone, PartialEq, uniffi::Record)]
+pub struct SearchOptions {
+    #[uniffi(default = None)]
+    pub case_insensitive: Option<bool>,
+    #[uniffi(default = None)]
+    pub literal: Option<bool>, // match `pattern` as plain text, not a regex
+    #[uniffi(default = None)]
+    pub max_matches: Option<u32>, // default DEFAULT_SEARCH_MAX_MATCHES
+    #[uniffi(default = None)]
+    pub newest_first: Option<bool>, // keep and return the most recent matches
+}
+
+/// A match in the ring buffer. Offsets are into the chunks' raw bytes, so
+/// `Cursor::Seq { seq }` replays from the chunk where it starts.
+#[derive(Debug, Clone, PartialEq, uniffi::Record)]
+pub struct SearchMatch {
+    pub seq: u64,
+    pub offset: u64,
+    pub end_seq: u64,
+    pub end_offset: u64, // exclusive
+    pub text: String,    // matched text, escapes stripped
 }
 
-// Internal chunk type kept in ring/broadcast
-#[derive(Debug)]
-pub(crate) struct Chunk {
-    // TODO: This is very similar to TerminalChunk. The only difference is the bytes field
-    seq: u64,
-    t_ms: f64,
-    stream: StreamKind,
-    bytes: Bytes,
+#[derive(Debug, Clone, PartialEq, uniffi::Record)]
+pub struct SearchResult {
+    pub matches: Vec<SearchMatch>,
+    pub truncated: bool, // more than max_matches matched
 }
 
 /// ---------- Methods ----------
@@ -221,18 +462,87 @@ pub(crate) static DEFAULT_SHELL_RING_BUFFER_CAPACITY: usize = 2 * 1024 * 1024; /
 // loss impact; larger values reduce per-message overhead.
 pub(crate) static DEFAULT_MAX_CHUNK_SIZE: usize = 16 * 1024; // 16KB
 
+// How far a lossless listener may fall behind before its channel is paused.
+// Keep it below the ring size, or eviction can still drop output.
+pub(crate) static DEFAULT_LOSSLESS_MAX_LAG_BYTES: u64 = 256 * 1024; // 256KB
+
 pub(crate) static DEFAULT_READ_BUFFER_MAX_BYTES: u64 = 512 * 1024; // 512KB
 
+pub(crate) static DEFAULT_SEARCH_MAX_MATCHES: u32 = 1000;
+
 #[uniffi::export(async_runtime = "tokio")]
 impl ShellSession {
     pub fn get_info(&self) -> ShellSessionInfo {
-        self.info.clone()
+        let mut info = self.info.clone();
+        if let Ok(geometry) = self.terminal_geometry.lock() {
+            info.terminal_geometry = *geometry;
+        }
+        info
+    }
+
+    /// Tell the server the terminal changed size (`window-change`), e.g. on
+    /// rotation or when the keyboard opens.
+    pub async fn resize(
+        &self,
+        cols: u32,
+        rows: u32,
+        pixel_width: u32,
+        pixel_height: u32,
+    ) -> Result<(), SshError> {
+        if !self.info.pty {
+            return Err(SshError::Unsupported(
+                "resize needs a PTY; this session has none".to_string(),
+            ));
+        }
+        let w = self.writer.lock().await;
+        w.window_change(cols, rows, pixel_width, pixel_height)
+            .await?;
+        if let Some(emulator) = self.buffer.emulator() {
+            emulator.resize(cols, rows);
+        }
+        self.buffer.record(CastEvent::Resize {
+            t_ms: now_ms(),
+            cols,
+            rows,
+        });
+        if let Ok(mut geometry) = self.terminal_geometry.lock() {
+            *geometry = TerminalGeometry {
+                cols,
+                rows,
+                pixel_width,
+                pixel_height,
+            };
+        }
+        Ok(())
     }
 
     /// Send bytes to the active shell (stdin).
     pub async fn send_data(&self, data: Vec<u8>) -> Result<(), SshError> {
         let w = self.writer.lock().await;
         w.data(&data[..]).await?;
+        if let Some(parent) = self.parent.upgrade() {
+            parent.metrics.payload_sent(data.len());
+        }
+        self.buffer.record(CastEvent::Input {
+            t_ms: now_ms(),
+            bytes: data,
+        });
+        Ok(())
+    }
+
+    /// Deliver a signal to the remote process. Works in raw mode and for
+    /// signals with no control character (KILL); the server may ignore it.
+    pub async fn send_signal(&self, signal: ShellSignal) -> Result<(), SshError> {
+        let w = self.writer.lock().await;
+        w.signal(signal.as_russh()).await?;
+        Ok(())
+    }
+
+    /// Send a BREAK (RFC 4335) of `duration_ms`, as console servers expect
+    /// for serial consoles. Servers without break support ignore it.
+    pub async fn send_break(&self, duration_ms: u32) -> Result<(), SshError> {
+        let w = self.writer.lock().await;
+        w.send_break(duration_ms).await?;
         Ok(())
     }
 
@@ -243,117 +553,42 @@ impl ShellSession {
 
     /// Buffer statistics snapshot.
     pub fn buffer_stats(&self) -> BufferStats {
-        let used = *self.used_bytes.lock().unwrap_or_else(|p| p.into_inner()) as u64;
-        let chunks_count = match self.ring.lock() {
-            Ok(q) => q.len() as u64,
-            Err(p) => p.into_inner().len() as u64,
-        };
-        BufferStats {
-            ring_bytes_count: self.ring_bytes_capacity.load(Ordering::Relaxed) as u64,
-            used_bytes: used,
-            chunks_count,
-            head_seq: self.head_seq.load(Ordering::Relaxed),
-            tail_seq: self.tail_seq.load(Ordering::Relaxed),
-            dropped_bytes_total: self.dropped_bytes_total.load(Ordering::Relaxed),
-        }
+        self.buffer.buffer_stats()
     }
 
     /// Current next sequence number.
     pub fn current_seq(&self) -> u64 {
-        self.tail_seq.load(Ordering::Relaxed).saturating_add(1)
+        self.buffer.current_seq()
     }
 
     /// Read the ring buffer from a cursor.
     pub fn read_buffer(&self, cursor: Cursor, max_bytes: Option<u64>) -> BufferReadResult {
-        let max_total = max_bytes.unwrap_or(DEFAULT_READ_BUFFER_MAX_BYTES) as usize;
-        let mut out_chunks: Vec<TerminalChunk> = Vec::new();
-        let mut dropped: Option<DroppedRange> = None;
-        let head_seq_now = self.head_seq.load(Ordering::Relaxed);
-        let tail_seq_now = self.tail_seq.load(Ordering::Relaxed);
-
-        // Lock ring to determine start and collect arcs, then drop lock.
-        let (_start_idx_unused, _start_seq, arcs): (usize, u64, Vec<Arc<Chunk>>) = {
-            let ring = match self.ring.lock() {
-                Ok(g) => g,
-                Err(p) => p.into_inner(),
-            };
-            let (start_seq, idx) = match cursor {
-                Cursor::Head => (head_seq_now, 0usize),
-                Cursor::Seq { seq: mut s } => {
-                    if s < head_seq_now {
-                        dropped = Some(DroppedRange {
-                            from_seq: s,
-                            to_seq: head_seq_now - 1,
-                        });
-                        s = head_seq_now;
-                    }
-                    let idx = s.saturating_sub(head_seq_now) as usize;
-                    (s, idx.min(ring.len()))
-                }
-                Cursor::TimeMs { t_ms: t } => {
-                    // linear scan to find first chunk with t_ms >= t
-                    let mut idx = 0usize;
-                    let mut s = head_seq_now;
-                    for (i, ch) in ring.iter().enumerate() {
-                        if ch.t_ms >= t {
-                            idx = i;
-                            s = ch.seq;
-                            break;
-                        }
-                    }
-                    (s, idx)
-                }
-                Cursor::TailBytes { bytes: n } => {
-                    // Walk from tail backwards until approx n bytes, then forward.
-                    let mut bytes = 0usize;
-                    let mut idx = ring.len();
-                    for i in (0..ring.len()).rev() {
-                        let b = ring[i].bytes.len();
-                        if bytes >= n as usize {
-                            idx = i + 1;
-                            break;
-                        }
-                        bytes += b;
-                        idx = i;
-                    }
-                    let s = if idx < ring.len() {
-                        ring[idx].seq
-                    } else {
-                        tail_seq_now.saturating_add(1)
-                    };
-                    (s, idx)
-                }
-                Cursor::Live => (tail_seq_now.saturating_add(1), ring.len()),
-            };
-            let arcs: Vec<Arc<Chunk>> = ring.iter().skip(idx).cloned().collect();
-            (idx, start_seq, arcs)
-        };
+        self.buffer.read_buffer(cursor, max_bytes)
+    }
 
-        // Build output respecting max_bytes
-        let mut total = 0usize;
-        for ch in arcs {
-            let len = ch.bytes.len();
-            if total + len > max_total {
-                break;
-            }
-            out_chunks.push(TerminalChunk {
-                seq: ch.seq,
-                t_ms: ch.t_ms,
-                stream: ch.stream,
-                bytes: ch.bytes.clone().to_vec(),
-            });
-            total += len;
-        }
-        let next_seq = if let Some(last) = out_chunks.last() {
-            last.seq + 1
-        } else {
-            tail_seq_now.saturating_add(1)
-        };
-        BufferReadResult {
-            chunks: out_chunks,
-            next_seq,
-            dropped,
-        }
+    /// Like `read_buffer`, but as text with escape sequences removed and
+    /// carriage returns, backspaces and line erases applied, so overwritten
+    /// progress output shows only its final state.
+    pub fn read_text(&self, cursor: Cursor, max_bytes: Option<u64>) -> TextReadResult {
+        self.buffer.read_text(cursor, max_bytes)
+    }
+
+    /// Search the buffered output with escape sequences and control characters
+    /// (other than LF and TAB) removed, so matches may span chunks and
+    /// styling. A CR not followed by LF counts as a line break. `^`/`$` match
+    /// at line boundaries.
+    pub async fn search(
+        &self,
+        pattern: String,
+        opts: SearchOptions,
+    ) -> Result<SearchResult, SshError> {
+        self.buffer.search(pattern, opts).await
+    }
+
+    /// Change the buffer policy. Shrinking `ring_bytes` evicts the oldest
+    /// chunks right away; `broadcast_chunks` can't change after start.
+    pub fn set_buffer_policy(&self, policy: BufferPolicy) -> Result<(), SshError> {
+        self.buffer.set_policy(&policy)
     }
 
     /// Add a listener with optional replay and live follow.
@@ -362,89 +597,52 @@ impl ShellSession {
         listener: Arc<dyn ShellListener>,
         opts: ListenerOptions,
     ) -> Result<u64, SshError> {
-        // Snapshot for replay; emit from task to avoid re-entrant callbacks during FFI.
-        let replay = self.read_buffer(opts.cursor.clone(), None);
-        let mut rx = self.sender.subscribe();
-        let id = self.next_listener_id.fetch_add(1, Ordering::Relaxed);
-        let default_coalesce_ms = self.coalesce_ms.load(Ordering::Relaxed) as u32;
-        let coalesce_ms = opts.coalesce_ms.unwrap_or(default_coalesce_ms);
-
-        let rt = self.rt_handle.clone();
-        let handle = rt.spawn(async move {
-            // Emit replay first
-            if let Some(dr) = replay.dropped.as_ref() {
-                listener.on_event(ShellEvent::Dropped { from_seq: dr.from_seq, to_seq: dr.to_seq });
-            }
-            for ch in replay.chunks.into_iter() {
-                listener.on_event(ShellEvent::Chunk(ch));
-            }
+        self.buffer.add_listener(listener, opts)
+    }
 
-            let mut last_seq_seen: u64 = replay.next_seq.saturating_sub(1);
-            let mut acc: Vec<u8> = Vec::new();
-            let mut acc_stream: Option<StreamKind>;
-            let mut acc_last_seq: u64;
-            let mut acc_last_t: f64;
-            let window = Duration::from_millis(coalesce_ms as u64);
-            let mut pending_drop_from: Option<u64> = None;
-
-            loop {
-                // First receive an item
-                let first = match rx.recv().await {
-                    Ok(c) => c,
-                    Err(broadcast::error::RecvError::Lagged(_n)) => { pending_drop_from = Some(last_seq_seen.saturating_add(1)); continue; }
-                    Err(broadcast::error::RecvError::Closed) => break,
-                };
-                if let Some(from) = pending_drop_from.take() {
-                    if from <= first.seq.saturating_sub(1) {
-                        listener.on_event(ShellEvent::Dropped { from_seq: from, to_seq: first.seq - 1 });
-                    }
-                }
-                // Start accumulating
-                acc.clear(); acc_stream = Some(first.stream); acc_last_seq = first.seq; acc_last_t = first.t_ms; acc.extend_from_slice(&first.bytes);
-                last_seq_seen = first.seq;
-
-                // Drain within window while same stream
-                let mut deadline = tokio::time::Instant::now() + window;
-                loop {
-                    let timeout = tokio::time::sleep_until(deadline);
-                    tokio::pin!(timeout);
-                    tokio::select! {
-                        _ = &mut timeout => break,
-                        msg = rx.recv() => {
-                            match msg {
-                                Ok(c) => {
-                                    if Some(c.stream) == acc_stream { acc.extend_from_slice(&c.bytes); acc_last_seq = c.seq; acc_last_t = c.t_ms; last_seq_seen = c.seq; }
-                                    else { // flush and start new
-                                        let chunk = TerminalChunk { seq: acc_last_seq, t_ms: acc_last_t, stream: acc_stream.unwrap_or(StreamKind::Stdout), bytes: std::mem::take(&mut acc) };
-                                        listener.on_event(ShellEvent::Chunk(chunk));
-                                        acc_stream = Some(c.stream); acc_last_seq = c.seq; acc_last_t = c.t_ms; acc.extend_from_slice(&c.bytes); last_seq_seen = c.seq;
-                                        deadline = tokio::time::Instant::now() + window;
-                                    }
-                                }
-                                Err(broadcast::error::RecvError::Lagged(_n)) => { pending_drop_from = Some(last_seq_seen.saturating_add(1)); break; }
-                                Err(broadcast::error::RecvError::Closed) => { break; }
-                            }
-                        }
-                    }
-                }
-                if let Some(s) = acc_stream.take() {
-                    let chunk = TerminalChunk { seq: acc_last_seq, t_ms: acc_last_t, stream: s, bytes: std::mem::take(&mut acc) };
-                    listener.on_event(ShellEvent::Chunk(chunk));
-                }
-            }
-        });
-        if let Ok(mut map) = self.listener_tasks.lock() {
-            map.insert(id, handle);
-        }
-        Ok(id)
+    /// Keep a screen model of this session's output. Starts from what the
+    /// ring buffer still holds; calling it again returns the same emulator.
+    pub fn attach_emulator(&self, scrollback_lines: Option<u32>) -> Arc<TerminalEmulator> {
+        let geometry = *self
+            .terminal_geometry
+            .lock()
+            .unwrap_or_else(|p| p.into_inner());
+        self.buffer
+            .attach_emulator(geometry.cols, geometry.rows, scrollback_lines)
+    }
+
+    pub fn detach_emulator(&self) {
+        self.buffer.detach_emulator();
+    }
+
+    /// Record output from now on to an asciicast v2 file at `path`
+    /// (created or truncated), with resizes and optionally input.
+    pub fn start_recording(&self, path: String, opts: RecordingOptions) -> Result<(), SshError> {
+        let geometry = self.get_info().terminal_geometry;
+        let header = CastHeader {
+            cols: geometry.cols,
+            rows: geometry.rows,
+            started_at_ms: now_ms(),
+            term: self.info.term_name.clone(),
+            title: opts.title,
+        };
+        self.buffer
+            .start_recording(path, header, opts.record_input.unwrap_or(false))
+    }
+
+    /// Finish the recording; None if none was running.
+    pub async fn stop_recording(&self) -> Result<Option<RecordingStats>, SshError> {
+        self.buffer.stop_recording().await
+    }
+
+    /// Commands seen via shell integration marks (OSC 133), oldest first.
+    /// Needs a shell set up to emit them, e.g. via its prompt.
+    pub fn commands(&self) -> Vec<CommandRecord> {
+        self.buffer.commands()
     }
 
     pub fn remove_listener(&self, id: u64) {
-        if let Ok(mut map) = self.listener_tasks.lock() {
-            if let Some(h) = map.remove(&id) {
-                h.abort();
-            }
-        }
+        self.buffer.remove_listener(id);
     }
 }
 
@@ -454,95 +652,61 @@ impl ShellSession {
         // Try to close channel gracefully; ignore error.
         self.writer.lock().await.close().await.ok();
         self.reader_task.abort();
+        // Dropping the recorder finishes its file.
+        self.buffer.abandon_recording();
         if let Some(sl) = self.on_closed_callback.as_ref() {
             sl.on_change(self.info.channel_id);
         }
         // Clear parent's notion of active shell if it matches us.
         if let Some(parent) = self.parent.upgrade() {
-            parent.shells.lock().await.remove(&self.info.channel_id);
+            parent.forget_shell(self.info.channel_id).await;
         }
         Ok(())
     }
+}
 
-    // /// This was on the public interface but I don't think we need it
-    // pub async fn set_buffer_policy(&self, ring_bytes: Option<u64>, coalesce_ms: Option<u32>) {
-    //     if let Some(rb) = ring_bytes { self.ring_bytes_capacity.store(rb as usize, Ordering::Relaxed); self.evict_if_needed(); }
-    //     if let Some(cm) = coalesce_ms { self.default_coalesce_ms.store(cm as u64, Ordering::Relaxed); }
-    // }
-
-    // fn evict_if_needed(&self) {
-    //     let cap = self.ring_bytes_capacity.load(Ordering::Relaxed);
-    //     let mut ring = match self.ring.lock() { Ok(g) => g, Err(p) => p.into_inner() };
-    //     let mut used = self.used_bytes.lock().unwrap_or_else(|p| p.into_inner());
-    //     while *used > cap {
-    //         if let Some(front) = ring.pop_front() {
-    //             *used -= front.bytes.len();
-    //             self.dropped_bytes_total.fetch_add(front.bytes.len() as u64, Ordering::Relaxed);
-    //             self.head_seq.store(front.seq.saturating_add(1), Ordering::Relaxed);
-    //         } else { break; }
-    //     }
-    // }
-}
-
-#[allow(clippy::too_many_arguments)]
-pub(crate) fn append_and_broadcast(
-    data: &[u8],
-    stream: StreamKind,
-    ring: &Arc<Mutex<std::collections::VecDeque<Arc<Chunk>>>>,
-    used_bytes: &Arc<Mutex<usize>>,
-    ring_bytes_capacity: &Arc<AtomicUsize>,
-    dropped_bytes_total: &Arc<AtomicU64>,
-    head_seq: &Arc<AtomicU64>,
-    tail_seq: &Arc<AtomicU64>,
-    next_seq: &Arc<AtomicU64>,
-    sender: &broadcast::Sender<Arc<Chunk>>,
-    max_chunk: usize,
-) {
-    let mut offset = 0usize;
-    while offset < data.len() {
-        let end = (offset + max_chunk).min(data.len());
-        let slice = &data[offset..end];
-        let seq = next_seq.fetch_add(1, Ordering::Relaxed);
-        let t_ms = now_ms();
-        let chunk = Arc::new(Chunk {
-            seq,
-            t_ms,
-            stream,
-            bytes: Bytes::copy_from_slice(slice),
-        });
-        // push to ring
-        {
-            let mut q = match ring.lock() {
-                Ok(g) => g,
-                Err(p) => p.into_inner(),
-            };
-            q.push_back(chunk.clone());
-        }
-        {
-            let mut used = used_bytes.lock().unwrap_or_else(|p| p.into_inner());
-            *used += slice.len();
-            tail_seq.store(seq, Ordering::Relaxed);
-            // evict if needed
-            let cap = ring_bytes_capacity.load(Ordering::Relaxed);
-            if *used > cap {
-                let mut q = match ring.lock() {
-                    Ok(g) => g,
-                    Err(p) => p.into_inner(),
-                };
-                while *used > cap {
-                    if let Some(front) = q.pop_front() {
-                        *used -= front.bytes.len();
-                        dropped_bytes_total.fetch_add(front.bytes.len() as u64, Ordering::Relaxed);
-                        head_seq.store(front.seq.saturating_add(1), Ordering::Relaxed);
-                    } else {
-                        break;
-                    }
-                }
-            }
-        }
-        // broadcast
-        let _ = sender.send(chunk);
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn maps_bcp47_tags_to_posix_locales() {
+        assert_eq!(posix_locale("en-US").as_deref(), Some("en_US.UTF-8"));
+        assert_eq!(posix_locale("pt_br").as_deref(), Some("pt_BR.UTF-8"));
+        assert_eq!(posix_locale("zh-Hans-CN").as_deref(), Some("zh_CN.UTF-8"));
+        assert_eq!(
+            posix_locale("de-DE-u-co-phonebk").as_deref(),
+            Some("de_DE.UTF-8")
+        );
+        assert_eq!(posix_locale("fr"), None);
+        assert_eq!(posix_locale("es-419"), None);
+        assert_eq!(posix_locale(""), None);
+    }
 
-        offset = end;
+    #[test]
+    fn forwards_the_locale_as_lang_and_lc_ctype() {
+        let pair = |k: &str, v: &str| (k.to_string(), v.to_string());
+        assert_eq!(
+            shell_env(Some("pt-BR"), None),
+            [pair("LANG", "pt_BR.UTF-8"), pair("LC_CTYPE", "pt_BR.UTF-8")]
+        );
+        assert_eq!(
+            shell_env(Some("fr"), None),
+            [pair("LANG", "C.UTF-8"), pair("LC_CTYPE", "C.UTF-8")]
+        );
+        // Explicit entries win, and the rest are sent as given.
+        let explicit = HashMap::from([
+            ("LC_CTYPE".to_string(), "C".to_string()),
+            ("EDITOR".to_string(), "vi".to_string()),
+        ]);
+        assert_eq!(
+            shell_env(Some("en-US"), Some(&explicit)),
+            [
+                pair("EDITOR", "vi"),
+                pair("LANG", "en_US.UTF-8"),
+                pair("LC_CTYPE", "C"),
+            ]
+        );
+        assert!(shell_env(None, None).is_empty());
     }
 }
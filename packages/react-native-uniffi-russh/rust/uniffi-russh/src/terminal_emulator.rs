@@ -0,0 +1,1453 @@
+use std::{
+    collections::VecDeque,
+    sync::{Arc, Mutex},
+};
+
+use unicode_width::UnicodeWidthChar;
+use vte::{Params, Parser, Perform};
+
+// Lines kept above the main screen when none is requested.
+pub(crate) static DEFAULT_EMULATOR_SCROLLBACK_LINES: u32 = 10_000;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, uniffi::Enum)]
+pub enum TerminalColor {
+    #[default]
+    Default,
+    Indexed {
+        index: u8,
+    }, // 0-15 ANSI, 16-255 xterm palette
+    Rgb {
+        r: u8,
+        g: u8,
+        b: u8,
+    },
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, uniffi::Record)]
+pub struct CellStyle {
+    pub fg: TerminalColor,
+    pub bg: TerminalColor,
+    pub bold: bool,
+    pub dim: bool,
+    pub italic: bool,
+    pub underline: bool,
+    pub blink: bool,
+    pub inverse: bool,
+    pub hidden: bool,
+    pub strikethrough: bool,
+}
+
+/// Consecutive cells sharing a style. Wide characters count as two cells.
+#[derive(Debug, Clone, PartialEq, uniffi::Record)]
+pub struct TextRun {
+    pub col: u32,
+    pub cells: u32,
+    pub text: String,
+    pub style: CellStyle,
+}
+
+#[derive(Debug, Clone, PartialEq, uniffi::Record)]
+pub struct ScreenLine {
+    pub runs: Vec<TextRun>, // trailing unstyled blanks are omitted
+    pub wrapped: bool,      // continues on the next line (soft wrap)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, uniffi::Enum)]
+pub enum CursorShape {
+    Block,
+    Underline,
+    Bar,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, uniffi::Record)]
+pub struct CursorState {
+    pub row: u32,
+    pub col: u32,
+    pub visible: bool,
+    pub shape: CursorShape,
+    pub blinking: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, uniffi::Enum)]
+pub enum MouseTracking {
+    Off,
+    X10,         // ?9: presses only
+    Normal,      // ?1000: presses and releases
+    ButtonEvent, // ?1002: plus motion while a button is held
+    AnyEvent,    // ?1003: plus all motion
+}
+
+/// Modes a renderer needs to encode input the way the remote program expects.
+#[derive(Debug, Clone, Copy, PartialEq, uniffi::Record)]
+pub struct ScreenModes {
+    pub application_cursor_keys: bool,
+    pub application_keypad: bool,
+    pub bracketed_paste: bool,
+    pub mouse_tracking: MouseTracking,
+    pub mouse_sgr: bool,
+    pub focus_events: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, uniffi::Record)]
+pub struct ScreenSnapshot {
+    pub cols: u32,
+    pub rows: u32,
+    pub lines: Vec<ScreenLine>, // one per row, top to bottom
+    pub cursor: CursorState,
+    pub alternate_screen: bool,
+    pub title: String,
+    pub modes: ScreenModes,
+    pub scrollback_len: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, uniffi::Record)]
+pub struct DamagedLine {
+    pub row: u32,
+    pub line: ScreenLine,
+}
+
+/// What changed since the previous `take_damage`.
+#[derive(Debug, Clone, PartialEq, uniffi::Record)]
+pub struct ScreenDamage {
+    // Repaint everything (resize, screen switch, reset); `lines` then has
+    // every row.
+    pub full: bool,
+    pub lines: Vec<DamagedLine>,
+    pub cursor: CursorState,
+    pub alternate_screen: bool,
+    pub title: String,
+    pub modes: ScreenModes,
+    pub scrolled_into_scrollback: u64, // lines pushed into scrollback meanwhile
+}
+
+/// Terminal emulator (xterm subset) keeping a cell grid, alternate screen and
+/// scrollback. Attach one to a shell with `ShellSession::attach_emulator`, or
+/// feed it bytes directly (e.g. from a recording).
+#[derive(uniffi::Object)]
+pub struct TerminalEmulator {
+    inner: Mutex<Inner>,
+}
+
+struct Inner {
+    parser: Parser,
+    term: Term,
+    fed_seq: u64, // last shell chunk applied
+}
+
+#[uniffi::export]
+impl TerminalEmulator {
+    #[uniffi::constructor]
+    pub fn new(cols: u32, rows: u32, scrollback_lines: Option<u32>) -> Arc<Self> {
+        let scrollback = scrollback_lines.unwrap_or(DEFAULT_EMULATOR_SCROLLBACK_LINES);
+        Arc::new(Self {
+            inner: Mutex::new(Inner {
+                parser: Parser::new(),
+                term: Term::new(cols as usize, rows as usize, scrollback as usize),
+                fed_seq: 0,
+            }),
+        })
+    }
+
+    /// Parse terminal output.
+    pub fn feed(&self, bytes: Vec<u8>) {
+        self.lock().advance(&bytes);
+    }
+
+    /// Resize the grid. Lines are truncated or padded, not reflowed.
+    pub fn resize(&self, cols: u32, rows: u32) {
+        self.lock().term.resize(cols as usize, rows as usize);
+    }
+
+    pub fn snapshot(&self) -> ScreenSnapshot {
+        let inner = self.lock();
+        let t = &inner.term;
+        ScreenSnapshot {
+            cols: t.cols as u32,
+            rows: t.rows as u32,
+            lines: t.grid().iter().map(Line::to_screen_line).collect(),
+            cursor: t.cursor_state(),
+            alternate_screen: t.alt_active,
+            title: t.title.clone(),
+            modes: t.modes(),
+            scrollback_len: t.scrollback.len() as u64,
+        }
+    }
+
+    /// Rows changed since the last call (or since creation), then reset.
+    pub fn take_damage(&self) -> ScreenDamage {
+        let mut inner = self.lock();
+        let t = &mut inner.term;
+        let full = std::mem::take(&mut t.full_damage);
+        let lines = t
+            .grid()
+            .iter()
+            .enumerate()
+            .filter(|(row, _)| full || t.dirty[*row])
+            .map(|(row, line)| DamagedLine {
+                row: row as u32,
+                line: line.to_screen_line(),
+            })
+            .collect();
+        t.dirty.iter_mut().for_each(|d| *d = false);
+        ScreenDamage {
+            full,
+            lines,
+            cursor: t.cursor_state(),
+            alternate_screen: t.alt_active,
+            title: t.title.clone(),
+            modes: t.modes(),
+            scrolled_into_scrollback: std::mem::take(&mut t.scrolled),
+        }
+    }
+
+    pub fn scrollback_len(&self) -> u64 {
+        self.lock().term.scrollback.len() as u64
+    }
+
+    /// Scrollback lines from `start` (0 = oldest).
+    pub fn scrollback_lines(&self, start: u64, count: u32) -> Vec<ScreenLine> {
+        let inner = self.lock();
+        inner
+            .term
+            .scrollback
+            .iter()
+            .skip(start as usize)
+            .take(count as usize)
+            .map(Line::to_screen_line)
+            .collect()
+    }
+
+    /// Replies the output asked the terminal for (cursor position, device
+    /// attributes). Only send them to the server if nothing else (e.g. a
+    /// webview terminal) already answers.
+    pub fn take_responses(&self) -> Vec<u8> {
+        std::mem::take(&mut self.lock().term.responses)
+    }
+}
+
+impl TerminalEmulator {
+    fn lock(&self) -> std::sync::MutexGuard<'_, Inner> {
+        self.inner.lock().unwrap_or_else(|p| p.into_inner())
+    }
+
+    /// Apply a shell chunk unless it was already applied (attach replays the
+    /// ring while the reader keeps feeding).
+    pub(crate) fn feed_chunk(&self, seq: u64, bytes: &[u8]) {
+        let mut inner = self.lock();
+        if seq > inner.fed_seq {
+            inner.fed_seq = seq;
+            inner.advance(bytes);
+        }
+    }
+
+    /// Catch up on `chunks` (oldest first). `attach` runs first, with
+    /// `feed_chunk` blocked, so chunks read after it are fed exactly once.
+    pub(crate) fn replay(
+        &self,
+        attach: impl FnOnce(),
+        chunks: impl FnOnce() -> Vec<(u64, Vec<u8>)>,
+    ) {
+        let mut inner = self.lock();
+        attach();
+        for (seq, bytes) in chunks() {
+            if seq > inner.fed_seq {
+                inner.fed_seq = seq;
+                inner.advance(&bytes);
+            }
+        }
+    }
+}
+
+impl Inner {
+    fn advance(&mut self, bytes: &[u8]) {
+        self.parser.advance(&mut self.term, bytes);
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Cell {
+    ch: char,
+    width: u8, // 0: right half of a wide character
+    style: CellStyle,
+}
+
+impl Cell {
+    fn blank(style: CellStyle) -> Self {
+        Cell {
+            ch: ' ',
+            width: 1,
+            style,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Line {
+    cells: Vec<Cell>,
+    combining: Vec<(usize, String)>, // marks attached to a cell, by column
+    wrapped: bool,
+}
+
+impl Line {
+    fn new(cols: usize, style: CellStyle) -> Self {
+        Line {
+            cells: vec![Cell::blank(style); cols],
+            combining: Vec::new(),
+            wrapped: false,
+        }
+    }
+
+    fn clear_combining(&mut self, from: usize, to: usize) {
+        self.combining.retain(|(col, _)| *col < from || *col >= to);
+    }
+
+    fn to_screen_line(&self) -> ScreenLine {
+        let end = self
+            .cells
+            .iter()
+            .rposition(|c| c.ch != ' ' || c.style != CellStyle::default())
+            .map_or(0, |i| i + 1);
+        let mut runs: Vec<TextRun> = Vec::new();
+        for (col, cell) in self.cells[..end].iter().enumerate() {
+            let run = match runs.last_mut() {
+                Some(run) if run.style == cell.style => run,
+                _ => {
+                    runs.push(TextRun {
+                        col: col as u32,
+                        cells: 0,
+                        text: String::new(),
+                        style: cell.style,
+                    });
+                    runs.last_mut().unwrap()
+                }
+            };
+            run.cells += 1;
+            if cell.width > 0 {
+                run.text.push(cell.ch);
+                for (_, marks) in self.combining.iter().filter(|(c, _)| *c == col) {
+                    run.text.push_str(marks);
+                }
+            }
+        }
+        ScreenLine {
+            runs,
+            wrapped: self.wrapped,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Charset {
+    Ascii,
+    DecSpecialGraphics,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct CursorPos {
+    row: usize,
+    col: usize,
+    pending_wrap: bool, // printed in the last column; wrap before the next char
+    style: CellStyle,
+    charsets: [Charset; 2],
+    shift_out: bool, // G1 active
+    origin_mode: bool,
+}
+
+struct Term {
+    cols: usize,
+    rows: usize,
+    main: Vec<Line>,
+    alt: Vec<Line>,
+    alt_active: bool,
+    scrollback: VecDeque<Line>,
+    scrollback_limit: usize,
+    cursor: CursorPos,
+    saved_main: Option<CursorPos>,
+    saved_alt: Option<CursorPos>,
+    scroll_top: usize,
+    scroll_bottom: usize, // inclusive
+    tabs: Vec<bool>,
+    autowrap: bool,
+    insert_mode: bool,
+    newline_mode: bool,
+    cursor_visible: bool,
+    cursor_shape: CursorShape,
+    cursor_blinking: bool,
+    application_cursor_keys: bool,
+    application_keypad: bool,
+    bracketed_paste: bool,
+    mouse_tracking: MouseTracking,
+    mouse_sgr: bool,
+    focus_events: bool,
+    title: String,
+    last_printed: Option<char>, // for REP
+    dirty: Vec<bool>,
+    full_damage: bool,
+    scrolled: u64,
+    responses: Vec<u8>,
+}
+
+impl Term {
+    fn new(cols: usize, rows: usize, scrollback_limit: usize) -> Self {
+        let (cols, rows) = (cols.max(1), rows.max(1));
+        Term {
+            cols,
+            rows,
+            main: vec![Line::new(cols, CellStyle::default()); rows],
+            alt: vec![Line::new(cols, CellStyle::default()); rows],
+            alt_active: false,
+            scrollback: VecDeque::new(),
+            scrollback_limit,
+            cursor: CursorPos {
+                row: 0,
+                col: 0,
+                pending_wrap: false,
+                style: CellStyle::default(),
+                charsets: [Charset::Ascii; 2],
+                shift_out: false,
+                origin_mode: false,
+            },
+            saved_main: None,
+            saved_alt: None,
+            scroll_top: 0,
+            scroll_bottom: rows - 1,
+            tabs: default_tabs(cols),
+            autowrap: true,
+            insert_mode: false,
+            newline_mode: false,
+            cursor_visible: true,
+            cursor_shape: CursorShape::Block,
+            cursor_blinking: true,
+            application_cursor_keys: false,
+            application_keypad: false,
+            bracketed_paste: false,
+            mouse_tracking: MouseTracking::Off,
+            mouse_sgr: false,
+            focus_events: false,
+            title: String::new(),
+            last_printed: None,
+            dirty: vec![false; rows],
+            full_damage: true,
+            scrolled: 0,
+            responses: Vec::new(),
+        }
+    }
+
+    fn grid(&self) -> &Vec<Line> {
+        if self.alt_active {
+            &self.alt
+        } else {
+            &self.main
+        }
+    }
+
+    fn grid_mut(&mut self) -> &mut Vec<Line> {
+        if self.alt_active {
+            &mut self.alt
+        } else {
+            &mut self.main
+        }
+    }
+
+    fn cursor_state(&self) -> CursorState {
+        CursorState {
+            row: self.cursor.row as u32,
+            col: self.cursor.col as u32,
+            visible: self.cursor_visible,
+            shape: self.cursor_shape,
+            blinking: self.cursor_blinking,
+        }
+    }
+
+    fn modes(&self) -> ScreenModes {
+        ScreenModes {
+            application_cursor_keys: self.application_cursor_keys,
+            application_keypad: self.application_keypad,
+            bracketed_paste: self.bracketed_paste,
+            mouse_tracking: self.mouse_tracking,
+            mouse_sgr: self.mouse_sgr,
+            focus_events: self.focus_events,
+        }
+    }
+
+    // Erased cells keep the current background (xterm's back color erase).
+    fn erase_style(&self) -> CellStyle {
+        CellStyle {
+            bg: self.cursor.style.bg,
+            ..CellStyle::default()
+        }
+    }
+
+    fn blank_line(&self) -> Line {
+        Line::new(self.cols, self.erase_style())
+    }
+
+    fn touch(&mut self, row: usize) {
+        if let Some(d) = self.dirty.get_mut(row) {
+            *d = true;
+        }
+    }
+
+    fn touch_rows(&mut self, from: usize, to: usize) {
+        for row in from..=to.min(self.rows - 1) {
+            self.touch(row);
+        }
+    }
+
+    // ---- cursor ----
+
+    fn goto(&mut self, row: usize, col: usize) {
+        let (top, bottom) = if self.cursor.origin_mode {
+            (self.scroll_top, self.scroll_bottom)
+        } else {
+            (0, self.rows - 1)
+        };
+        self.cursor.row = (top + row).min(bottom);
+        self.cursor.col = col.min(self.cols - 1);
+        self.cursor.pending_wrap = false;
+    }
+
+    fn set_col(&mut self, col: usize) {
+        self.cursor.col = col.min(self.cols - 1);
+        self.cursor.pending_wrap = false;
+    }
+
+    fn move_up(&mut self, n: usize) {
+        let top = if self.cursor.row >= self.scroll_top {
+            self.scroll_top
+        } else {
+            0
+        };
+        self.cursor.row = self.cursor.row.saturating_sub(n).max(top);
+        self.cursor.pending_wrap = false;
+    }
+
+    fn move_down(&mut self, n: usize) {
+        let bottom = if self.cursor.row <= self.scroll_bottom {
+            self.scroll_bottom
+        } else {
+            self.rows - 1
+        };
+        self.cursor.row = (self.cursor.row + n).min(bottom);
+        self.cursor.pending_wrap = false;
+    }
+
+    fn carriage_return(&mut self) {
+        self.set_col(0);
+    }
+
+    fn linefeed(&mut self) {
+        if self.cursor.row == self.scroll_bottom {
+            self.scroll_up(1);
+        } else if self.cursor.row < self.rows - 1 {
+            self.cursor.row += 1;
+        }
+        self.cursor.pending_wrap = false;
+    }
+
+    fn reverse_index(&mut self) {
+        if self.cursor.row == self.scroll_top {
+            self.scroll_down(1);
+        } else if self.cursor.row > 0 {
+            self.cursor.row -= 1;
+        }
+        self.cursor.pending_wrap = false;
+    }
+
+    fn tab_forward(&mut self, n: usize) {
+        for _ in 0..n {
+            let next = (self.cursor.col + 1..self.cols).find(|c| self.tabs[*c]);
+            self.cursor.col = next.unwrap_or(self.cols - 1);
+        }
+        self.cursor.pending_wrap = false;
+    }
+
+    fn tab_backward(&mut self, n: usize) {
+        for _ in 0..n {
+            let prev = (0..self.cursor.col).rev().find(|c| self.tabs[*c]);
+            self.cursor.col = prev.unwrap_or(0);
+        }
+        self.cursor.pending_wrap = false;
+    }
+
+    fn save_cursor(&mut self) {
+        let saved = Some(self.cursor);
+        if self.alt_active {
+            self.saved_alt = saved;
+        } else {
+            self.saved_main = saved;
+        }
+    }
+
+    fn restore_cursor(&mut self) {
+        let saved = if self.alt_active {
+            self.saved_alt
+        } else {
+            self.saved_main
+        };
+        match saved {
+            Some(mut pos) => {
+                // The scroll region may have moved since the save; in origin
+                // mode the cursor must stay inside it.
+                pos.row = if pos.origin_mode {
+                    pos.row.clamp(self.scroll_top, self.scroll_bottom)
+                } else {
+                    pos.row.min(self.rows - 1)
+                };
+                pos.col = pos.col.min(self.cols - 1);
+                self.cursor = pos;
+            }
+            None => {
+                self.cursor.origin_mode = false;
+                self.goto(0, 0);
+            }
+        }
+    }
+
+    // ---- scrolling ----
+
+    fn scroll_up(&mut self, n: usize) {
+        let (top, bottom) = (self.scroll_top, self.scroll_bottom);
+        let n = n.min(bottom - top + 1);
+        let to_scrollback = !self.alt_active && top == 0;
+        for _ in 0..n {
+            let blank = self.blank_line();
+            let grid = self.grid_mut();
+            let line = grid.remove(top);
+            grid.insert(bottom, blank);
+            if to_scrollback && self.scrollback_limit > 0 {
+                if self.scrollback.len() == self.scrollback_limit {
+                    self.scrollback.pop_front();
+                }
+                self.scrollback.push_back(line);
+                self.scrolled += 1;
+            }
+        }
+        self.touch_rows(top, bottom);
+    }
+
+    fn scroll_down(&mut self, n: usize) {
+        let (top, bottom) = (self.scroll_top, self.scroll_bottom);
+        let n = n.min(bottom - top + 1);
+        for _ in 0..n {
+            let blank = self.blank_line();
+            let grid = self.grid_mut();
+            grid.remove(bottom);
+            grid.insert(top, blank);
+        }
+        self.touch_rows(top, bottom);
+    }
+
+    fn insert_lines(&mut self, n: usize) {
+        let row = self.cursor.row;
+        if row < self.scroll_top || row > self.scroll_bottom {
+            return;
+        }
+        let top = self.scroll_top;
+        self.scroll_top = row;
+        self.scroll_down(n);
+        self.scroll_top = top;
+        self.carriage_return();
+    }
+
+    fn delete_lines(&mut self, n: usize) {
+        let row = self.cursor.row;
+        if row < self.scroll_top || row > self.scroll_bottom {
+            return;
+        }
+        // Deleted lines never go to scrollback.
+        let (top, bottom) = (row, self.scroll_bottom);
+        let n = n.min(bottom - top + 1);
+        for _ in 0..n {
+            let blank = self.blank_line();
+            let grid = self.grid_mut();
+            grid.remove(top);
+            grid.insert(bottom, blank);
+        }
+        self.touch_rows(top, bottom);
+        self.carriage_return();
+    }
+
+    // ---- editing ----
+
+    /// Blank the other half of any wide character cut by writing `col`.
+    fn split_wide(&mut self, row: usize, col: usize) {
+        let erase = self.erase_style();
+        let cols = self.cols;
+        let line = &mut self.grid_mut()[row];
+        match line.cells[col].width {
+            0 if col > 0 => line.cells[col - 1] = Cell::blank(erase),
+            2 if col + 1 < cols => line.cells[col + 1] = Cell::blank(erase),
+            _ => {}
+        }
+    }
+
+    fn erase_cells(&mut self, row: usize, from: usize, to: usize) {
+        let to = to.min(self.cols);
+        if from >= to {
+            return;
+        }
+        self.split_wide(row, from);
+        self.split_wide(row, to - 1);
+        let erase = self.erase_style();
+        let line = &mut self.grid_mut()[row];
+        line.cells[from..to].fill(Cell::blank(erase));
+        line.clear_combining(from, to);
+        self.touch(row);
+    }
+
+    fn erase_in_line(&mut self, mode: u16) {
+        let (row, col) = (self.cursor.row, self.cursor.col);
+        match mode {
+            0 => self.erase_cells(row, col, self.cols),
+            1 => self.erase_cells(row, 0, col + 1),
+            2 => self.erase_cells(row, 0, self.cols),
+            _ => {}
+        }
+        if mode == 0 || mode == 2 {
+            self.grid_mut()[row].wrapped = false;
+        }
+    }
+
+    fn erase_in_display(&mut self, mode: u16) {
+        let row = self.cursor.row;
+        match mode {
+            0 => {
+                self.erase_in_line(0);
+                for r in row + 1..self.rows {
+                    self.erase_cells(r, 0, self.cols);
+                    self.grid_mut()[r].wrapped = false;
+                }
+            }
+            1 => {
+                for r in 0..row {
+                    self.erase_cells(r, 0, self.cols);
+                    self.grid_mut()[r].wrapped = false;
+                }
+                self.erase_in_line(1);
+            }
+            2 => {
+                for r in 0..self.rows {
+                    self.erase_cells(r, 0, self.cols);
+                    self.grid_mut()[r].wrapped = false;
+                }
+            }
+            3 => self.scrollback.clear(),
+            _ => {}
+        }
+    }
+
+    fn insert_blanks(&mut self, n: usize) {
+        let (row, col) = (self.cursor.row, self.cursor.col);
+        self.split_wide(row, col);
+        let n = n.min(self.cols - col);
+        let erase = self.erase_style();
+        let cols = self.cols;
+        let line = &mut self.grid_mut()[row];
+        line.cells[col..].rotate_right(n);
+        line.cells[col..col + n].fill(Cell::blank(erase));
+        line.clear_combining(col, cols);
+        if line.cells[cols - 1].width == 2 {
+            line.cells[cols - 1] = Cell::blank(erase);
+        }
+        self.cursor.pending_wrap = false;
+        self.touch(row);
+    }
+
+    fn delete_chars(&mut self, n: usize) {
+        let (row, col) = (self.cursor.row, self.cursor.col);
+        self.split_wide(row, col);
+        let n = n.min(self.cols - col);
+        if col + n < self.cols {
+            self.split_wide(row, col + n);
+        }
+        let erase = self.erase_style();
+        let cols = self.cols;
+        let line = &mut self.grid_mut()[row];
+        line.cells[col..].rotate_left(n);
+        line.cells[cols - n..].fill(Cell::blank(erase));
+        line.clear_combining(col, cols);
+        self.cursor.pending_wrap = false;
+        self.touch(row);
+    }
+
+    fn put_char(&mut self, c: char) {
+        let c = match self.cursor.charsets[self.cursor.shift_out as usize] {
+            Charset::DecSpecialGraphics => dec_special_graphics(c),
+            Charset::Ascii => c,
+        };
+        let width = c.width().unwrap_or(0);
+        if width == 0 {
+            self.put_combining(c);
+            return;
+        }
+        if self.cursor.pending_wrap && self.autowrap {
+            let row = self.cursor.row;
+            self.grid_mut()[row].wrapped = true;
+            self.carriage_return();
+            self.linefeed();
+        }
+        if width == 2 && self.cursor.col + 1 >= self.cols {
+            if self.cols < 2 {
+                return;
+            }
+            if self.autowrap {
+                // Doesn't fit: leave the last column blank and wrap.
+                let (row, col) = (self.cursor.row, self.cursor.col);
+                self.erase_cells(row, col, self.cols);
+                self.grid_mut()[row].wrapped = true;
+                self.carriage_return();
+                self.linefeed();
+            } else {
+                self.cursor.col = self.cols - 2;
+            }
+        }
+        let (row, col) = (self.cursor.row, self.cursor.col);
+        if self.insert_mode {
+            self.insert_blanks(width);
+        }
+        self.split_wide(row, col);
+        if width == 2 {
+            self.split_wide(row, col + 1);
+        }
+        let style = self.cursor.style;
+        let line = &mut self.grid_mut()[row];
+        line.clear_combining(col, col + width);
+        line.cells[col] = Cell {
+            ch: c,
+            width: width as u8,
+            style,
+        };
+        if width == 2 {
+            line.cells[col + 1] = Cell {
+                ch: ' ',
+                width: 0,
+                style,
+            };
+        }
+        self.touch(row);
+        self.last_printed = Some(c);
+        if col + width >= self.cols {
+            self.cursor.col = self.cols - 1;
+            self.cursor.pending_wrap = self.autowrap;
+        } else {
+            self.cursor.col = col + width;
+        }
+    }
+
+    fn put_combining(&mut self, c: char) {
+        let row = self.cursor.row;
+        let mut col = if self.cursor.pending_wrap {
+            self.cursor.col
+        } else if self.cursor.col > 0 {
+            self.cursor.col - 1
+        } else {
+            return;
+        };
+        let line = &mut self.grid_mut()[row];
+        if line.cells[col].width == 0 && col > 0 {
+            col -= 1;
+        }
+        match line.combining.iter_mut().find(|(c, _)| *c == col) {
+            Some((_, marks)) => marks.push(c),
+            None => line.combining.push((col, c.to_string())),
+        }
+        self.touch(row);
+    }
+
+    // ---- modes ----
+
+    fn set_alt_screen(&mut self, on: bool, save_cursor: bool, clear: bool) {
+        if on == self.alt_active {
+            return;
+        }
+        if on {
+            if save_cursor {
+                self.save_cursor();
+            }
+            self.alt_active = true;
+            if clear {
+                let blank = Line::new(self.cols, CellStyle::default());
+                self.alt.iter_mut().for_each(|l| *l = blank.clone());
+            }
+        } else {
+            self.alt_active = false;
+            if save_cursor {
+                self.restore_cursor();
+            }
+        }
+        self.full_damage = true;
+    }
+
+    fn set_private_mode(&mut self, mode: u16, on: bool) {
+        match mode {
+            1 => self.application_cursor_keys = on,
+            6 => {
+                self.cursor.origin_mode = on;
+                self.goto(0, 0);
+            }
+            7 => self.autowrap = on,
+            9 => self.set_mouse(MouseTracking::X10, on),
+            12 => self.cursor_blinking = on,
+            25 => self.cursor_visible = on,
+            47 | 1047 => {
+                if !on && self.alt_active && mode == 1047 {
+                    let blank = Line::new(self.cols, CellStyle::default());
+                    self.alt.iter_mut().for_each(|l| *l = blank.clone());
+                }
+                self.set_alt_screen(on, false, false);
+            }
+            66 => self.application_keypad = on,
+            1000 => self.set_mouse(MouseTracking::Normal, on),
+            1002 => self.set_mouse(MouseTracking::ButtonEvent, on),
+            1003 => self.set_mouse(MouseTracking::AnyEvent, on),
+            1004 => self.focus_events = on,
+            1006 => self.mouse_sgr = on,
+            1048 => {
+                if on {
+                    self.save_cursor()
+                } else {
+                    self.restore_cursor()
+                }
+            }
+            1049 => self.set_alt_screen(on, true, true),
+            2004 => self.bracketed_paste = on,
+            _ => {}
+        }
+    }
+
+    fn set_mouse(&mut self, tracking: MouseTracking, on: bool) {
+        if on {
+            self.mouse_tracking = tracking;
+        } else if self.mouse_tracking == tracking {
+            self.mouse_tracking = MouseTracking::Off;
+        }
+    }
+
+    fn set_scroll_region(&mut self, top: usize, bottom: usize) {
+        let bottom = if bottom == 0 {
+            self.rows
+        } else {
+            bottom.min(self.rows)
+        };
+        let top = top.max(1);
+        if top < bottom {
+            self.scroll_top = top - 1;
+            self.scroll_bottom = bottom - 1;
+            self.goto(0, 0);
+        }
+    }
+
+    fn sgr(&mut self, params: &Params) {
+        let groups: Vec<&[u16]> = params.iter().collect();
+        if groups.is_empty() {
+            self.cursor.style = CellStyle::default();
+            return;
+        }
+        let s = &mut self.cursor.style;
+        let mut i = 0;
+        while i < groups.len() {
+            let group = groups[i];
+            match group[0] {
+                0 => *s = CellStyle::default(),
+                1 => s.bold = true,
+                2 => s.dim = true,
+                3 => s.italic = true,
+                4 => s.underline = group.get(1) != Some(&0),
+                5 | 6 => s.blink = true,
+                7 => s.inverse = true,
+                8 => s.hidden = true,
+                9 => s.strikethrough = true,
+                21 => s.underline = true,
+                22 => {
+                    s.bold = false;
+                    s.dim = false;
+                }
+                23 => s.italic = false,
+                24 => s.underline = false,
+                25 => s.blink = false,
+                27 => s.inverse = false,
+                28 => s.hidden = false,
+                29 => s.strikethrough = false,
+                n @ 30..=37 => {
+                    s.fg = TerminalColor::Indexed {
+                        index: (n - 30) as u8,
+                    }
+                }
+                38 => s.fg = extended_color(&groups, &mut i),
+                39 => s.fg = TerminalColor::Default,
+                n @ 40..=47 => {
+                    s.bg = TerminalColor::Indexed {
+                        index: (n - 40) as u8,
+                    }
+                }
+                48 => s.bg = extended_color(&groups, &mut i),
+                49 => s.bg = TerminalColor::Default,
+                n @ 90..=97 => {
+                    s.fg = TerminalColor::Indexed {
+                        index: (n - 82) as u8,
+                    }
+                }
+                n @ 100..=107 => {
+                    s.bg = TerminalColor::Indexed {
+                        index: (n - 92) as u8,
+                    }
+                }
+                _ => {}
+            }
+            i += 1;
+        }
+    }
+
+    fn reset(&mut self) {
+        let mut fresh = Term::new(self.cols, self.rows, self.scrollback_limit);
+        fresh.title = std::mem::take(&mut self.title);
+        fresh.scrollback = std::mem::take(&mut self.scrollback);
+        fresh.responses = std::mem::take(&mut self.responses);
+        fresh.scrolled = self.scrolled;
+        *self = fresh;
+    }
+
+    fn soft_reset(&mut self) {
+        self.cursor_visible = true;
+        self.insert_mode = false;
+        self.cursor.origin_mode = false;
+        self.autowrap = true;
+        self.application_cursor_keys = false;
+        self.application_keypad = false;
+        self.scroll_top = 0;
+        self.scroll_bottom = self.rows - 1;
+        self.cursor.style = CellStyle::default();
+        self.cursor.charsets = [Charset::Ascii; 2];
+        self.cursor.shift_out = false;
+        self.saved_main = None;
+        self.saved_alt = None;
+    }
+
+    fn resize(&mut self, cols: usize, rows: usize) {
+        let (cols, rows) = (cols.max(1), rows.max(1));
+        if (cols, rows) == (self.cols, self.rows) {
+            return;
+        }
+        for alt in [false, true] {
+            let grid = if alt { &mut self.alt } else { &mut self.main };
+            let mut cursor_row = self.cursor.row;
+            while grid.len() > rows {
+                // Drop blank rows below the cursor first, then push the top
+                // into scrollback so the cursor's line stays on screen.
+                if alt != self.alt_active || cursor_row + 1 < grid.len() {
+                    grid.pop();
+                } else {
+                    let line = grid.remove(0);
+                    cursor_row = cursor_row.saturating_sub(1);
+                    if !alt && self.scrollback_limit > 0 {
+                        if self.scrollback.len() == self.scrollback_limit {
+                            self.scrollback.pop_front();
+                        }
+                        self.scrollback.push_back(line);
+                    }
+                }
+            }
+            grid.resize(rows, Line::new(cols, CellStyle::default()));
+            for line in grid.iter_mut() {
+                line.cells.resize(cols, Cell::blank(CellStyle::default()));
+                line.clear_combining(cols, usize::MAX);
+                if line.cells[cols - 1].width == 2 {
+                    line.cells[cols - 1] = Cell::blank(line.cells[cols - 1].style);
+                }
+            }
+            if alt == self.alt_active {
+                self.cursor.row = cursor_row;
+            }
+        }
+        self.cols = cols;
+        self.rows = rows;
+        self.cursor.row = self.cursor.row.min(rows - 1);
+        self.cursor.col = self.cursor.col.min(cols - 1);
+        self.cursor.pending_wrap = false;
+        self.scroll_top = 0;
+        self.scroll_bottom = rows - 1;
+        self.tabs = default_tabs(cols);
+        self.dirty = vec![false; rows];
+        self.full_damage = true;
+    }
+}
+
+fn default_tabs(cols: usize) -> Vec<bool> {
+    (0..cols).map(|c| c > 0 && c % 8 == 0).collect()
+}
+
+/// `38;5;n`, `38;2;r;g;b` and their colon forms (`38:2::r:g:b`). Advances `i`
+/// past semicolon-separated arguments.
+fn extended_color(groups: &[&[u16]], i: &mut usize) -> TerminalColor {
+    let group = groups[*i];
+    let args: Vec<u16> = if group.len() > 1 {
+        group[1..].to_vec()
+    } else {
+        let kind = groups.get(*i + 1).map_or(0, |g| g[0]);
+        let count = match kind {
+            5 => 2,
+            2 => 4,
+            _ => 1,
+        };
+        let args: Vec<u16> = groups[*i + 1..].iter().take(count).map(|g| g[0]).collect();
+        *i += args.len();
+        args
+    };
+    match args.as_slice() {
+        [5, index, ..] => TerminalColor::Indexed {
+            index: *index as u8,
+        },
+        // Colon form may carry a color space id before the components.
+        [2, _, r, g, b, ..] if group.len() > 5 => rgb(*r, *g, *b),
+        [2, r, g, b, ..] => rgb(*r, *g, *b),
+        _ => TerminalColor::Default,
+    }
+}
+
+fn rgb(r: u16, g: u16, b: u16) -> TerminalColor {
+    TerminalColor::Rgb {
+        r: r as u8,
+        g: g as u8,
+        b: b as u8,
+    }
+}
+
+// VT100 line-drawing set, selected with ESC ( 0.
+fn dec_special_graphics(c: char) -> char {
+    match c {
+        '`' => '◆',
+        'a' => '▒',
+        'f' => '°',
+        'g' => '±',
+        'j' => '┘',
+        'k' => '┐',
+        'l' => '┌',
+        'm' => '└',
+        'n' => '┼',
+        'o' => '⎺',
+        'p' => '⎻',
+        'q' => '─',
+        'r' => '⎼',
+        's' => '⎽',
+        't' => '├',
+        'u' => '┤',
+        'v' => '┴',
+        'w' => '┬',
+        'x' => '│',
+        'y' => '≤',
+        'z' => '≥',
+        '{' => 'π',
+        '|' => '≠',
+        '}' => '£',
+        '~' => '·',
+        _ => c,
+    }
+}
+
+// First parameter, with 0/missing meaning 1 (cursor movement counts).
+fn count(params: &Params, index: usize) -> usize {
+    match params.iter().nth(index).map(|p| p[0]) {
+        None | Some(0) => 1,
+        Some(n) => n as usize,
+    }
+}
+
+fn arg(params: &Params, index: usize) -> u16 {
+    params.iter().nth(index).map_or(0, |p| p[0])
+}
+
+impl Perform for Term {
+    fn print(&mut self, c: char) {
+        self.put_char(c);
+    }
+
+    fn execute(&mut self, byte: u8) {
+        match byte {
+            0x08 => {
+                self.cursor.col = self.cursor.col.saturating_sub(1);
+                self.cursor.pending_wrap = false;
+            }
+            0x09 => self.tab_forward(1),
+            0x0a..=0x0c => {
+                self.linefeed();
+                if self.newline_mode {
+                    self.carriage_return();
+                }
+            }
+            0x0d => self.carriage_return(),
+            0x0e => self.cursor.shift_out = true,
+            0x0f => self.cursor.shift_out = false,
+            _ => {}
+        }
+    }
+
+    fn osc_dispatch(&mut self, params: &[&[u8]], _bell_terminated: bool) {
+        if let [b"0" | b"2", rest @ ..] = params {
+            let title: Vec<String> = rest
+                .iter()
+                .map(|p| String::from_utf8_lossy(p).into_owned())
+                .collect();
+            self.title = title.join(";");
+        }
+    }
+
+    fn csi_dispatch(&mut self, params: &Params, intermediates: &[u8], ignore: bool, action: char) {
+        if ignore {
+            return;
+        }
+        match (intermediates, action) {
+            ([], '@') => self.insert_blanks(count(params, 0)),
+            ([], 'A') => self.move_up(count(params, 0)),
+            ([], 'B' | 'e') => self.move_down(count(params, 0)),
+            ([], 'C' | 'a') => {
+                let col = self.cursor.col + count(params, 0);
+                self.set_col(col);
+            }
+            ([], 'D') => {
+                let col = self.cursor.col.saturating_sub(count(params, 0));
+                self.set_col(col);
+            }
+            ([], 'E') => {
+                self.move_down(count(params, 0));
+                self.carriage_return();
+            }
+            ([], 'F') => {
+                self.move_up(count(params, 0));
+                self.carriage_return();
+            }
+            ([], 'G' | '`') => self.set_col(count(params, 0) - 1),
+            ([], 'H' | 'f') => self.goto(count(params, 0) - 1, count(params, 1) - 1),
+            ([], 'I') => self.tab_forward(count(params, 0)),
+            ([], 'J') => self.erase_in_display(arg(params, 0)),
+            ([], 'K') => self.erase_in_line(arg(params, 0)),
+            ([], 'L') => self.insert_lines(count(params, 0)),
+            ([], 'M') => self.delete_lines(count(params, 0)),
+            ([], 'P') => self.delete_chars(count(params, 0)),
+            ([], 'S') => self.scroll_up(count(params, 0)),
+            ([], 'T') => self.scroll_down(count(params, 0)),
+            ([], 'X') => {
+                let (row, col) = (self.cursor.row, self.cursor.col);
+                self.erase_cells(row, col, col + count(params, 0));
+                self.cursor.pending_wrap = false;
+            }
+            ([], 'Z') => self.tab_backward(count(params, 0)),
+            ([], 'b') => {
+                if let Some(c) = self.last_printed {
+                    for _ in 0..count(params, 0) {
+                        self.put_char(c);
+                    }
+                }
+            }
+            ([], 'c') if arg(params, 0) == 0 => self.responses.extend_from_slice(b"\x1b[?1;2c"),
+            // Relative to the scroll region in origin mode, as in xterm.
+            ([], 'd') => self.goto(count(params, 0) - 1, self.cursor.col),
+            ([], 'g') => match arg(params, 0) {
+                0 => self.tabs[self.cursor.col] = false,
+                3 => self.tabs.iter_mut().for_each(|t| *t = false),
+                _ => {}
+            },
+            ([], 'h' | 'l') => {
+                for mode in params.iter() {
+                    match mode[0] {
+                        4 => self.insert_mode = action == 'h',
+                        20 => self.newline_mode = action == 'h',
+                        _ => {}
+                    }
+                }
+            }
+            ([b'?'], 'h' | 'l') => {
+                for mode in params.iter() {
+                    self.set_private_mode(mode[0], action == 'h');
+                }
+            }
+            ([], 'm') => self.sgr(params),
+            ([], 'n') => match arg(params, 0) {
+                5 => self.responses.extend_from_slice(b"\x1b[0n"),
+                6 => {
+                    let top = if self.cursor.origin_mode {
+                        self.scroll_top
+                    } else {
+                        0
+                    };
+                    let report = format!(
+                        "\x1b[{};{}R",
+                        self.cursor.row.saturating_sub(top) + 1,
+                        self.cursor.col + 1
+                    );
+                    self.responses.extend_from_slice(report.as_bytes());
+                }
+                _ => {}
+            },
+            ([], 'r') => self.set_scroll_region(arg(params, 0) as usize, arg(params, 1) as usize),
+            ([], 's') => self.save_cursor(),
+            ([], 'u') => self.restore_cursor(),
+            ([b' '], 'q') => {
+                let style = arg(params, 0);
+                self.cursor_shape = match style {
+                    3 | 4 => CursorShape::Underline,
+                    5 | 6 => CursorShape::Bar,
+                    _ => CursorShape::Block,
+                };
+                self.cursor_blinking = style == 0 || style % 2 == 1;
+            }
+            ([b'!'], 'p') => self.soft_reset(),
+            _ => {}
+        }
+    }
+
+    fn esc_dispatch(&mut self, intermediates: &[u8], _ignore: bool, byte: u8) {
+        match (intermediates, byte) {
+            ([], b'7') => self.save_cursor(),
+            ([], b'8') => self.restore_cursor(),
+            ([], b'D') => self.linefeed(),
+            ([], b'E') => {
+                self.linefeed();
+                self.carriage_return();
+            }
+            ([], b'H') => self.tabs[self.cursor.col] = true,
+            ([], b'M') => self.reverse_index(),
+            ([], b'c') => self.reset(),
+            ([], b'=') => self.application_keypad = true,
+            ([], b'>') => self.application_keypad = false,
+            ([slot @ (b'(' | b')')], set) => {
+                self.cursor.charsets[(*slot == b')') as usize] = match set {
+                    b'0' => Charset::DecSpecialGraphics,
+                    _ => Charset::Ascii,
+                };
+            }
+            ([b'#'], b'8') => {
+                // DECALN: fill the screen with 'E' (alignment test).
+                let cell = Cell {
+                    ch: 'E',
+                    width: 1,
+                    style: CellStyle::default(),
+                };
+                for line in self.grid_mut().iter_mut() {
+                    line.cells.fill(cell);
+                    line.combining.clear();
+                    line.wrapped = false;
+                }
+                self.scroll_top = 0;
+                self.scroll_bottom = self.rows - 1;
+                self.goto(0, 0);
+                self.full_damage = true;
+            }
+            _ => {}
+        }
+    }
+
+    fn hook(&mut self, _params: &Params, _intermediates: &[u8], _ignore: bool, _action: char) {}
+
+    fn put(&mut self, _byte: u8) {}
+
+    fn unhook(&mut self) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rows(emu: &TerminalEmulator) -> Vec<String> {
+        emu.snapshot()
+            .lines
+            .iter()
+            .map(|l| l.runs.iter().map(|r| r.text.as_str()).collect())
+            .collect()
+    }
+
+    #[test]
+    fn wraps_scrolls_and_moves_cursor() {
+        let emu = TerminalEmulator::new(5, 3, Some(10));
+        emu.feed(b"abcdefg\r\nxy\r\nz\r\n".to_vec());
+        assert_eq!(rows(&emu), ["xy", "z", ""]);
+        assert_eq!(emu.scrollback_len(), 2);
+        let scrollback = emu.scrollback_lines(0, 2);
+        assert!(scrollback[0].wrapped);
+        assert_eq!(scrollback[1].runs[0].text, "fg");
+
+        emu.feed(b"\x1b[2;3H\x1b[1K!\x1b[6n".to_vec());
+        assert_eq!(rows(&emu), ["xy", "  !", ""]);
+        assert_eq!(emu.take_responses(), b"\x1b[2;4R");
+    }
+
+    #[test]
+    fn restore_keeps_origin_mode_cursor_inside_a_moved_region() {
+        let emu = TerminalEmulator::new(20, 12, None);
+        emu.feed(b"\x1b[?6h\x1b7\x1b[5;10r\x1b8\x1b[6n".to_vec());
+        assert_eq!(emu.take_responses(), b"\x1b[1;1R");
+        assert_eq!(emu.snapshot().cursor.row, 4);
+    }
+
+    #[test]
+    fn vertical_position_is_relative_to_the_region_in_origin_mode() {
+        let emu = TerminalEmulator::new(20, 12, None);
+        emu.feed(b"\x1b[5;10r\x1b[?6h\x1b[1d\x1b[6n".to_vec());
+        assert_eq!(emu.take_responses(), b"\x1b[1;1R");
+        assert_eq!(emu.snapshot().cursor.row, 4);
+
+        // Clamped to the region's bottom line.
+        emu.feed(b"\x1b[20d\x1b[6n".to_vec());
+        assert_eq!(emu.take_responses(), b"\x1b[6;1R");
+        assert_eq!(emu.snapshot().cursor.row, 9);
+    }
+
+    #[test]
+    fn tracks_styles_wide_chars_and_line_drawing() {
+        let emu = TerminalEmulator::new(10, 2, None);
+        emu.feed(
+            "\x1b[1;38;5;196mR\x1b[0;48:2::1:2:3m界\x1b[m\x1b(0qx\x1b(B"
+                .as_bytes()
+                .to_vec(),
+        );
+        let line = &emu.snapshot().lines[0];
+        assert_eq!(line.runs.len(), 3);
+        assert!(line.runs[0].style.bold);
+        assert_eq!(line.runs[0].style.fg, TerminalColor::Indexed { index: 196 });
+        assert_eq!((line.runs[1].text.as_str(), line.runs[1].cells), ("界", 2));
+        assert_eq!(
+            line.runs[1].style.bg,
+            TerminalColor::Rgb { r: 1, g: 2, b: 3 }
+        );
+        assert_eq!(line.runs[2].text, "─│");
+
+        // Overwriting half of a wide character blanks the other half.
+        emu.feed(b"\x1b[1;3Hx".to_vec());
+        assert_eq!(rows(&emu)[0], "R x─│");
+    }
+
+    #[test]
+    fn alternate_screen_and_scroll_region() {
+        let emu = TerminalEmulator::new(4, 4, None);
+        emu.feed(b"main".to_vec());
+        emu.feed(b"\x1b[?1049h\x1b[2;3r\x1b[3;1H1\n2\n3".to_vec());
+        let snapshot = emu.snapshot();
+        assert!(snapshot.alternate_screen);
+        assert_eq!(rows(&emu), ["", " 2", "  3", ""]);
+        assert_eq!(snapshot.scrollback_len, 0);
+
+        emu.feed(b"\x1b[?1049l".to_vec());
+        assert_eq!(rows(&emu), ["main", "", "", ""]);
+        assert_eq!(emu.snapshot().cursor.col, 3);
+    }
+
+    #[test]
+    fn reports_damage_since_last_take() {
+        let emu = TerminalEmulator::new(8, 3, None);
+        assert!(emu.take_damage().full);
+        emu.feed(b"\x1b]2;vim\x07\x1b[3;1Hhi\x1b[?2004h".to_vec());
+        let damage = emu.take_damage();
+        assert!(!damage.full);
+        assert_eq!(damage.lines.len(), 1);
+        assert_eq!(damage.lines[0].row, 2);
+        assert_eq!(damage.title, "vim");
+        assert!(damage.modes.bracketed_paste);
+        assert!(emu.take_damage().lines.is_empty());
+
+        emu.resize(6, 2);
+        let damage = emu.take_damage();
+        assert!(damage.full);
+        assert_eq!(damage.lines.len(), 2);
+        assert_eq!(damage.cursor.row, 1);
+    }
+}
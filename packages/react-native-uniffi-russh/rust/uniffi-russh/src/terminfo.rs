@@ -0,0 +1,112 @@
+use crate::utils::SshError;
+
+// Compiled entries shipped with the library, by TERM name. Sources and the
+// tic command that builds them are in terminfo/.
+static BUNDLED_ENTRIES: &[(&str, &[u8])] = &[("fressh", include_bytes!("../terminfo/fressh"))];
+
+#[derive(Debug, Clone, Copy, PartialEq, uniffi::Enum)]
+pub enum TerminfoStatus {
+    AlreadyPresent,
+    Installed, // written to ~/.terminfo
+}
+
+// Compiled terminfo magic numbers (little-endian): legacy 16-bit format and
+// the ncurses 6.1 extended-number format.
+const TERMINFO_MAGIC: [u16; 2] = [0o432, 0o1036];
+
+/// TERM names end up in a shell command and a file name, so only accept what
+/// real entries use.
+pub(crate) fn validate_term_name(name: &str) -> Result<(), SshError> {
+    let valid = !name.is_empty()
+        && name.len() <= 128
+        && !name.starts_with(['.', '-'])
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '_' | '+' | '-'));
+    if valid {
+        Ok(())
+    } else {
+        Err(SshError::InvalidArgument(format!(
+            "invalid TERM name {name:?}"
+        )))
+    }
+}
+
+pub(crate) fn validate_compiled_entry(entry: &[u8]) -> Result<(), SshError> {
+    match entry {
+        [lo, hi, ..] if TERMINFO_MAGIC.contains(&u16::from_le_bytes([*lo, *hi])) => Ok(()),
+        _ => Err(SshError::InvalidArgument(
+            "not a compiled terminfo entry (run it through tic first)".to_string(),
+        )),
+    }
+}
+
+pub(crate) fn bundled_entry(name: &str) -> Result<&'static [u8], SshError> {
+    BUNDLED_ENTRIES
+        .iter()
+        .find(|(bundled, _)| *bundled == name)
+        .map(|(_, entry)| *entry)
+        .ok_or_else(|| SshError::InvalidArgument(format!("no bundled terminfo entry for {name:?}")))
+}
+
+// ncurses on Linux files entries under their first letter, macOS and the BSDs
+// under its hex code; check and install both.
+fn entry_dirs(name: &str) -> [String; 2] {
+    let first = name.as_bytes()[0];
+    [
+        format!("\"$HOME/.terminfo/{}\"", first as char),
+        format!("\"$HOME/.terminfo/{first:02x}\""),
+    ]
+}
+
+/// Exits 0 when the remote can already resolve `name`.
+pub(crate) fn check_command(name: &str) -> String {
+    let [by_letter, by_hex] = entry_dirs(name);
+    format!("infocmp {name} >/dev/null 2>&1 || [ -e {by_letter}/{name} ] || [ -e {by_hex}/{name} ]")
+}
+
+/// Reads the compiled entry from stdin into ~/.terminfo.
+pub(crate) fn install_command(name: &str) -> String {
+    let [by_letter, by_hex] = entry_dirs(name);
+    format!(
+        "umask 022 && mkdir -p {by_letter} {by_hex} && cat > {by_letter}/{name}.tmp && \
+         mv {by_letter}/{name}.tmp {by_letter}/{name} && cp {by_letter}/{name} {by_hex}/{name}"
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validates_names_and_entries() {
+        for ok in ["xterm-kitty", "tmux-256color", "fressh", "rxvt-unicode+x"] {
+            assert!(validate_term_name(ok).is_ok(), "{ok}");
+        }
+        for bad in ["", "-x", "../x", "a b", "x;rm -rf ~", "x$(id)"] {
+            assert!(validate_term_name(bad).is_err(), "{bad}");
+        }
+
+        assert!(validate_compiled_entry(&[0x1a, 0x01, 0, 0]).is_ok());
+        assert!(validate_compiled_entry(&[0x1e, 0x02, 0, 0]).is_ok());
+        assert!(validate_compiled_entry(b"xterm-kitty|KovIdTTY,").is_err());
+
+        assert_eq!(
+            check_command("fressh"),
+            "infocmp fressh >/dev/null 2>&1 || [ -e \"$HOME/.terminfo/f\"/fressh ] \
+             || [ -e \"$HOME/.terminfo/66\"/fressh ]"
+        );
+    }
+
+    #[test]
+    fn bundles_a_legacy_format_fressh_entry() {
+        let entry = bundled_entry("fressh").unwrap();
+        validate_compiled_entry(entry).unwrap();
+        // The 16-bit format, which ncurses before 6.1 can read.
+        assert_eq!(u16::from_le_bytes([entry[0], entry[1]]), TERMINFO_MAGIC[0]);
+        // The names section starts after the 12-byte header.
+        assert!(entry[12..].starts_with(b"fressh|"));
+
+        assert!(bundled_entry("xterm-kitty").is_err());
+    }
+}
@@ -0,0 +1,381 @@
+use std::{
+    future::Future,
+    io,
+    pin::Pin,
+    sync::{atomic::Ordering, Arc, Mutex},
+    task::{Context, Poll},
+};
+
+use tokio::io::{AsyncRead, AsyncWrite, ReadBuf};
+
+use crate::metrics::ConnectionMetrics;
+use crate::ssh_connection::NegotiatedAlgorithms;
+
+/// Socket wrapper handed to russh so we can observe the raw transport.
+/// russh keeps its byte counters and negotiation results private, so we count
+/// at the socket and read the cleartext part of the initial handshake instead.
+pub(crate) struct MeteredStream<S> {
+    inner: S,
+    metrics: Arc<ConnectionMetrics>,
+    transcript: Arc<Mutex<HandshakeTranscript>>,
+    inbound: HandshakeSniffer,
+    outbound: HandshakeSniffer,
+    abort: AbortFuse,
+}
+
+impl<S> MeteredStream<S> {
+    pub(crate) fn new(
+        inner: S,
+        metrics: Arc<ConnectionMetrics>,
+        transcript: Arc<Mutex<HandshakeTranscript>>,
+    ) -> Self {
+        Self {
+            inner,
+            metrics,
+            transcript,
+            inbound: HandshakeSniffer::default(),
+            outbound: HandshakeSniffer::default(),
+            abort: AbortFuse::default(),
+        }
+    }
+
+    /// Fail all further I/O once `abort` resolves, so an abandoned handshake
+    /// closes its socket instead of lingering in russh's session task.
+    pub(crate) fn with_abort(mut self, abort: impl Future<Output = ()> + Send + 'static) -> Self {
+        self.abort = AbortFuse {
+            pending: Some(Box::pin(abort)),
+            fired: false,
+        };
+        self
+    }
+}
+
+#[derive(Default)]
+struct AbortFuse {
+    pending: Option<Pin<Box<dyn Future<Output = ()> + Send>>>,
+    fired: bool,
+}
+impl AbortFuse {
+    fn check(&mut self, cx: &mut Context<'_>) -> io::Result<()> {
+        if let Some(fut) = self.pending.as_mut() {
+            if fut.as_mut().poll(cx).is_ready() {
+                self.pending = None;
+                self.fired = true;
+            }
+        }
+        if self.fired {
+            return Err(io::Error::new(
+                io::ErrorKind::ConnectionAborted,
+                "connect aborted",
+            ));
+        }
+        Ok(())
+    }
+}
+
+impl<S: AsyncRead + Unpin> AsyncRead for MeteredStream<S> {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        let this = self.get_mut();
+        this.abort.check(cx)?;
+        let before = buf.filled().len();
+        let res = Pin::new(&mut this.inner).poll_read(cx, buf);
+        if let Poll::Ready(Ok(())) = res {
+            let read = &buf.filled()[before..];
+            this.metrics
+                .bytes_received
+                .fetch_add(read.len() as u64, Ordering::Relaxed);
+            this.inbound
+                .feed(read, Direction::ServerToClient, &this.transcript);
+        }
+        res
+    }
+}
+
+impl<S: AsyncWrite + Unpin> AsyncWrite for MeteredStream<S> {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        let this = self.get_mut();
+        this.abort.check(cx)?;
+        let res = Pin::new(&mut this.inner).poll_write(cx, buf);
+        if let Poll::Ready(Ok(n)) = res {
+            this.metrics
+                .bytes_sent
+                .fetch_add(n as u64, Ordering::Relaxed);
+            this.outbound
+                .feed(&buf[..n], Direction::ClientToServer, &this.transcript);
+        }
+        res
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.get_mut().inner).poll_flush(cx)
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.get_mut().inner).poll_shutdown(cx)
+    }
+}
+
+// ---------- Handshake sniffing ----------
+
+const SSH_MSG_KEXINIT: u8 = 20;
+const SSH_MSG_NEWKEYS: u8 = 21;
+
+// Anything bigger than this before NEWKEYS is not a handshake we understand.
+const MAX_CLEARTEXT_PACKET: usize = 256 * 1024;
+const MAX_IDENTIFICATION_BYTES: usize = 64 * 1024;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Direction {
+    ClientToServer,
+    ServerToClient,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+enum SniffPhase {
+    #[default]
+    Identification, // "SSH-2.0-..." line (servers may send other lines first)
+    Packets, // cleartext binary packets of the initial key exchange
+    Done,    // NEWKEYS seen; everything after is encrypted
+}
+
+#[derive(Debug, Default)]
+struct HandshakeSniffer {
+    phase: SniffPhase,
+    buf: Vec<u8>,
+}
+
+impl HandshakeSniffer {
+    fn feed(&mut self, data: &[u8], dir: Direction, transcript: &Mutex<HandshakeTranscript>) {
+        if self.phase == SniffPhase::Done || data.is_empty() {
+            return;
+        }
+        self.buf.extend_from_slice(data);
+
+        if self.phase == SniffPhase::Identification {
+            while let Some(nl) = self.buf.iter().position(|b| *b == b'\n') {
+                let line: Vec<u8> = self.buf.drain(..=nl).collect();
+                let line = String::from_utf8_lossy(&line).trim_end().to_string();
+                if line.starts_with("SSH-") {
+                    if dir == Direction::ServerToClient {
+                        lock(transcript).server_id = Some(line);
+                    }
+                    self.phase = SniffPhase::Packets;
+                    break;
+                }
+            }
+            if self.phase == SniffPhase::Identification {
+                if self.buf.len() > MAX_IDENTIFICATION_BYTES {
+                    self.give_up();
+                }
+                return;
+            }
+        }
+
+        while self.buf.len() >= 5 {
+            let len = u32::from_be_bytes([self.buf[0], self.buf[1], self.buf[2], self.buf[3]]);
+            let len = len as usize;
+            if !(1..=MAX_CLEARTEXT_PACKET).contains(&len) {
+                self.give_up();
+                return;
+            }
+            if self.buf.len() < 4 + len {
+                return;
+            }
+            let padding = self.buf[4] as usize;
+            let payload_end = (4 + len).saturating_sub(padding).max(5);
+            let payload = &self.buf[5..payload_end];
+            match payload.first().copied() {
+                Some(SSH_MSG_KEXINIT) => {
+                    if let Some(lists) = KexInitLists::parse(payload) {
+                        let mut t = lock(transcript);
+                        match dir {
+                            Direction::ClientToServer => t.client_kexinit = Some(lists),
+                            Direction::ServerToClient => t.server_kexinit = Some(lists),
+                        }
+                    }
+                }
+                Some(SSH_MSG_NEWKEYS) => {
+                    self.give_up();
+                    return;
+                }
+                _ => {}
+            }
+            self.buf.drain(..4 + len);
+        }
+    }
+
+    fn give_up(&mut self) {
+        self.phase = SniffPhase::Done;
+        self.buf = Vec::new();
+    }
+}
+
+fn lock(t: &Mutex<HandshakeTranscript>) -> std::sync::MutexGuard<'_, HandshakeTranscript> {
+    t.lock().unwrap_or_else(|p| p.into_inner())
+}
+
+/// The algorithm name-lists of one SSH_MSG_KEXINIT (RFC 4253 §7.1).
+#[derive(Debug, Clone, Default, PartialEq)]
+pub(crate) struct KexInitLists {
+    kex: Vec<String>,
+    host_key: Vec<String>,
+    cipher_c2s: Vec<String>,
+    cipher_s2c: Vec<String>,
+    mac_c2s: Vec<String>,
+    mac_s2c: Vec<String>,
+    compression_c2s: Vec<String>,
+    compression_s2c: Vec<String>,
+}
+
+impl KexInitLists {
+    fn parse(payload: &[u8]) -> Option<Self> {
+        // byte SSH_MSG_KEXINIT, byte[16] cookie, then the name-lists.
+        let mut idx = 1 + 16;
+        let mut next = || -> Option<Vec<String>> {
+            let n = u32::from_be_bytes(payload.get(idx..idx + 4)?.try_into().ok()?) as usize;
+            idx += 4;
+            let raw = payload.get(idx..idx + n)?;
+            idx += n;
+            Some(
+                String::from_utf8_lossy(raw)
+                    .split(',')
+                    .filter(|s| !s.is_empty())
+                    .map(str::to_string)
+                    .collect(),
+            )
+        };
+        Some(Self {
+            kex: next()?,
+            host_key: next()?,
+            cipher_c2s: next()?,
+            cipher_s2c: next()?,
+            mac_c2s: next()?,
+            mac_s2c: next()?,
+            compression_c2s: next()?,
+            compression_s2c: next()?,
+        })
+    }
+}
+
+/// What we learned from the cleartext part of the handshake.
+#[derive(Debug, Default)]
+pub(crate) struct HandshakeTranscript {
+    pub(crate) server_id: Option<String>,
+    client_kexinit: Option<KexInitLists>,
+    server_kexinit: Option<KexInitLists>,
+}
+
+impl HandshakeTranscript {
+    /// Re-run the RFC 4253 §7.1 selection: the first client algorithm the
+    /// server also supports wins.
+    pub(crate) fn negotiated(&self) -> Option<NegotiatedAlgorithms> {
+        let c = self.client_kexinit.as_ref()?;
+        let s = self.server_kexinit.as_ref()?;
+        let pick = |client: &[String], server: &[String]| -> Option<String> {
+            client.iter().find(|a| server.contains(a)).cloned()
+        };
+        let cipher_c2s = pick(&c.cipher_c2s, &s.cipher_c2s)?;
+        let cipher_s2c = pick(&c.cipher_s2c, &s.cipher_s2c)?;
+        // AEAD ciphers authenticate on their own; the MAC lists are ignored.
+        let mac = |cipher: &str, client: &[String], server: &[String]| -> Option<String> {
+            if is_aead_cipher(cipher) {
+                Some("implicit".to_string())
+            } else {
+                pick(client, server)
+            }
+        };
+        Some(NegotiatedAlgorithms {
+            kex: pick(&c.kex, &s.kex)?,
+            host_key: pick(&c.host_key, &s.host_key)?,
+            mac_client_to_server: mac(&cipher_c2s, &c.mac_c2s, &s.mac_c2s)?,
+            mac_server_to_client: mac(&cipher_s2c, &c.mac_s2c, &s.mac_s2c)?,
+            cipher_client_to_server: cipher_c2s,
+            cipher_server_to_client: cipher_s2c,
+            compression_client_to_server: pick(&c.compression_c2s, &s.compression_c2s)?,
+            compression_server_to_client: pick(&c.compression_s2c, &s.compression_s2c)?,
+        })
+    }
+}
+
+pub(crate) fn is_aead_cipher(name: &str) -> bool {
+    name.starts_with("chacha20-poly1305") || name.contains("-gcm@")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn name_list(out: &mut Vec<u8>, names: &str) {
+        out.extend_from_slice(&(names.len() as u32).to_be_bytes());
+        out.extend_from_slice(names.as_bytes());
+    }
+
+    fn kexinit_packet(kex: &str, cipher: &str, mac: &str, compression: &str) -> Vec<u8> {
+        let mut payload = vec![SSH_MSG_KEXINIT];
+        payload.extend_from_slice(&[0u8; 16]);
+        name_list(&mut payload, kex);
+        name_list(&mut payload, "ssh-ed25519,rsa-sha2-512");
+        name_list(&mut payload, cipher);
+        name_list(&mut payload, cipher);
+        name_list(&mut payload, mac);
+        name_list(&mut payload, mac);
+        name_list(&mut payload, compression);
+        name_list(&mut payload, compression);
+        name_list(&mut payload, "");
+        name_list(&mut payload, "");
+        payload.push(0);
+        payload.extend_from_slice(&[0u8; 4]);
+
+        let padding = 4u8;
+        let len = (1 + payload.len() + padding as usize) as u32;
+        let mut packet = len.to_be_bytes().to_vec();
+        packet.push(padding);
+        packet.extend_from_slice(&payload);
+        packet.extend_from_slice(&[0u8; 4]);
+        packet
+    }
+
+    #[test]
+    fn negotiates_from_split_handshake_bytes() {
+        let transcript = Mutex::new(HandshakeTranscript::default());
+
+        let mut server = b"Welcome\r\nSSH-2.0-OpenSSH_9.6\r\n".to_vec();
+        server.extend(kexinit_packet(
+            "curve25519-sha256,ext-info-s",
+            "aes128-ctr,chacha20-poly1305@openssh.com",
+            "hmac-sha2-256",
+            "none,zlib@openssh.com",
+        ));
+        let mut client = b"SSH-2.0-russh_0.54.3\r\n".to_vec();
+        client.extend(kexinit_packet(
+            "curve25519-sha256,ext-info-c",
+            "chacha20-poly1305@openssh.com,aes128-ctr",
+            "hmac-sha2-512,hmac-sha2-256",
+            "zlib@openssh.com,none",
+        ));
+
+        let mut inbound = HandshakeSniffer::default();
+        for piece in server.chunks(7) {
+            inbound.feed(piece, Direction::ServerToClient, &transcript);
+        }
+        let mut outbound = HandshakeSniffer::default();
+        outbound.feed(&client, Direction::ClientToServer, &transcript);
+
+        let t = transcript.lock().unwrap();
+        assert_eq!(t.server_id.as_deref(), Some("SSH-2.0-OpenSSH_9.6"));
+        let n = t.negotiated().expect("both KEXINITs seen");
+        assert_eq!(n.kex, "curve25519-sha256");
+        assert_eq!(n.host_key, "ssh-ed25519");
+        assert_eq!(n.cipher_client_to_server, "chacha20-poly1305@openssh.com");
+        assert_eq!(n.mac_client_to_server, "implicit");
+        assert_eq!(n.compression_server_to_client, "zlib@openssh.com");
+    }
+}
@@ -0,0 +1,87 @@
+use std::fmt::Write;
+
+use crate::ssh_shell::InvalidUtf8;
+
+/// Decodes a byte stream split at arbitrary points, holding back an
+/// incomplete UTF-8 sequence at the end of each piece.
+pub(crate) struct Utf8Carry {
+    pending: Vec<u8>,
+    invalid: InvalidUtf8,
+}
+
+impl Utf8Carry {
+    pub fn new(invalid: InvalidUtf8) -> Self {
+        Utf8Carry {
+            pending: Vec::new(),
+            invalid,
+        }
+    }
+
+    pub fn decode(&mut self, bytes: &[u8]) -> String {
+        self.pending.extend_from_slice(bytes);
+        let mut out = String::with_capacity(self.pending.len());
+        let mut rest = &self.pending[..];
+        while !rest.is_empty() {
+            let error = match std::str::from_utf8(rest) {
+                Ok(text) => {
+                    out.push_str(text);
+                    rest = &[];
+                    break;
+                }
+                Err(e) => e,
+            };
+            let (valid, after) = rest.split_at(error.valid_up_to());
+            out.push_str(std::str::from_utf8(valid).unwrap_or_default());
+            // error_len None: the input ended mid-sequence.
+            let Some(len) = error.error_len() else {
+                rest = after;
+                break;
+            };
+            self.push_invalid(&mut out, &after[..len]);
+            rest = &after[len..];
+        }
+        self.pending = rest.to_vec();
+        out
+    }
+
+    /// Give up on a held-back sequence (the stream ended or skipped ahead).
+    pub fn flush(&mut self) -> String {
+        let mut out = String::new();
+        let pending = std::mem::take(&mut self.pending);
+        if !pending.is_empty() {
+            self.push_invalid(&mut out, &pending);
+        }
+        out
+    }
+
+    fn push_invalid(&self, out: &mut String, bytes: &[u8]) {
+        match self.invalid {
+            InvalidUtf8::Replace => out.push(char::REPLACEMENT_CHARACTER),
+            InvalidUtf8::Escape => {
+                for byte in bytes {
+                    let _ = write!(out, "\\x{byte:02x}");
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn carries_split_sequences_and_escapes_invalid_bytes() {
+        let mut carry = Utf8Carry::new(InvalidUtf8::Escape);
+        // "€" is e2 82 ac; split it over three pieces.
+        assert_eq!(carry.decode(b"a\xe2"), "a");
+        assert_eq!(carry.decode(b"\x82"), "");
+        assert_eq!(carry.decode(b"\xacb\xff\xc3("), "€b\\xff\\xc3(");
+        assert_eq!(carry.decode(b"\xf0\x9f"), "");
+        assert_eq!(carry.flush(), "\\xf0\\x9f");
+
+        let mut carry = Utf8Carry::new(InvalidUtf8::Replace);
+        assert_eq!(carry.decode(b"\xc3"), "");
+        assert_eq!(carry.decode(b"\xa9\xff\xfe!"), "é\u{fffd}\u{fffd}!");
+    }
+}
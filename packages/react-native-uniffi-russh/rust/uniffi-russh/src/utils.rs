@@ -19,10 +19,26 @@ pub enum SshError {
     Auth(String),
     #[error("Shell already running")]
     ShellAlreadyRunning,
+    #[error("Recording already running")]
+    RecordingAlreadyRunning,
     #[error("russh error: {0}")]
     Russh(String),
     #[error("russh-keys error: {0}")]
     RusshKeys(String),
+    #[error("Cancelled")]
+    Cancelled,
+    #[error("Unsupported: {0}")]
+    Unsupported(String),
+    #[error("Invalid pattern: {0}")]
+    InvalidPattern(String),
+    #[error("I/O error: {0}")]
+    Io(String),
+    #[error("Invalid recording: {0}")]
+    InvalidRecording(String),
+    #[error("Invalid argument: {0}")]
+    InvalidArgument(String),
+    #[error("Remote command failed: {0}")]
+    RemoteCommandFailed(String),
 }
 impl From<russh::Error> for SshError {
     fn from(e: russh::Error) -> Self {
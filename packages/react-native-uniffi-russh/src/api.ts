@@ -39,13 +39,44 @@ export type ConnectionDetails = {
 
 /**
  * This status is only to provide updates for discrete events
- * during the connect() promise.
+ * during the connect() promise, in this order.
  *
  * It is no longer relevant after the connect() promise is resolved.
  */
 export type SshConnectionProgress =
-	| 'tcpConnected' // TCP established, starting SSH handshake
-	| 'sshHandshake'; // SSH protocol negotiation complete
+	| { type: 'dnsResolved'; candidates: string[] } // in attempt order
+	// TCP established to the address that won the race; starting SSH handshake
+	| { type: 'tcpConnected'; chosen: string }
+	| { type: 'hostKeyVerified' } // onServerKey accepted the key
+	| { type: 'sshHandshake' } // SSH protocol negotiation complete
+	| { type: 'authenticating' }
+	| { type: 'authAttempt'; method: string } // 'password', 'publickey'
+	| { type: 'authenticated' }
+	| { type: 'connected' };
+
+/**
+ * Events on an established connection. Every completed rekey is reported,
+ * whichever side started it, with the algorithms it negotiated
+ * (`negotiatedAlgorithms` holds the initial ones).
+ */
+export type SshConnectionEvent = {
+	type: 'rekeyed';
+	rekeyCount: number;
+	atMs: number;
+	algorithms: GeneratedRussh.NegotiatedAlgorithms;
+};
+
+export type CompressionPreference = 'off' | 'prefer' | 'require';
+
+/**
+ * Key re-exchange thresholds; unset fields keep the defaults (512MiB / 1h).
+ * Bytes must be 1..768MiB and time at least 1000ms, or connect rejects them.
+ */
+export type RekeyLimits = {
+	writeBytes?: bigint;
+	readBytes?: bigint;
+	timeMs?: bigint;
+};
 
 export type ConnectOptions = ConnectionDetails & {
 	onConnectionProgress?: (status: SshConnectionProgress) => void;
@@ -54,18 +85,76 @@ export type ConnectOptions = ConnectionDetails & {
 		serverKeyInfo: GeneratedRussh.ServerPublicKeyInfo,
 		signal?: AbortSignal,
 	) => Promise<boolean>;
+	/** Banner text (legal notices etc.) sent during authentication. */
+	onAuthBanner?: (banner: string) => void;
+	onConnectionEvent?: (event: SshConnectionEvent) => void;
+	onMetrics?: (metrics: GeneratedRussh.SshConnectionMetrics) => void;
+	/** Round-trip probe and onMetrics period. */
+	metricsIntervalMs?: number;
+	rekeyLimits?: RekeyLimits;
+	/** Unset: russh's default order. */
+	compression?: CompressionPreference;
 	abortSignal?: AbortSignal;
 };
 
+export type ShellCommand =
+	| { type: 'exec'; command: string } // e.g., 'tmux attach'
+	| { type: 'subsystem'; name: string };
+
+export type BufferPolicy = {
+	ringBytes?: bigint;
+	maxChunkBytes?: number;
+	/** Only applies at start. */
+	broadcastChunks?: number;
+	coalesceMs?: number;
+};
+
 export type StartShellOptions = {
 	term: TerminalType;
+	/** Any TERM name (e.g., 'xterm-kitty'); overrides `term`. */
+	termName?: string;
 	terminalMode?: GeneratedRussh.TerminalMode[];
 	terminalPixelSize?: GeneratedRussh.TerminalPixelSize;
 	terminalSize?: GeneratedRussh.TerminalSize;
+	/** Sent as `env` requests; overrides the locale. */
+	env?: Record<string, string>;
+	/**
+	 * BCP-47 tag sent as LANG and LC_CTYPE in POSIX form ('en-US' ->
+	 * 'en_US.UTF-8'), e.g. `Intl.DateTimeFormat().resolvedOptions().locale`.
+	 */
+	locale?: string;
+	/** Unset: the user's login shell. */
+	command?: ShellCommand;
+	/** Defaults to true; false for scripting/automation. */
+	allocatePty?: boolean;
+	bufferPolicy?: BufferPolicy;
 	onClosed?: (shellId: number) => void;
+	/**
+	 * A lossless listener fell behind (true) or caught up (false). While it is
+	 * behind this shell's server output is paused; other shells on the
+	 * connection keep running.
+	 */
+	onChannelPause?: (paused: boolean) => void;
 	abortSignal?: AbortSignal;
 };
 
+export type ShellSignal =
+	| 'ABRT'
+	| 'ALRM'
+	| 'FPE'
+	| 'HUP'
+	| 'ILL'
+	| 'INT'
+	| 'KILL'
+	| 'PIPE'
+	| 'QUIT'
+	| 'SEGV'
+	| 'TERM'
+	| 'USR1'
+	| 'USR2';
+
+export type TerminfoStatus = 'alreadyPresent' | 'installed';
+
 export type StreamKind = 'stdout' | 'stderr';
 
 export type TerminalChunk = {
@@ -76,20 +165,60 @@ export type TerminalChunk = {
 	bytes: ArrayBuffer;
 };
 
+/** Delivered in place of TerminalChunk to listeners with `text` set. */
+export type TerminalText = {
+	kind: 'text';
+	seq: bigint;
+	tMs: number;
+	stream: StreamKind;
+	text: string;
+};
+
 export type DropNotice = { kind: 'dropped'; fromSeq: bigint; toSeq: bigint };
-export type ListenerEvent = TerminalChunk | DropNotice;
+/** A shell integration mark (OSC 133) updated this record. */
+export type CommandNotice = {
+	kind: 'command';
+	command: GeneratedRussh.CommandRecord;
+};
+export type ListenerEvent =
+	| TerminalChunk
+	| TerminalText
+	| DropNotice
+	| CommandNotice;
 
 export type Cursor =
 	| { mode: 'head' } // earliest available in ring
 	| { mode: 'tailBytes'; bytes: bigint } // last N bytes (best-effort)
 	| { mode: 'seq'; seq: bigint } // from a given sequence
 	| { mode: 'time'; tMs: number } // from timestamp
-	| { mode: 'live' }; // no replay, live only
+	| { mode: 'live' } // no replay, live only
+	| { mode: 'tailLines'; lines: bigint } // start of the last N lines
+	| { mode: 'line'; line: bigint }; // line N (see headLine); earlier ones set dropped
 
 export type ListenerOptions = {
 	cursor: Cursor;
 	/** Optional per-listener coalescing window in ms (e.g., 10–25). */
 	coalesceMs?: number;
+	/** Never skip output; the connection stops reading while this lags. */
+	lossless?: boolean;
+	maxLagBytes?: bigint;
+	/** Deliver TerminalText; invalid UTF-8 is replaced with U+FFFD or escaped. */
+	text?: 'replace' | 'escape';
+};
+
+export type SearchOptions = {
+	caseInsensitive?: boolean;
+	/** Match `pattern` as plain text, not a regex. */
+	literal?: boolean;
+	maxMatches?: number;
+	/** Keep and return the most recent matches. */
+	newestFirst?: boolean;
+};
+
+export type RecordingOptions = {
+	/** Also record sendData (may capture passwords). */
+	recordInput?: boolean;
+	title?: string;
 };
 
 export type BufferReadResult = {
@@ -102,10 +231,7 @@ export type BufferReadResult = {
 // Handles
 // ─────────────────────────────────────────────────────────────────────────────
 
-type ProgressTimings = {
-	tcpEstablishedAtMs: number;
-	sshHandshakeAtMs: number;
-};
+type ProgressTimings = GeneratedRussh.SshConnectionInfoProgressTimings;
 
 export type SshConnection = {
 	readonly connectionId: string;
@@ -113,39 +239,130 @@ export type SshConnection = {
 	readonly connectedAtMs: number;
 	readonly connectionDetails: ConnectionDetails;
 	readonly progressTimings: ProgressTimings;
-
+	readonly serverIdentification?: string; // e.g., 'SSH-2.0-OpenSSH_9.6'
+	readonly authBanner?: string;
+	readonly negotiatedAlgorithms?: GeneratedRussh.NegotiatedAlgorithms;
+
+	getMetrics: () => GeneratedRussh.SshConnectionMetrics;
+	rekey: (opts?: { signal?: AbortSignal }) => Promise<void>;
+	/**
+	 * Install a compiled terminfo entry (`tic -o` output) into the server's
+	 * ~/.terminfo unless it already resolves `termName`. Without an entry,
+	 * installs the one bundled for `termName` (currently 'fressh').
+	 */
+	installTerminfo: {
+		(
+			termName: string,
+			opts?: { signal?: AbortSignal },
+		): Promise<TerminfoStatus>;
+		(
+			termName: string,
+			compiledEntry: ArrayBuffer,
+			opts?: { signal?: AbortSignal },
+		): Promise<TerminfoStatus>;
+	};
 	startShell: (opts: StartShellOptions) => Promise<SshShell>;
 	disconnect: (opts?: { signal?: AbortSignal }) => Promise<void>;
 };
 
-export type SshShell = {
+/** Replay ring, listeners and attachments of a shell or cast player. */
+export type OutputBuffer = {
+	// Stats
+	bufferStats: () => GeneratedRussh.BufferStats;
+	currentSeq: () => number;
+
+	// Replay + live
+	readBuffer: (cursor: Cursor, maxBytes?: bigint) => BufferReadResult;
+	readText: (
+		cursor: Cursor,
+		maxBytes?: bigint,
+	) => GeneratedRussh.TextReadResult;
+	search: (
+		pattern: string,
+		opts?: SearchOptions & { signal?: AbortSignal },
+	) => Promise<GeneratedRussh.SearchResult>;
+	addListener: (
+		cb: (ev: ListenerEvent) => void,
+		opts: ListenerOptions,
+	) => bigint;
+	removeListener: (id: bigint) => void;
+
+	// Attachments
+	attachEmulator: (
+		scrollbackLines?: number,
+	) => GeneratedRussh.TerminalEmulatorInterface;
+	detachEmulator: () => void;
+	commands: () => GeneratedRussh.CommandRecord[];
+};
+
+export type SshShell = OutputBuffer & {
 	readonly channelId: number;
 	readonly createdAtMs: number;
 	readonly pty: TerminalType;
+	readonly termName: string;
 	readonly connectionId: string;
+	/** false: raw byte stream with no terminal semantics. */
+	readonly ptyAllocated: boolean;
+	readonly command?: ShellCommand;
+	/** `env` variables the server refused (see sshd AcceptEnv). */
+	readonly rejectedEnv: string[];
+	terminalGeometry: () => GeneratedRussh.TerminalGeometry;
 
 	// I/O
 	sendData: (
 		data: ArrayBuffer,
 		opts?: { signal?: AbortSignal },
 	) => Promise<void>;
+	sendSignal: (
+		signal: ShellSignal,
+		opts?: { signal?: AbortSignal },
+	) => Promise<void>;
+	sendBreak: (
+		durationMs: number,
+		opts?: { signal?: AbortSignal },
+	) => Promise<void>;
+	resize: (
+		size: {
+			cols: number;
+			rows: number;
+			pixelWidth?: number;
+			pixelHeight?: number;
+		},
+		opts?: { signal?: AbortSignal },
+	) => Promise<void>;
 	close: (opts?: { signal?: AbortSignal }) => Promise<void>;
 
-	// Buffer policy & stats
-	// setBufferPolicy: (policy: {
-	//   ringBytes?: number;
-	//   coalesceMs?: number;
-	// }) => Promise<void>;
-	bufferStats: () => GeneratedRussh.BufferStats;
-	currentSeq: () => number;
+	// Buffer policy
+	setBufferPolicy: (policy: BufferPolicy) => void;
 
-	// Replay + live
-	readBuffer: (cursor: Cursor, maxBytes?: bigint) => BufferReadResult;
-	addListener: (
-		cb: (ev: ListenerEvent) => void,
-		opts: ListenerOptions,
-	) => bigint;
-	removeListener: (id: bigint) => void;
+	// Recording (asciicast v2)
+	startRecording: (path: string, opts?: RecordingOptions) => void;
+	stopRecording: (opts?: {
+		signal?: AbortSignal;
+	}) => Promise<GeneratedRussh.RecordingStats | undefined>;
+};
+
+export type CastSource =
+	| { type: 'file'; path: string }
+	| { type: 'text'; contents: string };
+
+export type CastPlayerOptions = {
+	speed?: number;
+	/** Longest pause kept; defaults to the file's idle_time_limit, 0 disables. */
+	idleTimeLimitMs?: number;
+	autoplay?: boolean;
+	onStatusChanged?: (status: GeneratedRussh.PlaybackStatus) => void;
+	bufferPolicy?: BufferPolicy;
+	abortSignal?: AbortSignal;
+};
+
+export type CastPlayer = OutputBuffer & {
+	readonly info: GeneratedRussh.CastInfo;
+	getStatus: () => GeneratedRussh.PlaybackStatus;
+	play: () => void;
+	pause: () => void;
+	seek: (positionMs: number) => void;
+	setSpeed: (speed: number) => void;
 };
 
 type RusshApi = {
@@ -168,6 +385,24 @@ type RusshApi = {
 	) =>
 		| { publicKey: string; error?: never }
 		| { publicKey?: never; error: GeneratedRussh.SshError };
+	publicKeyFingerprints: (
+		publicKey: string,
+	) => GeneratedRussh.PublicKeyFingerprints;
+	openCastPlayer: (
+		source: CastSource,
+		opts?: CastPlayerOptions,
+	) => Promise<CastPlayer>;
+	createTerminalEmulator: (
+		cols: number,
+		rows: number,
+		scrollbackLines?: number,
+	) => GeneratedRussh.TerminalEmulatorInterface;
+	/**
+	 * Cap the ring buffers of all shells and players (undefined: no cap).
+	 * Throws InvalidArgument for a cap past the address space.
+	 */
+	setMemoryBudget: (bytes?: bigint) => void;
+	memoryUsage: () => GeneratedRussh.MemoryUsage;
 };
 
 // #endregion
@@ -197,19 +432,116 @@ const terminalTypeEnumToLiteral: Record<
 	[GeneratedRussh.TerminalType.Xterm256]: 'Xterm256',
 };
 
-const sshConnProgressEnumToLiteral = {
-	[GeneratedRussh.SshConnectionProgressEvent.TcpConnected]: 'tcpConnected',
-	[GeneratedRussh.SshConnectionProgressEvent.SshHandshake]: 'sshHandshake',
-} as const satisfies Record<
-	GeneratedRussh.SshConnectionProgressEvent,
-	SshConnectionProgress
->;
-
 const streamEnumToLiteral = {
 	[GeneratedRussh.StreamKind.Stdout]: 'stdout',
 	[GeneratedRussh.StreamKind.Stderr]: 'stderr',
 } as const satisfies Record<GeneratedRussh.StreamKind, StreamKind>;
 
+const compressionLiteralToEnum = {
+	off: GeneratedRussh.CompressionPreference.Off,
+	prefer: GeneratedRussh.CompressionPreference.Prefer,
+	require: GeneratedRussh.CompressionPreference.Require,
+} as const satisfies Record<
+	CompressionPreference,
+	GeneratedRussh.CompressionPreference
+>;
+
+const shellSignalLiteralToEnum = {
+	ABRT: GeneratedRussh.ShellSignal.Abrt,
+	ALRM: GeneratedRussh.ShellSignal.Alrm,
+	FPE: GeneratedRussh.ShellSignal.Fpe,
+	HUP: GeneratedRussh.ShellSignal.Hup,
+	ILL: GeneratedRussh.ShellSignal.Ill,
+	INT: GeneratedRussh.ShellSignal.Int,
+	KILL: GeneratedRussh.ShellSignal.Kill,
+	PIPE: GeneratedRussh.ShellSignal.Pipe,
+	QUIT: GeneratedRussh.ShellSignal.Quit,
+	SEGV: GeneratedRussh.ShellSignal.Segv,
+	TERM: GeneratedRussh.ShellSignal.Term,
+	USR1: GeneratedRussh.ShellSignal.Usr1,
+	USR2: GeneratedRussh.ShellSignal.Usr2,
+} as const satisfies Record<ShellSignal, GeneratedRussh.ShellSignal>;
+
+const terminfoStatusEnumToLiteral = {
+	[GeneratedRussh.TerminfoStatus.AlreadyPresent]: 'alreadyPresent',
+	[GeneratedRussh.TerminfoStatus.Installed]: 'installed',
+} as const satisfies Record<GeneratedRussh.TerminfoStatus, TerminfoStatus>;
+
+const invalidUtf8LiteralToEnum = {
+	replace: GeneratedRussh.InvalidUtf8.Replace,
+	escape: GeneratedRussh.InvalidUtf8.Escape,
+} as const satisfies Record<
+	NonNullable<ListenerOptions['text']>,
+	GeneratedRussh.InvalidUtf8
+>;
+
+function progressEventToIdeal(
+	ev: GeneratedRussh.SshConnectionProgressEvent,
+): SshConnectionProgress {
+	const Tags = GeneratedRussh.SshConnectionProgressEvent_Tags;
+	switch (ev.tag) {
+		case Tags.DnsResolved:
+			return { type: 'dnsResolved', candidates: ev.inner.candidates };
+		case Tags.TcpConnected:
+			return { type: 'tcpConnected', chosen: ev.inner.chosen };
+		case Tags.HostKeyVerified:
+			return { type: 'hostKeyVerified' };
+		case Tags.SshHandshake:
+			return { type: 'sshHandshake' };
+		case Tags.Authenticating:
+			return { type: 'authenticating' };
+		case Tags.AuthAttempt:
+			return { type: 'authAttempt', method: ev.inner.method };
+		case Tags.Authenticated:
+			return { type: 'authenticated' };
+		case Tags.Connected:
+			return { type: 'connected' };
+	}
+}
+
+function connectionEventToIdeal(
+	ev: GeneratedRussh.SshConnectionEvent,
+): SshConnectionEvent {
+	return {
+		type: 'rekeyed',
+		rekeyCount: ev.inner.rekeyCount,
+		atMs: ev.inner.atMs,
+		algorithms: ev.inner.algorithms,
+	};
+}
+
+function shellCommandToGenerated(
+	command: ShellCommand,
+): GeneratedRussh.ShellCommand {
+	switch (command.type) {
+		case 'exec':
+			return new GeneratedRussh.ShellCommand.Exec({
+				command: command.command,
+			});
+		case 'subsystem':
+			return new GeneratedRussh.ShellCommand.Subsystem({ name: command.name });
+	}
+}
+
+function shellCommandToIdeal(
+	command: GeneratedRussh.ShellCommand,
+): ShellCommand {
+	return command instanceof GeneratedRussh.ShellCommand.Exec
+		? { type: 'exec', command: command.inner.command }
+		: { type: 'subsystem', name: command.inner.name };
+}
+
+function bufferPolicyToGenerated(
+	policy: BufferPolicy,
+): GeneratedRussh.BufferPolicy {
+	return GeneratedRussh.BufferPolicy.create({
+		ringBytes: policy.ringBytes,
+		maxChunkBytes: policy.maxChunkBytes,
+		broadcastChunks: policy.broadcastChunks,
+		coalesceMs: policy.coalesceMs,
+	});
+}
+
 function generatedConnDetailsToIdeal(
 	details: GeneratedRussh.ConnectionDetails,
 ): ConnectionDetails {
@@ -239,6 +571,10 @@ function cursorToGenerated(cursor: Cursor): GeneratedRussh.Cursor {
 			return new GeneratedRussh.Cursor.TimeMs({ tMs: cursor.tMs });
 		case 'live':
 			return new GeneratedRussh.Cursor.Live();
+		case 'tailLines':
+			return new GeneratedRussh.Cursor.TailLines({ lines: cursor.lines });
+		case 'line':
+			return new GeneratedRussh.Cursor.Line({ line: cursor.line });
 	}
 }
 
@@ -251,13 +587,15 @@ function toTerminalChunk(ch: GeneratedRussh.TerminalChunk): TerminalChunk {
 	};
 }
 
-function wrapShellSession(
-	shell: GeneratedRussh.ShellSessionInterface,
-): SshShell {
-	const info = shell.getInfo();
+type GeneratedOutputBuffer = Pick<
+	GeneratedRussh.ShellSessionInterface,
+	keyof GeneratedRussh.ShellSessionInterface &
+		keyof GeneratedRussh.CastPlayerInterface
+>;
 
-	const readBuffer: SshShell['readBuffer'] = (cursor, maxBytes) => {
-		const res = shell.readBuffer(cursorToGenerated(cursor), maxBytes);
+function wrapOutputBuffer(buffer: GeneratedOutputBuffer): OutputBuffer {
+	const readBuffer: OutputBuffer['readBuffer'] = (cursor, maxBytes) => {
+		const res = buffer.readBuffer(cursorToGenerated(cursor), maxBytes);
 		return {
 			chunks: res.chunks.map(toTerminalChunk),
 			nextSeq: res.nextSeq,
@@ -265,26 +603,50 @@ function wrapShellSession(
 		} satisfies BufferReadResult;
 	};
 
-	const addListener: SshShell['addListener'] = (cb, opts) => {
+	const addListener: OutputBuffer['addListener'] = (cb, opts) => {
+		const Tags = GeneratedRussh.ShellEvent_Tags;
 		const listener = {
 			onEvent: (ev: GeneratedRussh.ShellEvent) => {
-				if (ev instanceof GeneratedRussh.ShellEvent.Chunk) {
-					cb(toTerminalChunk(ev.inner[0]!));
-				} else if (ev instanceof GeneratedRussh.ShellEvent.Dropped) {
-					cb({
-						kind: 'dropped',
-						fromSeq: ev.inner.fromSeq,
-						toSeq: ev.inner.toSeq,
-					});
+				switch (ev.tag) {
+					case Tags.Chunk:
+						cb(toTerminalChunk(ev.inner[0]));
+						break;
+					case Tags.Text: {
+						const text = ev.inner[0];
+						cb({
+							kind: 'text',
+							seq: text.seq,
+							tMs: text.tMs,
+							stream: streamEnumToLiteral[text.stream],
+							text: text.text,
+						});
+						break;
+					}
+					case Tags.Dropped:
+						cb({
+							kind: 'dropped',
+							fromSeq: ev.inner.fromSeq,
+							toSeq: ev.inner.toSeq,
+						});
+						break;
+					case Tags.Command:
+						cb({ kind: 'command', command: ev.inner[0] });
+						break;
 				}
 			},
 		} satisfies GeneratedRussh.ShellListener;
 
 		try {
-			const id = shell.addListener(listener, {
-				cursor: cursorToGenerated(opts.cursor),
-				coalesceMs: opts.coalesceMs,
-			});
+			const id = buffer.addListener(
+				listener,
+				GeneratedRussh.ListenerOptions.create({
+					cursor: cursorToGenerated(opts.cursor),
+					coalesceMs: opts.coalesceMs,
+					lossless: opts.lossless,
+					maxLagBytes: opts.maxLagBytes,
+					text: opts.text ? invalidUtf8LiteralToEnum[opts.text] : undefined,
+				}),
+			);
 			if (id === 0n) {
 				throw new Error('Failed to attach shell listener (id=0)');
 			}
@@ -297,19 +659,85 @@ function wrapShellSession(
 	};
 
 	return {
+		bufferStats: () => buffer.bufferStats(),
+		currentSeq: () => Number(buffer.currentSeq()),
+		readBuffer,
+		readText: (cursor, maxBytes) =>
+			buffer.readText(cursorToGenerated(cursor), maxBytes),
+		search: (pattern, { signal, ...opts } = {}) =>
+			buffer.search(
+				pattern,
+				GeneratedRussh.SearchOptions.create(opts),
+				signal ? { signal } : undefined,
+			),
+		addListener,
+		removeListener: (id) => buffer.removeListener(id),
+		attachEmulator: (scrollbackLines) => buffer.attachEmulator(scrollbackLines),
+		detachEmulator: () => buffer.detachEmulator(),
+		commands: () => buffer.commands(),
+	};
+}
+
+function wrapShellSession(
+	shell: GeneratedRussh.ShellSessionInterface,
+): SshShell {
+	const info = shell.getInfo();
+
+	return {
+		...wrapOutputBuffer(shell),
 		channelId: info.channelId,
 		createdAtMs: info.createdAtMs,
 		pty: terminalTypeEnumToLiteral[info.term],
+		termName: info.termName,
 		connectionId: info.connectionId,
+		ptyAllocated: info.pty,
+		command: info.command ? shellCommandToIdeal(info.command) : undefined,
+		rejectedEnv: info.rejectedEnv,
+		terminalGeometry: () => shell.getInfo().terminalGeometry,
 		sendData: (data, o) =>
 			shell.sendData(data, o?.signal ? { signal: o.signal } : undefined),
+		sendSignal: (signal, o) =>
+			shell.sendSignal(
+				shellSignalLiteralToEnum[signal],
+				o?.signal ? { signal: o.signal } : undefined,
+			),
+		sendBreak: (durationMs, o) =>
+			shell.sendBreak(
+				durationMs,
+				o?.signal ? { signal: o.signal } : undefined,
+			),
+		resize: (size, o) =>
+			shell.resize(
+				size.cols,
+				size.rows,
+				size.pixelWidth ?? 0,
+				size.pixelHeight ?? 0,
+				o?.signal ? { signal: o.signal } : undefined,
+			),
 		close: (o) => shell.close(o?.signal ? { signal: o.signal } : undefined),
-		// setBufferPolicy,
-		bufferStats: shell.bufferStats,
-		currentSeq: () => Number(shell.currentSeq()),
-		readBuffer,
-		addListener,
-		removeListener: (id) => shell.removeListener(id),
+		setBufferPolicy: (policy) =>
+			shell.setBufferPolicy(bufferPolicyToGenerated(policy)),
+		startRecording: (path, opts) =>
+			shell.startRecording(
+				path,
+				GeneratedRussh.RecordingOptions.create(opts ?? {}),
+			),
+		stopRecording: (o) =>
+			shell.stopRecording(o?.signal ? { signal: o.signal } : undefined),
+	};
+}
+
+function wrapCastPlayer(
+	player: GeneratedRussh.CastPlayerInterface,
+): CastPlayer {
+	return {
+		...wrapOutputBuffer(player),
+		info: player.getInfo(),
+		getStatus: () => player.getStatus(),
+		play: () => player.play(),
+		pause: () => player.pause(),
+		seek: (positionMs) => player.seek(positionMs),
+		setSpeed: (speed) => player.setSpeed(speed),
 	};
 }
 
@@ -322,13 +750,29 @@ function wrapConnection(
 		connectionDetails: generatedConnDetailsToIdeal(info.connectionDetails),
 		createdAtMs: info.createdAtMs,
 		connectedAtMs: info.connectedAtMs,
-		progressTimings: {
-			tcpEstablishedAtMs: info.progressTimings.tcpEstablishedAtMs,
-			sshHandshakeAtMs: info.progressTimings.sshHandshakeAtMs,
+		progressTimings: info.progressTimings,
+		serverIdentification: info.serverIdentification,
+		authBanner: info.authBanner,
+		negotiatedAlgorithms: info.negotiatedAlgorithms,
+		getMetrics: () => conn.getMetrics(),
+		rekey: (opts) =>
+			conn.rekey(opts?.signal ? { signal: opts.signal } : undefined),
+		installTerminfo: async (
+			termName: string,
+			entryOrOpts?: ArrayBuffer | { signal?: AbortSignal },
+			maybeOpts?: { signal?: AbortSignal },
+		) => {
+			const bundled = !(entryOrOpts instanceof ArrayBuffer);
+			const opts = bundled ? entryOrOpts : maybeOpts;
+			const asyncOpts = opts?.signal ? { signal: opts.signal } : undefined;
+			const status = bundled
+				? await conn.installBundledTerminfo(termName, asyncOpts)
+				: await conn.installTerminfo(termName, entryOrOpts, asyncOpts);
+			return terminfoStatusEnumToLiteral[status];
 		},
-		startShell: async ({ onClosed, ...params }) => {
+		startShell: async ({ onClosed, onChannelPause, ...params }) => {
 			const shell = await conn.startShell(
-				{
+				GeneratedRussh.StartShellOptions.create({
 					term: terminalTypeLiteralToEnum[params.term],
 					onClosedCallback: onClosed
 						? {
@@ -338,7 +782,22 @@ function wrapConnection(
 					terminalMode: params.terminalMode,
 					terminalPixelSize: params.terminalPixelSize,
 					terminalSize: params.terminalSize,
-				},
+					env: params.env ? new Map(Object.entries(params.env)) : undefined,
+					locale: params.locale,
+					command: params.command
+						? shellCommandToGenerated(params.command)
+						: undefined,
+					allocatePty: params.allocatePty,
+					termName: params.termName,
+					bufferPolicy: params.bufferPolicy
+						? bufferPolicyToGenerated(params.bufferPolicy)
+						: undefined,
+					onChannelPauseCallback: onChannelPause
+						? {
+								onChange: (paused) => onChannelPause(paused),
+							}
+						: undefined,
+				}),
 				params.abortSignal ? { signal: params.abortSignal } : undefined,
 			);
 			return wrapShellSession(shell);
@@ -352,6 +811,9 @@ async function connect({
 	onServerKey,
 	onConnectionProgress,
 	onDisconnected,
+	onAuthBanner,
+	onConnectionEvent,
+	onMetrics,
 	...options
 }: ConnectOptions): Promise<SshConnection> {
 	const security =
@@ -362,8 +824,13 @@ async function connect({
 			: new GeneratedRussh.Security.Key({
 					privateKeyContent: options.security.privateKey,
 				});
+	// Aborting also stops Rust-side work the promise itself doesn't cover
+	// (DNS, the TCP race, a pending server key decision).
+	const abortToken = new GeneratedRussh.ConnectAbortToken();
+	if (options.abortSignal?.aborted) abortToken.abort();
+	options.abortSignal?.addEventListener('abort', () => abortToken.abort());
 	const sshConnection = await GeneratedRussh.connect(
-		{
+		GeneratedRussh.ConnectOptions.create({
 			connectionDetails: {
 				host: options.host,
 				port: options.port,
@@ -372,8 +839,8 @@ async function connect({
 			},
 			onConnectionProgressCallback: onConnectionProgress
 				? {
-						onChange: (statusEnum) =>
-							onConnectionProgress(sshConnProgressEnumToLiteral[statusEnum]),
+						onChange: (event) =>
+							onConnectionProgress(progressEventToIdeal(event)),
 					}
 				: undefined,
 			onDisconnectedCallback: onDisconnected
@@ -385,7 +852,31 @@ async function connect({
 				onChange: (serverKeyInfo) =>
 					onServerKey(serverKeyInfo, options.abortSignal),
 			},
-		},
+			onAuthBannerCallback: onAuthBanner
+				? { onChange: (banner) => onAuthBanner(banner) }
+				: undefined,
+			onConnectionEventCallback: onConnectionEvent
+				? {
+						onChange: (event) =>
+							onConnectionEvent(connectionEventToIdeal(event)),
+					}
+				: undefined,
+			onMetricsCallback: onMetrics
+				? { onChange: (metrics) => onMetrics(metrics) }
+				: undefined,
+			metricsIntervalMs: options.metricsIntervalMs,
+			rekeyLimits: options.rekeyLimits
+				? {
+						writeBytes: options.rekeyLimits.writeBytes,
+						readBytes: options.rekeyLimits.readBytes,
+						timeMs: options.rekeyLimits.timeMs,
+					}
+				: undefined,
+			abortToken,
+			compression: options.compression
+				? compressionLiteralToEnum[options.compression]
+				: undefined,
+		}),
 		options.abortSignal ? { signal: options.abortSignal } : undefined,
 	);
 	return wrapConnection(sshConnection);
@@ -426,6 +917,30 @@ function extractPublicKey(
 	}
 }
 
+async function openCastPlayer(
+	source: CastSource,
+	{ onStatusChanged, abortSignal, ...opts }: CastPlayerOptions = {},
+): Promise<CastPlayer> {
+	const player = await GeneratedRussh.openCastPlayer(
+		source.type === 'file'
+			? new GeneratedRussh.CastSource.File({ path: source.path })
+			: new GeneratedRussh.CastSource.Text({ contents: source.contents }),
+		GeneratedRussh.CastPlayerOptions.create({
+			speed: opts.speed,
+			idleTimeLimitMs: opts.idleTimeLimitMs,
+			autoplay: opts.autoplay,
+			onStatusChanged: onStatusChanged
+				? { onChange: (status) => onStatusChanged(status) }
+				: undefined,
+			bufferPolicy: opts.bufferPolicy
+				? bufferPolicyToGenerated(opts.bufferPolicy)
+				: undefined,
+		}),
+		abortSignal ? { signal: abortSignal } : undefined,
+	);
+	return wrapCastPlayer(player);
+}
+
 // #endregion
 
 export { SshError, SshError_Tags } from './generated/uniffi_russh';
@@ -436,4 +951,10 @@ export const RnRussh = {
 	generateKeyPair,
 	validatePrivateKey,
 	extractPublicKey,
+	publicKeyFingerprints: GeneratedRussh.publicKeyFingerprints,
+	openCastPlayer,
+	createTerminalEmulator: (cols, rows, scrollbackLines) =>
+		new GeneratedRussh.TerminalEmulator(cols, rows, scrollbackLines),
+	setMemoryBudget: GeneratedRussh.setMemoryBudget,
+	memoryUsage: GeneratedRussh.memoryUsage,
 } satisfies RusshApi;
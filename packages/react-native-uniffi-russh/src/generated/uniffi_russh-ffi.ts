@@ -27,6 +27,156 @@ interface NativeModuleInterface {
 		buffer: Uint8Array,
 		uniffi_out_err: UniffiRustCallStatus,
 	): string;
+	ubrn_uniffi_uniffi_russh_fn_clone_authbannercallback(
+		ptr: bigint,
+		uniffi_out_err: UniffiRustCallStatus,
+	): bigint;
+	ubrn_uniffi_uniffi_russh_fn_free_authbannercallback(
+		ptr: bigint,
+		uniffi_out_err: UniffiRustCallStatus,
+	): void;
+	ubrn_uniffi_uniffi_russh_fn_init_callback_vtable_authbannercallback(
+		vtable: UniffiVTableCallbackInterfaceAuthBannerCallback,
+	): void;
+	ubrn_uniffi_uniffi_russh_fn_method_authbannercallback_on_change(
+		ptr: bigint,
+		banner: Uint8Array,
+		uniffi_out_err: UniffiRustCallStatus,
+	): void;
+	ubrn_uniffi_uniffi_russh_fn_clone_castplayer(
+		ptr: bigint,
+		uniffi_out_err: UniffiRustCallStatus,
+	): bigint;
+	ubrn_uniffi_uniffi_russh_fn_free_castplayer(
+		ptr: bigint,
+		uniffi_out_err: UniffiRustCallStatus,
+	): void;
+	ubrn_uniffi_uniffi_russh_fn_method_castplayer_add_listener(
+		ptr: bigint,
+		listener: bigint,
+		opts: Uint8Array,
+		uniffi_out_err: UniffiRustCallStatus,
+	): bigint;
+	ubrn_uniffi_uniffi_russh_fn_method_castplayer_attach_emulator(
+		ptr: bigint,
+		scrollbackLines: Uint8Array,
+		uniffi_out_err: UniffiRustCallStatus,
+	): bigint;
+	ubrn_uniffi_uniffi_russh_fn_method_castplayer_buffer_stats(
+		ptr: bigint,
+		uniffi_out_err: UniffiRustCallStatus,
+	): Uint8Array;
+	ubrn_uniffi_uniffi_russh_fn_method_castplayer_commands(
+		ptr: bigint,
+		uniffi_out_err: UniffiRustCallStatus,
+	): Uint8Array;
+	ubrn_uniffi_uniffi_russh_fn_method_castplayer_current_seq(
+		ptr: bigint,
+		uniffi_out_err: UniffiRustCallStatus,
+	): bigint;
+	ubrn_uniffi_uniffi_russh_fn_method_castplayer_detach_emulator(
+		ptr: bigint,
+		uniffi_out_err: UniffiRustCallStatus,
+	): void;
+	ubrn_uniffi_uniffi_russh_fn_method_castplayer_get_info(
+		ptr: bigint,
+		uniffi_out_err: UniffiRustCallStatus,
+	): Uint8Array;
+	ubrn_uniffi_uniffi_russh_fn_method_castplayer_get_status(
+		ptr: bigint,
+		uniffi_out_err: UniffiRustCallStatus,
+	): Uint8Array;
+	ubrn_uniffi_uniffi_russh_fn_method_castplayer_pause(
+		ptr: bigint,
+		uniffi_out_err: UniffiRustCallStatus,
+	): void;
+	ubrn_uniffi_uniffi_russh_fn_method_castplayer_play(
+		ptr: bigint,
+		uniffi_out_err: UniffiRustCallStatus,
+	): void;
+	ubrn_uniffi_uniffi_russh_fn_method_castplayer_read_buffer(
+		ptr: bigint,
+		cursor: Uint8Array,
+		maxBytes: Uint8Array,
+		uniffi_out_err: UniffiRustCallStatus,
+	): Uint8Array;
+	ubrn_uniffi_uniffi_russh_fn_method_castplayer_read_text(
+		ptr: bigint,
+		cursor: Uint8Array,
+		maxBytes: Uint8Array,
+		uniffi_out_err: UniffiRustCallStatus,
+	): Uint8Array;
+	ubrn_uniffi_uniffi_russh_fn_method_castplayer_remove_listener(
+		ptr: bigint,
+		id: bigint,
+		uniffi_out_err: UniffiRustCallStatus,
+	): void;
+	ubrn_uniffi_uniffi_russh_fn_method_castplayer_search(
+		ptr: bigint,
+		pattern: Uint8Array,
+		opts: Uint8Array,
+	): bigint;
+	ubrn_uniffi_uniffi_russh_fn_method_castplayer_seek(
+		ptr: bigint,
+		positionMs: number,
+		uniffi_out_err: UniffiRustCallStatus,
+	): void;
+	ubrn_uniffi_uniffi_russh_fn_method_castplayer_set_speed(
+		ptr: bigint,
+		speed: number,
+		uniffi_out_err: UniffiRustCallStatus,
+	): void;
+	ubrn_uniffi_uniffi_russh_fn_clone_castplayerstatuscallback(
+		ptr: bigint,
+		uniffi_out_err: UniffiRustCallStatus,
+	): bigint;
+	ubrn_uniffi_uniffi_russh_fn_free_castplayerstatuscallback(
+		ptr: bigint,
+		uniffi_out_err: UniffiRustCallStatus,
+	): void;
+	ubrn_uniffi_uniffi_russh_fn_init_callback_vtable_castplayerstatuscallback(
+		vtable: UniffiVTableCallbackInterfaceCastPlayerStatusCallback,
+	): void;
+	ubrn_uniffi_uniffi_russh_fn_method_castplayerstatuscallback_on_change(
+		ptr: bigint,
+		status: Uint8Array,
+		uniffi_out_err: UniffiRustCallStatus,
+	): void;
+	ubrn_uniffi_uniffi_russh_fn_clone_channelpausecallback(
+		ptr: bigint,
+		uniffi_out_err: UniffiRustCallStatus,
+	): bigint;
+	ubrn_uniffi_uniffi_russh_fn_free_channelpausecallback(
+		ptr: bigint,
+		uniffi_out_err: UniffiRustCallStatus,
+	): void;
+	ubrn_uniffi_uniffi_russh_fn_init_callback_vtable_channelpausecallback(
+		vtable: UniffiVTableCallbackInterfaceChannelPauseCallback,
+	): void;
+	ubrn_uniffi_uniffi_russh_fn_method_channelpausecallback_on_change(
+		ptr: bigint,
+		paused: number,
+		uniffi_out_err: UniffiRustCallStatus,
+	): void;
+	ubrn_uniffi_uniffi_russh_fn_clone_connectaborttoken(
+		ptr: bigint,
+		uniffi_out_err: UniffiRustCallStatus,
+	): bigint;
+	ubrn_uniffi_uniffi_russh_fn_free_connectaborttoken(
+		ptr: bigint,
+		uniffi_out_err: UniffiRustCallStatus,
+	): void;
+	ubrn_uniffi_uniffi_russh_fn_constructor_connectaborttoken_new(
+		uniffi_out_err: UniffiRustCallStatus,
+	): bigint;
+	ubrn_uniffi_uniffi_russh_fn_method_connectaborttoken_abort(
+		ptr: bigint,
+		uniffi_out_err: UniffiRustCallStatus,
+	): void;
+	ubrn_uniffi_uniffi_russh_fn_method_connectaborttoken_is_aborted(
+		ptr: bigint,
+		uniffi_out_err: UniffiRustCallStatus,
+	): number;
 	ubrn_uniffi_uniffi_russh_fn_clone_connectprogresscallback(
 		ptr: bigint,
 		uniffi_out_err: UniffiRustCallStatus,
@@ -59,6 +209,38 @@ interface NativeModuleInterface {
 		connectionId: Uint8Array,
 		uniffi_out_err: UniffiRustCallStatus,
 	): void;
+	ubrn_uniffi_uniffi_russh_fn_clone_connectioneventcallback(
+		ptr: bigint,
+		uniffi_out_err: UniffiRustCallStatus,
+	): bigint;
+	ubrn_uniffi_uniffi_russh_fn_free_connectioneventcallback(
+		ptr: bigint,
+		uniffi_out_err: UniffiRustCallStatus,
+	): void;
+	ubrn_uniffi_uniffi_russh_fn_init_callback_vtable_connectioneventcallback(
+		vtable: UniffiVTableCallbackInterfaceConnectionEventCallback,
+	): void;
+	ubrn_uniffi_uniffi_russh_fn_method_connectioneventcallback_on_change(
+		ptr: bigint,
+		event: Uint8Array,
+		uniffi_out_err: UniffiRustCallStatus,
+	): void;
+	ubrn_uniffi_uniffi_russh_fn_clone_connectionmetricscallback(
+		ptr: bigint,
+		uniffi_out_err: UniffiRustCallStatus,
+	): bigint;
+	ubrn_uniffi_uniffi_russh_fn_free_connectionmetricscallback(
+		ptr: bigint,
+		uniffi_out_err: UniffiRustCallStatus,
+	): void;
+	ubrn_uniffi_uniffi_russh_fn_init_callback_vtable_connectionmetricscallback(
+		vtable: UniffiVTableCallbackInterfaceConnectionMetricsCallback,
+	): void;
+	ubrn_uniffi_uniffi_russh_fn_method_connectionmetricscallback_on_change(
+		ptr: bigint,
+		metrics: Uint8Array,
+		uniffi_out_err: UniffiRustCallStatus,
+	): void;
 	ubrn_uniffi_uniffi_russh_fn_clone_serverkeycallback(
 		ptr: bigint,
 		uniffi_out_err: UniffiRustCallStatus,
@@ -120,15 +302,28 @@ interface NativeModuleInterface {
 		opts: Uint8Array,
 		uniffi_out_err: UniffiRustCallStatus,
 	): bigint;
+	ubrn_uniffi_uniffi_russh_fn_method_shellsession_attach_emulator(
+		ptr: bigint,
+		scrollbackLines: Uint8Array,
+		uniffi_out_err: UniffiRustCallStatus,
+	): bigint;
 	ubrn_uniffi_uniffi_russh_fn_method_shellsession_buffer_stats(
 		ptr: bigint,
 		uniffi_out_err: UniffiRustCallStatus,
 	): Uint8Array;
 	ubrn_uniffi_uniffi_russh_fn_method_shellsession_close(ptr: bigint): bigint;
+	ubrn_uniffi_uniffi_russh_fn_method_shellsession_commands(
+		ptr: bigint,
+		uniffi_out_err: UniffiRustCallStatus,
+	): Uint8Array;
 	ubrn_uniffi_uniffi_russh_fn_method_shellsession_current_seq(
 		ptr: bigint,
 		uniffi_out_err: UniffiRustCallStatus,
 	): bigint;
+	ubrn_uniffi_uniffi_russh_fn_method_shellsession_detach_emulator(
+		ptr: bigint,
+		uniffi_out_err: UniffiRustCallStatus,
+	): void;
 	ubrn_uniffi_uniffi_russh_fn_method_shellsession_get_info(
 		ptr: bigint,
 		uniffi_out_err: UniffiRustCallStatus,
@@ -139,15 +334,55 @@ interface NativeModuleInterface {
 		maxBytes: Uint8Array,
 		uniffi_out_err: UniffiRustCallStatus,
 	): Uint8Array;
+	ubrn_uniffi_uniffi_russh_fn_method_shellsession_read_text(
+		ptr: bigint,
+		cursor: Uint8Array,
+		maxBytes: Uint8Array,
+		uniffi_out_err: UniffiRustCallStatus,
+	): Uint8Array;
 	ubrn_uniffi_uniffi_russh_fn_method_shellsession_remove_listener(
 		ptr: bigint,
 		id: bigint,
 		uniffi_out_err: UniffiRustCallStatus,
 	): void;
+	ubrn_uniffi_uniffi_russh_fn_method_shellsession_resize(
+		ptr: bigint,
+		cols: number,
+		rows: number,
+		pixelWidth: number,
+		pixelHeight: number,
+	): bigint;
+	ubrn_uniffi_uniffi_russh_fn_method_shellsession_search(
+		ptr: bigint,
+		pattern: Uint8Array,
+		opts: Uint8Array,
+	): bigint;
+	ubrn_uniffi_uniffi_russh_fn_method_shellsession_send_break(
+		ptr: bigint,
+		durationMs: number,
+	): bigint;
 	ubrn_uniffi_uniffi_russh_fn_method_shellsession_send_data(
 		ptr: bigint,
 		data: Uint8Array,
 	): bigint;
+	ubrn_uniffi_uniffi_russh_fn_method_shellsession_send_signal(
+		ptr: bigint,
+		signal: Uint8Array,
+	): bigint;
+	ubrn_uniffi_uniffi_russh_fn_method_shellsession_set_buffer_policy(
+		ptr: bigint,
+		policy: Uint8Array,
+		uniffi_out_err: UniffiRustCallStatus,
+	): void;
+	ubrn_uniffi_uniffi_russh_fn_method_shellsession_start_recording(
+		ptr: bigint,
+		path: Uint8Array,
+		opts: Uint8Array,
+		uniffi_out_err: UniffiRustCallStatus,
+	): void;
+	ubrn_uniffi_uniffi_russh_fn_method_shellsession_stop_recording(
+		ptr: bigint,
+	): bigint;
 	ubrn_uniffi_uniffi_russh_fn_clone_sshconnection(
 		ptr: bigint,
 		uniffi_out_err: UniffiRustCallStatus,
@@ -163,10 +398,71 @@ interface NativeModuleInterface {
 		ptr: bigint,
 		uniffi_out_err: UniffiRustCallStatus,
 	): Uint8Array;
+	ubrn_uniffi_uniffi_russh_fn_method_sshconnection_get_metrics(
+		ptr: bigint,
+		uniffi_out_err: UniffiRustCallStatus,
+	): Uint8Array;
+	ubrn_uniffi_uniffi_russh_fn_method_sshconnection_install_bundled_terminfo(
+		ptr: bigint,
+		termName: Uint8Array,
+	): bigint;
+	ubrn_uniffi_uniffi_russh_fn_method_sshconnection_install_terminfo(
+		ptr: bigint,
+		termName: Uint8Array,
+		compiledEntry: Uint8Array,
+	): bigint;
+	ubrn_uniffi_uniffi_russh_fn_method_sshconnection_rekey(ptr: bigint): bigint;
 	ubrn_uniffi_uniffi_russh_fn_method_sshconnection_start_shell(
 		ptr: bigint,
 		opts: Uint8Array,
 	): bigint;
+	ubrn_uniffi_uniffi_russh_fn_clone_terminalemulator(
+		ptr: bigint,
+		uniffi_out_err: UniffiRustCallStatus,
+	): bigint;
+	ubrn_uniffi_uniffi_russh_fn_free_terminalemulator(
+		ptr: bigint,
+		uniffi_out_err: UniffiRustCallStatus,
+	): void;
+	ubrn_uniffi_uniffi_russh_fn_constructor_terminalemulator_new(
+		cols: number,
+		rows: number,
+		scrollbackLines: Uint8Array,
+		uniffi_out_err: UniffiRustCallStatus,
+	): bigint;
+	ubrn_uniffi_uniffi_russh_fn_method_terminalemulator_feed(
+		ptr: bigint,
+		bytes: Uint8Array,
+		uniffi_out_err: UniffiRustCallStatus,
+	): void;
+	ubrn_uniffi_uniffi_russh_fn_method_terminalemulator_resize(
+		ptr: bigint,
+		cols: number,
+		rows: number,
+		uniffi_out_err: UniffiRustCallStatus,
+	): void;
+	ubrn_uniffi_uniffi_russh_fn_method_terminalemulator_scrollback_len(
+		ptr: bigint,
+		uniffi_out_err: UniffiRustCallStatus,
+	): bigint;
+	ubrn_uniffi_uniffi_russh_fn_method_terminalemulator_scrollback_lines(
+		ptr: bigint,
+		start: bigint,
+		count: number,
+		uniffi_out_err: UniffiRustCallStatus,
+	): Uint8Array;
+	ubrn_uniffi_uniffi_russh_fn_method_terminalemulator_snapshot(
+		ptr: bigint,
+		uniffi_out_err: UniffiRustCallStatus,
+	): Uint8Array;
+	ubrn_uniffi_uniffi_russh_fn_method_terminalemulator_take_damage(
+		ptr: bigint,
+		uniffi_out_err: UniffiRustCallStatus,
+	): Uint8Array;
+	ubrn_uniffi_uniffi_russh_fn_method_terminalemulator_take_responses(
+		ptr: bigint,
+		uniffi_out_err: UniffiRustCallStatus,
+	): Uint8Array;
 	ubrn_uniffi_uniffi_russh_fn_func_connect(options: Uint8Array): bigint;
 	ubrn_uniffi_uniffi_russh_fn_func_extract_public_key(
 		privateKeyContent: Uint8Array,
@@ -176,6 +472,21 @@ interface NativeModuleInterface {
 		keyType: Uint8Array,
 		uniffi_out_err: UniffiRustCallStatus,
 	): Uint8Array;
+	ubrn_uniffi_uniffi_russh_fn_func_memory_usage(
+		uniffi_out_err: UniffiRustCallStatus,
+	): Uint8Array;
+	ubrn_uniffi_uniffi_russh_fn_func_open_cast_player(
+		source: Uint8Array,
+		options: Uint8Array,
+	): bigint;
+	ubrn_uniffi_uniffi_russh_fn_func_public_key_fingerprints(
+		publicKey: Uint8Array,
+		uniffi_out_err: UniffiRustCallStatus,
+	): Uint8Array;
+	ubrn_uniffi_uniffi_russh_fn_func_set_memory_budget(
+		bytes: Uint8Array,
+		uniffi_out_err: UniffiRustCallStatus,
+	): void;
 	ubrn_uniffi_uniffi_russh_fn_func_validate_private_key(
 		privateKeyContent: Uint8Array,
 		uniffi_out_err: UniffiRustCallStatus,
@@ -326,24 +637,95 @@ interface NativeModuleInterface {
 	ubrn_uniffi_uniffi_russh_checksum_func_connect(): number;
 	ubrn_uniffi_uniffi_russh_checksum_func_extract_public_key(): number;
 	ubrn_uniffi_uniffi_russh_checksum_func_generate_key_pair(): number;
+	ubrn_uniffi_uniffi_russh_checksum_func_memory_usage(): number;
+	ubrn_uniffi_uniffi_russh_checksum_func_open_cast_player(): number;
+	ubrn_uniffi_uniffi_russh_checksum_func_public_key_fingerprints(): number;
+	ubrn_uniffi_uniffi_russh_checksum_func_set_memory_budget(): number;
 	ubrn_uniffi_uniffi_russh_checksum_func_validate_private_key(): number;
+	ubrn_uniffi_uniffi_russh_checksum_method_authbannercallback_on_change(): number;
+	ubrn_uniffi_uniffi_russh_checksum_method_castplayer_add_listener(): number;
+	ubrn_uniffi_uniffi_russh_checksum_method_castplayer_attach_emulator(): number;
+	ubrn_uniffi_uniffi_russh_checksum_method_castplayer_buffer_stats(): number;
+	ubrn_uniffi_uniffi_russh_checksum_method_castplayer_commands(): number;
+	ubrn_uniffi_uniffi_russh_checksum_method_castplayer_current_seq(): number;
+	ubrn_uniffi_uniffi_russh_checksum_method_castplayer_detach_emulator(): number;
+	ubrn_uniffi_uniffi_russh_checksum_method_castplayer_get_info(): number;
+	ubrn_uniffi_uniffi_russh_checksum_method_castplayer_get_status(): number;
+	ubrn_uniffi_uniffi_russh_checksum_method_castplayer_pause(): number;
+	ubrn_uniffi_uniffi_russh_checksum_method_castplayer_play(): number;
+	ubrn_uniffi_uniffi_russh_checksum_method_castplayer_read_buffer(): number;
+	ubrn_uniffi_uniffi_russh_checksum_method_castplayer_read_text(): number;
+	ubrn_uniffi_uniffi_russh_checksum_method_castplayer_remove_listener(): number;
+	ubrn_uniffi_uniffi_russh_checksum_method_castplayer_search(): number;
+	ubrn_uniffi_uniffi_russh_checksum_method_castplayer_seek(): number;
+	ubrn_uniffi_uniffi_russh_checksum_method_castplayer_set_speed(): number;
+	ubrn_uniffi_uniffi_russh_checksum_method_castplayerstatuscallback_on_change(): number;
+	ubrn_uniffi_uniffi_russh_checksum_method_channelpausecallback_on_change(): number;
+	ubrn_uniffi_uniffi_russh_checksum_method_connectaborttoken_abort(): number;
+	ubrn_uniffi_uniffi_russh_checksum_method_connectaborttoken_is_aborted(): number;
 	ubrn_uniffi_uniffi_russh_checksum_method_connectprogresscallback_on_change(): number;
 	ubrn_uniffi_uniffi_russh_checksum_method_connectiondisconnectedcallback_on_change(): number;
+	ubrn_uniffi_uniffi_russh_checksum_method_connectioneventcallback_on_change(): number;
+	ubrn_uniffi_uniffi_russh_checksum_method_connectionmetricscallback_on_change(): number;
 	ubrn_uniffi_uniffi_russh_checksum_method_serverkeycallback_on_change(): number;
 	ubrn_uniffi_uniffi_russh_checksum_method_shellclosedcallback_on_change(): number;
 	ubrn_uniffi_uniffi_russh_checksum_method_shelllistener_on_event(): number;
 	ubrn_uniffi_uniffi_russh_checksum_method_shellsession_add_listener(): number;
+	ubrn_uniffi_uniffi_russh_checksum_method_shellsession_attach_emulator(): number;
 	ubrn_uniffi_uniffi_russh_checksum_method_shellsession_buffer_stats(): number;
 	ubrn_uniffi_uniffi_russh_checksum_method_shellsession_close(): number;
+	ubrn_uniffi_uniffi_russh_checksum_method_shellsession_commands(): number;
 	ubrn_uniffi_uniffi_russh_checksum_method_shellsession_current_seq(): number;
+	ubrn_uniffi_uniffi_russh_checksum_method_shellsession_detach_emulator(): number;
 	ubrn_uniffi_uniffi_russh_checksum_method_shellsession_get_info(): number;
 	ubrn_uniffi_uniffi_russh_checksum_method_shellsession_read_buffer(): number;
+	ubrn_uniffi_uniffi_russh_checksum_method_shellsession_read_text(): number;
 	ubrn_uniffi_uniffi_russh_checksum_method_shellsession_remove_listener(): number;
+	ubrn_uniffi_uniffi_russh_checksum_method_shellsession_resize(): number;
+	ubrn_uniffi_uniffi_russh_checksum_method_shellsession_search(): number;
+	ubrn_uniffi_uniffi_russh_checksum_method_shellsession_send_break(): number;
 	ubrn_uniffi_uniffi_russh_checksum_method_shellsession_send_data(): number;
+	ubrn_uniffi_uniffi_russh_checksum_method_shellsession_send_signal(): number;
+	ubrn_uniffi_uniffi_russh_checksum_method_shellsession_set_buffer_policy(): number;
+	ubrn_uniffi_uniffi_russh_checksum_method_shellsession_start_recording(): number;
+	ubrn_uniffi_uniffi_russh_checksum_method_shellsession_stop_recording(): number;
 	ubrn_uniffi_uniffi_russh_checksum_method_sshconnection_disconnect(): number;
 	ubrn_uniffi_uniffi_russh_checksum_method_sshconnection_get_info(): number;
+	ubrn_uniffi_uniffi_russh_checksum_method_sshconnection_get_metrics(): number;
+	ubrn_uniffi_uniffi_russh_checksum_method_sshconnection_install_bundled_terminfo(): number;
+	ubrn_uniffi_uniffi_russh_checksum_method_sshconnection_install_terminfo(): number;
+	ubrn_uniffi_uniffi_russh_checksum_method_sshconnection_rekey(): number;
 	ubrn_uniffi_uniffi_russh_checksum_method_sshconnection_start_shell(): number;
+	ubrn_uniffi_uniffi_russh_checksum_method_terminalemulator_feed(): number;
+	ubrn_uniffi_uniffi_russh_checksum_method_terminalemulator_resize(): number;
+	ubrn_uniffi_uniffi_russh_checksum_method_terminalemulator_scrollback_len(): number;
+	ubrn_uniffi_uniffi_russh_checksum_method_terminalemulator_scrollback_lines(): number;
+	ubrn_uniffi_uniffi_russh_checksum_method_terminalemulator_snapshot(): number;
+	ubrn_uniffi_uniffi_russh_checksum_method_terminalemulator_take_damage(): number;
+	ubrn_uniffi_uniffi_russh_checksum_method_terminalemulator_take_responses(): number;
+	ubrn_uniffi_uniffi_russh_checksum_constructor_connectaborttoken_new(): number;
+	ubrn_uniffi_uniffi_russh_checksum_constructor_terminalemulator_new(): number;
 	ubrn_ffi_uniffi_russh_uniffi_contract_version(): number;
+	ubrn_uniffi_internal_fn_method_authbannercallback_ffi__bless_pointer(
+		pointer: bigint,
+		uniffi_out_err: UniffiRustCallStatus,
+	): UniffiRustArcPtr;
+	ubrn_uniffi_internal_fn_method_castplayer_ffi__bless_pointer(
+		pointer: bigint,
+		uniffi_out_err: UniffiRustCallStatus,
+	): UniffiRustArcPtr;
+	ubrn_uniffi_internal_fn_method_castplayerstatuscallback_ffi__bless_pointer(
+		pointer: bigint,
+		uniffi_out_err: UniffiRustCallStatus,
+	): UniffiRustArcPtr;
+	ubrn_uniffi_internal_fn_method_channelpausecallback_ffi__bless_pointer(
+		pointer: bigint,
+		uniffi_out_err: UniffiRustCallStatus,
+	): UniffiRustArcPtr;
+	ubrn_uniffi_internal_fn_method_connectaborttoken_ffi__bless_pointer(
+		pointer: bigint,
+		uniffi_out_err: UniffiRustCallStatus,
+	): UniffiRustArcPtr;
 	ubrn_uniffi_internal_fn_method_connectprogresscallback_ffi__bless_pointer(
 		pointer: bigint,
 		uniffi_out_err: UniffiRustCallStatus,
@@ -352,6 +734,14 @@ interface NativeModuleInterface {
 		pointer: bigint,
 		uniffi_out_err: UniffiRustCallStatus,
 	): UniffiRustArcPtr;
+	ubrn_uniffi_internal_fn_method_connectioneventcallback_ffi__bless_pointer(
+		pointer: bigint,
+		uniffi_out_err: UniffiRustCallStatus,
+	): UniffiRustArcPtr;
+	ubrn_uniffi_internal_fn_method_connectionmetricscallback_ffi__bless_pointer(
+		pointer: bigint,
+		uniffi_out_err: UniffiRustCallStatus,
+	): UniffiRustArcPtr;
 	ubrn_uniffi_internal_fn_method_serverkeycallback_ffi__bless_pointer(
 		pointer: bigint,
 		uniffi_out_err: UniffiRustCallStatus,
@@ -372,6 +762,10 @@ interface NativeModuleInterface {
 		pointer: bigint,
 		uniffi_out_err: UniffiRustCallStatus,
 	): UniffiRustArcPtr;
+	ubrn_uniffi_internal_fn_method_terminalemulator_ffi__bless_pointer(
+		pointer: bigint,
+		uniffi_out_err: UniffiRustCallStatus,
+	): UniffiRustArcPtr;
 }
 
 // Casting globalThis to any allows us to look for `NativeUniffiRussh`
@@ -497,6 +891,18 @@ export type UniffiForeignFutureCompleteVoid = (
 	callbackData: bigint,
 	result: UniffiForeignFutureStructVoid,
 ) => void;
+type UniffiCallbackInterfaceAuthBannerCallbackMethod0 = (
+	uniffiHandle: bigint,
+	banner: Uint8Array,
+) => UniffiResult<void>;
+type UniffiCallbackInterfaceCastPlayerStatusCallbackMethod0 = (
+	uniffiHandle: bigint,
+	status: Uint8Array,
+) => UniffiResult<void>;
+type UniffiCallbackInterfaceChannelPauseCallbackMethod0 = (
+	uniffiHandle: bigint,
+	paused: number,
+) => UniffiResult<void>;
 type UniffiCallbackInterfaceConnectProgressCallbackMethod0 = (
 	uniffiHandle: bigint,
 	status: Uint8Array,
@@ -505,6 +911,14 @@ type UniffiCallbackInterfaceConnectionDisconnectedCallbackMethod0 = (
 	uniffiHandle: bigint,
 	connectionId: Uint8Array,
 ) => UniffiResult<void>;
+type UniffiCallbackInterfaceConnectionEventCallbackMethod0 = (
+	uniffiHandle: bigint,
+	event: Uint8Array,
+) => UniffiResult<void>;
+type UniffiCallbackInterfaceConnectionMetricsCallbackMethod0 = (
+	uniffiHandle: bigint,
+	metrics: Uint8Array,
+) => UniffiResult<void>;
 type UniffiCallbackInterfaceServerKeyCallbackMethod0 = (
 	uniffiHandle: bigint,
 	serverKeyInfo: Uint8Array,
@@ -519,6 +933,18 @@ type UniffiCallbackInterfaceShellListenerMethod0 = (
 	uniffiHandle: bigint,
 	ev: Uint8Array,
 ) => UniffiResult<void>;
+export type UniffiVTableCallbackInterfaceAuthBannerCallback = {
+	onChange: UniffiCallbackInterfaceAuthBannerCallbackMethod0;
+	uniffiFree: UniffiCallbackInterfaceFree;
+};
+export type UniffiVTableCallbackInterfaceCastPlayerStatusCallback = {
+	onChange: UniffiCallbackInterfaceCastPlayerStatusCallbackMethod0;
+	uniffiFree: UniffiCallbackInterfaceFree;
+};
+export type UniffiVTableCallbackInterfaceChannelPauseCallback = {
+	onChange: UniffiCallbackInterfaceChannelPauseCallbackMethod0;
+	uniffiFree: UniffiCallbackInterfaceFree;
+};
 export type UniffiVTableCallbackInterfaceConnectProgressCallback = {
 	onChange: UniffiCallbackInterfaceConnectProgressCallbackMethod0;
 	uniffiFree: UniffiCallbackInterfaceFree;
@@ -527,6 +953,14 @@ export type UniffiVTableCallbackInterfaceConnectionDisconnectedCallback = {
 	onChange: UniffiCallbackInterfaceConnectionDisconnectedCallbackMethod0;
 	uniffiFree: UniffiCallbackInterfaceFree;
 };
+export type UniffiVTableCallbackInterfaceConnectionEventCallback = {
+	onChange: UniffiCallbackInterfaceConnectionEventCallbackMethod0;
+	uniffiFree: UniffiCallbackInterfaceFree;
+};
+export type UniffiVTableCallbackInterfaceConnectionMetricsCallback = {
+	onChange: UniffiCallbackInterfaceConnectionMetricsCallbackMethod0;
+	uniffiFree: UniffiCallbackInterfaceFree;
+};
 export type UniffiVTableCallbackInterfaceServerKeyCallback = {
 	onChange: UniffiCallbackInterfaceServerKeyCallbackMethod0;
 	uniffiFree: UniffiCallbackInterfaceFree;
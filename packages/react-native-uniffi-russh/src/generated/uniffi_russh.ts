@@ -33,8 +33,13 @@ import nativeModule, {
 	type UniffiForeignFutureCompleteRustBuffer,
 	type UniffiForeignFutureStructVoid,
 	type UniffiForeignFutureCompleteVoid,
+	type UniffiVTableCallbackInterfaceAuthBannerCallback,
+	type UniffiVTableCallbackInterfaceCastPlayerStatusCallback,
+	type UniffiVTableCallbackInterfaceChannelPauseCallback,
 	type UniffiVTableCallbackInterfaceConnectProgressCallback,
 	type UniffiVTableCallbackInterfaceConnectionDisconnectedCallback,
+	type UniffiVTableCallbackInterfaceConnectionEventCallback,
+	type UniffiVTableCallbackInterfaceConnectionMetricsCallback,
 	type UniffiVTableCallbackInterfaceServerKeyCallback,
 	type UniffiVTableCallbackInterfaceShellClosedCallback,
 	type UniffiVTableCallbackInterfaceShellListener,
@@ -54,6 +59,7 @@ import {
 	FfiConverterBool,
 	FfiConverterFloat64,
 	FfiConverterInt32,
+	FfiConverterMap,
 	FfiConverterObject,
 	FfiConverterObjectWithCallbacks,
 	FfiConverterOptional,
@@ -163,6 +169,103 @@ export function generateKeyPair(keyType: KeyType): string /*throws*/ {
 		),
 	);
 }
+export function memoryUsage(): MemoryUsage {
+	return FfiConverterTypeMemoryUsage.lift(
+		uniffiCaller.rustCall(
+			/*caller:*/ (callStatus) => {
+				return nativeModule().ubrn_uniffi_uniffi_russh_fn_func_memory_usage(
+					callStatus,
+				);
+			},
+			/*liftString:*/ FfiConverterString.lift,
+		),
+	);
+}
+/**
+ * Load an asciicast v2 (or v1) recording. Paused at the start unless
+ * `autoplay` is set.
+ */
+export async function openCastPlayer(
+	source: CastSource,
+	options: CastPlayerOptions,
+	asyncOpts_?: { signal: AbortSignal },
+): Promise<CastPlayerInterface> /*throws*/ {
+	const __stack = uniffiIsDebug ? new Error().stack : undefined;
+	try {
+		return await uniffiRustCallAsync(
+			/*rustCaller:*/ uniffiCaller,
+			/*rustFutureFunc:*/ () => {
+				return nativeModule().ubrn_uniffi_uniffi_russh_fn_func_open_cast_player(
+					FfiConverterTypeCastSource.lower(source),
+					FfiConverterTypeCastPlayerOptions.lower(options),
+				);
+			},
+			/*pollFunc:*/ nativeModule()
+				.ubrn_ffi_uniffi_russh_rust_future_poll_pointer,
+			/*cancelFunc:*/ nativeModule()
+				.ubrn_ffi_uniffi_russh_rust_future_cancel_pointer,
+			/*completeFunc:*/ nativeModule()
+				.ubrn_ffi_uniffi_russh_rust_future_complete_pointer,
+			/*freeFunc:*/ nativeModule()
+				.ubrn_ffi_uniffi_russh_rust_future_free_pointer,
+			/*liftFunc:*/ FfiConverterTypeCastPlayer.lift.bind(
+				FfiConverterTypeCastPlayer,
+			),
+			/*liftString:*/ FfiConverterString.lift,
+			/*asyncOpts:*/ asyncOpts_,
+			/*errorHandler:*/ FfiConverterTypeSshError.lift.bind(
+				FfiConverterTypeSshError,
+			),
+		);
+	} catch (__error: any) {
+		if (uniffiIsDebug && __error instanceof Error) {
+			__error.stack = __stack;
+		}
+		throw __error;
+	}
+}
+/**
+ * Fingerprints and randomart for a public key given as an OpenSSH line
+ * ("ssh-ed25519 AAAA... comment", e.g. from `extract_public_key`) or as the
+ * bare base64 key blob (e.g. `ServerPublicKeyInfo.key_base64`).
+ */
+export function publicKeyFingerprints(
+	publicKey: string,
+): PublicKeyFingerprints /*throws*/ {
+	return FfiConverterTypePublicKeyFingerprints.lift(
+		uniffiCaller.rustCallWithError(
+			/*liftError:*/ FfiConverterTypeSshError.lift.bind(
+				FfiConverterTypeSshError,
+			),
+			/*caller:*/ (callStatus) => {
+				return nativeModule().ubrn_uniffi_uniffi_russh_fn_func_public_key_fingerprints(
+					FfiConverterString.lower(publicKey),
+					callStatus,
+				);
+			},
+			/*liftString:*/ FfiConverterString.lift,
+		),
+	);
+}
+/**
+ * Cap the ring buffers of all sessions at `bytes` in total (None: no cap).
+ * Over the cap, the chunks appended first go first, whichever session or
+ * player holds them.
+ */
+export function setMemoryBudget(
+	bytes: /*u64*/ bigint | undefined,
+): void /*throws*/ {
+	uniffiCaller.rustCallWithError(
+		/*liftError:*/ FfiConverterTypeSshError.lift.bind(FfiConverterTypeSshError),
+		/*caller:*/ (callStatus) => {
+			nativeModule().ubrn_uniffi_uniffi_russh_fn_func_set_memory_budget(
+				FfiConverterOptionalUInt64.lower(bytes),
+				callStatus,
+			);
+		},
+		/*liftString:*/ FfiConverterString.lift,
+	);
+}
 export function validatePrivateKey(
 	privateKeyContent: string,
 ): string /*throws*/ {
@@ -182,6 +285,81 @@ export function validatePrivateKey(
 	);
 }
 
+/**
+ * Ring buffer and delivery settings. Unset fields keep the default (at
+ * start) or the current value (`set_buffer_policy`).
+ */
+export type BufferPolicy = {
+	ringBytes: /*u64*/ bigint | undefined;
+	maxChunkBytes: /*u32*/ number | undefined;
+	broadcastChunks: /*u32*/ number | undefined;
+	coalesceMs: /*u32*/ number | undefined;
+};
+
+/**
+ * Generated factory for {@link BufferPolicy} record objects.
+ */
+export const BufferPolicy = (() => {
+	const defaults = () => ({
+		ringBytes: undefined,
+		maxChunkBytes: undefined,
+		broadcastChunks: undefined,
+		coalesceMs: undefined,
+	});
+	const create = (() => {
+		return uniffiCreateRecord<BufferPolicy, ReturnType<typeof defaults>>(
+			defaults,
+		);
+	})();
+	return Object.freeze({
+		/**
+		 * Create a frozen instance of {@link BufferPolicy}, with defaults specified
+		 * in Rust, in the {@link uniffi_russh} crate.
+		 */
+		create,
+
+		/**
+		 * Create a frozen instance of {@link BufferPolicy}, with defaults specified
+		 * in Rust, in the {@link uniffi_russh} crate.
+		 */
+		new: create,
+
+		/**
+		 * Defaults specified in the {@link uniffi_russh} crate.
+		 */
+		defaults: () => Object.freeze(defaults()) as Partial<BufferPolicy>,
+	});
+})();
+
+const FfiConverterTypeBufferPolicy = (() => {
+	type TypeName = BufferPolicy;
+	class FFIConverter extends AbstractFfiConverterByteArray<TypeName> {
+		read(from: RustBuffer): TypeName {
+			return {
+				ringBytes: FfiConverterOptionalUInt64.read(from),
+				maxChunkBytes: FfiConverterOptionalUInt32.read(from),
+				broadcastChunks: FfiConverterOptionalUInt32.read(from),
+				coalesceMs: FfiConverterOptionalUInt32.read(from),
+			};
+		}
+		write(value: TypeName, into: RustBuffer): void {
+			FfiConverterOptionalUInt64.write(value.ringBytes, into);
+			FfiConverterOptionalUInt32.write(value.maxChunkBytes, into);
+			FfiConverterOptionalUInt32.write(value.broadcastChunks, into);
+			FfiConverterOptionalUInt32.write(value.coalesceMs, into);
+		}
+		allocationSize(value: TypeName): number {
+			return (
+				FfiConverterOptionalUInt64.allocationSize(value.ringBytes) +
+				FfiConverterOptionalUInt32.allocationSize(value.maxChunkBytes) +
+				FfiConverterOptionalUInt32.allocationSize(value.broadcastChunks) +
+				FfiConverterOptionalUInt32.allocationSize(value.coalesceMs)
+			);
+		}
+	}
+	return new FFIConverter();
+})();
+
 export type BufferReadResult = {
 	chunks: Array<TerminalChunk>;
 	nextSeq: /*u64*/ bigint;
@@ -251,6 +429,10 @@ export type BufferStats = {
 	tailSeq: /*u64*/ bigint;
 	droppedBytesTotal: /*u64*/ bigint;
 	chunksCount: /*u64*/ bigint;
+	headLine: /*u64*/ bigint;
+	tailLine: /*u64*/ bigint;
+	channelPaused: boolean;
+	channelPausedMsTotal: /*f64*/ number;
 };
 
 /**
@@ -294,6 +476,10 @@ const FfiConverterTypeBufferStats = (() => {
 				tailSeq: FfiConverterUInt64.read(from),
 				droppedBytesTotal: FfiConverterUInt64.read(from),
 				chunksCount: FfiConverterUInt64.read(from),
+				headLine: FfiConverterUInt64.read(from),
+				tailLine: FfiConverterUInt64.read(from),
+				channelPaused: FfiConverterBool.read(from),
+				channelPausedMsTotal: FfiConverterFloat64.read(from),
 			};
 		}
 		write(value: TypeName, into: RustBuffer): void {
@@ -303,6 +489,10 @@ const FfiConverterTypeBufferStats = (() => {
 			FfiConverterUInt64.write(value.tailSeq, into);
 			FfiConverterUInt64.write(value.droppedBytesTotal, into);
 			FfiConverterUInt64.write(value.chunksCount, into);
+			FfiConverterUInt64.write(value.headLine, into);
+			FfiConverterUInt64.write(value.tailLine, into);
+			FfiConverterBool.write(value.channelPaused, into);
+			FfiConverterFloat64.write(value.channelPausedMsTotal, into);
 		}
 		allocationSize(value: TypeName): number {
 			return (
@@ -311,39 +501,45 @@ const FfiConverterTypeBufferStats = (() => {
 				FfiConverterUInt64.allocationSize(value.headSeq) +
 				FfiConverterUInt64.allocationSize(value.tailSeq) +
 				FfiConverterUInt64.allocationSize(value.droppedBytesTotal) +
-				FfiConverterUInt64.allocationSize(value.chunksCount)
+				FfiConverterUInt64.allocationSize(value.chunksCount) +
+				FfiConverterUInt64.allocationSize(value.headLine) +
+				FfiConverterUInt64.allocationSize(value.tailLine) +
+				FfiConverterBool.allocationSize(value.channelPaused) +
+				FfiConverterFloat64.allocationSize(value.channelPausedMsTotal)
 			);
 		}
 	}
 	return new FFIConverter();
 })();
 
-export type ConnectOptions = {
-	connectionDetails: ConnectionDetails;
-	onConnectionProgressCallback: ConnectProgressCallback | undefined;
-	onDisconnectedCallback: ConnectionDisconnectedCallback | undefined;
-	onServerKeyCallback: ServerKeyCallback;
+export type CastInfo = {
+	version: /*u32*/ number;
+	cols: /*u32*/ number;
+	rows: /*u32*/ number;
+	title: string | undefined;
+	term: string | undefined;
+	startedAtMs: /*f64*/ number | undefined;
+	durationMs: /*f64*/ number;
+	idleTimeLimitMs: /*f64*/ number | undefined;
 };
 
 /**
- * Generated factory for {@link ConnectOptions} record objects.
+ * Generated factory for {@link CastInfo} record objects.
  */
-export const ConnectOptions = (() => {
+export const CastInfo = (() => {
 	const defaults = () => ({});
 	const create = (() => {
-		return uniffiCreateRecord<ConnectOptions, ReturnType<typeof defaults>>(
-			defaults,
-		);
+		return uniffiCreateRecord<CastInfo, ReturnType<typeof defaults>>(defaults);
 	})();
 	return Object.freeze({
 		/**
-		 * Create a frozen instance of {@link ConnectOptions}, with defaults specified
+		 * Create a frozen instance of {@link CastInfo}, with defaults specified
 		 * in Rust, in the {@link uniffi_russh} crate.
 		 */
 		create,
 
 		/**
-		 * Create a frozen instance of {@link ConnectOptions}, with defaults specified
+		 * Create a frozen instance of {@link CastInfo}, with defaults specified
 		 * in Rust, in the {@link uniffi_russh} crate.
 		 */
 		new: create,
@@ -351,81 +547,84 @@ export const ConnectOptions = (() => {
 		/**
 		 * Defaults specified in the {@link uniffi_russh} crate.
 		 */
-		defaults: () => Object.freeze(defaults()) as Partial<ConnectOptions>,
+		defaults: () => Object.freeze(defaults()) as Partial<CastInfo>,
 	});
 })();
 
-const FfiConverterTypeConnectOptions = (() => {
-	type TypeName = ConnectOptions;
+const FfiConverterTypeCastInfo = (() => {
+	type TypeName = CastInfo;
 	class FFIConverter extends AbstractFfiConverterByteArray<TypeName> {
 		read(from: RustBuffer): TypeName {
 			return {
-				connectionDetails: FfiConverterTypeConnectionDetails.read(from),
-				onConnectionProgressCallback:
-					FfiConverterOptionalTypeConnectProgressCallback.read(from),
-				onDisconnectedCallback:
-					FfiConverterOptionalTypeConnectionDisconnectedCallback.read(from),
-				onServerKeyCallback: FfiConverterTypeServerKeyCallback.read(from),
+				version: FfiConverterUInt32.read(from),
+				cols: FfiConverterUInt32.read(from),
+				rows: FfiConverterUInt32.read(from),
+				title: FfiConverterOptionalString.read(from),
+				term: FfiConverterOptionalString.read(from),
+				startedAtMs: FfiConverterOptionalFloat64.read(from),
+				durationMs: FfiConverterFloat64.read(from),
+				idleTimeLimitMs: FfiConverterOptionalFloat64.read(from),
 			};
 		}
 		write(value: TypeName, into: RustBuffer): void {
-			FfiConverterTypeConnectionDetails.write(value.connectionDetails, into);
-			FfiConverterOptionalTypeConnectProgressCallback.write(
-				value.onConnectionProgressCallback,
-				into,
-			);
-			FfiConverterOptionalTypeConnectionDisconnectedCallback.write(
-				value.onDisconnectedCallback,
-				into,
-			);
-			FfiConverterTypeServerKeyCallback.write(value.onServerKeyCallback, into);
+			FfiConverterUInt32.write(value.version, into);
+			FfiConverterUInt32.write(value.cols, into);
+			FfiConverterUInt32.write(value.rows, into);
+			FfiConverterOptionalString.write(value.title, into);
+			FfiConverterOptionalString.write(value.term, into);
+			FfiConverterOptionalFloat64.write(value.startedAtMs, into);
+			FfiConverterFloat64.write(value.durationMs, into);
+			FfiConverterOptionalFloat64.write(value.idleTimeLimitMs, into);
 		}
 		allocationSize(value: TypeName): number {
 			return (
-				FfiConverterTypeConnectionDetails.allocationSize(
-					value.connectionDetails,
-				) +
-				FfiConverterOptionalTypeConnectProgressCallback.allocationSize(
-					value.onConnectionProgressCallback,
-				) +
-				FfiConverterOptionalTypeConnectionDisconnectedCallback.allocationSize(
-					value.onDisconnectedCallback,
-				) +
-				FfiConverterTypeServerKeyCallback.allocationSize(
-					value.onServerKeyCallback,
-				)
+				FfiConverterUInt32.allocationSize(value.version) +
+				FfiConverterUInt32.allocationSize(value.cols) +
+				FfiConverterUInt32.allocationSize(value.rows) +
+				FfiConverterOptionalString.allocationSize(value.title) +
+				FfiConverterOptionalString.allocationSize(value.term) +
+				FfiConverterOptionalFloat64.allocationSize(value.startedAtMs) +
+				FfiConverterFloat64.allocationSize(value.durationMs) +
+				FfiConverterOptionalFloat64.allocationSize(value.idleTimeLimitMs)
 			);
 		}
 	}
 	return new FFIConverter();
 })();
 
-export type ConnectionDetails = {
-	host: string;
-	port: /*u16*/ number;
-	username: string;
-	security: Security;
+export type CastPlayerOptions = {
+	speed: /*f64*/ number | undefined;
+	idleTimeLimitMs: /*f64*/ number | undefined;
+	autoplay: boolean | undefined;
+	onStatusChanged: CastPlayerStatusCallback | undefined;
+	bufferPolicy: BufferPolicy | undefined;
 };
 
 /**
- * Generated factory for {@link ConnectionDetails} record objects.
+ * Generated factory for {@link CastPlayerOptions} record objects.
  */
-export const ConnectionDetails = (() => {
-	const defaults = () => ({});
+export const CastPlayerOptions = (() => {
+	const defaults = () => ({
+		speed: undefined,
+		idleTimeLimitMs: undefined,
+		autoplay: undefined,
+		onStatusChanged: undefined,
+		bufferPolicy: undefined,
+	});
 	const create = (() => {
-		return uniffiCreateRecord<ConnectionDetails, ReturnType<typeof defaults>>(
+		return uniffiCreateRecord<CastPlayerOptions, ReturnType<typeof defaults>>(
 			defaults,
 		);
 	})();
 	return Object.freeze({
 		/**
-		 * Create a frozen instance of {@link ConnectionDetails}, with defaults specified
+		 * Create a frozen instance of {@link CastPlayerOptions}, with defaults specified
 		 * in Rust, in the {@link uniffi_russh} crate.
 		 */
 		create,
 
 		/**
-		 * Create a frozen instance of {@link ConnectionDetails}, with defaults specified
+		 * Create a frozen instance of {@link CastPlayerOptions}, with defaults specified
 		 * in Rust, in the {@link uniffi_russh} crate.
 		 */
 		new: create,
@@ -433,63 +632,78 @@ export const ConnectionDetails = (() => {
 		/**
 		 * Defaults specified in the {@link uniffi_russh} crate.
 		 */
-		defaults: () => Object.freeze(defaults()) as Partial<ConnectionDetails>,
+		defaults: () => Object.freeze(defaults()) as Partial<CastPlayerOptions>,
 	});
 })();
 
-const FfiConverterTypeConnectionDetails = (() => {
-	type TypeName = ConnectionDetails;
+const FfiConverterTypeCastPlayerOptions = (() => {
+	type TypeName = CastPlayerOptions;
 	class FFIConverter extends AbstractFfiConverterByteArray<TypeName> {
 		read(from: RustBuffer): TypeName {
 			return {
-				host: FfiConverterString.read(from),
-				port: FfiConverterUInt16.read(from),
-				username: FfiConverterString.read(from),
-				security: FfiConverterTypeSecurity.read(from),
+				speed: FfiConverterOptionalFloat64.read(from),
+				idleTimeLimitMs: FfiConverterOptionalFloat64.read(from),
+				autoplay: FfiConverterOptionalBool.read(from),
+				onStatusChanged:
+					FfiConverterOptionalTypeCastPlayerStatusCallback.read(from),
+				bufferPolicy: FfiConverterOptionalTypeBufferPolicy.read(from),
 			};
 		}
 		write(value: TypeName, into: RustBuffer): void {
-			FfiConverterString.write(value.host, into);
-			FfiConverterUInt16.write(value.port, into);
-			FfiConverterString.write(value.username, into);
-			FfiConverterTypeSecurity.write(value.security, into);
+			FfiConverterOptionalFloat64.write(value.speed, into);
+			FfiConverterOptionalFloat64.write(value.idleTimeLimitMs, into);
+			FfiConverterOptionalBool.write(value.autoplay, into);
+			FfiConverterOptionalTypeCastPlayerStatusCallback.write(
+				value.onStatusChanged,
+				into,
+			);
+			FfiConverterOptionalTypeBufferPolicy.write(value.bufferPolicy, into);
 		}
 		allocationSize(value: TypeName): number {
 			return (
-				FfiConverterString.allocationSize(value.host) +
-				FfiConverterUInt16.allocationSize(value.port) +
-				FfiConverterString.allocationSize(value.username) +
-				FfiConverterTypeSecurity.allocationSize(value.security)
+				FfiConverterOptionalFloat64.allocationSize(value.speed) +
+				FfiConverterOptionalFloat64.allocationSize(value.idleTimeLimitMs) +
+				FfiConverterOptionalBool.allocationSize(value.autoplay) +
+				FfiConverterOptionalTypeCastPlayerStatusCallback.allocationSize(
+					value.onStatusChanged,
+				) +
+				FfiConverterOptionalTypeBufferPolicy.allocationSize(value.bufferPolicy)
 			);
 		}
 	}
 	return new FFIConverter();
 })();
 
-export type DroppedRange = {
-	fromSeq: /*u64*/ bigint;
-	toSeq: /*u64*/ bigint;
+export type CellStyle = {
+	fg: TerminalColor;
+	bg: TerminalColor;
+	bold: boolean;
+	dim: boolean;
+	italic: boolean;
+	underline: boolean;
+	blink: boolean;
+	inverse: boolean;
+	hidden: boolean;
+	strikethrough: boolean;
 };
 
 /**
- * Generated factory for {@link DroppedRange} record objects.
+ * Generated factory for {@link CellStyle} record objects.
  */
-export const DroppedRange = (() => {
+export const CellStyle = (() => {
 	const defaults = () => ({});
 	const create = (() => {
-		return uniffiCreateRecord<DroppedRange, ReturnType<typeof defaults>>(
-			defaults,
-		);
+		return uniffiCreateRecord<CellStyle, ReturnType<typeof defaults>>(defaults);
 	})();
 	return Object.freeze({
 		/**
-		 * Create a frozen instance of {@link DroppedRange}, with defaults specified
+		 * Create a frozen instance of {@link CellStyle}, with defaults specified
 		 * in Rust, in the {@link uniffi_russh} crate.
 		 */
 		create,
 
 		/**
-		 * Create a frozen instance of {@link DroppedRange}, with defaults specified
+		 * Create a frozen instance of {@link CellStyle}, with defaults specified
 		 * in Rust, in the {@link uniffi_russh} crate.
 		 */
 		new: create,
@@ -497,57 +711,93 @@ export const DroppedRange = (() => {
 		/**
 		 * Defaults specified in the {@link uniffi_russh} crate.
 		 */
-		defaults: () => Object.freeze(defaults()) as Partial<DroppedRange>,
+		defaults: () => Object.freeze(defaults()) as Partial<CellStyle>,
 	});
 })();
 
-const FfiConverterTypeDroppedRange = (() => {
-	type TypeName = DroppedRange;
+const FfiConverterTypeCellStyle = (() => {
+	type TypeName = CellStyle;
 	class FFIConverter extends AbstractFfiConverterByteArray<TypeName> {
 		read(from: RustBuffer): TypeName {
 			return {
-				fromSeq: FfiConverterUInt64.read(from),
-				toSeq: FfiConverterUInt64.read(from),
+				fg: FfiConverterTypeTerminalColor.read(from),
+				bg: FfiConverterTypeTerminalColor.read(from),
+				bold: FfiConverterBool.read(from),
+				dim: FfiConverterBool.read(from),
+				italic: FfiConverterBool.read(from),
+				underline: FfiConverterBool.read(from),
+				blink: FfiConverterBool.read(from),
+				inverse: FfiConverterBool.read(from),
+				hidden: FfiConverterBool.read(from),
+				strikethrough: FfiConverterBool.read(from),
 			};
 		}
 		write(value: TypeName, into: RustBuffer): void {
-			FfiConverterUInt64.write(value.fromSeq, into);
-			FfiConverterUInt64.write(value.toSeq, into);
+			FfiConverterTypeTerminalColor.write(value.fg, into);
+			FfiConverterTypeTerminalColor.write(value.bg, into);
+			FfiConverterBool.write(value.bold, into);
+			FfiConverterBool.write(value.dim, into);
+			FfiConverterBool.write(value.italic, into);
+			FfiConverterBool.write(value.underline, into);
+			FfiConverterBool.write(value.blink, into);
+			FfiConverterBool.write(value.inverse, into);
+			FfiConverterBool.write(value.hidden, into);
+			FfiConverterBool.write(value.strikethrough, into);
 		}
 		allocationSize(value: TypeName): number {
 			return (
-				FfiConverterUInt64.allocationSize(value.fromSeq) +
-				FfiConverterUInt64.allocationSize(value.toSeq)
+				FfiConverterTypeTerminalColor.allocationSize(value.fg) +
+				FfiConverterTypeTerminalColor.allocationSize(value.bg) +
+				FfiConverterBool.allocationSize(value.bold) +
+				FfiConverterBool.allocationSize(value.dim) +
+				FfiConverterBool.allocationSize(value.italic) +
+				FfiConverterBool.allocationSize(value.underline) +
+				FfiConverterBool.allocationSize(value.blink) +
+				FfiConverterBool.allocationSize(value.inverse) +
+				FfiConverterBool.allocationSize(value.hidden) +
+				FfiConverterBool.allocationSize(value.strikethrough)
 			);
 		}
 	}
 	return new FFIConverter();
 })();
 
-export type ListenerOptions = {
-	cursor: Cursor;
-	coalesceMs: /*u32*/ number | undefined;
+/**
+ * One prompt/command/output cycle, from shell integration marks (OSC 133,
+ * or VS Code's OSC 633). Fields fill in as marks arrive.
+ */
+export type CommandRecord = {
+	id: /*u64*/ bigint;
+	promptStart: StreamPosition | undefined;
+	commandStart: StreamPosition | undefined;
+	outputStart: StreamPosition | undefined;
+	outputEnd: StreamPosition | undefined;
+	command: string | undefined;
+	exitCode: /*i32*/ number | undefined;
+	startedAtMs: /*f64*/ number | undefined;
+	finishedAtMs: /*f64*/ number | undefined;
+	durationMs: /*f64*/ number | undefined;
 };
 
 /**
- * Generated factory for {@link ListenerOptions} record objects.
+ * Generated factory for {@link CommandRecord} record objects.
  */
-export const ListenerOptions = (() => {
+export const CommandRecord = (() => {
 	const defaults = () => ({});
 	const create = (() => {
-		return uniffiCreateRecord<ListenerOptions, ReturnType<typeof defaults>>(
+		return uniffiCreateRecord<CommandRecord, ReturnType<typeof defaults>>(
 			defaults,
 		);
 	})();
 	return Object.freeze({
 		/**
-		 * Create a frozen instance of {@link ListenerOptions}, with defaults specified
+		 * Create a frozen instance of {@link CommandRecord}, with defaults specified
 		 * in Rust, in the {@link uniffi_russh} crate.
 		 */
 		create,
 
 		/**
-		 * Create a frozen instance of {@link ListenerOptions}, with defaults specified
+		 * Create a frozen instance of {@link CommandRecord}, with defaults specified
 		 * in Rust, in the {@link uniffi_russh} crate.
 		 */
 		new: create,
@@ -555,61 +805,104 @@ export const ListenerOptions = (() => {
 		/**
 		 * Defaults specified in the {@link uniffi_russh} crate.
 		 */
-		defaults: () => Object.freeze(defaults()) as Partial<ListenerOptions>,
+		defaults: () => Object.freeze(defaults()) as Partial<CommandRecord>,
 	});
 })();
 
-const FfiConverterTypeListenerOptions = (() => {
-	type TypeName = ListenerOptions;
+const FfiConverterTypeCommandRecord = (() => {
+	type TypeName = CommandRecord;
 	class FFIConverter extends AbstractFfiConverterByteArray<TypeName> {
 		read(from: RustBuffer): TypeName {
 			return {
-				cursor: FfiConverterTypeCursor.read(from),
-				coalesceMs: FfiConverterOptionalUInt32.read(from),
+				id: FfiConverterUInt64.read(from),
+				promptStart: FfiConverterOptionalTypeStreamPosition.read(from),
+				commandStart: FfiConverterOptionalTypeStreamPosition.read(from),
+				outputStart: FfiConverterOptionalTypeStreamPosition.read(from),
+				outputEnd: FfiConverterOptionalTypeStreamPosition.read(from),
+				command: FfiConverterOptionalString.read(from),
+				exitCode: FfiConverterOptionalInt32.read(from),
+				startedAtMs: FfiConverterOptionalFloat64.read(from),
+				finishedAtMs: FfiConverterOptionalFloat64.read(from),
+				durationMs: FfiConverterOptionalFloat64.read(from),
 			};
 		}
 		write(value: TypeName, into: RustBuffer): void {
-			FfiConverterTypeCursor.write(value.cursor, into);
-			FfiConverterOptionalUInt32.write(value.coalesceMs, into);
+			FfiConverterUInt64.write(value.id, into);
+			FfiConverterOptionalTypeStreamPosition.write(value.promptStart, into);
+			FfiConverterOptionalTypeStreamPosition.write(value.commandStart, into);
+			FfiConverterOptionalTypeStreamPosition.write(value.outputStart, into);
+			FfiConverterOptionalTypeStreamPosition.write(value.outputEnd, into);
+			FfiConverterOptionalString.write(value.command, into);
+			FfiConverterOptionalInt32.write(value.exitCode, into);
+			FfiConverterOptionalFloat64.write(value.startedAtMs, into);
+			FfiConverterOptionalFloat64.write(value.finishedAtMs, into);
+			FfiConverterOptionalFloat64.write(value.durationMs, into);
 		}
 		allocationSize(value: TypeName): number {
 			return (
-				FfiConverterTypeCursor.allocationSize(value.cursor) +
-				FfiConverterOptionalUInt32.allocationSize(value.coalesceMs)
+				FfiConverterUInt64.allocationSize(value.id) +
+				FfiConverterOptionalTypeStreamPosition.allocationSize(
+					value.promptStart,
+				) +
+				FfiConverterOptionalTypeStreamPosition.allocationSize(
+					value.commandStart,
+				) +
+				FfiConverterOptionalTypeStreamPosition.allocationSize(
+					value.outputStart,
+				) +
+				FfiConverterOptionalTypeStreamPosition.allocationSize(value.outputEnd) +
+				FfiConverterOptionalString.allocationSize(value.command) +
+				FfiConverterOptionalInt32.allocationSize(value.exitCode) +
+				FfiConverterOptionalFloat64.allocationSize(value.startedAtMs) +
+				FfiConverterOptionalFloat64.allocationSize(value.finishedAtMs) +
+				FfiConverterOptionalFloat64.allocationSize(value.durationMs)
 			);
 		}
 	}
 	return new FFIConverter();
 })();
 
-export type ServerPublicKeyInfo = {
-	host: string;
-	port: /*u16*/ number;
-	remoteIp: string | undefined;
-	algorithm: string;
-	fingerprintSha256: string;
-	keyBase64: string;
+export type ConnectOptions = {
+	connectionDetails: ConnectionDetails;
+	onConnectionProgressCallback: ConnectProgressCallback | undefined;
+	onDisconnectedCallback: ConnectionDisconnectedCallback | undefined;
+	onServerKeyCallback: ServerKeyCallback;
+	onMetricsCallback: ConnectionMetricsCallback | undefined;
+	metricsIntervalMs: /*u32*/ number | undefined;
+	rekeyLimits: RekeyLimits | undefined;
+	onConnectionEventCallback: ConnectionEventCallback | undefined;
+	onAuthBannerCallback: AuthBannerCallback | undefined;
+	abortToken: ConnectAbortTokenInterface | undefined;
+	compression: CompressionPreference | undefined;
 };
 
 /**
- * Generated factory for {@link ServerPublicKeyInfo} record objects.
+ * Generated factory for {@link ConnectOptions} record objects.
  */
-export const ServerPublicKeyInfo = (() => {
-	const defaults = () => ({});
+export const ConnectOptions = (() => {
+	const defaults = () => ({
+		onMetricsCallback: undefined,
+		metricsIntervalMs: undefined,
+		rekeyLimits: undefined,
+		onConnectionEventCallback: undefined,
+		onAuthBannerCallback: undefined,
+		abortToken: undefined,
+		compression: undefined,
+	});
 	const create = (() => {
-		return uniffiCreateRecord<ServerPublicKeyInfo, ReturnType<typeof defaults>>(
+		return uniffiCreateRecord<ConnectOptions, ReturnType<typeof defaults>>(
 			defaults,
 		);
 	})();
 	return Object.freeze({
 		/**
-		 * Create a frozen instance of {@link ServerPublicKeyInfo}, with defaults specified
+		 * Create a frozen instance of {@link ConnectOptions}, with defaults specified
 		 * in Rust, in the {@link uniffi_russh} crate.
 		 */
 		create,
 
 		/**
-		 * Create a frozen instance of {@link ServerPublicKeyInfo}, with defaults specified
+		 * Create a frozen instance of {@link ConnectOptions}, with defaults specified
 		 * in Rust, in the {@link uniffi_russh} crate.
 		 */
 		new: create,
@@ -617,75 +910,127 @@ export const ServerPublicKeyInfo = (() => {
 		/**
 		 * Defaults specified in the {@link uniffi_russh} crate.
 		 */
-		defaults: () => Object.freeze(defaults()) as Partial<ServerPublicKeyInfo>,
+		defaults: () => Object.freeze(defaults()) as Partial<ConnectOptions>,
 	});
 })();
 
-const FfiConverterTypeServerPublicKeyInfo = (() => {
-	type TypeName = ServerPublicKeyInfo;
+const FfiConverterTypeConnectOptions = (() => {
+	type TypeName = ConnectOptions;
 	class FFIConverter extends AbstractFfiConverterByteArray<TypeName> {
 		read(from: RustBuffer): TypeName {
 			return {
-				host: FfiConverterString.read(from),
-				port: FfiConverterUInt16.read(from),
-				remoteIp: FfiConverterOptionalString.read(from),
-				algorithm: FfiConverterString.read(from),
-				fingerprintSha256: FfiConverterString.read(from),
-				keyBase64: FfiConverterString.read(from),
+				connectionDetails: FfiConverterTypeConnectionDetails.read(from),
+				onConnectionProgressCallback:
+					FfiConverterOptionalTypeConnectProgressCallback.read(from),
+				onDisconnectedCallback:
+					FfiConverterOptionalTypeConnectionDisconnectedCallback.read(from),
+				onServerKeyCallback: FfiConverterTypeServerKeyCallback.read(from),
+				onMetricsCallback:
+					FfiConverterOptionalTypeConnectionMetricsCallback.read(from),
+				metricsIntervalMs: FfiConverterOptionalUInt32.read(from),
+				rekeyLimits: FfiConverterOptionalTypeRekeyLimits.read(from),
+				onConnectionEventCallback:
+					FfiConverterOptionalTypeConnectionEventCallback.read(from),
+				onAuthBannerCallback:
+					FfiConverterOptionalTypeAuthBannerCallback.read(from),
+				abortToken: FfiConverterOptionalTypeConnectAbortToken.read(from),
+				compression: FfiConverterOptionalTypeCompressionPreference.read(from),
 			};
 		}
 		write(value: TypeName, into: RustBuffer): void {
-			FfiConverterString.write(value.host, into);
-			FfiConverterUInt16.write(value.port, into);
-			FfiConverterOptionalString.write(value.remoteIp, into);
-			FfiConverterString.write(value.algorithm, into);
-			FfiConverterString.write(value.fingerprintSha256, into);
-			FfiConverterString.write(value.keyBase64, into);
+			FfiConverterTypeConnectionDetails.write(value.connectionDetails, into);
+			FfiConverterOptionalTypeConnectProgressCallback.write(
+				value.onConnectionProgressCallback,
+				into,
+			);
+			FfiConverterOptionalTypeConnectionDisconnectedCallback.write(
+				value.onDisconnectedCallback,
+				into,
+			);
+			FfiConverterTypeServerKeyCallback.write(value.onServerKeyCallback, into);
+			FfiConverterOptionalTypeConnectionMetricsCallback.write(
+				value.onMetricsCallback,
+				into,
+			);
+			FfiConverterOptionalUInt32.write(value.metricsIntervalMs, into);
+			FfiConverterOptionalTypeRekeyLimits.write(value.rekeyLimits, into);
+			FfiConverterOptionalTypeConnectionEventCallback.write(
+				value.onConnectionEventCallback,
+				into,
+			);
+			FfiConverterOptionalTypeAuthBannerCallback.write(
+				value.onAuthBannerCallback,
+				into,
+			);
+			FfiConverterOptionalTypeConnectAbortToken.write(value.abortToken, into);
+			FfiConverterOptionalTypeCompressionPreference.write(
+				value.compression,
+				into,
+			);
 		}
 		allocationSize(value: TypeName): number {
 			return (
-				FfiConverterString.allocationSize(value.host) +
-				FfiConverterUInt16.allocationSize(value.port) +
-				FfiConverterOptionalString.allocationSize(value.remoteIp) +
-				FfiConverterString.allocationSize(value.algorithm) +
-				FfiConverterString.allocationSize(value.fingerprintSha256) +
-				FfiConverterString.allocationSize(value.keyBase64)
+				FfiConverterTypeConnectionDetails.allocationSize(
+					value.connectionDetails,
+				) +
+				FfiConverterOptionalTypeConnectProgressCallback.allocationSize(
+					value.onConnectionProgressCallback,
+				) +
+				FfiConverterOptionalTypeConnectionDisconnectedCallback.allocationSize(
+					value.onDisconnectedCallback,
+				) +
+				FfiConverterTypeServerKeyCallback.allocationSize(
+					value.onServerKeyCallback,
+				) +
+				FfiConverterOptionalTypeConnectionMetricsCallback.allocationSize(
+					value.onMetricsCallback,
+				) +
+				FfiConverterOptionalUInt32.allocationSize(value.metricsIntervalMs) +
+				FfiConverterOptionalTypeRekeyLimits.allocationSize(value.rekeyLimits) +
+				FfiConverterOptionalTypeConnectionEventCallback.allocationSize(
+					value.onConnectionEventCallback,
+				) +
+				FfiConverterOptionalTypeAuthBannerCallback.allocationSize(
+					value.onAuthBannerCallback,
+				) +
+				FfiConverterOptionalTypeConnectAbortToken.allocationSize(
+					value.abortToken,
+				) +
+				FfiConverterOptionalTypeCompressionPreference.allocationSize(
+					value.compression,
+				)
 			);
 		}
 	}
 	return new FFIConverter();
 })();
 
-/**
- * Snapshot of shell session info for property-like access in TS.
- */
-export type ShellSessionInfo = {
-	channelId: /*u32*/ number;
-	createdAtMs: /*f64*/ number;
-	connectedAtMs: /*f64*/ number;
-	term: TerminalType;
-	connectionId: string;
+export type ConnectionDetails = {
+	host: string;
+	port: /*u16*/ number;
+	username: string;
+	security: Security;
 };
 
 /**
- * Generated factory for {@link ShellSessionInfo} record objects.
+ * Generated factory for {@link ConnectionDetails} record objects.
  */
-export const ShellSessionInfo = (() => {
+export const ConnectionDetails = (() => {
 	const defaults = () => ({});
 	const create = (() => {
-		return uniffiCreateRecord<ShellSessionInfo, ReturnType<typeof defaults>>(
+		return uniffiCreateRecord<ConnectionDetails, ReturnType<typeof defaults>>(
 			defaults,
 		);
 	})();
 	return Object.freeze({
 		/**
-		 * Create a frozen instance of {@link ShellSessionInfo}, with defaults specified
+		 * Create a frozen instance of {@link ConnectionDetails}, with defaults specified
 		 * in Rust, in the {@link uniffi_russh} crate.
 		 */
 		create,
 
 		/**
-		 * Create a frozen instance of {@link ShellSessionInfo}, with defaults specified
+		 * Create a frozen instance of {@link ConnectionDetails}, with defaults specified
 		 * in Rust, in the {@link uniffi_russh} crate.
 		 */
 		new: create,
@@ -693,69 +1038,66 @@ export const ShellSessionInfo = (() => {
 		/**
 		 * Defaults specified in the {@link uniffi_russh} crate.
 		 */
-		defaults: () => Object.freeze(defaults()) as Partial<ShellSessionInfo>,
+		defaults: () => Object.freeze(defaults()) as Partial<ConnectionDetails>,
 	});
 })();
 
-const FfiConverterTypeShellSessionInfo = (() => {
-	type TypeName = ShellSessionInfo;
+const FfiConverterTypeConnectionDetails = (() => {
+	type TypeName = ConnectionDetails;
 	class FFIConverter extends AbstractFfiConverterByteArray<TypeName> {
 		read(from: RustBuffer): TypeName {
 			return {
-				channelId: FfiConverterUInt32.read(from),
-				createdAtMs: FfiConverterFloat64.read(from),
-				connectedAtMs: FfiConverterFloat64.read(from),
-				term: FfiConverterTypeTerminalType.read(from),
-				connectionId: FfiConverterString.read(from),
+				host: FfiConverterString.read(from),
+				port: FfiConverterUInt16.read(from),
+				username: FfiConverterString.read(from),
+				security: FfiConverterTypeSecurity.read(from),
 			};
 		}
 		write(value: TypeName, into: RustBuffer): void {
-			FfiConverterUInt32.write(value.channelId, into);
-			FfiConverterFloat64.write(value.createdAtMs, into);
-			FfiConverterFloat64.write(value.connectedAtMs, into);
-			FfiConverterTypeTerminalType.write(value.term, into);
-			FfiConverterString.write(value.connectionId, into);
+			FfiConverterString.write(value.host, into);
+			FfiConverterUInt16.write(value.port, into);
+			FfiConverterString.write(value.username, into);
+			FfiConverterTypeSecurity.write(value.security, into);
 		}
 		allocationSize(value: TypeName): number {
 			return (
-				FfiConverterUInt32.allocationSize(value.channelId) +
-				FfiConverterFloat64.allocationSize(value.createdAtMs) +
-				FfiConverterFloat64.allocationSize(value.connectedAtMs) +
-				FfiConverterTypeTerminalType.allocationSize(value.term) +
-				FfiConverterString.allocationSize(value.connectionId)
+				FfiConverterString.allocationSize(value.host) +
+				FfiConverterUInt16.allocationSize(value.port) +
+				FfiConverterString.allocationSize(value.username) +
+				FfiConverterTypeSecurity.allocationSize(value.security)
 			);
 		}
 	}
 	return new FFIConverter();
 })();
 
-export type SshConnectionInfo = {
-	connectionId: string;
-	connectionDetails: ConnectionDetails;
-	createdAtMs: /*f64*/ number;
-	connectedAtMs: /*f64*/ number;
-	progressTimings: SshConnectionInfoProgressTimings;
+export type CursorState = {
+	row: /*u32*/ number;
+	col: /*u32*/ number;
+	visible: boolean;
+	shape: CursorShape;
+	blinking: boolean;
 };
 
 /**
- * Generated factory for {@link SshConnectionInfo} record objects.
+ * Generated factory for {@link CursorState} record objects.
  */
-export const SshConnectionInfo = (() => {
+export const CursorState = (() => {
 	const defaults = () => ({});
 	const create = (() => {
-		return uniffiCreateRecord<SshConnectionInfo, ReturnType<typeof defaults>>(
+		return uniffiCreateRecord<CursorState, ReturnType<typeof defaults>>(
 			defaults,
 		);
 	})();
 	return Object.freeze({
 		/**
-		 * Create a frozen instance of {@link SshConnectionInfo}, with defaults specified
+		 * Create a frozen instance of {@link CursorState}, with defaults specified
 		 * in Rust, in the {@link uniffi_russh} crate.
 		 */
 		create,
 
 		/**
-		 * Create a frozen instance of {@link SshConnectionInfo}, with defaults specified
+		 * Create a frozen instance of {@link CursorState}, with defaults specified
 		 * in Rust, in the {@link uniffi_russh} crate.
 		 */
 		new: create,
@@ -763,75 +1105,66 @@ export const SshConnectionInfo = (() => {
 		/**
 		 * Defaults specified in the {@link uniffi_russh} crate.
 		 */
-		defaults: () => Object.freeze(defaults()) as Partial<SshConnectionInfo>,
+		defaults: () => Object.freeze(defaults()) as Partial<CursorState>,
 	});
 })();
 
-const FfiConverterTypeSshConnectionInfo = (() => {
-	type TypeName = SshConnectionInfo;
+const FfiConverterTypeCursorState = (() => {
+	type TypeName = CursorState;
 	class FFIConverter extends AbstractFfiConverterByteArray<TypeName> {
 		read(from: RustBuffer): TypeName {
 			return {
-				connectionId: FfiConverterString.read(from),
-				connectionDetails: FfiConverterTypeConnectionDetails.read(from),
-				createdAtMs: FfiConverterFloat64.read(from),
-				connectedAtMs: FfiConverterFloat64.read(from),
-				progressTimings:
-					FfiConverterTypeSshConnectionInfoProgressTimings.read(from),
+				row: FfiConverterUInt32.read(from),
+				col: FfiConverterUInt32.read(from),
+				visible: FfiConverterBool.read(from),
+				shape: FfiConverterTypeCursorShape.read(from),
+				blinking: FfiConverterBool.read(from),
 			};
 		}
 		write(value: TypeName, into: RustBuffer): void {
-			FfiConverterString.write(value.connectionId, into);
-			FfiConverterTypeConnectionDetails.write(value.connectionDetails, into);
-			FfiConverterFloat64.write(value.createdAtMs, into);
-			FfiConverterFloat64.write(value.connectedAtMs, into);
-			FfiConverterTypeSshConnectionInfoProgressTimings.write(
-				value.progressTimings,
-				into,
-			);
+			FfiConverterUInt32.write(value.row, into);
+			FfiConverterUInt32.write(value.col, into);
+			FfiConverterBool.write(value.visible, into);
+			FfiConverterTypeCursorShape.write(value.shape, into);
+			FfiConverterBool.write(value.blinking, into);
 		}
 		allocationSize(value: TypeName): number {
 			return (
-				FfiConverterString.allocationSize(value.connectionId) +
-				FfiConverterTypeConnectionDetails.allocationSize(
-					value.connectionDetails,
-				) +
-				FfiConverterFloat64.allocationSize(value.createdAtMs) +
-				FfiConverterFloat64.allocationSize(value.connectedAtMs) +
-				FfiConverterTypeSshConnectionInfoProgressTimings.allocationSize(
-					value.progressTimings,
-				)
+				FfiConverterUInt32.allocationSize(value.row) +
+				FfiConverterUInt32.allocationSize(value.col) +
+				FfiConverterBool.allocationSize(value.visible) +
+				FfiConverterTypeCursorShape.allocationSize(value.shape) +
+				FfiConverterBool.allocationSize(value.blinking)
 			);
 		}
 	}
 	return new FFIConverter();
 })();
 
-export type SshConnectionInfoProgressTimings = {
-	tcpEstablishedAtMs: /*f64*/ number;
-	sshHandshakeAtMs: /*f64*/ number;
+export type DamagedLine = {
+	row: /*u32*/ number;
+	line: ScreenLine;
 };
 
 /**
- * Generated factory for {@link SshConnectionInfoProgressTimings} record objects.
+ * Generated factory for {@link DamagedLine} record objects.
  */
-export const SshConnectionInfoProgressTimings = (() => {
+export const DamagedLine = (() => {
 	const defaults = () => ({});
 	const create = (() => {
-		return uniffiCreateRecord<
-			SshConnectionInfoProgressTimings,
-			ReturnType<typeof defaults>
-		>(defaults);
+		return uniffiCreateRecord<DamagedLine, ReturnType<typeof defaults>>(
+			defaults,
+		);
 	})();
 	return Object.freeze({
 		/**
-		 * Create a frozen instance of {@link SshConnectionInfoProgressTimings}, with defaults specified
+		 * Create a frozen instance of {@link DamagedLine}, with defaults specified
 		 * in Rust, in the {@link uniffi_russh} crate.
 		 */
 		create,
 
 		/**
-		 * Create a frozen instance of {@link SshConnectionInfoProgressTimings}, with defaults specified
+		 * Create a frozen instance of {@link DamagedLine}, with defaults specified
 		 * in Rust, in the {@link uniffi_russh} crate.
 		 */
 		new: create,
@@ -839,61 +1172,57 @@ export const SshConnectionInfoProgressTimings = (() => {
 		/**
 		 * Defaults specified in the {@link uniffi_russh} crate.
 		 */
-		defaults: () =>
-			Object.freeze(defaults()) as Partial<SshConnectionInfoProgressTimings>,
+		defaults: () => Object.freeze(defaults()) as Partial<DamagedLine>,
 	});
 })();
 
-const FfiConverterTypeSshConnectionInfoProgressTimings = (() => {
-	type TypeName = SshConnectionInfoProgressTimings;
+const FfiConverterTypeDamagedLine = (() => {
+	type TypeName = DamagedLine;
 	class FFIConverter extends AbstractFfiConverterByteArray<TypeName> {
 		read(from: RustBuffer): TypeName {
 			return {
-				tcpEstablishedAtMs: FfiConverterFloat64.read(from),
-				sshHandshakeAtMs: FfiConverterFloat64.read(from),
+				row: FfiConverterUInt32.read(from),
+				line: FfiConverterTypeScreenLine.read(from),
 			};
 		}
 		write(value: TypeName, into: RustBuffer): void {
-			FfiConverterFloat64.write(value.tcpEstablishedAtMs, into);
-			FfiConverterFloat64.write(value.sshHandshakeAtMs, into);
+			FfiConverterUInt32.write(value.row, into);
+			FfiConverterTypeScreenLine.write(value.line, into);
 		}
 		allocationSize(value: TypeName): number {
 			return (
-				FfiConverterFloat64.allocationSize(value.tcpEstablishedAtMs) +
-				FfiConverterFloat64.allocationSize(value.sshHandshakeAtMs)
+				FfiConverterUInt32.allocationSize(value.row) +
+				FfiConverterTypeScreenLine.allocationSize(value.line)
 			);
 		}
 	}
 	return new FFIConverter();
 })();
 
-export type StartShellOptions = {
-	term: TerminalType;
-	terminalMode: Array<TerminalMode> | undefined;
-	terminalSize: TerminalSize | undefined;
-	terminalPixelSize: TerminalPixelSize | undefined;
-	onClosedCallback: ShellClosedCallback | undefined;
+export type DroppedRange = {
+	fromSeq: /*u64*/ bigint;
+	toSeq: /*u64*/ bigint;
 };
 
 /**
- * Generated factory for {@link StartShellOptions} record objects.
+ * Generated factory for {@link DroppedRange} record objects.
  */
-export const StartShellOptions = (() => {
+export const DroppedRange = (() => {
 	const defaults = () => ({});
 	const create = (() => {
-		return uniffiCreateRecord<StartShellOptions, ReturnType<typeof defaults>>(
+		return uniffiCreateRecord<DroppedRange, ReturnType<typeof defaults>>(
 			defaults,
 		);
 	})();
 	return Object.freeze({
 		/**
-		 * Create a frozen instance of {@link StartShellOptions}, with defaults specified
+		 * Create a frozen instance of {@link DroppedRange}, with defaults specified
 		 * in Rust, in the {@link uniffi_russh} crate.
 		 */
 		create,
 
 		/**
-		 * Create a frozen instance of {@link StartShellOptions}, with defaults specified
+		 * Create a frozen instance of {@link DroppedRange}, with defaults specified
 		 * in Rust, in the {@link uniffi_russh} crate.
 		 */
 		new: create,
@@ -901,83 +1230,64 @@ export const StartShellOptions = (() => {
 		/**
 		 * Defaults specified in the {@link uniffi_russh} crate.
 		 */
-		defaults: () => Object.freeze(defaults()) as Partial<StartShellOptions>,
+		defaults: () => Object.freeze(defaults()) as Partial<DroppedRange>,
 	});
 })();
 
-const FfiConverterTypeStartShellOptions = (() => {
-	type TypeName = StartShellOptions;
+const FfiConverterTypeDroppedRange = (() => {
+	type TypeName = DroppedRange;
 	class FFIConverter extends AbstractFfiConverterByteArray<TypeName> {
 		read(from: RustBuffer): TypeName {
 			return {
-				term: FfiConverterTypeTerminalType.read(from),
-				terminalMode: FfiConverterOptionalArrayTypeTerminalMode.read(from),
-				terminalSize: FfiConverterOptionalTypeTerminalSize.read(from),
-				terminalPixelSize: FfiConverterOptionalTypeTerminalPixelSize.read(from),
-				onClosedCallback:
-					FfiConverterOptionalTypeShellClosedCallback.read(from),
+				fromSeq: FfiConverterUInt64.read(from),
+				toSeq: FfiConverterUInt64.read(from),
 			};
 		}
 		write(value: TypeName, into: RustBuffer): void {
-			FfiConverterTypeTerminalType.write(value.term, into);
-			FfiConverterOptionalArrayTypeTerminalMode.write(value.terminalMode, into);
-			FfiConverterOptionalTypeTerminalSize.write(value.terminalSize, into);
-			FfiConverterOptionalTypeTerminalPixelSize.write(
-				value.terminalPixelSize,
-				into,
-			);
-			FfiConverterOptionalTypeShellClosedCallback.write(
-				value.onClosedCallback,
-				into,
-			);
+			FfiConverterUInt64.write(value.fromSeq, into);
+			FfiConverterUInt64.write(value.toSeq, into);
 		}
 		allocationSize(value: TypeName): number {
 			return (
-				FfiConverterTypeTerminalType.allocationSize(value.term) +
-				FfiConverterOptionalArrayTypeTerminalMode.allocationSize(
-					value.terminalMode,
-				) +
-				FfiConverterOptionalTypeTerminalSize.allocationSize(
-					value.terminalSize,
-				) +
-				FfiConverterOptionalTypeTerminalPixelSize.allocationSize(
-					value.terminalPixelSize,
-				) +
-				FfiConverterOptionalTypeShellClosedCallback.allocationSize(
-					value.onClosedCallback,
-				)
+				FfiConverterUInt64.allocationSize(value.fromSeq) +
+				FfiConverterUInt64.allocationSize(value.toSeq)
 			);
 		}
 	}
 	return new FFIConverter();
 })();
 
-export type TerminalChunk = {
-	seq: /*u64*/ bigint;
-	tMs: /*f64*/ number;
-	stream: StreamKind;
-	bytes: ArrayBuffer;
+export type ListenerOptions = {
+	cursor: Cursor;
+	coalesceMs: /*u32*/ number | undefined;
+	lossless: boolean | undefined;
+	maxLagBytes: /*u64*/ bigint | undefined;
+	text: InvalidUtf8 | undefined;
 };
 
 /**
- * Generated factory for {@link TerminalChunk} record objects.
+ * Generated factory for {@link ListenerOptions} record objects.
  */
-export const TerminalChunk = (() => {
-	const defaults = () => ({});
+export const ListenerOptions = (() => {
+	const defaults = () => ({
+		lossless: undefined,
+		maxLagBytes: undefined,
+		text: undefined,
+	});
 	const create = (() => {
-		return uniffiCreateRecord<TerminalChunk, ReturnType<typeof defaults>>(
+		return uniffiCreateRecord<ListenerOptions, ReturnType<typeof defaults>>(
 			defaults,
 		);
 	})();
 	return Object.freeze({
 		/**
-		 * Create a frozen instance of {@link TerminalChunk}, with defaults specified
+		 * Create a frozen instance of {@link ListenerOptions}, with defaults specified
 		 * in Rust, in the {@link uniffi_russh} crate.
 		 */
 		create,
 
 		/**
-		 * Create a frozen instance of {@link TerminalChunk}, with defaults specified
+		 * Create a frozen instance of {@link ListenerOptions}, with defaults specified
 		 * in Rust, in the {@link uniffi_russh} crate.
 		 */
 		new: create,
@@ -985,63 +1295,70 @@ export const TerminalChunk = (() => {
 		/**
 		 * Defaults specified in the {@link uniffi_russh} crate.
 		 */
-		defaults: () => Object.freeze(defaults()) as Partial<TerminalChunk>,
+		defaults: () => Object.freeze(defaults()) as Partial<ListenerOptions>,
 	});
 })();
 
-const FfiConverterTypeTerminalChunk = (() => {
-	type TypeName = TerminalChunk;
-	class FFIConverter extends AbstractFfiConverterByteArray<TypeName> {
+const FfiConverterTypeListenerOptions = (() => {
+	type TypeName = ListenerOptions;
+	class FFIConverter extends AbstractFfiConverterByteArray<TypeName> {
 		read(from: RustBuffer): TypeName {
 			return {
-				seq: FfiConverterUInt64.read(from),
-				tMs: FfiConverterFloat64.read(from),
-				stream: FfiConverterTypeStreamKind.read(from),
-				bytes: FfiConverterArrayBuffer.read(from),
+				cursor: FfiConverterTypeCursor.read(from),
+				coalesceMs: FfiConverterOptionalUInt32.read(from),
+				lossless: FfiConverterOptionalBool.read(from),
+				maxLagBytes: FfiConverterOptionalUInt64.read(from),
+				text: FfiConverterOptionalTypeInvalidUtf8.read(from),
 			};
 		}
 		write(value: TypeName, into: RustBuffer): void {
-			FfiConverterUInt64.write(value.seq, into);
-			FfiConverterFloat64.write(value.tMs, into);
-			FfiConverterTypeStreamKind.write(value.stream, into);
-			FfiConverterArrayBuffer.write(value.bytes, into);
+			FfiConverterTypeCursor.write(value.cursor, into);
+			FfiConverterOptionalUInt32.write(value.coalesceMs, into);
+			FfiConverterOptionalBool.write(value.lossless, into);
+			FfiConverterOptionalUInt64.write(value.maxLagBytes, into);
+			FfiConverterOptionalTypeInvalidUtf8.write(value.text, into);
 		}
 		allocationSize(value: TypeName): number {
 			return (
-				FfiConverterUInt64.allocationSize(value.seq) +
-				FfiConverterFloat64.allocationSize(value.tMs) +
-				FfiConverterTypeStreamKind.allocationSize(value.stream) +
-				FfiConverterArrayBuffer.allocationSize(value.bytes)
+				FfiConverterTypeCursor.allocationSize(value.cursor) +
+				FfiConverterOptionalUInt32.allocationSize(value.coalesceMs) +
+				FfiConverterOptionalBool.allocationSize(value.lossless) +
+				FfiConverterOptionalUInt64.allocationSize(value.maxLagBytes) +
+				FfiConverterOptionalTypeInvalidUtf8.allocationSize(value.text)
 			);
 		}
 	}
 	return new FFIConverter();
 })();
 
-export type TerminalMode = {
-	opcode: /*u8*/ number;
-	value: /*u32*/ number;
+/**
+ * Ring buffer bytes held by all sessions and players, against the budget
+ * set with `set_memory_budget`.
+ */
+export type MemoryUsage = {
+	usedBytes: /*u64*/ bigint;
+	budgetBytes: /*u64*/ bigint | undefined;
 };
 
 /**
- * Generated factory for {@link TerminalMode} record objects.
+ * Generated factory for {@link MemoryUsage} record objects.
  */
-export const TerminalMode = (() => {
+export const MemoryUsage = (() => {
 	const defaults = () => ({});
 	const create = (() => {
-		return uniffiCreateRecord<TerminalMode, ReturnType<typeof defaults>>(
+		return uniffiCreateRecord<MemoryUsage, ReturnType<typeof defaults>>(
 			defaults,
 		);
 	})();
 	return Object.freeze({
 		/**
-		 * Create a frozen instance of {@link TerminalMode}, with defaults specified
+		 * Create a frozen instance of {@link MemoryUsage}, with defaults specified
 		 * in Rust, in the {@link uniffi_russh} crate.
 		 */
 		create,
 
 		/**
-		 * Create a frozen instance of {@link TerminalMode}, with defaults specified
+		 * Create a frozen instance of {@link MemoryUsage}, with defaults specified
 		 * in Rust, in the {@link uniffi_russh} crate.
 		 */
 		new: create,
@@ -1049,57 +1366,67 @@ export const TerminalMode = (() => {
 		/**
 		 * Defaults specified in the {@link uniffi_russh} crate.
 		 */
-		defaults: () => Object.freeze(defaults()) as Partial<TerminalMode>,
+		defaults: () => Object.freeze(defaults()) as Partial<MemoryUsage>,
 	});
 })();
 
-const FfiConverterTypeTerminalMode = (() => {
-	type TypeName = TerminalMode;
+const FfiConverterTypeMemoryUsage = (() => {
+	type TypeName = MemoryUsage;
 	class FFIConverter extends AbstractFfiConverterByteArray<TypeName> {
 		read(from: RustBuffer): TypeName {
 			return {
-				opcode: FfiConverterUInt8.read(from),
-				value: FfiConverterUInt32.read(from),
+				usedBytes: FfiConverterUInt64.read(from),
+				budgetBytes: FfiConverterOptionalUInt64.read(from),
 			};
 		}
 		write(value: TypeName, into: RustBuffer): void {
-			FfiConverterUInt8.write(value.opcode, into);
-			FfiConverterUInt32.write(value.value, into);
+			FfiConverterUInt64.write(value.usedBytes, into);
+			FfiConverterOptionalUInt64.write(value.budgetBytes, into);
 		}
 		allocationSize(value: TypeName): number {
 			return (
-				FfiConverterUInt8.allocationSize(value.opcode) +
-				FfiConverterUInt32.allocationSize(value.value)
+				FfiConverterUInt64.allocationSize(value.usedBytes) +
+				FfiConverterOptionalUInt64.allocationSize(value.budgetBytes)
 			);
 		}
 	}
 	return new FFIConverter();
 })();
 
-export type TerminalPixelSize = {
-	pixelWidth: /*u32*/ number | undefined;
-	pixelHeight: /*u32*/ number | undefined;
+/**
+ * Algorithms chosen by the key exchange, per RFC 4253 §7.1.
+ */
+export type NegotiatedAlgorithms = {
+	kex: string;
+	hostKey: string;
+	cipherClientToServer: string;
+	cipherServerToClient: string;
+	macClientToServer: string;
+	macServerToClient: string;
+	compressionClientToServer: string;
+	compressionServerToClient: string;
 };
 
 /**
- * Generated factory for {@link TerminalPixelSize} record objects.
+ * Generated factory for {@link NegotiatedAlgorithms} record objects.
  */
-export const TerminalPixelSize = (() => {
+export const NegotiatedAlgorithms = (() => {
 	const defaults = () => ({});
 	const create = (() => {
-		return uniffiCreateRecord<TerminalPixelSize, ReturnType<typeof defaults>>(
-			defaults,
-		);
+		return uniffiCreateRecord<
+			NegotiatedAlgorithms,
+			ReturnType<typeof defaults>
+		>(defaults);
 	})();
 	return Object.freeze({
 		/**
-		 * Create a frozen instance of {@link TerminalPixelSize}, with defaults specified
+		 * Create a frozen instance of {@link NegotiatedAlgorithms}, with defaults specified
 		 * in Rust, in the {@link uniffi_russh} crate.
 		 */
 		create,
 
 		/**
-		 * Create a frozen instance of {@link TerminalPixelSize}, with defaults specified
+		 * Create a frozen instance of {@link NegotiatedAlgorithms}, with defaults specified
 		 * in Rust, in the {@link uniffi_russh} crate.
 		 */
 		new: create,
@@ -1107,57 +1434,83 @@ export const TerminalPixelSize = (() => {
 		/**
 		 * Defaults specified in the {@link uniffi_russh} crate.
 		 */
-		defaults: () => Object.freeze(defaults()) as Partial<TerminalPixelSize>,
+		defaults: () => Object.freeze(defaults()) as Partial<NegotiatedAlgorithms>,
 	});
 })();
 
-const FfiConverterTypeTerminalPixelSize = (() => {
-	type TypeName = TerminalPixelSize;
+const FfiConverterTypeNegotiatedAlgorithms = (() => {
+	type TypeName = NegotiatedAlgorithms;
 	class FFIConverter extends AbstractFfiConverterByteArray<TypeName> {
 		read(from: RustBuffer): TypeName {
 			return {
-				pixelWidth: FfiConverterOptionalUInt32.read(from),
-				pixelHeight: FfiConverterOptionalUInt32.read(from),
+				kex: FfiConverterString.read(from),
+				hostKey: FfiConverterString.read(from),
+				cipherClientToServer: FfiConverterString.read(from),
+				cipherServerToClient: FfiConverterString.read(from),
+				macClientToServer: FfiConverterString.read(from),
+				macServerToClient: FfiConverterString.read(from),
+				compressionClientToServer: FfiConverterString.read(from),
+				compressionServerToClient: FfiConverterString.read(from),
 			};
 		}
 		write(value: TypeName, into: RustBuffer): void {
-			FfiConverterOptionalUInt32.write(value.pixelWidth, into);
-			FfiConverterOptionalUInt32.write(value.pixelHeight, into);
+			FfiConverterString.write(value.kex, into);
+			FfiConverterString.write(value.hostKey, into);
+			FfiConverterString.write(value.cipherClientToServer, into);
+			FfiConverterString.write(value.cipherServerToClient, into);
+			FfiConverterString.write(value.macClientToServer, into);
+			FfiConverterString.write(value.macServerToClient, into);
+			FfiConverterString.write(value.compressionClientToServer, into);
+			FfiConverterString.write(value.compressionServerToClient, into);
 		}
 		allocationSize(value: TypeName): number {
 			return (
-				FfiConverterOptionalUInt32.allocationSize(value.pixelWidth) +
-				FfiConverterOptionalUInt32.allocationSize(value.pixelHeight)
+				FfiConverterString.allocationSize(value.kex) +
+				FfiConverterString.allocationSize(value.hostKey) +
+				FfiConverterString.allocationSize(value.cipherClientToServer) +
+				FfiConverterString.allocationSize(value.cipherServerToClient) +
+				FfiConverterString.allocationSize(value.macClientToServer) +
+				FfiConverterString.allocationSize(value.macServerToClient) +
+				FfiConverterString.allocationSize(value.compressionClientToServer) +
+				FfiConverterString.allocationSize(value.compressionServerToClient)
 			);
 		}
 	}
 	return new FFIConverter();
 })();
 
-export type TerminalSize = {
-	rowHeight: /*u32*/ number | undefined;
-	colWidth: /*u32*/ number | undefined;
+/**
+ * Positions are on the compressed timeline, in recording time (not scaled
+ * by speed).
+ */
+export type PlaybackStatus = {
+	playing: boolean;
+	positionMs: /*f64*/ number;
+	durationMs: /*f64*/ number;
+	speed: /*f64*/ number;
+	cols: /*u32*/ number;
+	rows: /*u32*/ number;
 };
 
 /**
- * Generated factory for {@link TerminalSize} record objects.
+ * Generated factory for {@link PlaybackStatus} record objects.
  */
-export const TerminalSize = (() => {
+export const PlaybackStatus = (() => {
 	const defaults = () => ({});
 	const create = (() => {
-		return uniffiCreateRecord<TerminalSize, ReturnType<typeof defaults>>(
+		return uniffiCreateRecord<PlaybackStatus, ReturnType<typeof defaults>>(
 			defaults,
 		);
 	})();
 	return Object.freeze({
 		/**
-		 * Create a frozen instance of {@link TerminalSize}, with defaults specified
+		 * Create a frozen instance of {@link PlaybackStatus}, with defaults specified
 		 * in Rust, in the {@link uniffi_russh} crate.
 		 */
 		create,
 
 		/**
-		 * Create a frozen instance of {@link TerminalSize}, with defaults specified
+		 * Create a frozen instance of {@link PlaybackStatus}, with defaults specified
 		 * in Rust, in the {@link uniffi_russh} crate.
 		 */
 		new: create,
@@ -1165,1098 +1518,6481 @@ export const TerminalSize = (() => {
 		/**
 		 * Defaults specified in the {@link uniffi_russh} crate.
 		 */
-		defaults: () => Object.freeze(defaults()) as Partial<TerminalSize>,
+		defaults: () => Object.freeze(defaults()) as Partial<PlaybackStatus>,
 	});
 })();
 
-const FfiConverterTypeTerminalSize = (() => {
-	type TypeName = TerminalSize;
+const FfiConverterTypePlaybackStatus = (() => {
+	type TypeName = PlaybackStatus;
 	class FFIConverter extends AbstractFfiConverterByteArray<TypeName> {
 		read(from: RustBuffer): TypeName {
 			return {
-				rowHeight: FfiConverterOptionalUInt32.read(from),
-				colWidth: FfiConverterOptionalUInt32.read(from),
+				playing: FfiConverterBool.read(from),
+				positionMs: FfiConverterFloat64.read(from),
+				durationMs: FfiConverterFloat64.read(from),
+				speed: FfiConverterFloat64.read(from),
+				cols: FfiConverterUInt32.read(from),
+				rows: FfiConverterUInt32.read(from),
 			};
 		}
 		write(value: TypeName, into: RustBuffer): void {
-			FfiConverterOptionalUInt32.write(value.rowHeight, into);
-			FfiConverterOptionalUInt32.write(value.colWidth, into);
+			FfiConverterBool.write(value.playing, into);
+			FfiConverterFloat64.write(value.positionMs, into);
+			FfiConverterFloat64.write(value.durationMs, into);
+			FfiConverterFloat64.write(value.speed, into);
+			FfiConverterUInt32.write(value.cols, into);
+			FfiConverterUInt32.write(value.rows, into);
 		}
 		allocationSize(value: TypeName): number {
 			return (
-				FfiConverterOptionalUInt32.allocationSize(value.rowHeight) +
-				FfiConverterOptionalUInt32.allocationSize(value.colWidth)
+				FfiConverterBool.allocationSize(value.playing) +
+				FfiConverterFloat64.allocationSize(value.positionMs) +
+				FfiConverterFloat64.allocationSize(value.durationMs) +
+				FfiConverterFloat64.allocationSize(value.speed) +
+				FfiConverterUInt32.allocationSize(value.cols) +
+				FfiConverterUInt32.allocationSize(value.rows)
 			);
 		}
 	}
 	return new FFIConverter();
 })();
 
-const stringConverter = {
-	stringToBytes: (s: string) =>
-		uniffiCaller.rustCall((status) =>
-			nativeModule().ubrn_uniffi_internal_fn_func_ffi__string_to_arraybuffer(
-				s,
-				status,
-			),
-		),
-	bytesToString: (ab: UniffiByteArray) =>
-		uniffiCaller.rustCall((status) =>
-			nativeModule().ubrn_uniffi_internal_fn_func_ffi__arraybuffer_to_string(
-				ab,
-				status,
-			),
-		),
-	stringByteLength: (s: string) =>
-		uniffiCaller.rustCall((status) =>
-			nativeModule().ubrn_uniffi_internal_fn_func_ffi__string_to_byte_length(
-				s,
-				status,
-			),
-		),
+export type PublicKeyFingerprints = {
+	algorithm: string;
+	fingerprintSha256: string;
+	fingerprintMd5: string;
+	randomart: string;
 };
-const FfiConverterString = uniffiCreateFfiConverterString(stringConverter);
 
-// Enum: Cursor
-export enum Cursor_Tags {
-	Head = 'Head',
-	TailBytes = 'TailBytes',
-	Seq = 'Seq',
-	TimeMs = 'TimeMs',
-	Live = 'Live',
-}
-export const Cursor = (() => {
-	type Head__interface = {
-		tag: Cursor_Tags.Head;
-	};
+/**
+ * Generated factory for {@link PublicKeyFingerprints} record objects.
+ */
+export const PublicKeyFingerprints = (() => {
+	const defaults = () => ({});
+	const create = (() => {
+		return uniffiCreateRecord<
+			PublicKeyFingerprints,
+			ReturnType<typeof defaults>
+		>(defaults);
+	})();
+	return Object.freeze({
+		/**
+		 * Create a frozen instance of {@link PublicKeyFingerprints}, with defaults specified
+		 * in Rust, in the {@link uniffi_russh} crate.
+		 */
+		create,
 
-	class Head_ extends UniffiEnum implements Head__interface {
 		/**
-		 * @private
-		 * This field is private and should not be used, use `tag` instead.
+		 * Create a frozen instance of {@link PublicKeyFingerprints}, with defaults specified
+		 * in Rust, in the {@link uniffi_russh} crate.
 		 */
-		readonly [uniffiTypeNameSymbol] = 'Cursor';
-		readonly tag = Cursor_Tags.Head;
-		constructor() {
-			super('Cursor', 'Head');
-		}
+		new: create,
 
-		static new(): Head_ {
-			return new Head_();
-		}
+		/**
+		 * Defaults specified in the {@link uniffi_russh} crate.
+		 */
+		defaults: () => Object.freeze(defaults()) as Partial<PublicKeyFingerprints>,
+	});
+})();
 
-		static instanceOf(obj: any): obj is Head_ {
-			return obj.tag === Cursor_Tags.Head;
+const FfiConverterTypePublicKeyFingerprints = (() => {
+	type TypeName = PublicKeyFingerprints;
+	class FFIConverter extends AbstractFfiConverterByteArray<TypeName> {
+		read(from: RustBuffer): TypeName {
+			return {
+				algorithm: FfiConverterString.read(from),
+				fingerprintSha256: FfiConverterString.read(from),
+				fingerprintMd5: FfiConverterString.read(from),
+				randomart: FfiConverterString.read(from),
+			};
+		}
+		write(value: TypeName, into: RustBuffer): void {
+			FfiConverterString.write(value.algorithm, into);
+			FfiConverterString.write(value.fingerprintSha256, into);
+			FfiConverterString.write(value.fingerprintMd5, into);
+			FfiConverterString.write(value.randomart, into);
+		}
+		allocationSize(value: TypeName): number {
+			return (
+				FfiConverterString.allocationSize(value.algorithm) +
+				FfiConverterString.allocationSize(value.fingerprintSha256) +
+				FfiConverterString.allocationSize(value.fingerprintMd5) +
+				FfiConverterString.allocationSize(value.randomart)
+			);
 		}
 	}
+	return new FFIConverter();
+})();
 
-	type TailBytes__interface = {
-		tag: Cursor_Tags.TailBytes;
-		inner: Readonly<{ bytes: /*u64*/ bigint }>;
-	};
+export type RecordingOptions = {
+	recordInput: boolean | undefined;
+	title: string | undefined;
+};
 
-	class TailBytes_ extends UniffiEnum implements TailBytes__interface {
+/**
+ * Generated factory for {@link RecordingOptions} record objects.
+ */
+export const RecordingOptions = (() => {
+	const defaults = () => ({
+		recordInput: undefined,
+		title: undefined,
+	});
+	const create = (() => {
+		return uniffiCreateRecord<RecordingOptions, ReturnType<typeof defaults>>(
+			defaults,
+		);
+	})();
+	return Object.freeze({
 		/**
-		 * @private
-		 * This field is private and should not be used, use `tag` instead.
+		 * Create a frozen instance of {@link RecordingOptions}, with defaults specified
+		 * in Rust, in the {@link uniffi_russh} crate.
 		 */
-		readonly [uniffiTypeNameSymbol] = 'Cursor';
-		readonly tag = Cursor_Tags.TailBytes;
-		readonly inner: Readonly<{ bytes: /*u64*/ bigint }>;
-		constructor(inner: { bytes: /*u64*/ bigint }) {
-			super('Cursor', 'TailBytes');
-			this.inner = Object.freeze(inner);
-		}
-
-		static new(inner: { bytes: /*u64*/ bigint }): TailBytes_ {
-			return new TailBytes_(inner);
-		}
-
-		static instanceOf(obj: any): obj is TailBytes_ {
-			return obj.tag === Cursor_Tags.TailBytes;
-		}
-	}
+		create,
 
-	type Seq__interface = {
-		tag: Cursor_Tags.Seq;
-		inner: Readonly<{ seq: /*u64*/ bigint }>;
-	};
+		/**
+		 * Create a frozen instance of {@link RecordingOptions}, with defaults specified
+		 * in Rust, in the {@link uniffi_russh} crate.
+		 */
+		new: create,
 
-	class Seq_ extends UniffiEnum implements Seq__interface {
 		/**
-		 * @private
-		 * This field is private and should not be used, use `tag` instead.
+		 * Defaults specified in the {@link uniffi_russh} crate.
 		 */
-		readonly [uniffiTypeNameSymbol] = 'Cursor';
-		readonly tag = Cursor_Tags.Seq;
-		readonly inner: Readonly<{ seq: /*u64*/ bigint }>;
-		constructor(inner: { seq: /*u64*/ bigint }) {
-			super('Cursor', 'Seq');
-			this.inner = Object.freeze(inner);
-		}
+		defaults: () => Object.freeze(defaults()) as Partial<RecordingOptions>,
+	});
+})();
 
-		static new(inner: { seq: /*u64*/ bigint }): Seq_ {
-			return new Seq_(inner);
+const FfiConverterTypeRecordingOptions = (() => {
+	type TypeName = RecordingOptions;
+	class FFIConverter extends AbstractFfiConverterByteArray<TypeName> {
+		read(from: RustBuffer): TypeName {
+			return {
+				recordInput: FfiConverterOptionalBool.read(from),
+				title: FfiConverterOptionalString.read(from),
+			};
 		}
-
-		static instanceOf(obj: any): obj is Seq_ {
-			return obj.tag === Cursor_Tags.Seq;
+		write(value: TypeName, into: RustBuffer): void {
+			FfiConverterOptionalBool.write(value.recordInput, into);
+			FfiConverterOptionalString.write(value.title, into);
+		}
+		allocationSize(value: TypeName): number {
+			return (
+				FfiConverterOptionalBool.allocationSize(value.recordInput) +
+				FfiConverterOptionalString.allocationSize(value.title)
+			);
 		}
 	}
+	return new FFIConverter();
+})();
 
-	type TimeMs__interface = {
-		tag: Cursor_Tags.TimeMs;
-		inner: Readonly<{ tMs: /*f64*/ number }>;
-	};
+export type RecordingStats = {
+	path: string;
+	events: /*u64*/ bigint;
+	durationMs: /*f64*/ number;
+};
 
-	class TimeMs_ extends UniffiEnum implements TimeMs__interface {
-		/**
-		 * @private
-		 * This field is private and should not be used, use `tag` instead.
-		 */
-		readonly [uniffiTypeNameSymbol] = 'Cursor';
-		readonly tag = Cursor_Tags.TimeMs;
-		readonly inner: Readonly<{ tMs: /*f64*/ number }>;
-		constructor(inner: { tMs: /*f64*/ number }) {
-			super('Cursor', 'TimeMs');
-			this.inner = Object.freeze(inner);
-		}
-
-		static new(inner: { tMs: /*f64*/ number }): TimeMs_ {
-			return new TimeMs_(inner);
-		}
-
-		static instanceOf(obj: any): obj is TimeMs_ {
-			return obj.tag === Cursor_Tags.TimeMs;
-		}
-	}
+/**
+ * Generated factory for {@link RecordingStats} record objects.
+ */
+export const RecordingStats = (() => {
+	const defaults = () => ({});
+	const create = (() => {
+		return uniffiCreateRecord<RecordingStats, ReturnType<typeof defaults>>(
+			defaults,
+		);
+	})();
+	return Object.freeze({
+		/**
+		 * Create a frozen instance of {@link RecordingStats}, with defaults specified
+		 * in Rust, in the {@link uniffi_russh} crate.
+		 */
+		create,
 
-	type Live__interface = {
-		tag: Cursor_Tags.Live;
-	};
+		/**
+		 * Create a frozen instance of {@link RecordingStats}, with defaults specified
+		 * in Rust, in the {@link uniffi_russh} crate.
+		 */
+		new: create,
 
-	class Live_ extends UniffiEnum implements Live__interface {
 		/**
-		 * @private
-		 * This field is private and should not be used, use `tag` instead.
+		 * Defaults specified in the {@link uniffi_russh} crate.
 		 */
-		readonly [uniffiTypeNameSymbol] = 'Cursor';
-		readonly tag = Cursor_Tags.Live;
-		constructor() {
-			super('Cursor', 'Live');
-		}
+		defaults: () => Object.freeze(defaults()) as Partial<RecordingStats>,
+	});
+})();
 
-		static new(): Live_ {
-			return new Live_();
+const FfiConverterTypeRecordingStats = (() => {
+	type TypeName = RecordingStats;
+	class FFIConverter extends AbstractFfiConverterByteArray<TypeName> {
+		read(from: RustBuffer): TypeName {
+			return {
+				path: FfiConverterString.read(from),
+				events: FfiConverterUInt64.read(from),
+				durationMs: FfiConverterFloat64.read(from),
+			};
 		}
-
-		static instanceOf(obj: any): obj is Live_ {
-			return obj.tag === Cursor_Tags.Live;
+		write(value: TypeName, into: RustBuffer): void {
+			FfiConverterString.write(value.path, into);
+			FfiConverterUInt64.write(value.events, into);
+			FfiConverterFloat64.write(value.durationMs, into);
+		}
+		allocationSize(value: TypeName): number {
+			return (
+				FfiConverterString.allocationSize(value.path) +
+				FfiConverterUInt64.allocationSize(value.events) +
+				FfiConverterFloat64.allocationSize(value.durationMs)
+			);
 		}
 	}
+	return new FFIConverter();
+})();
 
-	function instanceOf(obj: any): obj is Cursor {
-		return obj[uniffiTypeNameSymbol] === 'Cursor';
-	}
+/**
+ * Key re-exchange thresholds. Byte limits apply to each direction, like
+ * russh's own limits. Unset fields keep the defaults (512 MiB / 1 h).
+ *
+ * Byte limits must be between 1 byte and 768 MiB, so they stay below russh's
+ * own 1 GiB backstop; time limits must be at least 1 s, the policy's check
+ * period. Anything else fails the connect with `InvalidArgument`.
+ */
+export type RekeyLimits = {
+	writeBytes: /*u64*/ bigint | undefined;
+	readBytes: /*u64*/ bigint | undefined;
+	timeMs: /*u64*/ bigint | undefined;
+};
 
+/**
+ * Generated factory for {@link RekeyLimits} record objects.
+ */
+export const RekeyLimits = (() => {
+	const defaults = () => ({});
+	const create = (() => {
+		return uniffiCreateRecord<RekeyLimits, ReturnType<typeof defaults>>(
+			defaults,
+		);
+	})();
 	return Object.freeze({
-		instanceOf,
-		Head: Head_,
-		TailBytes: TailBytes_,
-		Seq: Seq_,
-		TimeMs: TimeMs_,
-		Live: Live_,
+		/**
+		 * Create a frozen instance of {@link RekeyLimits}, with defaults specified
+		 * in Rust, in the {@link uniffi_russh} crate.
+		 */
+		create,
+
+		/**
+		 * Create a frozen instance of {@link RekeyLimits}, with defaults specified
+		 * in Rust, in the {@link uniffi_russh} crate.
+		 */
+		new: create,
+
+		/**
+		 * Defaults specified in the {@link uniffi_russh} crate.
+		 */
+		defaults: () => Object.freeze(defaults()) as Partial<RekeyLimits>,
 	});
 })();
 
-export type Cursor = InstanceType<
-	(typeof Cursor)[keyof Omit<typeof Cursor, 'instanceOf'>]
->;
-
-// FfiConverter for enum Cursor
-const FfiConverterTypeCursor = (() => {
-	const ordinalConverter = FfiConverterInt32;
-	type TypeName = Cursor;
+const FfiConverterTypeRekeyLimits = (() => {
+	type TypeName = RekeyLimits;
 	class FFIConverter extends AbstractFfiConverterByteArray<TypeName> {
 		read(from: RustBuffer): TypeName {
-			switch (ordinalConverter.read(from)) {
-				case 1:
-					return new Cursor.Head();
-				case 2:
-					return new Cursor.TailBytes({ bytes: FfiConverterUInt64.read(from) });
-				case 3:
-					return new Cursor.Seq({ seq: FfiConverterUInt64.read(from) });
-				case 4:
-					return new Cursor.TimeMs({ tMs: FfiConverterFloat64.read(from) });
-				case 5:
-					return new Cursor.Live();
-				default:
-					throw new UniffiInternalError.UnexpectedEnumCase();
-			}
+			return {
+				writeBytes: FfiConverterOptionalUInt64.read(from),
+				readBytes: FfiConverterOptionalUInt64.read(from),
+				timeMs: FfiConverterOptionalUInt64.read(from),
+			};
 		}
 		write(value: TypeName, into: RustBuffer): void {
-			switch (value.tag) {
-				case Cursor_Tags.Head: {
-					ordinalConverter.write(1, into);
-					return;
-				}
-				case Cursor_Tags.TailBytes: {
-					ordinalConverter.write(2, into);
-					const inner = value.inner;
-					FfiConverterUInt64.write(inner.bytes, into);
-					return;
-				}
-				case Cursor_Tags.Seq: {
-					ordinalConverter.write(3, into);
-					const inner = value.inner;
-					FfiConverterUInt64.write(inner.seq, into);
-					return;
-				}
-				case Cursor_Tags.TimeMs: {
-					ordinalConverter.write(4, into);
-					const inner = value.inner;
-					FfiConverterFloat64.write(inner.tMs, into);
-					return;
-				}
-				case Cursor_Tags.Live: {
-					ordinalConverter.write(5, into);
-					return;
-				}
-				default:
-					// Throwing from here means that Cursor_Tags hasn't matched an ordinal.
-					throw new UniffiInternalError.UnexpectedEnumCase();
-			}
+			FfiConverterOptionalUInt64.write(value.writeBytes, into);
+			FfiConverterOptionalUInt64.write(value.readBytes, into);
+			FfiConverterOptionalUInt64.write(value.timeMs, into);
 		}
 		allocationSize(value: TypeName): number {
-			switch (value.tag) {
-				case Cursor_Tags.Head: {
-					return ordinalConverter.allocationSize(1);
-				}
-				case Cursor_Tags.TailBytes: {
-					const inner = value.inner;
-					let size = ordinalConverter.allocationSize(2);
-					size += FfiConverterUInt64.allocationSize(inner.bytes);
-					return size;
-				}
-				case Cursor_Tags.Seq: {
-					const inner = value.inner;
-					let size = ordinalConverter.allocationSize(3);
-					size += FfiConverterUInt64.allocationSize(inner.seq);
-					return size;
-				}
-				case Cursor_Tags.TimeMs: {
-					const inner = value.inner;
-					let size = ordinalConverter.allocationSize(4);
-					size += FfiConverterFloat64.allocationSize(inner.tMs);
-					return size;
-				}
-				case Cursor_Tags.Live: {
-					return ordinalConverter.allocationSize(5);
-				}
-				default:
-					throw new UniffiInternalError.UnexpectedEnumCase();
-			}
+			return (
+				FfiConverterOptionalUInt64.allocationSize(value.writeBytes) +
+				FfiConverterOptionalUInt64.allocationSize(value.readBytes) +
+				FfiConverterOptionalUInt64.allocationSize(value.timeMs)
+			);
 		}
 	}
 	return new FFIConverter();
 })();
 
-export enum KeyType {
-	Rsa,
-	Ecdsa,
-	Ed25519,
-	Ed448,
-}
+/**
+ * What changed since the previous `take_damage`.
+ */
+export type ScreenDamage = {
+	full: boolean;
+	lines: Array<DamagedLine>;
+	cursor: CursorState;
+	alternateScreen: boolean;
+	title: string;
+	modes: ScreenModes;
+	scrolledIntoScrollback: /*u64*/ bigint;
+};
 
-const FfiConverterTypeKeyType = (() => {
-	const ordinalConverter = FfiConverterInt32;
-	type TypeName = KeyType;
+/**
+ * Generated factory for {@link ScreenDamage} record objects.
+ */
+export const ScreenDamage = (() => {
+	const defaults = () => ({});
+	const create = (() => {
+		return uniffiCreateRecord<ScreenDamage, ReturnType<typeof defaults>>(
+			defaults,
+		);
+	})();
+	return Object.freeze({
+		/**
+		 * Create a frozen instance of {@link ScreenDamage}, with defaults specified
+		 * in Rust, in the {@link uniffi_russh} crate.
+		 */
+		create,
+
+		/**
+		 * Create a frozen instance of {@link ScreenDamage}, with defaults specified
+		 * in Rust, in the {@link uniffi_russh} crate.
+		 */
+		new: create,
+
+		/**
+		 * Defaults specified in the {@link uniffi_russh} crate.
+		 */
+		defaults: () => Object.freeze(defaults()) as Partial<ScreenDamage>,
+	});
+})();
+
+const FfiConverterTypeScreenDamage = (() => {
+	type TypeName = ScreenDamage;
 	class FFIConverter extends AbstractFfiConverterByteArray<TypeName> {
 		read(from: RustBuffer): TypeName {
-			switch (ordinalConverter.read(from)) {
-				case 1:
-					return KeyType.Rsa;
-				case 2:
-					return KeyType.Ecdsa;
-				case 3:
-					return KeyType.Ed25519;
-				case 4:
-					return KeyType.Ed448;
-				default:
-					throw new UniffiInternalError.UnexpectedEnumCase();
-			}
+			return {
+				full: FfiConverterBool.read(from),
+				lines: FfiConverterArrayTypeDamagedLine.read(from),
+				cursor: FfiConverterTypeCursorState.read(from),
+				alternateScreen: FfiConverterBool.read(from),
+				title: FfiConverterString.read(from),
+				modes: FfiConverterTypeScreenModes.read(from),
+				scrolledIntoScrollback: FfiConverterUInt64.read(from),
+			};
 		}
 		write(value: TypeName, into: RustBuffer): void {
-			switch (value) {
-				case KeyType.Rsa:
-					return ordinalConverter.write(1, into);
-				case KeyType.Ecdsa:
-					return ordinalConverter.write(2, into);
-				case KeyType.Ed25519:
-					return ordinalConverter.write(3, into);
-				case KeyType.Ed448:
-					return ordinalConverter.write(4, into);
-			}
+			FfiConverterBool.write(value.full, into);
+			FfiConverterArrayTypeDamagedLine.write(value.lines, into);
+			FfiConverterTypeCursorState.write(value.cursor, into);
+			FfiConverterBool.write(value.alternateScreen, into);
+			FfiConverterString.write(value.title, into);
+			FfiConverterTypeScreenModes.write(value.modes, into);
+			FfiConverterUInt64.write(value.scrolledIntoScrollback, into);
 		}
 		allocationSize(value: TypeName): number {
-			return ordinalConverter.allocationSize(0);
+			return (
+				FfiConverterBool.allocationSize(value.full) +
+				FfiConverterArrayTypeDamagedLine.allocationSize(value.lines) +
+				FfiConverterTypeCursorState.allocationSize(value.cursor) +
+				FfiConverterBool.allocationSize(value.alternateScreen) +
+				FfiConverterString.allocationSize(value.title) +
+				FfiConverterTypeScreenModes.allocationSize(value.modes) +
+				FfiConverterUInt64.allocationSize(value.scrolledIntoScrollback)
+			);
 		}
 	}
 	return new FFIConverter();
 })();
 
-// Enum: Security
-export enum Security_Tags {
-	Password = 'Password',
-	Key = 'Key',
-}
-export const Security = (() => {
-	type Password__interface = {
-		tag: Security_Tags.Password;
-		inner: Readonly<{ password: string }>;
-	};
+export type ScreenLine = {
+	runs: Array<TextRun>;
+	wrapped: boolean;
+};
 
-	class Password_ extends UniffiEnum implements Password__interface {
-		/**
-		 * @private
-		 * This field is private and should not be used, use `tag` instead.
+/**
+ * Generated factory for {@link ScreenLine} record objects.
+ */
+export const ScreenLine = (() => {
+	const defaults = () => ({});
+	const create = (() => {
+		return uniffiCreateRecord<ScreenLine, ReturnType<typeof defaults>>(
+			defaults,
+		);
+	})();
+	return Object.freeze({
+		/**
+		 * Create a frozen instance of {@link ScreenLine}, with defaults specified
+		 * in Rust, in the {@link uniffi_russh} crate.
 		 */
-		readonly [uniffiTypeNameSymbol] = 'Security';
-		readonly tag = Security_Tags.Password;
-		readonly inner: Readonly<{ password: string }>;
-		constructor(inner: { password: string }) {
-			super('Security', 'Password');
-			this.inner = Object.freeze(inner);
-		}
-
-		static new(inner: { password: string }): Password_ {
-			return new Password_(inner);
-		}
-
-		static instanceOf(obj: any): obj is Password_ {
-			return obj.tag === Security_Tags.Password;
-		}
-	}
-
-	type Key__interface = {
-		tag: Security_Tags.Key;
-		inner: Readonly<{ privateKeyContent: string }>;
-	};
+		create,
 
-	class Key_ extends UniffiEnum implements Key__interface {
 		/**
-		 * @private
-		 * This field is private and should not be used, use `tag` instead.
+		 * Create a frozen instance of {@link ScreenLine}, with defaults specified
+		 * in Rust, in the {@link uniffi_russh} crate.
 		 */
-		readonly [uniffiTypeNameSymbol] = 'Security';
-		readonly tag = Security_Tags.Key;
-		readonly inner: Readonly<{ privateKeyContent: string }>;
-		constructor(inner: { privateKeyContent: string }) {
-			super('Security', 'Key');
-			this.inner = Object.freeze(inner);
-		}
-
-		static new(inner: { privateKeyContent: string }): Key_ {
-			return new Key_(inner);
-		}
-
-		static instanceOf(obj: any): obj is Key_ {
-			return obj.tag === Security_Tags.Key;
-		}
-	}
-
-	function instanceOf(obj: any): obj is Security {
-		return obj[uniffiTypeNameSymbol] === 'Security';
-	}
+		new: create,
 
-	return Object.freeze({
-		instanceOf,
-		Password: Password_,
-		Key: Key_,
+		/**
+		 * Defaults specified in the {@link uniffi_russh} crate.
+		 */
+		defaults: () => Object.freeze(defaults()) as Partial<ScreenLine>,
 	});
 })();
 
-export type Security = InstanceType<
-	(typeof Security)[keyof Omit<typeof Security, 'instanceOf'>]
->;
-
-// FfiConverter for enum Security
-const FfiConverterTypeSecurity = (() => {
-	const ordinalConverter = FfiConverterInt32;
-	type TypeName = Security;
+const FfiConverterTypeScreenLine = (() => {
+	type TypeName = ScreenLine;
 	class FFIConverter extends AbstractFfiConverterByteArray<TypeName> {
 		read(from: RustBuffer): TypeName {
-			switch (ordinalConverter.read(from)) {
-				case 1:
-					return new Security.Password({
-						password: FfiConverterString.read(from),
-					});
-				case 2:
-					return new Security.Key({
-						privateKeyContent: FfiConverterString.read(from),
-					});
-				default:
-					throw new UniffiInternalError.UnexpectedEnumCase();
-			}
+			return {
+				runs: FfiConverterArrayTypeTextRun.read(from),
+				wrapped: FfiConverterBool.read(from),
+			};
 		}
 		write(value: TypeName, into: RustBuffer): void {
-			switch (value.tag) {
-				case Security_Tags.Password: {
-					ordinalConverter.write(1, into);
-					const inner = value.inner;
-					FfiConverterString.write(inner.password, into);
-					return;
-				}
-				case Security_Tags.Key: {
-					ordinalConverter.write(2, into);
-					const inner = value.inner;
-					FfiConverterString.write(inner.privateKeyContent, into);
-					return;
-				}
-				default:
-					// Throwing from here means that Security_Tags hasn't matched an ordinal.
-					throw new UniffiInternalError.UnexpectedEnumCase();
-			}
+			FfiConverterArrayTypeTextRun.write(value.runs, into);
+			FfiConverterBool.write(value.wrapped, into);
 		}
 		allocationSize(value: TypeName): number {
-			switch (value.tag) {
-				case Security_Tags.Password: {
-					const inner = value.inner;
-					let size = ordinalConverter.allocationSize(1);
-					size += FfiConverterString.allocationSize(inner.password);
-					return size;
-				}
-				case Security_Tags.Key: {
-					const inner = value.inner;
-					let size = ordinalConverter.allocationSize(2);
-					size += FfiConverterString.allocationSize(inner.privateKeyContent);
-					return size;
-				}
-				default:
-					throw new UniffiInternalError.UnexpectedEnumCase();
-			}
+			return (
+				FfiConverterArrayTypeTextRun.allocationSize(value.runs) +
+				FfiConverterBool.allocationSize(value.wrapped)
+			);
 		}
 	}
 	return new FFIConverter();
 })();
 
-// Enum: ShellEvent
-export enum ShellEvent_Tags {
-	Chunk = 'Chunk',
-	Dropped = 'Dropped',
-}
-export const ShellEvent = (() => {
-	type Chunk__interface = {
-		tag: ShellEvent_Tags.Chunk;
-		inner: Readonly<[TerminalChunk]>;
-	};
+/**
+ * Modes a renderer needs to encode input the way the remote program expects.
+ */
+export type ScreenModes = {
+	applicationCursorKeys: boolean;
+	applicationKeypad: boolean;
+	bracketedPaste: boolean;
+	mouseTracking: MouseTracking;
+	mouseSgr: boolean;
+	focusEvents: boolean;
+};
 
-	class Chunk_ extends UniffiEnum implements Chunk__interface {
+/**
+ * Generated factory for {@link ScreenModes} record objects.
+ */
+export const ScreenModes = (() => {
+	const defaults = () => ({});
+	const create = (() => {
+		return uniffiCreateRecord<ScreenModes, ReturnType<typeof defaults>>(
+			defaults,
+		);
+	})();
+	return Object.freeze({
 		/**
-		 * @private
-		 * This field is private and should not be used, use `tag` instead.
+		 * Create a frozen instance of {@link ScreenModes}, with defaults specified
+		 * in Rust, in the {@link uniffi_russh} crate.
 		 */
-		readonly [uniffiTypeNameSymbol] = 'ShellEvent';
-		readonly tag = ShellEvent_Tags.Chunk;
-		readonly inner: Readonly<[TerminalChunk]>;
-		constructor(v0: TerminalChunk) {
-			super('ShellEvent', 'Chunk');
-			this.inner = Object.freeze([v0]);
-		}
-
-		static new(v0: TerminalChunk): Chunk_ {
-			return new Chunk_(v0);
-		}
-
-		static instanceOf(obj: any): obj is Chunk_ {
-			return obj.tag === ShellEvent_Tags.Chunk;
-		}
-	}
+		create,
 
-	type Dropped__interface = {
-		tag: ShellEvent_Tags.Dropped;
-		inner: Readonly<{ fromSeq: /*u64*/ bigint; toSeq: /*u64*/ bigint }>;
-	};
+		/**
+		 * Create a frozen instance of {@link ScreenModes}, with defaults specified
+		 * in Rust, in the {@link uniffi_russh} crate.
+		 */
+		new: create,
 
-	class Dropped_ extends UniffiEnum implements Dropped__interface {
 		/**
-		 * @private
-		 * This field is private and should not be used, use `tag` instead.
+		 * Defaults specified in the {@link uniffi_russh} crate.
 		 */
-		readonly [uniffiTypeNameSymbol] = 'ShellEvent';
-		readonly tag = ShellEvent_Tags.Dropped;
-		readonly inner: Readonly<{
-			fromSeq: /*u64*/ bigint;
-			toSeq: /*u64*/ bigint;
-		}>;
-		constructor(inner: { fromSeq: /*u64*/ bigint; toSeq: /*u64*/ bigint }) {
-			super('ShellEvent', 'Dropped');
-			this.inner = Object.freeze(inner);
-		}
+		defaults: () => Object.freeze(defaults()) as Partial<ScreenModes>,
+	});
+})();
 
-		static new(inner: {
-			fromSeq: /*u64*/ bigint;
-			toSeq: /*u64*/ bigint;
-		}): Dropped_ {
-			return new Dropped_(inner);
+const FfiConverterTypeScreenModes = (() => {
+	type TypeName = ScreenModes;
+	class FFIConverter extends AbstractFfiConverterByteArray<TypeName> {
+		read(from: RustBuffer): TypeName {
+			return {
+				applicationCursorKeys: FfiConverterBool.read(from),
+				applicationKeypad: FfiConverterBool.read(from),
+				bracketedPaste: FfiConverterBool.read(from),
+				mouseTracking: FfiConverterTypeMouseTracking.read(from),
+				mouseSgr: FfiConverterBool.read(from),
+				focusEvents: FfiConverterBool.read(from),
+			};
 		}
-
-		static instanceOf(obj: any): obj is Dropped_ {
-			return obj.tag === ShellEvent_Tags.Dropped;
+		write(value: TypeName, into: RustBuffer): void {
+			FfiConverterBool.write(value.applicationCursorKeys, into);
+			FfiConverterBool.write(value.applicationKeypad, into);
+			FfiConverterBool.write(value.bracketedPaste, into);
+			FfiConverterTypeMouseTracking.write(value.mouseTracking, into);
+			FfiConverterBool.write(value.mouseSgr, into);
+			FfiConverterBool.write(value.focusEvents, into);
+		}
+		allocationSize(value: TypeName): number {
+			return (
+				FfiConverterBool.allocationSize(value.applicationCursorKeys) +
+				FfiConverterBool.allocationSize(value.applicationKeypad) +
+				FfiConverterBool.allocationSize(value.bracketedPaste) +
+				FfiConverterTypeMouseTracking.allocationSize(value.mouseTracking) +
+				FfiConverterBool.allocationSize(value.mouseSgr) +
+				FfiConverterBool.allocationSize(value.focusEvents)
+			);
 		}
 	}
+	return new FFIConverter();
+})();
 
-	function instanceOf(obj: any): obj is ShellEvent {
-		return obj[uniffiTypeNameSymbol] === 'ShellEvent';
-	}
+export type ScreenSnapshot = {
+	cols: /*u32*/ number;
+	rows: /*u32*/ number;
+	lines: Array<ScreenLine>;
+	cursor: CursorState;
+	alternateScreen: boolean;
+	title: string;
+	modes: ScreenModes;
+	scrollbackLen: /*u64*/ bigint;
+};
 
+/**
+ * Generated factory for {@link ScreenSnapshot} record objects.
+ */
+export const ScreenSnapshot = (() => {
+	const defaults = () => ({});
+	const create = (() => {
+		return uniffiCreateRecord<ScreenSnapshot, ReturnType<typeof defaults>>(
+			defaults,
+		);
+	})();
 	return Object.freeze({
-		instanceOf,
-		Chunk: Chunk_,
-		Dropped: Dropped_,
+		/**
+		 * Create a frozen instance of {@link ScreenSnapshot}, with defaults specified
+		 * in Rust, in the {@link uniffi_russh} crate.
+		 */
+		create,
+
+		/**
+		 * Create a frozen instance of {@link ScreenSnapshot}, with defaults specified
+		 * in Rust, in the {@link uniffi_russh} crate.
+		 */
+		new: create,
+
+		/**
+		 * Defaults specified in the {@link uniffi_russh} crate.
+		 */
+		defaults: () => Object.freeze(defaults()) as Partial<ScreenSnapshot>,
 	});
 })();
 
-export type ShellEvent = InstanceType<
-	(typeof ShellEvent)[keyof Omit<typeof ShellEvent, 'instanceOf'>]
->;
-
-// FfiConverter for enum ShellEvent
-const FfiConverterTypeShellEvent = (() => {
-	const ordinalConverter = FfiConverterInt32;
-	type TypeName = ShellEvent;
+const FfiConverterTypeScreenSnapshot = (() => {
+	type TypeName = ScreenSnapshot;
 	class FFIConverter extends AbstractFfiConverterByteArray<TypeName> {
 		read(from: RustBuffer): TypeName {
-			switch (ordinalConverter.read(from)) {
-				case 1:
-					return new ShellEvent.Chunk(FfiConverterTypeTerminalChunk.read(from));
-				case 2:
-					return new ShellEvent.Dropped({
-						fromSeq: FfiConverterUInt64.read(from),
-						toSeq: FfiConverterUInt64.read(from),
-					});
-				default:
-					throw new UniffiInternalError.UnexpectedEnumCase();
-			}
+			return {
+				cols: FfiConverterUInt32.read(from),
+				rows: FfiConverterUInt32.read(from),
+				lines: FfiConverterArrayTypeScreenLine.read(from),
+				cursor: FfiConverterTypeCursorState.read(from),
+				alternateScreen: FfiConverterBool.read(from),
+				title: FfiConverterString.read(from),
+				modes: FfiConverterTypeScreenModes.read(from),
+				scrollbackLen: FfiConverterUInt64.read(from),
+			};
 		}
 		write(value: TypeName, into: RustBuffer): void {
-			switch (value.tag) {
-				case ShellEvent_Tags.Chunk: {
-					ordinalConverter.write(1, into);
-					const inner = value.inner;
-					FfiConverterTypeTerminalChunk.write(inner[0], into);
-					return;
-				}
-				case ShellEvent_Tags.Dropped: {
-					ordinalConverter.write(2, into);
-					const inner = value.inner;
-					FfiConverterUInt64.write(inner.fromSeq, into);
-					FfiConverterUInt64.write(inner.toSeq, into);
-					return;
-				}
-				default:
-					// Throwing from here means that ShellEvent_Tags hasn't matched an ordinal.
-					throw new UniffiInternalError.UnexpectedEnumCase();
-			}
+			FfiConverterUInt32.write(value.cols, into);
+			FfiConverterUInt32.write(value.rows, into);
+			FfiConverterArrayTypeScreenLine.write(value.lines, into);
+			FfiConverterTypeCursorState.write(value.cursor, into);
+			FfiConverterBool.write(value.alternateScreen, into);
+			FfiConverterString.write(value.title, into);
+			FfiConverterTypeScreenModes.write(value.modes, into);
+			FfiConverterUInt64.write(value.scrollbackLen, into);
 		}
 		allocationSize(value: TypeName): number {
-			switch (value.tag) {
-				case ShellEvent_Tags.Chunk: {
-					const inner = value.inner;
-					let size = ordinalConverter.allocationSize(1);
-					size += FfiConverterTypeTerminalChunk.allocationSize(inner[0]);
-					return size;
-				}
-				case ShellEvent_Tags.Dropped: {
-					const inner = value.inner;
-					let size = ordinalConverter.allocationSize(2);
-					size += FfiConverterUInt64.allocationSize(inner.fromSeq);
-					size += FfiConverterUInt64.allocationSize(inner.toSeq);
-					return size;
-				}
-				default:
-					throw new UniffiInternalError.UnexpectedEnumCase();
-			}
+			return (
+				FfiConverterUInt32.allocationSize(value.cols) +
+				FfiConverterUInt32.allocationSize(value.rows) +
+				FfiConverterArrayTypeScreenLine.allocationSize(value.lines) +
+				FfiConverterTypeCursorState.allocationSize(value.cursor) +
+				FfiConverterBool.allocationSize(value.alternateScreen) +
+				FfiConverterString.allocationSize(value.title) +
+				FfiConverterTypeScreenModes.allocationSize(value.modes) +
+				FfiConverterUInt64.allocationSize(value.scrollbackLen)
+			);
 		}
 	}
 	return new FFIConverter();
 })();
 
-export enum SshConnectionProgressEvent {
-	TcpConnected,
-	SshHandshake,
-}
+/**
+ * A match in the ring buffer. Offsets are into the chunks' raw bytes, so
+ * `Cursor::Seq { seq }` replays from the chunk where it starts.
+ */
+export type SearchMatch = {
+	seq: /*u64*/ bigint;
+	offset: /*u64*/ bigint;
+	endSeq: /*u64*/ bigint;
+	endOffset: /*u64*/ bigint;
+	text: string;
+};
 
-const FfiConverterTypeSshConnectionProgressEvent = (() => {
-	const ordinalConverter = FfiConverterInt32;
-	type TypeName = SshConnectionProgressEvent;
+/**
+ * Generated factory for {@link SearchMatch} record objects.
+ */
+export const SearchMatch = (() => {
+	const defaults = () => ({});
+	const create = (() => {
+		return uniffiCreateRecord<SearchMatch, ReturnType<typeof defaults>>(
+			defaults,
+		);
+	})();
+	return Object.freeze({
+		/**
+		 * Create a frozen instance of {@link SearchMatch}, with defaults specified
+		 * in Rust, in the {@link uniffi_russh} crate.
+		 */
+		create,
+
+		/**
+		 * Create a frozen instance of {@link SearchMatch}, with defaults specified
+		 * in Rust, in the {@link uniffi_russh} crate.
+		 */
+		new: create,
+
+		/**
+		 * Defaults specified in the {@link uniffi_russh} crate.
+		 */
+		defaults: () => Object.freeze(defaults()) as Partial<SearchMatch>,
+	});
+})();
+
+const FfiConverterTypeSearchMatch = (() => {
+	type TypeName = SearchMatch;
 	class FFIConverter extends AbstractFfiConverterByteArray<TypeName> {
 		read(from: RustBuffer): TypeName {
-			switch (ordinalConverter.read(from)) {
-				case 1:
-					return SshConnectionProgressEvent.TcpConnected;
-				case 2:
-					return SshConnectionProgressEvent.SshHandshake;
-				default:
-					throw new UniffiInternalError.UnexpectedEnumCase();
-			}
+			return {
+				seq: FfiConverterUInt64.read(from),
+				offset: FfiConverterUInt64.read(from),
+				endSeq: FfiConverterUInt64.read(from),
+				endOffset: FfiConverterUInt64.read(from),
+				text: FfiConverterString.read(from),
+			};
 		}
 		write(value: TypeName, into: RustBuffer): void {
-			switch (value) {
-				case SshConnectionProgressEvent.TcpConnected:
-					return ordinalConverter.write(1, into);
-				case SshConnectionProgressEvent.SshHandshake:
-					return ordinalConverter.write(2, into);
-			}
+			FfiConverterUInt64.write(value.seq, into);
+			FfiConverterUInt64.write(value.offset, into);
+			FfiConverterUInt64.write(value.endSeq, into);
+			FfiConverterUInt64.write(value.endOffset, into);
+			FfiConverterString.write(value.text, into);
 		}
 		allocationSize(value: TypeName): number {
-			return ordinalConverter.allocationSize(0);
+			return (
+				FfiConverterUInt64.allocationSize(value.seq) +
+				FfiConverterUInt64.allocationSize(value.offset) +
+				FfiConverterUInt64.allocationSize(value.endSeq) +
+				FfiConverterUInt64.allocationSize(value.endOffset) +
+				FfiConverterString.allocationSize(value.text)
+			);
 		}
 	}
 	return new FFIConverter();
 })();
 
-// Error type: SshError
-
-// Enum: SshError
-export enum SshError_Tags {
-	Disconnected = 'Disconnected',
-	UnsupportedKeyType = 'UnsupportedKeyType',
-	Auth = 'Auth',
-	ShellAlreadyRunning = 'ShellAlreadyRunning',
-	Russh = 'Russh',
-	RusshKeys = 'RusshKeys',
-}
-export const SshError = (() => {
-	type Disconnected__interface = {
-		tag: SshError_Tags.Disconnected;
-	};
+export type SearchOptions = {
+	caseInsensitive: boolean | undefined;
+	literal: boolean | undefined;
+	maxMatches: /*u32*/ number | undefined;
+	newestFirst: boolean | undefined;
+};
 
-	class Disconnected_ extends UniffiError implements Disconnected__interface {
+/**
+ * Generated factory for {@link SearchOptions} record objects.
+ */
+export const SearchOptions = (() => {
+	const defaults = () => ({
+		caseInsensitive: undefined,
+		literal: undefined,
+		maxMatches: undefined,
+		newestFirst: undefined,
+	});
+	const create = (() => {
+		return uniffiCreateRecord<SearchOptions, ReturnType<typeof defaults>>(
+			defaults,
+		);
+	})();
+	return Object.freeze({
 		/**
-		 * @private
-		 * This field is private and should not be used, use `tag` instead.
+		 * Create a frozen instance of {@link SearchOptions}, with defaults specified
+		 * in Rust, in the {@link uniffi_russh} crate.
 		 */
-		readonly [uniffiTypeNameSymbol] = 'SshError';
-		readonly tag = SshError_Tags.Disconnected;
-		constructor() {
-			super('SshError', 'Disconnected');
-		}
-
-		static new(): Disconnected_ {
-			return new Disconnected_();
-		}
-
-		static instanceOf(obj: any): obj is Disconnected_ {
-			return obj.tag === SshError_Tags.Disconnected;
-		}
-
-		static hasInner(obj: any): obj is Disconnected_ {
-			return false;
-		}
-	}
+		create,
 
-	type UnsupportedKeyType__interface = {
-		tag: SshError_Tags.UnsupportedKeyType;
-	};
+		/**
+		 * Create a frozen instance of {@link SearchOptions}, with defaults specified
+		 * in Rust, in the {@link uniffi_russh} crate.
+		 */
+		new: create,
 
-	class UnsupportedKeyType_
-		extends UniffiError
-		implements UnsupportedKeyType__interface
-	{
 		/**
-		 * @private
-		 * This field is private and should not be used, use `tag` instead.
+		 * Defaults specified in the {@link uniffi_russh} crate.
 		 */
-		readonly [uniffiTypeNameSymbol] = 'SshError';
-		readonly tag = SshError_Tags.UnsupportedKeyType;
-		constructor() {
-			super('SshError', 'UnsupportedKeyType');
-		}
+		defaults: () => Object.freeze(defaults()) as Partial<SearchOptions>,
+	});
+})();
 
-		static new(): UnsupportedKeyType_ {
-			return new UnsupportedKeyType_();
+const FfiConverterTypeSearchOptions = (() => {
+	type TypeName = SearchOptions;
+	class FFIConverter extends AbstractFfiConverterByteArray<TypeName> {
+		read(from: RustBuffer): TypeName {
+			return {
+				caseInsensitive: FfiConverterOptionalBool.read(from),
+				literal: FfiConverterOptionalBool.read(from),
+				maxMatches: FfiConverterOptionalUInt32.read(from),
+				newestFirst: FfiConverterOptionalBool.read(from),
+			};
 		}
-
-		static instanceOf(obj: any): obj is UnsupportedKeyType_ {
-			return obj.tag === SshError_Tags.UnsupportedKeyType;
+		write(value: TypeName, into: RustBuffer): void {
+			FfiConverterOptionalBool.write(value.caseInsensitive, into);
+			FfiConverterOptionalBool.write(value.literal, into);
+			FfiConverterOptionalUInt32.write(value.maxMatches, into);
+			FfiConverterOptionalBool.write(value.newestFirst, into);
 		}
-
-		static hasInner(obj: any): obj is UnsupportedKeyType_ {
-			return false;
+		allocationSize(value: TypeName): number {
+			return (
+				FfiConverterOptionalBool.allocationSize(value.caseInsensitive) +
+				FfiConverterOptionalBool.allocationSize(value.literal) +
+				FfiConverterOptionalUInt32.allocationSize(value.maxMatches) +
+				FfiConverterOptionalBool.allocationSize(value.newestFirst)
+			);
 		}
 	}
+	return new FFIConverter();
+})();
 
-	type Auth__interface = {
-		tag: SshError_Tags.Auth;
-		inner: Readonly<[string]>;
-	};
+export type SearchResult = {
+	matches: Array<SearchMatch>;
+	truncated: boolean;
+};
 
-	class Auth_ extends UniffiError implements Auth__interface {
+/**
+ * Generated factory for {@link SearchResult} record objects.
+ */
+export const SearchResult = (() => {
+	const defaults = () => ({});
+	const create = (() => {
+		return uniffiCreateRecord<SearchResult, ReturnType<typeof defaults>>(
+			defaults,
+		);
+	})();
+	return Object.freeze({
 		/**
-		 * @private
-		 * This field is private and should not be used, use `tag` instead.
+		 * Create a frozen instance of {@link SearchResult}, with defaults specified
+		 * in Rust, in the {@link uniffi_russh} crate.
 		 */
-		readonly [uniffiTypeNameSymbol] = 'SshError';
-		readonly tag = SshError_Tags.Auth;
-		readonly inner: Readonly<[string]>;
-		constructor(v0: string) {
-			super('SshError', 'Auth');
-			this.inner = Object.freeze([v0]);
-		}
-
-		static new(v0: string): Auth_ {
-			return new Auth_(v0);
-		}
-
-		static instanceOf(obj: any): obj is Auth_ {
-			return obj.tag === SshError_Tags.Auth;
-		}
-
-		static hasInner(obj: any): obj is Auth_ {
-			return Auth_.instanceOf(obj);
-		}
-
-		static getInner(obj: Auth_): Readonly<[string]> {
-			return obj.inner;
-		}
-	}
+		create,
 
-	type ShellAlreadyRunning__interface = {
-		tag: SshError_Tags.ShellAlreadyRunning;
-	};
+		/**
+		 * Create a frozen instance of {@link SearchResult}, with defaults specified
+		 * in Rust, in the {@link uniffi_russh} crate.
+		 */
+		new: create,
 
-	class ShellAlreadyRunning_
-		extends UniffiError
-		implements ShellAlreadyRunning__interface
-	{
 		/**
-		 * @private
-		 * This field is private and should not be used, use `tag` instead.
+		 * Defaults specified in the {@link uniffi_russh} crate.
 		 */
-		readonly [uniffiTypeNameSymbol] = 'SshError';
-		readonly tag = SshError_Tags.ShellAlreadyRunning;
-		constructor() {
-			super('SshError', 'ShellAlreadyRunning');
-		}
+		defaults: () => Object.freeze(defaults()) as Partial<SearchResult>,
+	});
+})();
 
-		static new(): ShellAlreadyRunning_ {
-			return new ShellAlreadyRunning_();
+const FfiConverterTypeSearchResult = (() => {
+	type TypeName = SearchResult;
+	class FFIConverter extends AbstractFfiConverterByteArray<TypeName> {
+		read(from: RustBuffer): TypeName {
+			return {
+				matches: FfiConverterArrayTypeSearchMatch.read(from),
+				truncated: FfiConverterBool.read(from),
+			};
 		}
-
-		static instanceOf(obj: any): obj is ShellAlreadyRunning_ {
-			return obj.tag === SshError_Tags.ShellAlreadyRunning;
+		write(value: TypeName, into: RustBuffer): void {
+			FfiConverterArrayTypeSearchMatch.write(value.matches, into);
+			FfiConverterBool.write(value.truncated, into);
 		}
-
-		static hasInner(obj: any): obj is ShellAlreadyRunning_ {
-			return false;
+		allocationSize(value: TypeName): number {
+			return (
+				FfiConverterArrayTypeSearchMatch.allocationSize(value.matches) +
+				FfiConverterBool.allocationSize(value.truncated)
+			);
 		}
 	}
+	return new FFIConverter();
+})();
 
-	type Russh__interface = {
-		tag: SshError_Tags.Russh;
-		inner: Readonly<[string]>;
-	};
+export type ServerPublicKeyInfo = {
+	host: string;
+	port: /*u16*/ number;
+	remoteIp: string | undefined;
+	algorithm: string;
+	fingerprintSha256: string;
+	fingerprintMd5: string;
+	randomart: string;
+	keyBase64: string;
+};
 
-	class Russh_ extends UniffiError implements Russh__interface {
+/**
+ * Generated factory for {@link ServerPublicKeyInfo} record objects.
+ */
+export const ServerPublicKeyInfo = (() => {
+	const defaults = () => ({});
+	const create = (() => {
+		return uniffiCreateRecord<ServerPublicKeyInfo, ReturnType<typeof defaults>>(
+			defaults,
+		);
+	})();
+	return Object.freeze({
 		/**
-		 * @private
-		 * This field is private and should not be used, use `tag` instead.
+		 * Create a frozen instance of {@link ServerPublicKeyInfo}, with defaults specified
+		 * in Rust, in the {@link uniffi_russh} crate.
 		 */
-		readonly [uniffiTypeNameSymbol] = 'SshError';
-		readonly tag = SshError_Tags.Russh;
-		readonly inner: Readonly<[string]>;
-		constructor(v0: string) {
-			super('SshError', 'Russh');
-			this.inner = Object.freeze([v0]);
-		}
+		create,
 
-		static new(v0: string): Russh_ {
-			return new Russh_(v0);
-		}
+		/**
+		 * Create a frozen instance of {@link ServerPublicKeyInfo}, with defaults specified
+		 * in Rust, in the {@link uniffi_russh} crate.
+		 */
+		new: create,
 
-		static instanceOf(obj: any): obj is Russh_ {
-			return obj.tag === SshError_Tags.Russh;
-		}
+		/**
+		 * Defaults specified in the {@link uniffi_russh} crate.
+		 */
+		defaults: () => Object.freeze(defaults()) as Partial<ServerPublicKeyInfo>,
+	});
+})();
 
-		static hasInner(obj: any): obj is Russh_ {
-			return Russh_.instanceOf(obj);
+const FfiConverterTypeServerPublicKeyInfo = (() => {
+	type TypeName = ServerPublicKeyInfo;
+	class FFIConverter extends AbstractFfiConverterByteArray<TypeName> {
+		read(from: RustBuffer): TypeName {
+			return {
+				host: FfiConverterString.read(from),
+				port: FfiConverterUInt16.read(from),
+				remoteIp: FfiConverterOptionalString.read(from),
+				algorithm: FfiConverterString.read(from),
+				fingerprintSha256: FfiConverterString.read(from),
+				fingerprintMd5: FfiConverterString.read(from),
+				randomart: FfiConverterString.read(from),
+				keyBase64: FfiConverterString.read(from),
+			};
 		}
-
-		static getInner(obj: Russh_): Readonly<[string]> {
-			return obj.inner;
+		write(value: TypeName, into: RustBuffer): void {
+			FfiConverterString.write(value.host, into);
+			FfiConverterUInt16.write(value.port, into);
+			FfiConverterOptionalString.write(value.remoteIp, into);
+			FfiConverterString.write(value.algorithm, into);
+			FfiConverterString.write(value.fingerprintSha256, into);
+			FfiConverterString.write(value.fingerprintMd5, into);
+			FfiConverterString.write(value.randomart, into);
+			FfiConverterString.write(value.keyBase64, into);
+		}
+		allocationSize(value: TypeName): number {
+			return (
+				FfiConverterString.allocationSize(value.host) +
+				FfiConverterUInt16.allocationSize(value.port) +
+				FfiConverterOptionalString.allocationSize(value.remoteIp) +
+				FfiConverterString.allocationSize(value.algorithm) +
+				FfiConverterString.allocationSize(value.fingerprintSha256) +
+				FfiConverterString.allocationSize(value.fingerprintMd5) +
+				FfiConverterString.allocationSize(value.randomart) +
+				FfiConverterString.allocationSize(value.keyBase64)
+			);
 		}
 	}
+	return new FFIConverter();
+})();
 
-	type RusshKeys__interface = {
-		tag: SshError_Tags.RusshKeys;
-		inner: Readonly<[string]>;
-	};
+/**
+ * Snapshot of shell session info for property-like access in TS.
+ */
+export type ShellSessionInfo = {
+	channelId: /*u32*/ number;
+	createdAtMs: /*f64*/ number;
+	connectedAtMs: /*f64*/ number;
+	term: TerminalType;
+	termName: string;
+	connectionId: string;
+	rejectedEnv: Array<string>;
+	terminalGeometry: TerminalGeometry;
+	command: ShellCommand | undefined;
+	pty: boolean;
+};
 
-	class RusshKeys_ extends UniffiError implements RusshKeys__interface {
+/**
+ * Generated factory for {@link ShellSessionInfo} record objects.
+ */
+export const ShellSessionInfo = (() => {
+	const defaults = () => ({});
+	const create = (() => {
+		return uniffiCreateRecord<ShellSessionInfo, ReturnType<typeof defaults>>(
+			defaults,
+		);
+	})();
+	return Object.freeze({
 		/**
-		 * @private
-		 * This field is private and should not be used, use `tag` instead.
+		 * Create a frozen instance of {@link ShellSessionInfo}, with defaults specified
+		 * in Rust, in the {@link uniffi_russh} crate.
 		 */
-		readonly [uniffiTypeNameSymbol] = 'SshError';
-		readonly tag = SshError_Tags.RusshKeys;
-		readonly inner: Readonly<[string]>;
-		constructor(v0: string) {
-			super('SshError', 'RusshKeys');
-			this.inner = Object.freeze([v0]);
-		}
+		create,
 
-		static new(v0: string): RusshKeys_ {
-			return new RusshKeys_(v0);
-		}
+		/**
+		 * Create a frozen instance of {@link ShellSessionInfo}, with defaults specified
+		 * in Rust, in the {@link uniffi_russh} crate.
+		 */
+		new: create,
 
-		static instanceOf(obj: any): obj is RusshKeys_ {
-			return obj.tag === SshError_Tags.RusshKeys;
-		}
+		/**
+		 * Defaults specified in the {@link uniffi_russh} crate.
+		 */
+		defaults: () => Object.freeze(defaults()) as Partial<ShellSessionInfo>,
+	});
+})();
 
-		static hasInner(obj: any): obj is RusshKeys_ {
-			return RusshKeys_.instanceOf(obj);
+const FfiConverterTypeShellSessionInfo = (() => {
+	type TypeName = ShellSessionInfo;
+	class FFIConverter extends AbstractFfiConverterByteArray<TypeName> {
+		read(from: RustBuffer): TypeName {
+			return {
+				channelId: FfiConverterUInt32.read(from),
+				createdAtMs: FfiConverterFloat64.read(from),
+				connectedAtMs: FfiConverterFloat64.read(from),
+				term: FfiConverterTypeTerminalType.read(from),
+				termName: FfiConverterString.read(from),
+				connectionId: FfiConverterString.read(from),
+				rejectedEnv: FfiConverterArrayString.read(from),
+				terminalGeometry: FfiConverterTypeTerminalGeometry.read(from),
+				command: FfiConverterOptionalTypeShellCommand.read(from),
+				pty: FfiConverterBool.read(from),
+			};
 		}
-
-		static getInner(obj: RusshKeys_): Readonly<[string]> {
-			return obj.inner;
+		write(value: TypeName, into: RustBuffer): void {
+			FfiConverterUInt32.write(value.channelId, into);
+			FfiConverterFloat64.write(value.createdAtMs, into);
+			FfiConverterFloat64.write(value.connectedAtMs, into);
+			FfiConverterTypeTerminalType.write(value.term, into);
+			FfiConverterString.write(value.termName, into);
+			FfiConverterString.write(value.connectionId, into);
+			FfiConverterArrayString.write(value.rejectedEnv, into);
+			FfiConverterTypeTerminalGeometry.write(value.terminalGeometry, into);
+			FfiConverterOptionalTypeShellCommand.write(value.command, into);
+			FfiConverterBool.write(value.pty, into);
+		}
+		allocationSize(value: TypeName): number {
+			return (
+				FfiConverterUInt32.allocationSize(value.channelId) +
+				FfiConverterFloat64.allocationSize(value.createdAtMs) +
+				FfiConverterFloat64.allocationSize(value.connectedAtMs) +
+				FfiConverterTypeTerminalType.allocationSize(value.term) +
+				FfiConverterString.allocationSize(value.termName) +
+				FfiConverterString.allocationSize(value.connectionId) +
+				FfiConverterArrayString.allocationSize(value.rejectedEnv) +
+				FfiConverterTypeTerminalGeometry.allocationSize(
+					value.terminalGeometry,
+				) +
+				FfiConverterOptionalTypeShellCommand.allocationSize(value.command) +
+				FfiConverterBool.allocationSize(value.pty)
+			);
 		}
 	}
+	return new FFIConverter();
+})();
 
-	function instanceOf(obj: any): obj is SshError {
-		return obj[uniffiTypeNameSymbol] === 'SshError';
-	}
+export type SshConnectionInfo = {
+	connectionId: string;
+	connectionDetails: ConnectionDetails;
+	createdAtMs: /*f64*/ number;
+	connectedAtMs: /*f64*/ number;
+	progressTimings: SshConnectionInfoProgressTimings;
+	serverIdentification: string | undefined;
+	authBanner: string | undefined;
+	negotiatedAlgorithms: NegotiatedAlgorithms | undefined;
+};
 
+/**
+ * Generated factory for {@link SshConnectionInfo} record objects.
+ */
+export const SshConnectionInfo = (() => {
+	const defaults = () => ({});
+	const create = (() => {
+		return uniffiCreateRecord<SshConnectionInfo, ReturnType<typeof defaults>>(
+			defaults,
+		);
+	})();
 	return Object.freeze({
-		instanceOf,
-		Disconnected: Disconnected_,
-		UnsupportedKeyType: UnsupportedKeyType_,
-		Auth: Auth_,
-		ShellAlreadyRunning: ShellAlreadyRunning_,
-		Russh: Russh_,
-		RusshKeys: RusshKeys_,
+		/**
+		 * Create a frozen instance of {@link SshConnectionInfo}, with defaults specified
+		 * in Rust, in the {@link uniffi_russh} crate.
+		 */
+		create,
+
+		/**
+		 * Create a frozen instance of {@link SshConnectionInfo}, with defaults specified
+		 * in Rust, in the {@link uniffi_russh} crate.
+		 */
+		new: create,
+
+		/**
+		 * Defaults specified in the {@link uniffi_russh} crate.
+		 */
+		defaults: () => Object.freeze(defaults()) as Partial<SshConnectionInfo>,
 	});
 })();
 
-export type SshError = InstanceType<
-	(typeof SshError)[keyof Omit<typeof SshError, 'instanceOf'>]
->;
-
-// FfiConverter for enum SshError
-const FfiConverterTypeSshError = (() => {
-	const ordinalConverter = FfiConverterInt32;
-	type TypeName = SshError;
+const FfiConverterTypeSshConnectionInfo = (() => {
+	type TypeName = SshConnectionInfo;
 	class FFIConverter extends AbstractFfiConverterByteArray<TypeName> {
 		read(from: RustBuffer): TypeName {
-			switch (ordinalConverter.read(from)) {
-				case 1:
-					return new SshError.Disconnected();
-				case 2:
-					return new SshError.UnsupportedKeyType();
-				case 3:
-					return new SshError.Auth(FfiConverterString.read(from));
-				case 4:
-					return new SshError.ShellAlreadyRunning();
-				case 5:
-					return new SshError.Russh(FfiConverterString.read(from));
-				case 6:
-					return new SshError.RusshKeys(FfiConverterString.read(from));
-				default:
-					throw new UniffiInternalError.UnexpectedEnumCase();
-			}
+			return {
+				connectionId: FfiConverterString.read(from),
+				connectionDetails: FfiConverterTypeConnectionDetails.read(from),
+				createdAtMs: FfiConverterFloat64.read(from),
+				connectedAtMs: FfiConverterFloat64.read(from),
+				progressTimings:
+					FfiConverterTypeSshConnectionInfoProgressTimings.read(from),
+				serverIdentification: FfiConverterOptionalString.read(from),
+				authBanner: FfiConverterOptionalString.read(from),
+				negotiatedAlgorithms:
+					FfiConverterOptionalTypeNegotiatedAlgorithms.read(from),
+			};
 		}
 		write(value: TypeName, into: RustBuffer): void {
-			switch (value.tag) {
-				case SshError_Tags.Disconnected: {
-					ordinalConverter.write(1, into);
-					return;
-				}
-				case SshError_Tags.UnsupportedKeyType: {
-					ordinalConverter.write(2, into);
-					return;
-				}
-				case SshError_Tags.Auth: {
-					ordinalConverter.write(3, into);
-					const inner = value.inner;
-					FfiConverterString.write(inner[0], into);
-					return;
-				}
-				case SshError_Tags.ShellAlreadyRunning: {
-					ordinalConverter.write(4, into);
-					return;
-				}
-				case SshError_Tags.Russh: {
-					ordinalConverter.write(5, into);
-					const inner = value.inner;
-					FfiConverterString.write(inner[0], into);
-					return;
-				}
-				case SshError_Tags.RusshKeys: {
-					ordinalConverter.write(6, into);
-					const inner = value.inner;
-					FfiConverterString.write(inner[0], into);
-					return;
-				}
-				default:
-					// Throwing from here means that SshError_Tags hasn't matched an ordinal.
-					throw new UniffiInternalError.UnexpectedEnumCase();
-			}
+			FfiConverterString.write(value.connectionId, into);
+			FfiConverterTypeConnectionDetails.write(value.connectionDetails, into);
+			FfiConverterFloat64.write(value.createdAtMs, into);
+			FfiConverterFloat64.write(value.connectedAtMs, into);
+			FfiConverterTypeSshConnectionInfoProgressTimings.write(
+				value.progressTimings,
+				into,
+			);
+			FfiConverterOptionalString.write(value.serverIdentification, into);
+			FfiConverterOptionalString.write(value.authBanner, into);
+			FfiConverterOptionalTypeNegotiatedAlgorithms.write(
+				value.negotiatedAlgorithms,
+				into,
+			);
 		}
 		allocationSize(value: TypeName): number {
-			switch (value.tag) {
-				case SshError_Tags.Disconnected: {
-					return ordinalConverter.allocationSize(1);
-				}
-				case SshError_Tags.UnsupportedKeyType: {
-					return ordinalConverter.allocationSize(2);
-				}
-				case SshError_Tags.Auth: {
-					const inner = value.inner;
-					let size = ordinalConverter.allocationSize(3);
-					size += FfiConverterString.allocationSize(inner[0]);
-					return size;
-				}
-				case SshError_Tags.ShellAlreadyRunning: {
-					return ordinalConverter.allocationSize(4);
-				}
-				case SshError_Tags.Russh: {
-					const inner = value.inner;
-					let size = ordinalConverter.allocationSize(5);
-					size += FfiConverterString.allocationSize(inner[0]);
-					return size;
-				}
-				case SshError_Tags.RusshKeys: {
-					const inner = value.inner;
-					let size = ordinalConverter.allocationSize(6);
-					size += FfiConverterString.allocationSize(inner[0]);
-					return size;
-				}
-				default:
-					throw new UniffiInternalError.UnexpectedEnumCase();
-			}
+			return (
+				FfiConverterString.allocationSize(value.connectionId) +
+				FfiConverterTypeConnectionDetails.allocationSize(
+					value.connectionDetails,
+				) +
+				FfiConverterFloat64.allocationSize(value.createdAtMs) +
+				FfiConverterFloat64.allocationSize(value.connectedAtMs) +
+				FfiConverterTypeSshConnectionInfoProgressTimings.allocationSize(
+					value.progressTimings,
+				) +
+				FfiConverterOptionalString.allocationSize(value.serverIdentification) +
+				FfiConverterOptionalString.allocationSize(value.authBanner) +
+				FfiConverterOptionalTypeNegotiatedAlgorithms.allocationSize(
+					value.negotiatedAlgorithms,
+				)
+			);
 		}
 	}
 	return new FFIConverter();
 })();
 
-export enum StreamKind {
-	Stdout,
-	Stderr,
-}
+export type SshConnectionInfoProgressTimings = {
+	dnsResolvedAtMs: /*f64*/ number;
+	tcpEstablishedAtMs: /*f64*/ number;
+	hostKeyVerifiedAtMs: /*f64*/ number;
+	sshHandshakeAtMs: /*f64*/ number;
+	authenticatingAtMs: /*f64*/ number;
+	authAttemptAtMs: /*f64*/ number;
+	authenticatedAtMs: /*f64*/ number;
+	connectedAtMs: /*f64*/ number;
+};
 
-const FfiConverterTypeStreamKind = (() => {
-	const ordinalConverter = FfiConverterInt32;
-	type TypeName = StreamKind;
+/**
+ * Generated factory for {@link SshConnectionInfoProgressTimings} record objects.
+ */
+export const SshConnectionInfoProgressTimings = (() => {
+	const defaults = () => ({});
+	const create = (() => {
+		return uniffiCreateRecord<
+			SshConnectionInfoProgressTimings,
+			ReturnType<typeof defaults>
+		>(defaults);
+	})();
+	return Object.freeze({
+		/**
+		 * Create a frozen instance of {@link SshConnectionInfoProgressTimings}, with defaults specified
+		 * in Rust, in the {@link uniffi_russh} crate.
+		 */
+		create,
+
+		/**
+		 * Create a frozen instance of {@link SshConnectionInfoProgressTimings}, with defaults specified
+		 * in Rust, in the {@link uniffi_russh} crate.
+		 */
+		new: create,
+
+		/**
+		 * Defaults specified in the {@link uniffi_russh} crate.
+		 */
+		defaults: () =>
+			Object.freeze(defaults()) as Partial<SshConnectionInfoProgressTimings>,
+	});
+})();
+
+const FfiConverterTypeSshConnectionInfoProgressTimings = (() => {
+	type TypeName = SshConnectionInfoProgressTimings;
 	class FFIConverter extends AbstractFfiConverterByteArray<TypeName> {
 		read(from: RustBuffer): TypeName {
-			switch (ordinalConverter.read(from)) {
-				case 1:
-					return StreamKind.Stdout;
-				case 2:
-					return StreamKind.Stderr;
-				default:
-					throw new UniffiInternalError.UnexpectedEnumCase();
-			}
+			return {
+				dnsResolvedAtMs: FfiConverterFloat64.read(from),
+				tcpEstablishedAtMs: FfiConverterFloat64.read(from),
+				hostKeyVerifiedAtMs: FfiConverterFloat64.read(from),
+				sshHandshakeAtMs: FfiConverterFloat64.read(from),
+				authenticatingAtMs: FfiConverterFloat64.read(from),
+				authAttemptAtMs: FfiConverterFloat64.read(from),
+				authenticatedAtMs: FfiConverterFloat64.read(from),
+				connectedAtMs: FfiConverterFloat64.read(from),
+			};
 		}
 		write(value: TypeName, into: RustBuffer): void {
-			switch (value) {
-				case StreamKind.Stdout:
-					return ordinalConverter.write(1, into);
-				case StreamKind.Stderr:
-					return ordinalConverter.write(2, into);
-			}
+			FfiConverterFloat64.write(value.dnsResolvedAtMs, into);
+			FfiConverterFloat64.write(value.tcpEstablishedAtMs, into);
+			FfiConverterFloat64.write(value.hostKeyVerifiedAtMs, into);
+			FfiConverterFloat64.write(value.sshHandshakeAtMs, into);
+			FfiConverterFloat64.write(value.authenticatingAtMs, into);
+			FfiConverterFloat64.write(value.authAttemptAtMs, into);
+			FfiConverterFloat64.write(value.authenticatedAtMs, into);
+			FfiConverterFloat64.write(value.connectedAtMs, into);
 		}
 		allocationSize(value: TypeName): number {
-			return ordinalConverter.allocationSize(0);
+			return (
+				FfiConverterFloat64.allocationSize(value.dnsResolvedAtMs) +
+				FfiConverterFloat64.allocationSize(value.tcpEstablishedAtMs) +
+				FfiConverterFloat64.allocationSize(value.hostKeyVerifiedAtMs) +
+				FfiConverterFloat64.allocationSize(value.sshHandshakeAtMs) +
+				FfiConverterFloat64.allocationSize(value.authenticatingAtMs) +
+				FfiConverterFloat64.allocationSize(value.authAttemptAtMs) +
+				FfiConverterFloat64.allocationSize(value.authenticatedAtMs) +
+				FfiConverterFloat64.allocationSize(value.connectedAtMs)
+			);
 		}
 	}
 	return new FFIConverter();
 })();
 
-export enum TerminalType {
-	Vanilla,
-	Vt100,
-	Vt102,
-	Vt220,
-	Ansi,
-	Xterm,
-	Xterm256,
-}
+/**
+ * Snapshot of the traffic and latency counters of a connection.
+ */
+export type SshConnectionMetrics = {
+	bytesSent: /*u64*/ bigint;
+	bytesReceived: /*u64*/ bigint;
+	payloadBytesSent: /*u64*/ bigint;
+	payloadBytesReceived: /*u64*/ bigint;
+	compressionRatioSent: /*f64*/ number | undefined;
+	compressionRatioReceived: /*f64*/ number | undefined;
+	compressionClientToServer: string | undefined;
+	compressionServerToClient: string | undefined;
+	channelsOpen: /*u32*/ number;
+	channelsOpenedTotal: /*u32*/ number;
+	rekeyCount: /*u32*/ number;
+	rttLastMs: /*f64*/ number | undefined;
+	rttMinMs: /*f64*/ number | undefined;
+	rttSmoothedMs: /*f64*/ number | undefined;
+	sampledAtMs: /*f64*/ number;
+};
 
-const FfiConverterTypeTerminalType = (() => {
-	const ordinalConverter = FfiConverterInt32;
-	type TypeName = TerminalType;
+/**
+ * Generated factory for {@link SshConnectionMetrics} record objects.
+ */
+export const SshConnectionMetrics = (() => {
+	const defaults = () => ({});
+	const create = (() => {
+		return uniffiCreateRecord<
+			SshConnectionMetrics,
+			ReturnType<typeof defaults>
+		>(defaults);
+	})();
+	return Object.freeze({
+		/**
+		 * Create a frozen instance of {@link SshConnectionMetrics}, with defaults specified
+		 * in Rust, in the {@link uniffi_russh} crate.
+		 */
+		create,
+
+		/**
+		 * Create a frozen instance of {@link SshConnectionMetrics}, with defaults specified
+		 * in Rust, in the {@link uniffi_russh} crate.
+		 */
+		new: create,
+
+		/**
+		 * Defaults specified in the {@link uniffi_russh} crate.
+		 */
+		defaults: () => Object.freeze(defaults()) as Partial<SshConnectionMetrics>,
+	});
+})();
+
+const FfiConverterTypeSshConnectionMetrics = (() => {
+	type TypeName = SshConnectionMetrics;
 	class FFIConverter extends AbstractFfiConverterByteArray<TypeName> {
 		read(from: RustBuffer): TypeName {
-			switch (ordinalConverter.read(from)) {
-				case 1:
-					return TerminalType.Vanilla;
-				case 2:
-					return TerminalType.Vt100;
-				case 3:
-					return TerminalType.Vt102;
-				case 4:
-					return TerminalType.Vt220;
-				case 5:
-					return TerminalType.Ansi;
-				case 6:
-					return TerminalType.Xterm;
-				case 7:
-					return TerminalType.Xterm256;
-				default:
-					throw new UniffiInternalError.UnexpectedEnumCase();
-			}
+			return {
+				bytesSent: FfiConverterUInt64.read(from),
+				bytesReceived: FfiConverterUInt64.read(from),
+				payloadBytesSent: FfiConverterUInt64.read(from),
+				payloadBytesReceived: FfiConverterUInt64.read(from),
+				compressionRatioSent: FfiConverterOptionalFloat64.read(from),
+				compressionRatioReceived: FfiConverterOptionalFloat64.read(from),
+				compressionClientToServer: FfiConverterOptionalString.read(from),
+				compressionServerToClient: FfiConverterOptionalString.read(from),
+				channelsOpen: FfiConverterUInt32.read(from),
+				channelsOpenedTotal: FfiConverterUInt32.read(from),
+				rekeyCount: FfiConverterUInt32.read(from),
+				rttLastMs: FfiConverterOptionalFloat64.read(from),
+				rttMinMs: FfiConverterOptionalFloat64.read(from),
+				rttSmoothedMs: FfiConverterOptionalFloat64.read(from),
+				sampledAtMs: FfiConverterFloat64.read(from),
+			};
 		}
 		write(value: TypeName, into: RustBuffer): void {
-			switch (value) {
-				case TerminalType.Vanilla:
-					return ordinalConverter.write(1, into);
-				case TerminalType.Vt100:
-					return ordinalConverter.write(2, into);
-				case TerminalType.Vt102:
-					return ordinalConverter.write(3, into);
-				case TerminalType.Vt220:
-					return ordinalConverter.write(4, into);
-				case TerminalType.Ansi:
-					return ordinalConverter.write(5, into);
-				case TerminalType.Xterm:
-					return ordinalConverter.write(6, into);
-				case TerminalType.Xterm256:
-					return ordinalConverter.write(7, into);
-			}
+			FfiConverterUInt64.write(value.bytesSent, into);
+			FfiConverterUInt64.write(value.bytesReceived, into);
+			FfiConverterUInt64.write(value.payloadBytesSent, into);
+			FfiConverterUInt64.write(value.payloadBytesReceived, into);
+			FfiConverterOptionalFloat64.write(value.compressionRatioSent, into);
+			FfiConverterOptionalFloat64.write(value.compressionRatioReceived, into);
+			FfiConverterOptionalString.write(value.compressionClientToServer, into);
+			FfiConverterOptionalString.write(value.compressionServerToClient, into);
+			FfiConverterUInt32.write(value.channelsOpen, into);
+			FfiConverterUInt32.write(value.channelsOpenedTotal, into);
+			FfiConverterUInt32.write(value.rekeyCount, into);
+			FfiConverterOptionalFloat64.write(value.rttLastMs, into);
+			FfiConverterOptionalFloat64.write(value.rttMinMs, into);
+			FfiConverterOptionalFloat64.write(value.rttSmoothedMs, into);
+			FfiConverterFloat64.write(value.sampledAtMs, into);
 		}
 		allocationSize(value: TypeName): number {
-			return ordinalConverter.allocationSize(0);
+			return (
+				FfiConverterUInt64.allocationSize(value.bytesSent) +
+				FfiConverterUInt64.allocationSize(value.bytesReceived) +
+				FfiConverterUInt64.allocationSize(value.payloadBytesSent) +
+				FfiConverterUInt64.allocationSize(value.payloadBytesReceived) +
+				FfiConverterOptionalFloat64.allocationSize(value.compressionRatioSent) +
+				FfiConverterOptionalFloat64.allocationSize(
+					value.compressionRatioReceived,
+				) +
+				FfiConverterOptionalString.allocationSize(
+					value.compressionClientToServer,
+				) +
+				FfiConverterOptionalString.allocationSize(
+					value.compressionServerToClient,
+				) +
+				FfiConverterUInt32.allocationSize(value.channelsOpen) +
+				FfiConverterUInt32.allocationSize(value.channelsOpenedTotal) +
+				FfiConverterUInt32.allocationSize(value.rekeyCount) +
+				FfiConverterOptionalFloat64.allocationSize(value.rttLastMs) +
+				FfiConverterOptionalFloat64.allocationSize(value.rttMinMs) +
+				FfiConverterOptionalFloat64.allocationSize(value.rttSmoothedMs) +
+				FfiConverterFloat64.allocationSize(value.sampledAtMs)
+			);
 		}
 	}
 	return new FFIConverter();
 })();
 
-export interface ConnectProgressCallback {
-	onChange(status: SshConnectionProgressEvent): void;
-}
+export type StartShellOptions = {
+	term: TerminalType;
+	terminalMode: Array<TerminalMode> | undefined;
+	terminalSize: TerminalSize | undefined;
+	terminalPixelSize: TerminalPixelSize | undefined;
+	onClosedCallback: ShellClosedCallback | undefined;
+	env: Map<string, string> | undefined;
+	locale: string | undefined;
+	command: ShellCommand | undefined;
+	allocatePty: boolean | undefined;
+	termName: string | undefined;
+	bufferPolicy: BufferPolicy | undefined;
+	onChannelPauseCallback: ChannelPauseCallback | undefined;
+};
 
-export class ConnectProgressCallbackImpl
-	extends UniffiAbstractObject
-	implements ConnectProgressCallback
-{
-	readonly [uniffiTypeNameSymbol] = 'ConnectProgressCallbackImpl';
-	readonly [destructorGuardSymbol]: UniffiRustArcPtr;
-	readonly [pointerLiteralSymbol]: UnsafeMutableRawPointer;
-	// No primary constructor declared for this class.
-	private constructor(pointer: UnsafeMutableRawPointer) {
+/**
+ * Generated factory for {@link StartShellOptions} record objects.
+ */
+export const StartShellOptions = (() => {
+	const defaults = () => ({
+		env: undefined,
+		locale: undefined,
+		command: undefined,
+		allocatePty: undefined,
+		termName: undefined,
+		bufferPolicy: undefined,
+		onChannelPauseCallback: undefined,
+	});
+	const create = (() => {
+		return uniffiCreateRecord<StartShellOptions, ReturnType<typeof defaults>>(
+			defaults,
+		);
+	})();
+	return Object.freeze({
+		/**
+		 * Create a frozen instance of {@link StartShellOptions}, with defaults specified
+		 * in Rust, in the {@link uniffi_russh} crate.
+		 */
+		create,
+
+		/**
+		 * Create a frozen instance of {@link StartShellOptions}, with defaults specified
+		 * in Rust, in the {@link uniffi_russh} crate.
+		 */
+		new: create,
+
+		/**
+		 * Defaults specified in the {@link uniffi_russh} crate.
+		 */
+		defaults: () => Object.freeze(defaults()) as Partial<StartShellOptions>,
+	});
+})();
+
+const FfiConverterTypeStartShellOptions = (() => {
+	type TypeName = StartShellOptions;
+	class FFIConverter extends AbstractFfiConverterByteArray<TypeName> {
+		read(from: RustBuffer): TypeName {
+			return {
+				term: FfiConverterTypeTerminalType.read(from),
+				terminalMode: FfiConverterOptionalArrayTypeTerminalMode.read(from),
+				terminalSize: FfiConverterOptionalTypeTerminalSize.read(from),
+				terminalPixelSize: FfiConverterOptionalTypeTerminalPixelSize.read(from),
+				onClosedCallback:
+					FfiConverterOptionalTypeShellClosedCallback.read(from),
+				env: FfiConverterOptionalMapStringString.read(from),
+				locale: FfiConverterOptionalString.read(from),
+				command: FfiConverterOptionalTypeShellCommand.read(from),
+				allocatePty: FfiConverterOptionalBool.read(from),
+				termName: FfiConverterOptionalString.read(from),
+				bufferPolicy: FfiConverterOptionalTypeBufferPolicy.read(from),
+				onChannelPauseCallback:
+					FfiConverterOptionalTypeChannelPauseCallback.read(from),
+			};
+		}
+		write(value: TypeName, into: RustBuffer): void {
+			FfiConverterTypeTerminalType.write(value.term, into);
+			FfiConverterOptionalArrayTypeTerminalMode.write(value.terminalMode, into);
+			FfiConverterOptionalTypeTerminalSize.write(value.terminalSize, into);
+			FfiConverterOptionalTypeTerminalPixelSize.write(
+				value.terminalPixelSize,
+				into,
+			);
+			FfiConverterOptionalTypeShellClosedCallback.write(
+				value.onClosedCallback,
+				into,
+			);
+			FfiConverterOptionalMapStringString.write(value.env, into);
+			FfiConverterOptionalString.write(value.locale, into);
+			FfiConverterOptionalTypeShellCommand.write(value.command, into);
+			FfiConverterOptionalBool.write(value.allocatePty, into);
+			FfiConverterOptionalString.write(value.termName, into);
+			FfiConverterOptionalTypeBufferPolicy.write(value.bufferPolicy, into);
+			FfiConverterOptionalTypeChannelPauseCallback.write(
+				value.onChannelPauseCallback,
+				into,
+			);
+		}
+		allocationSize(value: TypeName): number {
+			return (
+				FfiConverterTypeTerminalType.allocationSize(value.term) +
+				FfiConverterOptionalArrayTypeTerminalMode.allocationSize(
+					value.terminalMode,
+				) +
+				FfiConverterOptionalTypeTerminalSize.allocationSize(
+					value.terminalSize,
+				) +
+				FfiConverterOptionalTypeTerminalPixelSize.allocationSize(
+					value.terminalPixelSize,
+				) +
+				FfiConverterOptionalTypeShellClosedCallback.allocationSize(
+					value.onClosedCallback,
+				) +
+				FfiConverterOptionalMapStringString.allocationSize(value.env) +
+				FfiConverterOptionalString.allocationSize(value.locale) +
+				FfiConverterOptionalTypeShellCommand.allocationSize(value.command) +
+				FfiConverterOptionalBool.allocationSize(value.allocatePty) +
+				FfiConverterOptionalString.allocationSize(value.termName) +
+				FfiConverterOptionalTypeBufferPolicy.allocationSize(
+					value.bufferPolicy,
+				) +
+				FfiConverterOptionalTypeChannelPauseCallback.allocationSize(
+					value.onChannelPauseCallback,
+				)
+			);
+		}
+	}
+	return new FFIConverter();
+})();
+
+/**
+ * A byte in the output stream: `offset` into the chunk with `seq` (may equal
+ * the chunk's length, i.e. the start of the next chunk).
+ */
+export type StreamPosition = {
+	seq: /*u64*/ bigint;
+	offset: /*u64*/ bigint;
+};
+
+/**
+ * Generated factory for {@link StreamPosition} record objects.
+ */
+export const StreamPosition = (() => {
+	const defaults = () => ({});
+	const create = (() => {
+		return uniffiCreateRecord<StreamPosition, ReturnType<typeof defaults>>(
+			defaults,
+		);
+	})();
+	return Object.freeze({
+		/**
+		 * Create a frozen instance of {@link StreamPosition}, with defaults specified
+		 * in Rust, in the {@link uniffi_russh} crate.
+		 */
+		create,
+
+		/**
+		 * Create a frozen instance of {@link StreamPosition}, with defaults specified
+		 * in Rust, in the {@link uniffi_russh} crate.
+		 */
+		new: create,
+
+		/**
+		 * Defaults specified in the {@link uniffi_russh} crate.
+		 */
+		defaults: () => Object.freeze(defaults()) as Partial<StreamPosition>,
+	});
+})();
+
+const FfiConverterTypeStreamPosition = (() => {
+	type TypeName = StreamPosition;
+	class FFIConverter extends AbstractFfiConverterByteArray<TypeName> {
+		read(from: RustBuffer): TypeName {
+			return {
+				seq: FfiConverterUInt64.read(from),
+				offset: FfiConverterUInt64.read(from),
+			};
+		}
+		write(value: TypeName, into: RustBuffer): void {
+			FfiConverterUInt64.write(value.seq, into);
+			FfiConverterUInt64.write(value.offset, into);
+		}
+		allocationSize(value: TypeName): number {
+			return (
+				FfiConverterUInt64.allocationSize(value.seq) +
+				FfiConverterUInt64.allocationSize(value.offset)
+			);
+		}
+	}
+	return new FFIConverter();
+})();
+
+export type TerminalChunk = {
+	seq: /*u64*/ bigint;
+	tMs: /*f64*/ number;
+	stream: StreamKind;
+	bytes: ArrayBuffer;
+};
+
+/**
+ * Generated factory for {@link TerminalChunk} record objects.
+ */
+export const TerminalChunk = (() => {
+	const defaults = () => ({});
+	const create = (() => {
+		return uniffiCreateRecord<TerminalChunk, ReturnType<typeof defaults>>(
+			defaults,
+		);
+	})();
+	return Object.freeze({
+		/**
+		 * Create a frozen instance of {@link TerminalChunk}, with defaults specified
+		 * in Rust, in the {@link uniffi_russh} crate.
+		 */
+		create,
+
+		/**
+		 * Create a frozen instance of {@link TerminalChunk}, with defaults specified
+		 * in Rust, in the {@link uniffi_russh} crate.
+		 */
+		new: create,
+
+		/**
+		 * Defaults specified in the {@link uniffi_russh} crate.
+		 */
+		defaults: () => Object.freeze(defaults()) as Partial<TerminalChunk>,
+	});
+})();
+
+const FfiConverterTypeTerminalChunk = (() => {
+	type TypeName = TerminalChunk;
+	class FFIConverter extends AbstractFfiConverterByteArray<TypeName> {
+		read(from: RustBuffer): TypeName {
+			return {
+				seq: FfiConverterUInt64.read(from),
+				tMs: FfiConverterFloat64.read(from),
+				stream: FfiConverterTypeStreamKind.read(from),
+				bytes: FfiConverterArrayBuffer.read(from),
+			};
+		}
+		write(value: TypeName, into: RustBuffer): void {
+			FfiConverterUInt64.write(value.seq, into);
+			FfiConverterFloat64.write(value.tMs, into);
+			FfiConverterTypeStreamKind.write(value.stream, into);
+			FfiConverterArrayBuffer.write(value.bytes, into);
+		}
+		allocationSize(value: TypeName): number {
+			return (
+				FfiConverterUInt64.allocationSize(value.seq) +
+				FfiConverterFloat64.allocationSize(value.tMs) +
+				FfiConverterTypeStreamKind.allocationSize(value.stream) +
+				FfiConverterArrayBuffer.allocationSize(value.bytes)
+			);
+		}
+	}
+	return new FFIConverter();
+})();
+
+/**
+ * PTY size in characters and pixels (0 = unknown, per RFC 4254).
+ */
+export type TerminalGeometry = {
+	cols: /*u32*/ number;
+	rows: /*u32*/ number;
+	pixelWidth: /*u32*/ number;
+	pixelHeight: /*u32*/ number;
+};
+
+/**
+ * Generated factory for {@link TerminalGeometry} record objects.
+ */
+export const TerminalGeometry = (() => {
+	const defaults = () => ({});
+	const create = (() => {
+		return uniffiCreateRecord<TerminalGeometry, ReturnType<typeof defaults>>(
+			defaults,
+		);
+	})();
+	return Object.freeze({
+		/**
+		 * Create a frozen instance of {@link TerminalGeometry}, with defaults specified
+		 * in Rust, in the {@link uniffi_russh} crate.
+		 */
+		create,
+
+		/**
+		 * Create a frozen instance of {@link TerminalGeometry}, with defaults specified
+		 * in Rust, in the {@link uniffi_russh} crate.
+		 */
+		new: create,
+
+		/**
+		 * Defaults specified in the {@link uniffi_russh} crate.
+		 */
+		defaults: () => Object.freeze(defaults()) as Partial<TerminalGeometry>,
+	});
+})();
+
+const FfiConverterTypeTerminalGeometry = (() => {
+	type TypeName = TerminalGeometry;
+	class FFIConverter extends AbstractFfiConverterByteArray<TypeName> {
+		read(from: RustBuffer): TypeName {
+			return {
+				cols: FfiConverterUInt32.read(from),
+				rows: FfiConverterUInt32.read(from),
+				pixelWidth: FfiConverterUInt32.read(from),
+				pixelHeight: FfiConverterUInt32.read(from),
+			};
+		}
+		write(value: TypeName, into: RustBuffer): void {
+			FfiConverterUInt32.write(value.cols, into);
+			FfiConverterUInt32.write(value.rows, into);
+			FfiConverterUInt32.write(value.pixelWidth, into);
+			FfiConverterUInt32.write(value.pixelHeight, into);
+		}
+		allocationSize(value: TypeName): number {
+			return (
+				FfiConverterUInt32.allocationSize(value.cols) +
+				FfiConverterUInt32.allocationSize(value.rows) +
+				FfiConverterUInt32.allocationSize(value.pixelWidth) +
+				FfiConverterUInt32.allocationSize(value.pixelHeight)
+			);
+		}
+	}
+	return new FFIConverter();
+})();
+
+export type TerminalMode = {
+	opcode: /*u8*/ number;
+	value: /*u32*/ number;
+};
+
+/**
+ * Generated factory for {@link TerminalMode} record objects.
+ */
+export const TerminalMode = (() => {
+	const defaults = () => ({});
+	const create = (() => {
+		return uniffiCreateRecord<TerminalMode, ReturnType<typeof defaults>>(
+			defaults,
+		);
+	})();
+	return Object.freeze({
+		/**
+		 * Create a frozen instance of {@link TerminalMode}, with defaults specified
+		 * in Rust, in the {@link uniffi_russh} crate.
+		 */
+		create,
+
+		/**
+		 * Create a frozen instance of {@link TerminalMode}, with defaults specified
+		 * in Rust, in the {@link uniffi_russh} crate.
+		 */
+		new: create,
+
+		/**
+		 * Defaults specified in the {@link uniffi_russh} crate.
+		 */
+		defaults: () => Object.freeze(defaults()) as Partial<TerminalMode>,
+	});
+})();
+
+const FfiConverterTypeTerminalMode = (() => {
+	type TypeName = TerminalMode;
+	class FFIConverter extends AbstractFfiConverterByteArray<TypeName> {
+		read(from: RustBuffer): TypeName {
+			return {
+				opcode: FfiConverterUInt8.read(from),
+				value: FfiConverterUInt32.read(from),
+			};
+		}
+		write(value: TypeName, into: RustBuffer): void {
+			FfiConverterUInt8.write(value.opcode, into);
+			FfiConverterUInt32.write(value.value, into);
+		}
+		allocationSize(value: TypeName): number {
+			return (
+				FfiConverterUInt8.allocationSize(value.opcode) +
+				FfiConverterUInt32.allocationSize(value.value)
+			);
+		}
+	}
+	return new FFIConverter();
+})();
+
+export type TerminalPixelSize = {
+	pixelWidth: /*u32*/ number | undefined;
+	pixelHeight: /*u32*/ number | undefined;
+};
+
+/**
+ * Generated factory for {@link TerminalPixelSize} record objects.
+ */
+export const TerminalPixelSize = (() => {
+	const defaults = () => ({});
+	const create = (() => {
+		return uniffiCreateRecord<TerminalPixelSize, ReturnType<typeof defaults>>(
+			defaults,
+		);
+	})();
+	return Object.freeze({
+		/**
+		 * Create a frozen instance of {@link TerminalPixelSize}, with defaults specified
+		 * in Rust, in the {@link uniffi_russh} crate.
+		 */
+		create,
+
+		/**
+		 * Create a frozen instance of {@link TerminalPixelSize}, with defaults specified
+		 * in Rust, in the {@link uniffi_russh} crate.
+		 */
+		new: create,
+
+		/**
+		 * Defaults specified in the {@link uniffi_russh} crate.
+		 */
+		defaults: () => Object.freeze(defaults()) as Partial<TerminalPixelSize>,
+	});
+})();
+
+const FfiConverterTypeTerminalPixelSize = (() => {
+	type TypeName = TerminalPixelSize;
+	class FFIConverter extends AbstractFfiConverterByteArray<TypeName> {
+		read(from: RustBuffer): TypeName {
+			return {
+				pixelWidth: FfiConverterOptionalUInt32.read(from),
+				pixelHeight: FfiConverterOptionalUInt32.read(from),
+			};
+		}
+		write(value: TypeName, into: RustBuffer): void {
+			FfiConverterOptionalUInt32.write(value.pixelWidth, into);
+			FfiConverterOptionalUInt32.write(value.pixelHeight, into);
+		}
+		allocationSize(value: TypeName): number {
+			return (
+				FfiConverterOptionalUInt32.allocationSize(value.pixelWidth) +
+				FfiConverterOptionalUInt32.allocationSize(value.pixelHeight)
+			);
+		}
+	}
+	return new FFIConverter();
+})();
+
+export type TerminalSize = {
+	rowHeight: /*u32*/ number | undefined;
+	colWidth: /*u32*/ number | undefined;
+};
+
+/**
+ * Generated factory for {@link TerminalSize} record objects.
+ */
+export const TerminalSize = (() => {
+	const defaults = () => ({});
+	const create = (() => {
+		return uniffiCreateRecord<TerminalSize, ReturnType<typeof defaults>>(
+			defaults,
+		);
+	})();
+	return Object.freeze({
+		/**
+		 * Create a frozen instance of {@link TerminalSize}, with defaults specified
+		 * in Rust, in the {@link uniffi_russh} crate.
+		 */
+		create,
+
+		/**
+		 * Create a frozen instance of {@link TerminalSize}, with defaults specified
+		 * in Rust, in the {@link uniffi_russh} crate.
+		 */
+		new: create,
+
+		/**
+		 * Defaults specified in the {@link uniffi_russh} crate.
+		 */
+		defaults: () => Object.freeze(defaults()) as Partial<TerminalSize>,
+	});
+})();
+
+const FfiConverterTypeTerminalSize = (() => {
+	type TypeName = TerminalSize;
+	class FFIConverter extends AbstractFfiConverterByteArray<TypeName> {
+		read(from: RustBuffer): TypeName {
+			return {
+				rowHeight: FfiConverterOptionalUInt32.read(from),
+				colWidth: FfiConverterOptionalUInt32.read(from),
+			};
+		}
+		write(value: TypeName, into: RustBuffer): void {
+			FfiConverterOptionalUInt32.write(value.rowHeight, into);
+			FfiConverterOptionalUInt32.write(value.colWidth, into);
+		}
+		allocationSize(value: TypeName): number {
+			return (
+				FfiConverterOptionalUInt32.allocationSize(value.rowHeight) +
+				FfiConverterOptionalUInt32.allocationSize(value.colWidth)
+			);
+		}
+	}
+	return new FFIConverter();
+})();
+
+/**
+ * Decoded output for listeners with `text` set. A character split across
+ * chunks is held back until it is complete.
+ */
+export type TerminalText = {
+	seq: /*u64*/ bigint;
+	tMs: /*f64*/ number;
+	stream: StreamKind;
+	text: string;
+};
+
+/**
+ * Generated factory for {@link TerminalText} record objects.
+ */
+export const TerminalText = (() => {
+	const defaults = () => ({});
+	const create = (() => {
+		return uniffiCreateRecord<TerminalText, ReturnType<typeof defaults>>(
+			defaults,
+		);
+	})();
+	return Object.freeze({
+		/**
+		 * Create a frozen instance of {@link TerminalText}, with defaults specified
+		 * in Rust, in the {@link uniffi_russh} crate.
+		 */
+		create,
+
+		/**
+		 * Create a frozen instance of {@link TerminalText}, with defaults specified
+		 * in Rust, in the {@link uniffi_russh} crate.
+		 */
+		new: create,
+
+		/**
+		 * Defaults specified in the {@link uniffi_russh} crate.
+		 */
+		defaults: () => Object.freeze(defaults()) as Partial<TerminalText>,
+	});
+})();
+
+const FfiConverterTypeTerminalText = (() => {
+	type TypeName = TerminalText;
+	class FFIConverter extends AbstractFfiConverterByteArray<TypeName> {
+		read(from: RustBuffer): TypeName {
+			return {
+				seq: FfiConverterUInt64.read(from),
+				tMs: FfiConverterFloat64.read(from),
+				stream: FfiConverterTypeStreamKind.read(from),
+				text: FfiConverterString.read(from),
+			};
+		}
+		write(value: TypeName, into: RustBuffer): void {
+			FfiConverterUInt64.write(value.seq, into);
+			FfiConverterFloat64.write(value.tMs, into);
+			FfiConverterTypeStreamKind.write(value.stream, into);
+			FfiConverterString.write(value.text, into);
+		}
+		allocationSize(value: TypeName): number {
+			return (
+				FfiConverterUInt64.allocationSize(value.seq) +
+				FfiConverterFloat64.allocationSize(value.tMs) +
+				FfiConverterTypeStreamKind.allocationSize(value.stream) +
+				FfiConverterString.allocationSize(value.text)
+			);
+		}
+	}
+	return new FFIConverter();
+})();
+
+/**
+ * Buffered output as plain text (see `ShellSession::read_text`).
+ */
+export type TextReadResult = {
+	text: string;
+	fromSeq: /*u64*/ bigint | undefined;
+	toSeq: /*u64*/ bigint | undefined;
+	nextSeq: /*u64*/ bigint;
+	dropped: DroppedRange | undefined;
+};
+
+/**
+ * Generated factory for {@link TextReadResult} record objects.
+ */
+export const TextReadResult = (() => {
+	const defaults = () => ({});
+	const create = (() => {
+		return uniffiCreateRecord<TextReadResult, ReturnType<typeof defaults>>(
+			defaults,
+		);
+	})();
+	return Object.freeze({
+		/**
+		 * Create a frozen instance of {@link TextReadResult}, with defaults specified
+		 * in Rust, in the {@link uniffi_russh} crate.
+		 */
+		create,
+
+		/**
+		 * Create a frozen instance of {@link TextReadResult}, with defaults specified
+		 * in Rust, in the {@link uniffi_russh} crate.
+		 */
+		new: create,
+
+		/**
+		 * Defaults specified in the {@link uniffi_russh} crate.
+		 */
+		defaults: () => Object.freeze(defaults()) as Partial<TextReadResult>,
+	});
+})();
+
+const FfiConverterTypeTextReadResult = (() => {
+	type TypeName = TextReadResult;
+	class FFIConverter extends AbstractFfiConverterByteArray<TypeName> {
+		read(from: RustBuffer): TypeName {
+			return {
+				text: FfiConverterString.read(from),
+				fromSeq: FfiConverterOptionalUInt64.read(from),
+				toSeq: FfiConverterOptionalUInt64.read(from),
+				nextSeq: FfiConverterUInt64.read(from),
+				dropped: FfiConverterOptionalTypeDroppedRange.read(from),
+			};
+		}
+		write(value: TypeName, into: RustBuffer): void {
+			FfiConverterString.write(value.text, into);
+			FfiConverterOptionalUInt64.write(value.fromSeq, into);
+			FfiConverterOptionalUInt64.write(value.toSeq, into);
+			FfiConverterUInt64.write(value.nextSeq, into);
+			FfiConverterOptionalTypeDroppedRange.write(value.dropped, into);
+		}
+		allocationSize(value: TypeName): number {
+			return (
+				FfiConverterString.allocationSize(value.text) +
+				FfiConverterOptionalUInt64.allocationSize(value.fromSeq) +
+				FfiConverterOptionalUInt64.allocationSize(value.toSeq) +
+				FfiConverterUInt64.allocationSize(value.nextSeq) +
+				FfiConverterOptionalTypeDroppedRange.allocationSize(value.dropped)
+			);
+		}
+	}
+	return new FFIConverter();
+})();
+
+/**
+ * Consecutive cells sharing a style. Wide characters count as two cells.
+ */
+export type TextRun = {
+	col: /*u32*/ number;
+	cells: /*u32*/ number;
+	text: string;
+	style: CellStyle;
+};
+
+/**
+ * Generated factory for {@link TextRun} record objects.
+ */
+export const TextRun = (() => {
+	const defaults = () => ({});
+	const create = (() => {
+		return uniffiCreateRecord<TextRun, ReturnType<typeof defaults>>(defaults);
+	})();
+	return Object.freeze({
+		/**
+		 * Create a frozen instance of {@link TextRun}, with defaults specified
+		 * in Rust, in the {@link uniffi_russh} crate.
+		 */
+		create,
+
+		/**
+		 * Create a frozen instance of {@link TextRun}, with defaults specified
+		 * in Rust, in the {@link uniffi_russh} crate.
+		 */
+		new: create,
+
+		/**
+		 * Defaults specified in the {@link uniffi_russh} crate.
+		 */
+		defaults: () => Object.freeze(defaults()) as Partial<TextRun>,
+	});
+})();
+
+const FfiConverterTypeTextRun = (() => {
+	type TypeName = TextRun;
+	class FFIConverter extends AbstractFfiConverterByteArray<TypeName> {
+		read(from: RustBuffer): TypeName {
+			return {
+				col: FfiConverterUInt32.read(from),
+				cells: FfiConverterUInt32.read(from),
+				text: FfiConverterString.read(from),
+				style: FfiConverterTypeCellStyle.read(from),
+			};
+		}
+		write(value: TypeName, into: RustBuffer): void {
+			FfiConverterUInt32.write(value.col, into);
+			FfiConverterUInt32.write(value.cells, into);
+			FfiConverterString.write(value.text, into);
+			FfiConverterTypeCellStyle.write(value.style, into);
+		}
+		allocationSize(value: TypeName): number {
+			return (
+				FfiConverterUInt32.allocationSize(value.col) +
+				FfiConverterUInt32.allocationSize(value.cells) +
+				FfiConverterString.allocationSize(value.text) +
+				FfiConverterTypeCellStyle.allocationSize(value.style)
+			);
+		}
+	}
+	return new FFIConverter();
+})();
+
+const stringConverter = {
+	stringToBytes: (s: string) =>
+		uniffiCaller.rustCall((status) =>
+			nativeModule().ubrn_uniffi_internal_fn_func_ffi__string_to_arraybuffer(
+				s,
+				status,
+			),
+		),
+	bytesToString: (ab: UniffiByteArray) =>
+		uniffiCaller.rustCall((status) =>
+			nativeModule().ubrn_uniffi_internal_fn_func_ffi__arraybuffer_to_string(
+				ab,
+				status,
+			),
+		),
+	stringByteLength: (s: string) =>
+		uniffiCaller.rustCall((status) =>
+			nativeModule().ubrn_uniffi_internal_fn_func_ffi__string_to_byte_length(
+				s,
+				status,
+			),
+		),
+};
+const FfiConverterString = uniffiCreateFfiConverterString(stringConverter);
+
+// Enum: CastSource
+export enum CastSource_Tags {
+	File = 'File',
+	Text = 'Text',
+}
+export const CastSource = (() => {
+	type File__interface = {
+		tag: CastSource_Tags.File;
+		inner: Readonly<{ path: string }>;
+	};
+
+	class File_ extends UniffiEnum implements File__interface {
+		/**
+		 * @private
+		 * This field is private and should not be used, use `tag` instead.
+		 */
+		readonly [uniffiTypeNameSymbol] = 'CastSource';
+		readonly tag = CastSource_Tags.File;
+		readonly inner: Readonly<{ path: string }>;
+		constructor(inner: { path: string }) {
+			super('CastSource', 'File');
+			this.inner = Object.freeze(inner);
+		}
+
+		static new(inner: { path: string }): File_ {
+			return new File_(inner);
+		}
+
+		static instanceOf(obj: any): obj is File_ {
+			return obj.tag === CastSource_Tags.File;
+		}
+	}
+
+	type Text__interface = {
+		tag: CastSource_Tags.Text;
+		inner: Readonly<{ contents: string }>;
+	};
+
+	class Text_ extends UniffiEnum implements Text__interface {
+		/**
+		 * @private
+		 * This field is private and should not be used, use `tag` instead.
+		 */
+		readonly [uniffiTypeNameSymbol] = 'CastSource';
+		readonly tag = CastSource_Tags.Text;
+		readonly inner: Readonly<{ contents: string }>;
+		constructor(inner: { contents: string }) {
+			super('CastSource', 'Text');
+			this.inner = Object.freeze(inner);
+		}
+
+		static new(inner: { contents: string }): Text_ {
+			return new Text_(inner);
+		}
+
+		static instanceOf(obj: any): obj is Text_ {
+			return obj.tag === CastSource_Tags.Text;
+		}
+	}
+
+	function instanceOf(obj: any): obj is CastSource {
+		return obj[uniffiTypeNameSymbol] === 'CastSource';
+	}
+
+	return Object.freeze({
+		instanceOf,
+		File: File_,
+		Text: Text_,
+	});
+})();
+
+export type CastSource = InstanceType<
+	(typeof CastSource)[keyof Omit<typeof CastSource, 'instanceOf'>]
+>;
+
+// FfiConverter for enum CastSource
+const FfiConverterTypeCastSource = (() => {
+	const ordinalConverter = FfiConverterInt32;
+	type TypeName = CastSource;
+	class FFIConverter extends AbstractFfiConverterByteArray<TypeName> {
+		read(from: RustBuffer): TypeName {
+			switch (ordinalConverter.read(from)) {
+				case 1:
+					return new CastSource.File({ path: FfiConverterString.read(from) });
+				case 2:
+					return new CastSource.Text({
+						contents: FfiConverterString.read(from),
+					});
+				default:
+					throw new UniffiInternalError.UnexpectedEnumCase();
+			}
+		}
+		write(value: TypeName, into: RustBuffer): void {
+			switch (value.tag) {
+				case CastSource_Tags.File: {
+					ordinalConverter.write(1, into);
+					const inner = value.inner;
+					FfiConverterString.write(inner.path, into);
+					return;
+				}
+				case CastSource_Tags.Text: {
+					ordinalConverter.write(2, into);
+					const inner = value.inner;
+					FfiConverterString.write(inner.contents, into);
+					return;
+				}
+				default:
+					// Throwing from here means that CastSource_Tags hasn't matched an ordinal.
+					throw new UniffiInternalError.UnexpectedEnumCase();
+			}
+		}
+		allocationSize(value: TypeName): number {
+			switch (value.tag) {
+				case CastSource_Tags.File: {
+					const inner = value.inner;
+					let size = ordinalConverter.allocationSize(1);
+					size += FfiConverterString.allocationSize(inner.path);
+					return size;
+				}
+				case CastSource_Tags.Text: {
+					const inner = value.inner;
+					let size = ordinalConverter.allocationSize(2);
+					size += FfiConverterString.allocationSize(inner.contents);
+					return size;
+				}
+				default:
+					throw new UniffiInternalError.UnexpectedEnumCase();
+			}
+		}
+	}
+	return new FFIConverter();
+})();
+
+/**
+ * Transport compression (zlib@openssh.com / zlib). Worth its CPU cost on slow
+ * links; on a fast LAN it mostly isn't.
+ */
+export enum CompressionPreference {
+	Off,
+	Prefer,
+	Require,
+}
+
+const FfiConverterTypeCompressionPreference = (() => {
+	const ordinalConverter = FfiConverterInt32;
+	type TypeName = CompressionPreference;
+	class FFIConverter extends AbstractFfiConverterByteArray<TypeName> {
+		read(from: RustBuffer): TypeName {
+			switch (ordinalConverter.read(from)) {
+				case 1:
+					return CompressionPreference.Off;
+				case 2:
+					return CompressionPreference.Prefer;
+				case 3:
+					return CompressionPreference.Require;
+				default:
+					throw new UniffiInternalError.UnexpectedEnumCase();
+			}
+		}
+		write(value: TypeName, into: RustBuffer): void {
+			switch (value) {
+				case CompressionPreference.Off:
+					return ordinalConverter.write(1, into);
+				case CompressionPreference.Prefer:
+					return ordinalConverter.write(2, into);
+				case CompressionPreference.Require:
+					return ordinalConverter.write(3, into);
+			}
+		}
+		allocationSize(value: TypeName): number {
+			return ordinalConverter.allocationSize(0);
+		}
+	}
+	return new FFIConverter();
+})();
+
+// Enum: Cursor
+export enum Cursor_Tags {
+	Head = 'Head',
+	TailBytes = 'TailBytes',
+	Seq = 'Seq',
+	TimeMs = 'TimeMs',
+	Live = 'Live',
+	TailLines = 'TailLines',
+	Line = 'Line',
+}
+export const Cursor = (() => {
+	type Head__interface = {
+		tag: Cursor_Tags.Head;
+	};
+
+	class Head_ extends UniffiEnum implements Head__interface {
+		/**
+		 * @private
+		 * This field is private and should not be used, use `tag` instead.
+		 */
+		readonly [uniffiTypeNameSymbol] = 'Cursor';
+		readonly tag = Cursor_Tags.Head;
+		constructor() {
+			super('Cursor', 'Head');
+		}
+
+		static new(): Head_ {
+			return new Head_();
+		}
+
+		static instanceOf(obj: any): obj is Head_ {
+			return obj.tag === Cursor_Tags.Head;
+		}
+	}
+
+	type TailBytes__interface = {
+		tag: Cursor_Tags.TailBytes;
+		inner: Readonly<{ bytes: /*u64*/ bigint }>;
+	};
+
+	class TailBytes_ extends UniffiEnum implements TailBytes__interface {
+		/**
+		 * @private
+		 * This field is private and should not be used, use `tag` instead.
+		 */
+		readonly [uniffiTypeNameSymbol] = 'Cursor';
+		readonly tag = Cursor_Tags.TailBytes;
+		readonly inner: Readonly<{ bytes: /*u64*/ bigint }>;
+		constructor(inner: { bytes: /*u64*/ bigint }) {
+			super('Cursor', 'TailBytes');
+			this.inner = Object.freeze(inner);
+		}
+
+		static new(inner: { bytes: /*u64*/ bigint }): TailBytes_ {
+			return new TailBytes_(inner);
+		}
+
+		static instanceOf(obj: any): obj is TailBytes_ {
+			return obj.tag === Cursor_Tags.TailBytes;
+		}
+	}
+
+	type Seq__interface = {
+		tag: Cursor_Tags.Seq;
+		inner: Readonly<{ seq: /*u64*/ bigint }>;
+	};
+
+	class Seq_ extends UniffiEnum implements Seq__interface {
+		/**
+		 * @private
+		 * This field is private and should not be used, use `tag` instead.
+		 */
+		readonly [uniffiTypeNameSymbol] = 'Cursor';
+		readonly tag = Cursor_Tags.Seq;
+		readonly inner: Readonly<{ seq: /*u64*/ bigint }>;
+		constructor(inner: { seq: /*u64*/ bigint }) {
+			super('Cursor', 'Seq');
+			this.inner = Object.freeze(inner);
+		}
+
+		static new(inner: { seq: /*u64*/ bigint }): Seq_ {
+			return new Seq_(inner);
+		}
+
+		static instanceOf(obj: any): obj is Seq_ {
+			return obj.tag === Cursor_Tags.Seq;
+		}
+	}
+
+	type TimeMs__interface = {
+		tag: Cursor_Tags.TimeMs;
+		inner: Readonly<{ tMs: /*f64*/ number }>;
+	};
+
+	class TimeMs_ extends UniffiEnum implements TimeMs__interface {
+		/**
+		 * @private
+		 * This field is private and should not be used, use `tag` instead.
+		 */
+		readonly [uniffiTypeNameSymbol] = 'Cursor';
+		readonly tag = Cursor_Tags.TimeMs;
+		readonly inner: Readonly<{ tMs: /*f64*/ number }>;
+		constructor(inner: { tMs: /*f64*/ number }) {
+			super('Cursor', 'TimeMs');
+			this.inner = Object.freeze(inner);
+		}
+
+		static new(inner: { tMs: /*f64*/ number }): TimeMs_ {
+			return new TimeMs_(inner);
+		}
+
+		static instanceOf(obj: any): obj is TimeMs_ {
+			return obj.tag === Cursor_Tags.TimeMs;
+		}
+	}
+
+	type Live__interface = {
+		tag: Cursor_Tags.Live;
+	};
+
+	class Live_ extends UniffiEnum implements Live__interface {
+		/**
+		 * @private
+		 * This field is private and should not be used, use `tag` instead.
+		 */
+		readonly [uniffiTypeNameSymbol] = 'Cursor';
+		readonly tag = Cursor_Tags.Live;
+		constructor() {
+			super('Cursor', 'Live');
+		}
+
+		static new(): Live_ {
+			return new Live_();
+		}
+
+		static instanceOf(obj: any): obj is Live_ {
+			return obj.tag === Cursor_Tags.Live;
+		}
+	}
+
+	type TailLines__interface = {
+		tag: Cursor_Tags.TailLines;
+		inner: Readonly<{ lines: /*u64*/ bigint }>;
+	};
+
+	class TailLines_ extends UniffiEnum implements TailLines__interface {
+		/**
+		 * @private
+		 * This field is private and should not be used, use `tag` instead.
+		 */
+		readonly [uniffiTypeNameSymbol] = 'Cursor';
+		readonly tag = Cursor_Tags.TailLines;
+		readonly inner: Readonly<{ lines: /*u64*/ bigint }>;
+		constructor(inner: { lines: /*u64*/ bigint }) {
+			super('Cursor', 'TailLines');
+			this.inner = Object.freeze(inner);
+		}
+
+		static new(inner: { lines: /*u64*/ bigint }): TailLines_ {
+			return new TailLines_(inner);
+		}
+
+		static instanceOf(obj: any): obj is TailLines_ {
+			return obj.tag === Cursor_Tags.TailLines;
+		}
+	}
+
+	type Line__interface = {
+		tag: Cursor_Tags.Line;
+		inner: Readonly<{ line: /*u64*/ bigint }>;
+	};
+
+	class Line_ extends UniffiEnum implements Line__interface {
+		/**
+		 * @private
+		 * This field is private and should not be used, use `tag` instead.
+		 */
+		readonly [uniffiTypeNameSymbol] = 'Cursor';
+		readonly tag = Cursor_Tags.Line;
+		readonly inner: Readonly<{ line: /*u64*/ bigint }>;
+		constructor(inner: { line: /*u64*/ bigint }) {
+			super('Cursor', 'Line');
+			this.inner = Object.freeze(inner);
+		}
+
+		static new(inner: { line: /*u64*/ bigint }): Line_ {
+			return new Line_(inner);
+		}
+
+		static instanceOf(obj: any): obj is Line_ {
+			return obj.tag === Cursor_Tags.Line;
+		}
+	}
+
+	function instanceOf(obj: any): obj is Cursor {
+		return obj[uniffiTypeNameSymbol] === 'Cursor';
+	}
+
+	return Object.freeze({
+		instanceOf,
+		Head: Head_,
+		TailBytes: TailBytes_,
+		Seq: Seq_,
+		TimeMs: TimeMs_,
+		Live: Live_,
+		TailLines: TailLines_,
+		Line: Line_,
+	});
+})();
+
+export type Cursor = InstanceType<
+	(typeof Cursor)[keyof Omit<typeof Cursor, 'instanceOf'>]
+>;
+
+// FfiConverter for enum Cursor
+const FfiConverterTypeCursor = (() => {
+	const ordinalConverter = FfiConverterInt32;
+	type TypeName = Cursor;
+	class FFIConverter extends AbstractFfiConverterByteArray<TypeName> {
+		read(from: RustBuffer): TypeName {
+			switch (ordinalConverter.read(from)) {
+				case 1:
+					return new Cursor.Head();
+				case 2:
+					return new Cursor.TailBytes({ bytes: FfiConverterUInt64.read(from) });
+				case 3:
+					return new Cursor.Seq({ seq: FfiConverterUInt64.read(from) });
+				case 4:
+					return new Cursor.TimeMs({ tMs: FfiConverterFloat64.read(from) });
+				case 5:
+					return new Cursor.Live();
+				case 6:
+					return new Cursor.TailLines({ lines: FfiConverterUInt64.read(from) });
+				case 7:
+					return new Cursor.Line({ line: FfiConverterUInt64.read(from) });
+				default:
+					throw new UniffiInternalError.UnexpectedEnumCase();
+			}
+		}
+		write(value: TypeName, into: RustBuffer): void {
+			switch (value.tag) {
+				case Cursor_Tags.Head: {
+					ordinalConverter.write(1, into);
+					return;
+				}
+				case Cursor_Tags.TailBytes: {
+					ordinalConverter.write(2, into);
+					const inner = value.inner;
+					FfiConverterUInt64.write(inner.bytes, into);
+					return;
+				}
+				case Cursor_Tags.Seq: {
+					ordinalConverter.write(3, into);
+					const inner = value.inner;
+					FfiConverterUInt64.write(inner.seq, into);
+					return;
+				}
+				case Cursor_Tags.TimeMs: {
+					ordinalConverter.write(4, into);
+					const inner = value.inner;
+					FfiConverterFloat64.write(inner.tMs, into);
+					return;
+				}
+				case Cursor_Tags.Live: {
+					ordinalConverter.write(5, into);
+					return;
+				}
+				case Cursor_Tags.TailLines: {
+					ordinalConverter.write(6, into);
+					const inner = value.inner;
+					FfiConverterUInt64.write(inner.lines, into);
+					return;
+				}
+				case Cursor_Tags.Line: {
+					ordinalConverter.write(7, into);
+					const inner = value.inner;
+					FfiConverterUInt64.write(inner.line, into);
+					return;
+				}
+				default:
+					// Throwing from here means that Cursor_Tags hasn't matched an ordinal.
+					throw new UniffiInternalError.UnexpectedEnumCase();
+			}
+		}
+		allocationSize(value: TypeName): number {
+			switch (value.tag) {
+				case Cursor_Tags.Head: {
+					return ordinalConverter.allocationSize(1);
+				}
+				case Cursor_Tags.TailBytes: {
+					const inner = value.inner;
+					let size = ordinalConverter.allocationSize(2);
+					size += FfiConverterUInt64.allocationSize(inner.bytes);
+					return size;
+				}
+				case Cursor_Tags.Seq: {
+					const inner = value.inner;
+					let size = ordinalConverter.allocationSize(3);
+					size += FfiConverterUInt64.allocationSize(inner.seq);
+					return size;
+				}
+				case Cursor_Tags.TimeMs: {
+					const inner = value.inner;
+					let size = ordinalConverter.allocationSize(4);
+					size += FfiConverterFloat64.allocationSize(inner.tMs);
+					return size;
+				}
+				case Cursor_Tags.Live: {
+					return ordinalConverter.allocationSize(5);
+				}
+				case Cursor_Tags.TailLines: {
+					const inner = value.inner;
+					let size = ordinalConverter.allocationSize(6);
+					size += FfiConverterUInt64.allocationSize(inner.lines);
+					return size;
+				}
+				case Cursor_Tags.Line: {
+					const inner = value.inner;
+					let size = ordinalConverter.allocationSize(7);
+					size += FfiConverterUInt64.allocationSize(inner.line);
+					return size;
+				}
+				default:
+					throw new UniffiInternalError.UnexpectedEnumCase();
+			}
+		}
+	}
+	return new FFIConverter();
+})();
+
+export enum CursorShape {
+	Block,
+	Underline,
+	Bar,
+}
+
+const FfiConverterTypeCursorShape = (() => {
+	const ordinalConverter = FfiConverterInt32;
+	type TypeName = CursorShape;
+	class FFIConverter extends AbstractFfiConverterByteArray<TypeName> {
+		read(from: RustBuffer): TypeName {
+			switch (ordinalConverter.read(from)) {
+				case 1:
+					return CursorShape.Block;
+				case 2:
+					return CursorShape.Underline;
+				case 3:
+					return CursorShape.Bar;
+				default:
+					throw new UniffiInternalError.UnexpectedEnumCase();
+			}
+		}
+		write(value: TypeName, into: RustBuffer): void {
+			switch (value) {
+				case CursorShape.Block:
+					return ordinalConverter.write(1, into);
+				case CursorShape.Underline:
+					return ordinalConverter.write(2, into);
+				case CursorShape.Bar:
+					return ordinalConverter.write(3, into);
+			}
+		}
+		allocationSize(value: TypeName): number {
+			return ordinalConverter.allocationSize(0);
+		}
+	}
+	return new FFIConverter();
+})();
+
+/**
+ * What text listeners get for bytes that aren't valid UTF-8.
+ */
+export enum InvalidUtf8 {
+	Replace,
+	Escape,
+}
+
+const FfiConverterTypeInvalidUtf8 = (() => {
+	const ordinalConverter = FfiConverterInt32;
+	type TypeName = InvalidUtf8;
+	class FFIConverter extends AbstractFfiConverterByteArray<TypeName> {
+		read(from: RustBuffer): TypeName {
+			switch (ordinalConverter.read(from)) {
+				case 1:
+					return InvalidUtf8.Replace;
+				case 2:
+					return InvalidUtf8.Escape;
+				default:
+					throw new UniffiInternalError.UnexpectedEnumCase();
+			}
+		}
+		write(value: TypeName, into: RustBuffer): void {
+			switch (value) {
+				case InvalidUtf8.Replace:
+					return ordinalConverter.write(1, into);
+				case InvalidUtf8.Escape:
+					return ordinalConverter.write(2, into);
+			}
+		}
+		allocationSize(value: TypeName): number {
+			return ordinalConverter.allocationSize(0);
+		}
+	}
+	return new FFIConverter();
+})();
+
+export enum KeyType {
+	Rsa,
+	Ecdsa,
+	Ed25519,
+	Ed448,
+}
+
+const FfiConverterTypeKeyType = (() => {
+	const ordinalConverter = FfiConverterInt32;
+	type TypeName = KeyType;
+	class FFIConverter extends AbstractFfiConverterByteArray<TypeName> {
+		read(from: RustBuffer): TypeName {
+			switch (ordinalConverter.read(from)) {
+				case 1:
+					return KeyType.Rsa;
+				case 2:
+					return KeyType.Ecdsa;
+				case 3:
+					return KeyType.Ed25519;
+				case 4:
+					return KeyType.Ed448;
+				default:
+					throw new UniffiInternalError.UnexpectedEnumCase();
+			}
+		}
+		write(value: TypeName, into: RustBuffer): void {
+			switch (value) {
+				case KeyType.Rsa:
+					return ordinalConverter.write(1, into);
+				case KeyType.Ecdsa:
+					return ordinalConverter.write(2, into);
+				case KeyType.Ed25519:
+					return ordinalConverter.write(3, into);
+				case KeyType.Ed448:
+					return ordinalConverter.write(4, into);
+			}
+		}
+		allocationSize(value: TypeName): number {
+			return ordinalConverter.allocationSize(0);
+		}
+	}
+	return new FFIConverter();
+})();
+
+export enum MouseTracking {
+	Off,
+	X10,
+	Normal,
+	ButtonEvent,
+	AnyEvent,
+}
+
+const FfiConverterTypeMouseTracking = (() => {
+	const ordinalConverter = FfiConverterInt32;
+	type TypeName = MouseTracking;
+	class FFIConverter extends AbstractFfiConverterByteArray<TypeName> {
+		read(from: RustBuffer): TypeName {
+			switch (ordinalConverter.read(from)) {
+				case 1:
+					return MouseTracking.Off;
+				case 2:
+					return MouseTracking.X10;
+				case 3:
+					return MouseTracking.Normal;
+				case 4:
+					return MouseTracking.ButtonEvent;
+				case 5:
+					return MouseTracking.AnyEvent;
+				default:
+					throw new UniffiInternalError.UnexpectedEnumCase();
+			}
+		}
+		write(value: TypeName, into: RustBuffer): void {
+			switch (value) {
+				case MouseTracking.Off:
+					return ordinalConverter.write(1, into);
+				case MouseTracking.X10:
+					return ordinalConverter.write(2, into);
+				case MouseTracking.Normal:
+					return ordinalConverter.write(3, into);
+				case MouseTracking.ButtonEvent:
+					return ordinalConverter.write(4, into);
+				case MouseTracking.AnyEvent:
+					return ordinalConverter.write(5, into);
+			}
+		}
+		allocationSize(value: TypeName): number {
+			return ordinalConverter.allocationSize(0);
+		}
+	}
+	return new FFIConverter();
+})();
+
+// Enum: Security
+export enum Security_Tags {
+	Password = 'Password',
+	Key = 'Key',
+}
+export const Security = (() => {
+	type Password__interface = {
+		tag: Security_Tags.Password;
+		inner: Readonly<{ password: string }>;
+	};
+
+	class Password_ extends UniffiEnum implements Password__interface {
+		/**
+		 * @private
+		 * This field is private and should not be used, use `tag` instead.
+		 */
+		readonly [uniffiTypeNameSymbol] = 'Security';
+		readonly tag = Security_Tags.Password;
+		readonly inner: Readonly<{ password: string }>;
+		constructor(inner: { password: string }) {
+			super('Security', 'Password');
+			this.inner = Object.freeze(inner);
+		}
+
+		static new(inner: { password: string }): Password_ {
+			return new Password_(inner);
+		}
+
+		static instanceOf(obj: any): obj is Password_ {
+			return obj.tag === Security_Tags.Password;
+		}
+	}
+
+	type Key__interface = {
+		tag: Security_Tags.Key;
+		inner: Readonly<{ privateKeyContent: string }>;
+	};
+
+	class Key_ extends UniffiEnum implements Key__interface {
+		/**
+		 * @private
+		 * This field is private and should not be used, use `tag` instead.
+		 */
+		readonly [uniffiTypeNameSymbol] = 'Security';
+		readonly tag = Security_Tags.Key;
+		readonly inner: Readonly<{ privateKeyContent: string }>;
+		constructor(inner: { privateKeyContent: string }) {
+			super('Security', 'Key');
+			this.inner = Object.freeze(inner);
+		}
+
+		static new(inner: { privateKeyContent: string }): Key_ {
+			return new Key_(inner);
+		}
+
+		static instanceOf(obj: any): obj is Key_ {
+			return obj.tag === Security_Tags.Key;
+		}
+	}
+
+	function instanceOf(obj: any): obj is Security {
+		return obj[uniffiTypeNameSymbol] === 'Security';
+	}
+
+	return Object.freeze({
+		instanceOf,
+		Password: Password_,
+		Key: Key_,
+	});
+})();
+
+export type Security = InstanceType<
+	(typeof Security)[keyof Omit<typeof Security, 'instanceOf'>]
+>;
+
+// FfiConverter for enum Security
+const FfiConverterTypeSecurity = (() => {
+	const ordinalConverter = FfiConverterInt32;
+	type TypeName = Security;
+	class FFIConverter extends AbstractFfiConverterByteArray<TypeName> {
+		read(from: RustBuffer): TypeName {
+			switch (ordinalConverter.read(from)) {
+				case 1:
+					return new Security.Password({
+						password: FfiConverterString.read(from),
+					});
+				case 2:
+					return new Security.Key({
+						privateKeyContent: FfiConverterString.read(from),
+					});
+				default:
+					throw new UniffiInternalError.UnexpectedEnumCase();
+			}
+		}
+		write(value: TypeName, into: RustBuffer): void {
+			switch (value.tag) {
+				case Security_Tags.Password: {
+					ordinalConverter.write(1, into);
+					const inner = value.inner;
+					FfiConverterString.write(inner.password, into);
+					return;
+				}
+				case Security_Tags.Key: {
+					ordinalConverter.write(2, into);
+					const inner = value.inner;
+					FfiConverterString.write(inner.privateKeyContent, into);
+					return;
+				}
+				default:
+					// Throwing from here means that Security_Tags hasn't matched an ordinal.
+					throw new UniffiInternalError.UnexpectedEnumCase();
+			}
+		}
+		allocationSize(value: TypeName): number {
+			switch (value.tag) {
+				case Security_Tags.Password: {
+					const inner = value.inner;
+					let size = ordinalConverter.allocationSize(1);
+					size += FfiConverterString.allocationSize(inner.password);
+					return size;
+				}
+				case Security_Tags.Key: {
+					const inner = value.inner;
+					let size = ordinalConverter.allocationSize(2);
+					size += FfiConverterString.allocationSize(inner.privateKeyContent);
+					return size;
+				}
+				default:
+					throw new UniffiInternalError.UnexpectedEnumCase();
+			}
+		}
+	}
+	return new FFIConverter();
+})();
+
+// Enum: ShellCommand
+export enum ShellCommand_Tags {
+	Exec = 'Exec',
+	Subsystem = 'Subsystem',
+}
+/**
+ * What to run in the PTY instead of the login shell.
+ */
+export const ShellCommand = (() => {
+	type Exec__interface = {
+		tag: ShellCommand_Tags.Exec;
+		inner: Readonly<{ command: string }>;
+	};
+
+	class Exec_ extends UniffiEnum implements Exec__interface {
+		/**
+		 * @private
+		 * This field is private and should not be used, use `tag` instead.
+		 */
+		readonly [uniffiTypeNameSymbol] = 'ShellCommand';
+		readonly tag = ShellCommand_Tags.Exec;
+		readonly inner: Readonly<{ command: string }>;
+		constructor(inner: { command: string }) {
+			super('ShellCommand', 'Exec');
+			this.inner = Object.freeze(inner);
+		}
+
+		static new(inner: { command: string }): Exec_ {
+			return new Exec_(inner);
+		}
+
+		static instanceOf(obj: any): obj is Exec_ {
+			return obj.tag === ShellCommand_Tags.Exec;
+		}
+	}
+
+	type Subsystem__interface = {
+		tag: ShellCommand_Tags.Subsystem;
+		inner: Readonly<{ name: string }>;
+	};
+
+	class Subsystem_ extends UniffiEnum implements Subsystem__interface {
+		/**
+		 * @private
+		 * This field is private and should not be used, use `tag` instead.
+		 */
+		readonly [uniffiTypeNameSymbol] = 'ShellCommand';
+		readonly tag = ShellCommand_Tags.Subsystem;
+		readonly inner: Readonly<{ name: string }>;
+		constructor(inner: { name: string }) {
+			super('ShellCommand', 'Subsystem');
+			this.inner = Object.freeze(inner);
+		}
+
+		static new(inner: { name: string }): Subsystem_ {
+			return new Subsystem_(inner);
+		}
+
+		static instanceOf(obj: any): obj is Subsystem_ {
+			return obj.tag === ShellCommand_Tags.Subsystem;
+		}
+	}
+
+	function instanceOf(obj: any): obj is ShellCommand {
+		return obj[uniffiTypeNameSymbol] === 'ShellCommand';
+	}
+
+	return Object.freeze({
+		instanceOf,
+		Exec: Exec_,
+		Subsystem: Subsystem_,
+	});
+})();
+
+export type ShellCommand = InstanceType<
+	(typeof ShellCommand)[keyof Omit<typeof ShellCommand, 'instanceOf'>]
+>;
+
+// FfiConverter for enum ShellCommand
+const FfiConverterTypeShellCommand = (() => {
+	const ordinalConverter = FfiConverterInt32;
+	type TypeName = ShellCommand;
+	class FFIConverter extends AbstractFfiConverterByteArray<TypeName> {
+		read(from: RustBuffer): TypeName {
+			switch (ordinalConverter.read(from)) {
+				case 1:
+					return new ShellCommand.Exec({
+						command: FfiConverterString.read(from),
+					});
+				case 2:
+					return new ShellCommand.Subsystem({
+						name: FfiConverterString.read(from),
+					});
+				default:
+					throw new UniffiInternalError.UnexpectedEnumCase();
+			}
+		}
+		write(value: TypeName, into: RustBuffer): void {
+			switch (value.tag) {
+				case ShellCommand_Tags.Exec: {
+					ordinalConverter.write(1, into);
+					const inner = value.inner;
+					FfiConverterString.write(inner.command, into);
+					return;
+				}
+				case ShellCommand_Tags.Subsystem: {
+					ordinalConverter.write(2, into);
+					const inner = value.inner;
+					FfiConverterString.write(inner.name, into);
+					return;
+				}
+				default:
+					// Throwing from here means that ShellCommand_Tags hasn't matched an ordinal.
+					throw new UniffiInternalError.UnexpectedEnumCase();
+			}
+		}
+		allocationSize(value: TypeName): number {
+			switch (value.tag) {
+				case ShellCommand_Tags.Exec: {
+					const inner = value.inner;
+					let size = ordinalConverter.allocationSize(1);
+					size += FfiConverterString.allocationSize(inner.command);
+					return size;
+				}
+				case ShellCommand_Tags.Subsystem: {
+					const inner = value.inner;
+					let size = ordinalConverter.allocationSize(2);
+					size += FfiConverterString.allocationSize(inner.name);
+					return size;
+				}
+				default:
+					throw new UniffiInternalError.UnexpectedEnumCase();
+			}
+		}
+	}
+	return new FFIConverter();
+})();
+
+// Enum: ShellEvent
+export enum ShellEvent_Tags {
+	Chunk = 'Chunk',
+	Text = 'Text',
+	Dropped = 'Dropped',
+	Command = 'Command',
+}
+export const ShellEvent = (() => {
+	type Chunk__interface = {
+		tag: ShellEvent_Tags.Chunk;
+		inner: Readonly<[TerminalChunk]>;
+	};
+
+	class Chunk_ extends UniffiEnum implements Chunk__interface {
+		/**
+		 * @private
+		 * This field is private and should not be used, use `tag` instead.
+		 */
+		readonly [uniffiTypeNameSymbol] = 'ShellEvent';
+		readonly tag = ShellEvent_Tags.Chunk;
+		readonly inner: Readonly<[TerminalChunk]>;
+		constructor(v0: TerminalChunk) {
+			super('ShellEvent', 'Chunk');
+			this.inner = Object.freeze([v0]);
+		}
+
+		static new(v0: TerminalChunk): Chunk_ {
+			return new Chunk_(v0);
+		}
+
+		static instanceOf(obj: any): obj is Chunk_ {
+			return obj.tag === ShellEvent_Tags.Chunk;
+		}
+	}
+
+	type Text__interface = {
+		tag: ShellEvent_Tags.Text;
+		inner: Readonly<[TerminalText]>;
+	};
+
+	class Text_ extends UniffiEnum implements Text__interface {
+		/**
+		 * @private
+		 * This field is private and should not be used, use `tag` instead.
+		 */
+		readonly [uniffiTypeNameSymbol] = 'ShellEvent';
+		readonly tag = ShellEvent_Tags.Text;
+		readonly inner: Readonly<[TerminalText]>;
+		constructor(v0: TerminalText) {
+			super('ShellEvent', 'Text');
+			this.inner = Object.freeze([v0]);
+		}
+
+		static new(v0: TerminalText): Text_ {
+			return new Text_(v0);
+		}
+
+		static instanceOf(obj: any): obj is Text_ {
+			return obj.tag === ShellEvent_Tags.Text;
+		}
+	}
+
+	type Dropped__interface = {
+		tag: ShellEvent_Tags.Dropped;
+		inner: Readonly<{ fromSeq: /*u64*/ bigint; toSeq: /*u64*/ bigint }>;
+	};
+
+	class Dropped_ extends UniffiEnum implements Dropped__interface {
+		/**
+		 * @private
+		 * This field is private and should not be used, use `tag` instead.
+		 */
+		readonly [uniffiTypeNameSymbol] = 'ShellEvent';
+		readonly tag = ShellEvent_Tags.Dropped;
+		readonly inner: Readonly<{
+			fromSeq: /*u64*/ bigint;
+			toSeq: /*u64*/ bigint;
+		}>;
+		constructor(inner: { fromSeq: /*u64*/ bigint; toSeq: /*u64*/ bigint }) {
+			super('ShellEvent', 'Dropped');
+			this.inner = Object.freeze(inner);
+		}
+
+		static new(inner: {
+			fromSeq: /*u64*/ bigint;
+			toSeq: /*u64*/ bigint;
+		}): Dropped_ {
+			return new Dropped_(inner);
+		}
+
+		static instanceOf(obj: any): obj is Dropped_ {
+			return obj.tag === ShellEvent_Tags.Dropped;
+		}
+	}
+
+	type Command__interface = {
+		tag: ShellEvent_Tags.Command;
+		inner: Readonly<[CommandRecord]>;
+	};
+
+	class Command_ extends UniffiEnum implements Command__interface {
+		/**
+		 * @private
+		 * This field is private and should not be used, use `tag` instead.
+		 */
+		readonly [uniffiTypeNameSymbol] = 'ShellEvent';
+		readonly tag = ShellEvent_Tags.Command;
+		readonly inner: Readonly<[CommandRecord]>;
+		constructor(v0: CommandRecord) {
+			super('ShellEvent', 'Command');
+			this.inner = Object.freeze([v0]);
+		}
+
+		static new(v0: CommandRecord): Command_ {
+			return new Command_(v0);
+		}
+
+		static instanceOf(obj: any): obj is Command_ {
+			return obj.tag === ShellEvent_Tags.Command;
+		}
+	}
+
+	function instanceOf(obj: any): obj is ShellEvent {
+		return obj[uniffiTypeNameSymbol] === 'ShellEvent';
+	}
+
+	return Object.freeze({
+		instanceOf,
+		Chunk: Chunk_,
+		Text: Text_,
+		Dropped: Dropped_,
+		Command: Command_,
+	});
+})();
+
+export type ShellEvent = InstanceType<
+	(typeof ShellEvent)[keyof Omit<typeof ShellEvent, 'instanceOf'>]
+>;
+
+// FfiConverter for enum ShellEvent
+const FfiConverterTypeShellEvent = (() => {
+	const ordinalConverter = FfiConverterInt32;
+	type TypeName = ShellEvent;
+	class FFIConverter extends AbstractFfiConverterByteArray<TypeName> {
+		read(from: RustBuffer): TypeName {
+			switch (ordinalConverter.read(from)) {
+				case 1:
+					return new ShellEvent.Chunk(FfiConverterTypeTerminalChunk.read(from));
+				case 2:
+					return new ShellEvent.Text(FfiConverterTypeTerminalText.read(from));
+				case 3:
+					return new ShellEvent.Dropped({
+						fromSeq: FfiConverterUInt64.read(from),
+						toSeq: FfiConverterUInt64.read(from),
+					});
+				case 4:
+					return new ShellEvent.Command(
+						FfiConverterTypeCommandRecord.read(from),
+					);
+				default:
+					throw new UniffiInternalError.UnexpectedEnumCase();
+			}
+		}
+		write(value: TypeName, into: RustBuffer): void {
+			switch (value.tag) {
+				case ShellEvent_Tags.Chunk: {
+					ordinalConverter.write(1, into);
+					const inner = value.inner;
+					FfiConverterTypeTerminalChunk.write(inner[0], into);
+					return;
+				}
+				case ShellEvent_Tags.Text: {
+					ordinalConverter.write(2, into);
+					const inner = value.inner;
+					FfiConverterTypeTerminalText.write(inner[0], into);
+					return;
+				}
+				case ShellEvent_Tags.Dropped: {
+					ordinalConverter.write(3, into);
+					const inner = value.inner;
+					FfiConverterUInt64.write(inner.fromSeq, into);
+					FfiConverterUInt64.write(inner.toSeq, into);
+					return;
+				}
+				case ShellEvent_Tags.Command: {
+					ordinalConverter.write(4, into);
+					const inner = value.inner;
+					FfiConverterTypeCommandRecord.write(inner[0], into);
+					return;
+				}
+				default:
+					// Throwing from here means that ShellEvent_Tags hasn't matched an ordinal.
+					throw new UniffiInternalError.UnexpectedEnumCase();
+			}
+		}
+		allocationSize(value: TypeName): number {
+			switch (value.tag) {
+				case ShellEvent_Tags.Chunk: {
+					const inner = value.inner;
+					let size = ordinalConverter.allocationSize(1);
+					size += FfiConverterTypeTerminalChunk.allocationSize(inner[0]);
+					return size;
+				}
+				case ShellEvent_Tags.Text: {
+					const inner = value.inner;
+					let size = ordinalConverter.allocationSize(2);
+					size += FfiConverterTypeTerminalText.allocationSize(inner[0]);
+					return size;
+				}
+				case ShellEvent_Tags.Dropped: {
+					const inner = value.inner;
+					let size = ordinalConverter.allocationSize(3);
+					size += FfiConverterUInt64.allocationSize(inner.fromSeq);
+					size += FfiConverterUInt64.allocationSize(inner.toSeq);
+					return size;
+				}
+				case ShellEvent_Tags.Command: {
+					const inner = value.inner;
+					let size = ordinalConverter.allocationSize(4);
+					size += FfiConverterTypeCommandRecord.allocationSize(inner[0]);
+					return size;
+				}
+				default:
+					throw new UniffiInternalError.UnexpectedEnumCase();
+			}
+		}
+	}
+	return new FFIConverter();
+})();
+
+/**
+ * Signals deliverable with the SSH `signal` channel request (RFC 4254 §6.9).
+ */
+export enum ShellSignal {
+	Abrt,
+	Alrm,
+	Fpe,
+	Hup,
+	Ill,
+	Int,
+	Kill,
+	Pipe,
+	Quit,
+	Segv,
+	Term,
+	Usr1,
+	Usr2,
+}
+
+const FfiConverterTypeShellSignal = (() => {
+	const ordinalConverter = FfiConverterInt32;
+	type TypeName = ShellSignal;
+	class FFIConverter extends AbstractFfiConverterByteArray<TypeName> {
+		read(from: RustBuffer): TypeName {
+			switch (ordinalConverter.read(from)) {
+				case 1:
+					return ShellSignal.Abrt;
+				case 2:
+					return ShellSignal.Alrm;
+				case 3:
+					return ShellSignal.Fpe;
+				case 4:
+					return ShellSignal.Hup;
+				case 5:
+					return ShellSignal.Ill;
+				case 6:
+					return ShellSignal.Int;
+				case 7:
+					return ShellSignal.Kill;
+				case 8:
+					return ShellSignal.Pipe;
+				case 9:
+					return ShellSignal.Quit;
+				case 10:
+					return ShellSignal.Segv;
+				case 11:
+					return ShellSignal.Term;
+				case 12:
+					return ShellSignal.Usr1;
+				case 13:
+					return ShellSignal.Usr2;
+				default:
+					throw new UniffiInternalError.UnexpectedEnumCase();
+			}
+		}
+		write(value: TypeName, into: RustBuffer): void {
+			switch (value) {
+				case ShellSignal.Abrt:
+					return ordinalConverter.write(1, into);
+				case ShellSignal.Alrm:
+					return ordinalConverter.write(2, into);
+				case ShellSignal.Fpe:
+					return ordinalConverter.write(3, into);
+				case ShellSignal.Hup:
+					return ordinalConverter.write(4, into);
+				case ShellSignal.Ill:
+					return ordinalConverter.write(5, into);
+				case ShellSignal.Int:
+					return ordinalConverter.write(6, into);
+				case ShellSignal.Kill:
+					return ordinalConverter.write(7, into);
+				case ShellSignal.Pipe:
+					return ordinalConverter.write(8, into);
+				case ShellSignal.Quit:
+					return ordinalConverter.write(9, into);
+				case ShellSignal.Segv:
+					return ordinalConverter.write(10, into);
+				case ShellSignal.Term:
+					return ordinalConverter.write(11, into);
+				case ShellSignal.Usr1:
+					return ordinalConverter.write(12, into);
+				case ShellSignal.Usr2:
+					return ordinalConverter.write(13, into);
+			}
+		}
+		allocationSize(value: TypeName): number {
+			return ordinalConverter.allocationSize(0);
+		}
+	}
+	return new FFIConverter();
+})();
+
+// Enum: SshConnectionEvent
+export enum SshConnectionEvent_Tags {
+	Rekeyed = 'Rekeyed',
+}
+/**
+ * Events that happen on an established connection.
+ */
+export const SshConnectionEvent = (() => {
+	type Rekeyed__interface = {
+		tag: SshConnectionEvent_Tags.Rekeyed;
+		inner: Readonly<{
+			rekeyCount: /*u32*/ number;
+			atMs: /*f64*/ number;
+			algorithms: NegotiatedAlgorithms;
+		}>;
+	};
+
+	class Rekeyed_ extends UniffiEnum implements Rekeyed__interface {
+		/**
+		 * @private
+		 * This field is private and should not be used, use `tag` instead.
+		 */
+		readonly [uniffiTypeNameSymbol] = 'SshConnectionEvent';
+		readonly tag = SshConnectionEvent_Tags.Rekeyed;
+		readonly inner: Readonly<{
+			rekeyCount: /*u32*/ number;
+			atMs: /*f64*/ number;
+			algorithms: NegotiatedAlgorithms;
+		}>;
+		constructor(inner: {
+			rekeyCount: /*u32*/ number;
+			atMs: /*f64*/ number;
+			algorithms: NegotiatedAlgorithms;
+		}) {
+			super('SshConnectionEvent', 'Rekeyed');
+			this.inner = Object.freeze(inner);
+		}
+
+		static new(inner: {
+			rekeyCount: /*u32*/ number;
+			atMs: /*f64*/ number;
+			algorithms: NegotiatedAlgorithms;
+		}): Rekeyed_ {
+			return new Rekeyed_(inner);
+		}
+
+		static instanceOf(obj: any): obj is Rekeyed_ {
+			return obj.tag === SshConnectionEvent_Tags.Rekeyed;
+		}
+	}
+
+	function instanceOf(obj: any): obj is SshConnectionEvent {
+		return obj[uniffiTypeNameSymbol] === 'SshConnectionEvent';
+	}
+
+	return Object.freeze({
+		instanceOf,
+		Rekeyed: Rekeyed_,
+	});
+})();
+
+export type SshConnectionEvent = InstanceType<
+	(typeof SshConnectionEvent)[keyof Omit<
+		typeof SshConnectionEvent,
+		'instanceOf'
+	>]
+>;
+
+// FfiConverter for enum SshConnectionEvent
+const FfiConverterTypeSshConnectionEvent = (() => {
+	const ordinalConverter = FfiConverterInt32;
+	type TypeName = SshConnectionEvent;
+	class FFIConverter extends AbstractFfiConverterByteArray<TypeName> {
+		read(from: RustBuffer): TypeName {
+			switch (ordinalConverter.read(from)) {
+				case 1:
+					return new SshConnectionEvent.Rekeyed({
+						rekeyCount: FfiConverterUInt32.read(from),
+						atMs: FfiConverterFloat64.read(from),
+						algorithms: FfiConverterTypeNegotiatedAlgorithms.read(from),
+					});
+				default:
+					throw new UniffiInternalError.UnexpectedEnumCase();
+			}
+		}
+		write(value: TypeName, into: RustBuffer): void {
+			switch (value.tag) {
+				case SshConnectionEvent_Tags.Rekeyed: {
+					ordinalConverter.write(1, into);
+					const inner = value.inner;
+					FfiConverterUInt32.write(inner.rekeyCount, into);
+					FfiConverterFloat64.write(inner.atMs, into);
+					FfiConverterTypeNegotiatedAlgorithms.write(inner.algorithms, into);
+					return;
+				}
+				default:
+					// Throwing from here means that SshConnectionEvent_Tags hasn't matched an ordinal.
+					throw new UniffiInternalError.UnexpectedEnumCase();
+			}
+		}
+		allocationSize(value: TypeName): number {
+			switch (value.tag) {
+				case SshConnectionEvent_Tags.Rekeyed: {
+					const inner = value.inner;
+					let size = ordinalConverter.allocationSize(1);
+					size += FfiConverterUInt32.allocationSize(inner.rekeyCount);
+					size += FfiConverterFloat64.allocationSize(inner.atMs);
+					size += FfiConverterTypeNegotiatedAlgorithms.allocationSize(
+						inner.algorithms,
+					);
+					return size;
+				}
+				default:
+					throw new UniffiInternalError.UnexpectedEnumCase();
+			}
+		}
+	}
+	return new FFIConverter();
+})();
+
+// Enum: SshConnectionProgressEvent
+export enum SshConnectionProgressEvent_Tags {
+	DnsResolved = 'DnsResolved',
+	TcpConnected = 'TcpConnected',
+	HostKeyVerified = 'HostKeyVerified',
+	SshHandshake = 'SshHandshake',
+	Authenticating = 'Authenticating',
+	AuthAttempt = 'AuthAttempt',
+	Authenticated = 'Authenticated',
+	Connected = 'Connected',
+}
+export const SshConnectionProgressEvent = (() => {
+	type DnsResolved__interface = {
+		tag: SshConnectionProgressEvent_Tags.DnsResolved;
+		inner: Readonly<{ candidates: Array<string> }>;
+	};
+
+	class DnsResolved_ extends UniffiEnum implements DnsResolved__interface {
+		/**
+		 * @private
+		 * This field is private and should not be used, use `tag` instead.
+		 */
+		readonly [uniffiTypeNameSymbol] = 'SshConnectionProgressEvent';
+		readonly tag = SshConnectionProgressEvent_Tags.DnsResolved;
+		readonly inner: Readonly<{ candidates: Array<string> }>;
+		constructor(inner: { candidates: Array<string> }) {
+			super('SshConnectionProgressEvent', 'DnsResolved');
+			this.inner = Object.freeze(inner);
+		}
+
+		static new(inner: { candidates: Array<string> }): DnsResolved_ {
+			return new DnsResolved_(inner);
+		}
+
+		static instanceOf(obj: any): obj is DnsResolved_ {
+			return obj.tag === SshConnectionProgressEvent_Tags.DnsResolved;
+		}
+	}
+
+	type TcpConnected__interface = {
+		tag: SshConnectionProgressEvent_Tags.TcpConnected;
+		inner: Readonly<{ chosen: string }>;
+	};
+
+	class TcpConnected_ extends UniffiEnum implements TcpConnected__interface {
+		/**
+		 * @private
+		 * This field is private and should not be used, use `tag` instead.
+		 */
+		readonly [uniffiTypeNameSymbol] = 'SshConnectionProgressEvent';
+		readonly tag = SshConnectionProgressEvent_Tags.TcpConnected;
+		readonly inner: Readonly<{ chosen: string }>;
+		constructor(inner: { chosen: string }) {
+			super('SshConnectionProgressEvent', 'TcpConnected');
+			this.inner = Object.freeze(inner);
+		}
+
+		static new(inner: { chosen: string }): TcpConnected_ {
+			return new TcpConnected_(inner);
+		}
+
+		static instanceOf(obj: any): obj is TcpConnected_ {
+			return obj.tag === SshConnectionProgressEvent_Tags.TcpConnected;
+		}
+	}
+
+	type HostKeyVerified__interface = {
+		tag: SshConnectionProgressEvent_Tags.HostKeyVerified;
+	};
+
+	class HostKeyVerified_
+		extends UniffiEnum
+		implements HostKeyVerified__interface
+	{
+		/**
+		 * @private
+		 * This field is private and should not be used, use `tag` instead.
+		 */
+		readonly [uniffiTypeNameSymbol] = 'SshConnectionProgressEvent';
+		readonly tag = SshConnectionProgressEvent_Tags.HostKeyVerified;
+		constructor() {
+			super('SshConnectionProgressEvent', 'HostKeyVerified');
+		}
+
+		static new(): HostKeyVerified_ {
+			return new HostKeyVerified_();
+		}
+
+		static instanceOf(obj: any): obj is HostKeyVerified_ {
+			return obj.tag === SshConnectionProgressEvent_Tags.HostKeyVerified;
+		}
+	}
+
+	type SshHandshake__interface = {
+		tag: SshConnectionProgressEvent_Tags.SshHandshake;
+	};
+
+	class SshHandshake_ extends UniffiEnum implements SshHandshake__interface {
+		/**
+		 * @private
+		 * This field is private and should not be used, use `tag` instead.
+		 */
+		readonly [uniffiTypeNameSymbol] = 'SshConnectionProgressEvent';
+		readonly tag = SshConnectionProgressEvent_Tags.SshHandshake;
+		constructor() {
+			super('SshConnectionProgressEvent', 'SshHandshake');
+		}
+
+		static new(): SshHandshake_ {
+			return new SshHandshake_();
+		}
+
+		static instanceOf(obj: any): obj is SshHandshake_ {
+			return obj.tag === SshConnectionProgressEvent_Tags.SshHandshake;
+		}
+	}
+
+	type Authenticating__interface = {
+		tag: SshConnectionProgressEvent_Tags.Authenticating;
+	};
+
+	class Authenticating_
+		extends UniffiEnum
+		implements Authenticating__interface
+	{
+		/**
+		 * @private
+		 * This field is private and should not be used, use `tag` instead.
+		 */
+		readonly [uniffiTypeNameSymbol] = 'SshConnectionProgressEvent';
+		readonly tag = SshConnectionProgressEvent_Tags.Authenticating;
+		constructor() {
+			super('SshConnectionProgressEvent', 'Authenticating');
+		}
+
+		static new(): Authenticating_ {
+			return new Authenticating_();
+		}
+
+		static instanceOf(obj: any): obj is Authenticating_ {
+			return obj.tag === SshConnectionProgressEvent_Tags.Authenticating;
+		}
+	}
+
+	type AuthAttempt__interface = {
+		tag: SshConnectionProgressEvent_Tags.AuthAttempt;
+		inner: Readonly<{ method: string }>;
+	};
+
+	class AuthAttempt_ extends UniffiEnum implements AuthAttempt__interface {
+		/**
+		 * @private
+		 * This field is private and should not be used, use `tag` instead.
+		 */
+		readonly [uniffiTypeNameSymbol] = 'SshConnectionProgressEvent';
+		readonly tag = SshConnectionProgressEvent_Tags.AuthAttempt;
+		readonly inner: Readonly<{ method: string }>;
+		constructor(inner: { method: string }) {
+			super('SshConnectionProgressEvent', 'AuthAttempt');
+			this.inner = Object.freeze(inner);
+		}
+
+		static new(inner: { method: string }): AuthAttempt_ {
+			return new AuthAttempt_(inner);
+		}
+
+		static instanceOf(obj: any): obj is AuthAttempt_ {
+			return obj.tag === SshConnectionProgressEvent_Tags.AuthAttempt;
+		}
+	}
+
+	type Authenticated__interface = {
+		tag: SshConnectionProgressEvent_Tags.Authenticated;
+	};
+
+	class Authenticated_ extends UniffiEnum implements Authenticated__interface {
+		/**
+		 * @private
+		 * This field is private and should not be used, use `tag` instead.
+		 */
+		readonly [uniffiTypeNameSymbol] = 'SshConnectionProgressEvent';
+		readonly tag = SshConnectionProgressEvent_Tags.Authenticated;
+		constructor() {
+			super('SshConnectionProgressEvent', 'Authenticated');
+		}
+
+		static new(): Authenticated_ {
+			return new Authenticated_();
+		}
+
+		static instanceOf(obj: any): obj is Authenticated_ {
+			return obj.tag === SshConnectionProgressEvent_Tags.Authenticated;
+		}
+	}
+
+	type Connected__interface = {
+		tag: SshConnectionProgressEvent_Tags.Connected;
+	};
+
+	class Connected_ extends UniffiEnum implements Connected__interface {
+		/**
+		 * @private
+		 * This field is private and should not be used, use `tag` instead.
+		 */
+		readonly [uniffiTypeNameSymbol] = 'SshConnectionProgressEvent';
+		readonly tag = SshConnectionProgressEvent_Tags.Connected;
+		constructor() {
+			super('SshConnectionProgressEvent', 'Connected');
+		}
+
+		static new(): Connected_ {
+			return new Connected_();
+		}
+
+		static instanceOf(obj: any): obj is Connected_ {
+			return obj.tag === SshConnectionProgressEvent_Tags.Connected;
+		}
+	}
+
+	function instanceOf(obj: any): obj is SshConnectionProgressEvent {
+		return obj[uniffiTypeNameSymbol] === 'SshConnectionProgressEvent';
+	}
+
+	return Object.freeze({
+		instanceOf,
+		DnsResolved: DnsResolved_,
+		TcpConnected: TcpConnected_,
+		HostKeyVerified: HostKeyVerified_,
+		SshHandshake: SshHandshake_,
+		Authenticating: Authenticating_,
+		AuthAttempt: AuthAttempt_,
+		Authenticated: Authenticated_,
+		Connected: Connected_,
+	});
+})();
+
+export type SshConnectionProgressEvent = InstanceType<
+	(typeof SshConnectionProgressEvent)[keyof Omit<
+		typeof SshConnectionProgressEvent,
+		'instanceOf'
+	>]
+>;
+
+// FfiConverter for enum SshConnectionProgressEvent
+const FfiConverterTypeSshConnectionProgressEvent = (() => {
+	const ordinalConverter = FfiConverterInt32;
+	type TypeName = SshConnectionProgressEvent;
+	class FFIConverter extends AbstractFfiConverterByteArray<TypeName> {
+		read(from: RustBuffer): TypeName {
+			switch (ordinalConverter.read(from)) {
+				case 1:
+					return new SshConnectionProgressEvent.DnsResolved({
+						candidates: FfiConverterArrayString.read(from),
+					});
+				case 2:
+					return new SshConnectionProgressEvent.TcpConnected({
+						chosen: FfiConverterString.read(from),
+					});
+				case 3:
+					return new SshConnectionProgressEvent.HostKeyVerified();
+				case 4:
+					return new SshConnectionProgressEvent.SshHandshake();
+				case 5:
+					return new SshConnectionProgressEvent.Authenticating();
+				case 6:
+					return new SshConnectionProgressEvent.AuthAttempt({
+						method: FfiConverterString.read(from),
+					});
+				case 7:
+					return new SshConnectionProgressEvent.Authenticated();
+				case 8:
+					return new SshConnectionProgressEvent.Connected();
+				default:
+					throw new UniffiInternalError.UnexpectedEnumCase();
+			}
+		}
+		write(value: TypeName, into: RustBuffer): void {
+			switch (value.tag) {
+				case SshConnectionProgressEvent_Tags.DnsResolved: {
+					ordinalConverter.write(1, into);
+					const inner = value.inner;
+					FfiConverterArrayString.write(inner.candidates, into);
+					return;
+				}
+				case SshConnectionProgressEvent_Tags.TcpConnected: {
+					ordinalConverter.write(2, into);
+					const inner = value.inner;
+					FfiConverterString.write(inner.chosen, into);
+					return;
+				}
+				case SshConnectionProgressEvent_Tags.HostKeyVerified: {
+					ordinalConverter.write(3, into);
+					return;
+				}
+				case SshConnectionProgressEvent_Tags.SshHandshake: {
+					ordinalConverter.write(4, into);
+					return;
+				}
+				case SshConnectionProgressEvent_Tags.Authenticating: {
+					ordinalConverter.write(5, into);
+					return;
+				}
+				case SshConnectionProgressEvent_Tags.AuthAttempt: {
+					ordinalConverter.write(6, into);
+					const inner = value.inner;
+					FfiConverterString.write(inner.method, into);
+					return;
+				}
+				case SshConnectionProgressEvent_Tags.Authenticated: {
+					ordinalConverter.write(7, into);
+					return;
+				}
+				case SshConnectionProgressEvent_Tags.Connected: {
+					ordinalConverter.write(8, into);
+					return;
+				}
+				default:
+					// Throwing from here means that SshConnectionProgressEvent_Tags hasn't matched an ordinal.
+					throw new UniffiInternalError.UnexpectedEnumCase();
+			}
+		}
+		allocationSize(value: TypeName): number {
+			switch (value.tag) {
+				case SshConnectionProgressEvent_Tags.DnsResolved: {
+					const inner = value.inner;
+					let size = ordinalConverter.allocationSize(1);
+					size += FfiConverterArrayString.allocationSize(inner.candidates);
+					return size;
+				}
+				case SshConnectionProgressEvent_Tags.TcpConnected: {
+					const inner = value.inner;
+					let size = ordinalConverter.allocationSize(2);
+					size += FfiConverterString.allocationSize(inner.chosen);
+					return size;
+				}
+				case SshConnectionProgressEvent_Tags.HostKeyVerified: {
+					return ordinalConverter.allocationSize(3);
+				}
+				case SshConnectionProgressEvent_Tags.SshHandshake: {
+					return ordinalConverter.allocationSize(4);
+				}
+				case SshConnectionProgressEvent_Tags.Authenticating: {
+					return ordinalConverter.allocationSize(5);
+				}
+				case SshConnectionProgressEvent_Tags.AuthAttempt: {
+					const inner = value.inner;
+					let size = ordinalConverter.allocationSize(6);
+					size += FfiConverterString.allocationSize(inner.method);
+					return size;
+				}
+				case SshConnectionProgressEvent_Tags.Authenticated: {
+					return ordinalConverter.allocationSize(7);
+				}
+				case SshConnectionProgressEvent_Tags.Connected: {
+					return ordinalConverter.allocationSize(8);
+				}
+				default:
+					throw new UniffiInternalError.UnexpectedEnumCase();
+			}
+		}
+	}
+	return new FFIConverter();
+})();
+
+// Error type: SshError
+
+// Enum: SshError
+export enum SshError_Tags {
+	Disconnected = 'Disconnected',
+	UnsupportedKeyType = 'UnsupportedKeyType',
+	Auth = 'Auth',
+	ShellAlreadyRunning = 'ShellAlreadyRunning',
+	RecordingAlreadyRunning = 'RecordingAlreadyRunning',
+	Russh = 'Russh',
+	RusshKeys = 'RusshKeys',
+	Cancelled = 'Cancelled',
+	Unsupported = 'Unsupported',
+	InvalidPattern = 'InvalidPattern',
+	Io = 'Io',
+	InvalidRecording = 'InvalidRecording',
+	InvalidArgument = 'InvalidArgument',
+	RemoteCommandFailed = 'RemoteCommandFailed',
+}
+export const SshError = (() => {
+	type Disconnected__interface = {
+		tag: SshError_Tags.Disconnected;
+	};
+
+	class Disconnected_ extends UniffiError implements Disconnected__interface {
+		/**
+		 * @private
+		 * This field is private and should not be used, use `tag` instead.
+		 */
+		readonly [uniffiTypeNameSymbol] = 'SshError';
+		readonly tag = SshError_Tags.Disconnected;
+		constructor() {
+			super('SshError', 'Disconnected');
+		}
+
+		static new(): Disconnected_ {
+			return new Disconnected_();
+		}
+
+		static instanceOf(obj: any): obj is Disconnected_ {
+			return obj.tag === SshError_Tags.Disconnected;
+		}
+
+		static hasInner(obj: any): obj is Disconnected_ {
+			return false;
+		}
+	}
+
+	type UnsupportedKeyType__interface = {
+		tag: SshError_Tags.UnsupportedKeyType;
+	};
+
+	class UnsupportedKeyType_
+		extends UniffiError
+		implements UnsupportedKeyType__interface
+	{
+		/**
+		 * @private
+		 * This field is private and should not be used, use `tag` instead.
+		 */
+		readonly [uniffiTypeNameSymbol] = 'SshError';
+		readonly tag = SshError_Tags.UnsupportedKeyType;
+		constructor() {
+			super('SshError', 'UnsupportedKeyType');
+		}
+
+		static new(): UnsupportedKeyType_ {
+			return new UnsupportedKeyType_();
+		}
+
+		static instanceOf(obj: any): obj is UnsupportedKeyType_ {
+			return obj.tag === SshError_Tags.UnsupportedKeyType;
+		}
+
+		static hasInner(obj: any): obj is UnsupportedKeyType_ {
+			return false;
+		}
+	}
+
+	type Auth__interface = {
+		tag: SshError_Tags.Auth;
+		inner: Readonly<[string]>;
+	};
+
+	class Auth_ extends UniffiError implements Auth__interface {
+		/**
+		 * @private
+		 * This field is private and should not be used, use `tag` instead.
+		 */
+		readonly [uniffiTypeNameSymbol] = 'SshError';
+		readonly tag = SshError_Tags.Auth;
+		readonly inner: Readonly<[string]>;
+		constructor(v0: string) {
+			super('SshError', 'Auth');
+			this.inner = Object.freeze([v0]);
+		}
+
+		static new(v0: string): Auth_ {
+			return new Auth_(v0);
+		}
+
+		static instanceOf(obj: any): obj is Auth_ {
+			return obj.tag === SshError_Tags.Auth;
+		}
+
+		static hasInner(obj: any): obj is Auth_ {
+			return Auth_.instanceOf(obj);
+		}
+
+		static getInner(obj: Auth_): Readonly<[string]> {
+			return obj.inner;
+		}
+	}
+
+	type ShellAlreadyRunning__interface = {
+		tag: SshError_Tags.ShellAlreadyRunning;
+	};
+
+	class ShellAlreadyRunning_
+		extends UniffiError
+		implements ShellAlreadyRunning__interface
+	{
+		/**
+		 * @private
+		 * This field is private and should not be used, use `tag` instead.
+		 */
+		readonly [uniffiTypeNameSymbol] = 'SshError';
+		readonly tag = SshError_Tags.ShellAlreadyRunning;
+		constructor() {
+			super('SshError', 'ShellAlreadyRunning');
+		}
+
+		static new(): ShellAlreadyRunning_ {
+			return new ShellAlreadyRunning_();
+		}
+
+		static instanceOf(obj: any): obj is ShellAlreadyRunning_ {
+			return obj.tag === SshError_Tags.ShellAlreadyRunning;
+		}
+
+		static hasInner(obj: any): obj is ShellAlreadyRunning_ {
+			return false;
+		}
+	}
+
+	type RecordingAlreadyRunning__interface = {
+		tag: SshError_Tags.RecordingAlreadyRunning;
+	};
+
+	class RecordingAlreadyRunning_
+		extends UniffiError
+		implements RecordingAlreadyRunning__interface
+	{
+		/**
+		 * @private
+		 * This field is private and should not be used, use `tag` instead.
+		 */
+		readonly [uniffiTypeNameSymbol] = 'SshError';
+		readonly tag = SshError_Tags.RecordingAlreadyRunning;
+		constructor() {
+			super('SshError', 'RecordingAlreadyRunning');
+		}
+
+		static new(): RecordingAlreadyRunning_ {
+			return new RecordingAlreadyRunning_();
+		}
+
+		static instanceOf(obj: any): obj is RecordingAlreadyRunning_ {
+			return obj.tag === SshError_Tags.RecordingAlreadyRunning;
+		}
+
+		static hasInner(obj: any): obj is RecordingAlreadyRunning_ {
+			return false;
+		}
+	}
+
+	type Russh__interface = {
+		tag: SshError_Tags.Russh;
+		inner: Readonly<[string]>;
+	};
+
+	class Russh_ extends UniffiError implements Russh__interface {
+		/**
+		 * @private
+		 * This field is private and should not be used, use `tag` instead.
+		 */
+		readonly [uniffiTypeNameSymbol] = 'SshError';
+		readonly tag = SshError_Tags.Russh;
+		readonly inner: Readonly<[string]>;
+		constructor(v0: string) {
+			super('SshError', 'Russh');
+			this.inner = Object.freeze([v0]);
+		}
+
+		static new(v0: string): Russh_ {
+			return new Russh_(v0);
+		}
+
+		static instanceOf(obj: any): obj is Russh_ {
+			return obj.tag === SshError_Tags.Russh;
+		}
+
+		static hasInner(obj: any): obj is Russh_ {
+			return Russh_.instanceOf(obj);
+		}
+
+		static getInner(obj: Russh_): Readonly<[string]> {
+			return obj.inner;
+		}
+	}
+
+	type RusshKeys__interface = {
+		tag: SshError_Tags.RusshKeys;
+		inner: Readonly<[string]>;
+	};
+
+	class RusshKeys_ extends UniffiError implements RusshKeys__interface {
+		/**
+		 * @private
+		 * This field is private and should not be used, use `tag` instead.
+		 */
+		readonly [uniffiTypeNameSymbol] = 'SshError';
+		readonly tag = SshError_Tags.RusshKeys;
+		readonly inner: Readonly<[string]>;
+		constructor(v0: string) {
+			super('SshError', 'RusshKeys');
+			this.inner = Object.freeze([v0]);
+		}
+
+		static new(v0: string): RusshKeys_ {
+			return new RusshKeys_(v0);
+		}
+
+		static instanceOf(obj: any): obj is RusshKeys_ {
+			return obj.tag === SshError_Tags.RusshKeys;
+		}
+
+		static hasInner(obj: any): obj is RusshKeys_ {
+			return RusshKeys_.instanceOf(obj);
+		}
+
+		static getInner(obj: RusshKeys_): Readonly<[string]> {
+			return obj.inner;
+		}
+	}
+
+	type Cancelled__interface = {
+		tag: SshError_Tags.Cancelled;
+	};
+
+	class Cancelled_ extends UniffiError implements Cancelled__interface {
+		/**
+		 * @private
+		 * This field is private and should not be used, use `tag` instead.
+		 */
+		readonly [uniffiTypeNameSymbol] = 'SshError';
+		readonly tag = SshError_Tags.Cancelled;
+		constructor() {
+			super('SshError', 'Cancelled');
+		}
+
+		static new(): Cancelled_ {
+			return new Cancelled_();
+		}
+
+		static instanceOf(obj: any): obj is Cancelled_ {
+			return obj.tag === SshError_Tags.Cancelled;
+		}
+
+		static hasInner(obj: any): obj is Cancelled_ {
+			return false;
+		}
+	}
+
+	type Unsupported__interface = {
+		tag: SshError_Tags.Unsupported;
+		inner: Readonly<[string]>;
+	};
+
+	class Unsupported_ extends UniffiError implements Unsupported__interface {
+		/**
+		 * @private
+		 * This field is private and should not be used, use `tag` instead.
+		 */
+		readonly [uniffiTypeNameSymbol] = 'SshError';
+		readonly tag = SshError_Tags.Unsupported;
+		readonly inner: Readonly<[string]>;
+		constructor(v0: string) {
+			super('SshError', 'Unsupported');
+			this.inner = Object.freeze([v0]);
+		}
+
+		static new(v0: string): Unsupported_ {
+			return new Unsupported_(v0);
+		}
+
+		static instanceOf(obj: any): obj is Unsupported_ {
+			return obj.tag === SshError_Tags.Unsupported;
+		}
+
+		static hasInner(obj: any): obj is Unsupported_ {
+			return Unsupported_.instanceOf(obj);
+		}
+
+		static getInner(obj: Unsupported_): Readonly<[string]> {
+			return obj.inner;
+		}
+	}
+
+	type InvalidPattern__interface = {
+		tag: SshError_Tags.InvalidPattern;
+		inner: Readonly<[string]>;
+	};
+
+	class InvalidPattern_
+		extends UniffiError
+		implements InvalidPattern__interface
+	{
+		/**
+		 * @private
+		 * This field is private and should not be used, use `tag` instead.
+		 */
+		readonly [uniffiTypeNameSymbol] = 'SshError';
+		readonly tag = SshError_Tags.InvalidPattern;
+		readonly inner: Readonly<[string]>;
+		constructor(v0: string) {
+			super('SshError', 'InvalidPattern');
+			this.inner = Object.freeze([v0]);
+		}
+
+		static new(v0: string): InvalidPattern_ {
+			return new InvalidPattern_(v0);
+		}
+
+		static instanceOf(obj: any): obj is InvalidPattern_ {
+			return obj.tag === SshError_Tags.InvalidPattern;
+		}
+
+		static hasInner(obj: any): obj is InvalidPattern_ {
+			return InvalidPattern_.instanceOf(obj);
+		}
+
+		static getInner(obj: InvalidPattern_): Readonly<[string]> {
+			return obj.inner;
+		}
+	}
+
+	type Io__interface = {
+		tag: SshError_Tags.Io;
+		inner: Readonly<[string]>;
+	};
+
+	class Io_ extends UniffiError implements Io__interface {
+		/**
+		 * @private
+		 * This field is private and should not be used, use `tag` instead.
+		 */
+		readonly [uniffiTypeNameSymbol] = 'SshError';
+		readonly tag = SshError_Tags.Io;
+		readonly inner: Readonly<[string]>;
+		constructor(v0: string) {
+			super('SshError', 'Io');
+			this.inner = Object.freeze([v0]);
+		}
+
+		static new(v0: string): Io_ {
+			return new Io_(v0);
+		}
+
+		static instanceOf(obj: any): obj is Io_ {
+			return obj.tag === SshError_Tags.Io;
+		}
+
+		static hasInner(obj: any): obj is Io_ {
+			return Io_.instanceOf(obj);
+		}
+
+		static getInner(obj: Io_): Readonly<[string]> {
+			return obj.inner;
+		}
+	}
+
+	type InvalidRecording__interface = {
+		tag: SshError_Tags.InvalidRecording;
+		inner: Readonly<[string]>;
+	};
+
+	class InvalidRecording_
+		extends UniffiError
+		implements InvalidRecording__interface
+	{
+		/**
+		 * @private
+		 * This field is private and should not be used, use `tag` instead.
+		 */
+		readonly [uniffiTypeNameSymbol] = 'SshError';
+		readonly tag = SshError_Tags.InvalidRecording;
+		readonly inner: Readonly<[string]>;
+		constructor(v0: string) {
+			super('SshError', 'InvalidRecording');
+			this.inner = Object.freeze([v0]);
+		}
+
+		static new(v0: string): InvalidRecording_ {
+			return new InvalidRecording_(v0);
+		}
+
+		static instanceOf(obj: any): obj is InvalidRecording_ {
+			return obj.tag === SshError_Tags.InvalidRecording;
+		}
+
+		static hasInner(obj: any): obj is InvalidRecording_ {
+			return InvalidRecording_.instanceOf(obj);
+		}
+
+		static getInner(obj: InvalidRecording_): Readonly<[string]> {
+			return obj.inner;
+		}
+	}
+
+	type InvalidArgument__interface = {
+		tag: SshError_Tags.InvalidArgument;
+		inner: Readonly<[string]>;
+	};
+
+	class InvalidArgument_
+		extends UniffiError
+		implements InvalidArgument__interface
+	{
+		/**
+		 * @private
+		 * This field is private and should not be used, use `tag` instead.
+		 */
+		readonly [uniffiTypeNameSymbol] = 'SshError';
+		readonly tag = SshError_Tags.InvalidArgument;
+		readonly inner: Readonly<[string]>;
+		constructor(v0: string) {
+			super('SshError', 'InvalidArgument');
+			this.inner = Object.freeze([v0]);
+		}
+
+		static new(v0: string): InvalidArgument_ {
+			return new InvalidArgument_(v0);
+		}
+
+		static instanceOf(obj: any): obj is InvalidArgument_ {
+			return obj.tag === SshError_Tags.InvalidArgument;
+		}
+
+		static hasInner(obj: any): obj is InvalidArgument_ {
+			return InvalidArgument_.instanceOf(obj);
+		}
+
+		static getInner(obj: InvalidArgument_): Readonly<[string]> {
+			return obj.inner;
+		}
+	}
+
+	type RemoteCommandFailed__interface = {
+		tag: SshError_Tags.RemoteCommandFailed;
+		inner: Readonly<[string]>;
+	};
+
+	class RemoteCommandFailed_
+		extends UniffiError
+		implements RemoteCommandFailed__interface
+	{
+		/**
+		 * @private
+		 * This field is private and should not be used, use `tag` instead.
+		 */
+		readonly [uniffiTypeNameSymbol] = 'SshError';
+		readonly tag = SshError_Tags.RemoteCommandFailed;
+		readonly inner: Readonly<[string]>;
+		constructor(v0: string) {
+			super('SshError', 'RemoteCommandFailed');
+			this.inner = Object.freeze([v0]);
+		}
+
+		static new(v0: string): RemoteCommandFailed_ {
+			return new RemoteCommandFailed_(v0);
+		}
+
+		static instanceOf(obj: any): obj is RemoteCommandFailed_ {
+			return obj.tag === SshError_Tags.RemoteCommandFailed;
+		}
+
+		static hasInner(obj: any): obj is RemoteCommandFailed_ {
+			return RemoteCommandFailed_.instanceOf(obj);
+		}
+
+		static getInner(obj: RemoteCommandFailed_): Readonly<[string]> {
+			return obj.inner;
+		}
+	}
+
+	function instanceOf(obj: any): obj is SshError {
+		return obj[uniffiTypeNameSymbol] === 'SshError';
+	}
+
+	return Object.freeze({
+		instanceOf,
+		Disconnected: Disconnected_,
+		UnsupportedKeyType: UnsupportedKeyType_,
+		Auth: Auth_,
+		ShellAlreadyRunning: ShellAlreadyRunning_,
+		RecordingAlreadyRunning: RecordingAlreadyRunning_,
+		Russh: Russh_,
+		RusshKeys: RusshKeys_,
+		Cancelled: Cancelled_,
+		Unsupported: Unsupported_,
+		InvalidPattern: InvalidPattern_,
+		Io: Io_,
+		InvalidRecording: InvalidRecording_,
+		InvalidArgument: InvalidArgument_,
+		RemoteCommandFailed: RemoteCommandFailed_,
+	});
+})();
+
+export type SshError = InstanceType<
+	(typeof SshError)[keyof Omit<typeof SshError, 'instanceOf'>]
+>;
+
+// FfiConverter for enum SshError
+const FfiConverterTypeSshError = (() => {
+	const ordinalConverter = FfiConverterInt32;
+	type TypeName = SshError;
+	class FFIConverter extends AbstractFfiConverterByteArray<TypeName> {
+		read(from: RustBuffer): TypeName {
+			switch (ordinalConverter.read(from)) {
+				case 1:
+					return new SshError.Disconnected();
+				case 2:
+					return new SshError.UnsupportedKeyType();
+				case 3:
+					return new SshError.Auth(FfiConverterString.read(from));
+				case 4:
+					return new SshError.ShellAlreadyRunning();
+				case 5:
+					return new SshError.RecordingAlreadyRunning();
+				case 6:
+					return new SshError.Russh(FfiConverterString.read(from));
+				case 7:
+					return new SshError.RusshKeys(FfiConverterString.read(from));
+				case 8:
+					return new SshError.Cancelled();
+				case 9:
+					return new SshError.Unsupported(FfiConverterString.read(from));
+				case 10:
+					return new SshError.InvalidPattern(FfiConverterString.read(from));
+				case 11:
+					return new SshError.Io(FfiConverterString.read(from));
+				case 12:
+					return new SshError.InvalidRecording(FfiConverterString.read(from));
+				case 13:
+					return new SshError.InvalidArgument(FfiConverterString.read(from));
+				case 14:
+					return new SshError.RemoteCommandFailed(
+						FfiConverterString.read(from),
+					);
+				default:
+					throw new UniffiInternalError.UnexpectedEnumCase();
+			}
+		}
+		write(value: TypeName, into: RustBuffer): void {
+			switch (value.tag) {
+				case SshError_Tags.Disconnected: {
+					ordinalConverter.write(1, into);
+					return;
+				}
+				case SshError_Tags.UnsupportedKeyType: {
+					ordinalConverter.write(2, into);
+					return;
+				}
+				case SshError_Tags.Auth: {
+					ordinalConverter.write(3, into);
+					const inner = value.inner;
+					FfiConverterString.write(inner[0], into);
+					return;
+				}
+				case SshError_Tags.ShellAlreadyRunning: {
+					ordinalConverter.write(4, into);
+					return;
+				}
+				case SshError_Tags.RecordingAlreadyRunning: {
+					ordinalConverter.write(5, into);
+					return;
+				}
+				case SshError_Tags.Russh: {
+					ordinalConverter.write(6, into);
+					const inner = value.inner;
+					FfiConverterString.write(inner[0], into);
+					return;
+				}
+				case SshError_Tags.RusshKeys: {
+					ordinalConverter.write(7, into);
+					const inner = value.inner;
+					FfiConverterString.write(inner[0], into);
+					return;
+				}
+				case SshError_Tags.Cancelled: {
+					ordinalConverter.write(8, into);
+					return;
+				}
+				case SshError_Tags.Unsupported: {
+					ordinalConverter.write(9, into);
+					const inner = value.inner;
+					FfiConverterString.write(inner[0], into);
+					return;
+				}
+				case SshError_Tags.InvalidPattern: {
+					ordinalConverter.write(10, into);
+					const inner = value.inner;
+					FfiConverterString.write(inner[0], into);
+					return;
+				}
+				case SshError_Tags.Io: {
+					ordinalConverter.write(11, into);
+					const inner = value.inner;
+					FfiConverterString.write(inner[0], into);
+					return;
+				}
+				case SshError_Tags.InvalidRecording: {
+					ordinalConverter.write(12, into);
+					const inner = value.inner;
+					FfiConverterString.write(inner[0], into);
+					return;
+				}
+				case SshError_Tags.InvalidArgument: {
+					ordinalConverter.write(13, into);
+					const inner = value.inner;
+					FfiConverterString.write(inner[0], into);
+					return;
+				}
+				case SshError_Tags.RemoteCommandFailed: {
+					ordinalConverter.write(14, into);
+					const inner = value.inner;
+					FfiConverterString.write(inner[0], into);
+					return;
+				}
+				default:
+					// Throwing from here means that SshError_Tags hasn't matched an ordinal.
+					throw new UniffiInternalError.UnexpectedEnumCase();
+			}
+		}
+		allocationSize(value: TypeName): number {
+			switch (value.tag) {
+				case SshError_Tags.Disconnected: {
+					return ordinalConverter.allocationSize(1);
+				}
+				case SshError_Tags.UnsupportedKeyType: {
+					return ordinalConverter.allocationSize(2);
+				}
+				case SshError_Tags.Auth: {
+					const inner = value.inner;
+					let size = ordinalConverter.allocationSize(3);
+					size += FfiConverterString.allocationSize(inner[0]);
+					return size;
+				}
+				case SshError_Tags.ShellAlreadyRunning: {
+					return ordinalConverter.allocationSize(4);
+				}
+				case SshError_Tags.RecordingAlreadyRunning: {
+					return ordinalConverter.allocationSize(5);
+				}
+				case SshError_Tags.Russh: {
+					const inner = value.inner;
+					let size = ordinalConverter.allocationSize(6);
+					size += FfiConverterString.allocationSize(inner[0]);
+					return size;
+				}
+				case SshError_Tags.RusshKeys: {
+					const inner = value.inner;
+					let size = ordinalConverter.allocationSize(7);
+					size += FfiConverterString.allocationSize(inner[0]);
+					return size;
+				}
+				case SshError_Tags.Cancelled: {
+					return ordinalConverter.allocationSize(8);
+				}
+				case SshError_Tags.Unsupported: {
+					const inner = value.inner;
+					let size = ordinalConverter.allocationSize(9);
+					size += FfiConverterString.allocationSize(inner[0]);
+					return size;
+				}
+				case SshError_Tags.InvalidPattern: {
+					const inner = value.inner;
+					let size = ordinalConverter.allocationSize(10);
+					size += FfiConverterString.allocationSize(inner[0]);
+					return size;
+				}
+				case SshError_Tags.Io: {
+					const inner = value.inner;
+					let size = ordinalConverter.allocationSize(11);
+					size += FfiConverterString.allocationSize(inner[0]);
+					return size;
+				}
+				case SshError_Tags.InvalidRecording: {
+					const inner = value.inner;
+					let size = ordinalConverter.allocationSize(12);
+					size += FfiConverterString.allocationSize(inner[0]);
+					return size;
+				}
+				case SshError_Tags.InvalidArgument: {
+					const inner = value.inner;
+					let size = ordinalConverter.allocationSize(13);
+					size += FfiConverterString.allocationSize(inner[0]);
+					return size;
+				}
+				case SshError_Tags.RemoteCommandFailed: {
+					const inner = value.inner;
+					let size = ordinalConverter.allocationSize(14);
+					size += FfiConverterString.allocationSize(inner[0]);
+					return size;
+				}
+				default:
+					throw new UniffiInternalError.UnexpectedEnumCase();
+			}
+		}
+	}
+	return new FFIConverter();
+})();
+
+export enum StreamKind {
+	Stdout,
+	Stderr,
+}
+
+const FfiConverterTypeStreamKind = (() => {
+	const ordinalConverter = FfiConverterInt32;
+	type TypeName = StreamKind;
+	class FFIConverter extends AbstractFfiConverterByteArray<TypeName> {
+		read(from: RustBuffer): TypeName {
+			switch (ordinalConverter.read(from)) {
+				case 1:
+					return StreamKind.Stdout;
+				case 2:
+					return StreamKind.Stderr;
+				default:
+					throw new UniffiInternalError.UnexpectedEnumCase();
+			}
+		}
+		write(value: TypeName, into: RustBuffer): void {
+			switch (value) {
+				case StreamKind.Stdout:
+					return ordinalConverter.write(1, into);
+				case StreamKind.Stderr:
+					return ordinalConverter.write(2, into);
+			}
+		}
+		allocationSize(value: TypeName): number {
+			return ordinalConverter.allocationSize(0);
+		}
+	}
+	return new FFIConverter();
+})();
+
+// Enum: TerminalColor
+export enum TerminalColor_Tags {
+	Default = 'Default',
+	Indexed = 'Indexed',
+	Rgb = 'Rgb',
+}
+export const TerminalColor = (() => {
+	type Default__interface = {
+		tag: TerminalColor_Tags.Default;
+	};
+
+	class Default_ extends UniffiEnum implements Default__interface {
+		/**
+		 * @private
+		 * This field is private and should not be used, use `tag` instead.
+		 */
+		readonly [uniffiTypeNameSymbol] = 'TerminalColor';
+		readonly tag = TerminalColor_Tags.Default;
+		constructor() {
+			super('TerminalColor', 'Default');
+		}
+
+		static new(): Default_ {
+			return new Default_();
+		}
+
+		static instanceOf(obj: any): obj is Default_ {
+			return obj.tag === TerminalColor_Tags.Default;
+		}
+	}
+
+	type Indexed__interface = {
+		tag: TerminalColor_Tags.Indexed;
+		inner: Readonly<{ index: /*u8*/ number }>;
+	};
+
+	class Indexed_ extends UniffiEnum implements Indexed__interface {
+		/**
+		 * @private
+		 * This field is private and should not be used, use `tag` instead.
+		 */
+		readonly [uniffiTypeNameSymbol] = 'TerminalColor';
+		readonly tag = TerminalColor_Tags.Indexed;
+		readonly inner: Readonly<{ index: /*u8*/ number }>;
+		constructor(inner: { index: /*u8*/ number }) {
+			super('TerminalColor', 'Indexed');
+			this.inner = Object.freeze(inner);
+		}
+
+		static new(inner: { index: /*u8*/ number }): Indexed_ {
+			return new Indexed_(inner);
+		}
+
+		static instanceOf(obj: any): obj is Indexed_ {
+			return obj.tag === TerminalColor_Tags.Indexed;
+		}
+	}
+
+	type Rgb__interface = {
+		tag: TerminalColor_Tags.Rgb;
+		inner: Readonly<{ r: /*u8*/ number; g: /*u8*/ number; b: /*u8*/ number }>;
+	};
+
+	class Rgb_ extends UniffiEnum implements Rgb__interface {
+		/**
+		 * @private
+		 * This field is private and should not be used, use `tag` instead.
+		 */
+		readonly [uniffiTypeNameSymbol] = 'TerminalColor';
+		readonly tag = TerminalColor_Tags.Rgb;
+		readonly inner: Readonly<{
+			r: /*u8*/ number;
+			g: /*u8*/ number;
+			b: /*u8*/ number;
+		}>;
+		constructor(inner: {
+			r: /*u8*/ number;
+			g: /*u8*/ number;
+			b: /*u8*/ number;
+		}) {
+			super('TerminalColor', 'Rgb');
+			this.inner = Object.freeze(inner);
+		}
+
+		static new(inner: {
+			r: /*u8*/ number;
+			g: /*u8*/ number;
+			b: /*u8*/ number;
+		}): Rgb_ {
+			return new Rgb_(inner);
+		}
+
+		static instanceOf(obj: any): obj is Rgb_ {
+			return obj.tag === TerminalColor_Tags.Rgb;
+		}
+	}
+
+	function instanceOf(obj: any): obj is TerminalColor {
+		return obj[uniffiTypeNameSymbol] === 'TerminalColor';
+	}
+
+	return Object.freeze({
+		instanceOf,
+		Default: Default_,
+		Indexed: Indexed_,
+		Rgb: Rgb_,
+	});
+})();
+
+export type TerminalColor = InstanceType<
+	(typeof TerminalColor)[keyof Omit<typeof TerminalColor, 'instanceOf'>]
+>;
+
+// FfiConverter for enum TerminalColor
+const FfiConverterTypeTerminalColor = (() => {
+	const ordinalConverter = FfiConverterInt32;
+	type TypeName = TerminalColor;
+	class FFIConverter extends AbstractFfiConverterByteArray<TypeName> {
+		read(from: RustBuffer): TypeName {
+			switch (ordinalConverter.read(from)) {
+				case 1:
+					return new TerminalColor.Default();
+				case 2:
+					return new TerminalColor.Indexed({
+						index: FfiConverterUInt8.read(from),
+					});
+				case 3:
+					return new TerminalColor.Rgb({
+						r: FfiConverterUInt8.read(from),
+						g: FfiConverterUInt8.read(from),
+						b: FfiConverterUInt8.read(from),
+					});
+				default:
+					throw new UniffiInternalError.UnexpectedEnumCase();
+			}
+		}
+		write(value: TypeName, into: RustBuffer): void {
+			switch (value.tag) {
+				case TerminalColor_Tags.Default: {
+					ordinalConverter.write(1, into);
+					return;
+				}
+				case TerminalColor_Tags.Indexed: {
+					ordinalConverter.write(2, into);
+					const inner = value.inner;
+					FfiConverterUInt8.write(inner.index, into);
+					return;
+				}
+				case TerminalColor_Tags.Rgb: {
+					ordinalConverter.write(3, into);
+					const inner = value.inner;
+					FfiConverterUInt8.write(inner.r, into);
+					FfiConverterUInt8.write(inner.g, into);
+					FfiConverterUInt8.write(inner.b, into);
+					return;
+				}
+				default:
+					// Throwing from here means that TerminalColor_Tags hasn't matched an ordinal.
+					throw new UniffiInternalError.UnexpectedEnumCase();
+			}
+		}
+		allocationSize(value: TypeName): number {
+			switch (value.tag) {
+				case TerminalColor_Tags.Default: {
+					return ordinalConverter.allocationSize(1);
+				}
+				case TerminalColor_Tags.Indexed: {
+					const inner = value.inner;
+					let size = ordinalConverter.allocationSize(2);
+					size += FfiConverterUInt8.allocationSize(inner.index);
+					return size;
+				}
+				case TerminalColor_Tags.Rgb: {
+					const inner = value.inner;
+					let size = ordinalConverter.allocationSize(3);
+					size += FfiConverterUInt8.allocationSize(inner.r);
+					size += FfiConverterUInt8.allocationSize(inner.g);
+					size += FfiConverterUInt8.allocationSize(inner.b);
+					return size;
+				}
+				default:
+					throw new UniffiInternalError.UnexpectedEnumCase();
+			}
+		}
+	}
+	return new FFIConverter();
+})();
+
+export enum TerminalType {
+	Vanilla,
+	Vt100,
+	Vt102,
+	Vt220,
+	Ansi,
+	Xterm,
+	Xterm256,
+}
+
+const FfiConverterTypeTerminalType = (() => {
+	const ordinalConverter = FfiConverterInt32;
+	type TypeName = TerminalType;
+	class FFIConverter extends AbstractFfiConverterByteArray<TypeName> {
+		read(from: RustBuffer): TypeName {
+			switch (ordinalConverter.read(from)) {
+				case 1:
+					return TerminalType.Vanilla;
+				case 2:
+					return TerminalType.Vt100;
+				case 3:
+					return TerminalType.Vt102;
+				case 4:
+					return TerminalType.Vt220;
+				case 5:
+					return TerminalType.Ansi;
+				case 6:
+					return TerminalType.Xterm;
+				case 7:
+					return TerminalType.Xterm256;
+				default:
+					throw new UniffiInternalError.UnexpectedEnumCase();
+			}
+		}
+		write(value: TypeName, into: RustBuffer): void {
+			switch (value) {
+				case TerminalType.Vanilla:
+					return ordinalConverter.write(1, into);
+				case TerminalType.Vt100:
+					return ordinalConverter.write(2, into);
+				case TerminalType.Vt102:
+					return ordinalConverter.write(3, into);
+				case TerminalType.Vt220:
+					return ordinalConverter.write(4, into);
+				case TerminalType.Ansi:
+					return ordinalConverter.write(5, into);
+				case TerminalType.Xterm:
+					return ordinalConverter.write(6, into);
+				case TerminalType.Xterm256:
+					return ordinalConverter.write(7, into);
+			}
+		}
+		allocationSize(value: TypeName): number {
+			return ordinalConverter.allocationSize(0);
+		}
+	}
+	return new FFIConverter();
+})();
+
+export enum TerminfoStatus {
+	AlreadyPresent,
+	Installed,
+}
+
+const FfiConverterTypeTerminfoStatus = (() => {
+	const ordinalConverter = FfiConverterInt32;
+	type TypeName = TerminfoStatus;
+	class FFIConverter extends AbstractFfiConverterByteArray<TypeName> {
+		read(from: RustBuffer): TypeName {
+			switch (ordinalConverter.read(from)) {
+				case 1:
+					return TerminfoStatus.AlreadyPresent;
+				case 2:
+					return TerminfoStatus.Installed;
+				default:
+					throw new UniffiInternalError.UnexpectedEnumCase();
+			}
+		}
+		write(value: TypeName, into: RustBuffer): void {
+			switch (value) {
+				case TerminfoStatus.AlreadyPresent:
+					return ordinalConverter.write(1, into);
+				case TerminfoStatus.Installed:
+					return ordinalConverter.write(2, into);
+			}
+		}
+		allocationSize(value: TypeName): number {
+			return ordinalConverter.allocationSize(0);
+		}
+	}
+	return new FFIConverter();
+})();
+
+/**
+ * Receives SSH_MSG_USERAUTH_BANNER text (legal notices, MOTD-style warnings)
+ * while authentication is still in progress.
+ */
+export interface AuthBannerCallback {
+	onChange(banner: string): void;
+}
+
+/**
+ * Receives SSH_MSG_USERAUTH_BANNER text (legal notices, MOTD-style warnings)
+ * while authentication is still in progress.
+ */
+export class AuthBannerCallbackImpl
+	extends UniffiAbstractObject
+	implements AuthBannerCallback
+{
+	readonly [uniffiTypeNameSymbol] = 'AuthBannerCallbackImpl';
+	readonly [destructorGuardSymbol]: UniffiRustArcPtr;
+	readonly [pointerLiteralSymbol]: UnsafeMutableRawPointer;
+	// No primary constructor declared for this class.
+	private constructor(pointer: UnsafeMutableRawPointer) {
+		super();
+		this[pointerLiteralSymbol] = pointer;
+		this[destructorGuardSymbol] =
+			uniffiTypeAuthBannerCallbackImplObjectFactory.bless(pointer);
+	}
+
+	public onChange(banner: string): void {
+		uniffiCaller.rustCall(
+			/*caller:*/ (callStatus) => {
+				nativeModule().ubrn_uniffi_uniffi_russh_fn_method_authbannercallback_on_change(
+					uniffiTypeAuthBannerCallbackImplObjectFactory.clonePointer(this),
+					FfiConverterString.lower(banner),
+					callStatus,
+				);
+			},
+			/*liftString:*/ FfiConverterString.lift,
+		);
+	}
+
+	/**
+	 * {@inheritDoc uniffi-bindgen-react-native#UniffiAbstractObject.uniffiDestroy}
+	 */
+	uniffiDestroy(): void {
+		const ptr = (this as any)[destructorGuardSymbol];
+		if (ptr !== undefined) {
+			const pointer =
+				uniffiTypeAuthBannerCallbackImplObjectFactory.pointer(this);
+			uniffiTypeAuthBannerCallbackImplObjectFactory.freePointer(pointer);
+			uniffiTypeAuthBannerCallbackImplObjectFactory.unbless(ptr);
+			delete (this as any)[destructorGuardSymbol];
+		}
+	}
+
+	static instanceOf(obj: any): obj is AuthBannerCallbackImpl {
+		return uniffiTypeAuthBannerCallbackImplObjectFactory.isConcreteType(obj);
+	}
+}
+
+const uniffiTypeAuthBannerCallbackImplObjectFactory: UniffiObjectFactory<AuthBannerCallback> =
+	(() => {
+		return {
+			create(pointer: UnsafeMutableRawPointer): AuthBannerCallback {
+				const instance = Object.create(AuthBannerCallbackImpl.prototype);
+				instance[pointerLiteralSymbol] = pointer;
+				instance[destructorGuardSymbol] = this.bless(pointer);
+				instance[uniffiTypeNameSymbol] = 'AuthBannerCallbackImpl';
+				return instance;
+			},
+
+			bless(p: UnsafeMutableRawPointer): UniffiRustArcPtr {
+				return uniffiCaller.rustCall(
+					/*caller:*/ (status) =>
+						nativeModule().ubrn_uniffi_internal_fn_method_authbannercallback_ffi__bless_pointer(
+							p,
+							status,
+						),
+					/*liftString:*/ FfiConverterString.lift,
+				);
+			},
+
+			unbless(ptr: UniffiRustArcPtr) {
+				ptr.markDestroyed();
+			},
+
+			pointer(obj: AuthBannerCallback): UnsafeMutableRawPointer {
+				if ((obj as any)[destructorGuardSymbol] === undefined) {
+					throw new UniffiInternalError.UnexpectedNullPointer();
+				}
+				return (obj as any)[pointerLiteralSymbol];
+			},
+
+			clonePointer(obj: AuthBannerCallback): UnsafeMutableRawPointer {
+				const pointer = this.pointer(obj);
+				return uniffiCaller.rustCall(
+					/*caller:*/ (callStatus) =>
+						nativeModule().ubrn_uniffi_uniffi_russh_fn_clone_authbannercallback(
+							pointer,
+							callStatus,
+						),
+					/*liftString:*/ FfiConverterString.lift,
+				);
+			},
+
+			freePointer(pointer: UnsafeMutableRawPointer): void {
+				uniffiCaller.rustCall(
+					/*caller:*/ (callStatus) =>
+						nativeModule().ubrn_uniffi_uniffi_russh_fn_free_authbannercallback(
+							pointer,
+							callStatus,
+						),
+					/*liftString:*/ FfiConverterString.lift,
+				);
+			},
+
+			isConcreteType(obj: any): obj is AuthBannerCallback {
+				return (
+					obj[destructorGuardSymbol] &&
+					obj[uniffiTypeNameSymbol] === 'AuthBannerCallbackImpl'
+				);
+			},
+		};
+	})();
+// FfiConverter for AuthBannerCallback
+const FfiConverterTypeAuthBannerCallback = new FfiConverterObjectWithCallbacks(
+	uniffiTypeAuthBannerCallbackImplObjectFactory,
+);
+
+// Add a vtavble for the callbacks that go in AuthBannerCallback.
+
+// Put the implementation in a struct so we don't pollute the top-level namespace
+const uniffiCallbackInterfaceAuthBannerCallback: {
+	vtable: UniffiVTableCallbackInterfaceAuthBannerCallback;
+	register: () => void;
+} = {
+	// Create the VTable using a series of closures.
+	// ts automatically converts these into C callback functions.
+	vtable: {
+		onChange: (uniffiHandle: bigint, banner: Uint8Array) => {
+			const uniffiMakeCall = (): void => {
+				const jsCallback =
+					FfiConverterTypeAuthBannerCallback.lift(uniffiHandle);
+				return jsCallback.onChange(FfiConverterString.lift(banner));
+			};
+			const uniffiResult = UniffiResult.ready<void>();
+			const uniffiHandleSuccess = (obj: any) => {};
+			const uniffiHandleError = (code: number, errBuf: UniffiByteArray) => {
+				UniffiResult.writeError(uniffiResult, code, errBuf);
+			};
+			uniffiTraitInterfaceCall(
+				/*makeCall:*/ uniffiMakeCall,
+				/*handleSuccess:*/ uniffiHandleSuccess,
+				/*handleError:*/ uniffiHandleError,
+				/*lowerString:*/ FfiConverterString.lower,
+			);
+			return uniffiResult;
+		},
+		uniffiFree: (uniffiHandle: UniffiHandle): void => {
+			// AuthBannerCallback: this will throw a stale handle error if the handle isn't found.
+			FfiConverterTypeAuthBannerCallback.drop(uniffiHandle);
+		},
+	},
+	register: () => {
+		nativeModule().ubrn_uniffi_uniffi_russh_fn_init_callback_vtable_authbannercallback(
+			uniffiCallbackInterfaceAuthBannerCallback.vtable,
+		);
+	},
+};
+
+/**
+ * Plays an asciicast recording into the same buffer and listener API as
+ * `ShellSession`, so recordings render like live sessions.
+ */
+export interface CastPlayerInterface {
+	/**
+	 * Add a listener with optional replay and live follow.
+	 */
+	addListener(
+		listener: ShellListener,
+		opts: ListenerOptions,
+	) /*throws*/ : /*u64*/ bigint;
+	/**
+	 * Same as `ShellSession::attach_emulator`; follows the recording's
+	 * resizes.
+	 */
+	attachEmulator(
+		scrollbackLines: /*u32*/ number | undefined,
+	): TerminalEmulatorInterface;
+	/**
+	 * Buffer statistics snapshot.
+	 */
+	bufferStats(): BufferStats;
+	/**
+	 * Shell integration commands played so far.
+	 */
+	commands(): Array<CommandRecord>;
+	/**
+	 * Current next sequence number.
+	 */
+	currentSeq(): /*u64*/ bigint;
+	detachEmulator(): void;
+	getInfo(): CastInfo;
+	getStatus(): PlaybackStatus;
+	pause(): void;
+	/**
+	 * Resume playback; at the end, start over.
+	 */
+	play(): void;
+	/**
+	 * Read what has been played so far from a cursor.
+	 */
+	readBuffer(
+		cursor: Cursor,
+		maxBytes: /*u64*/ bigint | undefined,
+	): BufferReadResult;
+	/**
+	 * Same as `ShellSession::read_text`, over what has been played so far.
+	 */
+	readText(
+		cursor: Cursor,
+		maxBytes: /*u64*/ bigint | undefined,
+	): TextReadResult;
+	removeListener(id: /*u64*/ bigint): void;
+	/**
+	 * Same as `ShellSession::search`, over what has been played so far.
+	 */
+	search(
+		pattern: string,
+		opts: SearchOptions,
+		asyncOpts_?: { signal: AbortSignal },
+	) /*throws*/ : Promise<SearchResult>;
+	/**
+	 * Jump to `position_ms`. Seeking back clears the buffer and replays from
+	 * the start behind a terminal reset (ESC c), so listeners see a `Dropped`
+	 * range if they read from an older seq.
+	 */
+	seek(positionMs: /*f64*/ number): void;
+	/**
+	 * Playback rate; 2.0 plays twice as fast.
+	 */
+	setSpeed(speed: /*f64*/ number) /*throws*/ : void;
+}
+
+/**
+ * Plays an asciicast recording into the same buffer and listener API as
+ * `ShellSession`, so recordings render like live sessions.
+ */
+export class CastPlayer
+	extends UniffiAbstractObject
+	implements CastPlayerInterface
+{
+	readonly [uniffiTypeNameSymbol] = 'CastPlayer';
+	readonly [destructorGuardSymbol]: UniffiRustArcPtr;
+	readonly [pointerLiteralSymbol]: UnsafeMutableRawPointer;
+	// No primary constructor declared for this class.
+	private constructor(pointer: UnsafeMutableRawPointer) {
+		super();
+		this[pointerLiteralSymbol] = pointer;
+		this[destructorGuardSymbol] =
+			uniffiTypeCastPlayerObjectFactory.bless(pointer);
+	}
+
+	/**
+	 * Add a listener with optional replay and live follow.
+	 */
+	public addListener(
+		listener: ShellListener,
+		opts: ListenerOptions,
+	): /*u64*/ bigint /*throws*/ {
+		return FfiConverterUInt64.lift(
+			uniffiCaller.rustCallWithError(
+				/*liftError:*/ FfiConverterTypeSshError.lift.bind(
+					FfiConverterTypeSshError,
+				),
+				/*caller:*/ (callStatus) => {
+					return nativeModule().ubrn_uniffi_uniffi_russh_fn_method_castplayer_add_listener(
+						uniffiTypeCastPlayerObjectFactory.clonePointer(this),
+						FfiConverterTypeShellListener.lower(listener),
+						FfiConverterTypeListenerOptions.lower(opts),
+						callStatus,
+					);
+				},
+				/*liftString:*/ FfiConverterString.lift,
+			),
+		);
+	}
+
+	/**
+	 * Same as `ShellSession::attach_emulator`; follows the recording's
+	 * resizes.
+	 */
+	public attachEmulator(
+		scrollbackLines: /*u32*/ number | undefined,
+	): TerminalEmulatorInterface {
+		return FfiConverterTypeTerminalEmulator.lift(
+			uniffiCaller.rustCall(
+				/*caller:*/ (callStatus) => {
+					return nativeModule().ubrn_uniffi_uniffi_russh_fn_method_castplayer_attach_emulator(
+						uniffiTypeCastPlayerObjectFactory.clonePointer(this),
+						FfiConverterOptionalUInt32.lower(scrollbackLines),
+						callStatus,
+					);
+				},
+				/*liftString:*/ FfiConverterString.lift,
+			),
+		);
+	}
+
+	/**
+	 * Buffer statistics snapshot.
+	 */
+	public bufferStats(): BufferStats {
+		return FfiConverterTypeBufferStats.lift(
+			uniffiCaller.rustCall(
+				/*caller:*/ (callStatus) => {
+					return nativeModule().ubrn_uniffi_uniffi_russh_fn_method_castplayer_buffer_stats(
+						uniffiTypeCastPlayerObjectFactory.clonePointer(this),
+						callStatus,
+					);
+				},
+				/*liftString:*/ FfiConverterString.lift,
+			),
+		);
+	}
+
+	/**
+	 * Shell integration commands played so far.
+	 */
+	public commands(): Array<CommandRecord> {
+		return FfiConverterArrayTypeCommandRecord.lift(
+			uniffiCaller.rustCall(
+				/*caller:*/ (callStatus) => {
+					return nativeModule().ubrn_uniffi_uniffi_russh_fn_method_castplayer_commands(
+						uniffiTypeCastPlayerObjectFactory.clonePointer(this),
+						callStatus,
+					);
+				},
+				/*liftString:*/ FfiConverterString.lift,
+			),
+		);
+	}
+
+	/**
+	 * Current next sequence number.
+	 */
+	public currentSeq(): /*u64*/ bigint {
+		return FfiConverterUInt64.lift(
+			uniffiCaller.rustCall(
+				/*caller:*/ (callStatus) => {
+					return nativeModule().ubrn_uniffi_uniffi_russh_fn_method_castplayer_current_seq(
+						uniffiTypeCastPlayerObjectFactory.clonePointer(this),
+						callStatus,
+					);
+				},
+				/*liftString:*/ FfiConverterString.lift,
+			),
+		);
+	}
+
+	public detachEmulator(): void {
+		uniffiCaller.rustCall(
+			/*caller:*/ (callStatus) => {
+				nativeModule().ubrn_uniffi_uniffi_russh_fn_method_castplayer_detach_emulator(
+					uniffiTypeCastPlayerObjectFactory.clonePointer(this),
+					callStatus,
+				);
+			},
+			/*liftString:*/ FfiConverterString.lift,
+		);
+	}
+
+	public getInfo(): CastInfo {
+		return FfiConverterTypeCastInfo.lift(
+			uniffiCaller.rustCall(
+				/*caller:*/ (callStatus) => {
+					return nativeModule().ubrn_uniffi_uniffi_russh_fn_method_castplayer_get_info(
+						uniffiTypeCastPlayerObjectFactory.clonePointer(this),
+						callStatus,
+					);
+				},
+				/*liftString:*/ FfiConverterString.lift,
+			),
+		);
+	}
+
+	public getStatus(): PlaybackStatus {
+		return FfiConverterTypePlaybackStatus.lift(
+			uniffiCaller.rustCall(
+				/*caller:*/ (callStatus) => {
+					return nativeModule().ubrn_uniffi_uniffi_russh_fn_method_castplayer_get_status(
+						uniffiTypeCastPlayerObjectFactory.clonePointer(this),
+						callStatus,
+					);
+				},
+				/*liftString:*/ FfiConverterString.lift,
+			),
+		);
+	}
+
+	public pause(): void {
+		uniffiCaller.rustCall(
+			/*caller:*/ (callStatus) => {
+				nativeModule().ubrn_uniffi_uniffi_russh_fn_method_castplayer_pause(
+					uniffiTypeCastPlayerObjectFactory.clonePointer(this),
+					callStatus,
+				);
+			},
+			/*liftString:*/ FfiConverterString.lift,
+		);
+	}
+
+	/**
+	 * Resume playback; at the end, start over.
+	 */
+	public play(): void {
+		uniffiCaller.rustCall(
+			/*caller:*/ (callStatus) => {
+				nativeModule().ubrn_uniffi_uniffi_russh_fn_method_castplayer_play(
+					uniffiTypeCastPlayerObjectFactory.clonePointer(this),
+					callStatus,
+				);
+			},
+			/*liftString:*/ FfiConverterString.lift,
+		);
+	}
+
+	/**
+	 * Read what has been played so far from a cursor.
+	 */
+	public readBuffer(
+		cursor: Cursor,
+		maxBytes: /*u64*/ bigint | undefined,
+	): BufferReadResult {
+		return FfiConverterTypeBufferReadResult.lift(
+			uniffiCaller.rustCall(
+				/*caller:*/ (callStatus) => {
+					return nativeModule().ubrn_uniffi_uniffi_russh_fn_method_castplayer_read_buffer(
+						uniffiTypeCastPlayerObjectFactory.clonePointer(this),
+						FfiConverterTypeCursor.lower(cursor),
+						FfiConverterOptionalUInt64.lower(maxBytes),
+						callStatus,
+					);
+				},
+				/*liftString:*/ FfiConverterString.lift,
+			),
+		);
+	}
+
+	/**
+	 * Same as `ShellSession::read_text`, over what has been played so far.
+	 */
+	public readText(
+		cursor: Cursor,
+		maxBytes: /*u64*/ bigint | undefined,
+	): TextReadResult {
+		return FfiConverterTypeTextReadResult.lift(
+			uniffiCaller.rustCall(
+				/*caller:*/ (callStatus) => {
+					return nativeModule().ubrn_uniffi_uniffi_russh_fn_method_castplayer_read_text(
+						uniffiTypeCastPlayerObjectFactory.clonePointer(this),
+						FfiConverterTypeCursor.lower(cursor),
+						FfiConverterOptionalUInt64.lower(maxBytes),
+						callStatus,
+					);
+				},
+				/*liftString:*/ FfiConverterString.lift,
+			),
+		);
+	}
+
+	public removeListener(id: /*u64*/ bigint): void {
+		uniffiCaller.rustCall(
+			/*caller:*/ (callStatus) => {
+				nativeModule().ubrn_uniffi_uniffi_russh_fn_method_castplayer_remove_listener(
+					uniffiTypeCastPlayerObjectFactory.clonePointer(this),
+					FfiConverterUInt64.lower(id),
+					callStatus,
+				);
+			},
+			/*liftString:*/ FfiConverterString.lift,
+		);
+	}
+
+	/**
+	 * Same as `ShellSession::search`, over what has been played so far.
+	 */
+	public async search(
+		pattern: string,
+		opts: SearchOptions,
+		asyncOpts_?: { signal: AbortSignal },
+	): Promise<SearchResult> /*throws*/ {
+		const __stack = uniffiIsDebug ? new Error().stack : undefined;
+		try {
+			return await uniffiRustCallAsync(
+				/*rustCaller:*/ uniffiCaller,
+				/*rustFutureFunc:*/ () => {
+					return nativeModule().ubrn_uniffi_uniffi_russh_fn_method_castplayer_search(
+						uniffiTypeCastPlayerObjectFactory.clonePointer(this),
+						FfiConverterString.lower(pattern),
+						FfiConverterTypeSearchOptions.lower(opts),
+					);
+				},
+				/*pollFunc:*/ nativeModule()
+					.ubrn_ffi_uniffi_russh_rust_future_poll_rust_buffer,
+				/*cancelFunc:*/ nativeModule()
+					.ubrn_ffi_uniffi_russh_rust_future_cancel_rust_buffer,
+				/*completeFunc:*/ nativeModule()
+					.ubrn_ffi_uniffi_russh_rust_future_complete_rust_buffer,
+				/*freeFunc:*/ nativeModule()
+					.ubrn_ffi_uniffi_russh_rust_future_free_rust_buffer,
+				/*liftFunc:*/ FfiConverterTypeSearchResult.lift.bind(
+					FfiConverterTypeSearchResult,
+				),
+				/*liftString:*/ FfiConverterString.lift,
+				/*asyncOpts:*/ asyncOpts_,
+				/*errorHandler:*/ FfiConverterTypeSshError.lift.bind(
+					FfiConverterTypeSshError,
+				),
+			);
+		} catch (__error: any) {
+			if (uniffiIsDebug && __error instanceof Error) {
+				__error.stack = __stack;
+			}
+			throw __error;
+		}
+	}
+
+	/**
+	 * Jump to `position_ms`. Seeking back clears the buffer and replays from
+	 * the start behind a terminal reset (ESC c), so listeners see a `Dropped`
+	 * range if they read from an older seq.
+	 */
+	public seek(positionMs: /*f64*/ number): void {
+		uniffiCaller.rustCall(
+			/*caller:*/ (callStatus) => {
+				nativeModule().ubrn_uniffi_uniffi_russh_fn_method_castplayer_seek(
+					uniffiTypeCastPlayerObjectFactory.clonePointer(this),
+					FfiConverterFloat64.lower(positionMs),
+					callStatus,
+				);
+			},
+			/*liftString:*/ FfiConverterString.lift,
+		);
+	}
+
+	/**
+	 * Playback rate; 2.0 plays twice as fast.
+	 */
+	public setSpeed(speed: /*f64*/ number): void /*throws*/ {
+		uniffiCaller.rustCallWithError(
+			/*liftError:*/ FfiConverterTypeSshError.lift.bind(
+				FfiConverterTypeSshError,
+			),
+			/*caller:*/ (callStatus) => {
+				nativeModule().ubrn_uniffi_uniffi_russh_fn_method_castplayer_set_speed(
+					uniffiTypeCastPlayerObjectFactory.clonePointer(this),
+					FfiConverterFloat64.lower(speed),
+					callStatus,
+				);
+			},
+			/*liftString:*/ FfiConverterString.lift,
+		);
+	}
+
+	/**
+	 * {@inheritDoc uniffi-bindgen-react-native#UniffiAbstractObject.uniffiDestroy}
+	 */
+	uniffiDestroy(): void {
+		const ptr = (this as any)[destructorGuardSymbol];
+		if (ptr !== undefined) {
+			const pointer = uniffiTypeCastPlayerObjectFactory.pointer(this);
+			uniffiTypeCastPlayerObjectFactory.freePointer(pointer);
+			uniffiTypeCastPlayerObjectFactory.unbless(ptr);
+			delete (this as any)[destructorGuardSymbol];
+		}
+	}
+
+	static instanceOf(obj: any): obj is CastPlayer {
+		return uniffiTypeCastPlayerObjectFactory.isConcreteType(obj);
+	}
+}
+
+const uniffiTypeCastPlayerObjectFactory: UniffiObjectFactory<CastPlayerInterface> =
+	(() => {
+		return {
+			create(pointer: UnsafeMutableRawPointer): CastPlayerInterface {
+				const instance = Object.create(CastPlayer.prototype);
+				instance[pointerLiteralSymbol] = pointer;
+				instance[destructorGuardSymbol] = this.bless(pointer);
+				instance[uniffiTypeNameSymbol] = 'CastPlayer';
+				return instance;
+			},
+
+			bless(p: UnsafeMutableRawPointer): UniffiRustArcPtr {
+				return uniffiCaller.rustCall(
+					/*caller:*/ (status) =>
+						nativeModule().ubrn_uniffi_internal_fn_method_castplayer_ffi__bless_pointer(
+							p,
+							status,
+						),
+					/*liftString:*/ FfiConverterString.lift,
+				);
+			},
+
+			unbless(ptr: UniffiRustArcPtr) {
+				ptr.markDestroyed();
+			},
+
+			pointer(obj: CastPlayerInterface): UnsafeMutableRawPointer {
+				if ((obj as any)[destructorGuardSymbol] === undefined) {
+					throw new UniffiInternalError.UnexpectedNullPointer();
+				}
+				return (obj as any)[pointerLiteralSymbol];
+			},
+
+			clonePointer(obj: CastPlayerInterface): UnsafeMutableRawPointer {
+				const pointer = this.pointer(obj);
+				return uniffiCaller.rustCall(
+					/*caller:*/ (callStatus) =>
+						nativeModule().ubrn_uniffi_uniffi_russh_fn_clone_castplayer(
+							pointer,
+							callStatus,
+						),
+					/*liftString:*/ FfiConverterString.lift,
+				);
+			},
+
+			freePointer(pointer: UnsafeMutableRawPointer): void {
+				uniffiCaller.rustCall(
+					/*caller:*/ (callStatus) =>
+						nativeModule().ubrn_uniffi_uniffi_russh_fn_free_castplayer(
+							pointer,
+							callStatus,
+						),
+					/*liftString:*/ FfiConverterString.lift,
+				);
+			},
+
+			isConcreteType(obj: any): obj is CastPlayerInterface {
+				return (
+					obj[destructorGuardSymbol] &&
+					obj[uniffiTypeNameSymbol] === 'CastPlayer'
+				);
+			},
+		};
+	})();
+// FfiConverter for CastPlayerInterface
+const FfiConverterTypeCastPlayer = new FfiConverterObject(
+	uniffiTypeCastPlayerObjectFactory,
+);
+
+export interface CastPlayerStatusCallback {
+	onChange(status: PlaybackStatus): void;
+}
+
+export class CastPlayerStatusCallbackImpl
+	extends UniffiAbstractObject
+	implements CastPlayerStatusCallback
+{
+	readonly [uniffiTypeNameSymbol] = 'CastPlayerStatusCallbackImpl';
+	readonly [destructorGuardSymbol]: UniffiRustArcPtr;
+	readonly [pointerLiteralSymbol]: UnsafeMutableRawPointer;
+	// No primary constructor declared for this class.
+	private constructor(pointer: UnsafeMutableRawPointer) {
+		super();
+		this[pointerLiteralSymbol] = pointer;
+		this[destructorGuardSymbol] =
+			uniffiTypeCastPlayerStatusCallbackImplObjectFactory.bless(pointer);
+	}
+
+	public onChange(status: PlaybackStatus): void {
+		uniffiCaller.rustCall(
+			/*caller:*/ (callStatus) => {
+				nativeModule().ubrn_uniffi_uniffi_russh_fn_method_castplayerstatuscallback_on_change(
+					uniffiTypeCastPlayerStatusCallbackImplObjectFactory.clonePointer(
+						this,
+					),
+					FfiConverterTypePlaybackStatus.lower(status),
+					callStatus,
+				);
+			},
+			/*liftString:*/ FfiConverterString.lift,
+		);
+	}
+
+	/**
+	 * {@inheritDoc uniffi-bindgen-react-native#UniffiAbstractObject.uniffiDestroy}
+	 */
+	uniffiDestroy(): void {
+		const ptr = (this as any)[destructorGuardSymbol];
+		if (ptr !== undefined) {
+			const pointer =
+				uniffiTypeCastPlayerStatusCallbackImplObjectFactory.pointer(this);
+			uniffiTypeCastPlayerStatusCallbackImplObjectFactory.freePointer(pointer);
+			uniffiTypeCastPlayerStatusCallbackImplObjectFactory.unbless(ptr);
+			delete (this as any)[destructorGuardSymbol];
+		}
+	}
+
+	static instanceOf(obj: any): obj is CastPlayerStatusCallbackImpl {
+		return uniffiTypeCastPlayerStatusCallbackImplObjectFactory.isConcreteType(
+			obj,
+		);
+	}
+}
+
+const uniffiTypeCastPlayerStatusCallbackImplObjectFactory: UniffiObjectFactory<CastPlayerStatusCallback> =
+	(() => {
+		return {
+			create(pointer: UnsafeMutableRawPointer): CastPlayerStatusCallback {
+				const instance = Object.create(CastPlayerStatusCallbackImpl.prototype);
+				instance[pointerLiteralSymbol] = pointer;
+				instance[destructorGuardSymbol] = this.bless(pointer);
+				instance[uniffiTypeNameSymbol] = 'CastPlayerStatusCallbackImpl';
+				return instance;
+			},
+
+			bless(p: UnsafeMutableRawPointer): UniffiRustArcPtr {
+				return uniffiCaller.rustCall(
+					/*caller:*/ (status) =>
+						nativeModule().ubrn_uniffi_internal_fn_method_castplayerstatuscallback_ffi__bless_pointer(
+							p,
+							status,
+						),
+					/*liftString:*/ FfiConverterString.lift,
+				);
+			},
+
+			unbless(ptr: UniffiRustArcPtr) {
+				ptr.markDestroyed();
+			},
+
+			pointer(obj: CastPlayerStatusCallback): UnsafeMutableRawPointer {
+				if ((obj as any)[destructorGuardSymbol] === undefined) {
+					throw new UniffiInternalError.UnexpectedNullPointer();
+				}
+				return (obj as any)[pointerLiteralSymbol];
+			},
+
+			clonePointer(obj: CastPlayerStatusCallback): UnsafeMutableRawPointer {
+				const pointer = this.pointer(obj);
+				return uniffiCaller.rustCall(
+					/*caller:*/ (callStatus) =>
+						nativeModule().ubrn_uniffi_uniffi_russh_fn_clone_castplayerstatuscallback(
+							pointer,
+							callStatus,
+						),
+					/*liftString:*/ FfiConverterString.lift,
+				);
+			},
+
+			freePointer(pointer: UnsafeMutableRawPointer): void {
+				uniffiCaller.rustCall(
+					/*caller:*/ (callStatus) =>
+						nativeModule().ubrn_uniffi_uniffi_russh_fn_free_castplayerstatuscallback(
+							pointer,
+							callStatus,
+						),
+					/*liftString:*/ FfiConverterString.lift,
+				);
+			},
+
+			isConcreteType(obj: any): obj is CastPlayerStatusCallback {
+				return (
+					obj[destructorGuardSymbol] &&
+					obj[uniffiTypeNameSymbol] === 'CastPlayerStatusCallbackImpl'
+				);
+			},
+		};
+	})();
+// FfiConverter for CastPlayerStatusCallback
+const FfiConverterTypeCastPlayerStatusCallback =
+	new FfiConverterObjectWithCallbacks(
+		uniffiTypeCastPlayerStatusCallbackImplObjectFactory,
+	);
+
+// Add a vtavble for the callbacks that go in CastPlayerStatusCallback.
+
+// Put the implementation in a struct so we don't pollute the top-level namespace
+const uniffiCallbackInterfaceCastPlayerStatusCallback: {
+	vtable: UniffiVTableCallbackInterfaceCastPlayerStatusCallback;
+	register: () => void;
+} = {
+	// Create the VTable using a series of closures.
+	// ts automatically converts these into C callback functions.
+	vtable: {
+		onChange: (uniffiHandle: bigint, status: Uint8Array) => {
+			const uniffiMakeCall = (): void => {
+				const jsCallback =
+					FfiConverterTypeCastPlayerStatusCallback.lift(uniffiHandle);
+				return jsCallback.onChange(FfiConverterTypePlaybackStatus.lift(status));
+			};
+			const uniffiResult = UniffiResult.ready<void>();
+			const uniffiHandleSuccess = (obj: any) => {};
+			const uniffiHandleError = (code: number, errBuf: UniffiByteArray) => {
+				UniffiResult.writeError(uniffiResult, code, errBuf);
+			};
+			uniffiTraitInterfaceCall(
+				/*makeCall:*/ uniffiMakeCall,
+				/*handleSuccess:*/ uniffiHandleSuccess,
+				/*handleError:*/ uniffiHandleError,
+				/*lowerString:*/ FfiConverterString.lower,
+			);
+			return uniffiResult;
+		},
+		uniffiFree: (uniffiHandle: UniffiHandle): void => {
+			// CastPlayerStatusCallback: this will throw a stale handle error if the handle isn't found.
+			FfiConverterTypeCastPlayerStatusCallback.drop(uniffiHandle);
+		},
+	},
+	register: () => {
+		nativeModule().ubrn_uniffi_uniffi_russh_fn_init_callback_vtable_castplayerstatuscallback(
+			uniffiCallbackInterfaceCastPlayerStatusCallback.vtable,
+		);
+	},
+};
+
+/**
+ * Called with true when a lossless listener falls behind and the channel
+ * stops granting the server window, and with false when it resumes. Only
+ * this channel waits; the rest of the connection keeps running.
+ */
+export interface ChannelPauseCallback {
+	onChange(paused: boolean): void;
+}
+
+/**
+ * Called with true when a lossless listener falls behind and the channel
+ * stops granting the server window, and with false when it resumes. Only
+ * this channel waits; the rest of the connection keeps running.
+ */
+export class ChannelPauseCallbackImpl
+	extends UniffiAbstractObject
+	implements ChannelPauseCallback
+{
+	readonly [uniffiTypeNameSymbol] = 'ChannelPauseCallbackImpl';
+	readonly [destructorGuardSymbol]: UniffiRustArcPtr;
+	readonly [pointerLiteralSymbol]: UnsafeMutableRawPointer;
+	// No primary constructor declared for this class.
+	private constructor(pointer: UnsafeMutableRawPointer) {
+		super();
+		this[pointerLiteralSymbol] = pointer;
+		this[destructorGuardSymbol] =
+			uniffiTypeChannelPauseCallbackImplObjectFactory.bless(pointer);
+	}
+
+	public onChange(paused: boolean): void {
+		uniffiCaller.rustCall(
+			/*caller:*/ (callStatus) => {
+				nativeModule().ubrn_uniffi_uniffi_russh_fn_method_channelpausecallback_on_change(
+					uniffiTypeChannelPauseCallbackImplObjectFactory.clonePointer(this),
+					FfiConverterBool.lower(paused),
+					callStatus,
+				);
+			},
+			/*liftString:*/ FfiConverterString.lift,
+		);
+	}
+
+	/**
+	 * {@inheritDoc uniffi-bindgen-react-native#UniffiAbstractObject.uniffiDestroy}
+	 */
+	uniffiDestroy(): void {
+		const ptr = (this as any)[destructorGuardSymbol];
+		if (ptr !== undefined) {
+			const pointer =
+				uniffiTypeChannelPauseCallbackImplObjectFactory.pointer(this);
+			uniffiTypeChannelPauseCallbackImplObjectFactory.freePointer(pointer);
+			uniffiTypeChannelPauseCallbackImplObjectFactory.unbless(ptr);
+			delete (this as any)[destructorGuardSymbol];
+		}
+	}
+
+	static instanceOf(obj: any): obj is ChannelPauseCallbackImpl {
+		return uniffiTypeChannelPauseCallbackImplObjectFactory.isConcreteType(obj);
+	}
+}
+
+const uniffiTypeChannelPauseCallbackImplObjectFactory: UniffiObjectFactory<ChannelPauseCallback> =
+	(() => {
+		return {
+			create(pointer: UnsafeMutableRawPointer): ChannelPauseCallback {
+				const instance = Object.create(ChannelPauseCallbackImpl.prototype);
+				instance[pointerLiteralSymbol] = pointer;
+				instance[destructorGuardSymbol] = this.bless(pointer);
+				instance[uniffiTypeNameSymbol] = 'ChannelPauseCallbackImpl';
+				return instance;
+			},
+
+			bless(p: UnsafeMutableRawPointer): UniffiRustArcPtr {
+				return uniffiCaller.rustCall(
+					/*caller:*/ (status) =>
+						nativeModule().ubrn_uniffi_internal_fn_method_channelpausecallback_ffi__bless_pointer(
+							p,
+							status,
+						),
+					/*liftString:*/ FfiConverterString.lift,
+				);
+			},
+
+			unbless(ptr: UniffiRustArcPtr) {
+				ptr.markDestroyed();
+			},
+
+			pointer(obj: ChannelPauseCallback): UnsafeMutableRawPointer {
+				if ((obj as any)[destructorGuardSymbol] === undefined) {
+					throw new UniffiInternalError.UnexpectedNullPointer();
+				}
+				return (obj as any)[pointerLiteralSymbol];
+			},
+
+			clonePointer(obj: ChannelPauseCallback): UnsafeMutableRawPointer {
+				const pointer = this.pointer(obj);
+				return uniffiCaller.rustCall(
+					/*caller:*/ (callStatus) =>
+						nativeModule().ubrn_uniffi_uniffi_russh_fn_clone_channelpausecallback(
+							pointer,
+							callStatus,
+						),
+					/*liftString:*/ FfiConverterString.lift,
+				);
+			},
+
+			freePointer(pointer: UnsafeMutableRawPointer): void {
+				uniffiCaller.rustCall(
+					/*caller:*/ (callStatus) =>
+						nativeModule().ubrn_uniffi_uniffi_russh_fn_free_channelpausecallback(
+							pointer,
+							callStatus,
+						),
+					/*liftString:*/ FfiConverterString.lift,
+				);
+			},
+
+			isConcreteType(obj: any): obj is ChannelPauseCallback {
+				return (
+					obj[destructorGuardSymbol] &&
+					obj[uniffiTypeNameSymbol] === 'ChannelPauseCallbackImpl'
+				);
+			},
+		};
+	})();
+// FfiConverter for ChannelPauseCallback
+const FfiConverterTypeChannelPauseCallback =
+	new FfiConverterObjectWithCallbacks(
+		uniffiTypeChannelPauseCallbackImplObjectFactory,
+	);
+
+// Add a vtavble for the callbacks that go in ChannelPauseCallback.
+
+// Put the implementation in a struct so we don't pollute the top-level namespace
+const uniffiCallbackInterfaceChannelPauseCallback: {
+	vtable: UniffiVTableCallbackInterfaceChannelPauseCallback;
+	register: () => void;
+} = {
+	// Create the VTable using a series of closures.
+	// ts automatically converts these into C callback functions.
+	vtable: {
+		onChange: (uniffiHandle: bigint, paused: number) => {
+			const uniffiMakeCall = (): void => {
+				const jsCallback =
+					FfiConverterTypeChannelPauseCallback.lift(uniffiHandle);
+				return jsCallback.onChange(FfiConverterBool.lift(paused));
+			};
+			const uniffiResult = UniffiResult.ready<void>();
+			const uniffiHandleSuccess = (obj: any) => {};
+			const uniffiHandleError = (code: number, errBuf: UniffiByteArray) => {
+				UniffiResult.writeError(uniffiResult, code, errBuf);
+			};
+			uniffiTraitInterfaceCall(
+				/*makeCall:*/ uniffiMakeCall,
+				/*handleSuccess:*/ uniffiHandleSuccess,
+				/*handleError:*/ uniffiHandleError,
+				/*lowerString:*/ FfiConverterString.lower,
+			);
+			return uniffiResult;
+		},
+		uniffiFree: (uniffiHandle: UniffiHandle): void => {
+			// ChannelPauseCallback: this will throw a stale handle error if the handle isn't found.
+			FfiConverterTypeChannelPauseCallback.drop(uniffiHandle);
+		},
+	},
+	register: () => {
+		nativeModule().ubrn_uniffi_uniffi_russh_fn_init_callback_vtable_channelpausecallback(
+			uniffiCallbackInterfaceChannelPauseCallback.vtable,
+		);
+	},
+};
+
+/**
+ * Cancels an in-flight `connect`, which then rejects with
+ * `SshError::Cancelled`. Aborting after connect has resolved does nothing.
+ * A token stays aborted, so use a fresh one per attempt.
+ */
+export interface ConnectAbortTokenInterface {
+	abort(): void;
+	isAborted(): boolean;
+}
+
+/**
+ * Cancels an in-flight `connect`, which then rejects with
+ * `SshError::Cancelled`. Aborting after connect has resolved does nothing.
+ * A token stays aborted, so use a fresh one per attempt.
+ */
+export class ConnectAbortToken
+	extends UniffiAbstractObject
+	implements ConnectAbortTokenInterface
+{
+	readonly [uniffiTypeNameSymbol] = 'ConnectAbortToken';
+	readonly [destructorGuardSymbol]: UniffiRustArcPtr;
+	readonly [pointerLiteralSymbol]: UnsafeMutableRawPointer;
+	constructor() {
+		super();
+		const pointer = uniffiCaller.rustCall(
+			/*caller:*/ (callStatus) => {
+				return nativeModule().ubrn_uniffi_uniffi_russh_fn_constructor_connectaborttoken_new(
+					callStatus,
+				);
+			},
+			/*liftString:*/ FfiConverterString.lift,
+		);
+		this[pointerLiteralSymbol] = pointer;
+		this[destructorGuardSymbol] =
+			uniffiTypeConnectAbortTokenObjectFactory.bless(pointer);
+	}
+
+	public abort(): void {
+		uniffiCaller.rustCall(
+			/*caller:*/ (callStatus) => {
+				nativeModule().ubrn_uniffi_uniffi_russh_fn_method_connectaborttoken_abort(
+					uniffiTypeConnectAbortTokenObjectFactory.clonePointer(this),
+					callStatus,
+				);
+			},
+			/*liftString:*/ FfiConverterString.lift,
+		);
+	}
+
+	public isAborted(): boolean {
+		return FfiConverterBool.lift(
+			uniffiCaller.rustCall(
+				/*caller:*/ (callStatus) => {
+					return nativeModule().ubrn_uniffi_uniffi_russh_fn_method_connectaborttoken_is_aborted(
+						uniffiTypeConnectAbortTokenObjectFactory.clonePointer(this),
+						callStatus,
+					);
+				},
+				/*liftString:*/ FfiConverterString.lift,
+			),
+		);
+	}
+
+	/**
+	 * {@inheritDoc uniffi-bindgen-react-native#UniffiAbstractObject.uniffiDestroy}
+	 */
+	uniffiDestroy(): void {
+		const ptr = (this as any)[destructorGuardSymbol];
+		if (ptr !== undefined) {
+			const pointer = uniffiTypeConnectAbortTokenObjectFactory.pointer(this);
+			uniffiTypeConnectAbortTokenObjectFactory.freePointer(pointer);
+			uniffiTypeConnectAbortTokenObjectFactory.unbless(ptr);
+			delete (this as any)[destructorGuardSymbol];
+		}
+	}
+
+	static instanceOf(obj: any): obj is ConnectAbortToken {
+		return uniffiTypeConnectAbortTokenObjectFactory.isConcreteType(obj);
+	}
+}
+
+const uniffiTypeConnectAbortTokenObjectFactory: UniffiObjectFactory<ConnectAbortTokenInterface> =
+	(() => {
+		return {
+			create(pointer: UnsafeMutableRawPointer): ConnectAbortTokenInterface {
+				const instance = Object.create(ConnectAbortToken.prototype);
+				instance[pointerLiteralSymbol] = pointer;
+				instance[destructorGuardSymbol] = this.bless(pointer);
+				instance[uniffiTypeNameSymbol] = 'ConnectAbortToken';
+				return instance;
+			},
+
+			bless(p: UnsafeMutableRawPointer): UniffiRustArcPtr {
+				return uniffiCaller.rustCall(
+					/*caller:*/ (status) =>
+						nativeModule().ubrn_uniffi_internal_fn_method_connectaborttoken_ffi__bless_pointer(
+							p,
+							status,
+						),
+					/*liftString:*/ FfiConverterString.lift,
+				);
+			},
+
+			unbless(ptr: UniffiRustArcPtr) {
+				ptr.markDestroyed();
+			},
+
+			pointer(obj: ConnectAbortTokenInterface): UnsafeMutableRawPointer {
+				if ((obj as any)[destructorGuardSymbol] === undefined) {
+					throw new UniffiInternalError.UnexpectedNullPointer();
+				}
+				return (obj as any)[pointerLiteralSymbol];
+			},
+
+			clonePointer(obj: ConnectAbortTokenInterface): UnsafeMutableRawPointer {
+				const pointer = this.pointer(obj);
+				return uniffiCaller.rustCall(
+					/*caller:*/ (callStatus) =>
+						nativeModule().ubrn_uniffi_uniffi_russh_fn_clone_connectaborttoken(
+							pointer,
+							callStatus,
+						),
+					/*liftString:*/ FfiConverterString.lift,
+				);
+			},
+
+			freePointer(pointer: UnsafeMutableRawPointer): void {
+				uniffiCaller.rustCall(
+					/*caller:*/ (callStatus) =>
+						nativeModule().ubrn_uniffi_uniffi_russh_fn_free_connectaborttoken(
+							pointer,
+							callStatus,
+						),
+					/*liftString:*/ FfiConverterString.lift,
+				);
+			},
+
+			isConcreteType(obj: any): obj is ConnectAbortTokenInterface {
+				return (
+					obj[destructorGuardSymbol] &&
+					obj[uniffiTypeNameSymbol] === 'ConnectAbortToken'
+				);
+			},
+		};
+	})();
+// FfiConverter for ConnectAbortTokenInterface
+const FfiConverterTypeConnectAbortToken = new FfiConverterObject(
+	uniffiTypeConnectAbortTokenObjectFactory,
+);
+
+export interface ConnectProgressCallback {
+	onChange(status: SshConnectionProgressEvent): void;
+}
+
+export class ConnectProgressCallbackImpl
+	extends UniffiAbstractObject
+	implements ConnectProgressCallback
+{
+	readonly [uniffiTypeNameSymbol] = 'ConnectProgressCallbackImpl';
+	readonly [destructorGuardSymbol]: UniffiRustArcPtr;
+	readonly [pointerLiteralSymbol]: UnsafeMutableRawPointer;
+	// No primary constructor declared for this class.
+	private constructor(pointer: UnsafeMutableRawPointer) {
+		super();
+		this[pointerLiteralSymbol] = pointer;
+		this[destructorGuardSymbol] =
+			uniffiTypeConnectProgressCallbackImplObjectFactory.bless(pointer);
+	}
+
+	public onChange(status: SshConnectionProgressEvent): void {
+		uniffiCaller.rustCall(
+			/*caller:*/ (callStatus) => {
+				nativeModule().ubrn_uniffi_uniffi_russh_fn_method_connectprogresscallback_on_change(
+					uniffiTypeConnectProgressCallbackImplObjectFactory.clonePointer(this),
+					FfiConverterTypeSshConnectionProgressEvent.lower(status),
+					callStatus,
+				);
+			},
+			/*liftString:*/ FfiConverterString.lift,
+		);
+	}
+
+	/**
+	 * {@inheritDoc uniffi-bindgen-react-native#UniffiAbstractObject.uniffiDestroy}
+	 */
+	uniffiDestroy(): void {
+		const ptr = (this as any)[destructorGuardSymbol];
+		if (ptr !== undefined) {
+			const pointer =
+				uniffiTypeConnectProgressCallbackImplObjectFactory.pointer(this);
+			uniffiTypeConnectProgressCallbackImplObjectFactory.freePointer(pointer);
+			uniffiTypeConnectProgressCallbackImplObjectFactory.unbless(ptr);
+			delete (this as any)[destructorGuardSymbol];
+		}
+	}
+
+	static instanceOf(obj: any): obj is ConnectProgressCallbackImpl {
+		return uniffiTypeConnectProgressCallbackImplObjectFactory.isConcreteType(
+			obj,
+		);
+	}
+}
+
+const uniffiTypeConnectProgressCallbackImplObjectFactory: UniffiObjectFactory<ConnectProgressCallback> =
+	(() => {
+		return {
+			create(pointer: UnsafeMutableRawPointer): ConnectProgressCallback {
+				const instance = Object.create(ConnectProgressCallbackImpl.prototype);
+				instance[pointerLiteralSymbol] = pointer;
+				instance[destructorGuardSymbol] = this.bless(pointer);
+				instance[uniffiTypeNameSymbol] = 'ConnectProgressCallbackImpl';
+				return instance;
+			},
+
+			bless(p: UnsafeMutableRawPointer): UniffiRustArcPtr {
+				return uniffiCaller.rustCall(
+					/*caller:*/ (status) =>
+						nativeModule().ubrn_uniffi_internal_fn_method_connectprogresscallback_ffi__bless_pointer(
+							p,
+							status,
+						),
+					/*liftString:*/ FfiConverterString.lift,
+				);
+			},
+
+			unbless(ptr: UniffiRustArcPtr) {
+				ptr.markDestroyed();
+			},
+
+			pointer(obj: ConnectProgressCallback): UnsafeMutableRawPointer {
+				if ((obj as any)[destructorGuardSymbol] === undefined) {
+					throw new UniffiInternalError.UnexpectedNullPointer();
+				}
+				return (obj as any)[pointerLiteralSymbol];
+			},
+
+			clonePointer(obj: ConnectProgressCallback): UnsafeMutableRawPointer {
+				const pointer = this.pointer(obj);
+				return uniffiCaller.rustCall(
+					/*caller:*/ (callStatus) =>
+						nativeModule().ubrn_uniffi_uniffi_russh_fn_clone_connectprogresscallback(
+							pointer,
+							callStatus,
+						),
+					/*liftString:*/ FfiConverterString.lift,
+				);
+			},
+
+			freePointer(pointer: UnsafeMutableRawPointer): void {
+				uniffiCaller.rustCall(
+					/*caller:*/ (callStatus) =>
+						nativeModule().ubrn_uniffi_uniffi_russh_fn_free_connectprogresscallback(
+							pointer,
+							callStatus,
+						),
+					/*liftString:*/ FfiConverterString.lift,
+				);
+			},
+
+			isConcreteType(obj: any): obj is ConnectProgressCallback {
+				return (
+					obj[destructorGuardSymbol] &&
+					obj[uniffiTypeNameSymbol] === 'ConnectProgressCallbackImpl'
+				);
+			},
+		};
+	})();
+// FfiConverter for ConnectProgressCallback
+const FfiConverterTypeConnectProgressCallback =
+	new FfiConverterObjectWithCallbacks(
+		uniffiTypeConnectProgressCallbackImplObjectFactory,
+	);
+
+// Add a vtavble for the callbacks that go in ConnectProgressCallback.
+
+// Put the implementation in a struct so we don't pollute the top-level namespace
+const uniffiCallbackInterfaceConnectProgressCallback: {
+	vtable: UniffiVTableCallbackInterfaceConnectProgressCallback;
+	register: () => void;
+} = {
+	// Create the VTable using a series of closures.
+	// ts automatically converts these into C callback functions.
+	vtable: {
+		onChange: (uniffiHandle: bigint, status: Uint8Array) => {
+			const uniffiMakeCall = (): void => {
+				const jsCallback =
+					FfiConverterTypeConnectProgressCallback.lift(uniffiHandle);
+				return jsCallback.onChange(
+					FfiConverterTypeSshConnectionProgressEvent.lift(status),
+				);
+			};
+			const uniffiResult = UniffiResult.ready<void>();
+			const uniffiHandleSuccess = (obj: any) => {};
+			const uniffiHandleError = (code: number, errBuf: UniffiByteArray) => {
+				UniffiResult.writeError(uniffiResult, code, errBuf);
+			};
+			uniffiTraitInterfaceCall(
+				/*makeCall:*/ uniffiMakeCall,
+				/*handleSuccess:*/ uniffiHandleSuccess,
+				/*handleError:*/ uniffiHandleError,
+				/*lowerString:*/ FfiConverterString.lower,
+			);
+			return uniffiResult;
+		},
+		uniffiFree: (uniffiHandle: UniffiHandle): void => {
+			// ConnectProgressCallback: this will throw a stale handle error if the handle isn't found.
+			FfiConverterTypeConnectProgressCallback.drop(uniffiHandle);
+		},
+	},
+	register: () => {
+		nativeModule().ubrn_uniffi_uniffi_russh_fn_init_callback_vtable_connectprogresscallback(
+			uniffiCallbackInterfaceConnectProgressCallback.vtable,
+		);
+	},
+};
+
+export interface ConnectionDisconnectedCallback {
+	onChange(connectionId: string): void;
+}
+
+export class ConnectionDisconnectedCallbackImpl
+	extends UniffiAbstractObject
+	implements ConnectionDisconnectedCallback
+{
+	readonly [uniffiTypeNameSymbol] = 'ConnectionDisconnectedCallbackImpl';
+	readonly [destructorGuardSymbol]: UniffiRustArcPtr;
+	readonly [pointerLiteralSymbol]: UnsafeMutableRawPointer;
+	// No primary constructor declared for this class.
+	private constructor(pointer: UnsafeMutableRawPointer) {
+		super();
+		this[pointerLiteralSymbol] = pointer;
+		this[destructorGuardSymbol] =
+			uniffiTypeConnectionDisconnectedCallbackImplObjectFactory.bless(pointer);
+	}
+
+	public onChange(connectionId: string): void {
+		uniffiCaller.rustCall(
+			/*caller:*/ (callStatus) => {
+				nativeModule().ubrn_uniffi_uniffi_russh_fn_method_connectiondisconnectedcallback_on_change(
+					uniffiTypeConnectionDisconnectedCallbackImplObjectFactory.clonePointer(
+						this,
+					),
+					FfiConverterString.lower(connectionId),
+					callStatus,
+				);
+			},
+			/*liftString:*/ FfiConverterString.lift,
+		);
+	}
+
+	/**
+	 * {@inheritDoc uniffi-bindgen-react-native#UniffiAbstractObject.uniffiDestroy}
+	 */
+	uniffiDestroy(): void {
+		const ptr = (this as any)[destructorGuardSymbol];
+		if (ptr !== undefined) {
+			const pointer =
+				uniffiTypeConnectionDisconnectedCallbackImplObjectFactory.pointer(this);
+			uniffiTypeConnectionDisconnectedCallbackImplObjectFactory.freePointer(
+				pointer,
+			);
+			uniffiTypeConnectionDisconnectedCallbackImplObjectFactory.unbless(ptr);
+			delete (this as any)[destructorGuardSymbol];
+		}
+	}
+
+	static instanceOf(obj: any): obj is ConnectionDisconnectedCallbackImpl {
+		return uniffiTypeConnectionDisconnectedCallbackImplObjectFactory.isConcreteType(
+			obj,
+		);
+	}
+}
+
+const uniffiTypeConnectionDisconnectedCallbackImplObjectFactory: UniffiObjectFactory<ConnectionDisconnectedCallback> =
+	(() => {
+		return {
+			create(pointer: UnsafeMutableRawPointer): ConnectionDisconnectedCallback {
+				const instance = Object.create(
+					ConnectionDisconnectedCallbackImpl.prototype,
+				);
+				instance[pointerLiteralSymbol] = pointer;
+				instance[destructorGuardSymbol] = this.bless(pointer);
+				instance[uniffiTypeNameSymbol] = 'ConnectionDisconnectedCallbackImpl';
+				return instance;
+			},
+
+			bless(p: UnsafeMutableRawPointer): UniffiRustArcPtr {
+				return uniffiCaller.rustCall(
+					/*caller:*/ (status) =>
+						nativeModule().ubrn_uniffi_internal_fn_method_connectiondisconnectedcallback_ffi__bless_pointer(
+							p,
+							status,
+						),
+					/*liftString:*/ FfiConverterString.lift,
+				);
+			},
+
+			unbless(ptr: UniffiRustArcPtr) {
+				ptr.markDestroyed();
+			},
+
+			pointer(obj: ConnectionDisconnectedCallback): UnsafeMutableRawPointer {
+				if ((obj as any)[destructorGuardSymbol] === undefined) {
+					throw new UniffiInternalError.UnexpectedNullPointer();
+				}
+				return (obj as any)[pointerLiteralSymbol];
+			},
+
+			clonePointer(
+				obj: ConnectionDisconnectedCallback,
+			): UnsafeMutableRawPointer {
+				const pointer = this.pointer(obj);
+				return uniffiCaller.rustCall(
+					/*caller:*/ (callStatus) =>
+						nativeModule().ubrn_uniffi_uniffi_russh_fn_clone_connectiondisconnectedcallback(
+							pointer,
+							callStatus,
+						),
+					/*liftString:*/ FfiConverterString.lift,
+				);
+			},
+
+			freePointer(pointer: UnsafeMutableRawPointer): void {
+				uniffiCaller.rustCall(
+					/*caller:*/ (callStatus) =>
+						nativeModule().ubrn_uniffi_uniffi_russh_fn_free_connectiondisconnectedcallback(
+							pointer,
+							callStatus,
+						),
+					/*liftString:*/ FfiConverterString.lift,
+				);
+			},
+
+			isConcreteType(obj: any): obj is ConnectionDisconnectedCallback {
+				return (
+					obj[destructorGuardSymbol] &&
+					obj[uniffiTypeNameSymbol] === 'ConnectionDisconnectedCallbackImpl'
+				);
+			},
+		};
+	})();
+// FfiConverter for ConnectionDisconnectedCallback
+const FfiConverterTypeConnectionDisconnectedCallback =
+	new FfiConverterObjectWithCallbacks(
+		uniffiTypeConnectionDisconnectedCallbackImplObjectFactory,
+	);
+
+// Add a vtavble for the callbacks that go in ConnectionDisconnectedCallback.
+
+// Put the implementation in a struct so we don't pollute the top-level namespace
+const uniffiCallbackInterfaceConnectionDisconnectedCallback: {
+	vtable: UniffiVTableCallbackInterfaceConnectionDisconnectedCallback;
+	register: () => void;
+} = {
+	// Create the VTable using a series of closures.
+	// ts automatically converts these into C callback functions.
+	vtable: {
+		onChange: (uniffiHandle: bigint, connectionId: Uint8Array) => {
+			const uniffiMakeCall = (): void => {
+				const jsCallback =
+					FfiConverterTypeConnectionDisconnectedCallback.lift(uniffiHandle);
+				return jsCallback.onChange(FfiConverterString.lift(connectionId));
+			};
+			const uniffiResult = UniffiResult.ready<void>();
+			const uniffiHandleSuccess = (obj: any) => {};
+			const uniffiHandleError = (code: number, errBuf: UniffiByteArray) => {
+				UniffiResult.writeError(uniffiResult, code, errBuf);
+			};
+			uniffiTraitInterfaceCall(
+				/*makeCall:*/ uniffiMakeCall,
+				/*handleSuccess:*/ uniffiHandleSuccess,
+				/*handleError:*/ uniffiHandleError,
+				/*lowerString:*/ FfiConverterString.lower,
+			);
+			return uniffiResult;
+		},
+		uniffiFree: (uniffiHandle: UniffiHandle): void => {
+			// ConnectionDisconnectedCallback: this will throw a stale handle error if the handle isn't found.
+			FfiConverterTypeConnectionDisconnectedCallback.drop(uniffiHandle);
+		},
+	},
+	register: () => {
+		nativeModule().ubrn_uniffi_uniffi_russh_fn_init_callback_vtable_connectiondisconnectedcallback(
+			uniffiCallbackInterfaceConnectionDisconnectedCallback.vtable,
+		);
+	},
+};
+
+export interface ConnectionEventCallback {
+	onChange(event: SshConnectionEvent): void;
+}
+
+export class ConnectionEventCallbackImpl
+	extends UniffiAbstractObject
+	implements ConnectionEventCallback
+{
+	readonly [uniffiTypeNameSymbol] = 'ConnectionEventCallbackImpl';
+	readonly [destructorGuardSymbol]: UniffiRustArcPtr;
+	readonly [pointerLiteralSymbol]: UnsafeMutableRawPointer;
+	// No primary constructor declared for this class.
+	private constructor(pointer: UnsafeMutableRawPointer) {
+		super();
+		this[pointerLiteralSymbol] = pointer;
+		this[destructorGuardSymbol] =
+			uniffiTypeConnectionEventCallbackImplObjectFactory.bless(pointer);
+	}
+
+	public onChange(event: SshConnectionEvent): void {
+		uniffiCaller.rustCall(
+			/*caller:*/ (callStatus) => {
+				nativeModule().ubrn_uniffi_uniffi_russh_fn_method_connectioneventcallback_on_change(
+					uniffiTypeConnectionEventCallbackImplObjectFactory.clonePointer(this),
+					FfiConverterTypeSshConnectionEvent.lower(event),
+					callStatus,
+				);
+			},
+			/*liftString:*/ FfiConverterString.lift,
+		);
+	}
+
+	/**
+	 * {@inheritDoc uniffi-bindgen-react-native#UniffiAbstractObject.uniffiDestroy}
+	 */
+	uniffiDestroy(): void {
+		const ptr = (this as any)[destructorGuardSymbol];
+		if (ptr !== undefined) {
+			const pointer =
+				uniffiTypeConnectionEventCallbackImplObjectFactory.pointer(this);
+			uniffiTypeConnectionEventCallbackImplObjectFactory.freePointer(pointer);
+			uniffiTypeConnectionEventCallbackImplObjectFactory.unbless(ptr);
+			delete (this as any)[destructorGuardSymbol];
+		}
+	}
+
+	static instanceOf(obj: any): obj is ConnectionEventCallbackImpl {
+		return uniffiTypeConnectionEventCallbackImplObjectFactory.isConcreteType(
+			obj,
+		);
+	}
+}
+
+const uniffiTypeConnectionEventCallbackImplObjectFactory: UniffiObjectFactory<ConnectionEventCallback> =
+	(() => {
+		return {
+			create(pointer: UnsafeMutableRawPointer): ConnectionEventCallback {
+				const instance = Object.create(ConnectionEventCallbackImpl.prototype);
+				instance[pointerLiteralSymbol] = pointer;
+				instance[destructorGuardSymbol] = this.bless(pointer);
+				instance[uniffiTypeNameSymbol] = 'ConnectionEventCallbackImpl';
+				return instance;
+			},
+
+			bless(p: UnsafeMutableRawPointer): UniffiRustArcPtr {
+				return uniffiCaller.rustCall(
+					/*caller:*/ (status) =>
+						nativeModule().ubrn_uniffi_internal_fn_method_connectioneventcallback_ffi__bless_pointer(
+							p,
+							status,
+						),
+					/*liftString:*/ FfiConverterString.lift,
+				);
+			},
+
+			unbless(ptr: UniffiRustArcPtr) {
+				ptr.markDestroyed();
+			},
+
+			pointer(obj: ConnectionEventCallback): UnsafeMutableRawPointer {
+				if ((obj as any)[destructorGuardSymbol] === undefined) {
+					throw new UniffiInternalError.UnexpectedNullPointer();
+				}
+				return (obj as any)[pointerLiteralSymbol];
+			},
+
+			clonePointer(obj: ConnectionEventCallback): UnsafeMutableRawPointer {
+				const pointer = this.pointer(obj);
+				return uniffiCaller.rustCall(
+					/*caller:*/ (callStatus) =>
+						nativeModule().ubrn_uniffi_uniffi_russh_fn_clone_connectioneventcallback(
+							pointer,
+							callStatus,
+						),
+					/*liftString:*/ FfiConverterString.lift,
+				);
+			},
+
+			freePointer(pointer: UnsafeMutableRawPointer): void {
+				uniffiCaller.rustCall(
+					/*caller:*/ (callStatus) =>
+						nativeModule().ubrn_uniffi_uniffi_russh_fn_free_connectioneventcallback(
+							pointer,
+							callStatus,
+						),
+					/*liftString:*/ FfiConverterString.lift,
+				);
+			},
+
+			isConcreteType(obj: any): obj is ConnectionEventCallback {
+				return (
+					obj[destructorGuardSymbol] &&
+					obj[uniffiTypeNameSymbol] === 'ConnectionEventCallbackImpl'
+				);
+			},
+		};
+	})();
+// FfiConverter for ConnectionEventCallback
+const FfiConverterTypeConnectionEventCallback =
+	new FfiConverterObjectWithCallbacks(
+		uniffiTypeConnectionEventCallbackImplObjectFactory,
+	);
+
+// Add a vtavble for the callbacks that go in ConnectionEventCallback.
+
+// Put the implementation in a struct so we don't pollute the top-level namespace
+const uniffiCallbackInterfaceConnectionEventCallback: {
+	vtable: UniffiVTableCallbackInterfaceConnectionEventCallback;
+	register: () => void;
+} = {
+	// Create the VTable using a series of closures.
+	// ts automatically converts these into C callback functions.
+	vtable: {
+		onChange: (uniffiHandle: bigint, event: Uint8Array) => {
+			const uniffiMakeCall = (): void => {
+				const jsCallback =
+					FfiConverterTypeConnectionEventCallback.lift(uniffiHandle);
+				return jsCallback.onChange(
+					FfiConverterTypeSshConnectionEvent.lift(event),
+				);
+			};
+			const uniffiResult = UniffiResult.ready<void>();
+			const uniffiHandleSuccess = (obj: any) => {};
+			const uniffiHandleError = (code: number, errBuf: UniffiByteArray) => {
+				UniffiResult.writeError(uniffiResult, code, errBuf);
+			};
+			uniffiTraitInterfaceCall(
+				/*makeCall:*/ uniffiMakeCall,
+				/*handleSuccess:*/ uniffiHandleSuccess,
+				/*handleError:*/ uniffiHandleError,
+				/*lowerString:*/ FfiConverterString.lower,
+			);
+			return uniffiResult;
+		},
+		uniffiFree: (uniffiHandle: UniffiHandle): void => {
+			// ConnectionEventCallback: this will throw a stale handle error if the handle isn't found.
+			FfiConverterTypeConnectionEventCallback.drop(uniffiHandle);
+		},
+	},
+	register: () => {
+		nativeModule().ubrn_uniffi_uniffi_russh_fn_init_callback_vtable_connectioneventcallback(
+			uniffiCallbackInterfaceConnectionEventCallback.vtable,
+		);
+	},
+};
+
+export interface ConnectionMetricsCallback {
+	onChange(metrics: SshConnectionMetrics): void;
+}
+
+export class ConnectionMetricsCallbackImpl
+	extends UniffiAbstractObject
+	implements ConnectionMetricsCallback
+{
+	readonly [uniffiTypeNameSymbol] = 'ConnectionMetricsCallbackImpl';
+	readonly [destructorGuardSymbol]: UniffiRustArcPtr;
+	readonly [pointerLiteralSymbol]: UnsafeMutableRawPointer;
+	// No primary constructor declared for this class.
+	private constructor(pointer: UnsafeMutableRawPointer) {
 		super();
 		this[pointerLiteralSymbol] = pointer;
 		this[destructorGuardSymbol] =
-			uniffiTypeConnectProgressCallbackImplObjectFactory.bless(pointer);
+			uniffiTypeConnectionMetricsCallbackImplObjectFactory.bless(pointer);
 	}
 
-	public onChange(status: SshConnectionProgressEvent): void {
+	public onChange(metrics: SshConnectionMetrics): void {
 		uniffiCaller.rustCall(
 			/*caller:*/ (callStatus) => {
-				nativeModule().ubrn_uniffi_uniffi_russh_fn_method_connectprogresscallback_on_change(
-					uniffiTypeConnectProgressCallbackImplObjectFactory.clonePointer(this),
-					FfiConverterTypeSshConnectionProgressEvent.lower(status),
+				nativeModule().ubrn_uniffi_uniffi_russh_fn_method_connectionmetricscallback_on_change(
+					uniffiTypeConnectionMetricsCallbackImplObjectFactory.clonePointer(
+						this,
+					),
+					FfiConverterTypeSshConnectionMetrics.lower(metrics),
 					callStatus,
 				);
 			},
@@ -2271,35 +8007,221 @@ export class ConnectProgressCallbackImpl
 		const ptr = (this as any)[destructorGuardSymbol];
 		if (ptr !== undefined) {
 			const pointer =
-				uniffiTypeConnectProgressCallbackImplObjectFactory.pointer(this);
-			uniffiTypeConnectProgressCallbackImplObjectFactory.freePointer(pointer);
-			uniffiTypeConnectProgressCallbackImplObjectFactory.unbless(ptr);
+				uniffiTypeConnectionMetricsCallbackImplObjectFactory.pointer(this);
+			uniffiTypeConnectionMetricsCallbackImplObjectFactory.freePointer(pointer);
+			uniffiTypeConnectionMetricsCallbackImplObjectFactory.unbless(ptr);
 			delete (this as any)[destructorGuardSymbol];
 		}
 	}
 
-	static instanceOf(obj: any): obj is ConnectProgressCallbackImpl {
-		return uniffiTypeConnectProgressCallbackImplObjectFactory.isConcreteType(
+	static instanceOf(obj: any): obj is ConnectionMetricsCallbackImpl {
+		return uniffiTypeConnectionMetricsCallbackImplObjectFactory.isConcreteType(
 			obj,
 		);
 	}
 }
 
-const uniffiTypeConnectProgressCallbackImplObjectFactory: UniffiObjectFactory<ConnectProgressCallback> =
+const uniffiTypeConnectionMetricsCallbackImplObjectFactory: UniffiObjectFactory<ConnectionMetricsCallback> =
 	(() => {
 		return {
-			create(pointer: UnsafeMutableRawPointer): ConnectProgressCallback {
-				const instance = Object.create(ConnectProgressCallbackImpl.prototype);
+			create(pointer: UnsafeMutableRawPointer): ConnectionMetricsCallback {
+				const instance = Object.create(ConnectionMetricsCallbackImpl.prototype);
 				instance[pointerLiteralSymbol] = pointer;
 				instance[destructorGuardSymbol] = this.bless(pointer);
-				instance[uniffiTypeNameSymbol] = 'ConnectProgressCallbackImpl';
+				instance[uniffiTypeNameSymbol] = 'ConnectionMetricsCallbackImpl';
+				return instance;
+			},
+
+			bless(p: UnsafeMutableRawPointer): UniffiRustArcPtr {
+				return uniffiCaller.rustCall(
+					/*caller:*/ (status) =>
+						nativeModule().ubrn_uniffi_internal_fn_method_connectionmetricscallback_ffi__bless_pointer(
+							p,
+							status,
+						),
+					/*liftString:*/ FfiConverterString.lift,
+				);
+			},
+
+			unbless(ptr: UniffiRustArcPtr) {
+				ptr.markDestroyed();
+			},
+
+			pointer(obj: ConnectionMetricsCallback): UnsafeMutableRawPointer {
+				if ((obj as any)[destructorGuardSymbol] === undefined) {
+					throw new UniffiInternalError.UnexpectedNullPointer();
+				}
+				return (obj as any)[pointerLiteralSymbol];
+			},
+
+			clonePointer(obj: ConnectionMetricsCallback): UnsafeMutableRawPointer {
+				const pointer = this.pointer(obj);
+				return uniffiCaller.rustCall(
+					/*caller:*/ (callStatus) =>
+						nativeModule().ubrn_uniffi_uniffi_russh_fn_clone_connectionmetricscallback(
+							pointer,
+							callStatus,
+						),
+					/*liftString:*/ FfiConverterString.lift,
+				);
+			},
+
+			freePointer(pointer: UnsafeMutableRawPointer): void {
+				uniffiCaller.rustCall(
+					/*caller:*/ (callStatus) =>
+						nativeModule().ubrn_uniffi_uniffi_russh_fn_free_connectionmetricscallback(
+							pointer,
+							callStatus,
+						),
+					/*liftString:*/ FfiConverterString.lift,
+				);
+			},
+
+			isConcreteType(obj: any): obj is ConnectionMetricsCallback {
+				return (
+					obj[destructorGuardSymbol] &&
+					obj[uniffiTypeNameSymbol] === 'ConnectionMetricsCallbackImpl'
+				);
+			},
+		};
+	})();
+// FfiConverter for ConnectionMetricsCallback
+const FfiConverterTypeConnectionMetricsCallback =
+	new FfiConverterObjectWithCallbacks(
+		uniffiTypeConnectionMetricsCallbackImplObjectFactory,
+	);
+
+// Add a vtavble for the callbacks that go in ConnectionMetricsCallback.
+
+// Put the implementation in a struct so we don't pollute the top-level namespace
+const uniffiCallbackInterfaceConnectionMetricsCallback: {
+	vtable: UniffiVTableCallbackInterfaceConnectionMetricsCallback;
+	register: () => void;
+} = {
+	// Create the VTable using a series of closures.
+	// ts automatically converts these into C callback functions.
+	vtable: {
+		onChange: (uniffiHandle: bigint, metrics: Uint8Array) => {
+			const uniffiMakeCall = (): void => {
+				const jsCallback =
+					FfiConverterTypeConnectionMetricsCallback.lift(uniffiHandle);
+				return jsCallback.onChange(
+					FfiConverterTypeSshConnectionMetrics.lift(metrics),
+				);
+			};
+			const uniffiResult = UniffiResult.ready<void>();
+			const uniffiHandleSuccess = (obj: any) => {};
+			const uniffiHandleError = (code: number, errBuf: UniffiByteArray) => {
+				UniffiResult.writeError(uniffiResult, code, errBuf);
+			};
+			uniffiTraitInterfaceCall(
+				/*makeCall:*/ uniffiMakeCall,
+				/*handleSuccess:*/ uniffiHandleSuccess,
+				/*handleError:*/ uniffiHandleError,
+				/*lowerString:*/ FfiConverterString.lower,
+			);
+			return uniffiResult;
+		},
+		uniffiFree: (uniffiHandle: UniffiHandle): void => {
+			// ConnectionMetricsCallback: this will throw a stale handle error if the handle isn't found.
+			FfiConverterTypeConnectionMetricsCallback.drop(uniffiHandle);
+		},
+	},
+	register: () => {
+		nativeModule().ubrn_uniffi_uniffi_russh_fn_init_callback_vtable_connectionmetricscallback(
+			uniffiCallbackInterfaceConnectionMetricsCallback.vtable,
+		);
+	},
+};
+
+export interface ServerKeyCallback {
+	onChange(
+		serverKeyInfo: ServerPublicKeyInfo,
+		asyncOpts_?: { signal: AbortSignal },
+	): Promise<boolean>;
+}
+
+export class ServerKeyCallbackImpl
+	extends UniffiAbstractObject
+	implements ServerKeyCallback
+{
+	readonly [uniffiTypeNameSymbol] = 'ServerKeyCallbackImpl';
+	readonly [destructorGuardSymbol]: UniffiRustArcPtr;
+	readonly [pointerLiteralSymbol]: UnsafeMutableRawPointer;
+	// No primary constructor declared for this class.
+	private constructor(pointer: UnsafeMutableRawPointer) {
+		super();
+		this[pointerLiteralSymbol] = pointer;
+		this[destructorGuardSymbol] =
+			uniffiTypeServerKeyCallbackImplObjectFactory.bless(pointer);
+	}
+
+	public async onChange(
+		serverKeyInfo: ServerPublicKeyInfo,
+		asyncOpts_?: { signal: AbortSignal },
+	): Promise<boolean> {
+		const __stack = uniffiIsDebug ? new Error().stack : undefined;
+		try {
+			return await uniffiRustCallAsync(
+				/*rustCaller:*/ uniffiCaller,
+				/*rustFutureFunc:*/ () => {
+					return nativeModule().ubrn_uniffi_uniffi_russh_fn_method_serverkeycallback_on_change(
+						uniffiTypeServerKeyCallbackImplObjectFactory.clonePointer(this),
+						FfiConverterTypeServerPublicKeyInfo.lower(serverKeyInfo),
+					);
+				},
+				/*pollFunc:*/ nativeModule().ubrn_ffi_uniffi_russh_rust_future_poll_i8,
+				/*cancelFunc:*/ nativeModule()
+					.ubrn_ffi_uniffi_russh_rust_future_cancel_i8,
+				/*completeFunc:*/ nativeModule()
+					.ubrn_ffi_uniffi_russh_rust_future_complete_i8,
+				/*freeFunc:*/ nativeModule().ubrn_ffi_uniffi_russh_rust_future_free_i8,
+				/*liftFunc:*/ FfiConverterBool.lift.bind(FfiConverterBool),
+				/*liftString:*/ FfiConverterString.lift,
+				/*asyncOpts:*/ asyncOpts_,
+			);
+		} catch (__error: any) {
+			if (uniffiIsDebug && __error instanceof Error) {
+				__error.stack = __stack;
+			}
+			throw __error;
+		}
+	}
+
+	/**
+	 * {@inheritDoc uniffi-bindgen-react-native#UniffiAbstractObject.uniffiDestroy}
+	 */
+	uniffiDestroy(): void {
+		const ptr = (this as any)[destructorGuardSymbol];
+		if (ptr !== undefined) {
+			const pointer =
+				uniffiTypeServerKeyCallbackImplObjectFactory.pointer(this);
+			uniffiTypeServerKeyCallbackImplObjectFactory.freePointer(pointer);
+			uniffiTypeServerKeyCallbackImplObjectFactory.unbless(ptr);
+			delete (this as any)[destructorGuardSymbol];
+		}
+	}
+
+	static instanceOf(obj: any): obj is ServerKeyCallbackImpl {
+		return uniffiTypeServerKeyCallbackImplObjectFactory.isConcreteType(obj);
+	}
+}
+
+const uniffiTypeServerKeyCallbackImplObjectFactory: UniffiObjectFactory<ServerKeyCallback> =
+	(() => {
+		return {
+			create(pointer: UnsafeMutableRawPointer): ServerKeyCallback {
+				const instance = Object.create(ServerKeyCallbackImpl.prototype);
+				instance[pointerLiteralSymbol] = pointer;
+				instance[destructorGuardSymbol] = this.bless(pointer);
+				instance[uniffiTypeNameSymbol] = 'ServerKeyCallbackImpl';
 				return instance;
 			},
 
 			bless(p: UnsafeMutableRawPointer): UniffiRustArcPtr {
 				return uniffiCaller.rustCall(
 					/*caller:*/ (status) =>
-						nativeModule().ubrn_uniffi_internal_fn_method_connectprogresscallback_ffi__bless_pointer(
+						nativeModule().ubrn_uniffi_internal_fn_method_serverkeycallback_ffi__bless_pointer(
 							p,
 							status,
 						),
@@ -2311,18 +8233,18 @@ const uniffiTypeConnectProgressCallbackImplObjectFactory: UniffiObjectFactory<Co
 				ptr.markDestroyed();
 			},
 
-			pointer(obj: ConnectProgressCallback): UnsafeMutableRawPointer {
+			pointer(obj: ServerKeyCallback): UnsafeMutableRawPointer {
 				if ((obj as any)[destructorGuardSymbol] === undefined) {
 					throw new UniffiInternalError.UnexpectedNullPointer();
 				}
 				return (obj as any)[pointerLiteralSymbol];
 			},
 
-			clonePointer(obj: ConnectProgressCallback): UnsafeMutableRawPointer {
+			clonePointer(obj: ServerKeyCallback): UnsafeMutableRawPointer {
 				const pointer = this.pointer(obj);
 				return uniffiCaller.rustCall(
 					/*caller:*/ (callStatus) =>
-						nativeModule().ubrn_uniffi_uniffi_russh_fn_clone_connectprogresscallback(
+						nativeModule().ubrn_uniffi_uniffi_russh_fn_clone_serverkeycallback(
 							pointer,
 							callStatus,
 						),
@@ -2333,7 +8255,7 @@ const uniffiTypeConnectProgressCallbackImplObjectFactory: UniffiObjectFactory<Co
 			freePointer(pointer: UnsafeMutableRawPointer): void {
 				uniffiCaller.rustCall(
 					/*caller:*/ (callStatus) =>
-						nativeModule().ubrn_uniffi_uniffi_russh_fn_free_connectprogresscallback(
+						nativeModule().ubrn_uniffi_uniffi_russh_fn_free_serverkeycallback(
 							pointer,
 							callStatus,
 						),
@@ -2341,72 +8263,92 @@ const uniffiTypeConnectProgressCallbackImplObjectFactory: UniffiObjectFactory<Co
 				);
 			},
 
-			isConcreteType(obj: any): obj is ConnectProgressCallback {
+			isConcreteType(obj: any): obj is ServerKeyCallback {
 				return (
 					obj[destructorGuardSymbol] &&
-					obj[uniffiTypeNameSymbol] === 'ConnectProgressCallbackImpl'
+					obj[uniffiTypeNameSymbol] === 'ServerKeyCallbackImpl'
 				);
 			},
 		};
 	})();
-// FfiConverter for ConnectProgressCallback
-const FfiConverterTypeConnectProgressCallback =
-	new FfiConverterObjectWithCallbacks(
-		uniffiTypeConnectProgressCallbackImplObjectFactory,
-	);
+// FfiConverter for ServerKeyCallback
+const FfiConverterTypeServerKeyCallback = new FfiConverterObjectWithCallbacks(
+	uniffiTypeServerKeyCallbackImplObjectFactory,
+);
 
-// Add a vtavble for the callbacks that go in ConnectProgressCallback.
+// Add a vtavble for the callbacks that go in ServerKeyCallback.
 
 // Put the implementation in a struct so we don't pollute the top-level namespace
-const uniffiCallbackInterfaceConnectProgressCallback: {
-	vtable: UniffiVTableCallbackInterfaceConnectProgressCallback;
+const uniffiCallbackInterfaceServerKeyCallback: {
+	vtable: UniffiVTableCallbackInterfaceServerKeyCallback;
 	register: () => void;
 } = {
 	// Create the VTable using a series of closures.
 	// ts automatically converts these into C callback functions.
 	vtable: {
-		onChange: (uniffiHandle: bigint, status: Uint8Array) => {
-			const uniffiMakeCall = (): void => {
-				const jsCallback =
-					FfiConverterTypeConnectProgressCallback.lift(uniffiHandle);
-				return jsCallback.onChange(
-					FfiConverterTypeSshConnectionProgressEvent.lift(status),
+		onChange: (
+			uniffiHandle: bigint,
+			serverKeyInfo: Uint8Array,
+			uniffiFutureCallback: UniffiForeignFutureCompleteI8,
+			uniffiCallbackData: bigint,
+		) => {
+			const uniffiMakeCall = async (signal: AbortSignal): Promise<boolean> => {
+				const jsCallback = FfiConverterTypeServerKeyCallback.lift(uniffiHandle);
+				return await jsCallback.onChange(
+					FfiConverterTypeServerPublicKeyInfo.lift(serverKeyInfo),
+					{ signal },
 				);
 			};
-			const uniffiResult = UniffiResult.ready<void>();
-			const uniffiHandleSuccess = (obj: any) => {};
-			const uniffiHandleError = (code: number, errBuf: UniffiByteArray) => {
-				UniffiResult.writeError(uniffiResult, code, errBuf);
+			const uniffiHandleSuccess = (returnValue: boolean) => {
+				uniffiFutureCallback.call(
+					uniffiFutureCallback,
+					uniffiCallbackData,
+					/* UniffiForeignFutureStructI8 */ {
+						returnValue: FfiConverterBool.lower(returnValue),
+						callStatus: uniffiCaller.createCallStatus(),
+					},
+				);
 			};
-			uniffiTraitInterfaceCall(
+			const uniffiHandleError = (code: number, errorBuf: UniffiByteArray) => {
+				uniffiFutureCallback.call(
+					uniffiFutureCallback,
+					uniffiCallbackData,
+					/* UniffiForeignFutureStructI8 */ {
+						returnValue: 0,
+						// TODO create callstatus with error.
+						callStatus: uniffiCaller.createErrorStatus(code, errorBuf),
+					},
+				);
+			};
+			const uniffiForeignFuture = uniffiTraitInterfaceCallAsync(
 				/*makeCall:*/ uniffiMakeCall,
 				/*handleSuccess:*/ uniffiHandleSuccess,
 				/*handleError:*/ uniffiHandleError,
 				/*lowerString:*/ FfiConverterString.lower,
 			);
-			return uniffiResult;
+			return uniffiForeignFuture;
 		},
 		uniffiFree: (uniffiHandle: UniffiHandle): void => {
-			// ConnectProgressCallback: this will throw a stale handle error if the handle isn't found.
-			FfiConverterTypeConnectProgressCallback.drop(uniffiHandle);
+			// ServerKeyCallback: this will throw a stale handle error if the handle isn't found.
+			FfiConverterTypeServerKeyCallback.drop(uniffiHandle);
 		},
 	},
 	register: () => {
-		nativeModule().ubrn_uniffi_uniffi_russh_fn_init_callback_vtable_connectprogresscallback(
-			uniffiCallbackInterfaceConnectProgressCallback.vtable,
+		nativeModule().ubrn_uniffi_uniffi_russh_fn_init_callback_vtable_serverkeycallback(
+			uniffiCallbackInterfaceServerKeyCallback.vtable,
 		);
 	},
 };
 
-export interface ConnectionDisconnectedCallback {
-	onChange(connectionId: string): void;
+export interface ShellClosedCallback {
+	onChange(channelId: /*u32*/ number): void;
 }
 
-export class ConnectionDisconnectedCallbackImpl
+export class ShellClosedCallbackImpl
 	extends UniffiAbstractObject
-	implements ConnectionDisconnectedCallback
+	implements ShellClosedCallback
 {
-	readonly [uniffiTypeNameSymbol] = 'ConnectionDisconnectedCallbackImpl';
+	readonly [uniffiTypeNameSymbol] = 'ShellClosedCallbackImpl';
 	readonly [destructorGuardSymbol]: UniffiRustArcPtr;
 	readonly [pointerLiteralSymbol]: UnsafeMutableRawPointer;
 	// No primary constructor declared for this class.
@@ -2414,17 +8356,15 @@ export class ConnectionDisconnectedCallbackImpl
 		super();
 		this[pointerLiteralSymbol] = pointer;
 		this[destructorGuardSymbol] =
-			uniffiTypeConnectionDisconnectedCallbackImplObjectFactory.bless(pointer);
+			uniffiTypeShellClosedCallbackImplObjectFactory.bless(pointer);
 	}
 
-	public onChange(connectionId: string): void {
+	public onChange(channelId: /*u32*/ number): void {
 		uniffiCaller.rustCall(
 			/*caller:*/ (callStatus) => {
-				nativeModule().ubrn_uniffi_uniffi_russh_fn_method_connectiondisconnectedcallback_on_change(
-					uniffiTypeConnectionDisconnectedCallbackImplObjectFactory.clonePointer(
-						this,
-					),
-					FfiConverterString.lower(connectionId),
+				nativeModule().ubrn_uniffi_uniffi_russh_fn_method_shellclosedcallback_on_change(
+					uniffiTypeShellClosedCallbackImplObjectFactory.clonePointer(this),
+					FfiConverterUInt32.lower(channelId),
 					callStatus,
 				);
 			},
@@ -2439,39 +8379,33 @@ export class ConnectionDisconnectedCallbackImpl
 		const ptr = (this as any)[destructorGuardSymbol];
 		if (ptr !== undefined) {
 			const pointer =
-				uniffiTypeConnectionDisconnectedCallbackImplObjectFactory.pointer(this);
-			uniffiTypeConnectionDisconnectedCallbackImplObjectFactory.freePointer(
-				pointer,
-			);
-			uniffiTypeConnectionDisconnectedCallbackImplObjectFactory.unbless(ptr);
+				uniffiTypeShellClosedCallbackImplObjectFactory.pointer(this);
+			uniffiTypeShellClosedCallbackImplObjectFactory.freePointer(pointer);
+			uniffiTypeShellClosedCallbackImplObjectFactory.unbless(ptr);
 			delete (this as any)[destructorGuardSymbol];
 		}
 	}
 
-	static instanceOf(obj: any): obj is ConnectionDisconnectedCallbackImpl {
-		return uniffiTypeConnectionDisconnectedCallbackImplObjectFactory.isConcreteType(
-			obj,
-		);
+	static instanceOf(obj: any): obj is ShellClosedCallbackImpl {
+		return uniffiTypeShellClosedCallbackImplObjectFactory.isConcreteType(obj);
 	}
 }
 
-const uniffiTypeConnectionDisconnectedCallbackImplObjectFactory: UniffiObjectFactory<ConnectionDisconnectedCallback> =
+const uniffiTypeShellClosedCallbackImplObjectFactory: UniffiObjectFactory<ShellClosedCallback> =
 	(() => {
 		return {
-			create(pointer: UnsafeMutableRawPointer): ConnectionDisconnectedCallback {
-				const instance = Object.create(
-					ConnectionDisconnectedCallbackImpl.prototype,
-				);
+			create(pointer: UnsafeMutableRawPointer): ShellClosedCallback {
+				const instance = Object.create(ShellClosedCallbackImpl.prototype);
 				instance[pointerLiteralSymbol] = pointer;
 				instance[destructorGuardSymbol] = this.bless(pointer);
-				instance[uniffiTypeNameSymbol] = 'ConnectionDisconnectedCallbackImpl';
+				instance[uniffiTypeNameSymbol] = 'ShellClosedCallbackImpl';
 				return instance;
 			},
 
 			bless(p: UnsafeMutableRawPointer): UniffiRustArcPtr {
 				return uniffiCaller.rustCall(
 					/*caller:*/ (status) =>
-						nativeModule().ubrn_uniffi_internal_fn_method_connectiondisconnectedcallback_ffi__bless_pointer(
+						nativeModule().ubrn_uniffi_internal_fn_method_shellclosedcallback_ffi__bless_pointer(
 							p,
 							status,
 						),
@@ -2483,20 +8417,18 @@ const uniffiTypeConnectionDisconnectedCallbackImplObjectFactory: UniffiObjectFac
 				ptr.markDestroyed();
 			},
 
-			pointer(obj: ConnectionDisconnectedCallback): UnsafeMutableRawPointer {
+			pointer(obj: ShellClosedCallback): UnsafeMutableRawPointer {
 				if ((obj as any)[destructorGuardSymbol] === undefined) {
 					throw new UniffiInternalError.UnexpectedNullPointer();
 				}
 				return (obj as any)[pointerLiteralSymbol];
 			},
 
-			clonePointer(
-				obj: ConnectionDisconnectedCallback,
-			): UnsafeMutableRawPointer {
+			clonePointer(obj: ShellClosedCallback): UnsafeMutableRawPointer {
 				const pointer = this.pointer(obj);
 				return uniffiCaller.rustCall(
 					/*caller:*/ (callStatus) =>
-						nativeModule().ubrn_uniffi_uniffi_russh_fn_clone_connectiondisconnectedcallback(
+						nativeModule().ubrn_uniffi_uniffi_russh_fn_clone_shellclosedcallback(
 							pointer,
 							callStatus,
 						),
@@ -2507,7 +8439,7 @@ const uniffiTypeConnectionDisconnectedCallbackImplObjectFactory: UniffiObjectFac
 			freePointer(pointer: UnsafeMutableRawPointer): void {
 				uniffiCaller.rustCall(
 					/*caller:*/ (callStatus) =>
-						nativeModule().ubrn_uniffi_uniffi_russh_fn_free_connectiondisconnectedcallback(
+						nativeModule().ubrn_uniffi_uniffi_russh_fn_free_shellclosedcallback(
 							pointer,
 							callStatus,
 						),
@@ -2515,35 +8447,34 @@ const uniffiTypeConnectionDisconnectedCallbackImplObjectFactory: UniffiObjectFac
 				);
 			},
 
-			isConcreteType(obj: any): obj is ConnectionDisconnectedCallback {
+			isConcreteType(obj: any): obj is ShellClosedCallback {
 				return (
 					obj[destructorGuardSymbol] &&
-					obj[uniffiTypeNameSymbol] === 'ConnectionDisconnectedCallbackImpl'
+					obj[uniffiTypeNameSymbol] === 'ShellClosedCallbackImpl'
 				);
 			},
 		};
 	})();
-// FfiConverter for ConnectionDisconnectedCallback
-const FfiConverterTypeConnectionDisconnectedCallback =
-	new FfiConverterObjectWithCallbacks(
-		uniffiTypeConnectionDisconnectedCallbackImplObjectFactory,
-	);
+// FfiConverter for ShellClosedCallback
+const FfiConverterTypeShellClosedCallback = new FfiConverterObjectWithCallbacks(
+	uniffiTypeShellClosedCallbackImplObjectFactory,
+);
 
-// Add a vtavble for the callbacks that go in ConnectionDisconnectedCallback.
+// Add a vtavble for the callbacks that go in ShellClosedCallback.
 
 // Put the implementation in a struct so we don't pollute the top-level namespace
-const uniffiCallbackInterfaceConnectionDisconnectedCallback: {
-	vtable: UniffiVTableCallbackInterfaceConnectionDisconnectedCallback;
+const uniffiCallbackInterfaceShellClosedCallback: {
+	vtable: UniffiVTableCallbackInterfaceShellClosedCallback;
 	register: () => void;
 } = {
 	// Create the VTable using a series of closures.
 	// ts automatically converts these into C callback functions.
 	vtable: {
-		onChange: (uniffiHandle: bigint, connectionId: Uint8Array) => {
+		onChange: (uniffiHandle: bigint, channelId: number) => {
 			const uniffiMakeCall = (): void => {
 				const jsCallback =
-					FfiConverterTypeConnectionDisconnectedCallback.lift(uniffiHandle);
-				return jsCallback.onChange(FfiConverterString.lift(connectionId));
+					FfiConverterTypeShellClosedCallback.lift(uniffiHandle);
+				return jsCallback.onChange(FfiConverterUInt32.lift(channelId));
 			};
 			const uniffiResult = UniffiResult.ready<void>();
 			const uniffiHandleSuccess = (obj: any) => {};
@@ -2559,29 +8490,26 @@ const uniffiCallbackInterfaceConnectionDisconnectedCallback: {
 			return uniffiResult;
 		},
 		uniffiFree: (uniffiHandle: UniffiHandle): void => {
-			// ConnectionDisconnectedCallback: this will throw a stale handle error if the handle isn't found.
-			FfiConverterTypeConnectionDisconnectedCallback.drop(uniffiHandle);
+			// ShellClosedCallback: this will throw a stale handle error if the handle isn't found.
+			FfiConverterTypeShellClosedCallback.drop(uniffiHandle);
 		},
 	},
 	register: () => {
-		nativeModule().ubrn_uniffi_uniffi_russh_fn_init_callback_vtable_connectiondisconnectedcallback(
-			uniffiCallbackInterfaceConnectionDisconnectedCallback.vtable,
+		nativeModule().ubrn_uniffi_uniffi_russh_fn_init_callback_vtable_shellclosedcallback(
+			uniffiCallbackInterfaceShellClosedCallback.vtable,
 		);
 	},
 };
 
-export interface ServerKeyCallback {
-	onChange(
-		serverKeyInfo: ServerPublicKeyInfo,
-		asyncOpts_?: { signal: AbortSignal },
-	): Promise<boolean>;
+export interface ShellListener {
+	onEvent(ev: ShellEvent): void;
 }
 
-export class ServerKeyCallbackImpl
+export class ShellListenerImpl
 	extends UniffiAbstractObject
-	implements ServerKeyCallback
+	implements ShellListener
 {
-	readonly [uniffiTypeNameSymbol] = 'ServerKeyCallbackImpl';
+	readonly [uniffiTypeNameSymbol] = 'ShellListenerImpl';
 	readonly [destructorGuardSymbol]: UniffiRustArcPtr;
 	readonly [pointerLiteralSymbol]: UnsafeMutableRawPointer;
 	// No primary constructor declared for this class.
@@ -2589,39 +8517,20 @@ export class ServerKeyCallbackImpl
 		super();
 		this[pointerLiteralSymbol] = pointer;
 		this[destructorGuardSymbol] =
-			uniffiTypeServerKeyCallbackImplObjectFactory.bless(pointer);
+			uniffiTypeShellListenerImplObjectFactory.bless(pointer);
 	}
 
-	public async onChange(
-		serverKeyInfo: ServerPublicKeyInfo,
-		asyncOpts_?: { signal: AbortSignal },
-	): Promise<boolean> {
-		const __stack = uniffiIsDebug ? new Error().stack : undefined;
-		try {
-			return await uniffiRustCallAsync(
-				/*rustCaller:*/ uniffiCaller,
-				/*rustFutureFunc:*/ () => {
-					return nativeModule().ubrn_uniffi_uniffi_russh_fn_method_serverkeycallback_on_change(
-						uniffiTypeServerKeyCallbackImplObjectFactory.clonePointer(this),
-						FfiConverterTypeServerPublicKeyInfo.lower(serverKeyInfo),
-					);
-				},
-				/*pollFunc:*/ nativeModule().ubrn_ffi_uniffi_russh_rust_future_poll_i8,
-				/*cancelFunc:*/ nativeModule()
-					.ubrn_ffi_uniffi_russh_rust_future_cancel_i8,
-				/*completeFunc:*/ nativeModule()
-					.ubrn_ffi_uniffi_russh_rust_future_complete_i8,
-				/*freeFunc:*/ nativeModule().ubrn_ffi_uniffi_russh_rust_future_free_i8,
-				/*liftFunc:*/ FfiConverterBool.lift.bind(FfiConverterBool),
-				/*liftString:*/ FfiConverterString.lift,
-				/*asyncOpts:*/ asyncOpts_,
-			);
-		} catch (__error: any) {
-			if (uniffiIsDebug && __error instanceof Error) {
-				__error.stack = __stack;
-			}
-			throw __error;
-		}
+	public onEvent(ev: ShellEvent): void {
+		uniffiCaller.rustCall(
+			/*caller:*/ (callStatus) => {
+				nativeModule().ubrn_uniffi_uniffi_russh_fn_method_shelllistener_on_event(
+					uniffiTypeShellListenerImplObjectFactory.clonePointer(this),
+					FfiConverterTypeShellEvent.lower(ev),
+					callStatus,
+				);
+			},
+			/*liftString:*/ FfiConverterString.lift,
+		);
 	}
 
 	/**
@@ -2630,34 +8539,33 @@ export class ServerKeyCallbackImpl
 	uniffiDestroy(): void {
 		const ptr = (this as any)[destructorGuardSymbol];
 		if (ptr !== undefined) {
-			const pointer =
-				uniffiTypeServerKeyCallbackImplObjectFactory.pointer(this);
-			uniffiTypeServerKeyCallbackImplObjectFactory.freePointer(pointer);
-			uniffiTypeServerKeyCallbackImplObjectFactory.unbless(ptr);
+			const pointer = uniffiTypeShellListenerImplObjectFactory.pointer(this);
+			uniffiTypeShellListenerImplObjectFactory.freePointer(pointer);
+			uniffiTypeShellListenerImplObjectFactory.unbless(ptr);
 			delete (this as any)[destructorGuardSymbol];
 		}
 	}
 
-	static instanceOf(obj: any): obj is ServerKeyCallbackImpl {
-		return uniffiTypeServerKeyCallbackImplObjectFactory.isConcreteType(obj);
+	static instanceOf(obj: any): obj is ShellListenerImpl {
+		return uniffiTypeShellListenerImplObjectFactory.isConcreteType(obj);
 	}
 }
 
-const uniffiTypeServerKeyCallbackImplObjectFactory: UniffiObjectFactory<ServerKeyCallback> =
+const uniffiTypeShellListenerImplObjectFactory: UniffiObjectFactory<ShellListener> =
 	(() => {
 		return {
-			create(pointer: UnsafeMutableRawPointer): ServerKeyCallback {
-				const instance = Object.create(ServerKeyCallbackImpl.prototype);
+			create(pointer: UnsafeMutableRawPointer): ShellListener {
+				const instance = Object.create(ShellListenerImpl.prototype);
 				instance[pointerLiteralSymbol] = pointer;
 				instance[destructorGuardSymbol] = this.bless(pointer);
-				instance[uniffiTypeNameSymbol] = 'ServerKeyCallbackImpl';
+				instance[uniffiTypeNameSymbol] = 'ShellListenerImpl';
 				return instance;
 			},
 
 			bless(p: UnsafeMutableRawPointer): UniffiRustArcPtr {
 				return uniffiCaller.rustCall(
 					/*caller:*/ (status) =>
-						nativeModule().ubrn_uniffi_internal_fn_method_serverkeycallback_ffi__bless_pointer(
+						nativeModule().ubrn_uniffi_internal_fn_method_shelllistener_ffi__bless_pointer(
 							p,
 							status,
 						),
@@ -2669,18 +8577,18 @@ const uniffiTypeServerKeyCallbackImplObjectFactory: UniffiObjectFactory<ServerKe
 				ptr.markDestroyed();
 			},
 
-			pointer(obj: ServerKeyCallback): UnsafeMutableRawPointer {
+			pointer(obj: ShellListener): UnsafeMutableRawPointer {
 				if ((obj as any)[destructorGuardSymbol] === undefined) {
 					throw new UniffiInternalError.UnexpectedNullPointer();
 				}
 				return (obj as any)[pointerLiteralSymbol];
 			},
 
-			clonePointer(obj: ServerKeyCallback): UnsafeMutableRawPointer {
+			clonePointer(obj: ShellListener): UnsafeMutableRawPointer {
 				const pointer = this.pointer(obj);
 				return uniffiCaller.rustCall(
 					/*caller:*/ (callStatus) =>
-						nativeModule().ubrn_uniffi_uniffi_russh_fn_clone_serverkeycallback(
+						nativeModule().ubrn_uniffi_uniffi_russh_fn_clone_shelllistener(
 							pointer,
 							callStatus,
 						),
@@ -2691,7 +8599,7 @@ const uniffiTypeServerKeyCallbackImplObjectFactory: UniffiObjectFactory<ServerKe
 			freePointer(pointer: UnsafeMutableRawPointer): void {
 				uniffiCaller.rustCall(
 					/*caller:*/ (callStatus) =>
-						nativeModule().ubrn_uniffi_uniffi_russh_fn_free_serverkeycallback(
+						nativeModule().ubrn_uniffi_uniffi_russh_fn_free_shelllistener(
 							pointer,
 							callStatus,
 						),
@@ -2699,92 +8607,178 @@ const uniffiTypeServerKeyCallbackImplObjectFactory: UniffiObjectFactory<ServerKe
 				);
 			},
 
-			isConcreteType(obj: any): obj is ServerKeyCallback {
+			isConcreteType(obj: any): obj is ShellListener {
 				return (
 					obj[destructorGuardSymbol] &&
-					obj[uniffiTypeNameSymbol] === 'ServerKeyCallbackImpl'
+					obj[uniffiTypeNameSymbol] === 'ShellListenerImpl'
 				);
 			},
 		};
 	})();
-// FfiConverter for ServerKeyCallback
-const FfiConverterTypeServerKeyCallback = new FfiConverterObjectWithCallbacks(
-	uniffiTypeServerKeyCallbackImplObjectFactory,
+// FfiConverter for ShellListener
+const FfiConverterTypeShellListener = new FfiConverterObjectWithCallbacks(
+	uniffiTypeShellListenerImplObjectFactory,
 );
 
-// Add a vtavble for the callbacks that go in ServerKeyCallback.
+// Add a vtavble for the callbacks that go in ShellListener.
 
 // Put the implementation in a struct so we don't pollute the top-level namespace
-const uniffiCallbackInterfaceServerKeyCallback: {
-	vtable: UniffiVTableCallbackInterfaceServerKeyCallback;
+const uniffiCallbackInterfaceShellListener: {
+	vtable: UniffiVTableCallbackInterfaceShellListener;
 	register: () => void;
 } = {
 	// Create the VTable using a series of closures.
 	// ts automatically converts these into C callback functions.
 	vtable: {
-		onChange: (
-			uniffiHandle: bigint,
-			serverKeyInfo: Uint8Array,
-			uniffiFutureCallback: UniffiForeignFutureCompleteI8,
-			uniffiCallbackData: bigint,
-		) => {
-			const uniffiMakeCall = async (signal: AbortSignal): Promise<boolean> => {
-				const jsCallback = FfiConverterTypeServerKeyCallback.lift(uniffiHandle);
-				return await jsCallback.onChange(
-					FfiConverterTypeServerPublicKeyInfo.lift(serverKeyInfo),
-					{ signal },
-				);
-			};
-			const uniffiHandleSuccess = (returnValue: boolean) => {
-				uniffiFutureCallback.call(
-					uniffiFutureCallback,
-					uniffiCallbackData,
-					/* UniffiForeignFutureStructI8 */ {
-						returnValue: FfiConverterBool.lower(returnValue),
-						callStatus: uniffiCaller.createCallStatus(),
-					},
-				);
+		onEvent: (uniffiHandle: bigint, ev: Uint8Array) => {
+			const uniffiMakeCall = (): void => {
+				const jsCallback = FfiConverterTypeShellListener.lift(uniffiHandle);
+				return jsCallback.onEvent(FfiConverterTypeShellEvent.lift(ev));
 			};
-			const uniffiHandleError = (code: number, errorBuf: UniffiByteArray) => {
-				uniffiFutureCallback.call(
-					uniffiFutureCallback,
-					uniffiCallbackData,
-					/* UniffiForeignFutureStructI8 */ {
-						returnValue: 0,
-						// TODO create callstatus with error.
-						callStatus: uniffiCaller.createErrorStatus(code, errorBuf),
-					},
-				);
+			const uniffiResult = UniffiResult.ready<void>();
+			const uniffiHandleSuccess = (obj: any) => {};
+			const uniffiHandleError = (code: number, errBuf: UniffiByteArray) => {
+				UniffiResult.writeError(uniffiResult, code, errBuf);
 			};
-			const uniffiForeignFuture = uniffiTraitInterfaceCallAsync(
+			uniffiTraitInterfaceCall(
 				/*makeCall:*/ uniffiMakeCall,
 				/*handleSuccess:*/ uniffiHandleSuccess,
 				/*handleError:*/ uniffiHandleError,
 				/*lowerString:*/ FfiConverterString.lower,
 			);
-			return uniffiForeignFuture;
+			return uniffiResult;
 		},
 		uniffiFree: (uniffiHandle: UniffiHandle): void => {
-			// ServerKeyCallback: this will throw a stale handle error if the handle isn't found.
-			FfiConverterTypeServerKeyCallback.drop(uniffiHandle);
+			// ShellListener: this will throw a stale handle error if the handle isn't found.
+			FfiConverterTypeShellListener.drop(uniffiHandle);
 		},
 	},
 	register: () => {
-		nativeModule().ubrn_uniffi_uniffi_russh_fn_init_callback_vtable_serverkeycallback(
-			uniffiCallbackInterfaceServerKeyCallback.vtable,
+		nativeModule().ubrn_uniffi_uniffi_russh_fn_init_callback_vtable_shelllistener(
+			uniffiCallbackInterfaceShellListener.vtable,
 		);
 	},
 };
 
-export interface ShellClosedCallback {
-	onChange(channelId: /*u32*/ number): void;
+export interface ShellSessionInterface {
+	/**
+	 * Add a listener with optional replay and live follow.
+	 */
+	addListener(
+		listener: ShellListener,
+		opts: ListenerOptions,
+	) /*throws*/ : /*u64*/ bigint;
+	/**
+	 * Keep a screen model of this session's output. Starts from what the
+	 * ring buffer still holds; calling it again returns the same emulator.
+	 */
+	attachEmulator(
+		scrollbackLines: /*u32*/ number | undefined,
+	): TerminalEmulatorInterface;
+	/**
+	 * Buffer statistics snapshot.
+	 */
+	bufferStats(): BufferStats;
+	/**
+	 * Close the associated shell channel and stop its reader task.
+	 */
+	close(asyncOpts_?: { signal: AbortSignal }) /*throws*/ : Promise<void>;
+	/**
+	 * Commands seen via shell integration marks (OSC 133), oldest first.
+	 * Needs a shell set up to emit them, e.g. via its prompt.
+	 */
+	commands(): Array<CommandRecord>;
+	/**
+	 * Current next sequence number.
+	 */
+	currentSeq(): /*u64*/ bigint;
+	detachEmulator(): void;
+	getInfo(): ShellSessionInfo;
+	/**
+	 * Read the ring buffer from a cursor.
+	 */
+	readBuffer(
+		cursor: Cursor,
+		maxBytes: /*u64*/ bigint | undefined,
+	): BufferReadResult;
+	/**
+	 * Like `read_buffer`, but as text with escape sequences removed and
+	 * carriage returns, backspaces and line erases applied, so overwritten
+	 * progress output shows only its final state.
+	 */
+	readText(
+		cursor: Cursor,
+		maxBytes: /*u64*/ bigint | undefined,
+	): TextReadResult;
+	removeListener(id: /*u64*/ bigint): void;
+	/**
+	 * Tell the server the terminal changed size (`window-change`), e.g. on
+	 * rotation or when the keyboard opens.
+	 */
+	resize(
+		cols: /*u32*/ number,
+		rows: /*u32*/ number,
+		pixelWidth: /*u32*/ number,
+		pixelHeight: /*u32*/ number,
+		asyncOpts_?: { signal: AbortSignal },
+	) /*throws*/ : Promise<void>;
+	/**
+	 * Search the buffered output with escape sequences and control characters
+	 * (other than LF and TAB) removed, so matches may span chunks and
+	 * styling. A CR not followed by LF counts as a line break. `^`/`$` match
+	 * at line boundaries.
+	 */
+	search(
+		pattern: string,
+		opts: SearchOptions,
+		asyncOpts_?: { signal: AbortSignal },
+	) /*throws*/ : Promise<SearchResult>;
+	/**
+	 * Send a BREAK (RFC 4335) of `duration_ms`, as console servers expect
+	 * for serial consoles. Servers without break support ignore it.
+	 */
+	sendBreak(
+		durationMs: /*u32*/ number,
+		asyncOpts_?: { signal: AbortSignal },
+	) /*throws*/ : Promise<void>;
+	/**
+	 * Send bytes to the active shell (stdin).
+	 */
+	sendData(
+		data: ArrayBuffer,
+		asyncOpts_?: { signal: AbortSignal },
+	) /*throws*/ : Promise<void>;
+	/**
+	 * Deliver a signal to the remote process. Works in raw mode and for
+	 * signals with no control character (KILL); the server may ignore it.
+	 */
+	sendSignal(
+		signal: ShellSignal,
+		asyncOpts_?: { signal: AbortSignal },
+	) /*throws*/ : Promise<void>;
+	/**
+	 * Change the buffer policy. Shrinking `ring_bytes` evicts the oldest
+	 * chunks right away; `broadcast_chunks` can't change after start.
+	 */
+	setBufferPolicy(policy: BufferPolicy) /*throws*/ : void;
+	/**
+	 * Record output from now on to an asciicast v2 file at `path`
+	 * (created or truncated), with resizes and optionally input.
+	 */
+	startRecording(path: string, opts: RecordingOptions) /*throws*/ : void;
+	/**
+	 * Finish the recording; None if none was running.
+	 */
+	stopRecording(asyncOpts_?: {
+		signal: AbortSignal;
+	}) /*throws*/ : Promise<RecordingStats | undefined>;
 }
 
-export class ShellClosedCallbackImpl
+export class ShellSession
 	extends UniffiAbstractObject
-	implements ShellClosedCallback
+	implements ShellSessionInterface
 {
-	readonly [uniffiTypeNameSymbol] = 'ShellClosedCallbackImpl';
+	readonly [uniffiTypeNameSymbol] = 'ShellSession';
 	readonly [destructorGuardSymbol]: UniffiRustArcPtr;
 	readonly [pointerLiteralSymbol]: UnsafeMutableRawPointer;
 	// No primary constructor declared for this class.
@@ -2792,15 +8786,150 @@ export class ShellClosedCallbackImpl
 		super();
 		this[pointerLiteralSymbol] = pointer;
 		this[destructorGuardSymbol] =
-			uniffiTypeShellClosedCallbackImplObjectFactory.bless(pointer);
+			uniffiTypeShellSessionObjectFactory.bless(pointer);
+	}
+
+	/**
+	 * Add a listener with optional replay and live follow.
+	 */
+	public addListener(
+		listener: ShellListener,
+		opts: ListenerOptions,
+	): /*u64*/ bigint /*throws*/ {
+		return FfiConverterUInt64.lift(
+			uniffiCaller.rustCallWithError(
+				/*liftError:*/ FfiConverterTypeSshError.lift.bind(
+					FfiConverterTypeSshError,
+				),
+				/*caller:*/ (callStatus) => {
+					return nativeModule().ubrn_uniffi_uniffi_russh_fn_method_shellsession_add_listener(
+						uniffiTypeShellSessionObjectFactory.clonePointer(this),
+						FfiConverterTypeShellListener.lower(listener),
+						FfiConverterTypeListenerOptions.lower(opts),
+						callStatus,
+					);
+				},
+				/*liftString:*/ FfiConverterString.lift,
+			),
+		);
+	}
+
+	/**
+	 * Keep a screen model of this session's output. Starts from what the
+	 * ring buffer still holds; calling it again returns the same emulator.
+	 */
+	public attachEmulator(
+		scrollbackLines: /*u32*/ number | undefined,
+	): TerminalEmulatorInterface {
+		return FfiConverterTypeTerminalEmulator.lift(
+			uniffiCaller.rustCall(
+				/*caller:*/ (callStatus) => {
+					return nativeModule().ubrn_uniffi_uniffi_russh_fn_method_shellsession_attach_emulator(
+						uniffiTypeShellSessionObjectFactory.clonePointer(this),
+						FfiConverterOptionalUInt32.lower(scrollbackLines),
+						callStatus,
+					);
+				},
+				/*liftString:*/ FfiConverterString.lift,
+			),
+		);
+	}
+
+	/**
+	 * Buffer statistics snapshot.
+	 */
+	public bufferStats(): BufferStats {
+		return FfiConverterTypeBufferStats.lift(
+			uniffiCaller.rustCall(
+				/*caller:*/ (callStatus) => {
+					return nativeModule().ubrn_uniffi_uniffi_russh_fn_method_shellsession_buffer_stats(
+						uniffiTypeShellSessionObjectFactory.clonePointer(this),
+						callStatus,
+					);
+				},
+				/*liftString:*/ FfiConverterString.lift,
+			),
+		);
+	}
+
+	/**
+	 * Close the associated shell channel and stop its reader task.
+	 */
+	public async close(asyncOpts_?: {
+		signal: AbortSignal;
+	}): Promise<void> /*throws*/ {
+		const __stack = uniffiIsDebug ? new Error().stack : undefined;
+		try {
+			return await uniffiRustCallAsync(
+				/*rustCaller:*/ uniffiCaller,
+				/*rustFutureFunc:*/ () => {
+					return nativeModule().ubrn_uniffi_uniffi_russh_fn_method_shellsession_close(
+						uniffiTypeShellSessionObjectFactory.clonePointer(this),
+					);
+				},
+				/*pollFunc:*/ nativeModule()
+					.ubrn_ffi_uniffi_russh_rust_future_poll_void,
+				/*cancelFunc:*/ nativeModule()
+					.ubrn_ffi_uniffi_russh_rust_future_cancel_void,
+				/*completeFunc:*/ nativeModule()
+					.ubrn_ffi_uniffi_russh_rust_future_complete_void,
+				/*freeFunc:*/ nativeModule()
+					.ubrn_ffi_uniffi_russh_rust_future_free_void,
+				/*liftFunc:*/ (_v) => {},
+				/*liftString:*/ FfiConverterString.lift,
+				/*asyncOpts:*/ asyncOpts_,
+				/*errorHandler:*/ FfiConverterTypeSshError.lift.bind(
+					FfiConverterTypeSshError,
+				),
+			);
+		} catch (__error: any) {
+			if (uniffiIsDebug && __error instanceof Error) {
+				__error.stack = __stack;
+			}
+			throw __error;
+		}
+	}
+
+	/**
+	 * Commands seen via shell integration marks (OSC 133), oldest first.
+	 * Needs a shell set up to emit them, e.g. via its prompt.
+	 */
+	public commands(): Array<CommandRecord> {
+		return FfiConverterArrayTypeCommandRecord.lift(
+			uniffiCaller.rustCall(
+				/*caller:*/ (callStatus) => {
+					return nativeModule().ubrn_uniffi_uniffi_russh_fn_method_shellsession_commands(
+						uniffiTypeShellSessionObjectFactory.clonePointer(this),
+						callStatus,
+					);
+				},
+				/*liftString:*/ FfiConverterString.lift,
+			),
+		);
+	}
+
+	/**
+	 * Current next sequence number.
+	 */
+	public currentSeq(): /*u64*/ bigint {
+		return FfiConverterUInt64.lift(
+			uniffiCaller.rustCall(
+				/*caller:*/ (callStatus) => {
+					return nativeModule().ubrn_uniffi_uniffi_russh_fn_method_shellsession_current_seq(
+						uniffiTypeShellSessionObjectFactory.clonePointer(this),
+						callStatus,
+					);
+				},
+				/*liftString:*/ FfiConverterString.lift,
+			),
+		);
 	}
 
-	public onChange(channelId: /*u32*/ number): void {
+	public detachEmulator(): void {
 		uniffiCaller.rustCall(
 			/*caller:*/ (callStatus) => {
-				nativeModule().ubrn_uniffi_uniffi_russh_fn_method_shellclosedcallback_on_change(
-					uniffiTypeShellClosedCallbackImplObjectFactory.clonePointer(this),
-					FfiConverterUInt32.lower(channelId),
+				nativeModule().ubrn_uniffi_uniffi_russh_fn_method_shellsession_detach_emulator(
+					uniffiTypeShellSessionObjectFactory.clonePointer(this),
 					callStatus,
 				);
 			},
@@ -2808,160 +8937,329 @@ export class ShellClosedCallbackImpl
 		);
 	}
 
+	public getInfo(): ShellSessionInfo {
+		return FfiConverterTypeShellSessionInfo.lift(
+			uniffiCaller.rustCall(
+				/*caller:*/ (callStatus) => {
+					return nativeModule().ubrn_uniffi_uniffi_russh_fn_method_shellsession_get_info(
+						uniffiTypeShellSessionObjectFactory.clonePointer(this),
+						callStatus,
+					);
+				},
+				/*liftString:*/ FfiConverterString.lift,
+			),
+		);
+	}
+
 	/**
-	 * {@inheritDoc uniffi-bindgen-react-native#UniffiAbstractObject.uniffiDestroy}
+	 * Read the ring buffer from a cursor.
 	 */
-	uniffiDestroy(): void {
-		const ptr = (this as any)[destructorGuardSymbol];
-		if (ptr !== undefined) {
-			const pointer =
-				uniffiTypeShellClosedCallbackImplObjectFactory.pointer(this);
-			uniffiTypeShellClosedCallbackImplObjectFactory.freePointer(pointer);
-			uniffiTypeShellClosedCallbackImplObjectFactory.unbless(ptr);
-			delete (this as any)[destructorGuardSymbol];
-		}
+	public readBuffer(
+		cursor: Cursor,
+		maxBytes: /*u64*/ bigint | undefined,
+	): BufferReadResult {
+		return FfiConverterTypeBufferReadResult.lift(
+			uniffiCaller.rustCall(
+				/*caller:*/ (callStatus) => {
+					return nativeModule().ubrn_uniffi_uniffi_russh_fn_method_shellsession_read_buffer(
+						uniffiTypeShellSessionObjectFactory.clonePointer(this),
+						FfiConverterTypeCursor.lower(cursor),
+						FfiConverterOptionalUInt64.lower(maxBytes),
+						callStatus,
+					);
+				},
+				/*liftString:*/ FfiConverterString.lift,
+			),
+		);
 	}
 
-	static instanceOf(obj: any): obj is ShellClosedCallbackImpl {
-		return uniffiTypeShellClosedCallbackImplObjectFactory.isConcreteType(obj);
+	/**
+	 * Like `read_buffer`, but as text with escape sequences removed and
+	 * carriage returns, backspaces and line erases applied, so overwritten
+	 * progress output shows only its final state.
+	 */
+	public readText(
+		cursor: Cursor,
+		maxBytes: /*u64*/ bigint | undefined,
+	): TextReadResult {
+		return FfiConverterTypeTextReadResult.lift(
+			uniffiCaller.rustCall(
+				/*caller:*/ (callStatus) => {
+					return nativeModule().ubrn_uniffi_uniffi_russh_fn_method_shellsession_read_text(
+						uniffiTypeShellSessionObjectFactory.clonePointer(this),
+						FfiConverterTypeCursor.lower(cursor),
+						FfiConverterOptionalUInt64.lower(maxBytes),
+						callStatus,
+					);
+				},
+				/*liftString:*/ FfiConverterString.lift,
+			),
+		);
 	}
-}
-
-const uniffiTypeShellClosedCallbackImplObjectFactory: UniffiObjectFactory<ShellClosedCallback> =
-	(() => {
-		return {
-			create(pointer: UnsafeMutableRawPointer): ShellClosedCallback {
-				const instance = Object.create(ShellClosedCallbackImpl.prototype);
-				instance[pointerLiteralSymbol] = pointer;
-				instance[destructorGuardSymbol] = this.bless(pointer);
-				instance[uniffiTypeNameSymbol] = 'ShellClosedCallbackImpl';
-				return instance;
-			},
 
-			bless(p: UnsafeMutableRawPointer): UniffiRustArcPtr {
-				return uniffiCaller.rustCall(
-					/*caller:*/ (status) =>
-						nativeModule().ubrn_uniffi_internal_fn_method_shellclosedcallback_ffi__bless_pointer(
-							p,
-							status,
-						),
-					/*liftString:*/ FfiConverterString.lift,
+	public removeListener(id: /*u64*/ bigint): void {
+		uniffiCaller.rustCall(
+			/*caller:*/ (callStatus) => {
+				nativeModule().ubrn_uniffi_uniffi_russh_fn_method_shellsession_remove_listener(
+					uniffiTypeShellSessionObjectFactory.clonePointer(this),
+					FfiConverterUInt64.lower(id),
+					callStatus,
 				);
 			},
+			/*liftString:*/ FfiConverterString.lift,
+		);
+	}
 
-			unbless(ptr: UniffiRustArcPtr) {
-				ptr.markDestroyed();
-			},
+	/**
+	 * Tell the server the terminal changed size (`window-change`), e.g. on
+	 * rotation or when the keyboard opens.
+	 */
+	public async resize(
+		cols: /*u32*/ number,
+		rows: /*u32*/ number,
+		pixelWidth: /*u32*/ number,
+		pixelHeight: /*u32*/ number,
+		asyncOpts_?: { signal: AbortSignal },
+	): Promise<void> /*throws*/ {
+		const __stack = uniffiIsDebug ? new Error().stack : undefined;
+		try {
+			return await uniffiRustCallAsync(
+				/*rustCaller:*/ uniffiCaller,
+				/*rustFutureFunc:*/ () => {
+					return nativeModule().ubrn_uniffi_uniffi_russh_fn_method_shellsession_resize(
+						uniffiTypeShellSessionObjectFactory.clonePointer(this),
+						FfiConverterUInt32.lower(cols),
+						FfiConverterUInt32.lower(rows),
+						FfiConverterUInt32.lower(pixelWidth),
+						FfiConverterUInt32.lower(pixelHeight),
+					);
+				},
+				/*pollFunc:*/ nativeModule()
+					.ubrn_ffi_uniffi_russh_rust_future_poll_void,
+				/*cancelFunc:*/ nativeModule()
+					.ubrn_ffi_uniffi_russh_rust_future_cancel_void,
+				/*completeFunc:*/ nativeModule()
+					.ubrn_ffi_uniffi_russh_rust_future_complete_void,
+				/*freeFunc:*/ nativeModule()
+					.ubrn_ffi_uniffi_russh_rust_future_free_void,
+				/*liftFunc:*/ (_v) => {},
+				/*liftString:*/ FfiConverterString.lift,
+				/*asyncOpts:*/ asyncOpts_,
+				/*errorHandler:*/ FfiConverterTypeSshError.lift.bind(
+					FfiConverterTypeSshError,
+				),
+			);
+		} catch (__error: any) {
+			if (uniffiIsDebug && __error instanceof Error) {
+				__error.stack = __stack;
+			}
+			throw __error;
+		}
+	}
 
-			pointer(obj: ShellClosedCallback): UnsafeMutableRawPointer {
-				if ((obj as any)[destructorGuardSymbol] === undefined) {
-					throw new UniffiInternalError.UnexpectedNullPointer();
-				}
-				return (obj as any)[pointerLiteralSymbol];
-			},
+	/**
+	 * Search the buffered output with escape sequences and control characters
+	 * (other than LF and TAB) removed, so matches may span chunks and
+	 * styling. A CR not followed by LF counts as a line break. `^`/`$` match
+	 * at line boundaries.
+	 */
+	public async search(
+		pattern: string,
+		opts: SearchOptions,
+		asyncOpts_?: { signal: AbortSignal },
+	): Promise<SearchResult> /*throws*/ {
+		const __stack = uniffiIsDebug ? new Error().stack : undefined;
+		try {
+			return await uniffiRustCallAsync(
+				/*rustCaller:*/ uniffiCaller,
+				/*rustFutureFunc:*/ () => {
+					return nativeModule().ubrn_uniffi_uniffi_russh_fn_method_shellsession_search(
+						uniffiTypeShellSessionObjectFactory.clonePointer(this),
+						FfiConverterString.lower(pattern),
+						FfiConverterTypeSearchOptions.lower(opts),
+					);
+				},
+				/*pollFunc:*/ nativeModule()
+					.ubrn_ffi_uniffi_russh_rust_future_poll_rust_buffer,
+				/*cancelFunc:*/ nativeModule()
+					.ubrn_ffi_uniffi_russh_rust_future_cancel_rust_buffer,
+				/*completeFunc:*/ nativeModule()
+					.ubrn_ffi_uniffi_russh_rust_future_complete_rust_buffer,
+				/*freeFunc:*/ nativeModule()
+					.ubrn_ffi_uniffi_russh_rust_future_free_rust_buffer,
+				/*liftFunc:*/ FfiConverterTypeSearchResult.lift.bind(
+					FfiConverterTypeSearchResult,
+				),
+				/*liftString:*/ FfiConverterString.lift,
+				/*asyncOpts:*/ asyncOpts_,
+				/*errorHandler:*/ FfiConverterTypeSshError.lift.bind(
+					FfiConverterTypeSshError,
+				),
+			);
+		} catch (__error: any) {
+			if (uniffiIsDebug && __error instanceof Error) {
+				__error.stack = __stack;
+			}
+			throw __error;
+		}
+	}
 
-			clonePointer(obj: ShellClosedCallback): UnsafeMutableRawPointer {
-				const pointer = this.pointer(obj);
-				return uniffiCaller.rustCall(
-					/*caller:*/ (callStatus) =>
-						nativeModule().ubrn_uniffi_uniffi_russh_fn_clone_shellclosedcallback(
-							pointer,
-							callStatus,
-						),
-					/*liftString:*/ FfiConverterString.lift,
-				);
-			},
+	/**
+	 * Send a BREAK (RFC 4335) of `duration_ms`, as console servers expect
+	 * for serial consoles. Servers without break support ignore it.
+	 */
+	public async sendBreak(
+		durationMs: /*u32*/ number,
+		asyncOpts_?: { signal: AbortSignal },
+	): Promise<void> /*throws*/ {
+		const __stack = uniffiIsDebug ? new Error().stack : undefined;
+		try {
+			return await uniffiRustCallAsync(
+				/*rustCaller:*/ uniffiCaller,
+				/*rustFutureFunc:*/ () => {
+					return nativeModule().ubrn_uniffi_uniffi_russh_fn_method_shellsession_send_break(
+						uniffiTypeShellSessionObjectFactory.clonePointer(this),
+						FfiConverterUInt32.lower(durationMs),
+					);
+				},
+				/*pollFunc:*/ nativeModule()
+					.ubrn_ffi_uniffi_russh_rust_future_poll_void,
+				/*cancelFunc:*/ nativeModule()
+					.ubrn_ffi_uniffi_russh_rust_future_cancel_void,
+				/*completeFunc:*/ nativeModule()
+					.ubrn_ffi_uniffi_russh_rust_future_complete_void,
+				/*freeFunc:*/ nativeModule()
+					.ubrn_ffi_uniffi_russh_rust_future_free_void,
+				/*liftFunc:*/ (_v) => {},
+				/*liftString:*/ FfiConverterString.lift,
+				/*asyncOpts:*/ asyncOpts_,
+				/*errorHandler:*/ FfiConverterTypeSshError.lift.bind(
+					FfiConverterTypeSshError,
+				),
+			);
+		} catch (__error: any) {
+			if (uniffiIsDebug && __error instanceof Error) {
+				__error.stack = __stack;
+			}
+			throw __error;
+		}
+	}
+
+	/**
+	 * Send bytes to the active shell (stdin).
+	 */
+	public async sendData(
+		data: ArrayBuffer,
+		asyncOpts_?: { signal: AbortSignal },
+	): Promise<void> /*throws*/ {
+		const __stack = uniffiIsDebug ? new Error().stack : undefined;
+		try {
+			return await uniffiRustCallAsync(
+				/*rustCaller:*/ uniffiCaller,
+				/*rustFutureFunc:*/ () => {
+					return nativeModule().ubrn_uniffi_uniffi_russh_fn_method_shellsession_send_data(
+						uniffiTypeShellSessionObjectFactory.clonePointer(this),
+						FfiConverterArrayBuffer.lower(data),
+					);
+				},
+				/*pollFunc:*/ nativeModule()
+					.ubrn_ffi_uniffi_russh_rust_future_poll_void,
+				/*cancelFunc:*/ nativeModule()
+					.ubrn_ffi_uniffi_russh_rust_future_cancel_void,
+				/*completeFunc:*/ nativeModule()
+					.ubrn_ffi_uniffi_russh_rust_future_complete_void,
+				/*freeFunc:*/ nativeModule()
+					.ubrn_ffi_uniffi_russh_rust_future_free_void,
+				/*liftFunc:*/ (_v) => {},
+				/*liftString:*/ FfiConverterString.lift,
+				/*asyncOpts:*/ asyncOpts_,
+				/*errorHandler:*/ FfiConverterTypeSshError.lift.bind(
+					FfiConverterTypeSshError,
+				),
+			);
+		} catch (__error: any) {
+			if (uniffiIsDebug && __error instanceof Error) {
+				__error.stack = __stack;
+			}
+			throw __error;
+		}
+	}
 
-			freePointer(pointer: UnsafeMutableRawPointer): void {
-				uniffiCaller.rustCall(
-					/*caller:*/ (callStatus) =>
-						nativeModule().ubrn_uniffi_uniffi_russh_fn_free_shellclosedcallback(
-							pointer,
-							callStatus,
-						),
-					/*liftString:*/ FfiConverterString.lift,
-				);
-			},
+	/**
+	 * Deliver a signal to the remote process. Works in raw mode and for
+	 * signals with no control character (KILL); the server may ignore it.
+	 */
+	public async sendSignal(
+		signal: ShellSignal,
+		asyncOpts_?: { signal: AbortSignal },
+	): Promise<void> /*throws*/ {
+		const __stack = uniffiIsDebug ? new Error().stack : undefined;
+		try {
+			return await uniffiRustCallAsync(
+				/*rustCaller:*/ uniffiCaller,
+				/*rustFutureFunc:*/ () => {
+					return nativeModule().ubrn_uniffi_uniffi_russh_fn_method_shellsession_send_signal(
+						uniffiTypeShellSessionObjectFactory.clonePointer(this),
+						FfiConverterTypeShellSignal.lower(signal),
+					);
+				},
+				/*pollFunc:*/ nativeModule()
+					.ubrn_ffi_uniffi_russh_rust_future_poll_void,
+				/*cancelFunc:*/ nativeModule()
+					.ubrn_ffi_uniffi_russh_rust_future_cancel_void,
+				/*completeFunc:*/ nativeModule()
+					.ubrn_ffi_uniffi_russh_rust_future_complete_void,
+				/*freeFunc:*/ nativeModule()
+					.ubrn_ffi_uniffi_russh_rust_future_free_void,
+				/*liftFunc:*/ (_v) => {},
+				/*liftString:*/ FfiConverterString.lift,
+				/*asyncOpts:*/ asyncOpts_,
+				/*errorHandler:*/ FfiConverterTypeSshError.lift.bind(
+					FfiConverterTypeSshError,
+				),
+			);
+		} catch (__error: any) {
+			if (uniffiIsDebug && __error instanceof Error) {
+				__error.stack = __stack;
+			}
+			throw __error;
+		}
+	}
 
-			isConcreteType(obj: any): obj is ShellClosedCallback {
-				return (
-					obj[destructorGuardSymbol] &&
-					obj[uniffiTypeNameSymbol] === 'ShellClosedCallbackImpl'
+	/**
+	 * Change the buffer policy. Shrinking `ring_bytes` evicts the oldest
+	 * chunks right away; `broadcast_chunks` can't change after start.
+	 */
+	public setBufferPolicy(policy: BufferPolicy): void /*throws*/ {
+		uniffiCaller.rustCallWithError(
+			/*liftError:*/ FfiConverterTypeSshError.lift.bind(
+				FfiConverterTypeSshError,
+			),
+			/*caller:*/ (callStatus) => {
+				nativeModule().ubrn_uniffi_uniffi_russh_fn_method_shellsession_set_buffer_policy(
+					uniffiTypeShellSessionObjectFactory.clonePointer(this),
+					FfiConverterTypeBufferPolicy.lower(policy),
+					callStatus,
 				);
 			},
-		};
-	})();
-// FfiConverter for ShellClosedCallback
-const FfiConverterTypeShellClosedCallback = new FfiConverterObjectWithCallbacks(
-	uniffiTypeShellClosedCallbackImplObjectFactory,
-);
-
-// Add a vtavble for the callbacks that go in ShellClosedCallback.
-
-// Put the implementation in a struct so we don't pollute the top-level namespace
-const uniffiCallbackInterfaceShellClosedCallback: {
-	vtable: UniffiVTableCallbackInterfaceShellClosedCallback;
-	register: () => void;
-} = {
-	// Create the VTable using a series of closures.
-	// ts automatically converts these into C callback functions.
-	vtable: {
-		onChange: (uniffiHandle: bigint, channelId: number) => {
-			const uniffiMakeCall = (): void => {
-				const jsCallback =
-					FfiConverterTypeShellClosedCallback.lift(uniffiHandle);
-				return jsCallback.onChange(FfiConverterUInt32.lift(channelId));
-			};
-			const uniffiResult = UniffiResult.ready<void>();
-			const uniffiHandleSuccess = (obj: any) => {};
-			const uniffiHandleError = (code: number, errBuf: UniffiByteArray) => {
-				UniffiResult.writeError(uniffiResult, code, errBuf);
-			};
-			uniffiTraitInterfaceCall(
-				/*makeCall:*/ uniffiMakeCall,
-				/*handleSuccess:*/ uniffiHandleSuccess,
-				/*handleError:*/ uniffiHandleError,
-				/*lowerString:*/ FfiConverterString.lower,
-			);
-			return uniffiResult;
-		},
-		uniffiFree: (uniffiHandle: UniffiHandle): void => {
-			// ShellClosedCallback: this will throw a stale handle error if the handle isn't found.
-			FfiConverterTypeShellClosedCallback.drop(uniffiHandle);
-		},
-	},
-	register: () => {
-		nativeModule().ubrn_uniffi_uniffi_russh_fn_init_callback_vtable_shellclosedcallback(
-			uniffiCallbackInterfaceShellClosedCallback.vtable,
+			/*liftString:*/ FfiConverterString.lift,
 		);
-	},
-};
-
-export interface ShellListener {
-	onEvent(ev: ShellEvent): void;
-}
-
-export class ShellListenerImpl
-	extends UniffiAbstractObject
-	implements ShellListener
-{
-	readonly [uniffiTypeNameSymbol] = 'ShellListenerImpl';
-	readonly [destructorGuardSymbol]: UniffiRustArcPtr;
-	readonly [pointerLiteralSymbol]: UnsafeMutableRawPointer;
-	// No primary constructor declared for this class.
-	private constructor(pointer: UnsafeMutableRawPointer) {
-		super();
-		this[pointerLiteralSymbol] = pointer;
-		this[destructorGuardSymbol] =
-			uniffiTypeShellListenerImplObjectFactory.bless(pointer);
 	}
 
-	public onEvent(ev: ShellEvent): void {
-		uniffiCaller.rustCall(
+	/**
+	 * Record output from now on to an asciicast v2 file at `path`
+	 * (created or truncated), with resizes and optionally input.
+	 */
+	public startRecording(path: string, opts: RecordingOptions): void /*throws*/ {
+		uniffiCaller.rustCallWithError(
+			/*liftError:*/ FfiConverterTypeSshError.lift.bind(
+				FfiConverterTypeSshError,
+			),
 			/*caller:*/ (callStatus) => {
-				nativeModule().ubrn_uniffi_uniffi_russh_fn_method_shelllistener_on_event(
-					uniffiTypeShellListenerImplObjectFactory.clonePointer(this),
-					FfiConverterTypeShellEvent.lower(ev),
+				nativeModule().ubrn_uniffi_uniffi_russh_fn_method_shellsession_start_recording(
+					uniffiTypeShellSessionObjectFactory.clonePointer(this),
+					FfiConverterString.lower(path),
+					FfiConverterTypeRecordingOptions.lower(opts),
 					callStatus,
 				);
 			},
@@ -2969,39 +9267,79 @@ export class ShellListenerImpl
 		);
 	}
 
+	/**
+	 * Finish the recording; None if none was running.
+	 */
+	public async stopRecording(asyncOpts_?: {
+		signal: AbortSignal;
+	}): Promise<RecordingStats | undefined> /*throws*/ {
+		const __stack = uniffiIsDebug ? new Error().stack : undefined;
+		try {
+			return await uniffiRustCallAsync(
+				/*rustCaller:*/ uniffiCaller,
+				/*rustFutureFunc:*/ () => {
+					return nativeModule().ubrn_uniffi_uniffi_russh_fn_method_shellsession_stop_recording(
+						uniffiTypeShellSessionObjectFactory.clonePointer(this),
+					);
+				},
+				/*pollFunc:*/ nativeModule()
+					.ubrn_ffi_uniffi_russh_rust_future_poll_rust_buffer,
+				/*cancelFunc:*/ nativeModule()
+					.ubrn_ffi_uniffi_russh_rust_future_cancel_rust_buffer,
+				/*completeFunc:*/ nativeModule()
+					.ubrn_ffi_uniffi_russh_rust_future_complete_rust_buffer,
+				/*freeFunc:*/ nativeModule()
+					.ubrn_ffi_uniffi_russh_rust_future_free_rust_buffer,
+				/*liftFunc:*/ FfiConverterOptionalTypeRecordingStats.lift.bind(
+					FfiConverterOptionalTypeRecordingStats,
+				),
+				/*liftString:*/ FfiConverterString.lift,
+				/*asyncOpts:*/ asyncOpts_,
+				/*errorHandler:*/ FfiConverterTypeSshError.lift.bind(
+					FfiConverterTypeSshError,
+				),
+			);
+		} catch (__error: any) {
+			if (uniffiIsDebug && __error instanceof Error) {
+				__error.stack = __stack;
+			}
+			throw __error;
+		}
+	}
+
 	/**
 	 * {@inheritDoc uniffi-bindgen-react-native#UniffiAbstractObject.uniffiDestroy}
 	 */
 	uniffiDestroy(): void {
 		const ptr = (this as any)[destructorGuardSymbol];
 		if (ptr !== undefined) {
-			const pointer = uniffiTypeShellListenerImplObjectFactory.pointer(this);
-			uniffiTypeShellListenerImplObjectFactory.freePointer(pointer);
-			uniffiTypeShellListenerImplObjectFactory.unbless(ptr);
+			const pointer = uniffiTypeShellSessionObjectFactory.pointer(this);
+			uniffiTypeShellSessionObjectFactory.freePointer(pointer);
+			uniffiTypeShellSessionObjectFactory.unbless(ptr);
 			delete (this as any)[destructorGuardSymbol];
 		}
 	}
 
-	static instanceOf(obj: any): obj is ShellListenerImpl {
-		return uniffiTypeShellListenerImplObjectFactory.isConcreteType(obj);
+	static instanceOf(obj: any): obj is ShellSession {
+		return uniffiTypeShellSessionObjectFactory.isConcreteType(obj);
 	}
 }
 
-const uniffiTypeShellListenerImplObjectFactory: UniffiObjectFactory<ShellListener> =
+const uniffiTypeShellSessionObjectFactory: UniffiObjectFactory<ShellSessionInterface> =
 	(() => {
 		return {
-			create(pointer: UnsafeMutableRawPointer): ShellListener {
-				const instance = Object.create(ShellListenerImpl.prototype);
+			create(pointer: UnsafeMutableRawPointer): ShellSessionInterface {
+				const instance = Object.create(ShellSession.prototype);
 				instance[pointerLiteralSymbol] = pointer;
 				instance[destructorGuardSymbol] = this.bless(pointer);
-				instance[uniffiTypeNameSymbol] = 'ShellListenerImpl';
+				instance[uniffiTypeNameSymbol] = 'ShellSession';
 				return instance;
 			},
 
 			bless(p: UnsafeMutableRawPointer): UniffiRustArcPtr {
 				return uniffiCaller.rustCall(
 					/*caller:*/ (status) =>
-						nativeModule().ubrn_uniffi_internal_fn_method_shelllistener_ffi__bless_pointer(
+						nativeModule().ubrn_uniffi_internal_fn_method_shellsession_ffi__bless_pointer(
 							p,
 							status,
 						),
@@ -3013,18 +9351,18 @@ const uniffiTypeShellListenerImplObjectFactory: UniffiObjectFactory<ShellListene
 				ptr.markDestroyed();
 			},
 
-			pointer(obj: ShellListener): UnsafeMutableRawPointer {
+			pointer(obj: ShellSessionInterface): UnsafeMutableRawPointer {
 				if ((obj as any)[destructorGuardSymbol] === undefined) {
 					throw new UniffiInternalError.UnexpectedNullPointer();
 				}
 				return (obj as any)[pointerLiteralSymbol];
 			},
 
-			clonePointer(obj: ShellListener): UnsafeMutableRawPointer {
+			clonePointer(obj: ShellSessionInterface): UnsafeMutableRawPointer {
 				const pointer = this.pointer(obj);
 				return uniffiCaller.rustCall(
 					/*caller:*/ (callStatus) =>
-						nativeModule().ubrn_uniffi_uniffi_russh_fn_clone_shelllistener(
+						nativeModule().ubrn_uniffi_uniffi_russh_fn_clone_shellsession(
 							pointer,
 							callStatus,
 						),
@@ -3035,110 +9373,73 @@ const uniffiTypeShellListenerImplObjectFactory: UniffiObjectFactory<ShellListene
 			freePointer(pointer: UnsafeMutableRawPointer): void {
 				uniffiCaller.rustCall(
 					/*caller:*/ (callStatus) =>
-						nativeModule().ubrn_uniffi_uniffi_russh_fn_free_shelllistener(
+						nativeModule().ubrn_uniffi_uniffi_russh_fn_free_shellsession(
 							pointer,
 							callStatus,
 						),
 					/*liftString:*/ FfiConverterString.lift,
-				);
-			},
-
-			isConcreteType(obj: any): obj is ShellListener {
-				return (
-					obj[destructorGuardSymbol] &&
-					obj[uniffiTypeNameSymbol] === 'ShellListenerImpl'
-				);
-			},
-		};
-	})();
-// FfiConverter for ShellListener
-const FfiConverterTypeShellListener = new FfiConverterObjectWithCallbacks(
-	uniffiTypeShellListenerImplObjectFactory,
-);
-
-// Add a vtavble for the callbacks that go in ShellListener.
-
-// Put the implementation in a struct so we don't pollute the top-level namespace
-const uniffiCallbackInterfaceShellListener: {
-	vtable: UniffiVTableCallbackInterfaceShellListener;
-	register: () => void;
-} = {
-	// Create the VTable using a series of closures.
-	// ts automatically converts these into C callback functions.
-	vtable: {
-		onEvent: (uniffiHandle: bigint, ev: Uint8Array) => {
-			const uniffiMakeCall = (): void => {
-				const jsCallback = FfiConverterTypeShellListener.lift(uniffiHandle);
-				return jsCallback.onEvent(FfiConverterTypeShellEvent.lift(ev));
-			};
-			const uniffiResult = UniffiResult.ready<void>();
-			const uniffiHandleSuccess = (obj: any) => {};
-			const uniffiHandleError = (code: number, errBuf: UniffiByteArray) => {
-				UniffiResult.writeError(uniffiResult, code, errBuf);
-			};
-			uniffiTraitInterfaceCall(
-				/*makeCall:*/ uniffiMakeCall,
-				/*handleSuccess:*/ uniffiHandleSuccess,
-				/*handleError:*/ uniffiHandleError,
-				/*lowerString:*/ FfiConverterString.lower,
-			);
-			return uniffiResult;
-		},
-		uniffiFree: (uniffiHandle: UniffiHandle): void => {
-			// ShellListener: this will throw a stale handle error if the handle isn't found.
-			FfiConverterTypeShellListener.drop(uniffiHandle);
-		},
-	},
-	register: () => {
-		nativeModule().ubrn_uniffi_uniffi_russh_fn_init_callback_vtable_shelllistener(
-			uniffiCallbackInterfaceShellListener.vtable,
-		);
-	},
-};
+				);
+			},
 
-export interface ShellSessionInterface {
-	/**
-	 * Add a listener with optional replay and live follow.
-	 */
-	addListener(
-		listener: ShellListener,
-		opts: ListenerOptions,
-	) /*throws*/ : /*u64*/ bigint;
+			isConcreteType(obj: any): obj is ShellSessionInterface {
+				return (
+					obj[destructorGuardSymbol] &&
+					obj[uniffiTypeNameSymbol] === 'ShellSession'
+				);
+			},
+		};
+	})();
+// FfiConverter for ShellSessionInterface
+const FfiConverterTypeShellSession = new FfiConverterObject(
+	uniffiTypeShellSessionObjectFactory,
+);
+
+export interface SshConnectionInterface {
+	disconnect(asyncOpts_?: { signal: AbortSignal }) /*throws*/ : Promise<void>;
 	/**
-	 * Buffer statistics snapshot.
+	 * Convenience snapshot for property-like access in TS.
 	 */
-	bufferStats(): BufferStats;
+	getInfo(): SshConnectionInfo;
 	/**
-	 * Close the associated shell channel and stop its reader task.
+	 * Traffic and latency counters snapshot.
 	 */
-	close(asyncOpts_?: { signal: AbortSignal }) /*throws*/ : Promise<void>;
+	getMetrics(): SshConnectionMetrics;
 	/**
-	 * Current next sequence number.
+	 * install_terminfo with the entry bundled in this library for
+	 * `term_name` (currently "fressh"). Fails with InvalidArgument for
+	 * other names.
 	 */
-	currentSeq(): /*u64*/ bigint;
-	getInfo(): ShellSessionInfo;
+	installBundledTerminfo(
+		termName: string,
+		asyncOpts_?: { signal: AbortSignal },
+	) /*throws*/ : Promise<TerminfoStatus>;
 	/**
-	 * Read the ring buffer from a cursor.
+	 * Install `compiled_entry` for `term_name` into the server's ~/.terminfo,
+	 * unless the server can already resolve that name. Compile the entry
+	 * with `tic -o`, or use install_bundled_terminfo. Run before starting a
+	 * shell with a TERM the server may not know.
 	 */
-	readBuffer(
-		cursor: Cursor,
-		maxBytes: /*u64*/ bigint | undefined,
-	): BufferReadResult;
-	removeListener(id: /*u64*/ bigint): void;
+	installTerminfo(
+		termName: string,
+		compiledEntry: ArrayBuffer,
+		asyncOpts_?: { signal: AbortSignal },
+	) /*throws*/ : Promise<TerminfoStatus>;
 	/**
-	 * Send bytes to the active shell (stdin).
+	 * Force a key re-exchange now. Resolves once the new keys are in use;
+	 * fails if that takes longer than REKEY_TIMEOUT_MS.
 	 */
-	sendData(
-		data: ArrayBuffer,
+	rekey(asyncOpts_?: { signal: AbortSignal }) /*throws*/ : Promise<void>;
+	startShell(
+		opts: StartShellOptions,
 		asyncOpts_?: { signal: AbortSignal },
-	) /*throws*/ : Promise<void>;
+	) /*throws*/ : Promise<ShellSessionInterface>;
 }
 
-export class ShellSession
+export class SshConnection
 	extends UniffiAbstractObject
-	implements ShellSessionInterface
+	implements SshConnectionInterface
 {
-	readonly [uniffiTypeNameSymbol] = 'ShellSession';
+	readonly [uniffiTypeNameSymbol] = 'SshConnection';
 	readonly [destructorGuardSymbol]: UniffiRustArcPtr;
 	readonly [pointerLiteralSymbol]: UnsafeMutableRawPointer;
 	// No primary constructor declared for this class.
@@ -3146,55 +9447,10 @@ export class ShellSession
 		super();
 		this[pointerLiteralSymbol] = pointer;
 		this[destructorGuardSymbol] =
-			uniffiTypeShellSessionObjectFactory.bless(pointer);
-	}
-
-	/**
-	 * Add a listener with optional replay and live follow.
-	 */
-	public addListener(
-		listener: ShellListener,
-		opts: ListenerOptions,
-	): /*u64*/ bigint /*throws*/ {
-		return FfiConverterUInt64.lift(
-			uniffiCaller.rustCallWithError(
-				/*liftError:*/ FfiConverterTypeSshError.lift.bind(
-					FfiConverterTypeSshError,
-				),
-				/*caller:*/ (callStatus) => {
-					return nativeModule().ubrn_uniffi_uniffi_russh_fn_method_shellsession_add_listener(
-						uniffiTypeShellSessionObjectFactory.clonePointer(this),
-						FfiConverterTypeShellListener.lower(listener),
-						FfiConverterTypeListenerOptions.lower(opts),
-						callStatus,
-					);
-				},
-				/*liftString:*/ FfiConverterString.lift,
-			),
-		);
-	}
-
-	/**
-	 * Buffer statistics snapshot.
-	 */
-	public bufferStats(): BufferStats {
-		return FfiConverterTypeBufferStats.lift(
-			uniffiCaller.rustCall(
-				/*caller:*/ (callStatus) => {
-					return nativeModule().ubrn_uniffi_uniffi_russh_fn_method_shellsession_buffer_stats(
-						uniffiTypeShellSessionObjectFactory.clonePointer(this),
-						callStatus,
-					);
-				},
-				/*liftString:*/ FfiConverterString.lift,
-			),
-		);
+			uniffiTypeSshConnectionObjectFactory.bless(pointer);
 	}
 
-	/**
-	 * Close the associated shell channel and stop its reader task.
-	 */
-	public async close(asyncOpts_?: {
+	public async disconnect(asyncOpts_?: {
 		signal: AbortSignal;
 	}): Promise<void> /*throws*/ {
 		const __stack = uniffiIsDebug ? new Error().stack : undefined;
@@ -3202,8 +9458,8 @@ export class ShellSession
 			return await uniffiRustCallAsync(
 				/*rustCaller:*/ uniffiCaller,
 				/*rustFutureFunc:*/ () => {
-					return nativeModule().ubrn_uniffi_uniffi_russh_fn_method_shellsession_close(
-						uniffiTypeShellSessionObjectFactory.clonePointer(this),
+					return nativeModule().ubrn_uniffi_uniffi_russh_fn_method_sshconnection_disconnect(
+						uniffiTypeSshConnectionObjectFactory.clonePointer(this),
 					);
 				},
 				/*pollFunc:*/ nativeModule()
@@ -3230,14 +9486,14 @@ export class ShellSession
 	}
 
 	/**
-	 * Current next sequence number.
+	 * Convenience snapshot for property-like access in TS.
 	 */
-	public currentSeq(): /*u64*/ bigint {
-		return FfiConverterUInt64.lift(
+	public getInfo(): SshConnectionInfo {
+		return FfiConverterTypeSshConnectionInfo.lift(
 			uniffiCaller.rustCall(
 				/*caller:*/ (callStatus) => {
-					return nativeModule().ubrn_uniffi_uniffi_russh_fn_method_shellsession_current_seq(
-						uniffiTypeShellSessionObjectFactory.clonePointer(this),
+					return nativeModule().ubrn_uniffi_uniffi_russh_fn_method_sshconnection_get_info(
+						uniffiTypeSshConnectionObjectFactory.clonePointer(this),
 						callStatus,
 					);
 				},
@@ -3246,12 +9502,15 @@ export class ShellSession
 		);
 	}
 
-	public getInfo(): ShellSessionInfo {
-		return FfiConverterTypeShellSessionInfo.lift(
+	/**
+	 * Traffic and latency counters snapshot.
+	 */
+	public getMetrics(): SshConnectionMetrics {
+		return FfiConverterTypeSshConnectionMetrics.lift(
 			uniffiCaller.rustCall(
 				/*caller:*/ (callStatus) => {
-					return nativeModule().ubrn_uniffi_uniffi_russh_fn_method_shellsession_get_info(
-						uniffiTypeShellSessionObjectFactory.clonePointer(this),
+					return nativeModule().ubrn_uniffi_uniffi_russh_fn_method_sshconnection_get_metrics(
+						uniffiTypeSshConnectionObjectFactory.clonePointer(this),
 						callStatus,
 					);
 				},
@@ -3261,55 +9520,110 @@ export class ShellSession
 	}
 
 	/**
-	 * Read the ring buffer from a cursor.
+	 * install_terminfo with the entry bundled in this library for
+	 * `term_name` (currently "fressh"). Fails with InvalidArgument for
+	 * other names.
 	 */
-	public readBuffer(
-		cursor: Cursor,
-		maxBytes: /*u64*/ bigint | undefined,
-	): BufferReadResult {
-		return FfiConverterTypeBufferReadResult.lift(
-			uniffiCaller.rustCall(
-				/*caller:*/ (callStatus) => {
-					return nativeModule().ubrn_uniffi_uniffi_russh_fn_method_shellsession_read_buffer(
-						uniffiTypeShellSessionObjectFactory.clonePointer(this),
-						FfiConverterTypeCursor.lower(cursor),
-						FfiConverterOptionalUInt64.lower(maxBytes),
-						callStatus,
+	public async installBundledTerminfo(
+		termName: string,
+		asyncOpts_?: { signal: AbortSignal },
+	): Promise<TerminfoStatus> /*throws*/ {
+		const __stack = uniffiIsDebug ? new Error().stack : undefined;
+		try {
+			return await uniffiRustCallAsync(
+				/*rustCaller:*/ uniffiCaller,
+				/*rustFutureFunc:*/ () => {
+					return nativeModule().ubrn_uniffi_uniffi_russh_fn_method_sshconnection_install_bundled_terminfo(
+						uniffiTypeSshConnectionObjectFactory.clonePointer(this),
+						FfiConverterString.lower(termName),
 					);
 				},
+				/*pollFunc:*/ nativeModule()
+					.ubrn_ffi_uniffi_russh_rust_future_poll_rust_buffer,
+				/*cancelFunc:*/ nativeModule()
+					.ubrn_ffi_uniffi_russh_rust_future_cancel_rust_buffer,
+				/*completeFunc:*/ nativeModule()
+					.ubrn_ffi_uniffi_russh_rust_future_complete_rust_buffer,
+				/*freeFunc:*/ nativeModule()
+					.ubrn_ffi_uniffi_russh_rust_future_free_rust_buffer,
+				/*liftFunc:*/ FfiConverterTypeTerminfoStatus.lift.bind(
+					FfiConverterTypeTerminfoStatus,
+				),
 				/*liftString:*/ FfiConverterString.lift,
-			),
-		);
+				/*asyncOpts:*/ asyncOpts_,
+				/*errorHandler:*/ FfiConverterTypeSshError.lift.bind(
+					FfiConverterTypeSshError,
+				),
+			);
+		} catch (__error: any) {
+			if (uniffiIsDebug && __error instanceof Error) {
+				__error.stack = __stack;
+			}
+			throw __error;
+		}
 	}
 
-	public removeListener(id: /*u64*/ bigint): void {
-		uniffiCaller.rustCall(
-			/*caller:*/ (callStatus) => {
-				nativeModule().ubrn_uniffi_uniffi_russh_fn_method_shellsession_remove_listener(
-					uniffiTypeShellSessionObjectFactory.clonePointer(this),
-					FfiConverterUInt64.lower(id),
-					callStatus,
-				);
-			},
-			/*liftString:*/ FfiConverterString.lift,
-		);
+	/**
+	 * Install `compiled_entry` for `term_name` into the server's ~/.terminfo,
+	 * unless the server can already resolve that name. Compile the entry
+	 * with `tic -o`, or use install_bundled_terminfo. Run before starting a
+	 * shell with a TERM the server may not know.
+	 */
+	public async installTerminfo(
+		termName: string,
+		compiledEntry: ArrayBuffer,
+		asyncOpts_?: { signal: AbortSignal },
+	): Promise<TerminfoStatus> /*throws*/ {
+		const __stack = uniffiIsDebug ? new Error().stack : undefined;
+		try {
+			return await uniffiRustCallAsync(
+				/*rustCaller:*/ uniffiCaller,
+				/*rustFutureFunc:*/ () => {
+					return nativeModule().ubrn_uniffi_uniffi_russh_fn_method_sshconnection_install_terminfo(
+						uniffiTypeSshConnectionObjectFactory.clonePointer(this),
+						FfiConverterString.lower(termName),
+						FfiConverterArrayBuffer.lower(compiledEntry),
+					);
+				},
+				/*pollFunc:*/ nativeModule()
+					.ubrn_ffi_uniffi_russh_rust_future_poll_rust_buffer,
+				/*cancelFunc:*/ nativeModule()
+					.ubrn_ffi_uniffi_russh_rust_future_cancel_rust_buffer,
+				/*completeFunc:*/ nativeModule()
+					.ubrn_ffi_uniffi_russh_rust_future_complete_rust_buffer,
+				/*freeFunc:*/ nativeModule()
+					.ubrn_ffi_uniffi_russh_rust_future_free_rust_buffer,
+				/*liftFunc:*/ FfiConverterTypeTerminfoStatus.lift.bind(
+					FfiConverterTypeTerminfoStatus,
+				),
+				/*liftString:*/ FfiConverterString.lift,
+				/*asyncOpts:*/ asyncOpts_,
+				/*errorHandler:*/ FfiConverterTypeSshError.lift.bind(
+					FfiConverterTypeSshError,
+				),
+			);
+		} catch (__error: any) {
+			if (uniffiIsDebug && __error instanceof Error) {
+				__error.stack = __stack;
+			}
+			throw __error;
+		}
 	}
 
 	/**
-	 * Send bytes to the active shell (stdin).
+	 * Force a key re-exchange now. Resolves once the new keys are in use;
+	 * fails if that takes longer than REKEY_TIMEOUT_MS.
 	 */
-	public async sendData(
-		data: ArrayBuffer,
-		asyncOpts_?: { signal: AbortSignal },
-	): Promise<void> /*throws*/ {
+	public async rekey(asyncOpts_?: {
+		signal: AbortSignal;
+	}): Promise<void> /*throws*/ {
 		const __stack = uniffiIsDebug ? new Error().stack : undefined;
 		try {
 			return await uniffiRustCallAsync(
 				/*rustCaller:*/ uniffiCaller,
 				/*rustFutureFunc:*/ () => {
-					return nativeModule().ubrn_uniffi_uniffi_russh_fn_method_shellsession_send_data(
-						uniffiTypeShellSessionObjectFactory.clonePointer(this),
-						FfiConverterArrayBuffer.lower(data),
+					return nativeModule().ubrn_uniffi_uniffi_russh_fn_method_sshconnection_rekey(
+						uniffiTypeSshConnectionObjectFactory.clonePointer(this),
 					);
 				},
 				/*pollFunc:*/ nativeModule()
@@ -3335,39 +9649,78 @@ export class ShellSession
 		}
 	}
 
+	public async startShell(
+		opts: StartShellOptions,
+		asyncOpts_?: { signal: AbortSignal },
+	): Promise<ShellSessionInterface> /*throws*/ {
+		const __stack = uniffiIsDebug ? new Error().stack : undefined;
+		try {
+			return await uniffiRustCallAsync(
+				/*rustCaller:*/ uniffiCaller,
+				/*rustFutureFunc:*/ () => {
+					return nativeModule().ubrn_uniffi_uniffi_russh_fn_method_sshconnection_start_shell(
+						uniffiTypeSshConnectionObjectFactory.clonePointer(this),
+						FfiConverterTypeStartShellOptions.lower(opts),
+					);
+				},
+				/*pollFunc:*/ nativeModule()
+					.ubrn_ffi_uniffi_russh_rust_future_poll_pointer,
+				/*cancelFunc:*/ nativeModule()
+					.ubrn_ffi_uniffi_russh_rust_future_cancel_pointer,
+				/*completeFunc:*/ nativeModule()
+					.ubrn_ffi_uniffi_russh_rust_future_complete_pointer,
+				/*freeFunc:*/ nativeModule()
+					.ubrn_ffi_uniffi_russh_rust_future_free_pointer,
+				/*liftFunc:*/ FfiConverterTypeShellSession.lift.bind(
+					FfiConverterTypeShellSession,
+				),
+				/*liftString:*/ FfiConverterString.lift,
+				/*asyncOpts:*/ asyncOpts_,
+				/*errorHandler:*/ FfiConverterTypeSshError.lift.bind(
+					FfiConverterTypeSshError,
+				),
+			);
+		} catch (__error: any) {
+			if (uniffiIsDebug && __error instanceof Error) {
+				__error.stack = __stack;
+			}
+			throw __error;
+		}
+	}
+
 	/**
 	 * {@inheritDoc uniffi-bindgen-react-native#UniffiAbstractObject.uniffiDestroy}
 	 */
 	uniffiDestroy(): void {
 		const ptr = (this as any)[destructorGuardSymbol];
 		if (ptr !== undefined) {
-			const pointer = uniffiTypeShellSessionObjectFactory.pointer(this);
-			uniffiTypeShellSessionObjectFactory.freePointer(pointer);
-			uniffiTypeShellSessionObjectFactory.unbless(ptr);
+			const pointer = uniffiTypeSshConnectionObjectFactory.pointer(this);
+			uniffiTypeSshConnectionObjectFactory.freePointer(pointer);
+			uniffiTypeSshConnectionObjectFactory.unbless(ptr);
 			delete (this as any)[destructorGuardSymbol];
 		}
 	}
 
-	static instanceOf(obj: any): obj is ShellSession {
-		return uniffiTypeShellSessionObjectFactory.isConcreteType(obj);
+	static instanceOf(obj: any): obj is SshConnection {
+		return uniffiTypeSshConnectionObjectFactory.isConcreteType(obj);
 	}
 }
 
-const uniffiTypeShellSessionObjectFactory: UniffiObjectFactory<ShellSessionInterface> =
+const uniffiTypeSshConnectionObjectFactory: UniffiObjectFactory<SshConnectionInterface> =
 	(() => {
 		return {
-			create(pointer: UnsafeMutableRawPointer): ShellSessionInterface {
-				const instance = Object.create(ShellSession.prototype);
+			create(pointer: UnsafeMutableRawPointer): SshConnectionInterface {
+				const instance = Object.create(SshConnection.prototype);
 				instance[pointerLiteralSymbol] = pointer;
 				instance[destructorGuardSymbol] = this.bless(pointer);
-				instance[uniffiTypeNameSymbol] = 'ShellSession';
+				instance[uniffiTypeNameSymbol] = 'SshConnection';
 				return instance;
 			},
 
 			bless(p: UnsafeMutableRawPointer): UniffiRustArcPtr {
 				return uniffiCaller.rustCall(
 					/*caller:*/ (status) =>
-						nativeModule().ubrn_uniffi_internal_fn_method_shellsession_ffi__bless_pointer(
+						nativeModule().ubrn_uniffi_internal_fn_method_sshconnection_ffi__bless_pointer(
 							p,
 							status,
 						),
@@ -3379,18 +9732,18 @@ const uniffiTypeShellSessionObjectFactory: UniffiObjectFactory<ShellSessionInter
 				ptr.markDestroyed();
 			},
 
-			pointer(obj: ShellSessionInterface): UnsafeMutableRawPointer {
+			pointer(obj: SshConnectionInterface): UnsafeMutableRawPointer {
 				if ((obj as any)[destructorGuardSymbol] === undefined) {
 					throw new UniffiInternalError.UnexpectedNullPointer();
 				}
 				return (obj as any)[pointerLiteralSymbol];
 			},
 
-			clonePointer(obj: ShellSessionInterface): UnsafeMutableRawPointer {
+			clonePointer(obj: SshConnectionInterface): UnsafeMutableRawPointer {
 				const pointer = this.pointer(obj);
 				return uniffiCaller.rustCall(
 					/*caller:*/ (callStatus) =>
-						nativeModule().ubrn_uniffi_uniffi_russh_fn_clone_shellsession(
+						nativeModule().ubrn_uniffi_uniffi_russh_fn_clone_sshconnection(
 							pointer,
 							callStatus,
 						),
@@ -3401,7 +9754,7 @@ const uniffiTypeShellSessionObjectFactory: UniffiObjectFactory<ShellSessionInter
 			freePointer(pointer: UnsafeMutableRawPointer): void {
 				uniffiCaller.rustCall(
 					/*caller:*/ (callStatus) =>
-						nativeModule().ubrn_uniffi_uniffi_russh_fn_free_shellsession(
+						nativeModule().ubrn_uniffi_uniffi_russh_fn_free_sshconnection(
 							pointer,
 							callStatus,
 						),
@@ -3409,90 +9762,149 @@ const uniffiTypeShellSessionObjectFactory: UniffiObjectFactory<ShellSessionInter
 				);
 			},
 
-			isConcreteType(obj: any): obj is ShellSessionInterface {
+			isConcreteType(obj: any): obj is SshConnectionInterface {
 				return (
 					obj[destructorGuardSymbol] &&
-					obj[uniffiTypeNameSymbol] === 'ShellSession'
+					obj[uniffiTypeNameSymbol] === 'SshConnection'
 				);
 			},
 		};
 	})();
-// FfiConverter for ShellSessionInterface
-const FfiConverterTypeShellSession = new FfiConverterObject(
-	uniffiTypeShellSessionObjectFactory,
+// FfiConverter for SshConnectionInterface
+const FfiConverterTypeSshConnection = new FfiConverterObject(
+	uniffiTypeSshConnectionObjectFactory,
 );
 
-export interface SshConnectionInterface {
-	disconnect(asyncOpts_?: { signal: AbortSignal }) /*throws*/ : Promise<void>;
+/**
+ * Terminal emulator (xterm subset) keeping a cell grid, alternate screen and
+ * scrollback. Attach one to a shell with `ShellSession::attach_emulator`, or
+ * feed it bytes directly (e.g. from a recording).
+ */
+export interface TerminalEmulatorInterface {
 	/**
-	 * Convenience snapshot for property-like access in TS.
+	 * Parse terminal output.
 	 */
-	getInfo(): SshConnectionInfo;
-	startShell(
-		opts: StartShellOptions,
-		asyncOpts_?: { signal: AbortSignal },
-	) /*throws*/ : Promise<ShellSessionInterface>;
+	feed(bytes: ArrayBuffer): void;
+	/**
+	 * Resize the grid. Lines are truncated or padded, not reflowed.
+	 */
+	resize(cols: /*u32*/ number, rows: /*u32*/ number): void;
+	scrollbackLen(): /*u64*/ bigint;
+	/**
+	 * Scrollback lines from `start` (0 = oldest).
+	 */
+	scrollbackLines(
+		start: /*u64*/ bigint,
+		count: /*u32*/ number,
+	): Array<ScreenLine>;
+	snapshot(): ScreenSnapshot;
+	/**
+	 * Rows changed since the last call (or since creation), then reset.
+	 */
+	takeDamage(): ScreenDamage;
+	/**
+	 * Replies the output asked the terminal for (cursor position, device
+	 * attributes). Only send them to the server if nothing else (e.g. a
+	 * webview terminal) already answers.
+	 */
+	takeResponses(): ArrayBuffer;
 }
 
-export class SshConnection
+/**
+ * Terminal emulator (xterm subset) keeping a cell grid, alternate screen and
+ * scrollback. Attach one to a shell with `ShellSession::attach_emulator`, or
+ * feed it bytes directly (e.g. from a recording).
+ */
+export class TerminalEmulator
 	extends UniffiAbstractObject
-	implements SshConnectionInterface
+	implements TerminalEmulatorInterface
 {
-	readonly [uniffiTypeNameSymbol] = 'SshConnection';
+	readonly [uniffiTypeNameSymbol] = 'TerminalEmulator';
 	readonly [destructorGuardSymbol]: UniffiRustArcPtr;
 	readonly [pointerLiteralSymbol]: UnsafeMutableRawPointer;
-	// No primary constructor declared for this class.
-	private constructor(pointer: UnsafeMutableRawPointer) {
+	constructor(
+		cols: /*u32*/ number,
+		rows: /*u32*/ number,
+		scrollbackLines: /*u32*/ number | undefined,
+	) {
 		super();
+		const pointer = uniffiCaller.rustCall(
+			/*caller:*/ (callStatus) => {
+				return nativeModule().ubrn_uniffi_uniffi_russh_fn_constructor_terminalemulator_new(
+					FfiConverterUInt32.lower(cols),
+					FfiConverterUInt32.lower(rows),
+					FfiConverterOptionalUInt32.lower(scrollbackLines),
+					callStatus,
+				);
+			},
+			/*liftString:*/ FfiConverterString.lift,
+		);
 		this[pointerLiteralSymbol] = pointer;
 		this[destructorGuardSymbol] =
-			uniffiTypeSshConnectionObjectFactory.bless(pointer);
+			uniffiTypeTerminalEmulatorObjectFactory.bless(pointer);
 	}
 
-	public async disconnect(asyncOpts_?: {
-		signal: AbortSignal;
-	}): Promise<void> /*throws*/ {
-		const __stack = uniffiIsDebug ? new Error().stack : undefined;
-		try {
-			return await uniffiRustCallAsync(
-				/*rustCaller:*/ uniffiCaller,
-				/*rustFutureFunc:*/ () => {
-					return nativeModule().ubrn_uniffi_uniffi_russh_fn_method_sshconnection_disconnect(
-						uniffiTypeSshConnectionObjectFactory.clonePointer(this),
+	/**
+	 * Parse terminal output.
+	 */
+	public feed(bytes: ArrayBuffer): void {
+		uniffiCaller.rustCall(
+			/*caller:*/ (callStatus) => {
+				nativeModule().ubrn_uniffi_uniffi_russh_fn_method_terminalemulator_feed(
+					uniffiTypeTerminalEmulatorObjectFactory.clonePointer(this),
+					FfiConverterArrayBuffer.lower(bytes),
+					callStatus,
+				);
+			},
+			/*liftString:*/ FfiConverterString.lift,
+		);
+	}
+
+	/**
+	 * Resize the grid. Lines are truncated or padded, not reflowed.
+	 */
+	public resize(cols: /*u32*/ number, rows: /*u32*/ number): void {
+		uniffiCaller.rustCall(
+			/*caller:*/ (callStatus) => {
+				nativeModule().ubrn_uniffi_uniffi_russh_fn_method_terminalemulator_resize(
+					uniffiTypeTerminalEmulatorObjectFactory.clonePointer(this),
+					FfiConverterUInt32.lower(cols),
+					FfiConverterUInt32.lower(rows),
+					callStatus,
+				);
+			},
+			/*liftString:*/ FfiConverterString.lift,
+		);
+	}
+
+	public scrollbackLen(): /*u64*/ bigint {
+		return FfiConverterUInt64.lift(
+			uniffiCaller.rustCall(
+				/*caller:*/ (callStatus) => {
+					return nativeModule().ubrn_uniffi_uniffi_russh_fn_method_terminalemulator_scrollback_len(
+						uniffiTypeTerminalEmulatorObjectFactory.clonePointer(this),
+						callStatus,
 					);
 				},
-				/*pollFunc:*/ nativeModule()
-					.ubrn_ffi_uniffi_russh_rust_future_poll_void,
-				/*cancelFunc:*/ nativeModule()
-					.ubrn_ffi_uniffi_russh_rust_future_cancel_void,
-				/*completeFunc:*/ nativeModule()
-					.ubrn_ffi_uniffi_russh_rust_future_complete_void,
-				/*freeFunc:*/ nativeModule()
-					.ubrn_ffi_uniffi_russh_rust_future_free_void,
-				/*liftFunc:*/ (_v) => {},
 				/*liftString:*/ FfiConverterString.lift,
-				/*asyncOpts:*/ asyncOpts_,
-				/*errorHandler:*/ FfiConverterTypeSshError.lift.bind(
-					FfiConverterTypeSshError,
-				),
-			);
-		} catch (__error: any) {
-			if (uniffiIsDebug && __error instanceof Error) {
-				__error.stack = __stack;
-			}
-			throw __error;
-		}
+			),
+		);
 	}
 
 	/**
-	 * Convenience snapshot for property-like access in TS.
+	 * Scrollback lines from `start` (0 = oldest).
 	 */
-	public getInfo(): SshConnectionInfo {
-		return FfiConverterTypeSshConnectionInfo.lift(
+	public scrollbackLines(
+		start: /*u64*/ bigint,
+		count: /*u32*/ number,
+	): Array<ScreenLine> {
+		return FfiConverterArrayTypeScreenLine.lift(
 			uniffiCaller.rustCall(
 				/*caller:*/ (callStatus) => {
-					return nativeModule().ubrn_uniffi_uniffi_russh_fn_method_sshconnection_get_info(
-						uniffiTypeSshConnectionObjectFactory.clonePointer(this),
+					return nativeModule().ubrn_uniffi_uniffi_russh_fn_method_terminalemulator_scrollback_lines(
+						uniffiTypeTerminalEmulatorObjectFactory.clonePointer(this),
+						FfiConverterUInt64.lower(start),
+						FfiConverterUInt32.lower(count),
 						callStatus,
 					);
 				},
@@ -3501,43 +9913,54 @@ export class SshConnection
 		);
 	}
 
-	public async startShell(
-		opts: StartShellOptions,
-		asyncOpts_?: { signal: AbortSignal },
-	): Promise<ShellSessionInterface> /*throws*/ {
-		const __stack = uniffiIsDebug ? new Error().stack : undefined;
-		try {
-			return await uniffiRustCallAsync(
-				/*rustCaller:*/ uniffiCaller,
-				/*rustFutureFunc:*/ () => {
-					return nativeModule().ubrn_uniffi_uniffi_russh_fn_method_sshconnection_start_shell(
-						uniffiTypeSshConnectionObjectFactory.clonePointer(this),
-						FfiConverterTypeStartShellOptions.lower(opts),
+	public snapshot(): ScreenSnapshot {
+		return FfiConverterTypeScreenSnapshot.lift(
+			uniffiCaller.rustCall(
+				/*caller:*/ (callStatus) => {
+					return nativeModule().ubrn_uniffi_uniffi_russh_fn_method_terminalemulator_snapshot(
+						uniffiTypeTerminalEmulatorObjectFactory.clonePointer(this),
+						callStatus,
 					);
 				},
-				/*pollFunc:*/ nativeModule()
-					.ubrn_ffi_uniffi_russh_rust_future_poll_pointer,
-				/*cancelFunc:*/ nativeModule()
-					.ubrn_ffi_uniffi_russh_rust_future_cancel_pointer,
-				/*completeFunc:*/ nativeModule()
-					.ubrn_ffi_uniffi_russh_rust_future_complete_pointer,
-				/*freeFunc:*/ nativeModule()
-					.ubrn_ffi_uniffi_russh_rust_future_free_pointer,
-				/*liftFunc:*/ FfiConverterTypeShellSession.lift.bind(
-					FfiConverterTypeShellSession,
-				),
 				/*liftString:*/ FfiConverterString.lift,
-				/*asyncOpts:*/ asyncOpts_,
-				/*errorHandler:*/ FfiConverterTypeSshError.lift.bind(
-					FfiConverterTypeSshError,
-				),
-			);
-		} catch (__error: any) {
-			if (uniffiIsDebug && __error instanceof Error) {
-				__error.stack = __stack;
-			}
-			throw __error;
-		}
+			),
+		);
+	}
+
+	/**
+	 * Rows changed since the last call (or since creation), then reset.
+	 */
+	public takeDamage(): ScreenDamage {
+		return FfiConverterTypeScreenDamage.lift(
+			uniffiCaller.rustCall(
+				/*caller:*/ (callStatus) => {
+					return nativeModule().ubrn_uniffi_uniffi_russh_fn_method_terminalemulator_take_damage(
+						uniffiTypeTerminalEmulatorObjectFactory.clonePointer(this),
+						callStatus,
+					);
+				},
+				/*liftString:*/ FfiConverterString.lift,
+			),
+		);
+	}
+
+	/**
+	 * Replies the output asked the terminal for (cursor position, device
+	 * attributes). Only send them to the server if nothing else (e.g. a
+	 * webview terminal) already answers.
+	 */
+	public takeResponses(): ArrayBuffer {
+		return FfiConverterArrayBuffer.lift(
+			uniffiCaller.rustCall(
+				/*caller:*/ (callStatus) => {
+					return nativeModule().ubrn_uniffi_uniffi_russh_fn_method_terminalemulator_take_responses(
+						uniffiTypeTerminalEmulatorObjectFactory.clonePointer(this),
+						callStatus,
+					);
+				},
+				/*liftString:*/ FfiConverterString.lift,
+			),
+		);
 	}
 
 	/**
@@ -3546,33 +9969,33 @@ export class SshConnection
 	uniffiDestroy(): void {
 		const ptr = (this as any)[destructorGuardSymbol];
 		if (ptr !== undefined) {
-			const pointer = uniffiTypeSshConnectionObjectFactory.pointer(this);
-			uniffiTypeSshConnectionObjectFactory.freePointer(pointer);
-			uniffiTypeSshConnectionObjectFactory.unbless(ptr);
+			const pointer = uniffiTypeTerminalEmulatorObjectFactory.pointer(this);
+			uniffiTypeTerminalEmulatorObjectFactory.freePointer(pointer);
+			uniffiTypeTerminalEmulatorObjectFactory.unbless(ptr);
 			delete (this as any)[destructorGuardSymbol];
 		}
 	}
 
-	static instanceOf(obj: any): obj is SshConnection {
-		return uniffiTypeSshConnectionObjectFactory.isConcreteType(obj);
+	static instanceOf(obj: any): obj is TerminalEmulator {
+		return uniffiTypeTerminalEmulatorObjectFactory.isConcreteType(obj);
 	}
 }
 
-const uniffiTypeSshConnectionObjectFactory: UniffiObjectFactory<SshConnectionInterface> =
+const uniffiTypeTerminalEmulatorObjectFactory: UniffiObjectFactory<TerminalEmulatorInterface> =
 	(() => {
 		return {
-			create(pointer: UnsafeMutableRawPointer): SshConnectionInterface {
-				const instance = Object.create(SshConnection.prototype);
+			create(pointer: UnsafeMutableRawPointer): TerminalEmulatorInterface {
+				const instance = Object.create(TerminalEmulator.prototype);
 				instance[pointerLiteralSymbol] = pointer;
 				instance[destructorGuardSymbol] = this.bless(pointer);
-				instance[uniffiTypeNameSymbol] = 'SshConnection';
+				instance[uniffiTypeNameSymbol] = 'TerminalEmulator';
 				return instance;
 			},
 
 			bless(p: UnsafeMutableRawPointer): UniffiRustArcPtr {
 				return uniffiCaller.rustCall(
 					/*caller:*/ (status) =>
-						nativeModule().ubrn_uniffi_internal_fn_method_sshconnection_ffi__bless_pointer(
+						nativeModule().ubrn_uniffi_internal_fn_method_terminalemulator_ffi__bless_pointer(
 							p,
 							status,
 						),
@@ -3584,18 +10007,18 @@ const uniffiTypeSshConnectionObjectFactory: UniffiObjectFactory<SshConnectionInt
 				ptr.markDestroyed();
 			},
 
-			pointer(obj: SshConnectionInterface): UnsafeMutableRawPointer {
+			pointer(obj: TerminalEmulatorInterface): UnsafeMutableRawPointer {
 				if ((obj as any)[destructorGuardSymbol] === undefined) {
 					throw new UniffiInternalError.UnexpectedNullPointer();
 				}
 				return (obj as any)[pointerLiteralSymbol];
 			},
 
-			clonePointer(obj: SshConnectionInterface): UnsafeMutableRawPointer {
+			clonePointer(obj: TerminalEmulatorInterface): UnsafeMutableRawPointer {
 				const pointer = this.pointer(obj);
 				return uniffiCaller.rustCall(
 					/*caller:*/ (callStatus) =>
-						nativeModule().ubrn_uniffi_uniffi_russh_fn_clone_sshconnection(
+						nativeModule().ubrn_uniffi_uniffi_russh_fn_clone_terminalemulator(
 							pointer,
 							callStatus,
 						),
@@ -3606,7 +10029,7 @@ const uniffiTypeSshConnectionObjectFactory: UniffiObjectFactory<SshConnectionInt
 			freePointer(pointer: UnsafeMutableRawPointer): void {
 				uniffiCaller.rustCall(
 					/*caller:*/ (callStatus) =>
-						nativeModule().ubrn_uniffi_uniffi_russh_fn_free_sshconnection(
+						nativeModule().ubrn_uniffi_uniffi_russh_fn_free_terminalemulator(
 							pointer,
 							callStatus,
 						),
@@ -3614,17 +10037,27 @@ const uniffiTypeSshConnectionObjectFactory: UniffiObjectFactory<SshConnectionInt
 				);
 			},
 
-			isConcreteType(obj: any): obj is SshConnectionInterface {
+			isConcreteType(obj: any): obj is TerminalEmulatorInterface {
 				return (
 					obj[destructorGuardSymbol] &&
-					obj[uniffiTypeNameSymbol] === 'SshConnection'
+					obj[uniffiTypeNameSymbol] === 'TerminalEmulator'
 				);
 			},
 		};
 	})();
-// FfiConverter for SshConnectionInterface
-const FfiConverterTypeSshConnection = new FfiConverterObject(
-	uniffiTypeSshConnectionObjectFactory,
+// FfiConverter for TerminalEmulatorInterface
+const FfiConverterTypeTerminalEmulator = new FfiConverterObject(
+	uniffiTypeTerminalEmulatorObjectFactory,
+);
+
+// FfiConverter for BufferPolicy | undefined
+const FfiConverterOptionalTypeBufferPolicy = new FfiConverterOptional(
+	FfiConverterTypeBufferPolicy,
+);
+
+// FfiConverter for CompressionPreference | undefined
+const FfiConverterOptionalTypeCompressionPreference = new FfiConverterOptional(
+	FfiConverterTypeCompressionPreference,
 );
 
 // FfiConverter for DroppedRange | undefined
@@ -3632,6 +10065,36 @@ const FfiConverterOptionalTypeDroppedRange = new FfiConverterOptional(
 	FfiConverterTypeDroppedRange,
 );
 
+// FfiConverter for InvalidUtf8 | undefined
+const FfiConverterOptionalTypeInvalidUtf8 = new FfiConverterOptional(
+	FfiConverterTypeInvalidUtf8,
+);
+
+// FfiConverter for NegotiatedAlgorithms | undefined
+const FfiConverterOptionalTypeNegotiatedAlgorithms = new FfiConverterOptional(
+	FfiConverterTypeNegotiatedAlgorithms,
+);
+
+// FfiConverter for RecordingStats | undefined
+const FfiConverterOptionalTypeRecordingStats = new FfiConverterOptional(
+	FfiConverterTypeRecordingStats,
+);
+
+// FfiConverter for RekeyLimits | undefined
+const FfiConverterOptionalTypeRekeyLimits = new FfiConverterOptional(
+	FfiConverterTypeRekeyLimits,
+);
+
+// FfiConverter for ShellCommand | undefined
+const FfiConverterOptionalTypeShellCommand = new FfiConverterOptional(
+	FfiConverterTypeShellCommand,
+);
+
+// FfiConverter for StreamPosition | undefined
+const FfiConverterOptionalTypeStreamPosition = new FfiConverterOptional(
+	FfiConverterTypeStreamPosition,
+);
+
 // FfiConverter for TerminalPixelSize | undefined
 const FfiConverterOptionalTypeTerminalPixelSize = new FfiConverterOptional(
 	FfiConverterTypeTerminalPixelSize,
@@ -3642,6 +10105,17 @@ const FfiConverterOptionalTypeTerminalSize = new FfiConverterOptional(
 	FfiConverterTypeTerminalSize,
 );
 
+// FfiConverter for boolean | undefined
+const FfiConverterOptionalBool = new FfiConverterOptional(FfiConverterBool);
+
+// FfiConverter for /*f64*/number | undefined
+const FfiConverterOptionalFloat64 = new FfiConverterOptional(
+	FfiConverterFloat64,
+);
+
+// FfiConverter for /*i32*/number | undefined
+const FfiConverterOptionalInt32 = new FfiConverterOptional(FfiConverterInt32);
+
 // FfiConverter for string | undefined
 const FfiConverterOptionalString = new FfiConverterOptional(FfiConverterString);
 
@@ -3651,6 +10125,29 @@ const FfiConverterOptionalUInt32 = new FfiConverterOptional(FfiConverterUInt32);
 // FfiConverter for /*u64*/bigint | undefined
 const FfiConverterOptionalUInt64 = new FfiConverterOptional(FfiConverterUInt64);
 
+// FfiConverter for Array<string>
+const FfiConverterArrayString = new FfiConverterArray(FfiConverterString);
+
+// FfiConverter for Array<CommandRecord>
+const FfiConverterArrayTypeCommandRecord = new FfiConverterArray(
+	FfiConverterTypeCommandRecord,
+);
+
+// FfiConverter for Array<DamagedLine>
+const FfiConverterArrayTypeDamagedLine = new FfiConverterArray(
+	FfiConverterTypeDamagedLine,
+);
+
+// FfiConverter for Array<ScreenLine>
+const FfiConverterArrayTypeScreenLine = new FfiConverterArray(
+	FfiConverterTypeScreenLine,
+);
+
+// FfiConverter for Array<SearchMatch>
+const FfiConverterArrayTypeSearchMatch = new FfiConverterArray(
+	FfiConverterTypeSearchMatch,
+);
+
 // FfiConverter for Array<TerminalChunk>
 const FfiConverterArrayTypeTerminalChunk = new FfiConverterArray(
 	FfiConverterTypeTerminalChunk,
@@ -3661,6 +10158,30 @@ const FfiConverterArrayTypeTerminalMode = new FfiConverterArray(
 	FfiConverterTypeTerminalMode,
 );
 
+// FfiConverter for Array<TextRun>
+const FfiConverterArrayTypeTextRun = new FfiConverterArray(
+	FfiConverterTypeTextRun,
+);
+
+// FfiConverter for AuthBannerCallback | undefined
+const FfiConverterOptionalTypeAuthBannerCallback = new FfiConverterOptional(
+	FfiConverterTypeAuthBannerCallback,
+);
+
+// FfiConverter for CastPlayerStatusCallback | undefined
+const FfiConverterOptionalTypeCastPlayerStatusCallback =
+	new FfiConverterOptional(FfiConverterTypeCastPlayerStatusCallback);
+
+// FfiConverter for ChannelPauseCallback | undefined
+const FfiConverterOptionalTypeChannelPauseCallback = new FfiConverterOptional(
+	FfiConverterTypeChannelPauseCallback,
+);
+
+// FfiConverter for ConnectAbortTokenInterface | undefined
+const FfiConverterOptionalTypeConnectAbortToken = new FfiConverterOptional(
+	FfiConverterTypeConnectAbortToken,
+);
+
 // FfiConverter for ConnectProgressCallback | undefined
 const FfiConverterOptionalTypeConnectProgressCallback =
 	new FfiConverterOptional(FfiConverterTypeConnectProgressCallback);
@@ -3669,16 +10190,35 @@ const FfiConverterOptionalTypeConnectProgressCallback =
 const FfiConverterOptionalTypeConnectionDisconnectedCallback =
 	new FfiConverterOptional(FfiConverterTypeConnectionDisconnectedCallback);
 
+// FfiConverter for ConnectionEventCallback | undefined
+const FfiConverterOptionalTypeConnectionEventCallback =
+	new FfiConverterOptional(FfiConverterTypeConnectionEventCallback);
+
+// FfiConverter for ConnectionMetricsCallback | undefined
+const FfiConverterOptionalTypeConnectionMetricsCallback =
+	new FfiConverterOptional(FfiConverterTypeConnectionMetricsCallback);
+
 // FfiConverter for ShellClosedCallback | undefined
 const FfiConverterOptionalTypeShellClosedCallback = new FfiConverterOptional(
 	FfiConverterTypeShellClosedCallback,
 );
 
+// FfiConverter for Map<string, string>
+const FfiConverterMapStringString = new FfiConverterMap(
+	FfiConverterString,
+	FfiConverterString,
+);
+
 // FfiConverter for Array<TerminalMode> | undefined
 const FfiConverterOptionalArrayTypeTerminalMode = new FfiConverterOptional(
 	FfiConverterArrayTypeTerminalMode,
 );
 
+// FfiConverter for Map<string, string> | undefined
+const FfiConverterOptionalMapStringString = new FfiConverterOptional(
+	FfiConverterMapStringString,
+);
+
 /**
  * This should be called before anything else.
  *
@@ -3724,6 +10264,38 @@ function uniffiEnsureInitialized() {
 			'uniffi_uniffi_russh_checksum_func_generate_key_pair',
 		);
 	}
+	if (
+		nativeModule().ubrn_uniffi_uniffi_russh_checksum_func_memory_usage() !==
+		50752
+	) {
+		throw new UniffiInternalError.ApiChecksumMismatch(
+			'uniffi_uniffi_russh_checksum_func_memory_usage',
+		);
+	}
+	if (
+		nativeModule().ubrn_uniffi_uniffi_russh_checksum_func_open_cast_player() !==
+		17647
+	) {
+		throw new UniffiInternalError.ApiChecksumMismatch(
+			'uniffi_uniffi_russh_checksum_func_open_cast_player',
+		);
+	}
+	if (
+		nativeModule().ubrn_uniffi_uniffi_russh_checksum_func_public_key_fingerprints() !==
+		3432
+	) {
+		throw new UniffiInternalError.ApiChecksumMismatch(
+			'uniffi_uniffi_russh_checksum_func_public_key_fingerprints',
+		);
+	}
+	if (
+		nativeModule().ubrn_uniffi_uniffi_russh_checksum_func_set_memory_budget() !==
+		36222
+	) {
+		throw new UniffiInternalError.ApiChecksumMismatch(
+			'uniffi_uniffi_russh_checksum_func_set_memory_budget',
+		);
+	}
 	if (
 		nativeModule().ubrn_uniffi_uniffi_russh_checksum_func_validate_private_key() !==
 		49309
@@ -3732,6 +10304,174 @@ function uniffiEnsureInitialized() {
 			'uniffi_uniffi_russh_checksum_func_validate_private_key',
 		);
 	}
+	if (
+		nativeModule().ubrn_uniffi_uniffi_russh_checksum_method_authbannercallback_on_change() !==
+		15960
+	) {
+		throw new UniffiInternalError.ApiChecksumMismatch(
+			'uniffi_uniffi_russh_checksum_method_authbannercallback_on_change',
+		);
+	}
+	if (
+		nativeModule().ubrn_uniffi_uniffi_russh_checksum_method_castplayer_add_listener() !==
+		42002
+	) {
+		throw new UniffiInternalError.ApiChecksumMismatch(
+			'uniffi_uniffi_russh_checksum_method_castplayer_add_listener',
+		);
+	}
+	if (
+		nativeModule().ubrn_uniffi_uniffi_russh_checksum_method_castplayer_attach_emulator() !==
+		19198
+	) {
+		throw new UniffiInternalError.ApiChecksumMismatch(
+			'uniffi_uniffi_russh_checksum_method_castplayer_attach_emulator',
+		);
+	}
+	if (
+		nativeModule().ubrn_uniffi_uniffi_russh_checksum_method_castplayer_buffer_stats() !==
+		54456
+	) {
+		throw new UniffiInternalError.ApiChecksumMismatch(
+			'uniffi_uniffi_russh_checksum_method_castplayer_buffer_stats',
+		);
+	}
+	if (
+		nativeModule().ubrn_uniffi_uniffi_russh_checksum_method_castplayer_commands() !==
+		19188
+	) {
+		throw new UniffiInternalError.ApiChecksumMismatch(
+			'uniffi_uniffi_russh_checksum_method_castplayer_commands',
+		);
+	}
+	if (
+		nativeModule().ubrn_uniffi_uniffi_russh_checksum_method_castplayer_current_seq() !==
+		7364
+	) {
+		throw new UniffiInternalError.ApiChecksumMismatch(
+			'uniffi_uniffi_russh_checksum_method_castplayer_current_seq',
+		);
+	}
+	if (
+		nativeModule().ubrn_uniffi_uniffi_russh_checksum_method_castplayer_detach_emulator() !==
+		4770
+	) {
+		throw new UniffiInternalError.ApiChecksumMismatch(
+			'uniffi_uniffi_russh_checksum_method_castplayer_detach_emulator',
+		);
+	}
+	if (
+		nativeModule().ubrn_uniffi_uniffi_russh_checksum_method_castplayer_get_info() !==
+		62871
+	) {
+		throw new UniffiInternalError.ApiChecksumMismatch(
+			'uniffi_uniffi_russh_checksum_method_castplayer_get_info',
+		);
+	}
+	if (
+		nativeModule().ubrn_uniffi_uniffi_russh_checksum_method_castplayer_get_status() !==
+		15589
+	) {
+		throw new UniffiInternalError.ApiChecksumMismatch(
+			'uniffi_uniffi_russh_checksum_method_castplayer_get_status',
+		);
+	}
+	if (
+		nativeModule().ubrn_uniffi_uniffi_russh_checksum_method_castplayer_pause() !==
+		22142
+	) {
+		throw new UniffiInternalError.ApiChecksumMismatch(
+			'uniffi_uniffi_russh_checksum_method_castplayer_pause',
+		);
+	}
+	if (
+		nativeModule().ubrn_uniffi_uniffi_russh_checksum_method_castplayer_play() !==
+		5458
+	) {
+		throw new UniffiInternalError.ApiChecksumMismatch(
+			'uniffi_uniffi_russh_checksum_method_castplayer_play',
+		);
+	}
+	if (
+		nativeModule().ubrn_uniffi_uniffi_russh_checksum_method_castplayer_read_buffer() !==
+		9278
+	) {
+		throw new UniffiInternalError.ApiChecksumMismatch(
+			'uniffi_uniffi_russh_checksum_method_castplayer_read_buffer',
+		);
+	}
+	if (
+		nativeModule().ubrn_uniffi_uniffi_russh_checksum_method_castplayer_read_text() !==
+		49302
+	) {
+		throw new UniffiInternalError.ApiChecksumMismatch(
+			'uniffi_uniffi_russh_checksum_method_castplayer_read_text',
+		);
+	}
+	if (
+		nativeModule().ubrn_uniffi_uniffi_russh_checksum_method_castplayer_remove_listener() !==
+		59789
+	) {
+		throw new UniffiInternalError.ApiChecksumMismatch(
+			'uniffi_uniffi_russh_checksum_method_castplayer_remove_listener',
+		);
+	}
+	if (
+		nativeModule().ubrn_uniffi_uniffi_russh_checksum_method_castplayer_search() !==
+		38084
+	) {
+		throw new UniffiInternalError.ApiChecksumMismatch(
+			'uniffi_uniffi_russh_checksum_method_castplayer_search',
+		);
+	}
+	if (
+		nativeModule().ubrn_uniffi_uniffi_russh_checksum_method_castplayer_seek() !==
+		45730
+	) {
+		throw new UniffiInternalError.ApiChecksumMismatch(
+			'uniffi_uniffi_russh_checksum_method_castplayer_seek',
+		);
+	}
+	if (
+		nativeModule().ubrn_uniffi_uniffi_russh_checksum_method_castplayer_set_speed() !==
+		27263
+	) {
+		throw new UniffiInternalError.ApiChecksumMismatch(
+			'uniffi_uniffi_russh_checksum_method_castplayer_set_speed',
+		);
+	}
+	if (
+		nativeModule().ubrn_uniffi_uniffi_russh_checksum_method_castplayerstatuscallback_on_change() !==
+		16437
+	) {
+		throw new UniffiInternalError.ApiChecksumMismatch(
+			'uniffi_uniffi_russh_checksum_method_castplayerstatuscallback_on_change',
+		);
+	}
+	if (
+		nativeModule().ubrn_uniffi_uniffi_russh_checksum_method_channelpausecallback_on_change() !==
+		22782
+	) {
+		throw new UniffiInternalError.ApiChecksumMismatch(
+			'uniffi_uniffi_russh_checksum_method_channelpausecallback_on_change',
+		);
+	}
+	if (
+		nativeModule().ubrn_uniffi_uniffi_russh_checksum_method_connectaborttoken_abort() !==
+		58149
+	) {
+		throw new UniffiInternalError.ApiChecksumMismatch(
+			'uniffi_uniffi_russh_checksum_method_connectaborttoken_abort',
+		);
+	}
+	if (
+		nativeModule().ubrn_uniffi_uniffi_russh_checksum_method_connectaborttoken_is_aborted() !==
+		34372
+	) {
+		throw new UniffiInternalError.ApiChecksumMismatch(
+			'uniffi_uniffi_russh_checksum_method_connectaborttoken_is_aborted',
+		);
+	}
 	if (
 		nativeModule().ubrn_uniffi_uniffi_russh_checksum_method_connectprogresscallback_on_change() !==
 		17699
@@ -3748,6 +10488,22 @@ function uniffiEnsureInitialized() {
 			'uniffi_uniffi_russh_checksum_method_connectiondisconnectedcallback_on_change',
 		);
 	}
+	if (
+		nativeModule().ubrn_uniffi_uniffi_russh_checksum_method_connectioneventcallback_on_change() !==
+		64522
+	) {
+		throw new UniffiInternalError.ApiChecksumMismatch(
+			'uniffi_uniffi_russh_checksum_method_connectioneventcallback_on_change',
+		);
+	}
+	if (
+		nativeModule().ubrn_uniffi_uniffi_russh_checksum_method_connectionmetricscallback_on_change() !==
+		43491
+	) {
+		throw new UniffiInternalError.ApiChecksumMismatch(
+			'uniffi_uniffi_russh_checksum_method_connectionmetricscallback_on_change',
+		);
+	}
 	if (
 		nativeModule().ubrn_uniffi_uniffi_russh_checksum_method_serverkeycallback_on_change() !==
 		5111
@@ -3780,6 +10536,14 @@ function uniffiEnsureInitialized() {
 			'uniffi_uniffi_russh_checksum_method_shellsession_add_listener',
 		);
 	}
+	if (
+		nativeModule().ubrn_uniffi_uniffi_russh_checksum_method_shellsession_attach_emulator() !==
+		7972
+	) {
+		throw new UniffiInternalError.ApiChecksumMismatch(
+			'uniffi_uniffi_russh_checksum_method_shellsession_attach_emulator',
+		);
+	}
 	if (
 		nativeModule().ubrn_uniffi_uniffi_russh_checksum_method_shellsession_buffer_stats() !==
 		11878
@@ -3796,6 +10560,14 @@ function uniffiEnsureInitialized() {
 			'uniffi_uniffi_russh_checksum_method_shellsession_close',
 		);
 	}
+	if (
+		nativeModule().ubrn_uniffi_uniffi_russh_checksum_method_shellsession_commands() !==
+		27352
+	) {
+		throw new UniffiInternalError.ApiChecksumMismatch(
+			'uniffi_uniffi_russh_checksum_method_shellsession_commands',
+		);
+	}
 	if (
 		nativeModule().ubrn_uniffi_uniffi_russh_checksum_method_shellsession_current_seq() !==
 		5967
@@ -3804,6 +10576,14 @@ function uniffiEnsureInitialized() {
 			'uniffi_uniffi_russh_checksum_method_shellsession_current_seq',
 		);
 	}
+	if (
+		nativeModule().ubrn_uniffi_uniffi_russh_checksum_method_shellsession_detach_emulator() !==
+		52169
+	) {
+		throw new UniffiInternalError.ApiChecksumMismatch(
+			'uniffi_uniffi_russh_checksum_method_shellsession_detach_emulator',
+		);
+	}
 	if (
 		nativeModule().ubrn_uniffi_uniffi_russh_checksum_method_shellsession_get_info() !==
 		45659
@@ -3820,6 +10600,14 @@ function uniffiEnsureInitialized() {
 			'uniffi_uniffi_russh_checksum_method_shellsession_read_buffer',
 		);
 	}
+	if (
+		nativeModule().ubrn_uniffi_uniffi_russh_checksum_method_shellsession_read_text() !==
+		64074
+	) {
+		throw new UniffiInternalError.ApiChecksumMismatch(
+			'uniffi_uniffi_russh_checksum_method_shellsession_read_text',
+		);
+	}
 	if (
 		nativeModule().ubrn_uniffi_uniffi_russh_checksum_method_shellsession_remove_listener() !==
 		24718
@@ -3828,6 +10616,30 @@ function uniffiEnsureInitialized() {
 			'uniffi_uniffi_russh_checksum_method_shellsession_remove_listener',
 		);
 	}
+	if (
+		nativeModule().ubrn_uniffi_uniffi_russh_checksum_method_shellsession_resize() !==
+		2626
+	) {
+		throw new UniffiInternalError.ApiChecksumMismatch(
+			'uniffi_uniffi_russh_checksum_method_shellsession_resize',
+		);
+	}
+	if (
+		nativeModule().ubrn_uniffi_uniffi_russh_checksum_method_shellsession_search() !==
+		16018
+	) {
+		throw new UniffiInternalError.ApiChecksumMismatch(
+			'uniffi_uniffi_russh_checksum_method_shellsession_search',
+		);
+	}
+	if (
+		nativeModule().ubrn_uniffi_uniffi_russh_checksum_method_shellsession_send_break() !==
+		55935
+	) {
+		throw new UniffiInternalError.ApiChecksumMismatch(
+			'uniffi_uniffi_russh_checksum_method_shellsession_send_break',
+		);
+	}
 	if (
 		nativeModule().ubrn_uniffi_uniffi_russh_checksum_method_shellsession_send_data() !==
 		12893
@@ -3836,6 +10648,38 @@ function uniffiEnsureInitialized() {
 			'uniffi_uniffi_russh_checksum_method_shellsession_send_data',
 		);
 	}
+	if (
+		nativeModule().ubrn_uniffi_uniffi_russh_checksum_method_shellsession_send_signal() !==
+		54312
+	) {
+		throw new UniffiInternalError.ApiChecksumMismatch(
+			'uniffi_uniffi_russh_checksum_method_shellsession_send_signal',
+		);
+	}
+	if (
+		nativeModule().ubrn_uniffi_uniffi_russh_checksum_method_shellsession_set_buffer_policy() !==
+		65165
+	) {
+		throw new UniffiInternalError.ApiChecksumMismatch(
+			'uniffi_uniffi_russh_checksum_method_shellsession_set_buffer_policy',
+		);
+	}
+	if (
+		nativeModule().ubrn_uniffi_uniffi_russh_checksum_method_shellsession_start_recording() !==
+		51229
+	) {
+		throw new UniffiInternalError.ApiChecksumMismatch(
+			'uniffi_uniffi_russh_checksum_method_shellsession_start_recording',
+		);
+	}
+	if (
+		nativeModule().ubrn_uniffi_uniffi_russh_checksum_method_shellsession_stop_recording() !==
+		3939
+	) {
+		throw new UniffiInternalError.ApiChecksumMismatch(
+			'uniffi_uniffi_russh_checksum_method_shellsession_stop_recording',
+		);
+	}
 	if (
 		nativeModule().ubrn_uniffi_uniffi_russh_checksum_method_sshconnection_disconnect() !==
 		1510
@@ -3852,6 +10696,38 @@ function uniffiEnsureInitialized() {
 			'uniffi_uniffi_russh_checksum_method_sshconnection_get_info',
 		);
 	}
+	if (
+		nativeModule().ubrn_uniffi_uniffi_russh_checksum_method_sshconnection_get_metrics() !==
+		13812
+	) {
+		throw new UniffiInternalError.ApiChecksumMismatch(
+			'uniffi_uniffi_russh_checksum_method_sshconnection_get_metrics',
+		);
+	}
+	if (
+		nativeModule().ubrn_uniffi_uniffi_russh_checksum_method_sshconnection_install_bundled_terminfo() !==
+		12165
+	) {
+		throw new UniffiInternalError.ApiChecksumMismatch(
+			'uniffi_uniffi_russh_checksum_method_sshconnection_install_bundled_terminfo',
+		);
+	}
+	if (
+		nativeModule().ubrn_uniffi_uniffi_russh_checksum_method_sshconnection_install_terminfo() !==
+		2464
+	) {
+		throw new UniffiInternalError.ApiChecksumMismatch(
+			'uniffi_uniffi_russh_checksum_method_sshconnection_install_terminfo',
+		);
+	}
+	if (
+		nativeModule().ubrn_uniffi_uniffi_russh_checksum_method_sshconnection_rekey() !==
+		37541
+	) {
+		throw new UniffiInternalError.ApiChecksumMismatch(
+			'uniffi_uniffi_russh_checksum_method_sshconnection_rekey',
+		);
+	}
 	if (
 		nativeModule().ubrn_uniffi_uniffi_russh_checksum_method_sshconnection_start_shell() !==
 		21163
@@ -3860,9 +10736,86 @@ function uniffiEnsureInitialized() {
 			'uniffi_uniffi_russh_checksum_method_sshconnection_start_shell',
 		);
 	}
+	if (
+		nativeModule().ubrn_uniffi_uniffi_russh_checksum_method_terminalemulator_feed() !==
+		58706
+	) {
+		throw new UniffiInternalError.ApiChecksumMismatch(
+			'uniffi_uniffi_russh_checksum_method_terminalemulator_feed',
+		);
+	}
+	if (
+		nativeModule().ubrn_uniffi_uniffi_russh_checksum_method_terminalemulator_resize() !==
+		20153
+	) {
+		throw new UniffiInternalError.ApiChecksumMismatch(
+			'uniffi_uniffi_russh_checksum_method_terminalemulator_resize',
+		);
+	}
+	if (
+		nativeModule().ubrn_uniffi_uniffi_russh_checksum_method_terminalemulator_scrollback_len() !==
+		45916
+	) {
+		throw new UniffiInternalError.ApiChecksumMismatch(
+			'uniffi_uniffi_russh_checksum_method_terminalemulator_scrollback_len',
+		);
+	}
+	if (
+		nativeModule().ubrn_uniffi_uniffi_russh_checksum_method_terminalemulator_scrollback_lines() !==
+		39746
+	) {
+		throw new UniffiInternalError.ApiChecksumMismatch(
+			'uniffi_uniffi_russh_checksum_method_terminalemulator_scrollback_lines',
+		);
+	}
+	if (
+		nativeModule().ubrn_uniffi_uniffi_russh_checksum_method_terminalemulator_snapshot() !==
+		30090
+	) {
+		throw new UniffiInternalError.ApiChecksumMismatch(
+			'uniffi_uniffi_russh_checksum_method_terminalemulator_snapshot',
+		);
+	}
+	if (
+		nativeModule().ubrn_uniffi_uniffi_russh_checksum_method_terminalemulator_take_damage() !==
+		8613
+	) {
+		throw new UniffiInternalError.ApiChecksumMismatch(
+			'uniffi_uniffi_russh_checksum_method_terminalemulator_take_damage',
+		);
+	}
+	if (
+		nativeModule().ubrn_uniffi_uniffi_russh_checksum_method_terminalemulator_take_responses() !==
+		12943
+	) {
+		throw new UniffiInternalError.ApiChecksumMismatch(
+			'uniffi_uniffi_russh_checksum_method_terminalemulator_take_responses',
+		);
+	}
+	if (
+		nativeModule().ubrn_uniffi_uniffi_russh_checksum_constructor_connectaborttoken_new() !==
+		34860
+	) {
+		throw new UniffiInternalError.ApiChecksumMismatch(
+			'uniffi_uniffi_russh_checksum_constructor_connectaborttoken_new',
+		);
+	}
+	if (
+		nativeModule().ubrn_uniffi_uniffi_russh_checksum_constructor_terminalemulator_new() !==
+		43588
+	) {
+		throw new UniffiInternalError.ApiChecksumMismatch(
+			'uniffi_uniffi_russh_checksum_constructor_terminalemulator_new',
+		);
+	}
 
+	uniffiCallbackInterfaceAuthBannerCallback.register();
+	uniffiCallbackInterfaceCastPlayerStatusCallback.register();
+	uniffiCallbackInterfaceChannelPauseCallback.register();
 	uniffiCallbackInterfaceConnectProgressCallback.register();
 	uniffiCallbackInterfaceConnectionDisconnectedCallback.register();
+	uniffiCallbackInterfaceConnectionEventCallback.register();
+	uniffiCallbackInterfaceConnectionMetricsCallback.register();
 	uniffiCallbackInterfaceServerKeyCallback.register();
 	uniffiCallbackInterfaceShellClosedCallback.register();
 	uniffiCallbackInterfaceShellListener.register();
@@ -3871,35 +10824,80 @@ function uniffiEnsureInitialized() {
 export default Object.freeze({
 	initialize: uniffiEnsureInitialized,
 	converters: {
+		FfiConverterTypeAuthBannerCallback,
+		FfiConverterTypeBufferPolicy,
 		FfiConverterTypeBufferReadResult,
 		FfiConverterTypeBufferStats,
+		FfiConverterTypeCastInfo,
+		FfiConverterTypeCastPlayer,
+		FfiConverterTypeCastPlayerOptions,
+		FfiConverterTypeCastPlayerStatusCallback,
+		FfiConverterTypeCastSource,
+		FfiConverterTypeCellStyle,
+		FfiConverterTypeChannelPauseCallback,
+		FfiConverterTypeCommandRecord,
+		FfiConverterTypeCompressionPreference,
+		FfiConverterTypeConnectAbortToken,
 		FfiConverterTypeConnectOptions,
 		FfiConverterTypeConnectProgressCallback,
 		FfiConverterTypeConnectionDetails,
 		FfiConverterTypeConnectionDisconnectedCallback,
+		FfiConverterTypeConnectionEventCallback,
+		FfiConverterTypeConnectionMetricsCallback,
 		FfiConverterTypeCursor,
+		FfiConverterTypeCursorShape,
+		FfiConverterTypeCursorState,
+		FfiConverterTypeDamagedLine,
 		FfiConverterTypeDroppedRange,
+		FfiConverterTypeInvalidUtf8,
 		FfiConverterTypeKeyType,
 		FfiConverterTypeListenerOptions,
+		FfiConverterTypeMemoryUsage,
+		FfiConverterTypeMouseTracking,
+		FfiConverterTypeNegotiatedAlgorithms,
+		FfiConverterTypePlaybackStatus,
+		FfiConverterTypePublicKeyFingerprints,
+		FfiConverterTypeRecordingOptions,
+		FfiConverterTypeRecordingStats,
+		FfiConverterTypeRekeyLimits,
+		FfiConverterTypeScreenDamage,
+		FfiConverterTypeScreenLine,
+		FfiConverterTypeScreenModes,
+		FfiConverterTypeScreenSnapshot,
+		FfiConverterTypeSearchMatch,
+		FfiConverterTypeSearchOptions,
+		FfiConverterTypeSearchResult,
 		FfiConverterTypeSecurity,
 		FfiConverterTypeServerKeyCallback,
 		FfiConverterTypeServerPublicKeyInfo,
 		FfiConverterTypeShellClosedCallback,
+		FfiConverterTypeShellCommand,
 		FfiConverterTypeShellEvent,
 		FfiConverterTypeShellListener,
 		FfiConverterTypeShellSession,
 		FfiConverterTypeShellSessionInfo,
+		FfiConverterTypeShellSignal,
 		FfiConverterTypeSshConnection,
+		FfiConverterTypeSshConnectionEvent,
 		FfiConverterTypeSshConnectionInfo,
 		FfiConverterTypeSshConnectionInfoProgressTimings,
+		FfiConverterTypeSshConnectionMetrics,
 		FfiConverterTypeSshConnectionProgressEvent,
 		FfiConverterTypeSshError,
 		FfiConverterTypeStartShellOptions,
 		FfiConverterTypeStreamKind,
+		FfiConverterTypeStreamPosition,
 		FfiConverterTypeTerminalChunk,
+		FfiConverterTypeTerminalColor,
+		FfiConverterTypeTerminalEmulator,
+		FfiConverterTypeTerminalGeometry,
 		FfiConverterTypeTerminalMode,
 		FfiConverterTypeTerminalPixelSize,
 		FfiConverterTypeTerminalSize,
+		FfiConverterTypeTerminalText,
 		FfiConverterTypeTerminalType,
+		FfiConverterTypeTerminfoStatus,
+		FfiConverterTypeTextReadResult,
+		FfiConverterTypeTextRun,
 	},
 });